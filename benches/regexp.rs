@@ -0,0 +1,113 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks of the regexp functions on a batch of log lines, with a literal pattern and with a
+//! column of patterns sharing a few distinct values (e.g. one pattern per log format).
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, StringArray};
+use criterion::{criterion_group, criterion_main, Criterion};
+use datafusion::common::ScalarValue;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDF};
+use sql_functions::distinct::{self, JoniRegexp};
+
+const ROWS: usize = 8192;
+
+const LINES: &[&str] = &[
+    "2024-05-01 12:00:01 ERROR [db] connection 42 refused after 3 retries",
+    "2024-05-01 12:00:02 INFO [http] GET /api/v1/users/1234 200 15ms",
+    "2024-05-01 12:00:03 WARN [cache] eviction of 512 entries took 87ms",
+    "2024-05-01 12:00:04 DEBUG [scheduler] job 7 queued, 12 pending",
+];
+
+const PATTERNS: &[&str] = &[
+    r"\d+",
+    r"(ERROR|WARN) \[(\w+)\]",
+    r"/api/v\d+/\w+/(\d+)",
+    r"\b\d+ms\b",
+];
+
+fn udf(name: &str) -> Arc<ScalarUDF> {
+    let name = format!("trino::{name}");
+    sql_functions::trino::functions()
+        .into_iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, udf)| udf)
+        .unwrap_or_else(|| panic!("no function {name}"))
+}
+
+/// The `joniregexp` column of `patterns`.
+fn joniregexp(patterns: StringArray) -> ArrayRef {
+    Arc::new(distinct::wrap::<JoniRegexp>(patterns))
+}
+
+fn invoke(udf: &ScalarUDF, args: &[ColumnarValue]) -> ArrayRef {
+    let arg_types = args.iter().map(|arg| arg.data_type()).collect::<Vec<_>>();
+    let return_type = udf.return_type(&arg_types).unwrap();
+    udf.invoke_with_args(ScalarFunctionArgs {
+        args: args.to_vec(),
+        number_rows: ROWS,
+        return_type: &return_type,
+    })
+    .and_then(|result| result.into_array(ROWS))
+    .unwrap()
+}
+
+fn regexp_functions(c: &mut Criterion) {
+    let lines = (0..ROWS).map(|i| LINES[i % LINES.len()]);
+    let lines = ColumnarValue::Array(Arc::new(StringArray::from_iter_values(lines)));
+    let literal = joniregexp(StringArray::from(vec![PATTERNS[0]]));
+    let literal = ColumnarValue::Scalar(ScalarValue::try_from_array(&literal, 0).unwrap());
+    let patterns = (0..ROWS).map(|i| PATTERNS[i % PATTERNS.len()]);
+    let patterns = ColumnarValue::Array(joniregexp(StringArray::from_iter_values(patterns)));
+    let replacement = ColumnarValue::Scalar(ScalarValue::Utf8(Some("#".to_string())));
+
+    let functions = [
+        ("regexp_count_varchar_joniregexp", vec![]),
+        (
+            "regexp_extract_varchar_joniregexp_bigint",
+            vec![ScalarValue::Int64(Some(0))],
+        ),
+        ("regexp_extract_all_varchar_joniregexp", vec![]),
+        ("regexp_position_varchar_joniregexp", vec![]),
+        ("regexp_replace_varchar_joniregexp_varchar", vec![]),
+        ("regexp_split_varchar_joniregexp", vec![]),
+    ];
+    for (name, extra_args) in functions {
+        let udf = udf(name);
+        let mut extra_args = extra_args
+            .into_iter()
+            .map(ColumnarValue::Scalar)
+            .collect::<Vec<_>>();
+        if name.starts_with("regexp_replace") {
+            extra_args.push(replacement.clone());
+        }
+        let mut group = c.benchmark_group(name);
+        group.sample_size(10);
+        for (pattern_name, pattern) in
+            [("literal pattern", &literal), ("pattern column", &patterns)]
+        {
+            let args = [vec![lines.clone(), pattern.clone()], extra_args.clone()].concat();
+            group.bench_function(pattern_name, |b| b.iter(|| invoke(&udf, &args)));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, regexp_functions);
+criterion_main!(benches);
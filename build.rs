@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Records the implementation status of every generated function overload.
+//!
+//! The overloads are the UDFs of the generated registry, `src/<dialect>/mod.rs`, each made from
+//! `<file>_impl::<overload>Func`. The hand-written part of `src/<dialect>/<file>_impl.rs` (above
+//! the generated template) defines the functions of the overload: `<overload>_invoke` (or
+//! `_invoke_no_args` or `_invoke_with_args`), `_accumulator`, `_partition_evaluator` or `_call`,
+//! which the generated UDF calls to evaluate it, and `_return_type`, `_state_fields` and
+//! `_simplify`. An overload is
+//! - a stub if its evaluating function is still the generated stub body returning a "not
+//!   implemented" error, and it is not simplified either,
+//! - partial if another of its functions is still a stub, or if one of its functions reaches a
+//!   "not implemented" error, directly or through a helper of its file or of the crate (e.g. the
+//!   element types a `KernelLifter` does not support),
+//! - implemented otherwise.
+//!
+//! The result is written to `$OUT_DIR/implementation_status.rs` as a sorted table of
+//! `(dialect, overload, status)`, see `src/coverage.rs`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const DIALECTS: &[&str] = &["redshift", "snowflake", "trino"];
+const TEMPLATE_MARKER: &str = "// ========== Generated template below this line ==========";
+const NOT_IMPLEMENTED: &[&str] = &[
+    "DataFusionError::NotImplemented",
+    "not_impl_err!",
+    "todo!",
+    "unimplemented!",
+];
+/// The suffixes of the functions that evaluate an overload.
+const EVALUATE: &[&str] = &[
+    "_invoke",
+    "_invoke_no_args",
+    "_invoke_with_args",
+    "_accumulator",
+    "_partition_evaluator",
+    "_call",
+];
+/// The suffixes of the other hand-written functions of an overload.
+const DESCRIBE: &[&str] = &["_return_type", "_state_fields"];
+
+/// A top-level item of a (rustfmt-formatted) source file.
+struct Item {
+    /// The name of the function, type or trait, or the type of an `impl` block
+    name: String,
+    source: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    let helpers = crate_helpers();
+    let mut rows = vec![];
+    for dialect in DIALECTS {
+        let dir = Path::new("src").join(dialect);
+        let registry = read(&dir.join("mod.rs"));
+        let mut files: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (file, overload) in registered_overloads(&registry) {
+            files.entry(file).or_default().push(overload);
+        }
+        for (file, overloads) in files {
+            let source = read(&dir.join(format!("{file}.rs")));
+            let handwritten = source.split(TEMPLATE_MARKER).next().unwrap_or_default();
+            let items = items(handwritten);
+            let functions = items
+                .iter()
+                .map(|item| (item.name.as_str(), item.source.as_str()))
+                .collect::<BTreeMap<_, _>>();
+            let not_implemented = not_implemented_helpers(&items, &overloads, helpers.clone());
+            for overload in overloads {
+                rows.push(format!(
+                    "    (\"{dialect}\", \"{overload}\", ImplementationStatus::{}),\n",
+                    status(overload, &functions, &not_implemented)
+                ));
+            }
+        }
+    }
+
+    rows.sort();
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR")).join("implementation_status.rs");
+    let table = format!(
+        "/// Implementation status of every overload, sorted by dialect and overload name.\n\
+         pub(crate) static IMPLEMENTATION_STATUS: &[(&str, &str, ImplementationStatus)] = &[\n{}];\n",
+        rows.concat()
+    );
+    fs::write(&out, table).unwrap_or_else(|e| panic!("cannot write {}: {e}", out.display()));
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()))
+}
+
+/// The `(file, overload)` of every `<file>::<overload>Func` UDF of a generated registry.
+fn registered_overloads(registry: &str) -> Vec<(&str, &str)> {
+    registry
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .filter_map(|path| path.split_once("::"))
+        .filter_map(|(file, udf)| Some((file, udf.strip_suffix("Func")?)))
+        .filter(|(file, overload)| file.ends_with("_impl") && !overload.is_empty())
+        .collect()
+}
+
+fn status(
+    overload: &str,
+    functions: &BTreeMap<&str, &str>,
+    not_implemented: &BTreeSet<String>,
+) -> &'static str {
+    let function = |suffix: &str| {
+        functions
+            .get(format!("{overload}{suffix}").as_str())
+            .copied()
+    };
+    let evaluate = EVALUATE.iter().find_map(|suffix| function(suffix));
+    let simplified = function("_simplify").is_some_and(|body| !is_unchanged_simplify(body));
+    let stub = evaluate.is_none_or(is_stub);
+    if stub && !simplified {
+        return "Stub";
+    }
+    // The evaluating function of a simplified overload is not reached
+    let reached = DESCRIBE
+        .iter()
+        .filter_map(|suffix| function(suffix))
+        .chain(evaluate.filter(|_| !stub))
+        .chain(function("_simplify"));
+    let mut partial = false;
+    for body in reached {
+        partial |= is_stub(body)
+            || has_not_implemented(body)
+            || identifiers(body).any(|name| not_implemented.contains(name));
+    }
+    if partial {
+        "Partial"
+    } else {
+        "Implemented"
+    }
+}
+
+/// The helpers of the crate (outside the dialect modules) that return "not implemented" errors
+/// on some of their code paths.
+fn crate_helpers() -> BTreeSet<String> {
+    let mut paths = fs::read_dir("src")
+        .expect("cannot read src")
+        .map(|entry| entry.expect("directory entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    let items = paths
+        .iter()
+        .flat_map(|path| items(&read(path)))
+        .collect::<Vec<_>>();
+    not_implemented_helpers(&items, &[], BTreeSet::new())
+}
+
+/// Adds the items that return "not implemented" errors, or use an item that does, to
+/// `not_implemented`. The functions of `overloads` are not helpers.
+fn not_implemented_helpers(
+    items: &[Item],
+    overloads: &[&str],
+    mut not_implemented: BTreeSet<String>,
+) -> BTreeSet<String> {
+    let helpers = items
+        .iter()
+        .filter(|item| {
+            !overloads.iter().any(|overload| {
+                item.name
+                    .strip_prefix(overload)
+                    .is_some_and(|suffix| suffix.starts_with('_'))
+            })
+        })
+        .collect::<Vec<_>>();
+    loop {
+        let before = not_implemented.len();
+        for item in &helpers {
+            if !not_implemented.contains(&item.name)
+                && (has_not_implemented(&item.source)
+                    || identifiers(&item.source)
+                        .any(|name| name != item.name.as_str() && not_implemented.contains(name)))
+            {
+                not_implemented.insert(item.name.clone());
+            }
+        }
+        if not_implemented.len() == before {
+            return not_implemented;
+        }
+    }
+}
+
+fn has_not_implemented(body: &str) -> bool {
+    NOT_IMPLEMENTED.iter().any(|marker| body.contains(marker))
+}
+
+/// A body consisting of nothing but a "not implemented" error, as generated for a new overload.
+fn is_stub(body: &str) -> bool {
+    let inner = compact(body);
+    !inner.contains(';')
+        && (inner.starts_with("Err(DataFusionError::NotImplemented(")
+            || inner.starts_with("not_impl_err!(")
+            || inner.starts_with("todo!(")
+            || inner.starts_with("unimplemented!("))
+}
+
+/// The generated default simplification, which leaves the call unchanged.
+fn is_unchanged_simplify(body: &str) -> bool {
+    compact(body) == "Ok(ExprSimplifyResult::Original(args))"
+}
+
+/// The statements of a function body, without whitespace.
+fn compact(body: &str) -> String {
+    let inner = match (body.find('{'), body.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &body[start + 1..end],
+        _ => body,
+    };
+    inner.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn identifiers(source: &str) -> impl Iterator<Item = &str> {
+    source
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+}
+
+/// The top-level functions, types, traits and `impl` blocks of a (rustfmt-formatted) source
+/// file. An `impl` block is named after the type it implements.
+fn items(source: &str) -> Vec<Item> {
+    let mut res = vec![];
+    let mut current: Option<Item> = None;
+    for line in source.lines() {
+        if let Some(item) = current.as_mut() {
+            item.source.push_str(line);
+            item.source.push('\n');
+            if line == "}" || line == "};" {
+                res.extend(current.take());
+            }
+        } else if let Some(name) = item_name(line) {
+            let item = Item {
+                name,
+                source: format!("{line}\n"),
+            };
+            if line.ends_with('}') || line.ends_with(';') {
+                res.push(item);
+            } else {
+                current = Some(item);
+            }
+        }
+    }
+    res
+}
+
+fn item_name(line: &str) -> Option<String> {
+    let line = ["pub ", "pub(crate) ", "pub(super) "]
+        .iter()
+        .find_map(|visibility| line.strip_prefix(visibility))
+        .unwrap_or(line);
+    let rest = if let Some(rest) = line.strip_prefix("impl") {
+        // `impl<T> Trait<T> for Type<T> {` or `impl Type {`
+        let rest = skip_generics(rest);
+        rest.split_once(" for ").map_or(rest, |(_, target)| target)
+    } else {
+        ["fn ", "struct ", "enum ", "trait ", "const ", "static "]
+            .iter()
+            .find_map(|keyword| line.strip_prefix(keyword))?
+    };
+    identifiers(rest)
+        .next()
+        .filter(|name| *name != "dyn")
+        .map(str::to_string)
+}
+
+/// `text` after its leading generic parameters, if any.
+fn skip_generics(text: &str) -> &str {
+    if !text.starts_with('<') {
+        return text;
+    }
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return &text[i + 1..];
+        }
+    }
+    text
+}
@@ -0,0 +1,322 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Aggregate dialect functions.
+//!
+//! As for scalar functions (see [`crate::overload`]), every overload of an aggregate function is
+//! generated as its own `AggregateUDFImpl` (e.g. `geometric_mean_bigint`,
+//! `geometric_mean_double`, ...), whose hand-written `<overload>_accumulator`,
+//! `<overload>_return_type` and `<overload>_state_fields` functions define the accumulator, the
+//! result type and the fields of the intermediate state. The accumulator must also implement
+//! `retract_batch` for the function to be used over sliding window frames. An
+//! [`OverloadedUDAF`] bundles the overloads of one name and delegates to the one matching the
+//! argument types. The properties of a function that do not depend on its argument types, e.g.
+//! its value over no rows, are those its overloads agree on.
+
+use crate::overload::{overload_documentation, resolve_overload, Overload};
+use crate::type_signature::{FunctionSignature, Resolution};
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::{Result, ScalarValue};
+use datafusion::logical_expr::function::{
+    AccumulatorArgs, AggregateFunctionSimplification, StateFieldsArgs,
+};
+use datafusion::logical_expr::utils::AggregateOrderSensitivity;
+use datafusion::logical_expr::{
+    Accumulator, AggregateUDF, AggregateUDFImpl, Documentation, Expr, GroupsAccumulator,
+    ReversedUDAF, SetMonotonicity, Signature, StatisticsArgs, Volatility,
+};
+use std::any::Any;
+use std::sync::Arc;
+
+/// One overload of a dialect aggregate function.
+#[derive(Debug, Clone)]
+pub struct AggregateOverload {
+    pub dialect: &'static str,
+    /// The generated name of the overload, e.g. `geometric_mean_double`
+    pub name: &'static str,
+    pub udaf: Arc<AggregateUDF>,
+    pub signature: FunctionSignature,
+}
+
+impl Overload for AggregateOverload {
+    type Function = AggregateUDF;
+
+    fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn function_name(&self) -> &str {
+        self.udaf.name()
+    }
+
+    fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Option<DataType> {
+        self.udaf.return_type(arg_types).ok()
+    }
+
+    fn dispatcher(name: &str, overloads: Vec<Self>) -> Arc<AggregateUDF> {
+        Arc::new(AggregateUDF::new_from_impl(OverloadedUDAF::new(
+            name, overloads,
+        )))
+    }
+}
+
+/// An aggregate UDF dispatching to the overload of a function best matching its argument types.
+#[derive(Debug)]
+pub struct OverloadedUDAF {
+    name: String,
+    signature: Signature,
+    /// The overloads, in declaration order, see [`resolve_overload`].
+    overloads: Vec<AggregateOverload>,
+    documentation: Option<Documentation>,
+}
+
+impl OverloadedUDAF {
+    /// Create the dispatcher for `overloads`, which must all share the name `name`.
+    pub fn new(name: &str, overloads: Vec<AggregateOverload>) -> Self {
+        let volatility = overloads
+            .iter()
+            .map(|o| o.udaf.signature().volatility)
+            .max_by_key(|v| match v {
+                Volatility::Immutable => 0,
+                Volatility::Stable => 1,
+                Volatility::Volatile => 2,
+            })
+            .unwrap_or(Volatility::Immutable);
+        let documentation =
+            overload_documentation(overloads.iter().filter_map(|o| o.udaf.documentation()));
+        Self {
+            name: name.to_string(),
+            signature: Signature::user_defined(volatility),
+            overloads,
+            documentation,
+        }
+    }
+
+    /// The overloads of this function, in declaration order.
+    pub fn overloads(&self) -> &[AggregateOverload] {
+        &self.overloads
+    }
+
+    /// Pick the overload best matching `arg_types`, see [`resolve_overload`].
+    pub fn resolve(&self, arg_types: &[DataType]) -> Result<(&Arc<AggregateUDF>, Resolution)> {
+        resolve_overload(&self.name, &self.overloads, arg_types).map(|(o, res)| (&o.udaf, res))
+    }
+
+    /// The property `f` of the overloads if they all agree on it.
+    fn uniform<T: PartialEq>(&self, f: impl Fn(&AggregateUDF) -> T) -> Option<T> {
+        let mut values = self.overloads.iter().map(|o| f(&o.udaf));
+        let first = values.next()?;
+        values.all(|value| value == first).then_some(first)
+    }
+
+    /// The overload called with the arguments of `args`, which the planner has coerced.
+    fn resolve_accumulator(&self, args: &AccumulatorArgs) -> Result<&Arc<AggregateUDF>> {
+        let arg_types = args
+            .exprs
+            .iter()
+            .map(|e| e.data_type(args.schema))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.resolve(&arg_types)?.0)
+    }
+}
+
+impl AggregateUDFImpl for OverloadedUDAF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let (_, res) = self.resolve(arg_types)?;
+        Ok(res.coerced_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let (udaf, res) = self.resolve(arg_types)?;
+        udaf.return_type(&res.coerced_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        self.resolve_accumulator(&acc_args)?.accumulator(acc_args)
+    }
+
+    fn create_sliding_accumulator(&self, args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        self.resolve_accumulator(&args)?
+            .create_sliding_accumulator(args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        let (udaf, _) = self.resolve(args.input_types)?;
+        udaf.state_fields(args)
+    }
+
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        self.resolve_accumulator(&args)
+            .is_ok_and(|udaf| udaf.groups_accumulator_supported(args))
+    }
+
+    fn create_groups_accumulator(
+        &self,
+        args: AccumulatorArgs,
+    ) -> Result<Box<dyn GroupsAccumulator>> {
+        self.resolve_accumulator(&args)?
+            .create_groups_accumulator(args)
+    }
+
+    /// Nullable if any overload is.
+    fn is_nullable(&self) -> bool {
+        self.overloads.iter().any(|o| o.udaf.is_nullable())
+    }
+
+    /// The strongest ordering requirement of the overloads.
+    fn order_sensitivity(&self) -> AggregateOrderSensitivity {
+        let sensitivities = self
+            .overloads
+            .iter()
+            .map(|o| o.udaf.order_sensitivity())
+            .collect::<Vec<_>>();
+        if sensitivities
+            .iter()
+            .any(AggregateOrderSensitivity::hard_requires)
+        {
+            AggregateOrderSensitivity::HardRequirement
+        } else if sensitivities
+            .iter()
+            .any(AggregateOrderSensitivity::is_beneficial)
+        {
+            AggregateOrderSensitivity::Beneficial
+        } else {
+            AggregateOrderSensitivity::Insensitive
+        }
+    }
+
+    fn with_beneficial_ordering(
+        self: Arc<Self>,
+        beneficial_ordering: bool,
+    ) -> Result<Option<Arc<dyn AggregateUDFImpl>>> {
+        let mut overloads = vec![];
+        for overload in &self.overloads {
+            let udaf = overload.udaf.as_ref().clone();
+            match udaf.with_beneficial_ordering(beneficial_ordering)? {
+                Some(udaf) => overloads.push(AggregateOverload {
+                    udaf: Arc::new(udaf),
+                    ..overload.clone()
+                }),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(Arc::new(OverloadedUDAF::new(&self.name, overloads))))
+    }
+
+    /// Simplified by the overload matching the argument types, once they are known.
+    fn simplify(&self) -> Option<AggregateFunctionSimplification> {
+        if self.overloads.iter().all(|o| o.udaf.simplify().is_none()) {
+            return None;
+        }
+        let name = self.name.clone();
+        let overloads = self.overloads.clone();
+        Some(Box::new(move |function, info| {
+            let arg_types = function
+                .args
+                .iter()
+                .map(|a| info.get_data_type(a))
+                .collect::<Result<Vec<_>>>()?;
+            let (overload, _) = resolve_overload(&name, &overloads, &arg_types)?;
+            match overload.udaf.simplify() {
+                Some(simplify) => simplify(function, info),
+                None => Ok(Expr::AggregateFunction(function)),
+            }
+        }))
+    }
+
+    /// Identical if every overload is, and the dispatcher of the reversed overloads otherwise.
+    fn reverse_expr(&self) -> ReversedUDAF {
+        let mut reversed = false;
+        let mut overloads = vec![];
+        for overload in &self.overloads {
+            let udaf = match overload.udaf.inner().reverse_expr() {
+                ReversedUDAF::Identical => Arc::clone(&overload.udaf),
+                ReversedUDAF::Reversed(udaf) => {
+                    reversed = true;
+                    udaf
+                }
+                ReversedUDAF::NotSupported => return ReversedUDAF::NotSupported,
+            };
+            overloads.push(AggregateOverload {
+                udaf,
+                ..overload.clone()
+            });
+        }
+        if !reversed {
+            return ReversedUDAF::Identical;
+        }
+        let name = overloads[0].udaf.name().to_string();
+        ReversedUDAF::Reversed(Arc::new(AggregateUDF::new_from_impl(OverloadedUDAF::new(
+            &name, overloads,
+        ))))
+    }
+
+    fn is_descending(&self) -> Option<bool> {
+        self.uniform(|udaf| udaf.is_descending()).flatten()
+    }
+
+    /// The value of the first overload that can tell it from the statistics, with the type of the
+    /// planned result.
+    fn value_from_stats(&self, statistics_args: &StatisticsArgs) -> Option<ScalarValue> {
+        self.overloads.iter().find_map(|o| {
+            o.udaf
+                .value_from_stats(statistics_args)
+                .filter(|value| &value.data_type() == statistics_args.return_type)
+        })
+    }
+
+    /// The value over no rows of the first overload for which it is not NULL, e.g. 0 for
+    /// `count_if`: the overloads of a function agree on it, but for its type.
+    fn default_value(&self, data_type: &DataType) -> Result<ScalarValue> {
+        for overload in &self.overloads {
+            match overload.udaf.default_value(data_type) {
+                Ok(value) if !value.is_null() => return Ok(value),
+                _ => {}
+            }
+        }
+        ScalarValue::try_from(data_type)
+    }
+
+    fn set_monotonicity(&self, data_type: &DataType) -> SetMonotonicity {
+        self.uniform(|udaf| udaf.inner().set_monotonicity(data_type))
+            .unwrap_or(SetMonotonicity::NotMonotonic)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.documentation.as_ref()
+    }
+}
@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reports how much of a dialect's `functions.sdf.yml` is implemented, per section.
+//!
+//! Usage: `sql-functions-coverage [--dialect trino|redshift|snowflake] [--format text|json|markdown] [--details]`
+
+use sql_functions::coverage::{CoverageReport, SectionCoverage};
+use sql_functions::Dialect;
+use std::fmt::Write;
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "Usage: sql-functions-coverage [--dialect trino|redshift|snowflake] [--format text|json|markdown] [--details]";
+
+enum Format {
+    Text,
+    Json,
+    Markdown,
+}
+
+fn main() -> ExitCode {
+    let mut dialect = Dialect::Trino;
+    let mut format = Format::Text;
+    let mut details = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--details" => details = true,
+            "--dialect" | "--format" => match (arg.as_str(), args.next().as_deref()) {
+                ("--dialect", Some("trino")) => dialect = Dialect::Trino,
+                ("--dialect", Some("redshift")) => dialect = Dialect::Redshift,
+                ("--dialect", Some("snowflake")) => dialect = Dialect::Snowflake,
+                ("--format", Some("text")) => format = Format::Text,
+                ("--format", Some("json")) => format = Format::Json,
+                ("--format", Some("markdown")) => format = Format::Markdown,
+                _ => {
+                    eprintln!("{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let report = match CoverageReport::new(dialect) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = match format {
+        Format::Text => text(&report, details),
+        Format::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        Format::Markdown => markdown(&report, details),
+    };
+    println!("{output}");
+    ExitCode::SUCCESS
+}
+
+fn text(report: &CoverageReport, details: bool) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Coverage of {} functions", report.dialect);
+    let _ = writeln!(
+        out,
+        "{:<16} {:>6} {:>12} {:>8} {:>6} {:>8} {:>8}",
+        "section", "total", "implemented", "partial", "stub", "missing", "%"
+    );
+    for section in report.sections.iter().chain([&report.total]) {
+        let _ = writeln!(
+            out,
+            "{:<16} {:>6} {:>12} {:>8} {:>6} {:>8} {:>7.1}%",
+            section.section,
+            section.total,
+            section.implemented,
+            section.partial,
+            section.stub,
+            section.missing,
+            section.implemented_percent()
+        );
+    }
+    if details {
+        let _ = writeln!(out);
+        for f in &report.functions {
+            let status = f
+                .status
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "missing".to_string());
+            let _ = writeln!(out, "{:<16} {:<60} {status}", f.section, f.overload);
+        }
+        for f in &report.undefined {
+            let _ = writeln!(out, "{:<16} {:<60} undefined", "-", f.overload);
+        }
+    }
+    out
+}
+
+fn markdown_row(section: &SectionCoverage) -> String {
+    format!(
+        "| {} | {} | {} | {} | {} | {} | {:.1}% |",
+        section.section,
+        section.total,
+        section.implemented,
+        section.partial,
+        section.stub,
+        section.missing,
+        section.implemented_percent()
+    )
+}
+
+fn markdown(report: &CoverageReport, details: bool) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Coverage of {} functions\n", report.dialect);
+    let _ = writeln!(
+        out,
+        "| Section | Total | Implemented | Partial | Stub | Missing | Implemented % |"
+    );
+    let _ = writeln!(out, "|---|---:|---:|---:|---:|---:|---:|");
+    for section in &report.sections {
+        let _ = writeln!(out, "{}", markdown_row(section));
+    }
+    let _ = writeln!(
+        out,
+        "{}",
+        markdown_row(&report.total).replace("total", "**total**")
+    );
+    if details {
+        let _ = writeln!(out, "\n| Section | Overload | Status |");
+        let _ = writeln!(out, "|---|---|---|");
+        for f in &report.functions {
+            let status = f
+                .status
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "missing".to_string());
+            let _ = writeln!(out, "| {} | `{}` | {status} |", f.section, f.overload);
+        }
+        for f in &report.undefined {
+            let _ = writeln!(out, "| - | `{}` | undefined |", f.overload);
+        }
+    }
+    out
+}
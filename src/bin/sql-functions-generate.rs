@@ -0,0 +1,1176 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generates the UDFs of a dialect from its `assets/<dialect>/functions.sdf.yml`.
+//!
+//! Usage: `sql-functions-generate [--dialect trino|redshift|snowflake] [--root <crate dir>] [--check]`
+//!
+//! For every scalar function `name`, `src/<dialect>/<name>_impl.rs` holds one `ScalarUDFImpl`
+//! per overload. Everything below the "Generated template below this line" marker is
+//! regenerated, while the hand-written `<overload>_invoke`, `<overload>_return_type` and
+//! `<overload>_simplify` functions above it are preserved. Stubs of these functions are added
+//! for new overloads, and files are created for new functions.
+//! An overload whose return type depends on its literal arguments (e.g. the type of a cast)
+//! also defines `<overload>_return_type_from_args`, and one whose values depend on its planned
+//! return type (e.g. its time zone) defines `<overload>_invoke_with_args` instead of
+//! `<overload>_invoke`.
+//!
+//! Aggregate functions are generated the same way, as one `AggregateUDFImpl` per overload whose
+//! hand-written functions are `<overload>_accumulator`, `<overload>_return_type` and
+//! `<overload>_state_fields`, and optionally `<overload>_default_value` if the value of the
+//! function over no rows is not NULL (e.g. 0 for `count_if`).
+//!
+//! Window functions are generated as one `WindowUDFImpl` per overload, whose hand-written
+//! functions are `<overload>_partition_evaluator` and `<overload>_return_type`, and optionally
+//! `<overload>_reverse_expr` if the function can be evaluated over the reversed window.
+//!
+//! Table functions are generated as one `TableFunctionImpl` per overload, whose hand-written
+//! `<overload>_call` function creates the table of a call from the values of its arguments.
+//!
+//! Only the window and table functions of the dialects other than Trino are generated so far,
+//! see `generated_kinds`, as well as those of their scalar functions that already have a
+//! hand-written `src/<dialect>/<name>_impl.rs`. Creating that file with the hand-written
+//! functions of an overload is how a scalar function of these dialects gets generated.
+//!
+//! `src/<dialect>/mod.rs`, which declares and exports all the UDFs, as well as the other,
+//! hand-written modules of `src/<dialect>/`, is regenerated entirely.
+//!
+//! The generated code is formatted with `rustfmt`. With `--check`, nothing is written, and
+//! the generator fails if any file is not up to date.
+
+use arrow::datatypes::DataType;
+use sql_functions::sdf::{parse_functions, SdfFunction};
+use sql_functions::type_signature::SdfType;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+const USAGE: &str =
+    "Usage: sql-functions-generate [--dialect trino|redshift|snowflake] [--root <dir>] [--check]";
+
+const LICENSE: &str = "\
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// \"License\"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// \"AS IS\" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+";
+
+const MARKER: &str = "\
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+";
+
+const IMPL_HEADER: &str = "\
+#![allow(non_camel_case_types)]
+use arrow::datatypes::DataType;
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use std::any::Any;
+";
+
+const AGGREGATE_IMPL_HEADER: &str = "\
+#![allow(non_camel_case_types)]
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use std::any::Any;
+";
+
+const WINDOW_IMPL_HEADER: &str = "\
+#![allow(non_camel_case_types)]
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+use std::any::Any;
+";
+
+const TABLE_IMPL_HEADER: &str = "\
+#![allow(non_camel_case_types)]
+use datafusion::catalog::{TableFunctionImpl, TableProvider};
+use datafusion::common::{Result, ScalarValue};
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::Expr;
+use std::sync::Arc;
+";
+
+/// The precisions of the variants of a parameter of a variable precision, one per time unit.
+const PRECISIONS: &[&str] = &["0", "3", "6", "9"];
+
+/// The Arrow type of a parameter that has a single representation.
+fn exact_type(datatype: &str) -> Option<DataType> {
+    SdfType::parse(datatype).ok()?.exact_data_type()
+}
+
+/// The Arrow types of a parameter of a variable precision such as `timestamp(p)`, one per
+/// precision of `PRECISIONS`.
+fn precision_types(datatype: &str) -> Option<Vec<DataType>> {
+    let SdfType::Parametric(name, params) = SdfType::parse(datatype).ok()? else {
+        return None;
+    };
+    if !matches!(params.as_slice(), [p] if p.parse::<u32>().is_err()) {
+        return None;
+    }
+    PRECISIONS
+        .iter()
+        .map(|p| SdfType::Parametric(name.clone(), vec![p.to_string()]).exact_data_type())
+        .collect()
+}
+
+/// The Rust expression of `dt` in the generated code, which imports `DataType`.
+fn data_type_expr(dt: &DataType) -> String {
+    match dt {
+        DataType::Timestamp(unit, None) => {
+            format!("DataType::Timestamp(arrow::datatypes::TimeUnit::{unit:?}, None)")
+        }
+        DataType::Time32(unit) => format!("DataType::Time32(arrow::datatypes::TimeUnit::{unit:?})"),
+        DataType::Time64(unit) => format!("DataType::Time64(arrow::datatypes::TimeUnit::{unit:?})"),
+        DataType::Interval(unit) => {
+            format!("DataType::Interval(arrow::datatypes::IntervalUnit::{unit:?})")
+        }
+        dt => format!("DataType::{dt:?}"),
+    }
+}
+
+fn datatypes(function: &SdfFunction) -> Vec<&str> {
+    function
+        .parameters
+        .iter()
+        .map(|p| p.datatype.as_str())
+        .collect()
+}
+
+/// Fail if a parameter or the result of `function` is of a type without an Arrow type, rather
+/// than generating a function that cannot be called.
+fn check_types(function: &SdfFunction) -> Result<(), String> {
+    let datatypes = function
+        .parameters
+        .iter()
+        .chain(&function.optional_parameters)
+        .map(|p| p.datatype.as_str())
+        // The columns of a table function are those of the tables it creates
+        .chain(
+            function
+                .returns
+                .as_ref()
+                .filter(|_| Kind::of(function) != Some(Kind::Table))
+                .map(|r| r.datatype.as_str()),
+        );
+    let mut unknown = vec![];
+    for datatype in datatypes {
+        let parsed = SdfType::parse(datatype)
+            .map_err(|e| format!("{}: {}", function.overload_name(), e.strip_backtrace()))?;
+        unknown.extend(parsed.unknown_names().into_iter().map(str::to_string));
+    }
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: unknown types {}",
+            function.overload_name(),
+            unknown.join(", ")
+        ))
+    }
+}
+
+/// Whether the parameters need the coercion rules of `crate::type_signature`, rather than
+/// a signature DataFusion can check by itself.
+fn is_user_defined(function: &SdfFunction) -> bool {
+    let datatypes = datatypes(function);
+    match (datatypes.first(), &function.variadic) {
+        (None, _) => false,
+        (Some(first), Some(_)) => exact_type(first).is_none(),
+        (Some(_), None) => !datatypes
+            .iter()
+            .all(|dt| exact_type(dt).is_some() || precision_types(dt).is_some()),
+    }
+}
+
+fn signature(function: &SdfFunction) -> String {
+    let datatypes = datatypes(function);
+    if datatypes.is_empty() {
+        return "Signature::nullary(volatility)".to_string();
+    }
+    if is_user_defined(function) {
+        return "Signature::user_defined(volatility)".to_string();
+    }
+    let exact = |dt: &str| {
+        exact_type(dt)
+            .map(|dt| data_type_expr(&dt))
+            .unwrap_or_default()
+    };
+    if function.variadic.is_some() {
+        return format!(
+            "Signature::variadic(vec![{}], volatility)",
+            exact(datatypes[0])
+        );
+    }
+    if datatypes.iter().any(|dt| precision_types(dt).is_some()) {
+        let variants = (0..PRECISIONS.len())
+            .map(|i| {
+                let types = datatypes
+                    .iter()
+                    .map(|dt| match precision_types(dt) {
+                        Some(types) => data_type_expr(&types[i]),
+                        None => exact(dt),
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "datafusion::logical_expr::TypeSignature::Exact(vec![{}])",
+                    types.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        return format!(
+            "Signature::one_of(vec![{}], volatility)",
+            variants.join(", ")
+        );
+    }
+    let types = datatypes.iter().map(|dt| exact(dt)).collect::<Vec<_>>();
+    format!("Signature::exact(vec![{}], volatility)", types.join(", "))
+}
+
+fn quoted_datatypes(function: &SdfFunction) -> String {
+    datatypes(function)
+        .iter()
+        .map(|dt| format!("\"{dt}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The field of the UDF of `function` holding its parsed parameters, if its signature is user
+/// defined: they are parsed once, when the UDF is created, rather than by every `coerce_types`.
+fn parameters_field(function: &SdfFunction) -> &'static str {
+    if is_user_defined(function) {
+        "\n    parameters: crate::type_signature::FunctionSignature,"
+    } else {
+        ""
+    }
+}
+
+/// The initialization of the field of [`parameters_field`]. The generator has parsed the
+/// parameters already, see [`check_types`].
+fn parameters(function: &SdfFunction) -> String {
+    if !is_user_defined(function) {
+        return String::new();
+    }
+    format!(
+        "
+            parameters: crate::type_signature::FunctionSignature::new(&[{}], {})
+                .expect(\"the parameters are checked when generated\"),",
+        quoted_datatypes(function),
+        function.variadic.is_some()
+    )
+}
+
+/// The `coerce_types` method of the UDF of `function`, if its signature is user defined.
+fn coerce_types(function: &SdfFunction) -> String {
+    if !is_user_defined(function) {
+        return String::new();
+    }
+    format!(
+        "
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {{
+        crate::type_signature::coerce_arguments(\"{}\", &self.parameters, arg_types)
+    }}
+",
+        function.name.to_lowercase(),
+    )
+}
+
+fn generated_udf(dialect: &str, function: &SdfFunction, handwritten: &str) -> String {
+    let n = function.overload_name();
+    let name = function.name.to_lowercase();
+    let coerce_types = coerce_types(function);
+    let invoke = if handwritten.contains(&format!("fn {n}_invoke_with_args(")) {
+        format!(
+            "    fn invoke_with_args(
+        &self,
+        args: datafusion::logical_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {{
+        {n}_invoke_with_args(args)
+    }}
+"
+        )
+    } else if function.parameters.is_empty() {
+        format!(
+            "    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {{
+        {n}_invoke_no_args(number_rows)
+    }}
+"
+        )
+    } else {
+        format!(
+            "    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {{
+        {n}_invoke(args)
+    }}
+"
+        )
+    };
+    let return_type_from_args = if handwritten.contains(&format!("fn {n}_return_type_from_args(")) {
+        format!(
+            "
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {{
+        {n}_return_type_from_args(args)
+    }}
+"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "
+#[derive(Debug)]
+pub(super) struct {n}Func {{
+    signature: Signature,{parameters_field}
+}}
+
+impl {n}Func {{
+    pub fn new(volatility: Volatility) -> Self {{
+        Self {{
+            signature: {signature},{parameters}
+        }}
+    }}
+}}
+
+impl ScalarUDFImpl for {n}Func {{
+    fn as_any(&self) -> &dyn Any {{
+        self
+    }}
+    fn name(&self) -> &str {{
+        \"{name}\"
+    }}
+
+    fn signature(&self) -> &Signature {{
+        &self.signature
+    }}
+{coerce_types}
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {{
+        {n}_return_type(arg_types)
+    }}
+{return_type_from_args}
+{invoke}
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {{
+        {n}_simplify(args, info)
+    }}
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {{
+        crate::documentation::documentation(crate::Dialect::{variant}, \"{n}\")
+    }}
+}}
+",
+        signature = signature(function),
+        parameters_field = parameters_field(function),
+        parameters = parameters(function),
+        variant = dialect_variant(dialect),
+    )
+}
+
+fn generated_udaf(dialect: &str, function: &SdfFunction, handwritten: &str) -> String {
+    let n = function.overload_name();
+    let name = function.name.to_lowercase();
+    let coerce_types = coerce_types(function);
+    let default_value = if handwritten.contains(&format!("fn {n}_default_value(")) {
+        format!(
+            "
+    fn default_value(&self, data_type: &DataType) -> Result<datafusion::common::ScalarValue> {{
+        {n}_default_value(data_type)
+    }}
+"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "
+#[derive(Debug)]
+pub(super) struct {n}Func {{
+    signature: Signature,{parameters_field}
+}}
+
+impl {n}Func {{
+    pub fn new(volatility: Volatility) -> Self {{
+        Self {{
+            signature: {signature},{parameters}
+        }}
+    }}
+}}
+
+impl AggregateUDFImpl for {n}Func {{
+    fn as_any(&self) -> &dyn Any {{
+        self
+    }}
+    fn name(&self) -> &str {{
+        \"{name}\"
+    }}
+
+    fn signature(&self) -> &Signature {{
+        &self.signature
+    }}
+{coerce_types}
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {{
+        {n}_return_type(arg_types)
+    }}
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {{
+        {n}_accumulator(acc_args)
+    }}
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {{
+        {n}_state_fields(args)
+    }}
+{default_value}
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {{
+        crate::documentation::documentation(crate::Dialect::{variant}, \"{n}\")
+    }}
+}}
+",
+        signature = signature(function),
+        parameters_field = parameters_field(function),
+        parameters = parameters(function),
+        variant = dialect_variant(dialect),
+    )
+}
+
+fn generated_udwf(dialect: &str, function: &SdfFunction, handwritten: &str) -> String {
+    let n = function.overload_name();
+    let name = function.name.to_lowercase();
+    let coerce_types = coerce_types(function);
+    let reverse_expr = if handwritten.contains(&format!("fn {n}_reverse_expr(")) {
+        format!(
+            "
+    fn reverse_expr(&self) -> datafusion::logical_expr::ReversedUDWF {{
+        {n}_reverse_expr()
+    }}
+"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "
+#[derive(Debug)]
+pub(super) struct {n}Func {{
+    signature: Signature,{parameters_field}
+}}
+
+impl {n}Func {{
+    pub fn new(volatility: Volatility) -> Self {{
+        Self {{
+            signature: {signature},{parameters}
+        }}
+    }}
+}}
+
+impl WindowUDFImpl for {n}Func {{
+    fn as_any(&self) -> &dyn Any {{
+        self
+    }}
+    fn name(&self) -> &str {{
+        \"{name}\"
+    }}
+
+    fn signature(&self) -> &Signature {{
+        &self.signature
+    }}
+{coerce_types}
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {{
+        let return_type = {n}_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }}
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {{
+        {n}_partition_evaluator(partition_evaluator_args)
+    }}
+{reverse_expr}
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {{
+        crate::documentation::documentation(crate::Dialect::{variant}, \"{n}\")
+    }}
+}}
+",
+        signature = signature(function),
+        parameters_field = parameters_field(function),
+        parameters = parameters(function),
+        variant = dialect_variant(dialect),
+    )
+}
+
+fn generated_udtf(function: &SdfFunction) -> String {
+    let n = function.overload_name();
+    let name = function.name.to_lowercase();
+    format!(
+        "
+#[derive(Debug, Default)]
+pub(super) struct {n}Func;
+
+impl {n}Func {{
+    pub const NAME: &'static str = \"{name}\";
+}}
+
+impl TableFunctionImpl for {n}Func {{
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {{
+        let args = crate::table::literal_arguments(Self::NAME, args)?;
+        {n}_call(&args)
+    }}
+}}
+"
+    )
+}
+
+/// The variant of `sql_functions::Dialect` of `dialect`, e.g. `Trino`.
+fn dialect_variant(dialect: &str) -> String {
+    let mut chars = dialect.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// The names of the arguments of the `expr_fn` function of an overload: the names of its
+/// parameters in the definitions, or `arg<i>` for the unnamed ones and those that are not valid
+/// Rust identifiers.
+fn argument_names(function: &SdfFunction) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for (i, param) in function.parameters.iter().enumerate() {
+        let name = param.name.as_deref().map(str::to_lowercase);
+        let name = match name {
+            Some(name)
+                if is_identifier(&name) && !name.starts_with("arg") && !names.contains(&name) =>
+            {
+                name
+            }
+            _ => format!("arg{}", i + 1),
+        };
+        names.push(name);
+    }
+    names
+}
+
+fn is_identifier(name: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true",
+        "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+        "gen", "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+    ];
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && !KEYWORDS.contains(&name)
+}
+
+/// The rustdoc of the `expr_fn` function of an overload: its description, signature and link
+/// to the dialect's documentation.
+fn rustdoc(function: &SdfFunction) -> String {
+    let mut doc = function
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("The {} function.", function.name));
+    let mut signature = format!("{}({}", function.name, datatypes(function).join(", "));
+    if function.variadic.is_some() {
+        signature.push_str(", ...");
+    }
+    signature.push(')');
+    if let Some(returns) = &function.returns {
+        signature.push_str(&format!(" -> {}", returns.datatype));
+    }
+    doc.push_str(&format!("\n\n`{signature}`"));
+    if let Some(cross_link) = &function.cross_link {
+        doc.push_str(&format!("\n\nSee <{cross_link}>"));
+    }
+    doc
+}
+
+/// Hand-written functions of a new overload, to be filled in.
+fn stubs(function: &SdfFunction) -> String {
+    let n = function.overload_name();
+    let not_implemented = "    Err(DataFusionError::NotImplemented(format!(
+        \"Not implemented {}:{}\",
+        file!(),
+        line!()
+    )))";
+    let invoke = if function.parameters.is_empty() {
+        format!("fn {n}_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {{")
+    } else {
+        format!("fn {n}_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {{")
+    };
+    format!(
+        "
+{invoke}
+{not_implemented}
+}}
+
+fn {n}_return_type(_arg_types: &[DataType]) -> Result<DataType> {{
+{not_implemented}
+}}
+
+fn {n}_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {{
+    Ok(ExprSimplifyResult::Original(args))
+}}
+"
+    )
+}
+
+/// Hand-written functions of a new aggregate overload, to be filled in. The state is a single
+/// field of the return type until the accumulator says otherwise.
+fn aggregate_stubs(function: &SdfFunction) -> String {
+    let n = function.overload_name();
+    let not_implemented = "    Err(DataFusionError::NotImplemented(format!(
+        \"Not implemented {}:{}\",
+        file!(),
+        line!()
+    )))";
+    format!(
+        "
+fn {n}_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {{
+{not_implemented}
+}}
+
+fn {n}_return_type(_arg_types: &[DataType]) -> Result<DataType> {{
+{not_implemented}
+}}
+
+fn {n}_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {{
+    Ok(vec![Field::new(
+        format_state_name(args.name, \"value\"),
+        args.return_type.clone(),
+        true,
+    )])
+}}
+"
+    )
+}
+
+/// Hand-written functions of a new window overload, to be filled in.
+fn window_stubs(function: &SdfFunction) -> String {
+    let n = function.overload_name();
+    let not_implemented = "    Err(DataFusionError::NotImplemented(format!(
+        \"Not implemented {}:{}\",
+        file!(),
+        line!()
+    )))";
+    format!(
+        "
+fn {n}_partition_evaluator(_args: PartitionEvaluatorArgs) -> Result<Box<dyn PartitionEvaluator>> {{
+{not_implemented}
+}}
+
+fn {n}_return_type(_arg_types: &[DataType]) -> Result<DataType> {{
+{not_implemented}
+}}
+"
+    )
+}
+
+/// Hand-written function of a new table overload, to be filled in.
+fn table_stubs(function: &SdfFunction) -> String {
+    let n = function.overload_name();
+    let not_implemented = "    Err(DataFusionError::NotImplemented(format!(
+        \"Not implemented {}:{}\",
+        file!(),
+        line!()
+    )))";
+    format!(
+        "
+fn {n}_call(_args: &[ScalarValue]) -> Result<Arc<dyn TableProvider>> {{
+{not_implemented}
+}}
+"
+    )
+}
+
+/// The kinds of functions that are generated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Scalar,
+    Aggregate,
+    Window,
+    Table,
+}
+
+impl Kind {
+    /// The kind of a function definition, if it is generated at all.
+    fn of(function: &SdfFunction) -> Option<Kind> {
+        match function.kind() {
+            "scalar" => Some(Kind::Scalar),
+            "aggregate" => Some(Kind::Aggregate),
+            "window" => Some(Kind::Window),
+            "table" => Some(Kind::Table),
+            _ => None,
+        }
+    }
+}
+
+/// The kinds of functions generated for `dialect`. Trino is the only dialect whose scalar and
+/// aggregate functions are generated, the others only provide the window and table functions
+/// that DataFusion lacks, and the scalar functions that are implemented by hand.
+fn generated_kinds(dialect: &str) -> &'static [Kind] {
+    match dialect {
+        "trino" => &[Kind::Scalar, Kind::Aggregate, Kind::Window, Kind::Table],
+        _ => &[Kind::Window, Kind::Table],
+    }
+}
+
+/// The content of `<name>_impl.rs`, given its current content if it exists.
+fn impl_file(
+    dialect: &str,
+    kind: Kind,
+    existing: Option<&str>,
+    overloads: &[SdfFunction],
+) -> String {
+    let header = match kind {
+        Kind::Scalar => IMPL_HEADER,
+        Kind::Aggregate => AGGREGATE_IMPL_HEADER,
+        Kind::Window => WINDOW_IMPL_HEADER,
+        Kind::Table => TABLE_IMPL_HEADER,
+    };
+    let mut head = match existing {
+        Some(existing) => match existing.find(MARKER.lines().next().unwrap_or(MARKER)) {
+            Some(pos) => existing[..pos].to_string(),
+            None => format!("{}\n", existing.trim_end()),
+        },
+        None => format!("{LICENSE}{header}"),
+    };
+    for function in overloads {
+        let n = function.overload_name();
+        let defined = [
+            format!("fn {n}_invoke("),
+            format!("fn {n}_invoke_no_args("),
+            format!("fn {n}_invoke_with_args("),
+            format!("fn {n}_accumulator("),
+            format!("fn {n}_partition_evaluator("),
+            format!("fn {n}_call("),
+        ]
+        .iter()
+        .any(|f| head.contains(f.as_str()));
+        if !defined {
+            let stubs = match kind {
+                Kind::Scalar => stubs(function),
+                Kind::Aggregate => aggregate_stubs(function),
+                Kind::Window => window_stubs(function),
+                Kind::Table => table_stubs(function),
+            };
+            head = format!("{}\n{}\n", head.trim_end(), stubs);
+        }
+    }
+    let mut content = head.clone();
+    content.push_str(MARKER);
+    for function in overloads {
+        content.push_str(&match kind {
+            Kind::Scalar => generated_udf(dialect, function, &head),
+            Kind::Aggregate => generated_udaf(dialect, function, &head),
+            Kind::Window => generated_udwf(dialect, function, &head),
+            Kind::Table => generated_udtf(function),
+        });
+    }
+    content
+}
+
+fn volatility_argument(function: &SdfFunction) -> &'static str {
+    match function.volatility.as_deref() {
+        Some("stable") => ", Stable",
+        Some("volatile") => ", Volatile",
+        _ => "",
+    }
+}
+
+/// The content of `mod.rs`, declaring and exporting all the UDFs, UDAFs, UDWFs and UDTFs, and
+/// declaring the hand-written `modules`.
+fn mod_file(
+    dialect: &str,
+    functions: &BTreeMap<String, Vec<SdfFunction>>,
+    aggregates: &BTreeMap<String, Vec<SdfFunction>>,
+    windows: &BTreeMap<String, Vec<SdfFunction>>,
+    tables: &BTreeMap<String, Vec<SdfFunction>>,
+    modules: &[String],
+) -> String {
+    let mut content = format!("{LICENSE}#![allow(non_snake_case)]\n");
+    let mut names = functions
+        .keys()
+        .chain(aggregates.keys())
+        .chain(windows.keys())
+        .chain(tables.keys())
+        .collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        content.push_str(&format!("mod {name}_impl;\n"));
+    }
+    if !modules.is_empty() {
+        content.push('\n');
+        for module in modules {
+            content.push_str(&format!("pub mod {module};\n"));
+        }
+    }
+    if !functions.is_empty() {
+        content.push_str("\n// create  UDFs\n");
+        for (name, overloads) in functions {
+            for function in overloads {
+                let n = function.overload_name();
+                content.push_str(&format!(
+                    "make_udf_function!({name}_impl::{n}Func, {}, {n}{});\n",
+                    n.to_uppercase(),
+                    volatility_argument(function)
+                ));
+            }
+            content.push('\n');
+        }
+    }
+    if !aggregates.is_empty() {
+        content.push_str("\n// create UDAFs\n");
+        for (name, overloads) in aggregates {
+            for function in overloads {
+                let n = function.overload_name();
+                content.push_str(&format!(
+                    "make_udaf_function!({name}_impl::{n}Func, {}, {n}{});\n",
+                    n.to_uppercase(),
+                    volatility_argument(function)
+                ));
+            }
+            content.push('\n');
+        }
+    }
+    if !windows.is_empty() {
+        content.push_str("\n// create UDWFs\n");
+        for (name, overloads) in windows {
+            for function in overloads {
+                let n = function.overload_name();
+                content.push_str(&format!(
+                    "make_udwf_function!({name}_impl::{n}Func, {}, {n}{});\n",
+                    n.to_uppercase(),
+                    volatility_argument(function)
+                ));
+            }
+            content.push('\n');
+        }
+    }
+    if !tables.is_empty() {
+        content.push_str("\n// create UDTFs\n");
+        for (name, overloads) in tables {
+            for function in overloads {
+                let n = function.overload_name();
+                content.push_str(&format!(
+                    "make_udtf_function!({name}_impl::{n}Func, {}, {n});\n",
+                    n.to_uppercase(),
+                ));
+            }
+            content.push('\n');
+        }
+    }
+    content.push_str(
+        "\n// Export the functions out of this package, both as expr_fn as well as a list of functions\n\
+         export_functions!(\n",
+    );
+    for overloads in functions.values() {
+        for function in overloads {
+            let args = argument_names(function).join(" ");
+            content.push_str(&format!(
+                "    ({dialect}, {}, {args}, {}[{}], {:?}),\n",
+                function.overload_name(),
+                variadic_argument(function),
+                quoted_datatypes(function),
+                rustdoc(function)
+            ));
+        }
+        content.push('\n');
+    }
+    content.push_str(");\n\n// Export the aggregate functions out of this package\nexport_aggregate_functions!(\n");
+    for overloads in aggregates.values() {
+        for function in overloads {
+            content.push_str(&format!(
+                "    ({dialect}, {}, {}[{}]),\n",
+                function.overload_name(),
+                variadic_argument(function),
+                quoted_datatypes(function),
+            ));
+        }
+        content.push('\n');
+    }
+    content.push_str(
+        ");\n\n// Export the window functions out of this package\nexport_window_functions!(\n",
+    );
+    for overloads in windows.values() {
+        for function in overloads {
+            content.push_str(&format!(
+                "    ({dialect}, {}, {}[{}]),\n",
+                function.overload_name(),
+                variadic_argument(function),
+                quoted_datatypes(function),
+            ));
+        }
+        content.push('\n');
+    }
+    content.push_str(
+        ");\n\n// Export the table functions out of this package\nexport_table_functions!(\n",
+    );
+    for overloads in tables.values() {
+        for function in overloads {
+            let optional = function
+                .optional_parameters
+                .iter()
+                .map(|p| format!("\"{}\"", p.datatype))
+                .collect::<Vec<_>>();
+            let names = function
+                .parameters
+                .iter()
+                .chain(&function.optional_parameters)
+                .map(|p| {
+                    format!(
+                        "\"{}\"",
+                        p.name.as_deref().unwrap_or_default().to_lowercase()
+                    )
+                })
+                .collect::<Vec<_>>();
+            content.push_str(&format!(
+                "    ({dialect}, {}, {}[{}], [{}], [{}]),\n",
+                function.overload_name(),
+                variadic_argument(function),
+                quoted_datatypes(function),
+                optional.join(", "),
+                names.join(", "),
+            ));
+        }
+        content.push('\n');
+    }
+    content.push_str(");\n");
+    content
+}
+
+fn variadic_argument(function: &SdfFunction) -> &'static str {
+    if function.variadic.is_some() {
+        "variadic "
+    } else {
+        ""
+    }
+}
+
+fn rustfmt(source: &str) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("cannot run rustfmt: {e}"))?;
+    child
+        .stdin
+        .take()
+        .ok_or("cannot write to rustfmt")?
+        .write_all(source.as_bytes())
+        .map_err(|e| format!("cannot write to rustfmt: {e}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("rustfmt failed: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|e| format!("rustfmt output: {e}"))
+}
+
+/// The names of the modules of `dir` other than `mod.rs` and the `_impl.rs` files, sorted.
+fn handwritten_modules(dir: &Path) -> Result<Vec<String>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("cannot read {}: {e}", dir.display()))?;
+    let mut modules = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("cannot read {}: {e}", dir.display()))?
+            .path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if let Some(module) = name.strip_suffix(".rs") {
+            if module != "mod" && !module.ends_with("_impl") {
+                modules.push(module.to_string());
+            }
+        }
+    }
+    modules.sort();
+    Ok(modules)
+}
+
+/// Generate all the files of `dialect` into `root`; returns the files that were (or, when
+/// checking, would be) changed.
+fn generate(root: &Path, dialect: &str, check: bool) -> Result<Vec<PathBuf>, String> {
+    let definitions = root.join("assets").join(dialect).join("functions.sdf.yml");
+    let yaml = fs::read_to_string(&definitions)
+        .map_err(|e| format!("cannot read {}: {e}", definitions.display()))?;
+    let kinds = generated_kinds(dialect);
+    let dir = root.join("src").join(dialect);
+    let mut functions: BTreeMap<String, Vec<SdfFunction>> = BTreeMap::new();
+    let mut aggregates: BTreeMap<String, Vec<SdfFunction>> = BTreeMap::new();
+    let mut windows: BTreeMap<String, Vec<SdfFunction>> = BTreeMap::new();
+    let mut tables: BTreeMap<String, Vec<SdfFunction>> = BTreeMap::new();
+    let mut errors = vec![];
+    for function in parse_functions(&yaml).map_err(|e| e.to_string())? {
+        let functions = match Kind::of(&function) {
+            Some(Kind::Scalar)
+                if !kinds.contains(&Kind::Scalar)
+                    && dir
+                        .join(format!("{}_impl.rs", function.name.to_lowercase()))
+                        .is_file() =>
+            {
+                &mut functions
+            }
+            Some(kind) if !kinds.contains(&kind) => continue,
+            Some(Kind::Scalar) => &mut functions,
+            Some(Kind::Aggregate) => &mut aggregates,
+            Some(Kind::Window) => &mut windows,
+            Some(Kind::Table) => &mut tables,
+            None => continue,
+        };
+        if let Err(e) = check_types(&function) {
+            errors.push(e);
+            continue;
+        }
+        functions
+            .entry(function.name.to_lowercase())
+            .or_default()
+            .push(function);
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+
+    if !check {
+        fs::create_dir_all(&dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    let mut files = vec![];
+    for (kind, functions) in [
+        (Kind::Scalar, &functions),
+        (Kind::Aggregate, &aggregates),
+        (Kind::Window, &windows),
+        (Kind::Table, &tables),
+    ] {
+        for (name, overloads) in functions {
+            let path = dir.join(format!("{name}_impl.rs"));
+            let existing = fs::read_to_string(&path).ok();
+            files.push((
+                path,
+                existing.clone(),
+                impl_file(dialect, kind, existing.as_deref(), overloads),
+            ));
+        }
+    }
+    let path = dir.join("mod.rs");
+    let existing = fs::read_to_string(&path).ok();
+    let modules = handwritten_modules(&dir)?;
+    files.push((
+        path,
+        existing,
+        mod_file(
+            dialect,
+            &functions,
+            &aggregates,
+            &windows,
+            &tables,
+            &modules,
+        ),
+    ));
+
+    let mut changed = vec![];
+    for (path, existing, content) in files {
+        let content = rustfmt(&content)?;
+        if existing.as_deref() == Some(content.as_str()) {
+            continue;
+        }
+        if !check {
+            fs::write(&path, &content)
+                .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        }
+        changed.push(path);
+    }
+    Ok(changed)
+}
+
+fn main() -> ExitCode {
+    let mut dialect = "trino".to_string();
+    let mut root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut check = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.len()) {
+            ("--check", _) => check = true,
+            ("--dialect", 1..) => dialect = args.next().unwrap_or_default(),
+            ("--root", 1..) => root = PathBuf::from(args.next().unwrap_or_default()),
+            ("--help", _) | ("-h", _) => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match generate(&root, &dialect, check) {
+        Ok(changed) if check && !changed.is_empty() => {
+            for path in changed {
+                eprintln!("{} is not up to date", path.display());
+            }
+            eprintln!("Run `cargo run --bin sql-functions-generate` to regenerate");
+            ExitCode::FAILURE
+        }
+        Ok(changed) => {
+            for path in changed {
+                println!("Generated {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Trino's calendar arithmetic on local date-times, as by `date_add`, `date_diff`, `date_trunc`
+//! and the intervals: the units `millisecond` to `year`, and the number of months, days and
+//! nanoseconds of an interval.
+//!
+//! As in Trino (and Joda-Time, which it uses), adding months keeps the day of the month, clamped to
+//! the end of the month (`2024-01-31` plus one month is `2024-02-29`), and the number of months
+//! between two date-times is the largest number of months that can be added to the first one
+//! without passing the second one.
+
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use datafusion::common::{exec_err, Result};
+
+/// A unit of `date_add`, `date_diff` and `date_trunc`, e.g. `'day'`, from the shortest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Unit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// The kind of the values a unit applies to, which accepts some of the units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Field {
+    Date,
+    Time,
+    Timestamp,
+}
+
+impl Field {
+    fn accepts(self, unit: Unit) -> bool {
+        match self {
+            Field::Date => unit >= Unit::Day,
+            Field::Time => unit <= Unit::Hour,
+            Field::Timestamp => true,
+        }
+    }
+
+    /// The name of the field in Trino's error messages.
+    fn name(self) -> &'static str {
+        match self {
+            Field::Date => "DATE",
+            Field::Time => "Time",
+            Field::Timestamp => "Timestamp",
+        }
+    }
+}
+
+impl Unit {
+    /// The unit named `unit`, case-insensitively, if `field` accepts it.
+    pub(crate) fn parse(unit: &str, field: Field) -> Result<Self> {
+        let parsed = match unit.to_ascii_lowercase().as_str() {
+            "millisecond" => Some(Unit::Millisecond),
+            "second" => Some(Unit::Second),
+            "minute" => Some(Unit::Minute),
+            "hour" => Some(Unit::Hour),
+            "day" => Some(Unit::Day),
+            "week" => Some(Unit::Week),
+            "month" => Some(Unit::Month),
+            "quarter" => Some(Unit::Quarter),
+            "year" => Some(Unit::Year),
+            _ => None,
+        };
+        match parsed.filter(|&parsed| field.accepts(parsed)) {
+            Some(parsed) => Ok(parsed),
+            None => exec_err!("'{unit}' is not a valid {} field", field.name()),
+        }
+    }
+
+    /// Whether the unit is shorter than a day. These units are added to the instants of timestamps
+    /// with a time zone, and the others to their local date-times.
+    pub(crate) fn is_time(self) -> bool {
+        self <= Unit::Hour
+    }
+
+    /// The length of the unit in nanoseconds, if fixed.
+    pub(crate) fn nanos(self) -> Option<i64> {
+        let millis = match self {
+            Unit::Millisecond => 1,
+            Unit::Second => 1_000,
+            Unit::Minute => 60_000,
+            Unit::Hour => 3_600_000,
+            Unit::Day => 86_400_000,
+            Unit::Week => 604_800_000,
+            Unit::Month | Unit::Quarter | Unit::Year => return None,
+        };
+        Some(millis * 1_000_000)
+    }
+
+    /// The length of the unit in months, if a number of months.
+    fn months(self) -> Option<i64> {
+        match self {
+            Unit::Month => Some(1),
+            Unit::Quarter => Some(3),
+            Unit::Year => Some(12),
+            _ => None,
+        }
+    }
+}
+
+/// `datetime` plus `value` `unit`s, if in range.
+pub(crate) fn add(datetime: &NaiveDateTime, unit: Unit, value: i64) -> Option<NaiveDateTime> {
+    let delta = match unit {
+        Unit::Millisecond => TimeDelta::try_milliseconds(value)?,
+        Unit::Second => TimeDelta::try_seconds(value)?,
+        Unit::Minute => TimeDelta::try_minutes(value)?,
+        Unit::Hour => TimeDelta::try_hours(value)?,
+        Unit::Day => TimeDelta::try_days(value)?,
+        Unit::Week => TimeDelta::try_weeks(value)?,
+        Unit::Month | Unit::Quarter | Unit::Year => {
+            return add_months(datetime, value.checked_mul(unit.months()?)?)
+        }
+    };
+    datetime.checked_add_signed(delta)
+}
+
+/// `datetime` plus `count` times an interval of `months`, `days` and `nanos`, added in this order,
+/// if in range.
+pub(crate) fn add_interval(
+    datetime: &NaiveDateTime,
+    (months, days, nanos): (i64, i64, i64),
+    count: i64,
+) -> Option<NaiveDateTime> {
+    let datetime = add_months(datetime, months.checked_mul(count)?)?;
+    let datetime = datetime.checked_add_signed(TimeDelta::try_days(days.checked_mul(count)?)?)?;
+    datetime.checked_add_signed(TimeDelta::nanoseconds(nanos.checked_mul(count)?))
+}
+
+/// `datetime` plus `months` months, on the same day of the month or on the last day of the month
+/// if it has fewer days, if in range.
+pub(crate) fn add_months(datetime: &NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let months = i32::try_from(months).ok()?;
+    let delta = Months::new(months.unsigned_abs());
+    match months >= 0 {
+        true => datetime.checked_add_months(delta),
+        false => datetime.checked_sub_months(delta),
+    }
+}
+
+/// The number of `unit`s from `start` to `end`, negative if `end` is before `start`, as by
+/// `date_diff`: the whole units, truncated towards zero.
+pub(crate) fn diff(start: &NaiveDateTime, end: &NaiveDateTime, unit: Unit) -> i64 {
+    match (unit.nanos(), unit.months()) {
+        (Some(nanos), _) => {
+            let delta = *end - *start;
+            // In i128, as the nanoseconds of the difference may overflow an i64
+            let delta_nanos =
+                i128::from(delta.num_seconds()) * 1_000_000_000 + i128::from(delta.subsec_nanos());
+            (delta_nanos / i128::from(nanos)) as i64
+        }
+        (None, Some(months)) => months_between(start, end) / months,
+        (None, None) => unreachable!("A unit has a length in nanoseconds or months"),
+    }
+}
+
+/// The number of whole months from `start` to `end`, negative if `end` is before `start`.
+fn months_between(start: &NaiveDateTime, end: &NaiveDateTime) -> i64 {
+    let months = i64::from(end.year() - start.year()) * 12 + i64::from(end.month())
+        - i64::from(start.month());
+    // One less if the day of the month and time of `end` are before those of `start`
+    match add_months(start, months) {
+        Some(moved) if months > 0 && moved > *end => months - 1,
+        Some(moved) if months < 0 && moved < *end => months + 1,
+        _ => months,
+    }
+}
+
+/// `datetime` truncated to `unit`: the start of its second, day, week (a Monday), quarter, ...
+pub(crate) fn truncate(datetime: &NaiveDateTime, unit: Unit) -> NaiveDateTime {
+    let date = datetime.date();
+    let month_start = |month: u32| {
+        NaiveDate::from_ymd_opt(date.year(), month, 1).expect("The first day of a month is valid")
+    };
+    let start = match unit {
+        Unit::Millisecond | Unit::Second | Unit::Minute | Unit::Hour => {
+            let nanos = unit.nanos().expect("A unit of time has a length");
+            let since_midnight = (datetime.time() - NaiveTime::MIN)
+                .num_nanoseconds()
+                .expect("A day fits in nanoseconds");
+            return date.and_time(NaiveTime::MIN)
+                + TimeDelta::nanoseconds(since_midnight - since_midnight % nanos);
+        }
+        Unit::Day => date,
+        Unit::Week => date - TimeDelta::days(date.weekday().num_days_from_monday().into()),
+        Unit::Month => month_start(date.month()),
+        Unit::Quarter => month_start(date.month0() / 3 * 3 + 1),
+        Unit::Year => month_start(1),
+    };
+    start.and_time(NaiveTime::MIN)
+}
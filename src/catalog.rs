@@ -0,0 +1,444 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Typed metadata of the functions of every dialect, e.g. for autocompletion, documentation
+//! or signature help.
+//!
+//! The catalog is built from the embedded `assets/<dialect>/functions.sdf.yml` files. The
+//! function lists exported from the engines (`trino/trino.csv`, `snowflake/snowflake.csv`)
+//! fill in the description and kind of the functions whose definition lacks them.
+
+use crate::sdf::{parse_functions, SdfFunction, SdfParameter};
+use crate::Asset;
+use datafusion::common::{exec_datafusion_err, exec_err, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The kind of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FunctionKind {
+    Scalar,
+    Aggregate,
+    Window,
+    Table,
+}
+
+impl FunctionKind {
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "scalar" => Some(FunctionKind::Scalar),
+            "aggregate" => Some(FunctionKind::Aggregate),
+            "window" => Some(FunctionKind::Window),
+            "table" => Some(FunctionKind::Table),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FunctionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FunctionKind::Scalar => write!(f, "scalar"),
+            FunctionKind::Aggregate => write!(f, "aggregate"),
+            FunctionKind::Window => write!(f, "window"),
+            FunctionKind::Table => write!(f, "table"),
+        }
+    }
+}
+
+/// How the last parameter of a variadic function repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VariadicMode {
+    /// Any number of arguments of the type of the last parameter.
+    Uniform,
+    /// Any number of arguments of any types.
+    Any,
+    /// Any number of arguments of different types.
+    NonUniform,
+    /// Pairs of arguments, e.g. the keys and values of `object_construct`.
+    EvenOdd,
+}
+
+impl VariadicMode {
+    fn parse(variadic: &str) -> Option<Self> {
+        match variadic {
+            "uniform" => Some(VariadicMode::Uniform),
+            "any" => Some(VariadicMode::Any),
+            "non-uniform" => Some(VariadicMode::NonUniform),
+            "even-odd" => Some(VariadicMode::EvenOdd),
+            _ => None,
+        }
+    }
+}
+
+/// A parameter of a function.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionParameter {
+    pub name: Option<String>,
+    /// The dialect's type of the parameter, e.g. `array(T)` or `decimal(p,s)`
+    pub datatype: String,
+    /// The parameter must be this literal, e.g. the precision of `current_timestamp(3)`
+    pub constant: Option<String>,
+}
+
+impl FunctionParameter {
+    fn new(param: &SdfParameter) -> Self {
+        let constant = param.constant.as_ref().map(|constant| match constant {
+            serde_yaml::Value::String(s) => s.clone(),
+            other => serde_yaml::to_string(other)
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        });
+        Self {
+            name: param.name.clone(),
+            datatype: param.datatype.clone(),
+            constant,
+        }
+    }
+}
+
+impl std::fmt::Display for FunctionParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.name, &self.constant) {
+            (_, Some(constant)) => write!(f, "{constant}"),
+            (Some(name), None) => write!(f, "{name} {}", self.datatype),
+            (None, None) => write!(f, "{}", self.datatype),
+        }
+    }
+}
+
+/// An example call of a function, with its result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionExample {
+    pub input: String,
+    pub output: Option<String>,
+}
+
+/// One overload of a function of a dialect.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionDefinition {
+    pub dialect: String,
+    /// The SQL name of the function, e.g. `regexp_like`
+    pub name: String,
+    /// The name of the generated overload, e.g. `regexp_like_varchar_joniregexp`
+    pub overload: String,
+    pub kind: FunctionKind,
+    pub parameters: Vec<FunctionParameter>,
+    /// Parameters that may be omitted, after the required ones
+    pub optional_parameters: Vec<FunctionParameter>,
+    pub variadic: Option<VariadicMode>,
+    /// The dialect's type of the result, e.g. `boolean`
+    pub return_type: Option<String>,
+    /// The section of the dialect's documentation, e.g. `math`
+    pub section: String,
+    pub description: Option<String>,
+    /// The URL of the dialect's documentation of the function
+    pub cross_link: Option<String>,
+    pub examples: Vec<FunctionExample>,
+}
+
+impl FunctionDefinition {
+    fn new(dialect: &str, function: &SdfFunction) -> Result<Self> {
+        let kind = FunctionKind::parse(function.kind()).ok_or_else(|| {
+            exec_datafusion_err!(
+                "Invalid kind {} of function {}",
+                function.kind(),
+                function.name
+            )
+        })?;
+        let variadic = match &function.variadic {
+            Some(variadic) => Some(VariadicMode::parse(variadic).ok_or_else(|| {
+                exec_datafusion_err!(
+                    "Invalid variadic mode {variadic} of function {}",
+                    function.name
+                )
+            })?),
+            None => None,
+        };
+        Ok(Self {
+            dialect: dialect.to_string(),
+            name: function.name.clone(),
+            overload: function.overload_name(),
+            kind,
+            parameters: function
+                .parameters
+                .iter()
+                .map(FunctionParameter::new)
+                .collect(),
+            optional_parameters: function
+                .optional_parameters
+                .iter()
+                .map(FunctionParameter::new)
+                .collect(),
+            variadic,
+            return_type: function.returns.as_ref().map(|r| r.datatype.clone()),
+            section: function.section().to_string(),
+            description: function
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|d| !d.is_empty())
+                .map(str::to_string),
+            cross_link: function.cross_link.clone(),
+            examples: function
+                .examples
+                .iter()
+                .map(|e| FunctionExample {
+                    input: e.input.trim().to_string(),
+                    output: e.output.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    /// The signature of the overload, e.g. `round(expr double[, scale bigint]) -> bigint` or
+    /// `concat(varchar, ...) -> varchar`, for signature help.
+    pub fn display_signature(&self) -> String {
+        let mut params = self
+            .parameters
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if self.variadic.is_some() {
+            params.push_str(", ...");
+        }
+        for (i, param) in self.optional_parameters.iter().enumerate() {
+            let separator = if i == 0 && self.parameters.is_empty() {
+                ""
+            } else {
+                ", "
+            };
+            params.push_str(&format!("[{separator}{param}"));
+        }
+        params.push_str(&"]".repeat(self.optional_parameters.len()));
+        match &self.return_type {
+            Some(return_type) => format!("{}({params}) -> {return_type}", self.name),
+            None => format!("{}({params})", self.name),
+        }
+    }
+}
+
+/// The function definitions of the dialects, indexed by dialect, name and section.
+#[derive(Debug, Clone, Default)]
+pub struct FunctionCatalog {
+    functions: Vec<FunctionDefinition>,
+    /// Indexes of the overloads of `(dialect, lowercased name)`, in definition order
+    by_name: BTreeMap<(String, String), Vec<usize>>,
+}
+
+impl FunctionCatalog {
+    /// The catalog of every dialect with embedded function definitions.
+    pub fn load() -> Result<Self> {
+        let dialects = Asset::iter()
+            .filter_map(|file| {
+                file.strip_suffix("/functions.sdf.yml")
+                    .map(|dialect| dialect.to_string())
+            })
+            .collect::<BTreeSet<_>>();
+        let mut catalog = Self::default();
+        for dialect in dialects {
+            catalog.add_dialect(&dialect)?;
+        }
+        Ok(catalog)
+    }
+
+    /// The catalog of `dialect`, e.g. `trino`.
+    pub fn load_dialect(dialect: &str) -> Result<Self> {
+        let mut catalog = Self::default();
+        catalog.add_dialect(dialect)?;
+        Ok(catalog)
+    }
+
+    fn add_dialect(&mut self, dialect: &str) -> Result<()> {
+        let yaml = load_asset(&format!("{dialect}/functions.sdf.yml"))?
+            .ok_or_else(|| exec_datafusion_err!("No function definitions for dialect {dialect}"))?;
+        let listed = listed_functions(dialect)?;
+        for function in parse_functions(&yaml)? {
+            let mut definition = FunctionDefinition::new(dialect, &function)?;
+            if let Some(listed) = listed.get(&definition.name.to_lowercase()) {
+                if definition.description.is_none() {
+                    definition.description = listed.description.clone();
+                }
+                if function.kind.is_none() {
+                    definition.kind = listed.kind;
+                }
+            }
+            self.by_name
+                .entry((dialect.to_string(), definition.name.to_lowercase()))
+                .or_default()
+                .push(self.functions.len());
+            self.functions.push(definition);
+        }
+        Ok(())
+    }
+
+    /// Every overload of every function, in definition order.
+    pub fn functions(&self) -> &[FunctionDefinition] {
+        &self.functions
+    }
+
+    /// The dialects of the catalog, sorted.
+    pub fn dialects(&self) -> Vec<&str> {
+        let mut dialects = self
+            .by_name
+            .keys()
+            .map(|(dialect, _)| dialect.as_str())
+            .collect::<Vec<_>>();
+        dialects.dedup();
+        dialects
+    }
+
+    /// Every overload of every function of `dialect`, in definition order.
+    pub fn dialect<'a>(
+        &'a self,
+        dialect: &'a str,
+    ) -> impl Iterator<Item = &'a FunctionDefinition> + 'a {
+        self.functions.iter().filter(move |f| f.dialect == dialect)
+    }
+
+    /// The sections of `dialect`, sorted.
+    pub fn sections(&self, dialect: &str) -> Vec<&str> {
+        self.functions
+            .iter()
+            .filter(|f| f.dialect == dialect)
+            .map(|f| f.section.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// The overloads of the functions of `section` of `dialect`, in definition order.
+    pub fn section<'a>(
+        &'a self,
+        dialect: &'a str,
+        section: &'a str,
+    ) -> impl Iterator<Item = &'a FunctionDefinition> + 'a {
+        self.dialect(dialect).filter(move |f| f.section == section)
+    }
+
+    /// The overloads of the function `name` (case-insensitive) of `dialect`, in definition order.
+    pub fn lookup(&self, dialect: &str, name: &str) -> Vec<&FunctionDefinition> {
+        self.by_name
+            .get(&(dialect.to_string(), name.to_lowercase()))
+            .map(|indexes| indexes.iter().map(|i| &self.functions[*i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// The lowercased names of the functions of `dialect` starting with `prefix`
+    /// (case-insensitive), sorted, e.g. for autocompletion.
+    pub fn names_with_prefix(&self, dialect: &str, prefix: &str) -> Vec<&str> {
+        let prefix = prefix.to_lowercase();
+        self.by_name
+            .range((dialect.to_string(), prefix.clone())..)
+            .map(|((d, name), _)| (d, name))
+            .take_while(|(d, name)| *d == dialect && name.starts_with(&prefix))
+            .map(|(_, name)| name.as_str())
+            .collect()
+    }
+}
+
+/// A function in the list of functions exported from the dialect's engine.
+struct ListedFunction {
+    kind: FunctionKind,
+    description: Option<String>,
+}
+
+fn load_asset(filename: &str) -> Result<Option<String>> {
+    Asset::load_file(filename)
+        .map(|file| {
+            String::from_utf8(file.data.into_owned())
+                .map_err(|e| exec_datafusion_err!("Invalid {filename}: {e}"))
+        })
+        .transpose()
+}
+
+/// The functions listed by `<dialect>/<dialect>.csv`, by lowercased name. When a name is listed
+/// with several kinds or descriptions, the first one is used.
+fn listed_functions(dialect: &str) -> Result<HashMap<String, ListedFunction>> {
+    let filename = format!("{dialect}/{dialect}.csv");
+    let Some(csv) = load_asset(&filename)? else {
+        return Ok(HashMap::new());
+    };
+    let rows = parse_csv(&csv).map_err(|e| exec_datafusion_err!("Invalid {filename}: {e}"))?;
+    let mut functions = HashMap::new();
+    for row in rows {
+        let field = |i: usize| row.get(i).map(String::as_str).unwrap_or_default();
+        let (name, kind, description) = match dialect {
+            // name, arguments, return type, kind, deterministic, description
+            "trino" => (
+                field(0),
+                FunctionKind::parse(field(3)).unwrap_or(FunctionKind::Scalar),
+                field(5),
+            ),
+            // created_on, name, schema_name, is_builtin, is_aggregate, ..., arguments,
+            // description, catalog_name, is_table_function, ...
+            "snowflake" if field(0) != "created_on" => {
+                let kind = match (field(4), field(11)) {
+                    ("Y", _) => FunctionKind::Aggregate,
+                    (_, "Y") => FunctionKind::Table,
+                    _ => FunctionKind::Scalar,
+                };
+                (field(1), kind, field(9))
+            }
+            _ => continue,
+        };
+        let description = Some(description.trim())
+            .filter(|d| !d.is_empty())
+            .map(str::to_string);
+        functions
+            .entry(name.to_lowercase())
+            .or_insert(ListedFunction { kind, description });
+    }
+    Ok(functions)
+}
+
+/// Parse a CSV file with optionally double-quoted fields (`""` being an escaped quote).
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if quoted {
+        return exec_err!("unterminated quoted field");
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
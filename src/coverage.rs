@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Implementation status of the dialect functions.
+//!
+//! The status of each overload is determined at build time from its hand-written
+//! functions, see `build.rs`. [`CoverageReport`] compares the generated overloads with the
+//! function definitions of the dialect, see the `sql-functions-coverage` binary.
+
+use crate::overload::Overload;
+use crate::registry::Dialect;
+use crate::sdf::load_functions;
+use datafusion::common::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// How far an overload of a dialect function is implemented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImplementationStatus {
+    /// Works for all arguments.
+    Implemented,
+    /// Works for some arguments, or some of its code paths are not implemented yet.
+    Partial,
+    /// Always fails with a "not implemented" error.
+    Stub,
+}
+
+impl ImplementationStatus {
+    /// Whether the overload can be called at all.
+    pub fn is_usable(&self) -> bool {
+        *self != ImplementationStatus::Stub
+    }
+}
+
+impl std::fmt::Display for ImplementationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImplementationStatus::Implemented => write!(f, "implemented"),
+            ImplementationStatus::Partial => write!(f, "partial"),
+            ImplementationStatus::Stub => write!(f, "stub"),
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/implementation_status.rs"));
+
+/// The implementation status of the overload `overload` (e.g. `abs_tinyint`) of `dialect`.
+pub fn implementation_status(dialect: &str, overload: &str) -> ImplementationStatus {
+    IMPLEMENTATION_STATUS
+        .binary_search_by(|(d, o, _)| (*d, *o).cmp(&(dialect, overload)))
+        .map(|i| IMPLEMENTATION_STATUS[i].2)
+        .unwrap_or(ImplementationStatus::Stub)
+}
+
+/// The implementation status of one overload of a dialect function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FunctionStatus {
+    /// The SQL name of the function, e.g. `abs`
+    pub name: String,
+    /// The generated name of the overload, e.g. `abs_tinyint`
+    pub overload: String,
+    /// The declared signature of the overload, e.g. `abs(tinyint)`
+    pub signature: String,
+    pub status: ImplementationStatus,
+}
+
+/// The implementation status of each of `overloads`, of any kind.
+pub fn coverage<O: Overload>(overloads: impl IntoIterator<Item = O>) -> Vec<FunctionStatus> {
+    overloads
+        .into_iter()
+        .map(|o| FunctionStatus {
+            name: o.function_name().to_string(),
+            overload: o.name().to_string(),
+            signature: o.signature().display_call(o.function_name()),
+            status: o.status(),
+        })
+        .collect()
+}
+
+/// Implementation status of one overload defined in the dialect's `functions.sdf.yml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DefinedFunction {
+    pub name: String,
+    pub overload: String,
+    /// `scalar`, `aggregate`, `window` or `table`
+    pub kind: String,
+    pub section: String,
+    /// `None` if no UDF is generated for the overload
+    pub status: Option<ImplementationStatus>,
+}
+
+/// Counts of the overloads of one section of the function definitions, by status.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SectionCoverage {
+    pub section: String,
+    pub total: usize,
+    pub implemented: usize,
+    pub partial: usize,
+    pub stub: usize,
+    /// Defined, but not generated
+    pub missing: usize,
+}
+
+impl SectionCoverage {
+    fn add(&mut self, status: Option<ImplementationStatus>) {
+        self.total += 1;
+        match status {
+            Some(ImplementationStatus::Implemented) => self.implemented += 1,
+            Some(ImplementationStatus::Partial) => self.partial += 1,
+            Some(ImplementationStatus::Stub) => self.stub += 1,
+            None => self.missing += 1,
+        }
+    }
+
+    /// Percentage of the overloads that are implemented.
+    pub fn implemented_percent(&self) -> f64 {
+        percent(self.implemented, self.total)
+    }
+
+    /// Percentage of the overloads that are at least partially implemented.
+    pub fn usable_percent(&self) -> f64 {
+        percent(self.implemented + self.partial, self.total)
+    }
+}
+
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        100.0 * count as f64 / total as f64
+    }
+}
+
+/// The generated overloads of a dialect compared with its function definitions.
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageReport {
+    pub dialect: String,
+    /// Every defined overload, in definition order
+    pub functions: Vec<DefinedFunction>,
+    /// Per section counts, sorted by section
+    pub sections: Vec<SectionCoverage>,
+    /// Counts over all sections
+    pub total: SectionCoverage,
+    /// Generated overloads without a definition
+    pub undefined: Vec<FunctionStatus>,
+}
+
+impl CoverageReport {
+    pub fn new(dialect: Dialect) -> Result<Self> {
+        let definitions = load_functions(dialect.name())?;
+        let mut generated: HashMap<String, FunctionStatus> = coverage(dialect.overloads())
+            .into_iter()
+            .chain(coverage(dialect.aggregate_overloads()))
+            .chain(coverage(dialect.window_overloads()))
+            .chain(coverage(dialect.table_overloads()))
+            .map(|s| (s.overload.clone(), s))
+            .collect();
+
+        let mut functions = vec![];
+        let mut sections: BTreeMap<String, SectionCoverage> = BTreeMap::new();
+        let mut total = SectionCoverage {
+            section: "total".to_string(),
+            ..Default::default()
+        };
+        for definition in definitions {
+            let overload = definition.overload_name();
+            let status = generated.remove(&overload).map(|s| s.status);
+            let section = definition.section().to_string();
+            sections
+                .entry(section.clone())
+                .or_insert_with(|| SectionCoverage {
+                    section: section.clone(),
+                    ..Default::default()
+                })
+                .add(status);
+            total.add(status);
+            functions.push(DefinedFunction {
+                name: definition.name.to_lowercase(),
+                overload,
+                kind: definition.kind().to_string(),
+                section,
+                status,
+            });
+        }
+        let mut undefined = generated.into_values().collect::<Vec<_>>();
+        undefined.sort_by(|a, b| a.overload.cmp(&b.overload));
+
+        Ok(Self {
+            dialect: dialect.name().to_string(),
+            functions,
+            sections: sections.into_values().collect(),
+            total,
+            undefined,
+        })
+    }
+}
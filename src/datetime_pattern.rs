@@ -0,0 +1,888 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Date-time patterns in Trino's two pattern languages, parsed into one list of [`Item`]s with
+//! which timestamps are formatted and parsed:
+//! - the Joda-Time patterns of `format_datetime` and `parse_datetime`, e.g.
+//!   `yyyy-MM-dd HH:mm:ss.SSS ZZZ`,
+//! - the MySQL format strings of `date_format` and `date_parse`, e.g. `%Y-%m-%d %H:%i:%s`.
+//!
+//! Trino builds Joda-Time formatters for both, so both print and parse as Joda-Time does: numbers
+//! are printed with at least as many digits as the pattern letters and parsed from as many digits
+//! as the field can have, names are parsed ignoring case, and the fields missing from a parsed
+//! text are the ones of `1970-01-01 00:00:00.000`. The parsed fields are set from the largest
+//! (the year) to the smallest (the millisecond), each in the range allowed by the larger ones, so
+//! that `2023-02-29` is rejected with Joda-Time's message. Timestamps have a millisecond
+//! precision, as in Joda-Time: the fractions of the second are truncated.
+
+use arrow::array::timezone::Tz;
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, Offset, Timelike, Utc,
+    Weekday,
+};
+use datafusion::common::{exec_datafusion_err, exec_err, DataFusionError, Result};
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::timezone::{from_local, zone_abbreviation, UTC};
+
+/// The two-digit years of the MySQL `%y` are between 1970 and 2069.
+const MYSQL_PIVOT_YEAR: i32 = 2020;
+
+const ERAS: [&str; 2] = ["BC", "AD"];
+const HALFDAYS: [&str; 2] = ["AM", "PM"];
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// The zone names parsed by Joda-Time's `z` and `zzzz`.
+const ZONE_NAMES: [(&str, &str); 11] = [
+    ("UT", "UTC"),
+    ("UTC", "UTC"),
+    ("GMT", "UTC"),
+    ("EST", "America/New_York"),
+    ("EDT", "America/New_York"),
+    ("CST", "America/Chicago"),
+    ("CDT", "America/Chicago"),
+    ("MST", "America/Denver"),
+    ("MDT", "America/Denver"),
+    ("PST", "America/Los_Angeles"),
+    ("PDT", "America/Los_Angeles"),
+];
+
+/// A field of a date-time, named as in Joda-Time. Parsed fields are set in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Field {
+    /// 0 for BC, 1 for AD
+    Era,
+    CenturyOfEra,
+    YearOfEra,
+    Year,
+    /// The year of the ISO week
+    Weekyear,
+    MonthOfYear,
+    /// The ISO week
+    WeekOfWeekyear,
+    DayOfYear,
+    DayOfMonth,
+    /// 1 for Monday to 7 for Sunday
+    DayOfWeek,
+    /// 0 for AM, 1 for PM
+    HalfdayOfDay,
+    /// 0 to 23
+    HourOfDay,
+    /// 1 to 24
+    ClockhourOfDay,
+    /// 0 to 11
+    HourOfHalfday,
+    /// 1 to 12
+    ClockhourOfHalfday,
+    MinuteOfHour,
+    SecondOfMinute,
+    MillisOfSecond,
+}
+
+/// An element of a pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Item {
+    /// Text printed as is, and parsed ignoring case
+    Literal(String),
+    /// A field printed with at least `min_digits` digits, and parsed from at most `max_digits`
+    /// digits, after a sign if `signed`
+    Number {
+        field: Field,
+        min_digits: usize,
+        max_digits: usize,
+        signed: bool,
+    },
+    /// The last two digits of the year or weekyear `field`, parsed as the year of the century
+    /// starting 50 years before `pivot`. A `lenient` parse reads any other number as the year.
+    TwoDigitYear {
+        field: Field,
+        pivot: i32,
+        lenient: bool,
+    },
+    /// The fraction of the second, printed with `min_digits` to `max_digits` digits
+    Fraction {
+        min_digits: usize,
+        max_digits: usize,
+    },
+    /// The English name of the era, month, day of the week or half of the day `field`
+    Text { field: Field, short: bool },
+    /// The offset from UTC, e.g. `-0800`, or `-08:00` with `colon`
+    Offset { colon: bool },
+    /// The time zone, e.g. `America/Los_Angeles`
+    ZoneId,
+    /// The name of the time zone, e.g. `UTC`
+    ZoneName { short: bool },
+}
+
+/// A pattern of `format_datetime` and `parse_datetime`, or of `date_format` and `date_parse`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DateTimePattern {
+    items: Vec<Item>,
+}
+
+impl DateTimePattern {
+    /// The Joda-Time pattern `pattern`, e.g. `yyyy-MM-dd'T'HH:mm:ss`.
+    pub(crate) fn joda(pattern: &str) -> Result<Self> {
+        if pattern.is_empty() {
+            return exec_err!("Invalid pattern specification");
+        }
+        let tokens = joda_tokens(pattern);
+        let mut items = vec![];
+        for (i, token) in tokens.iter().enumerate() {
+            let (letter, len) = match token {
+                JodaToken::Literal(text) => {
+                    push_literal(&mut items, text);
+                    continue;
+                }
+                JodaToken::Letter(letter, len) => (*letter, *len),
+            };
+            let next_is_numeric = tokens.get(i + 1).is_some_and(JodaToken::is_numeric);
+            let item = match letter {
+                'G' => text(Field::Era, false),
+                'C' => number(Field::CenturyOfEra, len, len),
+                'x' | 'y' | 'Y' => {
+                    let field = match letter {
+                        'x' => Field::Weekyear,
+                        'y' => Field::Year,
+                        _ => Field::YearOfEra,
+                    };
+                    if len == 2 {
+                        Item::TwoDigitYear {
+                            field,
+                            pivot: Utc::now().year() - 30,
+                            lenient: !next_is_numeric,
+                        }
+                    } else {
+                        let max_digits = if next_is_numeric { len } else { 9 };
+                        Item::Number {
+                            field,
+                            min_digits: len,
+                            max_digits: max_digits.max(len),
+                            signed: field != Field::YearOfEra,
+                        }
+                    }
+                }
+                'M' if len >= 3 => text(Field::MonthOfYear, len == 3),
+                'M' => number(Field::MonthOfYear, len, 2),
+                'w' => number(Field::WeekOfWeekyear, len, 2),
+                'D' => number(Field::DayOfYear, len, 3),
+                'd' => number(Field::DayOfMonth, len, 2),
+                'e' => number(Field::DayOfWeek, len, 1),
+                'E' => text(Field::DayOfWeek, len < 4),
+                'a' => text(Field::HalfdayOfDay, false),
+                'H' => number(Field::HourOfDay, len, 2),
+                'k' => number(Field::ClockhourOfDay, len, 2),
+                'K' => number(Field::HourOfHalfday, len, 2),
+                'h' => number(Field::ClockhourOfHalfday, len, 2),
+                'm' => number(Field::MinuteOfHour, len, 2),
+                's' => number(Field::SecondOfMinute, len, 2),
+                'S' => Item::Fraction {
+                    min_digits: len,
+                    max_digits: len,
+                },
+                'z' => Item::ZoneName { short: len < 4 },
+                'Z' if len <= 2 => Item::Offset { colon: len == 2 },
+                'Z' => Item::ZoneId,
+                _ => {
+                    let component = letter.to_string().repeat(len);
+                    return exec_err!("Illegal pattern component: {component}");
+                }
+            };
+            items.push(item);
+        }
+        Ok(Self { items })
+    }
+
+    /// The MySQL format string `format`, e.g. `%Y-%m-%d %H:%i:%s`.
+    pub(crate) fn mysql(format: &str) -> Result<Self> {
+        let mut items = vec![];
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                push_literal(&mut items, c.encode_utf8(&mut [0; 4]));
+                continue;
+            }
+            // A trailing `%` is ignored
+            let Some(specifier) = chars.next() else {
+                break;
+            };
+            let hour12 = number(Field::ClockhourOfHalfday, 2, 2);
+            let hour24 = number(Field::HourOfDay, 2, 2);
+            let minute = number(Field::MinuteOfHour, 2, 2);
+            let second = number(Field::SecondOfMinute, 2, 2);
+            let colon = || Item::Literal(":".to_string());
+            match specifier {
+                'a' => items.push(text(Field::DayOfWeek, true)),
+                'b' => items.push(text(Field::MonthOfYear, true)),
+                'c' => items.push(number(Field::MonthOfYear, 1, 2)),
+                'd' => items.push(number(Field::DayOfMonth, 2, 2)),
+                'e' => items.push(number(Field::DayOfMonth, 1, 2)),
+                'f' => items.push(Item::Fraction {
+                    min_digits: 6,
+                    max_digits: 9,
+                }),
+                'H' => items.push(hour24),
+                'h' | 'I' => items.push(hour12),
+                'i' => items.push(minute),
+                'j' => items.push(number(Field::DayOfYear, 3, 3)),
+                'k' => items.push(number(Field::HourOfDay, 1, 2)),
+                'l' => items.push(number(Field::ClockhourOfHalfday, 1, 2)),
+                'M' => items.push(text(Field::MonthOfYear, false)),
+                'm' => items.push(number(Field::MonthOfYear, 2, 2)),
+                'p' => items.push(text(Field::HalfdayOfDay, false)),
+                'r' => items.extend([
+                    hour12,
+                    colon(),
+                    minute,
+                    colon(),
+                    second,
+                    Item::Literal(" ".to_string()),
+                    text(Field::HalfdayOfDay, false),
+                ]),
+                'S' | 's' => items.push(second),
+                'T' => items.extend([hour24, colon(), minute, colon(), second]),
+                'v' => items.push(number(Field::WeekOfWeekyear, 2, 2)),
+                'x' => items.push(signed_number(Field::Weekyear, 4, 4)),
+                'W' => items.push(text(Field::DayOfWeek, false)),
+                'Y' => items.push(signed_number(Field::Year, 4, 4)),
+                'y' => items.push(Item::TwoDigitYear {
+                    field: Field::Year,
+                    pivot: MYSQL_PIVOT_YEAR,
+                    lenient: false,
+                }),
+                'D' | 'U' | 'u' | 'V' | 'w' | 'X' => {
+                    return exec_err!("%{specifier} not supported in date format string");
+                }
+                other => push_literal(&mut items, other.encode_utf8(&mut [0; 4])),
+            }
+        }
+        Ok(Self { items })
+    }
+
+    /// Print `datetime`, whose time zone is named `zone`, e.g. `UTC` or `Europe/Paris`.
+    pub(crate) fn format(&self, datetime: &DateTime<Tz>, zone: &str) -> String {
+        let local = datetime.naive_local();
+        let offset = datetime.offset().fix();
+        let mut out = String::new();
+        for item in &self.items {
+            match item {
+                Item::Literal(text) => out.push_str(text),
+                Item::Number {
+                    field, min_digits, ..
+                } => push_padded(&mut out, field.get(&local), *min_digits),
+                Item::TwoDigitYear { field, .. } => {
+                    push_padded(&mut out, field.get(&local).abs() % 100, 2)
+                }
+                Item::Fraction {
+                    min_digits,
+                    max_digits,
+                } => {
+                    let millis = Field::MillisOfSecond.get(&local);
+                    let mut digits =
+                        format!("{millis:03}{}", "0".repeat(max_digits.saturating_sub(3)));
+                    digits.truncate(*max_digits);
+                    while digits.len() > *min_digits && digits.ends_with('0') {
+                        digits.pop();
+                    }
+                    out.push_str(&digits);
+                }
+                Item::Text { field, short } => {
+                    let name = field.names()[field.text_index(&local)];
+                    out.push_str(if *short { short_name(name) } else { name });
+                }
+                Item::Offset { colon } => {
+                    let seconds = offset.local_minus_utc();
+                    let sign = if seconds < 0 { '-' } else { '+' };
+                    let (hours, minutes) = (seconds.abs() / 3600, seconds.abs() / 60 % 60);
+                    let colon = if *colon { ":" } else { "" };
+                    let _ = write!(out, "{sign}{hours:02}{colon}{minutes:02}");
+                }
+                Item::ZoneId => out.push_str(zone),
+                Item::ZoneName { short: false } if zone == UTC => {
+                    out.push_str("Coordinated Universal Time")
+                }
+                Item::ZoneName { short: true } => {
+                    match zone_abbreviation(zone, &datetime.naive_utc()) {
+                        Some(abbreviation) => out.push_str(&abbreviation),
+                        None => out.push_str(zone),
+                    }
+                }
+                Item::ZoneName { short: false } => out.push_str(zone),
+            }
+        }
+        out
+    }
+
+    /// Parse `text`, in the time zone `zone` unless the text has one.
+    pub(crate) fn parse(&self, text: &str, zone: &Tz) -> Result<DateTime<Tz>> {
+        let mut parsed = self.read(text)?;
+        let local = parsed
+            .resolve()
+            .map_err(|message| exec_datafusion_err!("Cannot parse \"{text}\": {message}"))?;
+        let zone = parsed.zone.as_ref().map_or(zone, |(zone, _)| zone);
+        Ok(from_local(zone, &local))
+    }
+
+    /// The name of the time zone of `text`, e.g. `+05:30` or `America/New_York`, if it has one.
+    pub(crate) fn zone_of(&self, text: &str) -> Result<Option<String>> {
+        Ok(self.read(text)?.zone.map(|(_, name)| name))
+    }
+
+    fn read(&self, text: &str) -> Result<Parsed> {
+        let mut parsed = Parsed::default();
+        let mut pos = 0;
+        for item in &self.items {
+            pos = item
+                .parse(text, pos, &mut parsed)
+                .map_err(|pos| invalid_format(text, pos))?;
+        }
+        if pos < text.len() {
+            return Err(invalid_format(text, pos));
+        }
+        Ok(parsed)
+    }
+}
+
+fn number(field: Field, min_digits: usize, max_digits: usize) -> Item {
+    Item::Number {
+        field,
+        min_digits,
+        max_digits: max_digits.max(min_digits),
+        signed: false,
+    }
+}
+
+fn signed_number(field: Field, min_digits: usize, max_digits: usize) -> Item {
+    Item::Number {
+        field,
+        min_digits,
+        max_digits: max_digits.max(min_digits),
+        signed: true,
+    }
+}
+
+fn text(field: Field, short: bool) -> Item {
+    Item::Text { field, short }
+}
+
+/// The abbreviation of an English name, e.g. `Jan`.
+fn short_name(name: &str) -> &str {
+    name.get(..3).unwrap_or(name)
+}
+
+fn push_literal(items: &mut Vec<Item>, text: &str) {
+    match items.last_mut() {
+        Some(Item::Literal(literal)) => literal.push_str(text),
+        _ => items.push(Item::Literal(text.to_string())),
+    }
+}
+
+fn push_padded(out: &mut String, value: i64, min_digits: usize) {
+    if value < 0 {
+        out.push('-');
+    }
+    let _ = write!(out, "{:0min_digits$}", value.unsigned_abs());
+}
+
+/// A token of a Joda-Time pattern: a run of a pattern letter, or literal text.
+enum JodaToken {
+    Letter(char, usize),
+    Literal(String),
+}
+
+impl JodaToken {
+    /// Whether the token is a number, which limits the digits of a preceding year.
+    fn is_numeric(&self) -> bool {
+        match self {
+            JodaToken::Letter('M', len) => *len <= 2,
+            JodaToken::Letter(letter, _) => "cCxyYdhHmsSeDFwWkK".contains(*letter),
+            JodaToken::Literal(_) => false,
+        }
+    }
+}
+
+/// Split a Joda-Time pattern into tokens: runs of the same ASCII letter, and the text between
+/// them, in which `'` quotes letters and `''` is a quote.
+fn joda_tokens(pattern: &str) -> Vec<JodaToken> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_alphabetic() {
+            let len = chars[i..].iter().take_while(|&&next| next == c).count();
+            tokens.push(JodaToken::Letter(c, len));
+            i += len;
+            continue;
+        }
+        let mut literal = String::new();
+        let mut quoted = false;
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '\'' {
+                if chars.get(i + 1) == Some(&'\'') {
+                    literal.push(c);
+                    i += 1;
+                } else {
+                    quoted = !quoted;
+                }
+            } else if !quoted && c.is_ascii_alphabetic() {
+                break;
+            } else {
+                literal.push(c);
+            }
+            i += 1;
+        }
+        tokens.push(JodaToken::Literal(literal));
+    }
+    tokens
+}
+
+/// Joda-Time's message for a text that does not match the pattern at the byte `pos`.
+pub(crate) fn invalid_format(text: &str, pos: usize) -> DataFusionError {
+    let error_chars = text[..pos].chars().count();
+    let sample_len = error_chars + 32;
+    let sample = if text.chars().count() <= sample_len + 3 {
+        text.to_string()
+    } else {
+        text.chars().take(sample_len).collect::<String>() + "..."
+    };
+    if pos == 0 {
+        exec_datafusion_err!("Invalid format: \"{sample}\"")
+    } else if pos >= text.len() {
+        exec_datafusion_err!("Invalid format: \"{sample}\" is too short")
+    } else {
+        let rest = sample.chars().skip(error_chars).collect::<String>();
+        exec_datafusion_err!("Invalid format: \"{sample}\" is malformed at \"{rest}\"")
+    }
+}
+
+/// The fields and time zone read from a text.
+#[derive(Default)]
+struct Parsed {
+    fields: Vec<(Field, i64)>,
+    /// The time zone and its name
+    zone: Option<(Tz, String)>,
+}
+
+impl Parsed {
+    /// The local date-time of the fields, set in order on `1970-01-01 00:00:00.000`.
+    fn resolve(&mut self) -> Result<NaiveDateTime, String> {
+        self.fields.sort_by_key(|(field, _)| *field);
+        self.fields.iter().try_fold(
+            DateTime::UNIX_EPOCH.naive_utc(),
+            |datetime, (field, value)| field.set(datetime, *value),
+        )
+    }
+}
+
+impl Item {
+    /// Parse the item at the byte `pos` of `text` into `parsed`, returning the position after
+    /// it, or the position of the error.
+    fn parse(&self, text: &str, pos: usize, parsed: &mut Parsed) -> Result<usize, usize> {
+        let rest = &text.as_bytes()[pos..];
+        match self {
+            Item::Literal(literal) => {
+                let literal = literal.as_bytes();
+                match rest.get(..literal.len()) {
+                    Some(prefix) if prefix.eq_ignore_ascii_case(literal) => Ok(pos + literal.len()),
+                    _ => Err(pos),
+                }
+            }
+            Item::Number {
+                field,
+                max_digits,
+                signed,
+                ..
+            } => {
+                let (value, len) = parse_number(rest, *max_digits, *signed).ok_or(pos)?;
+                parsed.fields.push((*field, value));
+                Ok(pos + len)
+            }
+            Item::TwoDigitYear {
+                field,
+                pivot,
+                lenient,
+            } => {
+                if *lenient {
+                    let (value, len) = parse_number(rest, 9, true).ok_or(pos)?;
+                    if len != 2 {
+                        parsed.fields.push((*field, value));
+                        return Ok(pos + len);
+                    }
+                }
+                match rest {
+                    [tens @ b'0'..=b'9', units @ b'0'..=b'9', ..] => {
+                        let year = i64::from((tens - b'0') * 10 + (units - b'0'));
+                        let low = i64::from(*pivot) - 50;
+                        let t = low.rem_euclid(100);
+                        let year = year + low - t + if year < t { 100 } else { 0 };
+                        parsed.fields.push((*field, year));
+                        Ok(pos + 2)
+                    }
+                    _ => Err(pos),
+                }
+            }
+            Item::Fraction { max_digits, .. } => {
+                let len = rest
+                    .iter()
+                    .take(*max_digits)
+                    .take_while(|b| b.is_ascii_digit())
+                    .count();
+                if len == 0 {
+                    return Err(pos);
+                }
+                let millis = rest[..len.min(3)]
+                    .iter()
+                    .chain(std::iter::repeat(&b'0'))
+                    .take(3)
+                    .fold(0, |millis, digit| millis * 10 + i64::from(digit - b'0'));
+                parsed.fields.push((Field::MillisOfSecond, millis));
+                Ok(pos + len)
+            }
+            Item::Text { field, .. } => {
+                let names = field.names();
+                let (index, len) = names
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, name)| [(i, *name), (i, short_name(name))])
+                    .filter(|(_, name)| {
+                        rest.get(..name.len())
+                            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name.as_bytes()))
+                    })
+                    .map(|(i, name)| (i, name.len()))
+                    .max_by_key(|(_, len)| *len)
+                    .ok_or(pos)?;
+                let value = match field {
+                    Field::DayOfWeek => index + 1,
+                    Field::MonthOfYear => index + 1,
+                    _ => index,
+                };
+                parsed.fields.push((*field, value as i64));
+                Ok(pos + len)
+            }
+            Item::Offset { .. } => {
+                let (offset, len) = parse_offset(rest).ok_or(pos)?;
+                parsed.zone = Some((Tz::from_str(&offset).map_err(|_| pos)?, offset));
+                Ok(pos + len)
+            }
+            Item::ZoneId => {
+                let len = rest
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || b"/_+-".contains(b))
+                    .count();
+                // The longest time zone at the position
+                let (zone, len) = (1..=len)
+                    .rev()
+                    .find_map(|len| {
+                        let name = std::str::from_utf8(&rest[..len]).ok()?;
+                        Some(((Tz::from_str(name).ok()?, name.to_string()), len))
+                    })
+                    .ok_or(pos)?;
+                parsed.zone = Some(zone);
+                Ok(pos + len)
+            }
+            Item::ZoneName { .. } => {
+                let (zone, len) = ZONE_NAMES
+                    .iter()
+                    .filter(|(name, _)| rest.starts_with(name.as_bytes()))
+                    .max_by_key(|(name, _)| name.len())
+                    .map(|(name, zone)| (zone, name.len()))
+                    .ok_or(pos)?;
+                parsed.zone = Tz::from_str(zone).ok().map(|tz| (tz, zone.to_string()));
+                Ok(pos + len)
+            }
+        }
+    }
+}
+
+/// A number of at most `max_digits` digits at the start of `text`, after a sign if `signed`, and
+/// its length.
+fn parse_number(text: &[u8], max_digits: usize, signed: bool) -> Option<(i64, usize)> {
+    let (negative, sign_len) = match text.first() {
+        Some(b'-') if signed => (true, 1),
+        Some(b'+') if signed => (false, 1),
+        _ => (false, 0),
+    };
+    let digits = text[sign_len..]
+        .iter()
+        .take(max_digits)
+        .take_while(|b| b.is_ascii_digit())
+        .collect::<Vec<_>>();
+    if digits.is_empty() {
+        return None;
+    }
+    let value = digits.iter().fold(0i64, |value, digit| {
+        value
+            .saturating_mul(10)
+            .saturating_add(i64::from(*digit - b'0'))
+    });
+    Some((
+        if negative { -value } else { value },
+        sign_len + digits.len(),
+    ))
+}
+
+/// The name of an offset from UTC, `Z` or `±HH`, `±HHMM` or `±HH:MM`, at the start of `text`, e.g.
+/// `+05:30`, and its length.
+fn parse_offset(text: &[u8]) -> Option<(String, usize)> {
+    if matches!(text.first(), Some(b'Z' | b'z')) {
+        return Some(("+00:00".to_string(), 1));
+    }
+    let two_digits = |pos: usize| match text.get(pos..pos + 2)? {
+        [tens @ b'0'..=b'9', units @ b'0'..=b'9'] => {
+            Some(i32::from((tens - b'0') * 10 + units - b'0'))
+        }
+        _ => None,
+    };
+    let sign = match text.first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let hours = two_digits(1).filter(|hours| *hours < 24)?;
+    let (minutes, len) = match text.get(3) {
+        Some(b':') => (two_digits(4).filter(|m| *m < 60)?, 6),
+        _ => match two_digits(3) {
+            Some(minutes) if minutes < 60 => (minutes, 5),
+            _ => (0, 3),
+        },
+    };
+    let offset = FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))?;
+    Some((offset.to_string(), len))
+}
+
+impl Field {
+    /// Joda-Time's name of the field.
+    fn name(&self) -> &'static str {
+        match self {
+            Field::Era => "era",
+            Field::CenturyOfEra => "centuryOfEra",
+            Field::YearOfEra => "yearOfEra",
+            Field::Year => "year",
+            Field::Weekyear => "weekyear",
+            Field::MonthOfYear => "monthOfYear",
+            Field::WeekOfWeekyear => "weekOfWeekyear",
+            Field::DayOfYear => "dayOfYear",
+            Field::DayOfMonth => "dayOfMonth",
+            Field::DayOfWeek => "dayOfWeek",
+            Field::HalfdayOfDay => "halfdayOfDay",
+            Field::HourOfDay => "hourOfDay",
+            Field::ClockhourOfDay => "clockhourOfDay",
+            Field::HourOfHalfday => "hourOfHalfday",
+            Field::ClockhourOfHalfday => "clockhourOfHalfday",
+            Field::MinuteOfHour => "minuteOfHour",
+            Field::SecondOfMinute => "secondOfMinute",
+            Field::MillisOfSecond => "millisOfSecond",
+        }
+    }
+
+    /// The English names of the values of a text field.
+    fn names(&self) -> &'static [&'static str] {
+        match self {
+            Field::Era => &ERAS,
+            Field::MonthOfYear => &MONTHS,
+            Field::DayOfWeek => &WEEKDAYS,
+            _ => &HALFDAYS,
+        }
+    }
+
+    /// The index of the name of the value of a text field in [`Field::names`].
+    fn text_index(&self, datetime: &NaiveDateTime) -> usize {
+        match self {
+            Field::MonthOfYear | Field::DayOfWeek => self.get(datetime) as usize - 1,
+            _ => self.get(datetime) as usize,
+        }
+    }
+
+    /// The value of the field in `datetime`.
+    fn get(&self, datetime: &NaiveDateTime) -> i64 {
+        let year = i64::from(datetime.year());
+        let year_of_era = if year <= 0 { 1 - year } else { year };
+        let hour = i64::from(datetime.hour());
+        match self {
+            Field::Era => i64::from(year > 0),
+            Field::CenturyOfEra => year_of_era / 100,
+            Field::YearOfEra => year_of_era,
+            Field::Year => year,
+            Field::Weekyear => i64::from(datetime.iso_week().year()),
+            Field::MonthOfYear => i64::from(datetime.month()),
+            Field::WeekOfWeekyear => i64::from(datetime.iso_week().week()),
+            Field::DayOfYear => i64::from(datetime.ordinal()),
+            Field::DayOfMonth => i64::from(datetime.day()),
+            Field::DayOfWeek => i64::from(datetime.weekday().number_from_monday()),
+            Field::HalfdayOfDay => hour / 12,
+            Field::HourOfDay => hour,
+            Field::ClockhourOfDay => (hour + 23) % 24 + 1,
+            Field::HourOfHalfday => hour % 12,
+            Field::ClockhourOfHalfday => (hour + 11) % 12 + 1,
+            Field::MinuteOfHour => i64::from(datetime.minute()),
+            Field::SecondOfMinute => i64::from(datetime.second()),
+            Field::MillisOfSecond => i64::from(datetime.nanosecond() / 1_000_000),
+        }
+    }
+
+    /// The range of the values of the field in `datetime`.
+    fn range(&self, datetime: &NaiveDateTime) -> (i64, i64) {
+        let min_year = i64::from(NaiveDate::MIN.year());
+        let max_year = i64::from(NaiveDate::MAX.year());
+        let date = datetime.date();
+        match self {
+            Field::Era | Field::HalfdayOfDay => (0, 1),
+            Field::CenturyOfEra => (0, max_year / 100),
+            Field::YearOfEra => (1, max_year),
+            Field::Year | Field::Weekyear => (min_year, max_year),
+            Field::MonthOfYear => (1, 12),
+            Field::WeekOfWeekyear => (1, weeks_in_weekyear(date.iso_week().year())),
+            Field::DayOfYear => (1, if date.leap_year() { 366 } else { 365 }),
+            Field::DayOfMonth => (1, days_in_month(date.year(), date.month())),
+            Field::DayOfWeek => (1, 7),
+            Field::HourOfDay => (0, 23),
+            Field::ClockhourOfDay => (1, 24),
+            Field::HourOfHalfday => (0, 11),
+            Field::ClockhourOfHalfday => (1, 12),
+            Field::MinuteOfHour | Field::SecondOfMinute => (0, 59),
+            Field::MillisOfSecond => (0, 999),
+        }
+    }
+
+    /// `datetime` with the field set to `value`, or Joda-Time's message for a value out of range.
+    fn set(&self, datetime: NaiveDateTime, value: i64) -> Result<NaiveDateTime, String> {
+        let (min, max) = self.range(&datetime);
+        if value < min || value > max {
+            let name = self.name();
+            return Err(format!(
+                "Value {value} for {name} must be in the range [{min},{max}]"
+            ));
+        }
+        // The value is in range, so it fits the fields of chrono
+        let value32 = value as i32;
+        let (date, time) = (datetime.date(), datetime.time());
+        let year = date.year();
+        let date = match self {
+            Field::Era if i64::from(year > 0) != value => with_year(date, 1 - year),
+            Field::CenturyOfEra => {
+                let year_of_era = Field::YearOfEra.get(&datetime) as i32;
+                set_year_of_era(date, value32 * 100 + year_of_era % 100)
+            }
+            Field::YearOfEra => set_year_of_era(date, value32),
+            Field::Year => with_year(date, value32),
+            Field::Weekyear => {
+                let week = date
+                    .iso_week()
+                    .week()
+                    .min(weeks_in_weekyear(value32) as u32);
+                NaiveDate::from_isoywd_opt(value32, week, date.weekday())
+            }
+            Field::MonthOfYear => {
+                let day = date.day().min(days_in_month(year, value as u32) as u32);
+                NaiveDate::from_ymd_opt(year, value as u32, day)
+            }
+            Field::WeekOfWeekyear => {
+                let week = i64::from(date.iso_week().week());
+                date.checked_add_signed(Duration::weeks(value - week))
+            }
+            Field::DayOfYear => date.with_ordinal(value as u32),
+            Field::DayOfMonth => date.with_day(value as u32),
+            Field::DayOfWeek => {
+                let weekday = i64::from(date.weekday().number_from_monday());
+                date.checked_add_signed(Duration::days(value - weekday))
+            }
+            _ => Some(date),
+        };
+        let hour = time.hour();
+        let time = match self {
+            Field::HalfdayOfDay => time.with_hour(hour % 12 + 12 * value as u32),
+            Field::HourOfDay | Field::ClockhourOfDay => time.with_hour(value as u32 % 24),
+            Field::HourOfHalfday | Field::ClockhourOfHalfday => {
+                time.with_hour(hour / 12 * 12 + value as u32 % 12)
+            }
+            Field::MinuteOfHour => time.with_minute(value as u32),
+            Field::SecondOfMinute => time.with_second(value as u32),
+            Field::MillisOfSecond => time.with_nanosecond(value as u32 * 1_000_000),
+            _ => Some(time),
+        };
+        match (date, time) {
+            (Some(date), Some(time)) => Ok(date.and_time(time)),
+            _ => Err(format!("Value {value} for {} is out of range", self.name())),
+        }
+    }
+}
+
+/// `date` in the year `year`, February 29 becoming February 28 outside of leap years.
+fn with_year(date: NaiveDate, year: i32) -> Option<NaiveDate> {
+    let day = date.day().min(days_in_month(year, date.month()) as u32);
+    NaiveDate::from_ymd_opt(year, date.month(), day)
+}
+
+/// `date` in the year `year_of_era` of its era.
+fn set_year_of_era(date: NaiveDate, year_of_era: i32) -> Option<NaiveDate> {
+    with_year(
+        date,
+        if date.year() > 0 {
+            year_of_era
+        } else {
+            1 - year_of_era
+        },
+    )
+}
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    match (
+        NaiveDate::from_ymd_opt(year, month, 1),
+        NaiveDate::from_ymd_opt(next_year, next_month, 1),
+    ) {
+        (Some(first), Some(next)) => (next - first).num_days(),
+        _ => 31,
+    }
+}
+
+fn weeks_in_weekyear(weekyear: i32) -> i64 {
+    if NaiveDate::from_isoywd_opt(weekyear, 53, Weekday::Mon).is_some() {
+        53
+    } else {
+        52
+    }
+}
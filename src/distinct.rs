@@ -0,0 +1,283 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arrays of the SDF "distinct" types, e.g. `joniregexp`.
+//!
+//! A distinct type is represented as a `FixedSizeList` of size 1 wrapping its underlying values,
+//! so that it can be told apart from the underlying type. Since each list holds exactly one
+//! value, the child array has one value per row: [`wrap`] and [`unwrap`] convert between the two
+//! representations without copying the values.
+//!
+//! ```
+//! use arrow::array::StringArray;
+//! use sql_functions::distinct::{self, JoniRegexp};
+//!
+//! let patterns = distinct::wrap::<JoniRegexp>(StringArray::from(vec![Some("a+"), None]));
+//! let values = distinct::unwrap::<JoniRegexp>(&patterns)?;
+//! assert_eq!(values.value(0), "a+");
+//! # Ok::<(), datafusion::error::DataFusionError>(())
+//! ```
+
+use arrow::array::{Array, ArrayData, ArrayRef, FixedSizeListArray, Int64Array, StringArray};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::{exec_err, Result, ScalarValue};
+use datafusion::logical_expr::ColumnarValue;
+use std::sync::Arc;
+
+use crate::type_signature::distinct_of;
+
+/// A distinct type, with the Arrow array of its underlying values.
+pub trait DistinctType {
+    /// The name of the type in the function definitions, e.g. `joniregexp`.
+    const NAME: &'static str;
+
+    /// The array of the underlying values.
+    type Values: Array + From<ArrayData> + 'static;
+
+    /// The data type of the underlying values.
+    fn values_type() -> DataType;
+}
+
+/// A Java regular expression, as taken by the Trino regexp functions.
+pub struct JoniRegexp;
+
+/// A JSON path, as taken by the Trino JSON functions.
+pub struct JsonPath;
+
+/// The code points of a string, as taken by the Trino `translate` function.
+pub struct CodePoints;
+
+/// An RGB color, as taken and returned by the Trino color functions.
+pub struct Color;
+
+impl DistinctType for JoniRegexp {
+    const NAME: &'static str = "joniregexp";
+    type Values = StringArray;
+
+    fn values_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl DistinctType for JsonPath {
+    const NAME: &'static str = "jsonpath";
+    type Values = StringArray;
+
+    fn values_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl DistinctType for CodePoints {
+    const NAME: &'static str = "codepoints";
+    type Values = StringArray;
+
+    fn values_type() -> DataType {
+        DataType::Utf8
+    }
+}
+
+impl DistinctType for Color {
+    const NAME: &'static str = "color";
+    type Values = Int64Array;
+
+    fn values_type() -> DataType {
+        DataType::Int64
+    }
+}
+
+/// The Arrow data type of the distinct type `T`.
+pub fn data_type<T: DistinctType>() -> DataType {
+    distinct_of(T::values_type())
+}
+
+fn values_field<T: DistinctType>() -> FieldRef {
+    match data_type::<T>() {
+        DataType::FixedSizeList(field, _) => field,
+        _ => unreachable!("distinct types are fixed size lists"),
+    }
+}
+
+/// Wraps `values` into an array of the distinct type `T`, without copying them.
+pub fn wrap<T: DistinctType>(values: T::Values) -> FixedSizeListArray {
+    let nulls = values.logical_nulls();
+    FixedSizeListArray::new(values_field::<T>(), 1, Arc::new(values), nulls)
+}
+
+/// The underlying values of an array of the distinct type `T`, without copying them.
+/// A row is NULL if either the list or its value is NULL.
+pub fn unwrap<T: DistinctType>(array: &dyn Array) -> Result<T::Values> {
+    let Some(list) = array.as_any().downcast_ref::<FixedSizeListArray>() else {
+        return exec_err!(
+            "Expected an array of type {}, got {}",
+            T::NAME,
+            array.data_type()
+        );
+    };
+    if list.value_length() != 1 || list.value_type() != T::values_type() {
+        return exec_err!(
+            "Expected an array of type {}, got {}",
+            T::NAME,
+            array.data_type()
+        );
+    }
+    // The child array of a sliced list is sliced too, so it has one value per row
+    let values = list.values();
+    let nulls = NullBuffer::union(list.nulls(), values.nulls());
+    let data = if nulls.as_ref() == values.nulls() {
+        values.to_data()
+    } else {
+        values.to_data().into_builder().nulls(nulls).build()?
+    };
+    Ok(T::Values::from(data))
+}
+
+/// The underlying values of a scalar or array of the distinct type `T`, a scalar having one row.
+pub fn unwrap_columnar<T: DistinctType>(value: &ColumnarValue) -> Result<T::Values> {
+    match value {
+        ColumnarValue::Array(array) => unwrap::<T>(array),
+        ColumnarValue::Scalar(scalar) => unwrap_scalar::<T>(scalar),
+    }
+}
+
+/// The underlying value of a scalar of the distinct type `T`, as an array of one row.
+pub fn unwrap_scalar<T: DistinctType>(scalar: &ScalarValue) -> Result<T::Values> {
+    match scalar {
+        ScalarValue::FixedSizeList(list) => unwrap::<T>(list.as_ref()),
+        // A NULL of another type, e.g. an untyped NULL literal
+        _ if scalar.is_null() => unwrap::<T>(&new_null_distinct::<T>(1)),
+        _ => exec_err!(
+            "Expected a value of type {}, got {}",
+            T::NAME,
+            scalar.data_type()
+        ),
+    }
+}
+
+/// An array of the distinct type `T` of `len` NULLs.
+fn new_null_distinct<T: DistinctType>(len: usize) -> FixedSizeListArray {
+    FixedSizeListArray::new_null(values_field::<T>(), 1, len)
+}
+
+/// Wraps the result `values` of a function returning the distinct type `T` into a columnar value:
+/// a scalar if all `args` are scalars, and an array otherwise.
+pub fn wrap_columnar<T: DistinctType>(
+    values: T::Values,
+    args: &[&ColumnarValue],
+) -> Result<ColumnarValue> {
+    crate::utils::array_to_columnar(Arc::new(wrap::<T>(values)) as ArrayRef, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_and_unwrap_share_the_values() -> Result<()> {
+        let values = StringArray::from(vec![Some("a+"), None, Some("b")]);
+        let data = values.value_data().as_ptr();
+        let wrapped = wrap::<JoniRegexp>(values);
+        assert_eq!(wrapped.data_type(), &data_type::<JoniRegexp>());
+        let unwrapped = unwrap::<JoniRegexp>(&wrapped)?;
+        assert_eq!(unwrapped.value_data().as_ptr(), data);
+
+        let colors = Int64Array::from(vec![Some(0xff0000), None]);
+        let data = colors.values().inner().as_ptr();
+        let unwrapped = unwrap::<Color>(&wrap::<Color>(colors))?;
+        assert_eq!(unwrapped.values().inner().as_ptr(), data);
+        Ok(())
+    }
+
+    #[test]
+    fn nulls_survive_wrap_and_unwrap() -> Result<()> {
+        let wrapped = wrap::<JsonPath>(StringArray::from(vec![Some("$.a"), None, Some("$.b")]));
+        assert_eq!(wrapped.null_count(), 1);
+        assert!(wrapped.is_null(1));
+        let unwrapped = unwrap::<JsonPath>(&wrapped)?;
+        assert_eq!(
+            unwrapped.iter().collect::<Vec<_>>(),
+            [Some("$.a"), None, Some("$.b")]
+        );
+
+        // A NULL list of a non-NULL value is NULL too
+        let values = Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef;
+        let list = FixedSizeListArray::new(
+            values_field::<CodePoints>(),
+            1,
+            values,
+            Some(NullBuffer::from(vec![true, false])),
+        );
+        let unwrapped = unwrap::<CodePoints>(&list)?;
+        assert_eq!(unwrapped.iter().collect::<Vec<_>>(), [Some("x"), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn sliced_arrays_unwrap_to_their_rows() -> Result<()> {
+        let colors = Int64Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let data = colors.values().inner().as_ptr();
+        let sliced = wrap::<Color>(colors).slice(1, 2);
+        let unwrapped = unwrap::<Color>(&sliced)?;
+        assert_eq!(unwrapped.iter().collect::<Vec<_>>(), [None, Some(3)]);
+        // The values start at the second row of the original buffer
+        let offset = std::mem::size_of::<i64>();
+        assert_eq!(
+            unwrapped.values().inner().as_ptr(),
+            data.wrapping_add(offset)
+        );
+
+        let patterns = wrap::<JoniRegexp>(StringArray::from(vec![Some("a"), Some("b"), None]));
+        let unwrapped = unwrap::<JoniRegexp>(&patterns.slice(1, 2))?;
+        assert_eq!(unwrapped.iter().collect::<Vec<_>>(), [Some("b"), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn unwrapping_another_type_is_an_error() {
+        let patterns = wrap::<JoniRegexp>(StringArray::from(vec!["a"]));
+        let err = unwrap::<Color>(&patterns).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Expected an array of type color, got FixedSizeList"),
+            "{err}"
+        );
+
+        let strings = StringArray::from(vec!["a"]);
+        let err = unwrap::<JoniRegexp>(&strings).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Expected an array of type joniregexp, got Utf8"),
+            "{err}"
+        );
+
+        let pairs = FixedSizeListArray::new(
+            values_field::<JoniRegexp>(),
+            2,
+            Arc::new(StringArray::from(vec!["a", "b"])),
+            None,
+        );
+        assert!(unwrap::<JoniRegexp>(&pairs).is_err());
+
+        let err = unwrap_scalar::<Color>(&ScalarValue::from("a")).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Expected a value of type color, got Utf8"),
+            "{err}"
+        );
+    }
+}
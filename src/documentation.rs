@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! DataFusion [`Documentation`] of the generated UDFs, built from the [`FunctionCatalog`]. The
+//! documentation of any overload, e.g. of a table function, is available from [`documentation`].
+
+use crate::catalog::{FunctionCatalog, FunctionDefinition};
+use crate::registry::Dialect;
+use datafusion::logical_expr::scalar_doc_sections::*;
+use datafusion::logical_expr::{DocSection, Documentation};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// The documentation of the overload `overload` (e.g. `abs_tinyint`) of `dialect`.
+pub fn documentation(dialect: Dialect, overload: &str) -> Option<&'static Documentation> {
+    static TRINO: OnceLock<HashMap<String, Documentation>> = OnceLock::new();
+    static REDSHIFT: OnceLock<HashMap<String, Documentation>> = OnceLock::new();
+    static SNOWFLAKE: OnceLock<HashMap<String, Documentation>> = OnceLock::new();
+    let documentation = match dialect {
+        Dialect::Trino => &TRINO,
+        Dialect::Redshift => &REDSHIFT,
+        Dialect::Snowflake => &SNOWFLAKE,
+    };
+    documentation.get_or_init(|| load(dialect)).get(overload)
+}
+
+fn load(dialect: Dialect) -> HashMap<String, Documentation> {
+    // The definitions are embedded, so this only fails if they are invalid: the functions are
+    // then simply undocumented
+    let Ok(catalog) = FunctionCatalog::load_dialect(dialect.name()) else {
+        return HashMap::new();
+    };
+    catalog
+        .functions()
+        .iter()
+        .map(|function| (function.overload.clone(), overload_documentation(function)))
+        .collect()
+}
+
+fn overload_documentation(function: &FunctionDefinition) -> Documentation {
+    let mut description = function
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("The {} function.", function.name));
+    if let Some(cross_link) = &function.cross_link {
+        description.push_str(&format!("\n\nSee {cross_link}"));
+    }
+    let mut builder = Documentation::builder(
+        doc_section(&function.section),
+        description,
+        function.display_signature(),
+    );
+    let last = function.parameters.len();
+    for (i, param) in function.parameters.iter().enumerate() {
+        let mut param_description = match &param.constant {
+            Some(constant) => format!("{} literal {constant}", param.datatype),
+            None => param.datatype.clone(),
+        };
+        if i + 1 == last && function.variadic.is_some() {
+            param_description.push_str(", repeated any number of times");
+        }
+        builder = builder.with_argument(argument_name(param.name.as_deref(), i), param_description);
+    }
+    for (i, param) in function.optional_parameters.iter().enumerate() {
+        builder = builder.with_argument(
+            argument_name(param.name.as_deref(), last + i),
+            format!("optional {}", param.datatype),
+        );
+    }
+    let examples = function
+        .examples
+        .iter()
+        .map(|example| match &example.output {
+            Some(output) => format!("> {}\n{output}", example.input),
+            None => format!("> {}", example.input),
+        })
+        .collect::<Vec<_>>();
+    if !examples.is_empty() {
+        builder = builder.with_sql_example(format!("```sql\n{}\n```", examples.join("\n\n")));
+    }
+    builder.build()
+}
+
+/// The name of the `i`th argument, as in the generated `expr_fn` functions if it has none.
+fn argument_name(name: Option<&str>, i: usize) -> String {
+    name.map(str::to_string)
+        .unwrap_or_else(|| format!("arg{}", i + 1))
+}
+
+/// The DataFusion documentation section of a section of the dialect's documentation.
+fn doc_section(section: &str) -> DocSection {
+    match section {
+        "math" | "bitwise" => DOC_SECTION_MATH,
+        "conditional" | "comparison" => DOC_SECTION_CONDITIONAL,
+        "string" | "url" => DOC_SECTION_STRING,
+        "binary" => DOC_SECTION_BINARY_STRING,
+        "regexp" => DOC_SECTION_REGEX,
+        "datetime" => DOC_SECTION_DATETIME,
+        "array" | "lambda" => DOC_SECTION_ARRAY,
+        "map" => DOC_SECTION_MAP,
+        _ => DOC_SECTION_OTHER,
+    }
+}
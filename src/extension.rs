@@ -0,0 +1,455 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arrow extension types of the Trino types without a dedicated Arrow data type.
+//!
+//! An extension type is recorded in the metadata of a [`Field`], on top of a fixed storage type,
+//! so that the Trino type of a column survives Parquet and IPC round trips:
+//!
+//! | Trino type           | Extension name              | Storage                                  |
+//! |----------------------|-----------------------------|------------------------------------------|
+//! | `geometry`           | `trino.geometry`            | `Binary`: well-known binary (WKB)        |
+//! | `sphericalgeography` | `trino.sphericalgeography`  | `Binary`: well-known binary (WKB)        |
+//! | `json`               | `arrow.json`                | `Utf8`: JSON text                        |
+//! | `uuid`               | `arrow.uuid`                | `FixedSizeBinary(16)`: big-endian bytes  |
+//! | `ipaddress`          | `trino.ipaddress`           | `FixedSizeBinary(16)`: IPv6, IPv4-mapped |
+//! | `hyperloglog`        | `trino.hyperloglog`         | `Binary`: serialized sketch              |
+//! | `qdigest`            | `trino.qdigest`             | `Binary`: serialized digest              |
+//! | `tdigest`            | `trino.tdigest`             | `Binary`: serialized digest              |
+//! | `setdigest`          | `trino.setdigest`           | `Binary`: serialized digest              |
+//! | `bingtile`           | `trino.bingtile`            | `Int64`: see [`BingTile`]                |
+//! | `color`              | `trino.color`               | the [`distinct`](crate::distinct) type   |
+//! | `objectid`           | `trino.objectid`            | `FixedSizeBinary(12)`                    |
+//!
+//! `json` and `uuid` are the canonical Arrow extension types of `arrow_schema`, re-exported here.
+//! The sketches and digests use the serialization of Trino, and the metadata of `qdigest` is the
+//! type of its values.
+//!
+//! The types describe the columns of tables, not yet the values passed between functions: the
+//! return types of DataFusion functions are data types rather than fields, so a function
+//! returns the storage type of its Trino type, without the extension type. Nor are the functions
+//! of the sketches implemented yet, e.g. `approx_set` and `cardinality(hyperloglog)`.
+//!
+//! ```
+//! use arrow::datatypes::Field;
+//! use sql_functions::extension::{self, Uuid};
+//!
+//! let field = extension::trino_field("id", "uuid", true).unwrap();
+//! assert_eq!(field.extension_type_name(), Some("arrow.uuid"));
+//! assert_eq!(extension::trino_type_name(&field).as_deref(), Some("uuid"));
+//! # let _: Uuid = field.extension_type();
+//! ```
+
+use arrow::array::{Array, ArrayRef, FixedSizeBinaryArray, FixedSizeBinaryBuilder, StringArray};
+use arrow::datatypes::{DataType, Field};
+use arrow::error::ArrowError;
+use arrow_schema::extension::ExtensionType;
+pub use arrow_schema::extension::{Json, Uuid};
+use datafusion::common::cast::{as_fixed_size_binary_array, as_string_array};
+use datafusion::common::{exec_err, Result};
+use std::net::{IpAddr, Ipv6Addr};
+use std::sync::Arc;
+
+use crate::distinct;
+
+/// Defines an extension type without metadata stored as `$storage`.
+macro_rules! trino_extension_type {
+    ($(#[$doc:meta])* $ty:ident, $name:literal, $storage:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub struct $ty;
+
+        impl ExtensionType for $ty {
+            const NAME: &'static str = $name;
+
+            type Metadata = ();
+
+            fn metadata(&self) -> &Self::Metadata {
+                &()
+            }
+
+            fn serialize_metadata(&self) -> Option<String> {
+                None
+            }
+
+            fn deserialize_metadata(metadata: Option<&str>) -> Result<(), ArrowError> {
+                match metadata {
+                    None | Some("") => Ok(()),
+                    Some(metadata) => Err(ArrowError::InvalidArgumentError(format!(
+                        "{} extension type expects no metadata, got {metadata}",
+                        Self::NAME
+                    ))),
+                }
+            }
+
+            fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+                check_storage(Self::NAME, data_type, &$storage)
+            }
+
+            fn try_new(data_type: &DataType, _metadata: ()) -> Result<Self, ArrowError> {
+                $ty.supports_data_type(data_type)?;
+                Ok($ty)
+            }
+        }
+    };
+}
+
+fn check_storage(name: &str, data_type: &DataType, storage: &DataType) -> Result<(), ArrowError> {
+    if data_type == storage {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{name} extension type expects storage type {storage}, got {data_type}"
+        )))
+    }
+}
+
+trino_extension_type!(
+    /// A geometry, as well-known binary.
+    Geometry,
+    "trino.geometry",
+    DataType::Binary
+);
+trino_extension_type!(
+    /// A geography on a sphere, as well-known binary.
+    SphericalGeography,
+    "trino.sphericalgeography",
+    DataType::Binary
+);
+trino_extension_type!(
+    /// An IP address, as the 16 bytes of an IPv6 address, IPv4 addresses being IPv4-mapped.
+    IpAddress,
+    "trino.ipaddress",
+    DataType::FixedSizeBinary(16)
+);
+trino_extension_type!(
+    /// A HyperLogLog sketch, serialized as by Trino.
+    HyperLogLog,
+    "trino.hyperloglog",
+    DataType::Binary
+);
+trino_extension_type!(
+    /// A t-digest, serialized as by Trino.
+    TDigest,
+    "trino.tdigest",
+    DataType::Binary
+);
+trino_extension_type!(
+    /// A set digest, serialized as by Trino.
+    SetDigest,
+    "trino.setdigest",
+    DataType::Binary
+);
+trino_extension_type!(
+    /// A Bing tile, as encoded by [`BingTile::encode`].
+    BingTileType,
+    "trino.bingtile",
+    DataType::Int64
+);
+trino_extension_type!(
+    /// An RGB or ANSI color, as its distinct type: RGB colors are `0xRRGGBB` and the 16 ANSI
+    /// colors are `-1 - index`.
+    Color,
+    "trino.color",
+    distinct::data_type::<distinct::Color>()
+);
+trino_extension_type!(
+    /// A MongoDB ObjectId, as its 12 bytes.
+    ObjectId,
+    "trino.objectid",
+    DataType::FixedSizeBinary(12)
+);
+
+/// A quantile digest, serialized as by Trino, with the type of its values as metadata
+/// (`bigint`, `double` or `real`) when known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QDigest {
+    value_type: Option<String>,
+}
+
+impl QDigest {
+    /// A quantile digest of values of `value_type`, if known.
+    pub fn new(value_type: Option<&str>) -> Result<Self> {
+        Ok(QDigest {
+            value_type: Self::deserialize_metadata(value_type)?,
+        })
+    }
+
+    /// The type of the values of the digest, if known.
+    pub fn value_type(&self) -> Option<&str> {
+        self.value_type.as_deref()
+    }
+}
+
+impl ExtensionType for QDigest {
+    const NAME: &'static str = "trino.qdigest";
+
+    type Metadata = Option<String>;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.value_type
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        self.value_type.clone()
+    }
+
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None | Some("") => Ok(None),
+            Some(value_type @ ("bigint" | "double" | "real")) => Ok(Some(value_type.to_string())),
+            Some(value_type) => Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type expects values of type bigint, double or real, got {value_type}",
+                Self::NAME
+            ))),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        check_storage(Self::NAME, data_type, &DataType::Binary)
+    }
+
+    fn try_new(data_type: &DataType, metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        let qdigest = QDigest {
+            value_type: metadata,
+        };
+        qdigest.supports_data_type(data_type)?;
+        Ok(qdigest)
+    }
+}
+
+/// The storage type of the Trino type `name` if it has an extension type.
+pub(crate) fn storage_type(name: &str) -> Option<DataType> {
+    let storage = match name {
+        "geometry" | "sphericalgeography" | "hyperloglog" | "qdigest" | "tdigest" | "setdigest" => {
+            DataType::Binary
+        }
+        "json" => DataType::Utf8,
+        "uuid" | "ipaddress" => DataType::FixedSizeBinary(16),
+        "bingtile" => DataType::Int64,
+        "color" => distinct::data_type::<distinct::Color>(),
+        "objectid" => DataType::FixedSizeBinary(12),
+        _ => return None,
+    };
+    Some(storage)
+}
+
+/// A field `name` of the Trino type `trino_type` (e.g. `uuid` or `qdigest(bigint)`), with its
+/// storage type and extension type, or `None` if the type has no extension type.
+pub fn trino_field(name: &str, trino_type: &str, nullable: bool) -> Option<Field> {
+    let (base, param) = match trino_type.split_once('(') {
+        Some((base, param)) => (base.trim(), param.strip_suffix(')').map(str::trim)),
+        None => (trino_type.trim(), None),
+    };
+    let field = Field::new(name, storage_type(base)?, nullable);
+    let field = match base {
+        "geometry" => field.with_extension_type(Geometry),
+        "sphericalgeography" => field.with_extension_type(SphericalGeography),
+        "json" => field.with_extension_type(Json::default()),
+        "uuid" => field.with_extension_type(Uuid),
+        "ipaddress" => field.with_extension_type(IpAddress),
+        "hyperloglog" => field.with_extension_type(HyperLogLog),
+        "qdigest" => field.with_extension_type(QDigest::new(param).ok()?),
+        "tdigest" => field.with_extension_type(TDigest),
+        "setdigest" => field.with_extension_type(SetDigest),
+        "bingtile" => field.with_extension_type(BingTileType),
+        "color" => field.with_extension_type(Color),
+        "objectid" => field.with_extension_type(ObjectId),
+        _ => return None,
+    };
+    Some(field)
+}
+
+/// The Trino type of a field with one of the extension types of this module, e.g. `uuid` or
+/// `qdigest(bigint)`, or `None` if it has none of them or its storage type does not match.
+pub fn trino_type_name(field: &Field) -> Option<String> {
+    let name = match field.extension_type_name()? {
+        Geometry::NAME => field.try_extension_type::<Geometry>().map(|_| "geometry"),
+        SphericalGeography::NAME => field
+            .try_extension_type::<SphericalGeography>()
+            .map(|_| "sphericalgeography"),
+        Json::NAME => field.try_extension_type::<Json>().map(|_| "json"),
+        Uuid::NAME => field.try_extension_type::<Uuid>().map(|_| "uuid"),
+        IpAddress::NAME => field.try_extension_type::<IpAddress>().map(|_| "ipaddress"),
+        HyperLogLog::NAME => field
+            .try_extension_type::<HyperLogLog>()
+            .map(|_| "hyperloglog"),
+        QDigest::NAME => {
+            let qdigest = field.try_extension_type::<QDigest>().ok()?;
+            return Some(match qdigest.value_type() {
+                Some(value_type) => format!("qdigest({value_type})"),
+                None => "qdigest".to_string(),
+            });
+        }
+        TDigest::NAME => field.try_extension_type::<TDigest>().map(|_| "tdigest"),
+        SetDigest::NAME => field.try_extension_type::<SetDigest>().map(|_| "setdigest"),
+        BingTileType::NAME => field
+            .try_extension_type::<BingTileType>()
+            .map(|_| "bingtile"),
+        Color::NAME => field.try_extension_type::<Color>().map(|_| "color"),
+        ObjectId::NAME => field.try_extension_type::<ObjectId>().map(|_| "objectid"),
+        _ => return None,
+    };
+    name.ok().map(str::to_string)
+}
+
+/// A Bing tile: its coordinates at a zoom level from 0 to 23.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BingTile {
+    pub x: u32,
+    pub y: u32,
+    pub zoom_level: u8,
+}
+
+impl BingTile {
+    const MAX_ZOOM_LEVEL: u8 = 23;
+    const BITS_23: i64 = (1 << 23) - 1;
+    const BITS_5: i64 = (1 << 5) - 1;
+
+    /// The tile at `x`, `y` at `zoom_level`, if the coordinates are within the zoom level.
+    pub fn new(x: u32, y: u32, zoom_level: u8) -> Option<Self> {
+        let size = 1u64 << zoom_level.min(Self::MAX_ZOOM_LEVEL);
+        (zoom_level <= Self::MAX_ZOOM_LEVEL && u64::from(x) < size && u64::from(y) < size)
+            .then_some(BingTile { x, y, zoom_level })
+    }
+
+    /// The encoding of Trino (version 0): `x` in bits 32 to 54, `y` in bits 5 to 27 and the zoom
+    /// level in bits 0 to 4.
+    pub fn encode(&self) -> i64 {
+        (i64::from(self.x) << 32) | (i64::from(self.y) << 5) | i64::from(self.zoom_level)
+    }
+
+    /// The tile encoded as `tile` by [`BingTile::encode`].
+    pub fn decode(tile: i64) -> Result<Self> {
+        let version = (tile >> 58) & Self::BITS_5;
+        if version != 0 {
+            return exec_err!("Unknown Bing tile encoding version: {version}");
+        }
+        let x = ((tile >> 32) & Self::BITS_23) as u32;
+        let y = ((tile >> 5) & Self::BITS_23) as u32;
+        let zoom_level = (tile & Self::BITS_5) as u8;
+        match BingTile::new(x, y, zoom_level) {
+            Some(tile) => Ok(tile),
+            None => exec_err!("Invalid Bing tile: x {x}, y {y}, zoom level {zoom_level}"),
+        }
+    }
+}
+
+/// The storage of the Trino type `trino_type` of the text representations `values`, as cast from
+/// varchar by Trino. Only `json`, `uuid`, `ipaddress` and `objectid` have one.
+pub fn from_text(trino_type: &str, values: &StringArray) -> Result<ArrayRef> {
+    let res: ArrayRef = match trino_type {
+        "json" => Arc::new(values.clone()),
+        "uuid" => Arc::new(fixed_size_binary_from_text(values, 16, parse_uuid)?),
+        "ipaddress" => Arc::new(fixed_size_binary_from_text(values, 16, parse_ipaddress)?),
+        "objectid" => Arc::new(fixed_size_binary_from_text(values, 12, parse_objectid)?),
+        _ => return exec_err!("Cannot cast varchar to {trino_type}"),
+    };
+    Ok(res)
+}
+
+/// The text representations of `values` of the Trino type `trino_type`, as cast to varchar by
+/// Trino. Only `json`, `uuid`, `ipaddress` and `objectid` have one.
+pub fn to_text(trino_type: &str, values: &dyn Array) -> Result<StringArray> {
+    match trino_type {
+        "json" => Ok(as_string_array(values)?.clone()),
+        "uuid" => fixed_size_binary_to_text(values, format_uuid),
+        "ipaddress" => fixed_size_binary_to_text(values, format_ipaddress),
+        "objectid" => fixed_size_binary_to_text(values, hex),
+        _ => exec_err!("Cannot cast {trino_type} to varchar"),
+    }
+}
+
+fn fixed_size_binary_from_text<const N: usize>(
+    values: &StringArray,
+    size: i32,
+    parse: fn(&str) -> Result<[u8; N]>,
+) -> Result<FixedSizeBinaryArray> {
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(values.len(), size);
+    for value in values {
+        match value {
+            Some(value) => builder.append_value(parse(value)?)?,
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn fixed_size_binary_to_text(
+    values: &dyn Array,
+    format: fn(&[u8]) -> String,
+) -> Result<StringArray> {
+    Ok(as_fixed_size_binary_array(values)?
+        .iter()
+        .map(|value| value.map(format))
+        .collect())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn parse_hex<const N: usize>(text: &str) -> Option<[u8; N]> {
+    if text.len() != 2 * N || !text.is_ascii() {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&text[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+fn parse_uuid(text: &str) -> Result<[u8; 16]> {
+    let groups = text.split('-').map(str::len).collect::<Vec<_>>();
+    match parse_hex(&text.replace('-', "")) {
+        Some(bytes) if groups == [8, 4, 4, 4, 12] => Ok(bytes),
+        _ => exec_err!("Cannot cast value to UUID: {text}"),
+    }
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex = hex(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn parse_ipaddress(text: &str) -> Result<[u8; 16]> {
+    match text.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => Ok(ip.to_ipv6_mapped().octets()),
+        Ok(IpAddr::V6(ip)) => Ok(ip.octets()),
+        Err(_) => exec_err!("Cannot cast value to IPADDRESS: {text}"),
+    }
+}
+
+fn format_ipaddress(bytes: &[u8]) -> String {
+    let octets: [u8; 16] = bytes.try_into().unwrap_or_default();
+    Ipv6Addr::from(octets).to_canonical().to_string()
+}
+
+fn parse_objectid(text: &str) -> Result<[u8; 12]> {
+    match parse_hex(text) {
+        Some(bytes) => Ok(bytes),
+        None => exec_err!("Invalid ObjectId: {text}"),
+    }
+}
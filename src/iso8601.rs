@@ -0,0 +1,331 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! ISO 8601 dates and date-times, as read by Trino's `from_iso8601_date` and
+//! `from_iso8601_timestamp` (with Joda-Time's ISO parsers) and written by `to_iso8601`.
+//!
+//! The grammar of the date-times:
+//! ```text
+//! datetime       = time | date ['T' [time-element] [offset]]
+//! time           = 'T' time-element [offset]
+//! date           = yyyy ['-' MM ['-' dd]] | yyyy '-' DDD | xxxx '-W' ww ['-' e]
+//! time-element   = HH [':' mm [':' ss]] [fraction]
+//! fraction       = ('.' | ',') digit+
+//! offset         = 'Z' | ('+' | '-') HH [[':'] mm [[':'] ss [fraction]]]
+//! ```
+//! The fraction is a fraction of the last field, e.g. `T10.5` is `10:30`, and is read to the
+//! nanosecond.
+
+use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Weekday,
+};
+use datafusion::common::{exec_datafusion_err, DataFusionError, Result};
+
+use crate::datetime_pattern::invalid_format;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// A date-time read from an ISO 8601 text: its local date-time and its offset from UTC, if any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct IsoDateTime {
+    pub(crate) local: NaiveDateTime,
+    pub(crate) offset: Option<TimeDelta>,
+}
+
+impl IsoDateTime {
+    /// The instant of the date-time in UTC, its local date-time being in UTC if it has no offset.
+    pub(crate) fn utc(&self) -> NaiveDateTime {
+        self.local - self.offset.unwrap_or_default()
+    }
+}
+
+/// The date of the ISO 8601 text `text`, e.g. `2024-03-05`, `2024-W10-2` or `2024-065`.
+pub(crate) fn parse_date(text: &str) -> Result<NaiveDate> {
+    let mut parser = Parser::new(text);
+    let date = parser.date()?;
+    parser.end()?;
+    Ok(date)
+}
+
+/// The date-time of the ISO 8601 text `text`, e.g. `2024-03-05T14:07:09.123+01:00`.
+pub(crate) fn parse_datetime(text: &str) -> Result<IsoDateTime> {
+    let mut parser = Parser::new(text);
+    let (date, with_time) = match parser.peek() {
+        Some(b'T' | b't') => (DateTime::UNIX_EPOCH.date_naive(), true),
+        _ => {
+            let date = parser.date()?;
+            (date, parser.peek().is_some())
+        }
+    };
+    let (time, offset) = match with_time {
+        true => {
+            parser.expect(|b| matches!(b, b'T' | b't'))?;
+            let time = match parser.peek() {
+                Some(b'0'..=b'9') => parser.time()?,
+                _ => TimeDelta::zero(),
+            };
+            let offset = match parser.peek() {
+                Some(_) => Some(parser.offset()?),
+                None => None,
+            };
+            (time, offset)
+        }
+        false => (TimeDelta::zero(), None),
+    };
+    parser.end()?;
+    Ok(IsoDateTime {
+        local: date.and_time(NaiveTime::MIN) + time,
+        offset,
+    })
+}
+
+/// The ISO 8601 text of the date `date`, e.g. `2024-03-05`.
+pub(crate) fn format_date(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// The ISO 8601 text of the local date-time `local` with `digits` fractional digits of the second,
+/// followed by its offset from UTC, if any, e.g. `2024-03-05T14:07:09.123+01:00`.
+pub(crate) fn format_datetime(local: &NaiveDateTime, digits: u32, offset: Option<i32>) -> String {
+    let mut text = local.format("%Y-%m-%dT%H:%M:%S").to_string();
+    if digits > 0 {
+        let fraction = local.nanosecond() % NANOS_PER_SECOND as u32 / 10u32.pow(9 - digits);
+        text.push_str(&format!(".{fraction:0width$}", width = digits as usize));
+    }
+    match offset {
+        None => {}
+        Some(0) => text.push('Z'),
+        Some(seconds) => {
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let seconds = seconds.unsigned_abs();
+            let (hours, minutes) = (seconds / 3600, seconds / 60 % 60);
+            text.push_str(&format!("{sign}{hours:02}:{minutes:02}"));
+            if seconds % 60 != 0 {
+                text.push_str(&format!(":{:02}", seconds % 60));
+            }
+        }
+    }
+    text
+}
+
+/// A cursor over an ISO 8601 text.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self) -> DataFusionError {
+        invalid_format(self.text, self.pos)
+    }
+
+    /// Skip a byte matching `expected`.
+    fn expect(&mut self, expected: impl Fn(u8) -> bool) -> Result<()> {
+        match self.peek() {
+            Some(b) if expected(b) => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Skip a byte matching `expected`, if any.
+    fn skip(&mut self, expected: impl Fn(u8) -> bool) -> bool {
+        self.expect(expected).is_ok()
+    }
+
+    fn end(&self) -> Result<()> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error()),
+        }
+    }
+
+    /// The number of `min_digits` to `max_digits` digits at the cursor.
+    fn number(&mut self, min_digits: usize, max_digits: usize) -> Result<i64> {
+        let digits = self.text.as_bytes()[self.pos..]
+            .iter()
+            .take(max_digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits < min_digits {
+            return Err(self.error());
+        }
+        let number = &self.text[self.pos..self.pos + digits];
+        self.pos += digits;
+        number.parse().map_err(|_| self.error())
+    }
+
+    /// The number of exactly `digits` digits, which must be in `min..=max`, for the Joda-Time
+    /// field `field`.
+    fn field(&mut self, digits: usize, field: &str, min: i64, max: i64) -> Result<i64> {
+        let value = self.number(digits, digits)?;
+        if !(min..=max).contains(&value) {
+            return Err(exec_datafusion_err!(
+                "Cannot parse \"{}\": Value {value} for {field} must be in the range [{min},{max}]",
+                self.text
+            ));
+        }
+        Ok(value)
+    }
+
+    /// The fraction at the cursor, if any, of a unit of `unit_nanos` nanoseconds, in nanoseconds.
+    fn fraction(&mut self, unit_nanos: i64) -> Result<i64> {
+        if !self.skip(|b| matches!(b, b'.' | b',')) {
+            return Ok(0);
+        }
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        let digits = &self.text[start..self.pos];
+        if digits.is_empty() {
+            return Err(self.error());
+        }
+        // The digits beyond the nanosecond of the longest unit do not change the result
+        let (numerator, denominator) =
+            digits
+                .bytes()
+                .take(18)
+                .fold((0i128, 1i128), |(numerator, denominator), digit| {
+                    (numerator * 10 + i128::from(digit - b'0'), denominator * 10)
+                });
+        Ok((numerator * i128::from(unit_nanos) / denominator) as i64)
+    }
+
+    fn date(&mut self) -> Result<NaiveDate> {
+        let negative = matches!(self.peek(), Some(b'-'));
+        if matches!(self.peek(), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+        let year = self.number(4, 9)? * if negative { -1 } else { 1 };
+        let year = i32::try_from(year).map_err(|_| self.error())?;
+        let text = self.text;
+        let invalid = || exec_datafusion_err!("Cannot parse \"{text}\": Invalid date");
+        if !self.skip(|b| b == b'-') {
+            return NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(invalid);
+        }
+        if self.skip(|b| b == b'W') {
+            let week = self.field(2, "weekOfWeekyear", 1, 53)? as u32;
+            let weekday = match self.skip(|b| b == b'-') {
+                true => self.field(1, "dayOfWeek", 1, 7)?,
+                false => 1,
+            };
+            let weekday = Weekday::try_from(weekday as u8 - 1).map_err(|_| self.error())?;
+            return NaiveDate::from_isoywd_opt(year, week, weekday).ok_or_else(|| {
+                exec_datafusion_err!(
+                    "Cannot parse \"{}\": Value {week} for weekOfWeekyear must be in the range [1,{}]",
+                    self.text,
+                    weeks_in_year(year)
+                )
+            });
+        }
+        let digits = self.text.as_bytes()[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 3 {
+            let last_day = days_in_year(year);
+            let day = self.field(3, "dayOfYear", 1, last_day)? as u32;
+            return NaiveDate::from_yo_opt(year, day).ok_or_else(invalid);
+        }
+        let month = self.field(2, "monthOfYear", 1, 12)? as u32;
+        if !self.skip(|b| b == b'-') {
+            return NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(invalid);
+        }
+        let last_day = days_in_month(year, month);
+        let day = self.field(2, "dayOfMonth", 1, last_day)? as u32;
+        NaiveDate::from_ymd_opt(year, month, day).ok_or_else(invalid)
+    }
+
+    /// The time since midnight at the cursor.
+    fn time(&mut self) -> Result<TimeDelta> {
+        const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+        const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+        let hours = self.field(2, "hourOfDay", 0, 23)?;
+        let mut nanos = hours * NANOS_PER_HOUR;
+        if self.skip(|b| b == b':') {
+            nanos += self.field(2, "minuteOfHour", 0, 59)? * NANOS_PER_MINUTE;
+            if self.skip(|b| b == b':') {
+                nanos += self.field(2, "secondOfMinute", 0, 59)? * NANOS_PER_SECOND;
+                nanos += self.fraction(NANOS_PER_SECOND)?;
+            } else {
+                nanos += self.fraction(NANOS_PER_MINUTE)?;
+            }
+        } else {
+            nanos += self.fraction(NANOS_PER_HOUR)?;
+        }
+        Ok(TimeDelta::nanoseconds(nanos))
+    }
+
+    /// The offset from UTC at the cursor.
+    fn offset(&mut self) -> Result<TimeDelta> {
+        if self.skip(|b| matches!(b, b'Z' | b'z')) {
+            return Ok(TimeDelta::zero());
+        }
+        let negative = match self.peek() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(self.error()),
+        };
+        self.pos += 1;
+        let mut seconds = self.field(2, "hourOfDay", 0, 23)? * 3600;
+        let mut nanos = 0;
+        let separator = |parser: &mut Self| {
+            parser.skip(|b| b == b':');
+            matches!(parser.peek(), Some(b'0'..=b'9'))
+        };
+        if separator(self) {
+            seconds += self.field(2, "minuteOfHour", 0, 59)? * 60;
+            if separator(self) {
+                seconds += self.field(2, "secondOfMinute", 0, 59)?;
+                nanos = self.fraction(NANOS_PER_SECOND)?;
+            }
+        }
+        let offset = TimeDelta::seconds(seconds) + TimeDelta::nanoseconds(nanos);
+        Ok(if negative { -offset } else { offset })
+    }
+}
+
+fn days_in_year(year: i32) -> i64 {
+    match NaiveDate::from_ymd_opt(year, 2, 29) {
+        Some(_) => 366,
+        None => 365,
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    (28..=31)
+        .rev()
+        .find(|&day| NaiveDate::from_ymd_opt(year, month, day).is_some())
+        .map_or(28, i64::from)
+}
+
+fn weeks_in_year(year: i32) -> u32 {
+    NaiveDate::from_ymd_opt(year, 12, 28)
+        .map(|date| date.iso_week().week())
+        .unwrap_or(52)
+}
@@ -0,0 +1,470 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regular expressions with the syntax of Trino's `joniregexp` type, i.e. of Joni in Java mode.
+//!
+//! Patterns are matched by `fancy_regex`, a backtracking engine supporting look-around,
+//! backreferences, atomic groups and possessive quantifiers. The Java constructs it does not know
+//! are translated first:
+//! - `\Q...\E` quoting (outside of character classes, as in Joni),
+//! - the Java (`\p{javaLowerCase}`), POSIX (`\p{Alpha}`) and `Is` (`\p{IsLatin}`) properties,
+//! - the `\h`, `\v` and `\R` line and space classes, the `\0` octal and `\c` control escapes,
+//! - `$`, which also matches before a final line terminator unless in multi-line mode.
+//!
+//! Constructs that have no equivalent (e.g. `\G` or Unicode blocks) are reported as errors.
+//! Replacement strings use the Java syntax as well, see [`Replacement`].
+
+use datafusion::common::{exec_err, Result};
+use datafusion::error::DataFusionError;
+use fancy_regex::{Captures, Match, Regex};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Horizontal whitespace, `\h` in Java.
+const HORIZONTAL_SPACE: &str =
+    r"[ \t\xA0\x{1680}\x{180E}\x{2000}-\x{200A}\x{202F}\x{205F}\x{3000}]";
+/// Vertical whitespace, `\v` in Java.
+const VERTICAL_SPACE: &str = r"[\n\x0B\f\r\x{85}\x{2028}\x{2029}]";
+/// A line break, `\R` in Java.
+const LINE_BREAK: &str = r"(?:\r\n|[\n\x0B\f\r\x{85}\x{2028}\x{2029}])";
+
+/// A compiled `joniregexp` pattern.
+pub(super) struct JoniRegex {
+    regex: Regex,
+}
+
+impl JoniRegex {
+    /// Compile the Java-syntax `pattern`.
+    pub(super) fn new(pattern: &str) -> Result<Self> {
+        let translated = translate(pattern).map_err(|reason| invalid_pattern(pattern, reason))?;
+        let regex = Regex::new(&translated).map_err(|e| invalid_pattern(pattern, e.to_string()))?;
+        Ok(Self { regex })
+    }
+
+    /// The number of capturing groups, not counting the whole match.
+    pub(super) fn group_count(&self) -> usize {
+        self.regex.captures_len() - 1
+    }
+
+    /// The group `group` as an index, checking that it exists.
+    pub(super) fn group_index_of(&self, group: i64) -> Result<usize> {
+        if group < 0 {
+            return exec_err!("Group cannot be negative");
+        }
+        match usize::try_from(group) {
+            Ok(index) if index <= self.group_count() => Ok(index),
+            _ => exec_err!(
+                "Pattern has {} groups. Cannot access group {group}",
+                self.group_count()
+            ),
+        }
+    }
+
+    pub(super) fn is_match(&self, hay: &str) -> Result<bool> {
+        self.regex.is_match(hay).map_err(|e| self.match_error(e))
+    }
+
+    /// The first match starting at or after the byte offset `start`.
+    pub(super) fn find_at<'t>(&self, hay: &'t str, start: usize) -> Result<Option<Match<'t>>> {
+        self.regex
+            .find_from_pos(hay, start)
+            .map_err(|e| self.match_error(e))
+    }
+
+    pub(super) fn find_iter<'r, 't>(
+        &'r self,
+        hay: &'t str,
+    ) -> impl Iterator<Item = Result<Match<'t>>> + 'r
+    where
+        't: 'r,
+    {
+        self.regex
+            .find_iter(hay)
+            .map(|m| m.map_err(|e| self.match_error(e)))
+    }
+
+    pub(super) fn captures<'t>(&self, hay: &'t str) -> Result<Option<Captures<'t>>> {
+        self.regex.captures(hay).map_err(|e| self.match_error(e))
+    }
+
+    pub(super) fn captures_iter<'r, 't>(
+        &'r self,
+        hay: &'t str,
+    ) -> impl Iterator<Item = Result<Captures<'t>>> + 'r
+    where
+        't: 'r,
+    {
+        self.regex
+            .captures_iter(hay)
+            .map(|c| c.map_err(|e| self.match_error(e)))
+    }
+
+    /// The substrings of `hay` between the matches, including the trailing empty ones.
+    pub(super) fn split<'t>(&self, hay: &'t str) -> Result<Vec<&'t str>> {
+        self.regex
+            .split(hay)
+            .map(|s| s.map_err(|e| self.match_error(e)))
+            .collect()
+    }
+
+    /// Replace every match in `hay` with `replacement`.
+    pub(super) fn replace_all(&self, hay: &str, replacement: &Replacement) -> Result<String> {
+        let mut res = String::with_capacity(hay.len());
+        let mut last = 0;
+        for captures in self.captures_iter(hay) {
+            let captures = captures?;
+            let Some(m) = captures.get(0) else {
+                continue;
+            };
+            res.push_str(&hay[last..m.start()]);
+            replacement.expand(&captures, &mut res);
+            last = m.end();
+        }
+        res.push_str(&hay[last..]);
+        Ok(res)
+    }
+
+    /// The index of the group named `name`.
+    fn group_index(&self, name: &str) -> Option<usize> {
+        self.regex
+            .capture_names()
+            .position(|group| group == Some(name))
+    }
+
+    fn match_error(&self, e: fancy_regex::Error) -> DataFusionError {
+        DataFusionError::Execution(format!(
+            "Regular expression '{}' could not be matched: {e}",
+            self.regex.as_str()
+        ))
+    }
+}
+
+fn invalid_pattern(pattern: &str, reason: String) -> DataFusionError {
+    DataFusionError::Execution(format!("Invalid regular expression '{pattern}': {reason}"))
+}
+
+/// A replacement string of `regexp_replace`, in the Java syntax of Trino:
+/// `$n` is the `n`th group (as many digits as name an existing group), `${name}` a named group,
+/// and `\` makes the next character literal.
+pub(super) struct Replacement {
+    pieces: Vec<Piece>,
+}
+
+enum Piece {
+    Literal(String),
+    Group(usize),
+}
+
+impl Replacement {
+    /// Parse `replacement`, whose group references must exist in `regex`.
+    pub(super) fn new(replacement: &str, regex: &JoniRegex) -> Result<Self> {
+        let illegal =
+            || DataFusionError::Execution(format!("Illegal replacement sequence: {replacement}"));
+        let mut pieces = vec![];
+        let mut literal = String::new();
+        let mut chars = replacement.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => literal.push(chars.next().ok_or_else(illegal)?),
+                '$' => {
+                    let group = match chars.next() {
+                        Some('{') => {
+                            let mut name = String::new();
+                            loop {
+                                match chars.next() {
+                                    Some('}') => break,
+                                    Some(c) if c.is_ascii_alphanumeric() => name.push(c),
+                                    _ => return Err(illegal()),
+                                }
+                            }
+                            regex.group_index(&name).ok_or_else(|| {
+                                DataFusionError::Execution(format!(
+                                    "Illegal replacement sequence: unknown group {{ {name} }}"
+                                ))
+                            })?
+                        }
+                        Some(c) if c.is_ascii_digit() => {
+                            let mut group = c as usize - '0' as usize;
+                            if group > regex.group_count() {
+                                return exec_err!(
+                                    "Illegal replacement sequence: unknown group {group}"
+                                );
+                            }
+                            // The longest number naming a group
+                            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                                let longer = group * 10 + digit as usize;
+                                if longer > regex.group_count() {
+                                    break;
+                                }
+                                group = longer;
+                                chars.next();
+                            }
+                            group
+                        }
+                        _ => return Err(illegal()),
+                    };
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(Piece::Group(group));
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Ok(Self { pieces })
+    }
+
+    /// The empty replacement, which removes the matches.
+    pub(super) fn empty() -> Self {
+        Self { pieces: vec![] }
+    }
+
+    fn expand(&self, captures: &Captures, res: &mut String) {
+        for piece in &self.pieces {
+            match piece {
+                Piece::Literal(literal) => res.push_str(literal),
+                // A group that did not participate in the match is replaced by nothing
+                Piece::Group(group) => {
+                    if let Some(m) = captures.get(*group) {
+                        res.push_str(m.as_str());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Translate the Java-syntax `pattern` into the syntax of `fancy_regex`.
+fn translate(pattern: &str) -> std::result::Result<String, String> {
+    let mut res = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    let mut class_depth = 0;
+    // Whether the multi-line mode is enabled in the current group, and in the enclosing ones
+    let mut multi_line = false;
+    let mut enclosing = Vec::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| "unexpected end of pattern after \\".to_string())?;
+                translate_escape(escaped, &mut chars, class_depth > 0, &mut res)?;
+            }
+            '[' => {
+                class_depth += 1;
+                res.push('[');
+            }
+            ']' if class_depth > 0 => {
+                class_depth -= 1;
+                res.push(']');
+            }
+            '(' if class_depth == 0 => {
+                res.push('(');
+                match inline_flags(&mut chars, &mut res) {
+                    // `(?m)` applies to the rest of the enclosing group
+                    Some((flags, ')')) => multi_line = flags.unwrap_or(multi_line),
+                    // `(?m:...)` to the group only
+                    Some((flags, _)) => {
+                        enclosing.push(multi_line);
+                        multi_line = flags.unwrap_or(multi_line);
+                    }
+                    None => enclosing.push(multi_line),
+                }
+            }
+            ')' if class_depth == 0 => {
+                multi_line = enclosing.pop().unwrap_or(multi_line);
+                res.push(')');
+            }
+            '$' if class_depth == 0 && !multi_line => res.push_str(r"\Z"),
+            c => res.push(c),
+        }
+    }
+    Ok(res)
+}
+
+/// Translate the escape sequence `\escaped`, followed by `chars`.
+fn translate_escape(
+    escaped: char,
+    chars: &mut Peekable<Chars>,
+    in_class: bool,
+    res: &mut String,
+) -> std::result::Result<(), String> {
+    match escaped {
+        // As in Joni, quoting does not apply in character classes, where `\Q` is `Q`
+        'Q' | 'E' if in_class => res.push(escaped),
+        'Q' => {
+            let mut quoted = String::new();
+            while let Some(c) = chars.next() {
+                if c == '\\' && chars.peek() == Some(&'E') {
+                    chars.next();
+                    break;
+                }
+                quoted.push(c);
+            }
+            res.push_str(&fancy_regex::escape(&quoted));
+        }
+        'E' => {}
+        'p' | 'P' => {
+            let name = match chars.next() {
+                Some('{') => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err(format!("unclosed property \\{escaped}{{{name}")),
+                        }
+                    }
+                    name
+                }
+                Some(c) => c.to_string(),
+                None => return Err(format!("unexpected end of pattern after \\{escaped}")),
+            };
+            res.push_str(&property(&name, escaped == 'P')?);
+        }
+        'h' => res.push_str(HORIZONTAL_SPACE),
+        'H' => res.push_str(&negate(HORIZONTAL_SPACE)),
+        'v' => res.push_str(VERTICAL_SPACE),
+        'V' => res.push_str(&negate(VERTICAL_SPACE)),
+        'R' if !in_class => res.push_str(LINE_BREAK),
+        '0' => {
+            // Up to three octal digits, the first of three being at most 3
+            let mut value = 0;
+            let mut digits = 0;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(8)) {
+                if digits == 3 || (digits == 2 && value > 0o37) {
+                    break;
+                }
+                value = value * 8 + digit;
+                digits += 1;
+                chars.next();
+            }
+            if digits == 0 {
+                return Err("illegal octal escape sequence \\0".to_string());
+            }
+            res.push_str(&format!(r"\x{{{value:X}}}"));
+        }
+        'c' => {
+            let c = chars
+                .next()
+                .ok_or_else(|| "illegal control escape sequence \\c".to_string())?;
+            res.push_str(&format!(r"\x{{{:X}}}", c as u32 ^ 64));
+        }
+        'u' => {
+            let hex = chars.by_ref().take(4).collect::<String>();
+            if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!("illegal Unicode escape sequence \\u{hex}"));
+            }
+            res.push_str(&format!(r"\x{{{hex}}}"));
+        }
+        'G' => return Err("\\G (end of the previous match) is not supported".to_string()),
+        'X' => return Err("\\X (grapheme cluster) is not supported".to_string()),
+        c => {
+            res.push('\\');
+            res.push(c);
+        }
+    }
+    Ok(())
+}
+
+/// The class of the Java property `\p{name}`, or `\P{name}` if `negated`.
+fn property(name: &str, negated: bool) -> std::result::Result<String, String> {
+    let class = match name {
+        // POSIX classes, US-ASCII only
+        "Lower" => "[a-z]",
+        "Upper" => "[A-Z]",
+        "ASCII" => r"[\x00-\x7F]",
+        "Alpha" => "[a-zA-Z]",
+        "Digit" => "[0-9]",
+        "Alnum" => "[a-zA-Z0-9]",
+        "Punct" => r"[!-/:-@\[-`{-~]",
+        "Graph" => "[!-~]",
+        "Print" => "[ -~]",
+        "Blank" => r"[ \t]",
+        "Cntrl" => r"[\x00-\x1F\x7F]",
+        "XDigit" => "[0-9a-fA-F]",
+        "Space" => r"[ \t\n\x0B\f\r]",
+        // java.lang.Character classes
+        "javaLowerCase" => r"[\p{Lowercase}]",
+        "javaUpperCase" => r"[\p{Uppercase}]",
+        "javaTitleCase" => r"[\p{Lt}]",
+        "javaDigit" => r"[\p{Nd}]",
+        "javaLetter" => r"[\p{L}]",
+        "javaLetterOrDigit" => r"[\p{L}\p{Nd}]",
+        "javaAlphabetic" => r"[\p{Alphabetic}]",
+        "javaIdeographic" => r"[\p{Ideographic}]",
+        "javaSpaceChar" => r"[\p{Z}]",
+        "javaWhitespace" => r"[\t-\r\x1C-\x1F\p{Zs}\p{Zl}\p{Zp}--\xA0\x{2007}\x{202F}]",
+        "javaISOControl" => r"[\x00-\x1F\x7F-\x9F]",
+        "javaMirrored" => r"[\p{Bidi_Mirrored}]",
+        "javaDefined" => r"[\P{Cn}]",
+        _ if name.starts_with("java") => {
+            return Err(format!("property \\p{{{name}}} is not supported"))
+        }
+        _ if name.starts_with("In") || name.starts_with("block=") || name.starts_with("blk=") => {
+            return Err(format!(
+                "Unicode blocks are not supported, as in \\p{{{name}}}; use a script instead"
+            ))
+        }
+        // Scripts, categories and binary properties, e.g. `IsLatin`, `IsLu`, `IsAlphabetic`
+        _ => {
+            let name = name.strip_prefix("Is").unwrap_or(name);
+            let escape = if negated { 'P' } else { 'p' };
+            return Ok(format!(r"\{escape}{{{name}}}"));
+        }
+    };
+    Ok(if negated {
+        negate(class)
+    } else {
+        class.to_string()
+    })
+}
+
+/// The complement of the character class `class`.
+fn negate(class: &str) -> String {
+    format!("[^{}", &class[1..])
+}
+
+/// Read the inline flags following `(`, e.g. `?im-s:` or `?m)`, into `res`, returning whether
+/// they enable or disable the multi-line mode and the `:` or `)` ending them. `None` if the group
+/// has no flags.
+fn inline_flags(chars: &mut Peekable<Chars>, res: &mut String) -> Option<(Option<bool>, char)> {
+    let mut lookahead = chars.clone();
+    lookahead.next().filter(|&c| c == '?')?;
+    let mut flags = String::new();
+    let end = lookahead.find(|&c| {
+        flags.push(c);
+        !(c.is_ascii_alphabetic() || c == '-')
+    })?;
+    if !matches!(end, ':' | ')') || (flags.len() == 1 && end == ':') {
+        return None;
+    }
+    let (on, off) = flags.split_once('-').unwrap_or((&flags, ""));
+    let multi_line = if off.contains('m') {
+        Some(false)
+    } else {
+        on.contains('m').then_some(true)
+    };
+    res.push('?');
+    res.push_str(&flags[..flags.len() - 1]);
+    *chars = lookahead;
+    res.push(end);
+    Some((multi_line, end))
+}
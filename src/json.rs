@@ -0,0 +1,477 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! JSON values as Trino reads and prints them, with Jackson.
+//!
+//! Documents are parsed strictly (no comments, trailing commas or non-finite numbers), keeping
+//! the order of the members of objects, and their duplicate keys. Numbers keep the text they are
+//! written with, which `json_extract_scalar` returns, but are printed as Jackson prints them:
+//! integers exactly, and the others as doubles in the format of Java's `Double.toString`, e.g.
+//! `1e2` as `100.0` and `1e7` as `1.0E7`.
+//!
+//! [`Json::canonical`] is the value `json_parse` makes of a document: the members of its objects
+//! sorted by key, the last of duplicate keys winning.
+
+use std::fmt::Write;
+
+/// The maximum nesting depth of arrays and objects, as in Jackson.
+const MAX_DEPTH: usize = 1000;
+
+/// A JSON value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Json>),
+    /// The members of an object, in order
+    Object(Vec<(String, Json)>),
+}
+
+/// A JSON number, as written in the document.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Number {
+    text: String,
+    /// Whether the number has neither a fraction nor an exponent
+    integer: bool,
+}
+
+impl Number {
+    /// The text of the number in the document.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The value of an integer that fits in an `i64`.
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        self.integer.then(|| self.text.parse().ok()).flatten()
+    }
+
+    pub(crate) fn as_f64(&self) -> f64 {
+        // Always valid, by the grammar of JSON numbers
+        self.text.parse().unwrap_or(f64::NAN)
+    }
+
+    fn write(&self, out: &mut String) {
+        if !self.integer {
+            write_double(self.as_f64(), out);
+        } else if self.text == "-0" {
+            out.push('0');
+        } else {
+            out.push_str(&self.text);
+        }
+    }
+}
+
+impl Json {
+    /// Parse a JSON document, failing with the reason if it is not valid.
+    pub(crate) fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser {
+            bytes: text.as_bytes(),
+            text,
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.bytes.len() {
+            return Err(parser.unexpected("trailing token"));
+        }
+        Ok(value)
+    }
+
+    /// The value with the members of its objects sorted by key, the last of duplicate keys
+    /// winning.
+    pub(crate) fn canonical(self) -> Json {
+        match self {
+            Json::Array(elements) => {
+                Json::Array(elements.into_iter().map(Json::canonical).collect())
+            }
+            Json::Object(members) => {
+                let mut members = members
+                    .into_iter()
+                    .map(|(key, value)| (key, value.canonical()))
+                    .collect::<Vec<_>>();
+                // Stable, so that the last of duplicate keys is the last of its run
+                members.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut deduplicated: Vec<(String, Json)> = Vec::with_capacity(members.len());
+                for (key, value) in members {
+                    match deduplicated.last_mut() {
+                        Some(last) if last.0 == key => last.1 = value,
+                        _ => deduplicated.push((key, value)),
+                    }
+                }
+                Json::Object(deduplicated)
+            }
+            scalar => scalar,
+        }
+    }
+
+    pub(crate) fn is_scalar(&self) -> bool {
+        !matches!(self, Json::Array(_) | Json::Object(_))
+    }
+
+    /// The number of elements of an array or members of an object, 0 for scalars.
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Json::Array(elements) => elements.len(),
+            Json::Object(members) => members.len(),
+            _ => 0,
+        }
+    }
+
+    /// The text of a scalar other than `null`: a string unquoted, a number as written in the
+    /// document.
+    pub(crate) fn scalar_text(&self) -> Option<String> {
+        match self {
+            Json::Bool(b) => Some(b.to_string()),
+            Json::Number(n) => Some(n.text().to_string()),
+            Json::String(s) => Some(s.clone()),
+            Json::Null | Json::Array(_) | Json::Object(_) => None,
+        }
+    }
+
+    /// The compact JSON text of the value.
+    pub(crate) fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out);
+        out
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Json::Number(n) => n.write(out),
+            Json::String(s) => write_string(s, out),
+            Json::Array(elements) => {
+                out.push('[');
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    element.write(out);
+                }
+                out.push(']');
+            }
+            Json::Object(members) => {
+                out.push('{');
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_string(key, out);
+                    out.push(':');
+                    value.write(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// Quote a string, escaping the quotes, backslashes and control characters as Jackson does.
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04X}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Print a double as Java's `Double.toString` does: in plain notation with at least one
+/// fractional digit between 10^-3 and 10^7, and in scientific notation (`1.0E-5`) otherwise.
+fn write_double(value: f64, out: &mut String) {
+    if !value.is_finite() {
+        // Jackson quotes non-finite numbers
+        let text = if value.is_nan() {
+            "NaN"
+        } else if value > 0.0 {
+            "Infinity"
+        } else {
+            "-Infinity"
+        };
+        write_string(text, out);
+        return;
+    }
+    let abs = value.abs();
+    if abs == 0.0 || (1e-3..1e7).contains(&abs) {
+        let plain = format!("{value:?}");
+        out.push_str(&plain);
+        if !plain.contains('.') {
+            out.push_str(".0");
+        }
+    } else {
+        let scientific = format!("{value:e}");
+        let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        out.push_str(mantissa);
+        if !mantissa.contains('.') {
+            out.push_str(".0");
+        }
+        out.push('E');
+        out.push_str(exponent);
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn unexpected(&self, what: &str) -> String {
+        match self.text[self.pos..].chars().next() {
+            Some(c) => format!("Unexpected {what} '{c}' at position {}", self.pos),
+            None => "Unexpected end of input".to_string(),
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected("character"))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => Ok(Json::String(self.string()?)),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'n') => self.literal("null", Json::Null),
+            _ => Err(self.unexpected("token")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Json, String>) -> Result<Json, String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("Nesting depth exceeds {MAX_DEPTH}"));
+        }
+        let value = parse(self)?;
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    fn literal(&mut self, literal: &str, value: Json) -> Result<Json, String> {
+        if self.text[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(self.unexpected("token"))
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut elements = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(elements));
+        }
+        loop {
+            elements.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(elements));
+                }
+                _ => return Err(self.unexpected("character")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut members = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.unexpected("character"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.unexpected("character")),
+            }
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let int_start = self.pos;
+        match self.digits() {
+            0 => return Err(self.unexpected("character")),
+            n if n > 1 && self.bytes[int_start] == b'0' => {
+                return Err("Leading zeroes are not allowed".to_string())
+            }
+            _ => {}
+        }
+        let mut integer = true;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            integer = false;
+            if self.digits() == 0 {
+                return Err(self.unexpected("character"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            integer = false;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.unexpected("character"));
+            }
+        }
+        Ok(Json::Number(Number {
+            text: self.text[start..self.pos].to_string(),
+            integer,
+        }))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let hex = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| "Invalid \\u escape".to_string())?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(hex, 16).unwrap_or_default())
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut s = String::new();
+        loop {
+            let start = self.pos;
+            while let Some(b) = self.peek() {
+                if b == b'"' || b == b'\\' || b < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            s.push_str(&self.text[start..self.pos]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = self.peek().ok_or_else(|| self.unexpected("escape"))?;
+                    self.pos += 1;
+                    match escaped {
+                        b'"' => s.push('"'),
+                        b'\\' => s.push('\\'),
+                        b'/' => s.push('/'),
+                        b'b' => s.push('\u{8}'),
+                        b'f' => s.push('\u{c}'),
+                        b'n' => s.push('\n'),
+                        b'r' => s.push('\r'),
+                        b't' => s.push('\t'),
+                        b'u' => s.push(self.unicode_escape()?),
+                        _ => {
+                            self.pos -= 1;
+                            return Err(self.unexpected("escape"));
+                        }
+                    }
+                }
+                Some(_) => return Err(self.unexpected("control character")),
+                None => return Err(self.unexpected("end of string")),
+            }
+        }
+    }
+
+    /// The character of a `\u` escape, combining the surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !self.text[self.pos..].starts_with("\\u") {
+                return Err("Unpaired surrogate in \\u escape".to_string());
+            }
+            self.pos += 2;
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err("Unpaired surrogate in \\u escape".to_string());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| "Unpaired surrogate in \\u escape".to_string())
+    }
+}
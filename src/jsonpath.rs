@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! JSON paths with the syntax of Trino's `jsonpath` type, as taken by `json_extract`,
+//! `json_extract_scalar` and `json_size`.
+//!
+//! A path starts at the root `$`, followed by any number of segments:
+//! - `.name`, where the name is made of letters, digits, `_` and `:`,
+//! - `[name]`, where the name is made of letters, digits and `_`, e.g. `[0]`,
+//! - `["name"]`, where the name is any string, `\"` and `\\` escaping quotes and backslashes,
+//! - `.*` or `[*]`, the wildcard.
+//!
+//! As in Trino, a name selects the member of an object with that key, or the element of an array
+//! at that index if it is a number: `$.a.0` and `$["a"][0]` are the same path. A path without a
+//! wildcard selects at most one value; a path with wildcards selects the array of all the values
+//! it matches.
+
+use datafusion::common::{exec_err, Result};
+use std::borrow::Cow;
+
+use crate::json::Json;
+
+/// A segment of a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// The member of an object with this key, or the element of an array at this index
+    Name(String),
+    /// All the members of an object, or all the elements of an array
+    Wildcard,
+}
+
+/// A parsed JSON path.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+impl JsonPath {
+    pub(crate) fn parse(path: &str) -> Result<Self> {
+        let invalid = || exec_err!("Invalid JSON path: '{path}'");
+        let Some(mut rest) = path.strip_prefix('$') else {
+            return invalid();
+        };
+        let mut segments = vec![];
+        while let Some(c) = rest.chars().next() {
+            rest = &rest[c.len_utf8()..];
+            let segment = match c {
+                '.' if rest.starts_with('*') => {
+                    rest = &rest[1..];
+                    Segment::Wildcard
+                }
+                '.' => match take_name(&mut rest, true) {
+                    Some(name) => Segment::Name(name.to_string()),
+                    None => return invalid(),
+                },
+                '[' => {
+                    let segment = if let Some(quoted) = rest.strip_prefix('"') {
+                        rest = quoted;
+                        match take_quoted(&mut rest) {
+                            Some(name) => Segment::Name(name),
+                            None => return invalid(),
+                        }
+                    } else if let Some(after) = rest.strip_prefix('*') {
+                        rest = after;
+                        Segment::Wildcard
+                    } else {
+                        match take_name(&mut rest, false) {
+                            Some(name) => Segment::Name(name.to_string()),
+                            None => return invalid(),
+                        }
+                    };
+                    let Some(after) = rest.strip_prefix(']') else {
+                        return invalid();
+                    };
+                    rest = after;
+                    segment
+                }
+                _ => return invalid(),
+            };
+            segments.push(segment);
+        }
+        Ok(Self { segments })
+    }
+
+    /// The value selected in `json`: the value at the path, or the array of the values matching
+    /// a path with wildcards. `None` if a path without wildcards does not match.
+    pub(crate) fn extract<'a>(&self, json: &'a Json) -> Option<Cow<'a, Json>> {
+        if self.segments.contains(&Segment::Wildcard) {
+            let mut matches = vec![];
+            collect(json, &self.segments, &mut matches);
+            let matches = matches.into_iter().cloned().collect();
+            return Some(Cow::Owned(Json::Array(matches)));
+        }
+        self.segments
+            .iter()
+            .try_fold(json, |value, segment| match segment {
+                Segment::Name(name) => member(value, name),
+                Segment::Wildcard => None,
+            })
+            .map(Cow::Borrowed)
+    }
+}
+
+/// The characters of an unquoted name, and `:` after a dot.
+fn is_name_char(c: char, after_dot: bool) -> bool {
+    c.is_alphanumeric() || c == '_' || (after_dot && c == ':')
+}
+
+fn take_name<'a>(rest: &mut &'a str, after_dot: bool) -> Option<&'a str> {
+    let end = rest
+        .find(|c| !is_name_char(c, after_dot))
+        .unwrap_or(rest.len());
+    let (name, after) = rest.split_at(end);
+    *rest = after;
+    (!name.is_empty()).then_some(name)
+}
+
+/// The name of a quoted subscript, up to its closing quote.
+fn take_quoted(rest: &mut &str) -> Option<String> {
+    let mut name = String::new();
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                *rest = &rest[i + 1..];
+                return Some(name);
+            }
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('"' | '\\'))) => name.push(escaped),
+                _ => return None,
+            },
+            c => name.push(c),
+        }
+    }
+    None
+}
+
+fn member<'a>(value: &'a Json, name: &str) -> Option<&'a Json> {
+    match value {
+        Json::Object(members) => members.iter().find(|(key, _)| key == name).map(|(_, v)| v),
+        Json::Array(elements) if name.bytes().all(|b| b.is_ascii_digit()) => {
+            elements.get(name.parse::<usize>().ok()?)
+        }
+        _ => None,
+    }
+}
+
+fn collect<'a>(value: &'a Json, segments: &[Segment], matches: &mut Vec<&'a Json>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        matches.push(value);
+        return;
+    };
+    match (segment, value) {
+        (Segment::Name(name), _) => {
+            if let Some(value) = member(value, name) {
+                collect(value, rest, matches);
+            }
+        }
+        (Segment::Wildcard, Json::Array(elements)) => {
+            for element in elements {
+                collect(element, rest, matches);
+            }
+        }
+        (Segment::Wildcard, Json::Object(members)) => {
+            for (_, value) in members {
+                collect(value, rest, matches);
+            }
+        }
+        (Segment::Wildcard, _) => {}
+    }
+}
@@ -0,0 +1,911 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lambda expressions, the arguments of higher-order functions such as Trino's
+//! `transform(array, x -> x + 1)`.
+//!
+//! DataFusion neither parses nor plans lambda expressions, and a UDF only ever receives the
+//! values of its arguments. Lambda expressions therefore go through three steps:
+//! - [`LambdaDialect`] parses them, and [`rewrite_lambdas`] rewrites each of them into a call to
+//!   the placeholder function `__lambda(id, body, name, ...)`, replacing the references to its
+//!   parameters in the body with calls to `__lambda_parameter(id, index, name, ...)`. The type of
+//!   a parameter is the one declared by the signature of the higher-order function, e.g. the
+//!   element type `$1` of its `array<$1>` argument, so that the body is planned and coerced as
+//!   any other expression. The type of the lambda expression is a struct wrapping the type of
+//!   its body, which only matches the `function(...)` parameters of the signatures.
+//! - When the call to the higher-order function is simplified, its hand-written `_simplify`
+//!   function compiles each lambda expression into a physical expression over its parameters and
+//!   the values it captures from the enclosing query (its columns, and the parameters of
+//!   enclosing lambda expressions), and replaces the call with a [`HigherOrderUDF`] whose
+//!   arguments are the other arguments of the call followed by the captured values.
+//! - The [`HigherOrderUDF`] calls the lambda expressions on batches of values, typically all the
+//!   elements of the arrays of a batch of rows at once, with the values they capture repeated for
+//!   the elements of each row.
+//!
+//! The placeholder functions are registered along with the functions of the dialect, and fail if
+//! they are ever evaluated: the higher-order functions can only be evaluated in simplified plans.
+
+use crate::overload::{FunctionOverload, Overload};
+use crate::type_signature::{lambda_of, lambda_result, FunctionSignature, SdfType};
+use arrow::array::{ArrayRef, RecordBatch, RecordBatchOptions, UInt32Array};
+use arrow::compute::{cast, take};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion::common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
+use datafusion::common::{
+    exec_err, internal_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
+};
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion::logical_expr::{
+    Cast, ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarFunctionArgs, ScalarUDF,
+    ScalarUDFImpl, Signature, TryCast, Volatility,
+};
+use datafusion::physical_expr::{create_physical_expr, PhysicalExpr};
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use datafusion::sql::sqlparser::ast::{
+    visit_expressions, visit_expressions_mut, Expr as SqlExpr, Function, FunctionArg,
+    FunctionArgExpr, FunctionArgumentList, FunctionArguments, Ident, LambdaFunction, ObjectName,
+    Statement, Value, VisitMut, VisitorMut,
+};
+use datafusion::sql::sqlparser::dialect::{Dialect as SqlDialect, GenericDialect};
+use std::any::{Any, TypeId};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// The name of the placeholder function of lambda expressions.
+const LAMBDA: &str = "__lambda";
+/// The name of the placeholder function of the parameters of lambda expressions.
+const LAMBDA_PARAMETER: &str = "__lambda_parameter";
+
+/// DataFusion's generic SQL dialect, which also parses lambda expressions: `x -> x + 1`,
+/// `(x, y) -> x + y`.
+#[derive(Debug, Default)]
+pub struct LambdaDialect;
+
+impl SqlDialect for LambdaDialect {
+    /// The parser handles this dialect as the generic one wherever it checks the dialect.
+    fn dialect(&self) -> TypeId {
+        TypeId::of::<GenericDialect>()
+    }
+
+    fn supports_lambda_functions(&self) -> bool {
+        true
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        GenericDialect.is_delimited_identifier_start(ch)
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        GenericDialect.is_identifier_start(ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        GenericDialect.is_identifier_part(ch)
+    }
+
+    fn supports_unicode_string_literal(&self) -> bool {
+        GenericDialect.supports_unicode_string_literal()
+    }
+
+    fn supports_group_by_expr(&self) -> bool {
+        GenericDialect.supports_group_by_expr()
+    }
+
+    fn supports_connect_by(&self) -> bool {
+        GenericDialect.supports_connect_by()
+    }
+
+    fn supports_match_recognize(&self) -> bool {
+        GenericDialect.supports_match_recognize()
+    }
+
+    fn supports_start_transaction_modifier(&self) -> bool {
+        GenericDialect.supports_start_transaction_modifier()
+    }
+
+    fn supports_window_function_null_treatment_arg(&self) -> bool {
+        GenericDialect.supports_window_function_null_treatment_arg()
+    }
+
+    fn supports_dictionary_syntax(&self) -> bool {
+        GenericDialect.supports_dictionary_syntax()
+    }
+
+    fn supports_window_clause_named_window_reference(&self) -> bool {
+        GenericDialect.supports_window_clause_named_window_reference()
+    }
+
+    fn supports_parenthesized_set_variables(&self) -> bool {
+        GenericDialect.supports_parenthesized_set_variables()
+    }
+
+    fn supports_select_wildcard_except(&self) -> bool {
+        GenericDialect.supports_select_wildcard_except()
+    }
+
+    fn support_map_literal_syntax(&self) -> bool {
+        GenericDialect.support_map_literal_syntax()
+    }
+
+    fn allow_extract_custom(&self) -> bool {
+        GenericDialect.allow_extract_custom()
+    }
+
+    fn allow_extract_single_quotes(&self) -> bool {
+        GenericDialect.allow_extract_single_quotes()
+    }
+
+    fn supports_create_index_with_clause(&self) -> bool {
+        GenericDialect.supports_create_index_with_clause()
+    }
+
+    fn supports_explain_with_utility_options(&self) -> bool {
+        GenericDialect.supports_explain_with_utility_options()
+    }
+
+    fn supports_limit_comma(&self) -> bool {
+        GenericDialect.supports_limit_comma()
+    }
+
+    fn supports_asc_desc_in_column_definition(&self) -> bool {
+        GenericDialect.supports_asc_desc_in_column_definition()
+    }
+
+    fn supports_try_convert(&self) -> bool {
+        GenericDialect.supports_try_convert()
+    }
+
+    fn supports_comment_on(&self) -> bool {
+        GenericDialect.supports_comment_on()
+    }
+
+    fn supports_load_extension(&self) -> bool {
+        GenericDialect.supports_load_extension()
+    }
+
+    fn supports_named_fn_args_with_assignment_operator(&self) -> bool {
+        GenericDialect.supports_named_fn_args_with_assignment_operator()
+    }
+
+    fn supports_struct_literal(&self) -> bool {
+        GenericDialect.supports_struct_literal()
+    }
+}
+
+/// Parse the single statement `sql` with the [`LambdaDialect`].
+pub fn parse_statement(sql: &str) -> Result<DFStatement> {
+    let mut statements = DFParser::parse_sql_with_dialect(sql, &LambdaDialect)?;
+    if statements.len() > 1 {
+        return plan_err!("Expected a single SQL statement, got {}", statements.len());
+    }
+    match statements.pop_front() {
+        Some(statement) => Ok(statement),
+        None => plan_err!("No SQL statements were provided in the query string"),
+    }
+}
+
+/// Rewrite the lambda expressions passed to the higher-order functions among `overloads` into
+/// calls to the placeholder functions DataFusion can plan (see the [module](self)
+/// documentation). Fails on lambda expressions anywhere else.
+pub fn rewrite_lambdas(statement: &mut Statement, overloads: &[FunctionOverload]) -> Result<()> {
+    let mut rewriter = LambdaArguments { overloads };
+    if let ControlFlow::Break(e) = statement.visit(&mut rewriter) {
+        return Err(e);
+    }
+    let unexpected = visit_expressions(statement, |expr| match expr {
+        SqlExpr::Lambda(lambda) => ControlFlow::Break(lambda.to_string()),
+        _ => ControlFlow::Continue(()),
+    });
+    match unexpected {
+        ControlFlow::Break(lambda) => plan_err!("Unexpected lambda expression {lambda}"),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+struct LambdaArguments<'a> {
+    overloads: &'a [FunctionOverload],
+}
+
+impl LambdaArguments<'_> {
+    fn rewrite(&self, function: &mut Function) -> Result<()> {
+        let FunctionArguments::List(list) = &mut function.args else {
+            return Ok(());
+        };
+        let arities = list
+            .args
+            .iter()
+            .map(|arg| lambda_argument(arg).map(|lambda| lambda.params.len()))
+            .collect::<Vec<_>>();
+        if arities.iter().all(Option::is_none) {
+            return Ok(());
+        }
+        let name = match function.name.0.last() {
+            Some(name) => name.value.clone(),
+            None => return Ok(()),
+        };
+        let parameters = self.parameters(&name, &arities)?;
+        // The other arguments, which bind the type variables of the parameters of the lambdas
+        let typing = parameters
+            .iter()
+            .zip(&list.args)
+            .filter(|(parameter, _)| !matches!(parameter, SdfType::Function(_)))
+            .filter_map(|(parameter, arg)| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) => {
+                    Some((parameter.to_string(), arg.clone()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (parameter, arg) in parameters.iter().zip(list.args.iter_mut()) {
+            let SdfType::Function(types) = parameter else {
+                continue;
+            };
+            if let FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) = arg {
+                if let SqlExpr::Lambda(lambda) = arg {
+                    *arg = placeholder(lambda, types, &typing);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The parameters of the overload of `function` taking lambda expressions with the
+    /// `arities` of its arguments (`None` for the other arguments).
+    fn parameters(&self, function: &str, arities: &[Option<usize>]) -> Result<Vec<SdfType>> {
+        let candidates = self
+            .overloads
+            .iter()
+            .filter(|o| o.function_name().eq_ignore_ascii_case(function))
+            .map(|o| &o.signature)
+            .filter(|s| !s.variadic && s.parameters.len() == arities.len())
+            .collect::<Vec<_>>();
+        let matching = candidates.iter().find(|s| {
+            s.parameters
+                .iter()
+                .zip(arities)
+                .all(|(parameter, arity)| match (parameter, arity) {
+                    (SdfType::Function(types), Some(arity)) => types.len() == arity + 1,
+                    (_, Some(_)) => false,
+                    (_, None) => true,
+                })
+        });
+        if let Some(signature) = matching {
+            return Ok(signature.parameters.clone());
+        }
+        for (i, arity) in arities.iter().enumerate() {
+            let Some(arity) = arity else {
+                continue;
+            };
+            let expected = candidates.iter().find_map(|s| match &s.parameters[i] {
+                SdfType::Function(types) => Some(types.len() - 1),
+                _ => None,
+            });
+            return match expected {
+                Some(expected) => {
+                    plan_err!("Expected a lambda that takes {expected} argument(s) but got {arity}")
+                }
+                None => plan_err!(
+                    "Function {function} does not take a lambda expression as argument {}",
+                    i + 1
+                ),
+            };
+        }
+        internal_err!("No lambda expression among the arguments of {function}")
+    }
+}
+
+impl VisitorMut for LambdaArguments<'_> {
+    type Break = DataFusionError;
+
+    /// Lambda expressions are rewritten inside out, so that the parameters of a nested lambda
+    /// expression are replaced before those of the enclosing one, which they shadow.
+    fn post_visit_expr(&mut self, expr: &mut SqlExpr) -> ControlFlow<Self::Break> {
+        if let SqlExpr::Function(function) = expr {
+            if let Err(e) = self.rewrite(function) {
+                return ControlFlow::Break(e);
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+fn lambda_argument(arg: &FunctionArg) -> Option<&LambdaFunction> {
+    match arg {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Lambda(lambda))) => Some(lambda),
+        _ => None,
+    }
+}
+
+/// The name an identifier is resolved by, unquoted identifiers being case-insensitive.
+fn normalized(ident: &Ident) -> String {
+    match ident.quote_style {
+        Some(_) => ident.value.clone(),
+        None => ident.value.to_ascii_lowercase(),
+    }
+}
+
+/// The placeholder of `lambda`, whose parameters are declared with `types`, followed by its
+/// result type. Each parameter placeholder carries the declared parameter types and the values
+/// of the other arguments of the call, from which it resolves its type.
+///
+/// The id of the lambda expression is its height, one more than that of the lambda expressions
+/// nested in its body: it tells its parameters apart from those of the lambda expressions it is
+/// nested in, while identical lambda expressions (e.g. in the `SELECT` and `GROUP BY` clauses)
+/// are rewritten identically.
+fn placeholder(
+    lambda: &LambdaFunction,
+    types: &[SdfType],
+    typing: &[(String, SqlExpr)],
+) -> SqlExpr {
+    let id = height(&lambda.body);
+    let names = lambda.params.iter().map(normalized).collect::<Vec<_>>();
+    let parameters = names
+        .iter()
+        .zip(types)
+        .enumerate()
+        .map(|(i, (name, parameter_type))| {
+            let mut args = vec![
+                number(id),
+                number(i as i64),
+                string(name),
+                string(&parameter_type.to_string()),
+            ];
+            args.extend(typing.iter().map(|(declared, _)| string(declared)));
+            args.extend(typing.iter().map(|(_, arg)| arg.clone()));
+            call(LAMBDA_PARAMETER, args)
+        })
+        .collect::<Vec<_>>();
+    let mut body = lambda.body.as_ref().clone();
+    let _ = visit_expressions_mut(&mut body, |expr| {
+        if let SqlExpr::Identifier(ident) = expr {
+            let ident = normalized(ident);
+            if let Some(i) = names.iter().position(|name| *name == ident) {
+                *expr = parameters[i].clone();
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    let mut args = vec![number(id), body];
+    args.extend(names.iter().map(|name| string(name)));
+    call(LAMBDA, args)
+}
+
+/// The height of the lambda expressions whose body is `body`, whose nested lambda expressions are
+/// already rewritten.
+fn height(body: &SqlExpr) -> i64 {
+    let mut nested = 0;
+    let _ = visit_expressions(body, |expr| {
+        if let SqlExpr::Function(function) = expr {
+            let is_lambda = function
+                .name
+                .0
+                .last()
+                .is_some_and(|name| name.value == LAMBDA);
+            if let (true, FunctionArguments::List(list)) = (is_lambda, &function.args) {
+                if let Some(FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
+                    Value::Number(id, _),
+                )))) = list.args.first()
+                {
+                    nested = nested.max(id.parse().unwrap_or(0));
+                }
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    nested + 1
+}
+
+fn number(n: i64) -> SqlExpr {
+    SqlExpr::Value(Value::Number(n.to_string(), false))
+}
+
+fn string(s: &str) -> SqlExpr {
+    SqlExpr::Value(Value::SingleQuotedString(s.to_string()))
+}
+
+fn call(name: &str, args: Vec<SqlExpr>) -> SqlExpr {
+    SqlExpr::Function(Function {
+        name: ObjectName(vec![Ident::new(name)]),
+        uses_odbc_syntax: false,
+        parameters: FunctionArguments::None,
+        args: FunctionArguments::List(FunctionArgumentList {
+            duplicate_treatment: None,
+            args: args
+                .into_iter()
+                .map(|arg| FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)))
+                .collect(),
+            clauses: vec![],
+        }),
+        filter: None,
+        null_treatment: None,
+        over: None,
+        within_group: vec![],
+    })
+}
+
+/// The placeholder functions lambda expressions are planned to, to register along with the
+/// higher-order functions.
+pub fn lambda_functions() -> Vec<Arc<ScalarUDF>> {
+    vec![
+        Arc::new(ScalarUDF::new_from_impl(LambdaPlaceholder::new())),
+        Arc::new(ScalarUDF::new_from_impl(ParameterPlaceholder::new())),
+    ]
+}
+
+/// `__lambda(id, body, name, ...)`: the lambda expression `id`, whose type wraps the type of its
+/// body.
+#[derive(Debug)]
+struct LambdaPlaceholder {
+    signature: Signature,
+}
+
+impl LambdaPlaceholder {
+    fn new() -> Self {
+        // Volatile, so that the lambda expression is never evaluated as a constant
+        Self {
+            signature: Signature::variadic_any(Volatility::Volatile),
+        }
+    }
+}
+
+impl ScalarUDFImpl for LambdaPlaceholder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        LAMBDA
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match arg_types.get(1) {
+            Some(body) => Ok(lambda_of(body.clone())),
+            None => internal_err!("{LAMBDA} expects a body"),
+        }
+    }
+
+    /// `x -> body` or `(x, y) -> body`
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        let names = args
+            .iter()
+            .skip(2)
+            .filter_map(string_literal)
+            .collect::<Vec<_>>();
+        let body = match args.get(1) {
+            Some(body) => body.schema_name().to_string(),
+            None => String::new(),
+        };
+        match names.as_slice() {
+            [name] => Ok(format!("{name} -> {body}")),
+            names => Ok(format!("({}) -> {body}", names.join(", "))),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!("A lambda expression can only be evaluated by the function it is passed to")
+    }
+}
+
+/// `__lambda_parameter(id, index, name, type, declared..., args...)`: the parameter number
+/// `index` of the lambda expression `id`, of the declared `type`, whose type variables
+/// are bound by matching the other arguments `args` of the higher-order function against their
+/// `declared` types.
+#[derive(Debug)]
+struct ParameterPlaceholder {
+    signature: Signature,
+}
+
+impl ParameterPlaceholder {
+    fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Volatile),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ParameterPlaceholder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        LAMBDA_PARAMETER
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("{LAMBDA_PARAMETER} is typed by return_type_from_args")
+    }
+
+    /// The declared type of the parameter, or NULL if the other arguments do not match theirs:
+    /// the higher-order function then reports them.
+    fn return_type_from_args(&self, args: ReturnTypeArgs) -> Result<ReturnInfo> {
+        let literals = args
+            .scalar_arguments
+            .iter()
+            .map(|arg| match arg {
+                Some(ScalarValue::Utf8(Some(s))) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let (Some(Some(parameter)), Some(rest)) = (literals.get(3), literals.get(4..)) else {
+            return internal_err!("Invalid arguments for {LAMBDA_PARAMETER}");
+        };
+        let (declared, _) = rest.split_at(rest.len() / 2);
+        let Some(declared) = declared.iter().copied().collect::<Option<Vec<_>>>() else {
+            return internal_err!("Invalid arguments for {LAMBDA_PARAMETER}");
+        };
+        let arg_types = &args.arg_types[4 + declared.len()..];
+        let signature = FunctionSignature::new(&declared, false)?;
+        let parameter = SdfType::parse(parameter)?;
+        let data_type = signature
+            .resolve(arg_types)
+            .and_then(|res| parameter.default_data_type(&res.bindings))
+            .unwrap_or(DataType::Null);
+        Ok(ReturnInfo::new_nullable(data_type))
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        match args.get(2).and_then(string_literal) {
+            Some(name) => Ok(name.to_string()),
+            None => internal_err!("{LAMBDA_PARAMETER} expects a name"),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!("A lambda parameter can only be evaluated by the function it is passed to")
+    }
+}
+
+fn string_literal(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Literal(ScalarValue::Utf8(Some(s))) => Some(s),
+        _ => None,
+    }
+}
+
+fn int64_literal(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(ScalarValue::Int64(Some(n))) => Some(*n),
+        _ => None,
+    }
+}
+
+/// The id and index of a parameter placeholder.
+fn parameter_placeholder(expr: &Expr) -> Option<(i64, usize)> {
+    match expr {
+        Expr::ScalarFunction(ScalarFunction { func, args })
+            if func.inner().as_any().is::<ParameterPlaceholder>() =>
+        {
+            let id = int64_literal(args.first()?)?;
+            let index = int64_literal(args.get(1)?)?;
+            Some((id, usize::try_from(index).ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// A column of the batches a [`Lambda`] is evaluated on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LambdaColumn {
+    /// The parameter of the given index
+    Parameter(usize),
+    /// The captured value of the given index
+    Capture(usize),
+}
+
+/// A lambda expression compiled into a physical expression over its parameters and the values
+/// it captures.
+#[derive(Debug, Clone)]
+pub struct Lambda {
+    /// The body, over the columns of `schema`
+    body: Expr,
+    physical: Arc<dyn PhysicalExpr>,
+    schema: SchemaRef,
+    /// What each column of `schema` holds
+    columns: Vec<LambdaColumn>,
+    /// The number of captured values
+    captures: usize,
+}
+
+impl Lambda {
+    /// Compile the lambda expression `expr`, the argument number `position` of `function`,
+    /// returning the values it captures, in the order the [`BoundLambda`] expects them.
+    fn compile(
+        function: &str,
+        position: usize,
+        expr: &Expr,
+        info: &dyn SimplifyInfo,
+    ) -> Result<(Self, Vec<Expr>)> {
+        // The planner coerces the lambda expression as a whole: coerce its body instead
+        let mut expr = expr;
+        let mut casts = vec![];
+        let (id, body) = loop {
+            match expr {
+                Expr::Cast(Cast { expr: inner, .. })
+                | Expr::TryCast(TryCast { expr: inner, .. }) => {
+                    casts.push(expr);
+                    expr = inner;
+                }
+                Expr::Alias(alias) => expr = &alias.expr,
+                Expr::ScalarFunction(ScalarFunction { func, args })
+                    if func.inner().as_any().is::<LambdaPlaceholder>() =>
+                {
+                    match (args.first().and_then(int64_literal), args.get(1)) {
+                        (Some(id), Some(body)) => break (id, body.clone()),
+                        _ => return internal_err!("Invalid arguments for {LAMBDA}"),
+                    }
+                }
+                _ => {
+                    return plan_err!(
+                        "{function} expects a lambda expression as argument {}, got {expr}",
+                        position + 1
+                    )
+                }
+            }
+        };
+        let body = casts.iter().rev().fold(body, |body, cast| match cast {
+            Expr::Cast(Cast { data_type, .. }) => match lambda_result(data_type) {
+                Some(result) => Expr::Cast(Cast::new(body.into(), result.clone())),
+                None => body,
+            },
+            Expr::TryCast(TryCast { data_type, .. }) => match lambda_result(data_type) {
+                Some(result) => Expr::TryCast(TryCast::new(body.into(), result.clone())),
+                None => body,
+            },
+            _ => body,
+        });
+
+        let mut columns: Vec<(LambdaColumn, DataType)> = vec![];
+        let mut captures: Vec<Expr> = vec![];
+        let mut column = |key: LambdaColumn, data_type: DataType| {
+            let index = match columns.iter().position(|(c, _)| *c == key) {
+                Some(index) => index,
+                None => {
+                    columns.push((key, data_type));
+                    columns.len() - 1
+                }
+            };
+            Expr::Column(format!("c{index}").into())
+        };
+        let body = body
+            .transform_down(|expr| {
+                let key = match parameter_placeholder(&expr) {
+                    Some((lambda, index)) if lambda == id => LambdaColumn::Parameter(index),
+                    // The parameter of an enclosing lambda expression is captured
+                    Some(_) => capture(&mut captures, &expr),
+                    None if matches!(expr, Expr::Column(_)) => capture(&mut captures, &expr),
+                    None => return Ok(Transformed::no(expr)),
+                };
+                let data_type = info.get_data_type(&expr)?;
+                Ok(Transformed::new(
+                    column(key, data_type),
+                    true,
+                    TreeNodeRecursion::Jump,
+                ))
+            })?
+            .data;
+
+        let fields = columns
+            .iter()
+            .enumerate()
+            .map(|(i, (_, data_type))| Field::new(format!("c{i}"), data_type.clone(), true))
+            .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new(fields));
+        let df_schema = DFSchema::try_from(Arc::clone(&schema))?;
+        let physical = create_physical_expr(&body, &df_schema, info.execution_props())?;
+        let lambda = Self {
+            body,
+            physical,
+            schema,
+            columns: columns.into_iter().map(|(c, _)| c).collect(),
+            captures: captures.len(),
+        };
+        Ok((lambda, captures))
+    }
+}
+
+/// The column key of the captured value `expr`, adding it to `captures` if needed.
+fn capture(captures: &mut Vec<Expr>, expr: &Expr) -> LambdaColumn {
+    match captures.iter().position(|c| c == expr) {
+        Some(index) => LambdaColumn::Capture(index),
+        None => {
+            captures.push(expr.clone());
+            LambdaColumn::Capture(captures.len() - 1)
+        }
+    }
+}
+
+/// A [`Lambda`] with the values it captures for a batch of rows.
+pub struct BoundLambda<'a> {
+    lambda: &'a Lambda,
+    captures: &'a [ArrayRef],
+}
+
+impl BoundLambda<'_> {
+    /// Evaluate the lambda expression on values of its `parameters`, all of the same length,
+    /// which belong to the given `rows` of the batch its captured values come from.
+    pub fn call(&self, parameters: &[ArrayRef], rows: &UInt32Array) -> Result<ArrayRef> {
+        let columns = self
+            .lambda
+            .columns
+            .iter()
+            .zip(self.lambda.schema.fields())
+            .map(|(column, field)| {
+                let values = match column {
+                    LambdaColumn::Parameter(i) => match parameters.get(*i) {
+                        Some(values) => Arc::clone(values),
+                        None => return internal_err!("Missing lambda parameter {i}"),
+                    },
+                    LambdaColumn::Capture(i) => take(&self.captures[*i], rows, None)?,
+                };
+                if values.data_type() == field.data_type() {
+                    Ok(values)
+                } else {
+                    Ok(cast(&values, field.data_type())?)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let options = RecordBatchOptions::new().with_row_count(Some(rows.len()));
+        let batch =
+            RecordBatch::try_new_with_options(Arc::clone(&self.lambda.schema), columns, &options)?;
+        self.lambda
+            .physical
+            .evaluate(&batch)?
+            .into_array(rows.len())
+    }
+}
+
+/// Evaluates a higher-order function, given its arguments that are not lambda expressions and
+/// its lambda expressions.
+pub(crate) type Evaluate = fn(&[ArrayRef], &[BoundLambda]) -> Result<ArrayRef>;
+
+/// A call to a higher-order function, with its lambda expressions compiled.
+#[derive(Debug)]
+pub struct HigherOrderUDF {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    /// The number of arguments that are not captured values
+    arguments: usize,
+    lambdas: Vec<Lambda>,
+    evaluate: Evaluate,
+}
+
+impl ScalarUDFImpl for HigherOrderUDF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let args = args
+            .args
+            .iter()
+            .map(|arg| arg.to_array(args.number_rows))
+            .collect::<Result<Vec<_>>>()?;
+        let (arguments, mut captures) = args.split_at(self.arguments);
+        let mut lambdas = Vec::with_capacity(self.lambdas.len());
+        for lambda in &self.lambdas {
+            let (bound, rest) = captures.split_at(lambda.captures);
+            lambdas.push(BoundLambda {
+                lambda,
+                captures: bound,
+            });
+            captures = rest;
+        }
+        (self.evaluate)(arguments, &lambdas).map(ColumnarValue::Array)
+    }
+
+    /// Calls to the same function differ by their lambda expressions.
+    fn equals(&self, other: &dyn ScalarUDFImpl) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some_and(|other| {
+            self.name == other.name
+                && self.return_type == other.return_type
+                && self.arguments == other.arguments
+                && self.lambdas.len() == other.lambdas.len()
+                && self
+                    .lambdas
+                    .iter()
+                    .zip(&other.lambdas)
+                    .all(|(a, b)| a.body == b.body && a.schema == b.schema)
+        })
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name.hash(hasher);
+        self.arguments.hash(hasher);
+        for lambda in &self.lambdas {
+            lambda.body.hash(hasher);
+        }
+        hasher.finish()
+    }
+}
+
+/// Simplify a call to the higher-order function `name`, whose last `lambdas` arguments are
+/// lambda expressions, into a [`HigherOrderUDF`] evaluated by `evaluate`. This is the
+/// `_simplify` function of the overloads of higher-order functions.
+pub(crate) fn simplify_higher_order(
+    name: &str,
+    args: Vec<Expr>,
+    info: &dyn SimplifyInfo,
+    lambdas: usize,
+    return_type: fn(&[DataType]) -> Result<DataType>,
+    evaluate: Evaluate,
+) -> Result<ExprSimplifyResult> {
+    let arg_types = args
+        .iter()
+        .map(|arg| info.get_data_type(arg))
+        .collect::<Result<Vec<_>>>()?;
+    let return_type = return_type(&arg_types)?;
+    let Some(arguments) = args.len().checked_sub(lambdas) else {
+        return plan_err!("{name} expects {lambdas} lambda expression(s)");
+    };
+    let mut compiled = Vec::with_capacity(lambdas);
+    let mut captures = vec![];
+    for (position, arg) in args.iter().enumerate().skip(arguments) {
+        let (lambda, captured) = Lambda::compile(name, position, arg, info)?;
+        compiled.push(lambda);
+        captures.extend(captured);
+    }
+    let volatility = if compiled.iter().any(|lambda| lambda.body.is_volatile()) {
+        Volatility::Volatile
+    } else {
+        Volatility::Immutable
+    };
+    let udf = HigherOrderUDF {
+        name: name.to_string(),
+        signature: Signature::variadic_any(volatility),
+        return_type,
+        arguments,
+        lambdas: compiled,
+        evaluate,
+    };
+    let mut args = args;
+    args.truncate(arguments);
+    args.extend(captures);
+    Ok(ExprSimplifyResult::Simplified(Expr::ScalarFunction(
+        ScalarFunction::new_udf(Arc::new(ScalarUDF::new_from_impl(udf)), args),
+    )))
+}
+
+/// The `_invoke` function of the overloads of higher-order functions, which are only evaluated
+/// once simplified.
+pub(crate) fn not_simplified(name: &str) -> Result<ColumnarValue> {
+    exec_err!("{name} can only be evaluated once its lambda expressions are simplified")
+}
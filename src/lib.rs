@@ -1,18 +1,118 @@
 use std::sync::Arc;
 
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::catalog::TableFunction;
+use datafusion::common::Result;
+use datafusion::dataframe::DataFrame;
+use datafusion::execution::context::SessionContext;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF, WindowUDF};
+use datafusion::sql::parser::Statement as DFStatement;
 use rust_embed::{EmbeddedFile, RustEmbed};
 
 #[macro_use]
 pub mod macros;
 
+pub mod aggregate;
+pub mod catalog;
+pub mod coverage;
+pub mod distinct;
+pub mod documentation;
+pub mod extension;
+pub mod lambda;
+pub mod overload;
+pub mod redshift;
+pub mod registry;
+pub mod sdf;
+pub mod snowflake;
+pub mod table;
 pub mod trino;
+pub mod type_signature;
+pub mod window;
+
+pub use catalog::FunctionCatalog;
+pub use registry::{
+    register_dialect, register_dialect_with_options, register_table_functions, Dialect,
+    RegisterOptions,
+};
 
 /// Registers all enabled packages with a [`FunctionRegistry`]
 pub fn get_all_functions() -> Vec<(String, Arc<ScalarUDF>)> {
     trino::functions()
 }
 
+/// All enabled packages, with one function per SQL name dispatching to the
+/// overload matching the argument types
+pub fn get_all_overloaded_functions() -> Vec<(String, Arc<ScalarUDF>)> {
+    trino::overloaded_functions()
+}
+
+/// The aggregate functions of all enabled packages
+pub fn get_all_aggregate_functions() -> Vec<(String, Arc<AggregateUDF>)> {
+    trino::aggregate_functions()
+}
+
+/// The window functions of all enabled packages
+pub fn get_all_window_functions() -> Vec<(String, Arc<WindowUDF>)> {
+    let mut functions = trino::window_functions();
+    functions.extend(redshift::window_functions());
+    functions.extend(snowflake::window_functions());
+    functions
+}
+
+/// The table functions of all enabled packages
+pub fn get_all_table_functions() -> Vec<(String, Arc<TableFunction>)> {
+    snowflake::table_functions()
+}
+
+/// Plan and run `sql` like `SessionContext::sql`, with the SQL extensions of `dialect` that
+/// DataFusion does not support: the lambda expressions passed to its higher-order functions (see
+/// [`lambda`]), the named arguments of its table functions (see
+/// [`table::rewrite_table_function_arguments`]), whose arguments are checked to be valid
+/// expressions (see [`table::check_table_function_arguments`]), and the window functions called `FROM LAST` (see
+/// [`window::rewrite_from_last`]).
+///
+/// Lambda expressions are parsed by a [`lambda::LambdaDialect`], whatever the SQL dialect
+/// configured in `ctx`. The session functions of Trino, e.g. `current_user`, are folded from the
+/// [`trino::session::TrinoSession`] of `ctx` (see [`trino::session::apply_session`]), and typed
+/// in its time zone (see [`trino::session::bind_session`]), as are its `TIMESTAMP` literals with a
+/// time zone (see [`trino::session::rewrite_zoned_literals`]).
+///
+/// ```ignore
+/// let ctx = SessionContext::new();
+/// register_table_functions(&ctx, Dialect::Snowflake, &RegisterOptions::default())?;
+/// sql_functions::sql(&ctx, Dialect::Snowflake, "SELECT count(*) FROM TABLE(generator(rowcount => 10))").await?;
+/// ```
+pub async fn sql(ctx: &SessionContext, dialect: Dialect, sql: &str) -> Result<DataFrame> {
+    let mut state = ctx.state();
+    if dialect == Dialect::Trino {
+        trino::session::bind_session(&mut state)?;
+    }
+    let overloads = dialect.overloads();
+    let sql = &window::mark_from_last(sql);
+    let mut statement = if overloads.iter().any(|o| o.signature.takes_lambda()) {
+        lambda::parse_statement(sql)?
+    } else {
+        let sql_dialect = state.config().options().sql_parser.dialect.clone();
+        state.sql_to_statement(sql, &sql_dialect)?
+    };
+    if let DFStatement::Statement(statement) = &mut statement {
+        lambda::rewrite_lambdas(statement, &overloads)?;
+        if dialect == Dialect::Trino {
+            trino::session::rewrite_session_functions(statement);
+            trino::session::rewrite_zoned_literals(statement);
+        }
+        let table_overloads = dialect.table_overloads();
+        table::rewrite_table_function_arguments(statement, &table_overloads)?;
+        table::check_table_function_arguments(statement, &table_overloads, &state)?;
+        window::rewrite_from_last(statement)?;
+    }
+    let mut plan = state.statement_to_plan(statement).await?;
+    if dialect == Dialect::Trino {
+        let start_time = state.execution_props().query_execution_start_time;
+        plan = trino::session::apply_session(plan, state.config_options(), start_time)?;
+    }
+    ctx.execute_logical_plan(plan).await
+}
+
 #[derive(RustEmbed)]
 #[folder = "assets/"]
 pub struct Asset;
@@ -23,6 +123,20 @@ impl Asset {
     }
 }
 
+mod calendar;
+mod datetime_pattern;
+
+mod iso8601;
+mod joni;
+mod json;
+mod jsonpath;
+mod timezone;
 mod utils;
+mod utils_aggregate;
 mod utils_arrow;
+mod utils_calendar;
+mod utils_datetime;
+mod utils_json;
+mod utils_lambda;
 mod utils_regexp;
+mod utils_window;
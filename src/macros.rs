@@ -1,9 +1,15 @@
 macro_rules! export_functions {
-    ($(($DIALECT:ident, $FUNC:ident,  $($arg:ident)*, $DOC:expr)),* $(,)?) => {
+    (@variadic variadic) => {
+        true
+    };
+    (@variadic) => {
+        false
+    };
+    ($(($DIALECT:ident, $FUNC:ident,  $($arg:ident)*, $($VARIADIC:ident)? [$($PARAM:literal),*], $DOC:expr)),* $(,)?) => {
         pub mod expr_fn {
             $(
                 #[doc = $DOC]
-                /// Return $name(arg)
+                #[allow(clippy::too_many_arguments)]
                 pub fn $FUNC($($arg: datafusion::logical_expr::Expr),*) -> datafusion::logical_expr::Expr {
                     super::$FUNC().call(vec![$($arg),*],)
                 }
@@ -20,15 +26,47 @@ macro_rules! export_functions {
             ]
 
         }
+
+        /// Return every overload in this package along with its declared signature
+        pub fn overloads() -> Vec<$crate::overload::FunctionOverload> {
+            vec![
+                $(
+                    $crate::overload::FunctionOverload {
+                        dialect: stringify!($DIALECT),
+                        name: stringify!($FUNC),
+                        udf: $FUNC(),
+                        signature: $crate::type_signature::FunctionSignature::new(
+                            &[$($PARAM),*],
+                            export_functions!(@variadic $($VARIADIC)?),
+                        )
+                        .expect(concat!("invalid signature of ", stringify!($FUNC))),
+                    },
+                )*
+            ]
+        }
+
+        /// Return the implementation status of every overload in this package
+        pub fn coverage() -> Vec<$crate::coverage::FunctionStatus> {
+            $crate::coverage::coverage(overloads())
+        }
+
+        /// Return one function per SQL name, dispatching to the overload matching the argument types
+        pub fn overloaded_functions() -> Vec<(String, std::sync::Arc<datafusion::logical_expr::ScalarUDF>)> {
+            $crate::overload::overloaded_functions(overloads())
+        }
     };
 }
 
 /// Creates a singleton `ScalarUDF` of the `$UDF` function named `$GNAME` and a
 /// function named `$NAME` which returns that function named $NAME.
+/// The function is `Immutable` unless another `$VOLATILITY` (`Stable` or `Volatile`) is given.
 ///
 /// This is used to ensure creating the list of `ScalarUDF` only happens once.
 macro_rules! make_udf_function {
     ($UDF:ty, $GNAME:ident, $NAME:ident) => {
+        make_udf_function!($UDF, $GNAME, $NAME, Immutable);
+    };
+    ($UDF:ty, $GNAME:ident, $NAME:ident, $VOLATILITY:ident) => {
         /// Singleton instance of the function
         static $GNAME: std::sync::OnceLock<std::sync::Arc<datafusion::logical_expr::ScalarUDF>> =
             std::sync::OnceLock::new();
@@ -40,7 +78,201 @@ macro_rules! make_udf_function {
             $GNAME
                 .get_or_init(|| {
                     std::sync::Arc::new(datafusion::logical_expr::ScalarUDF::new_from_impl(
-                        <$UDF>::new(),
+                        <$UDF>::new(datafusion::logical_expr::Volatility::$VOLATILITY),
+                    ))
+                })
+                .clone()
+        }
+    };
+}
+
+/// The aggregate counterpart of `export_functions!`: lists the aggregate UDFs of a package.
+macro_rules! export_aggregate_functions {
+    ($(($DIALECT:ident, $FUNC:ident, $($VARIADIC:ident)? [$($PARAM:literal),*])),* $(,)?) => {
+        /// Return a list of all aggregate functions in this package
+        pub fn aggregate_functions() -> Vec<(String, std::sync::Arc<datafusion::logical_expr::AggregateUDF>)> {
+            vec![
+                $(
+                    (format!("{}::{}", stringify!($DIALECT), stringify!($FUNC)), $FUNC()),
+                )*
+            ]
+        }
+
+        /// Return every aggregate overload in this package along with its declared signature
+        pub fn aggregate_overloads() -> Vec<$crate::aggregate::AggregateOverload> {
+            vec![
+                $(
+                    $crate::aggregate::AggregateOverload {
+                        dialect: stringify!($DIALECT),
+                        name: stringify!($FUNC),
+                        udaf: $FUNC(),
+                        signature: $crate::type_signature::FunctionSignature::new(
+                            &[$($PARAM),*],
+                            export_functions!(@variadic $($VARIADIC)?),
+                        )
+                        .expect(concat!("invalid signature of ", stringify!($FUNC))),
+                    },
+                )*
+            ]
+        }
+
+        /// Return one aggregate function per SQL name, dispatching to the overload matching the
+        /// argument types
+        pub fn overloaded_aggregate_functions() -> Vec<(String, std::sync::Arc<datafusion::logical_expr::AggregateUDF>)> {
+            $crate::overload::overloaded_functions(aggregate_overloads())
+        }
+    };
+}
+
+/// The aggregate counterpart of `make_udf_function!`: creates a singleton `AggregateUDF` of the
+/// `$UDAF` function named `$GNAME` and a function named `$NAME` which returns it.
+macro_rules! make_udaf_function {
+    ($UDAF:ty, $GNAME:ident, $NAME:ident) => {
+        make_udaf_function!($UDAF, $GNAME, $NAME, Immutable);
+    };
+    ($UDAF:ty, $GNAME:ident, $NAME:ident, $VOLATILITY:ident) => {
+        /// Singleton instance of the aggregate function
+        static $GNAME: std::sync::OnceLock<std::sync::Arc<datafusion::logical_expr::AggregateUDF>> =
+            std::sync::OnceLock::new();
+
+        /// Return an [`AggregateUDF`] for [`$UDAF`]
+        ///
+        /// [`AggregateUDF`]: datafusion::logical_expr::AggregateUDF
+        fn $NAME() -> std::sync::Arc<datafusion::logical_expr::AggregateUDF> {
+            $GNAME
+                .get_or_init(|| {
+                    std::sync::Arc::new(datafusion::logical_expr::AggregateUDF::new_from_impl(
+                        <$UDAF>::new(datafusion::logical_expr::Volatility::$VOLATILITY),
+                    ))
+                })
+                .clone()
+        }
+    };
+}
+
+/// The window counterpart of `export_functions!`: lists the window UDFs of a package.
+macro_rules! export_window_functions {
+    ($(($DIALECT:ident, $FUNC:ident, $($VARIADIC:ident)? [$($PARAM:literal),*])),* $(,)?) => {
+        /// Return a list of all window functions in this package
+        pub fn window_functions() -> Vec<(String, std::sync::Arc<datafusion::logical_expr::WindowUDF>)> {
+            vec![
+                $(
+                    (format!("{}::{}", stringify!($DIALECT), stringify!($FUNC)), $FUNC()),
+                )*
+            ]
+        }
+
+        /// Return every window overload in this package along with its declared signature
+        pub fn window_overloads() -> Vec<$crate::window::WindowOverload> {
+            vec![
+                $(
+                    $crate::window::WindowOverload {
+                        dialect: stringify!($DIALECT),
+                        name: stringify!($FUNC),
+                        udwf: $FUNC(),
+                        signature: $crate::type_signature::FunctionSignature::new(
+                            &[$($PARAM),*],
+                            export_functions!(@variadic $($VARIADIC)?),
+                        )
+                        .expect(concat!("invalid signature of ", stringify!($FUNC))),
+                    },
+                )*
+            ]
+        }
+
+        /// Return one window function per SQL name, dispatching to the overload matching the
+        /// argument types
+        pub fn overloaded_window_functions() -> Vec<(String, std::sync::Arc<datafusion::logical_expr::WindowUDF>)> {
+            $crate::overload::overloaded_functions(window_overloads())
+        }
+    };
+}
+
+/// The window counterpart of `make_udf_function!`: creates a singleton `WindowUDF` of the
+/// `$UDWF` function named `$GNAME` and a function named `$NAME` which returns it.
+macro_rules! make_udwf_function {
+    ($UDWF:ty, $GNAME:ident, $NAME:ident) => {
+        make_udwf_function!($UDWF, $GNAME, $NAME, Immutable);
+    };
+    ($UDWF:ty, $GNAME:ident, $NAME:ident, $VOLATILITY:ident) => {
+        /// Singleton instance of the window function
+        static $GNAME: std::sync::OnceLock<std::sync::Arc<datafusion::logical_expr::WindowUDF>> =
+            std::sync::OnceLock::new();
+
+        /// Return a [`WindowUDF`] for [`$UDWF`]
+        ///
+        /// [`WindowUDF`]: datafusion::logical_expr::WindowUDF
+        fn $NAME() -> std::sync::Arc<datafusion::logical_expr::WindowUDF> {
+            $GNAME
+                .get_or_init(|| {
+                    std::sync::Arc::new(datafusion::logical_expr::WindowUDF::new_from_impl(
+                        <$UDWF>::new(datafusion::logical_expr::Volatility::$VOLATILITY),
+                    ))
+                })
+                .clone()
+        }
+    };
+}
+
+/// The table counterpart of `export_functions!`: lists the table functions of a package. Each
+/// overload lists the types of its required and optional parameters, and the names of all its
+/// parameters (empty for positional-only ones).
+macro_rules! export_table_functions {
+    ($(($DIALECT:ident, $FUNC:ident, $($VARIADIC:ident)? [$($PARAM:literal),*], [$($OPTIONAL:literal),*], [$($NAME:literal),*])),* $(,)?) => {
+        /// Return a list of all table functions in this package
+        pub fn table_functions() -> Vec<(String, std::sync::Arc<datafusion::catalog::TableFunction>)> {
+            vec![
+                $(
+                    (format!("{}::{}", stringify!($DIALECT), stringify!($FUNC)), $FUNC()),
+                )*
+            ]
+        }
+
+        /// Return every table overload in this package along with its declared signature
+        pub fn table_overloads() -> Vec<$crate::table::TableOverload> {
+            vec![
+                $(
+                    $crate::table::TableOverload {
+                        dialect: stringify!($DIALECT),
+                        name: stringify!($FUNC),
+                        udtf: $FUNC(),
+                        signature: $crate::type_signature::FunctionSignature::new(
+                            &[$($PARAM,)* $($OPTIONAL),*],
+                            export_functions!(@variadic $($VARIADIC)?),
+                        )
+                        .expect(concat!("invalid signature of ", stringify!($FUNC))),
+                        required: <[&str]>::len(&[$($PARAM),*]),
+                        parameter_names: vec![$($NAME),*],
+                    },
+                )*
+            ]
+        }
+
+        /// Return one table function per SQL name, dispatching to the overload matching the
+        /// argument types
+        pub fn overloaded_table_functions() -> Vec<(String, std::sync::Arc<datafusion::catalog::TableFunction>)> {
+            $crate::overload::overloaded_functions(table_overloads())
+        }
+    };
+}
+
+/// The table counterpart of `make_udf_function!`: creates a singleton `TableFunction` of the
+/// `$UDTF` function named `$GNAME` and a function named `$NAME` which returns it.
+macro_rules! make_udtf_function {
+    ($UDTF:ty, $GNAME:ident, $NAME:ident) => {
+        /// Singleton instance of the table function
+        static $GNAME: std::sync::OnceLock<std::sync::Arc<datafusion::catalog::TableFunction>> =
+            std::sync::OnceLock::new();
+
+        /// Return a [`TableFunction`] for [`$UDTF`]
+        ///
+        /// [`TableFunction`]: datafusion::catalog::TableFunction
+        fn $NAME() -> std::sync::Arc<datafusion::catalog::TableFunction> {
+            $GNAME
+                .get_or_init(|| {
+                    std::sync::Arc::new(datafusion::catalog::TableFunction::new(
+                        <$UDTF>::NAME.to_string(),
+                        std::sync::Arc::new(<$UDTF>::default()),
                     ))
                 })
                 .clone()
@@ -0,0 +1,355 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Dispatch of overloaded dialect functions.
+//!
+//! Every overload of a dialect function is generated as its own UDF (e.g. `abs_tinyint`,
+//! `abs_bigint`, `abs_decimal_p_s`, ...), all sharing the SQL name `abs`. An [`OverloadedUDF`]
+//! bundles the overloads of one name into a single UDF which picks the overload to call from
+//! the argument types, following the dialect's implicit coercion rules
+//! (see [`crate::type_signature`]), and delegates to it.
+
+use crate::coverage::{implementation_status, ImplementationStatus};
+use crate::type_signature::{
+    ambiguous_call, can_coerce, unexpected_parameters, FunctionSignature, Resolution,
+};
+use arrow::datatypes::DataType;
+use datafusion::common::{plan_err, Result};
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, Expr, ReturnInfo, ReturnTypeArgs, ScalarFunctionArgs, ScalarUDF,
+    ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use std::any::Any;
+use std::sync::Arc;
+
+/// One overload of a dialect function.
+#[derive(Debug, Clone)]
+pub struct FunctionOverload {
+    pub dialect: &'static str,
+    /// The generated name of the overload, e.g. `abs_tinyint`
+    pub name: &'static str,
+    pub udf: Arc<ScalarUDF>,
+    pub signature: FunctionSignature,
+}
+
+impl Overload for FunctionOverload {
+    type Function = ScalarUDF;
+
+    fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn function_name(&self) -> &str {
+        self.udf.name()
+    }
+
+    fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Option<DataType> {
+        self.udf.return_type(arg_types).ok()
+    }
+
+    fn dispatcher(name: &str, overloads: Vec<Self>) -> Arc<ScalarUDF> {
+        Arc::new(ScalarUDF::new_from_impl(OverloadedUDF::new(
+            name, overloads,
+        )))
+    }
+}
+
+/// An overload of a dialect function of any kind: scalar ([`FunctionOverload`]), aggregate
+/// ([`crate::aggregate::AggregateOverload`]), window ([`crate::window::WindowOverload`]) or table
+/// ([`crate::table::TableOverload`]).
+pub trait Overload: Sized {
+    /// The kind of function the overloads of one name are bundled into, e.g. [`ScalarUDF`].
+    type Function;
+
+    fn dialect(&self) -> &'static str;
+
+    /// The generated name of the overload, e.g. `abs_tinyint`
+    fn name(&self) -> &'static str;
+
+    /// The SQL name of the function, e.g. `abs`
+    fn function_name(&self) -> &str;
+
+    /// The declared signature of the overload.
+    fn signature(&self) -> &FunctionSignature;
+
+    fn status(&self) -> ImplementationStatus {
+        implementation_status(self.dialect(), self.name())
+    }
+
+    /// Match the argument types against the signature of the overload.
+    fn resolve(&self, arg_types: &[DataType]) -> Option<Resolution> {
+        self.signature().resolve(arg_types)
+    }
+
+    /// The result type of the overload for the coerced argument types, if it has one.
+    fn return_type(&self, arg_types: &[DataType]) -> Option<DataType>;
+
+    /// The function dispatching to `overloads`, which must all share the name `name`.
+    fn dispatcher(name: &str, overloads: Vec<Self>) -> Arc<Self::Function>;
+}
+
+/// Pick among `overloads`, the overloads of the function `name`, the one with the cheapest
+/// coercion of `arg_types`. As in Trino, among the overloads of equal cost the most specific one
+/// wins: the one whose coerced argument types all coerce to those of the others, e.g.
+/// `abs(tinyint)` rather than `abs(bigint)` for a NULL. Without a most specific overload the call
+/// is ambiguous, unless some arguments are NULL and all the overloads return the same type, which
+/// then gives the same NULL result whichever overload is called.
+pub fn resolve_overload<'a, O: Overload>(
+    name: &str,
+    overloads: &'a [O],
+    arg_types: &[DataType],
+) -> Result<(&'a O, Resolution)> {
+    let mut candidates = overloads
+        .iter()
+        .filter_map(|o| o.resolve(arg_types).map(|res| (o, res)))
+        .collect::<Vec<_>>();
+    let Some(cost) = candidates.iter().map(|(_, res)| res.cost).min() else {
+        return plan_err!(
+            "{}",
+            unexpected_parameters(name, arg_types, overloads.iter().map(|o| o.signature()))
+        );
+    };
+    candidates.retain(|(_, res)| res.cost == cost);
+    let most_specific = candidates.iter().position(|(_, res)| {
+        candidates
+            .iter()
+            .all(|(_, other)| is_as_specific(&res.coerced_types, &other.coerced_types))
+    });
+    if let Some(i) = most_specific {
+        return Ok(candidates.swap_remove(i));
+    }
+    let return_types = candidates
+        .iter()
+        .map(|(o, res)| o.return_type(&res.coerced_types))
+        .collect::<Vec<_>>();
+    if arg_types.contains(&DataType::Null)
+        && return_types
+            .iter()
+            .all(|dt| dt.is_some() && *dt == return_types[0])
+    {
+        return Ok(candidates.swap_remove(0));
+    }
+    plan_err!(
+        "{}",
+        ambiguous_call(
+            name,
+            arg_types,
+            candidates.iter().map(|(o, _)| o.signature())
+        )
+    )
+}
+
+/// Whether the argument types `types` all coerce to the argument types `other`.
+fn is_as_specific(types: &[DataType], other: &[DataType]) -> bool {
+    types.len() == other.len()
+        && types
+            .iter()
+            .zip(other)
+            .all(|(dt, other)| can_coerce(dt, other))
+}
+
+/// A UDF dispatching to the overload of a function best matching its argument types.
+#[derive(Debug)]
+pub struct OverloadedUDF {
+    name: String,
+    signature: Signature,
+    /// The overloads, in declaration order, see [`resolve_overload`].
+    overloads: Vec<FunctionOverload>,
+    documentation: Option<Documentation>,
+}
+
+impl OverloadedUDF {
+    /// Create the dispatcher for `overloads`, which must all share the name `name`.
+    pub fn new(name: &str, overloads: Vec<FunctionOverload>) -> Self {
+        let volatility = overloads
+            .iter()
+            .map(|o| o.udf.signature().volatility)
+            .max_by_key(|v| match v {
+                Volatility::Immutable => 0,
+                Volatility::Stable => 1,
+                Volatility::Volatile => 2,
+            })
+            .unwrap_or(Volatility::Immutable);
+        let has_nullary = overloads
+            .iter()
+            .any(|o| o.signature.parameters.is_empty() && !o.signature.variadic);
+        let has_args = overloads.iter().any(|o| !o.signature.parameters.is_empty());
+        let signature = match (has_nullary, has_args) {
+            (true, false) => Signature::nullary(volatility),
+            (true, true) => Signature::one_of(
+                vec![TypeSignature::Nullary, TypeSignature::UserDefined],
+                volatility,
+            ),
+            (false, _) => Signature::user_defined(volatility),
+        };
+        let documentation =
+            overload_documentation(overloads.iter().filter_map(|o| o.udf.documentation()));
+        Self {
+            name: name.to_string(),
+            signature,
+            overloads,
+            documentation,
+        }
+    }
+
+    /// The overloads of this function, in declaration order.
+    pub fn overloads(&self) -> &[FunctionOverload] {
+        &self.overloads
+    }
+
+    /// Pick the overload best matching `arg_types`, see [`resolve_overload`].
+    pub fn resolve(&self, arg_types: &[DataType]) -> Result<(&Arc<ScalarUDF>, Resolution)> {
+        resolve_overload(&self.name, &self.overloads, arg_types).map(|(o, res)| (&o.udf, res))
+    }
+}
+
+impl ScalarUDFImpl for OverloadedUDF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let (_, res) = self.resolve(arg_types)?;
+        Ok(res.coerced_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let (udf, res) = self.resolve(arg_types)?;
+        udf.return_type(&res.coerced_types)
+    }
+
+    fn return_type_from_args(&self, args: ReturnTypeArgs) -> Result<ReturnInfo> {
+        let (udf, res) = self.resolve(args.arg_types)?;
+        udf.return_type_from_args(ReturnTypeArgs {
+            arg_types: &res.coerced_types,
+            ..args
+        })
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ScalarFunctionArgs {
+            args,
+            number_rows,
+            return_type,
+        } = args;
+        let arg_types = args.iter().map(|a| a.data_type()).collect::<Vec<_>>();
+        let (udf, res) = self.resolve(&arg_types)?;
+        // Arguments are normally coerced by the planner already, see `coerce_types`
+        let args = if res.cost == 0 {
+            args
+        } else {
+            args.iter()
+                .zip(&res.coerced_types)
+                .map(|(arg, dt)| arg.cast_to(dt, None))
+                .collect::<Result<Vec<_>>>()?
+        };
+        udf.invoke_with_args(ScalarFunctionArgs {
+            args,
+            number_rows,
+            return_type,
+        })
+    }
+
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        let arg_types = match args
+            .iter()
+            .map(|a| info.get_data_type(a))
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(arg_types) => arg_types,
+            Err(_) => return Ok(ExprSimplifyResult::Original(args)),
+        };
+        match self.resolve(&arg_types) {
+            Ok((udf, _)) => udf.simplify(args, info),
+            Err(_) => Ok(ExprSimplifyResult::Original(args)),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.documentation.as_ref()
+    }
+}
+
+/// The documentation of the first documented overload, listing the syntax of every overload.
+pub(crate) fn overload_documentation<'a>(
+    documentations: impl IntoIterator<Item = &'a Documentation>,
+) -> Option<Documentation> {
+    let documentations = documentations.into_iter().collect::<Vec<_>>();
+    let mut documentation = (*documentations.first()?).clone();
+    let mut syntax = vec![];
+    for example in documentations.iter().map(|d| &d.syntax_example) {
+        if !syntax.contains(example) {
+            syntax.push(example.clone());
+        }
+    }
+    documentation.syntax_example = syntax.join("\n");
+    Some(documentation)
+}
+
+/// Group `overloads` by dialect and function name, preserving their order.
+pub fn group_overloads<O: Overload>(
+    overloads: impl IntoIterator<Item = O>,
+) -> Vec<(&'static str, String, Vec<O>)> {
+    let mut groups: Vec<(&'static str, String, Vec<O>)> = vec![];
+    for overload in overloads {
+        let group = groups.iter_mut().find(|(dialect, name, _)| {
+            *dialect == overload.dialect() && name == overload.function_name()
+        });
+        match group {
+            Some((_, _, group)) => group.push(overload),
+            None => groups.push((
+                overload.dialect(),
+                overload.function_name().to_string(),
+                vec![overload],
+            )),
+        }
+    }
+    groups
+}
+
+/// Bundle the overloads in `functions` by dialect and name into their dispatchers (e.g.
+/// [`OverloadedUDF`]s), keyed `dialect::name`.
+pub fn overloaded_functions<O: Overload>(
+    functions: impl IntoIterator<Item = O>,
+) -> Vec<(String, Arc<O::Function>)> {
+    group_overloads(functions)
+        .into_iter()
+        .map(|(dialect, name, overloads)| {
+            (
+                format!("{dialect}::{name}"),
+                O::dispatcher(&name, overloads),
+            )
+        })
+        .collect()
+}
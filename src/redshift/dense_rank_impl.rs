@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+use std::any::Any;
+
+fn dense_rank_partition_evaluator(
+    _args: PartitionEvaluatorArgs,
+) -> Result<Box<dyn PartitionEvaluator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn dense_rank_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct dense_rankFunc {
+    signature: Signature,
+}
+
+impl dense_rankFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::nullary(volatility),
+        }
+    }
+}
+
+impl WindowUDFImpl for dense_rankFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "dense_rank"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type = dense_rank_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        dense_rank_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Redshift, "dense_rank")
+    }
+}
@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use crate::utils_window::OffsetEvaluator;
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+use std::any::Any;
+
+fn lag_1_partition_evaluator(args: PartitionEvaluatorArgs) -> Result<Box<dyn PartitionEvaluator>> {
+    Ok(Box::new(OffsetEvaluator::new(false, &args)))
+}
+
+fn lag_1_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    Ok(arg_types[0].clone())
+}
+
+fn lag_1_bigint_partition_evaluator(
+    args: PartitionEvaluatorArgs,
+) -> Result<Box<dyn PartitionEvaluator>> {
+    Ok(Box::new(OffsetEvaluator::new(false, &args)))
+}
+
+fn lag_1_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    Ok(arg_types[0].clone())
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct lag_1Func {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl lag_1Func {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl WindowUDFImpl for lag_1Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "lag"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("lag", &self.parameters, arg_types)
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type = lag_1_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        lag_1_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Redshift, "lag_1")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct lag_1_bigintFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl lag_1_bigintFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1", "bigint"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl WindowUDFImpl for lag_1_bigintFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "lag"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("lag", &self.parameters, arg_types)
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type = lag_1_bigint_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        lag_1_bigint_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Redshift, "lag_1_bigint")
+    }
+}
@@ -0,0 +1,108 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_snake_case)]
+mod cume_dist_impl;
+mod dense_rank_impl;
+mod first_value_impl;
+mod lag_impl;
+mod last_value_impl;
+mod lead_impl;
+mod nth_value_impl;
+mod ntile_impl;
+mod percent_rank_impl;
+mod rank_impl;
+mod ratio_to_report_impl;
+mod row_number_impl;
+mod variance_impl;
+
+// create UDWFs
+make_udwf_function!(cume_dist_impl::cume_distFunc, CUME_DIST, cume_dist);
+
+make_udwf_function!(dense_rank_impl::dense_rankFunc, DENSE_RANK, dense_rank);
+
+make_udwf_function!(
+    first_value_impl::first_value_1Func,
+    FIRST_VALUE_1,
+    first_value_1
+);
+
+make_udwf_function!(lag_impl::lag_1Func, LAG_1, lag_1);
+make_udwf_function!(lag_impl::lag_1_bigintFunc, LAG_1_BIGINT, lag_1_bigint);
+
+make_udwf_function!(
+    last_value_impl::last_value_1Func,
+    LAST_VALUE_1,
+    last_value_1
+);
+
+make_udwf_function!(lead_impl::lead_1Func, LEAD_1, lead_1);
+make_udwf_function!(lead_impl::lead_1_bigintFunc, LEAD_1_BIGINT, lead_1_bigint);
+
+make_udwf_function!(
+    nth_value_impl::nth_value_1_bigintFunc,
+    NTH_VALUE_1_BIGINT,
+    nth_value_1_bigint
+);
+
+make_udwf_function!(ntile_impl::ntile_bigintFunc, NTILE_BIGINT, ntile_bigint);
+
+make_udwf_function!(
+    percent_rank_impl::percent_rankFunc,
+    PERCENT_RANK,
+    percent_rank
+);
+
+make_udwf_function!(rank_impl::rankFunc, RANK, rank);
+
+make_udwf_function!(
+    ratio_to_report_impl::ratio_to_report_1Func,
+    RATIO_TO_REPORT_1,
+    ratio_to_report_1
+);
+
+make_udwf_function!(row_number_impl::row_numberFunc, ROW_NUMBER, row_number);
+
+make_udwf_function!(variance_impl::variance_1Func, VARIANCE_1, variance_1);
+
+// Export the functions out of this package, both as expr_fn as well as a list of functions
+export_functions!();
+
+// Export the aggregate functions out of this package
+export_aggregate_functions!();
+
+// Export the window functions out of this package
+export_window_functions!(
+    (redshift, cume_dist, []),
+    (redshift, dense_rank, []),
+    (redshift, first_value_1, ["$1"]),
+    (redshift, lag_1, ["$1"]),
+    (redshift, lag_1_bigint, ["$1", "bigint"]),
+    (redshift, last_value_1, ["$1"]),
+    (redshift, lead_1, ["$1"]),
+    (redshift, lead_1_bigint, ["$1", "bigint"]),
+    (redshift, nth_value_1_bigint, ["$1", "bigint"]),
+    (redshift, ntile_bigint, ["bigint"]),
+    (redshift, percent_rank, []),
+    (redshift, rank, []),
+    (redshift, ratio_to_report_1, ["$1"]),
+    (redshift, row_number, []),
+    (redshift, variance_1, ["$1"]),
+);
+
+// Export the table functions out of this package
+export_table_functions!();
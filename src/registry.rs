@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Registration of a dialect's functions into a DataFusion [`FunctionRegistry`]
+//! (e.g. a `SessionContext`) under their SQL names.
+
+use crate::aggregate::AggregateOverload;
+use crate::overload::{group_overloads, FunctionOverload, Overload};
+use crate::table::TableOverload;
+use crate::window::WindowOverload;
+use datafusion::common::Result;
+use datafusion::execution::context::SessionContext;
+use datafusion::execution::FunctionRegistry;
+use std::sync::Arc;
+
+/// The SQL dialects whose functions can be registered.
+///
+/// Only the window and table functions of Redshift and Snowflake are generated so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    Trino,
+    Redshift,
+    Snowflake,
+}
+
+impl Dialect {
+    /// The name of the dialect, as used in the function keys (e.g. `trino::abs`) and asset folders.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::Trino => "trino",
+            Dialect::Redshift => "redshift",
+            Dialect::Snowflake => "snowflake",
+        }
+    }
+
+    /// Every overload of every function of the dialect.
+    pub fn overloads(&self) -> Vec<FunctionOverload> {
+        match self {
+            Dialect::Trino => crate::trino::overloads(),
+            Dialect::Redshift => crate::redshift::overloads(),
+            Dialect::Snowflake => crate::snowflake::overloads(),
+        }
+    }
+
+    /// Every overload of every aggregate function of the dialect.
+    pub fn aggregate_overloads(&self) -> Vec<AggregateOverload> {
+        match self {
+            Dialect::Trino => crate::trino::aggregate_overloads(),
+            Dialect::Redshift => crate::redshift::aggregate_overloads(),
+            Dialect::Snowflake => crate::snowflake::aggregate_overloads(),
+        }
+    }
+
+    /// Every overload of every window function of the dialect.
+    pub fn window_overloads(&self) -> Vec<WindowOverload> {
+        match self {
+            Dialect::Trino => crate::trino::window_overloads(),
+            Dialect::Redshift => crate::redshift::window_overloads(),
+            Dialect::Snowflake => crate::snowflake::window_overloads(),
+        }
+    }
+
+    /// Every overload of every table function of the dialect.
+    pub fn table_overloads(&self) -> Vec<TableOverload> {
+        match self {
+            Dialect::Trino => crate::trino::table_overloads(),
+            Dialect::Redshift => crate::redshift::table_overloads(),
+            Dialect::Snowflake => crate::snowflake::table_overloads(),
+        }
+    }
+}
+
+/// What to do when a dialect function has the same name as a function already in the registry,
+/// typically a DataFusion builtin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Builtins {
+    /// Replace the existing function by the dialect's.
+    #[default]
+    Shadow,
+    /// Keep the existing function, and skip the dialect's.
+    Keep,
+}
+
+/// Options of [`register_dialect_with_options`].
+#[derive(Debug, Clone)]
+pub struct RegisterOptions {
+    /// How to handle functions already in the registry.
+    pub builtins: Builtins,
+    /// Register the functions as `<schema>.<name>` (e.g. `trino.abs`) instead of `<name>`.
+    pub schema: Option<String>,
+    /// Skip overloads that are not implemented at all (see [`crate::coverage`]), the default.
+    /// Functions with no implemented overload are not registered, so that they do not shadow the
+    /// DataFusion builtins of the same name (`abs`, `coalesce`, ...).
+    pub implemented_only: bool,
+}
+
+impl Default for RegisterOptions {
+    fn default() -> Self {
+        Self {
+            builtins: Builtins::default(),
+            schema: None,
+            implemented_only: true,
+        }
+    }
+}
+
+impl RegisterOptions {
+    pub fn with_builtins(mut self, builtins: Builtins) -> Self {
+        self.builtins = builtins;
+        self
+    }
+
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    pub fn with_implemented_only(mut self, implemented_only: bool) -> Self {
+        self.implemented_only = implemented_only;
+        self
+    }
+}
+
+/// Register the implemented functions of `dialect` under their SQL names, shadowing existing
+/// functions. Returns the names of the registered functions.
+///
+/// ```ignore
+/// let mut ctx = SessionContext::new();
+/// register_dialect(&mut ctx, Dialect::Trino)?;
+/// ctx.sql("SELECT regexp_like('abc', 'b')").await?;
+/// ```
+pub fn register_dialect(
+    registry: &mut dyn FunctionRegistry,
+    dialect: Dialect,
+) -> Result<Vec<String>> {
+    register_dialect_with_options(registry, dialect, &RegisterOptions::default())
+}
+
+/// Register the functions of `dialect` under their SQL names, one UDF per name dispatching
+/// to the overload matching the argument types (see [`crate::overload::OverloadedUDF`],
+/// [`crate::aggregate::OverloadedUDAF`] and [`crate::window::OverloadedUDWF`]). Returns the names of the registered functions.
+///
+/// The placeholder functions of lambda expressions (see [`crate::lambda`]) are registered along
+/// with the higher-order functions, under their plain names, and are not among the returned names.
+///
+/// Aggregate and window overloads that are not implemented at all are never registered, whatever
+/// `implemented_only`: they would shadow the DataFusion aggregate and window functions (`count`,
+/// `sum`, `rank`, ...) that most queries rely on.
+pub fn register_dialect_with_options(
+    registry: &mut dyn FunctionRegistry,
+    dialect: Dialect,
+    options: &RegisterOptions,
+) -> Result<Vec<String>> {
+    let overloads = dialect
+        .overloads()
+        .into_iter()
+        .filter(|o| !options.implemented_only || o.status().is_usable());
+    let existing = registry.udfs();
+    let mut registered = vec![];
+    for (_, name, overloads) in group_overloads(overloads) {
+        let name = match &options.schema {
+            Some(schema) => format!("{schema}.{name}"),
+            None => name,
+        };
+        if options.builtins == Builtins::Keep && existing.contains(&name) {
+            continue;
+        }
+        registry.register_udf(FunctionOverload::dispatcher(&name, overloads))?;
+        registered.push(name);
+    }
+    if dialect
+        .overloads()
+        .iter()
+        .any(|o| o.signature.takes_lambda())
+    {
+        for udf in crate::lambda::lambda_functions() {
+            registry.register_udf(udf)?;
+        }
+    }
+
+    let aggregates = dialect
+        .aggregate_overloads()
+        .into_iter()
+        .filter(|o| o.status().is_usable());
+    for (_, name, overloads) in group_overloads(aggregates) {
+        let name = match &options.schema {
+            Some(schema) => format!("{schema}.{name}"),
+            None => name,
+        };
+        if options.builtins == Builtins::Keep && registry.udaf(&name).is_ok() {
+            continue;
+        }
+        registry.register_udaf(AggregateOverload::dispatcher(&name, overloads))?;
+        registered.push(name);
+    }
+
+    let windows = dialect
+        .window_overloads()
+        .into_iter()
+        .filter(|o| o.status().is_usable());
+    for (_, name, overloads) in group_overloads(windows) {
+        let name = match &options.schema {
+            Some(schema) => format!("{schema}.{name}"),
+            None => name,
+        };
+        if options.builtins == Builtins::Keep && registry.udwf(&name).is_ok() {
+            continue;
+        }
+        registry.register_udwf(WindowOverload::dispatcher(&name, overloads))?;
+        registered.push(name);
+    }
+    Ok(registered)
+}
+
+/// Register the table functions of `dialect` with `ctx` under their SQL names, one function per
+/// name dispatching to the overload matching the argument types (see [`crate::table::OverloadedUDTF`]).
+/// Returns the names of the registered functions.
+///
+/// Table functions are registered with a `SessionContext` rather than a [`FunctionRegistry`],
+/// and always under their plain names: DataFusion only resolves a table function by the first
+/// part of its name, so `options.schema` does not apply. Queries passing named arguments to them
+/// (`generator(rowcount => 10)`) must be run with [`crate::sql`].
+pub fn register_table_functions(
+    ctx: &SessionContext,
+    dialect: Dialect,
+    options: &RegisterOptions,
+) -> Result<Vec<String>> {
+    let overloads = dialect
+        .table_overloads()
+        .into_iter()
+        .filter(|o| !options.implemented_only || o.status().is_usable());
+    let mut registered = vec![];
+    for (_, name, overloads) in group_overloads(overloads) {
+        if options.builtins == Builtins::Keep && ctx.table_function(&name).is_ok() {
+            continue;
+        }
+        let function = TableOverload::dispatcher(&name, overloads);
+        ctx.register_udtf(&name, Arc::clone(function.function()));
+        registered.push(name);
+    }
+    Ok(registered)
+}
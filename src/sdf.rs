@@ -0,0 +1,147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Function definitions of the embedded `assets/<dialect>/functions.sdf.yml` files.
+
+use crate::Asset;
+use datafusion::common::{exec_datafusion_err, Result};
+use serde::Deserialize;
+
+/// One `function:` entry of a `functions.sdf.yml` file, i.e. one overload of a function.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SdfFunction {
+    pub name: String,
+    /// `scalar` (the default), `aggregate`, `window` or `table`
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<SdfParameter>,
+    #[serde(default)]
+    pub optional_parameters: Vec<SdfParameter>,
+    #[serde(default)]
+    pub returns: Option<SdfReturns>,
+    /// `uniform`, `any`, `non-uniform` or `even-odd`
+    #[serde(default)]
+    pub variadic: Option<String>,
+    #[serde(default)]
+    pub volatility: Option<String>,
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub cross_link: Option<String>,
+    #[serde(default)]
+    pub examples: Vec<SdfExample>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SdfParameter {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub datatype: String,
+    /// The parameter must be this literal, e.g. the precision of `current_timestamp(3)`
+    #[serde(default)]
+    pub constant: Option<serde_yaml::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SdfReturns {
+    pub datatype: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SdfExample {
+    pub input: String,
+    #[serde(default)]
+    pub output: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SdfDocument {
+    function: SdfFunction,
+}
+
+impl SdfFunction {
+    pub fn kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or("scalar")
+    }
+
+    pub fn section(&self) -> &str {
+        self.section.as_deref().unwrap_or("other")
+    }
+
+    /// The name of the generated overload, e.g. `abs_tinyint` or `regexp_like_varchar_joniregexp`.
+    pub fn overload_name(&self) -> String {
+        let mut name = self.name.to_lowercase();
+        for param in &self.parameters {
+            name.push('_');
+            name.push_str(&param.name_part());
+        }
+        name
+    }
+}
+
+impl SdfParameter {
+    /// The part of the overload name describing this parameter: the datatype with every run of
+    /// non-alphanumeric characters replaced by `_`, e.g. `decimal(p, s)` -> `decimal_p_s`.
+    fn name_part(&self) -> String {
+        let datatype = self.datatype.replace([' ', '$'], "").to_lowercase();
+        let mut part = String::new();
+        for c in datatype.chars() {
+            if c.is_ascii_alphanumeric() {
+                part.push(c);
+            } else if !part.ends_with('_') {
+                part.push('_');
+            }
+        }
+        let mut part = part.trim_matches('_').to_string();
+        if let Some(constant) = &self.constant {
+            part.push('_');
+            part.push_str(&match constant {
+                serde_yaml::Value::String(s) => s.clone(),
+                other => serde_yaml::to_string(other)
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            });
+        }
+        part
+    }
+}
+
+/// Parse the function definitions of a `functions.sdf.yml` file.
+pub fn parse_functions(yaml: &str) -> Result<Vec<SdfFunction>> {
+    let mut functions = vec![];
+    for document in serde_yaml::Deserializer::from_str(yaml) {
+        let document = Option::<SdfDocument>::deserialize(document)
+            .map_err(|e| exec_datafusion_err!("Invalid function definition: {e}"))?;
+        functions.extend(document.map(|d| d.function));
+    }
+    Ok(functions)
+}
+
+/// The function definitions of `dialect`, from the embedded assets.
+pub fn load_functions(dialect: &str) -> Result<Vec<SdfFunction>> {
+    let filename = format!("{dialect}/functions.sdf.yml");
+    let file = Asset::load_file(&filename)
+        .ok_or_else(|| exec_datafusion_err!("No function definitions for dialect {dialect}"))?;
+    let yaml = std::str::from_utf8(&file.data)
+        .map_err(|e| exec_datafusion_err!("Invalid {filename}: {e}"))?;
+    parse_functions(yaml)
+}
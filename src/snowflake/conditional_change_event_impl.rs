@@ -0,0 +1,120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::array::{make_comparator, Array, ArrayRef, Decimal128Array};
+use arrow::compute::SortOptions;
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+use std::any::Any;
+use std::sync::Arc;
+
+/// The number of times the value changed since the first row of the partition, NULL values
+/// being skipped: a row is compared with the last row whose value is not NULL.
+#[derive(Debug)]
+struct ConditionalChangeEventEvaluator;
+
+impl PartitionEvaluator for ConditionalChangeEventEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        let values = &values[0];
+        let compare = make_comparator(values.as_ref(), values.as_ref(), SortOptions::default())?;
+        let mut events = Vec::with_capacity(num_rows);
+        let mut previous = None;
+        let mut count = 0;
+        for row in 0..num_rows {
+            if values.is_valid(row) {
+                if previous.is_some_and(|previous| compare(previous, row).is_ne()) {
+                    count += 1;
+                }
+                previous = Some(row);
+            }
+            events.push(count);
+        }
+        Ok(Arc::new(
+            Decimal128Array::from(events).with_precision_and_scale(38, 0)?,
+        ))
+    }
+}
+
+fn conditional_change_event_1_partition_evaluator(
+    _args: PartitionEvaluatorArgs,
+) -> Result<Box<dyn PartitionEvaluator>> {
+    Ok(Box::new(ConditionalChangeEventEvaluator))
+}
+
+fn conditional_change_event_1_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Ok(DataType::Decimal128(38, 0))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct conditional_change_event_1Func {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl conditional_change_event_1Func {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl WindowUDFImpl for conditional_change_event_1Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "conditional_change_event"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments(
+            "conditional_change_event",
+            &self.parameters,
+            arg_types,
+        )
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type = conditional_change_event_1_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        conditional_change_event_1_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Snowflake, "conditional_change_event_1")
+    }
+}
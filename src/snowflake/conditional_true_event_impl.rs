@@ -0,0 +1,178 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::array::{ArrayRef, Decimal128Array};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::cast::as_boolean_array;
+use datafusion::common::Result;
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+use std::any::Any;
+use std::sync::Arc;
+
+/// The number of rows whose condition is true since the first row of the partition, up to and
+/// including the current row. A NULL condition is false, and so is a number equal to 0.
+#[derive(Debug)]
+struct ConditionalTrueEventEvaluator;
+
+impl PartitionEvaluator for ConditionalTrueEventEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], _num_rows: usize) -> Result<ArrayRef> {
+        let conditions = match values[0].data_type() {
+            DataType::Boolean => Arc::clone(&values[0]),
+            // Decimals cannot be cast to booleans directly
+            _ => cast(&cast(&values[0], &DataType::Float64)?, &DataType::Boolean)?,
+        };
+        let events = as_boolean_array(&conditions)?
+            .iter()
+            .scan(0, |count, condition| {
+                *count += i128::from(condition == Some(true));
+                Some(*count)
+            })
+            .collect::<Vec<_>>();
+        Ok(Arc::new(
+            Decimal128Array::from(events).with_precision_and_scale(38, 0)?,
+        ))
+    }
+}
+
+fn conditional_true_event_boolean_partition_evaluator(
+    _args: PartitionEvaluatorArgs,
+) -> Result<Box<dyn PartitionEvaluator>> {
+    Ok(Box::new(ConditionalTrueEventEvaluator))
+}
+
+fn conditional_true_event_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Ok(DataType::Decimal128(38, 0))
+}
+
+fn conditional_true_event_decimal_38_0_partition_evaluator(
+    _args: PartitionEvaluatorArgs,
+) -> Result<Box<dyn PartitionEvaluator>> {
+    Ok(Box::new(ConditionalTrueEventEvaluator))
+}
+
+fn conditional_true_event_decimal_38_0_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Ok(DataType::Decimal128(38, 0))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct conditional_true_event_booleanFunc {
+    signature: Signature,
+}
+
+impl conditional_true_event_booleanFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Boolean], volatility),
+        }
+    }
+}
+
+impl WindowUDFImpl for conditional_true_event_booleanFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "conditional_true_event"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type = conditional_true_event_boolean_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        conditional_true_event_boolean_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Snowflake,
+            "conditional_true_event_boolean",
+        )
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct conditional_true_event_decimal_38_0Func {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl conditional_true_event_decimal_38_0Func {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(38, 0)"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl WindowUDFImpl for conditional_true_event_decimal_38_0Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "conditional_true_event"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments(
+            "conditional_true_event",
+            &self.parameters,
+            arg_types,
+        )
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let return_type =
+            conditional_true_event_decimal_38_0_return_type(field_args.input_types())?;
+        Ok(Field::new(field_args.name(), return_type, true))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        conditional_true_event_decimal_38_0_partition_evaluator(partition_evaluator_args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Snowflake,
+            "conditional_true_event_decimal_38_0",
+        )
+    }
+}
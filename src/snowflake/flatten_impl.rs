@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::array::{ArrayRef, Int64Array, Int64Builder, StringBuilder};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion::catalog::{TableFunctionImpl, TableProvider};
+use datafusion::common::{exec_datafusion_err, plan_err, Result, ScalarValue};
+use datafusion::datasource::MemTable;
+use datafusion::logical_expr::Expr;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// The kinds of values `flatten` expands, given by its `mode` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+impl FlattenMode {
+    fn parse(mode: &str) -> Result<Self> {
+        match mode.to_uppercase().as_str() {
+            "OBJECT" => Ok(Self::Object),
+            "ARRAY" => Ok(Self::Array),
+            "BOTH" => Ok(Self::Both),
+            _ => plan_err!("Invalid mode '{mode}' for flatten, expected OBJECT, ARRAY or BOTH"),
+        }
+    }
+}
+
+/// One step of a path into a semi-structured value, e.g. `a`, `[0]` or `['a b']`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathElement {
+    Key(String),
+    Index(usize),
+}
+
+/// The elements of a path such as `a.b[0]['c d']` or `a."b c"`.
+fn parse_path(path: &str) -> Result<Vec<PathElement>> {
+    let invalid = || plan_err!("Invalid path '{path}' for flatten");
+    let mut elements = vec![];
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' if !elements.is_empty() => {}
+            '[' => {
+                let element = match chars.peek() {
+                    Some(&quote) if quote == '\'' || quote == '"' => {
+                        chars.next();
+                        let key = chars.by_ref().take_while(|&c| c != quote).collect();
+                        PathElement::Key(key)
+                    }
+                    _ => {
+                        let mut digits = String::new();
+                        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                            digits.push(c);
+                        }
+                        match digits.parse() {
+                            Ok(index) => PathElement::Index(index),
+                            Err(_) => return invalid(),
+                        }
+                    }
+                };
+                if chars.next() != Some(']') {
+                    return invalid();
+                }
+                elements.push(element);
+            }
+            '"' => {
+                let key = chars.by_ref().take_while(|&c| c != '"').collect();
+                elements.push(PathElement::Key(key));
+            }
+            c if c != '.' && c != ']' => {
+                let mut key = c.to_string();
+                while let Some(c) = chars.next_if(|&c| c != '.' && c != '[') {
+                    key.push(c);
+                }
+                elements.push(PathElement::Key(key));
+            }
+            _ => return invalid(),
+        }
+    }
+    Ok(elements)
+}
+
+/// The path of the member `key` of the object at `path`: `key`, `path.key`, or `path['key']`
+/// if the key is not an identifier.
+fn member_path(path: &str, key: &str) -> String {
+    let is_identifier = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    match (path.is_empty(), is_identifier) {
+        (true, true) => key.to_string(),
+        (false, true) => format!("{path}.{key}"),
+        (_, false) => format!("{path}['{key}']"),
+    }
+}
+
+/// The rows of a `flatten` call, as the columns of its result.
+#[derive(Default)]
+struct FlattenRows {
+    keys: StringBuilder,
+    paths: StringBuilder,
+    indices: Int64Builder,
+    values: StringBuilder,
+    this: StringBuilder,
+    len: usize,
+}
+
+impl FlattenRows {
+    fn push(
+        &mut self,
+        key: Option<&str>,
+        path: &str,
+        index: Option<usize>,
+        value: Option<&Value>,
+        this: Option<&Value>,
+    ) {
+        self.keys.append_option(key);
+        self.paths.append_value(path);
+        self.indices.append_option(index.map(|i| i as i64));
+        self.values.append_option(value.map(Value::to_string));
+        self.this.append_option(this.map(Value::to_string));
+        self.len += 1;
+    }
+
+    /// Add a row per element of `container`, at `path`, and of its nested elements if
+    /// `recursive`.
+    fn flatten(&mut self, container: &Value, path: &str, recursive: bool, mode: FlattenMode) {
+        match container {
+            Value::Array(items) if mode != FlattenMode::Object => {
+                for (index, item) in items.iter().enumerate() {
+                    let path = format!("{path}[{index}]");
+                    self.push(None, &path, Some(index), Some(item), Some(container));
+                    if recursive {
+                        self.flatten(item, &path, recursive, mode);
+                    }
+                }
+            }
+            // The members of an object are sorted by key, as in Snowflake
+            Value::Object(members) if mode != FlattenMode::Array => {
+                for (key, member) in members {
+                    let path = member_path(path, key);
+                    self.push(Some(key), &path, None, Some(member), Some(container));
+                    if recursive {
+                        self.flatten(member, &path, recursive, mode);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Result<RecordBatch> {
+        let schema = Schema::new(vec![
+            Field::new("seq", DataType::Int64, true),
+            Field::new("key", DataType::Utf8, true),
+            Field::new("path", DataType::Utf8, true),
+            Field::new("index", DataType::Int64, true),
+            Field::new("value", DataType::Utf8, true),
+            Field::new("this", DataType::Utf8, true),
+        ]);
+        // A single input, whose sequence number is 1
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![1; self.len])),
+            Arc::new(self.keys.finish()),
+            Arc::new(self.paths.finish()),
+            Arc::new(self.indices.finish()),
+            Arc::new(self.values.finish()),
+            Arc::new(self.this.finish()),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+}
+
+/// The semi-structured value of the `input` of `flatten`, parsed from its JSON text.
+fn variant(input: &ScalarValue) -> Result<Option<Value>> {
+    match input {
+        _ if input.is_null() => Ok(None),
+        ScalarValue::Utf8(Some(text))
+        | ScalarValue::LargeUtf8(Some(text))
+        | ScalarValue::Utf8View(Some(text)) => serde_json::from_str(text)
+            .map(Some)
+            .map_err(|e| exec_datafusion_err!("Error parsing JSON: {e}")),
+        _ => Ok(Some(Value::String(input.to_string()))),
+    }
+}
+
+/// `flatten(input [, path [, outer [, recursive [, mode]]]])`: a row per element of the array
+/// or object `input` (a VARIANT, as JSON text), or of the value at `path` in it. The `value` and
+/// `this` columns are VARIANTs too. With `outer`, a single row of NULLs is returned rather than
+/// none if there is nothing to expand.
+///
+/// As DataFusion does not support lateral table functions, `input` must be a constant, e.g. a
+/// parameter of the query: [`crate::sql`] reports `LATERAL flatten(input => t.v)` as such.
+fn flatten_1_call(args: &[ScalarValue]) -> Result<Arc<dyn TableProvider>> {
+    let string = |i: usize| match args.get(i) {
+        Some(ScalarValue::Utf8(Some(s))) => Some(s.as_str()),
+        _ => None,
+    };
+    let boolean = |i: usize| matches!(args.get(i), Some(ScalarValue::Boolean(Some(true))));
+    let path = string(1).unwrap_or_default();
+    let outer = boolean(2);
+    let recursive = boolean(3);
+    let mode = FlattenMode::parse(string(4).unwrap_or("BOTH"))?;
+
+    let mut this = variant(&args[0])?;
+    for element in parse_path(path)? {
+        this = match (this, element) {
+            (Some(Value::Object(mut members)), PathElement::Key(key)) => members.remove(&key),
+            (Some(Value::Array(mut items)), PathElement::Index(index)) if index < items.len() => {
+                Some(items.swap_remove(index))
+            }
+            _ => None,
+        };
+    }
+    let mut rows = FlattenRows::default();
+    if let Some(this) = &this {
+        rows.flatten(this, path, recursive, mode);
+    }
+    if outer && rows.len == 0 {
+        rows.push(None, path, None, None, this.as_ref());
+    }
+    let batch = rows.finish()?;
+    Ok(Arc::new(MemTable::try_new(
+        batch.schema(),
+        vec![vec![batch]],
+    )?))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug, Default)]
+pub(super) struct flatten_1Func;
+
+impl flatten_1Func {
+    pub const NAME: &'static str = "flatten";
+}
+
+impl TableFunctionImpl for flatten_1Func {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        let args = crate::table::literal_arguments(Self::NAME, args)?;
+        flatten_1_call(&args)
+    }
+}
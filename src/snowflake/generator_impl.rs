@@ -0,0 +1,174 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::array::new_null_array;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use async_trait::async_trait;
+use datafusion::catalog::{Session, TableFunctionImpl, TableProvider};
+use datafusion::common::{plan_err, project_schema, Result, ScalarValue};
+use datafusion::logical_expr::{Expr, TableType};
+use datafusion::physical_plan::memory::{LazyBatchGenerator, LazyMemoryExec};
+use datafusion::physical_plan::ExecutionPlan;
+use parking_lot::RwLock;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The table of `generator`: `rowcount` rows of a NULL `dummy` column, generated lazily until
+/// `timelimit` has elapsed since the first batch.
+#[derive(Debug)]
+struct GeneratorTable {
+    schema: SchemaRef,
+    rowcount: usize,
+    timelimit: Option<Duration>,
+}
+
+#[async_trait]
+impl TableProvider for GeneratorTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Temporary
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = project_schema(&self.schema, projection)?;
+        let batches = GeneratorBatches {
+            schema: Arc::clone(&schema),
+            remaining: limit.map_or(self.rowcount, |limit| limit.min(self.rowcount)),
+            batch_size: state.config_options().execution.batch_size,
+            timelimit: self.timelimit,
+            deadline: None,
+        };
+        Ok(Arc::new(LazyMemoryExec::try_new(
+            schema,
+            vec![Arc::new(RwLock::new(batches))],
+        )?))
+    }
+}
+
+#[derive(Debug)]
+struct GeneratorBatches {
+    schema: SchemaRef,
+    remaining: usize,
+    batch_size: usize,
+    timelimit: Option<Duration>,
+    deadline: Option<Instant>,
+}
+
+impl fmt::Display for GeneratorBatches {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "generator: remaining={}", self.remaining)?;
+        if let Some(timelimit) = self.timelimit {
+            write!(f, ", timelimit={timelimit:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl LazyBatchGenerator for GeneratorBatches {
+    fn generate_next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        if let Some(timelimit) = self.timelimit {
+            let deadline = *self
+                .deadline
+                .get_or_insert_with(|| Instant::now() + timelimit);
+            if Instant::now() >= deadline {
+                return Ok(None);
+            }
+        }
+        let rows = self.remaining.min(self.batch_size);
+        self.remaining -= rows;
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| new_null_array(f.data_type(), rows))
+            .collect();
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        let batch = RecordBatch::try_new_with_options(Arc::clone(&self.schema), columns, &options)?;
+        Ok(Some(batch))
+    }
+}
+
+/// `generator([rowcount [, timelimit]])`: `rowcount` rows without data, usually to be numbered or
+/// filled with random values by the query. With a `timelimit` in seconds, rows are generated, one
+/// batch at a time, until either `rowcount` rows are generated or the time limit has elapsed.
+/// Without a `rowcount`, the number of rows is only bounded by the time limit and the `LIMIT` of
+/// the query.
+fn generator_call(args: &[ScalarValue]) -> Result<Arc<dyn TableProvider>> {
+    let argument = |i: usize| match args.get(i) {
+        Some(ScalarValue::Int64(Some(n))) => Some(*n),
+        _ => None,
+    };
+    let timelimit = match argument(1) {
+        Some(timelimit) => match u64::try_from(timelimit) {
+            Ok(seconds) => Some(Duration::from_secs(seconds)),
+            Err(_) => return plan_err!("TIMELIMIT must be at least 0, got {timelimit}"),
+        },
+        None => None,
+    };
+    let rowcount = match (argument(0), timelimit) {
+        (Some(rowcount), _) => match usize::try_from(rowcount) {
+            Ok(rowcount) => rowcount,
+            Err(_) => return plan_err!("ROWCOUNT must be at least 0, got {rowcount}"),
+        },
+        (None, Some(_)) => usize::MAX,
+        (None, None) => return plan_err!("generator expects a ROWCOUNT or a TIMELIMIT"),
+    };
+    let schema = Schema::new(vec![Field::new("dummy", DataType::Int64, true)]);
+    Ok(Arc::new(GeneratorTable {
+        schema: Arc::new(schema),
+        rowcount,
+        timelimit,
+    }))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug, Default)]
+pub(super) struct generatorFunc;
+
+impl generatorFunc {
+    pub const NAME: &'static str = "generator";
+}
+
+impl TableFunctionImpl for generatorFunc {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        let args = crate::table::literal_arguments(Self::NAME, args)?;
+        generator_call(&args)
+    }
+}
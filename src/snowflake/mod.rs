@@ -0,0 +1,929 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_snake_case)]
+mod alert_history_impl;
+mod auto_refresh_registration_history_impl;
+mod automatic_clustering_history_impl;
+mod complete_task_graphs_impl;
+mod conditional_change_event_impl;
+mod conditional_true_event_impl;
+mod copy_history_impl;
+mod current_task_graphs_impl;
+mod data_transfer_history_impl;
+mod database_refresh_history_impl;
+mod database_refresh_progress_by_job_impl;
+mod database_refresh_progress_impl;
+mod database_replication_usage_history_impl;
+mod database_storage_usage_history_impl;
+mod dynamic_table_graph_history_impl;
+mod dynamic_table_refresh_history_impl;
+mod external_functions_history_impl;
+mod external_table_file_registration_history_impl;
+mod external_table_files_impl;
+mod flatten_impl;
+mod generator_impl;
+mod get_object_references_impl;
+mod get_query_operator_stats_impl;
+mod infer_schema_impl;
+mod login_history_by_user_impl;
+mod login_history_impl;
+mod materialized_view_refresh_history_impl;
+mod notification_history_impl;
+mod parse_json_impl;
+mod pipe_usage_history_impl;
+mod policy_references_impl;
+mod query_acceleration_history_impl;
+mod query_history_by_session_impl;
+mod query_history_by_user_impl;
+mod query_history_by_warehouse_impl;
+mod query_history_impl;
+mod ratio_to_report_impl;
+mod replication_group_refresh_history_impl;
+mod replication_group_refresh_progress_by_job_impl;
+mod replication_group_refresh_progress_impl;
+mod replication_group_usage_history_impl;
+mod replication_usage_history_impl;
+mod rest_event_history_impl;
+mod search_optimization_history_impl;
+mod serverless_task_history_impl;
+mod split_to_table_impl;
+mod stage_directory_file_registration_history_impl;
+mod stage_storage_usage_history_impl;
+mod strtok_split_to_table_impl;
+mod tag_references_all_columns_impl;
+mod tag_references_impl;
+mod tag_references_with_lineage_impl;
+mod task_dependents_impl;
+mod task_history_impl;
+mod validate_impl;
+mod validate_pipe_load_impl;
+mod warehouse_load_history_impl;
+mod warehouse_metering_history_impl;
+
+// create  UDFs
+make_udf_function!(
+    parse_json_impl::parse_json_varcharFunc,
+    PARSE_JSON_VARCHAR,
+    parse_json_varchar
+);
+
+// create UDWFs
+make_udwf_function!(
+    conditional_change_event_impl::conditional_change_event_1Func,
+    CONDITIONAL_CHANGE_EVENT_1,
+    conditional_change_event_1
+);
+
+make_udwf_function!(
+    conditional_true_event_impl::conditional_true_event_booleanFunc,
+    CONDITIONAL_TRUE_EVENT_BOOLEAN,
+    conditional_true_event_boolean
+);
+make_udwf_function!(
+    conditional_true_event_impl::conditional_true_event_decimal_38_0Func,
+    CONDITIONAL_TRUE_EVENT_DECIMAL_38_0,
+    conditional_true_event_decimal_38_0
+);
+
+make_udwf_function!(
+    ratio_to_report_impl::ratio_to_report_decimal_38_0Func,
+    RATIO_TO_REPORT_DECIMAL_38_0,
+    ratio_to_report_decimal_38_0
+);
+make_udwf_function!(
+    ratio_to_report_impl::ratio_to_report_decimal_38_0_decimal_38_0Func,
+    RATIO_TO_REPORT_DECIMAL_38_0_DECIMAL_38_0,
+    ratio_to_report_decimal_38_0_decimal_38_0
+);
+make_udwf_function!(
+    ratio_to_report_impl::ratio_to_report_doubleFunc,
+    RATIO_TO_REPORT_DOUBLE,
+    ratio_to_report_double
+);
+make_udwf_function!(
+    ratio_to_report_impl::ratio_to_report_double_decimal_38_0Func,
+    RATIO_TO_REPORT_DOUBLE_DECIMAL_38_0,
+    ratio_to_report_double_decimal_38_0
+);
+
+// create UDTFs
+make_udtf_function!(
+    alert_history_impl::alert_historyFunc,
+    ALERT_HISTORY,
+    alert_history
+);
+
+make_udtf_function!(
+    auto_refresh_registration_history_impl::auto_refresh_registration_historyFunc,
+    AUTO_REFRESH_REGISTRATION_HISTORY,
+    auto_refresh_registration_history
+);
+
+make_udtf_function!(
+    automatic_clustering_history_impl::automatic_clustering_historyFunc,
+    AUTOMATIC_CLUSTERING_HISTORY,
+    automatic_clustering_history
+);
+
+make_udtf_function!(
+    complete_task_graphs_impl::complete_task_graphsFunc,
+    COMPLETE_TASK_GRAPHS,
+    complete_task_graphs
+);
+
+make_udtf_function!(
+    copy_history_impl::copy_history_string_timestampFunc,
+    COPY_HISTORY_STRING_TIMESTAMP,
+    copy_history_string_timestamp
+);
+
+make_udtf_function!(
+    current_task_graphs_impl::current_task_graphsFunc,
+    CURRENT_TASK_GRAPHS,
+    current_task_graphs
+);
+
+make_udtf_function!(
+    data_transfer_history_impl::data_transfer_historyFunc,
+    DATA_TRANSFER_HISTORY,
+    data_transfer_history
+);
+
+make_udtf_function!(
+    database_refresh_history_impl::database_refresh_history_stringFunc,
+    DATABASE_REFRESH_HISTORY_STRING,
+    database_refresh_history_string
+);
+make_udtf_function!(
+    database_refresh_history_impl::database_refresh_historyFunc,
+    DATABASE_REFRESH_HISTORY,
+    database_refresh_history
+);
+
+make_udtf_function!(
+    database_refresh_progress_impl::database_refresh_progress_stringFunc,
+    DATABASE_REFRESH_PROGRESS_STRING,
+    database_refresh_progress_string
+);
+make_udtf_function!(
+    database_refresh_progress_impl::database_refresh_progressFunc,
+    DATABASE_REFRESH_PROGRESS,
+    database_refresh_progress
+);
+
+make_udtf_function!(
+    database_refresh_progress_by_job_impl::database_refresh_progress_by_job_stringFunc,
+    DATABASE_REFRESH_PROGRESS_BY_JOB_STRING,
+    database_refresh_progress_by_job_string
+);
+
+make_udtf_function!(
+    database_replication_usage_history_impl::database_replication_usage_historyFunc,
+    DATABASE_REPLICATION_USAGE_HISTORY,
+    database_replication_usage_history
+);
+
+make_udtf_function!(
+    database_storage_usage_history_impl::database_storage_usage_historyFunc,
+    DATABASE_STORAGE_USAGE_HISTORY,
+    database_storage_usage_history
+);
+
+make_udtf_function!(
+    dynamic_table_graph_history_impl::dynamic_table_graph_historyFunc,
+    DYNAMIC_TABLE_GRAPH_HISTORY,
+    dynamic_table_graph_history
+);
+
+make_udtf_function!(
+    dynamic_table_refresh_history_impl::dynamic_table_refresh_historyFunc,
+    DYNAMIC_TABLE_REFRESH_HISTORY,
+    dynamic_table_refresh_history
+);
+
+make_udtf_function!(
+    external_functions_history_impl::external_functions_historyFunc,
+    EXTERNAL_FUNCTIONS_HISTORY,
+    external_functions_history
+);
+
+make_udtf_function!(external_table_file_registration_history_impl::external_table_file_registration_history_stringFunc, EXTERNAL_TABLE_FILE_REGISTRATION_HISTORY_STRING, external_table_file_registration_history_string);
+
+make_udtf_function!(
+    external_table_files_impl::external_table_files_stringFunc,
+    EXTERNAL_TABLE_FILES_STRING,
+    external_table_files_string
+);
+
+make_udtf_function!(flatten_impl::flatten_1Func, FLATTEN_1, flatten_1);
+
+make_udtf_function!(generator_impl::generatorFunc, GENERATOR, generator);
+
+make_udtf_function!(
+    get_object_references_impl::get_object_referencesFunc,
+    GET_OBJECT_REFERENCES,
+    get_object_references
+);
+
+make_udtf_function!(
+    get_query_operator_stats_impl::get_query_operator_stats_stringFunc,
+    GET_QUERY_OPERATOR_STATS_STRING,
+    get_query_operator_stats_string
+);
+
+make_udtf_function!(
+    infer_schema_impl::infer_schemaFunc,
+    INFER_SCHEMA,
+    infer_schema
+);
+
+make_udtf_function!(
+    login_history_impl::login_historyFunc,
+    LOGIN_HISTORY,
+    login_history
+);
+
+make_udtf_function!(
+    login_history_by_user_impl::login_history_by_userFunc,
+    LOGIN_HISTORY_BY_USER,
+    login_history_by_user
+);
+
+make_udtf_function!(
+    materialized_view_refresh_history_impl::materialized_view_refresh_historyFunc,
+    MATERIALIZED_VIEW_REFRESH_HISTORY,
+    materialized_view_refresh_history
+);
+
+make_udtf_function!(
+    notification_history_impl::notification_historyFunc,
+    NOTIFICATION_HISTORY,
+    notification_history
+);
+
+make_udtf_function!(
+    pipe_usage_history_impl::pipe_usage_historyFunc,
+    PIPE_USAGE_HISTORY,
+    pipe_usage_history
+);
+
+make_udtf_function!(
+    policy_references_impl::policy_referencesFunc,
+    POLICY_REFERENCES,
+    policy_references
+);
+
+make_udtf_function!(
+    query_acceleration_history_impl::query_acceleration_historyFunc,
+    QUERY_ACCELERATION_HISTORY,
+    query_acceleration_history
+);
+
+make_udtf_function!(
+    query_history_impl::query_historyFunc,
+    QUERY_HISTORY,
+    query_history
+);
+
+make_udtf_function!(
+    query_history_by_session_impl::query_history_by_sessionFunc,
+    QUERY_HISTORY_BY_SESSION,
+    query_history_by_session
+);
+
+make_udtf_function!(
+    query_history_by_user_impl::query_history_by_userFunc,
+    QUERY_HISTORY_BY_USER,
+    query_history_by_user
+);
+
+make_udtf_function!(
+    query_history_by_warehouse_impl::query_history_by_warehouseFunc,
+    QUERY_HISTORY_BY_WAREHOUSE,
+    query_history_by_warehouse
+);
+
+make_udtf_function!(
+    replication_group_refresh_history_impl::replication_group_refresh_history_stringFunc,
+    REPLICATION_GROUP_REFRESH_HISTORY_STRING,
+    replication_group_refresh_history_string
+);
+
+make_udtf_function!(
+    replication_group_refresh_progress_impl::replication_group_refresh_progress_stringFunc,
+    REPLICATION_GROUP_REFRESH_PROGRESS_STRING,
+    replication_group_refresh_progress_string
+);
+
+make_udtf_function!(replication_group_refresh_progress_by_job_impl::replication_group_refresh_progress_by_job_stringFunc, REPLICATION_GROUP_REFRESH_PROGRESS_BY_JOB_STRING, replication_group_refresh_progress_by_job_string);
+
+make_udtf_function!(
+    replication_group_usage_history_impl::replication_group_usage_historyFunc,
+    REPLICATION_GROUP_USAGE_HISTORY,
+    replication_group_usage_history
+);
+
+make_udtf_function!(
+    replication_usage_history_impl::replication_usage_historyFunc,
+    REPLICATION_USAGE_HISTORY,
+    replication_usage_history
+);
+
+make_udtf_function!(
+    rest_event_history_impl::rest_event_history_stringFunc,
+    REST_EVENT_HISTORY_STRING,
+    rest_event_history_string
+);
+
+make_udtf_function!(
+    search_optimization_history_impl::search_optimization_historyFunc,
+    SEARCH_OPTIMIZATION_HISTORY,
+    search_optimization_history
+);
+
+make_udtf_function!(
+    serverless_task_history_impl::serverless_task_historyFunc,
+    SERVERLESS_TASK_HISTORY,
+    serverless_task_history
+);
+
+make_udtf_function!(
+    split_to_table_impl::split_to_table_string_stringFunc,
+    SPLIT_TO_TABLE_STRING_STRING,
+    split_to_table_string_string
+);
+
+make_udtf_function!(stage_directory_file_registration_history_impl::stage_directory_file_registration_history_stringFunc, STAGE_DIRECTORY_FILE_REGISTRATION_HISTORY_STRING, stage_directory_file_registration_history_string);
+
+make_udtf_function!(
+    stage_storage_usage_history_impl::stage_storage_usage_historyFunc,
+    STAGE_STORAGE_USAGE_HISTORY,
+    stage_storage_usage_history
+);
+
+make_udtf_function!(
+    strtok_split_to_table_impl::strtok_split_to_table_stringFunc,
+    STRTOK_SPLIT_TO_TABLE_STRING,
+    strtok_split_to_table_string
+);
+
+make_udtf_function!(
+    tag_references_impl::tag_references_string_stringFunc,
+    TAG_REFERENCES_STRING_STRING,
+    tag_references_string_string
+);
+
+make_udtf_function!(
+    tag_references_all_columns_impl::tag_references_all_columns_string_stringFunc,
+    TAG_REFERENCES_ALL_COLUMNS_STRING_STRING,
+    tag_references_all_columns_string_string
+);
+
+make_udtf_function!(
+    tag_references_with_lineage_impl::tag_references_with_lineage_stringFunc,
+    TAG_REFERENCES_WITH_LINEAGE_STRING,
+    tag_references_with_lineage_string
+);
+
+make_udtf_function!(
+    task_dependents_impl::task_dependents_stringFunc,
+    TASK_DEPENDENTS_STRING,
+    task_dependents_string
+);
+
+make_udtf_function!(
+    task_history_impl::task_historyFunc,
+    TASK_HISTORY,
+    task_history
+);
+
+make_udtf_function!(
+    validate_impl::validate_string_stringFunc,
+    VALIDATE_STRING_STRING,
+    validate_string_string
+);
+make_udtf_function!(
+    validate_impl::validate_stringFunc,
+    VALIDATE_STRING,
+    validate_string
+);
+
+make_udtf_function!(
+    validate_pipe_load_impl::validate_pipe_load_string_timestampFunc,
+    VALIDATE_PIPE_LOAD_STRING_TIMESTAMP,
+    validate_pipe_load_string_timestamp
+);
+
+make_udtf_function!(
+    warehouse_load_history_impl::warehouse_load_historyFunc,
+    WAREHOUSE_LOAD_HISTORY,
+    warehouse_load_history
+);
+
+make_udtf_function!(
+    warehouse_metering_history_impl::warehouse_metering_historyFunc,
+    WAREHOUSE_METERING_HISTORY,
+    warehouse_metering_history
+);
+
+// Export the functions out of this package, both as expr_fn as well as a list of functions
+export_functions!(
+    (snowflake, parse_json_varchar, arg1, ["varchar"], "Parses string as a JSON document\n\n`parse_json(varchar) -> variant`\n\nSee <https://docs.snowflake.com/en/sql-reference/functions/parse_json>"),
+
+);
+
+// Export the aggregate functions out of this package
+export_aggregate_functions!();
+
+// Export the window functions out of this package
+export_window_functions!(
+    (snowflake, conditional_change_event_1, ["$1"]),
+    (snowflake, conditional_true_event_boolean, ["boolean"]),
+    (
+        snowflake,
+        conditional_true_event_decimal_38_0,
+        ["decimal(38, 0)"]
+    ),
+    (snowflake, ratio_to_report_decimal_38_0, ["decimal(38, 0)"]),
+    (
+        snowflake,
+        ratio_to_report_decimal_38_0_decimal_38_0,
+        ["decimal(38, 0)", "decimal(38, 0)"]
+    ),
+    (snowflake, ratio_to_report_double, ["double"]),
+    (
+        snowflake,
+        ratio_to_report_double_decimal_38_0,
+        ["double", "decimal(38, 0)"]
+    ),
+);
+
+// Export the table functions out of this package
+export_table_functions!(
+    (
+        snowflake,
+        alert_history,
+        [],
+        ["timestamp", "timestamp", "bigint", "string"],
+        [
+            "scheduled_time_range_start",
+            "scheduled_time_range_end",
+            "result_limit",
+            "alert_name"
+        ]
+    ),
+    (
+        snowflake,
+        auto_refresh_registration_history,
+        [],
+        ["date", "date", "string", "string"],
+        [
+            "date_range_start",
+            "date_range_end",
+            "object_type",
+            "object_name"
+        ]
+    ),
+    (
+        snowflake,
+        automatic_clustering_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "table_name"]
+    ),
+    (
+        snowflake,
+        complete_task_graphs,
+        [],
+        ["bigint", "string", "boolean"],
+        ["result_limit", "root_task_name", "error_only"]
+    ),
+    (
+        snowflake,
+        copy_history_string_timestamp,
+        ["string", "timestamp"],
+        ["timestamp"],
+        ["table_name", "start_time", "end_time"]
+    ),
+    (
+        snowflake,
+        current_task_graphs,
+        [],
+        ["bigint", "string"],
+        ["result_limit", "root_task_name"]
+    ),
+    (
+        snowflake,
+        data_transfer_history,
+        [],
+        ["date", "date"],
+        ["date_range_start", "date_range_end"]
+    ),
+    (
+        snowflake,
+        database_refresh_history_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (snowflake, database_refresh_history, [], [], []),
+    (
+        snowflake,
+        database_refresh_progress_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (snowflake, database_refresh_progress, [], [], []),
+    (
+        snowflake,
+        database_refresh_progress_by_job_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        database_replication_usage_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "database_name"]
+    ),
+    (
+        snowflake,
+        database_storage_usage_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "database_name"]
+    ),
+    (
+        snowflake,
+        dynamic_table_graph_history,
+        [],
+        ["timestamp", "timestamp", "timestamp"],
+        ["as_of", "history_start", "history_end"]
+    ),
+    (
+        snowflake,
+        dynamic_table_refresh_history,
+        [],
+        [
+            "timestamp",
+            "timestamp",
+            "bigint",
+            "string",
+            "string",
+            "boolean"
+        ],
+        [
+            "data_timestamp_start",
+            "data_timestamp_end",
+            "result_limit",
+            "name",
+            "name_prefix",
+            "error_only"
+        ]
+    ),
+    (
+        snowflake,
+        external_functions_history,
+        [],
+        ["timestamp", "timestamp", "string"],
+        ["date_range_start", "date_range_end", "function_signature"]
+    ),
+    (
+        snowflake,
+        external_table_file_registration_history_string,
+        ["string"],
+        ["timestamp"],
+        ["table_name", "start_time"]
+    ),
+    (
+        snowflake,
+        external_table_files_string,
+        ["string"],
+        [],
+        ["table_name"]
+    ),
+    (
+        snowflake,
+        flatten_1,
+        ["$1"],
+        ["string", "boolean", "boolean", "string"],
+        ["input", "path", "outer", "recursive", "mode"]
+    ),
+    (
+        snowflake,
+        generator,
+        [],
+        ["bigint", "bigint"],
+        ["rowcount", "timelimit"]
+    ),
+    (
+        snowflake,
+        get_object_references,
+        [],
+        ["string", "string", "string"],
+        ["database_name", "schema_name", "object_name"]
+    ),
+    (
+        snowflake,
+        get_query_operator_stats_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        infer_schema,
+        [],
+        ["string", "string", "string", "boolean", "bigint", "bigint"],
+        [
+            "location",
+            "file_format",
+            "files",
+            "ignore_case",
+            "max_file_count",
+            "max_records_per_file"
+        ]
+    ),
+    (
+        snowflake,
+        login_history,
+        [],
+        ["$1", "$2", "bigint"],
+        ["time_range_start", "time_range_end", "result_limit"]
+    ),
+    (
+        snowflake,
+        login_history_by_user,
+        [],
+        ["$1", "$2", "string", "bigint"],
+        [
+            "time_range_start",
+            "time_range_end",
+            "user_name",
+            "result_limit"
+        ]
+    ),
+    (
+        snowflake,
+        materialized_view_refresh_history,
+        [],
+        ["$1", "$2", "string"],
+        [
+            "date_range_start",
+            "date_range_end",
+            "materialized_view_name"
+        ]
+    ),
+    (
+        snowflake,
+        notification_history,
+        [],
+        ["timestamp", "timestamp", "string", "bigint"],
+        ["start_time", "end_time", "integration_name", "result_limit"]
+    ),
+    (
+        snowflake,
+        pipe_usage_history,
+        [],
+        ["date", "date", "string"],
+        ["date_range_start", "date_range_end", "pipe_name"]
+    ),
+    (
+        snowflake,
+        policy_references,
+        [],
+        ["string", "string", "string"],
+        ["policy_name", "ref_entity_name", "ref_entity_domain"]
+    ),
+    (
+        snowflake,
+        query_acceleration_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "warehouse_name"]
+    ),
+    (
+        snowflake,
+        query_history,
+        [],
+        ["$1", "$2", "bigint"],
+        ["end_time_range_start", "end_time_range_end", "result_limit"]
+    ),
+    (
+        snowflake,
+        query_history_by_session,
+        [],
+        ["bigint", "$1", "$2", "bigint"],
+        [
+            "session_id",
+            "end_time_range_start",
+            "end_time_range_end",
+            "result_limit"
+        ]
+    ),
+    (
+        snowflake,
+        query_history_by_user,
+        [],
+        ["bigint", "$1", "$2", "bigint"],
+        [
+            "user_name",
+            "end_time_range_start",
+            "end_time_range_end",
+            "result_limit"
+        ]
+    ),
+    (
+        snowflake,
+        query_history_by_warehouse,
+        [],
+        ["string", "$1", "$2", "bigint"],
+        [
+            "warehouse_name",
+            "end_time_range_start",
+            "end_time_range_end",
+            "result_limit"
+        ]
+    ),
+    (
+        snowflake,
+        replication_group_refresh_history_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        replication_group_refresh_progress_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        replication_group_refresh_progress_by_job_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        replication_group_usage_history,
+        [],
+        ["date", "date", "string"],
+        [
+            "date_range_start",
+            "date_range_end",
+            "replication_group_name"
+        ]
+    ),
+    (
+        snowflake,
+        replication_usage_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "database_name"]
+    ),
+    (
+        snowflake,
+        rest_event_history_string,
+        ["string"],
+        ["timestamp", "timestamp", "bigint"],
+        [
+            "rest_service_type",
+            "time_range_start",
+            "time_range_end",
+            "result_limit"
+        ]
+    ),
+    (
+        snowflake,
+        search_optimization_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "table_name"]
+    ),
+    (
+        snowflake,
+        serverless_task_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "task_name"]
+    ),
+    (
+        snowflake,
+        split_to_table_string_string,
+        ["string", "string"],
+        [],
+        ["", ""]
+    ),
+    (
+        snowflake,
+        stage_directory_file_registration_history_string,
+        ["string"],
+        ["$1"],
+        ["stage_name", "start_time"]
+    ),
+    (
+        snowflake,
+        stage_storage_usage_history,
+        [],
+        ["$1", "$2"],
+        ["date_range_start", "date_range_end"]
+    ),
+    (
+        snowflake,
+        strtok_split_to_table_string,
+        variadic["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        tag_references_string_string,
+        ["string", "string"],
+        [],
+        ["", ""]
+    ),
+    (
+        snowflake,
+        tag_references_all_columns_string_string,
+        ["string", "string"],
+        [],
+        ["", ""]
+    ),
+    (
+        snowflake,
+        tag_references_with_lineage_string,
+        ["string"],
+        [],
+        [""]
+    ),
+    (
+        snowflake,
+        task_dependents_string,
+        ["string"],
+        ["boolean"],
+        ["task_name", "recursive"]
+    ),
+    (
+        snowflake,
+        task_history,
+        [],
+        ["timestamp", "timestamp", "bigint", "string", "boolean"],
+        [
+            "scheduled_time_range_start",
+            "scheduled_time_range_end",
+            "result_limit",
+            "task_name",
+            "error_only"
+        ]
+    ),
+    (
+        snowflake,
+        validate_string_string,
+        ["string", "string"],
+        [],
+        ["", "job_id"]
+    ),
+    (snowflake, validate_string, ["string"], [], ["job_id"]),
+    (
+        snowflake,
+        validate_pipe_load_string_timestamp,
+        ["string", "timestamp"],
+        ["timestamp"],
+        ["pipe_name", "start_time", "end_time"]
+    ),
+    (
+        snowflake,
+        warehouse_load_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "warehouse_name"]
+    ),
+    (
+        snowflake,
+        warehouse_metering_history,
+        [],
+        ["$1", "$2", "string"],
+        ["date_range_start", "date_range_end", "warehouse_name"]
+    ),
+);
@@ -16,73 +16,82 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::StringArray;
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-{% for function_info in function_infos %}
-fn {{function_info.name}}_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!("Not implemented {}:{}", file!(), line!())))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+/// A VARIANT is the JSON text of its value, so parsing only validates and normalizes the text.
+/// An empty text is NULL, as in Snowflake.
+fn parse_json_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_rows::<StringArray, _, _>(&args[0], &[], |text, _| {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        match Json::parse(text) {
+            Ok(json) => Ok(Some(json.canonical().to_text())),
+            Err(_) => exec_err!("Error parsing JSON: '{text}'"),
+        }
+    })
 }
 
-fn {{function_info.name}}_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!("Not implemented {}:{}", file!(), line!())))
+fn parse_json_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Ok(DataType::Utf8)
 }
 
-fn {{function_info.name}}_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+fn parse_json_varchar_simplify(
+    args: Vec<Expr>,
+    _info: &dyn SimplifyInfo,
+) -> Result<ExprSimplifyResult> {
     Ok(ExprSimplifyResult::Original(args))
 }
-{% endfor %}
-
 // ========== Generated template below this line ==========
 // Do *NOT* edit below this line: all changes will be overwritten
 // when template is regenerated!
 
-{% for function_info in function_infos %}
 #[derive(Debug)]
-pub(super) struct {{function_info.name}}Func {
+pub(super) struct parse_json_varcharFunc {
     signature: Signature,
 }
 
-impl {{function_info.name}}Func {
-    pub fn new() -> Self {        
+impl parse_json_varcharFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: {{function_info.signature}},
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
 
-impl ScalarUDFImpl for {{function_info.name}}Func {
+impl ScalarUDFImpl for parse_json_varcharFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
     fn name(&self) -> &str {
-        "{{ name }}"
+        "parse_json"
     }
 
     fn signature(&self) -> &Signature {
         &self.signature
     }
 
-
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        {{function_info.name}}_return_type(arg_types)
+        parse_json_varchar_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        {{function_info.name}}_invoke(args)
+        parse_json_varchar_invoke(args)
     }
 
-    fn simplify(
-        &self,
-        args: Vec<Expr>,
-        info: &dyn SimplifyInfo,
-    ) -> Result<ExprSimplifyResult> {
-        {{function_info.name}}_simplify(args, info)
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        parse_json_varchar_simplify(args, info)
     }
 
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Snowflake, "parse_json_varchar")
+    }
 }
-{% endfor %}
@@ -0,0 +1,382 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table dialect functions.
+//!
+//! Every overload of a table function is generated as its own [`TableFunctionImpl`] (e.g.
+//! `flatten_1`, `generator`), whose hand-written `<overload>_call` function creates the
+//! [`TableProvider`] of a call from the values of its arguments. A [`OverloadedUDTF`] bundles the
+//! overloads of one name and delegates to the one matching the argument types.
+//!
+//! The arguments of a table function must be constants: they are simplified to literals, and
+//! cast to the parameter types of the resolved overload. The optional parameters of an overload
+//! may be omitted from the end of the call.
+//!
+//! DataFusion only passes the positional arguments of a call to a table function, and silently
+//! drops its named ones. [`crate::sql`] plans a query after rewriting the named arguments of the
+//! table functions of a dialect into positional ones (see [`rewrite_table_function_arguments`]),
+//! so that Snowflake's `TABLE(generator(rowcount => 10))` can be used as is. It also drops the
+//! arguments it fails to plan, e.g. calls of unknown functions, so [`crate::sql`] plans them first
+//! to report the offending argument (see [`check_table_function_arguments`]). DataFusion does not
+//! plan lateral table functions either, so `LATERAL flatten(input => t.v)` is reported as such.
+
+use crate::overload::{resolve_overload, Overload};
+use crate::type_signature::{FunctionSignature, Resolution};
+use arrow::datatypes::DataType;
+use datafusion::catalog::{TableFunction, TableFunctionImpl, TableProvider};
+use datafusion::common::{
+    plan_datafusion_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
+};
+use datafusion::execution::SessionState;
+use datafusion::logical_expr::execution_props::ExecutionProps;
+use datafusion::logical_expr::Expr;
+use datafusion::optimizer::simplify_expressions::{ExprSimplifier, SimplifyContext};
+use datafusion::sql::sqlparser::ast::{
+    Expr as SqlExpr, FunctionArg, FunctionArgExpr, FunctionArguments, Statement, TableFactor,
+    TableFunctionArgs, Value, Visit, VisitMut, Visitor, VisitorMut,
+};
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// One overload of a dialect table function.
+#[derive(Debug, Clone)]
+pub struct TableOverload {
+    pub dialect: &'static str,
+    /// The generated name of the overload, e.g. `flatten_1`
+    pub name: &'static str,
+    pub udtf: Arc<TableFunction>,
+    /// The required parameters, followed by the optional ones
+    pub signature: FunctionSignature,
+    /// The number of required parameters
+    pub required: usize,
+    /// The names of the parameters, in order, for named arguments. Positional-only parameters
+    /// have empty names.
+    pub parameter_names: Vec<&'static str>,
+}
+
+impl TableOverload {
+    /// The arguments of a call in the order of the parameters, given its positional arguments
+    /// followed by its named ones. Omitted optional parameters followed by given ones are NULL.
+    /// Returns `None` if the arguments do not match the parameters.
+    fn positional_arguments(&self, args: &[FunctionArg]) -> Option<Vec<FunctionArg>> {
+        let mut arranged: Vec<Option<FunctionArg>> = vec![None; self.parameter_names.len()];
+        let mut position = 0;
+        for arg in args {
+            let (index, arg) = match arg {
+                FunctionArg::Unnamed(_) if position < arranged.len() => {
+                    position += 1;
+                    (position - 1, arg.clone())
+                }
+                FunctionArg::Named { name, arg, .. } => {
+                    let index = self
+                        .parameter_names
+                        .iter()
+                        .position(|p| !p.is_empty() && p.eq_ignore_ascii_case(&name.value))?;
+                    (index, FunctionArg::Unnamed(arg.clone()))
+                }
+                _ => return None,
+            };
+            if arranged[index].replace(arg).is_some() {
+                return None;
+            }
+        }
+        let len = arranged
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |i| i + 1);
+        if len < self.required || arranged[..self.required].iter().any(Option::is_none) {
+            return None;
+        }
+        let null = FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(Value::Null)));
+        Some(
+            arranged
+                .into_iter()
+                .take(len)
+                .map(|arg| arg.unwrap_or_else(|| null.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl Overload for TableOverload {
+    type Function = TableFunction;
+
+    fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn function_name(&self) -> &str {
+        self.udtf.name()
+    }
+
+    fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    /// Match the argument types against the signature, whose optional parameters may be omitted.
+    fn resolve(&self, arg_types: &[DataType]) -> Option<Resolution> {
+        let parameters = &self.signature.parameters;
+        if self.signature.variadic || arg_types.len() == parameters.len() {
+            return self.signature.resolve(arg_types);
+        }
+        if arg_types.len() < self.required || arg_types.len() > parameters.len() {
+            return None;
+        }
+        FunctionSignature {
+            parameters: parameters[..arg_types.len()].to_vec(),
+            variadic: false,
+        }
+        .resolve(arg_types)
+    }
+
+    /// Table functions have no result type: an ambiguous call with NULL arguments stays ambiguous.
+    fn return_type(&self, _arg_types: &[DataType]) -> Option<DataType> {
+        None
+    }
+
+    fn dispatcher(name: &str, overloads: Vec<Self>) -> Arc<TableFunction> {
+        let udtf = OverloadedUDTF::new(name, overloads);
+        Arc::new(TableFunction::new(name.to_string(), Arc::new(udtf)))
+    }
+}
+
+/// A table function dispatching to the overload of a function best matching its argument types.
+#[derive(Debug)]
+pub struct OverloadedUDTF {
+    name: String,
+    /// The overloads, in declaration order, see [`resolve_overload`].
+    overloads: Vec<TableOverload>,
+}
+
+impl OverloadedUDTF {
+    /// Create the dispatcher for `overloads`, which must all share the name `name`.
+    pub fn new(name: &str, overloads: Vec<TableOverload>) -> Self {
+        Self {
+            name: name.to_string(),
+            overloads,
+        }
+    }
+
+    /// The overloads of this function, in declaration order.
+    pub fn overloads(&self) -> &[TableOverload] {
+        &self.overloads
+    }
+
+    /// Pick the overload best matching `arg_types`, see [`resolve_overload`].
+    pub fn resolve(&self, arg_types: &[DataType]) -> Result<(&TableOverload, Resolution)> {
+        resolve_overload(&self.name, &self.overloads, arg_types)
+    }
+}
+
+impl TableFunctionImpl for OverloadedUDTF {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        let values = literal_arguments(&self.name, args)?;
+        let arg_types = values
+            .iter()
+            .map(ScalarValue::data_type)
+            .collect::<Vec<_>>();
+        let (overload, res) = self.resolve(&arg_types)?;
+        let args = values
+            .iter()
+            .zip(&res.coerced_types)
+            .map(|(value, to)| Ok(Expr::Literal(value.cast_to(to)?)))
+            .collect::<Result<Vec<_>>>()?;
+        overload.udtf.create_table_provider(&args)
+    }
+}
+
+/// The values of the arguments `args` of the table function `name`, which must be constants.
+pub fn literal_arguments(name: &str, args: &[Expr]) -> Result<Vec<ScalarValue>> {
+    let props = ExecutionProps::new();
+    let context = SimplifyContext::new(&props).with_schema(Arc::new(DFSchema::empty()));
+    let simplifier = ExprSimplifier::new(context);
+    args.iter()
+        .map(|arg| match simplifier.simplify(arg.clone())? {
+            Expr::Literal(value) => Ok(value),
+            arg => plan_err!("The arguments of table function {name} must be constants, got {arg}"),
+        })
+        .collect()
+}
+
+/// Rewrite the calls of the table functions `overloads` in `statement`: their named arguments
+/// are moved to the position of their parameter, and `TABLE(f(...))` becomes `f(...)`.
+pub fn rewrite_table_function_arguments(
+    statement: &mut Statement,
+    overloads: &[TableOverload],
+) -> Result<()> {
+    match statement.visit(&mut TableFunctionArguments { overloads }) {
+        ControlFlow::Break(e) => Err(e),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+/// Check that the arguments of the calls of the table functions `overloads` in `statement`, once
+/// rewritten by [`rewrite_table_function_arguments`], can be planned in `state`: DataFusion
+/// silently drops the others, and the call then fails for want of arguments.
+pub fn check_table_function_arguments(
+    statement: &Statement,
+    overloads: &[TableOverload],
+    state: &SessionState,
+) -> Result<()> {
+    match statement.visit(&mut PlannedArguments { overloads, state }) {
+        ControlFlow::Break(e) => Err(e),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+struct PlannedArguments<'a> {
+    overloads: &'a [TableOverload],
+    state: &'a SessionState,
+}
+
+impl Visitor for PlannedArguments<'_> {
+    type Break = DataFusionError;
+
+    fn pre_visit_table_factor(&mut self, table_factor: &TableFactor) -> ControlFlow<Self::Break> {
+        let TableFactor::Table {
+            name,
+            args: Some(args),
+            ..
+        } = table_factor
+        else {
+            return ControlFlow::Continue(());
+        };
+        let function = name.to_string();
+        if !self
+            .overloads
+            .iter()
+            .any(|o| o.function_name().eq_ignore_ascii_case(&function))
+        {
+            return ControlFlow::Continue(());
+        }
+        for arg in &args.args {
+            let planned = match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => self
+                    .state
+                    .create_logical_expr(&expr.to_string(), &DFSchema::empty())
+                    .map(|_| ()),
+                _ => plan_err!("only positional and named arguments are supported"),
+            };
+            if let Err(e) = planned {
+                return ControlFlow::Break(plan_datafusion_err!(
+                    "Invalid argument {arg} of table function {function}: {}",
+                    e.strip_backtrace()
+                ));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+struct TableFunctionArguments<'a> {
+    overloads: &'a [TableOverload],
+}
+
+impl TableFunctionArguments<'_> {
+    fn rewrite(&self, function: &str, args: &mut Vec<FunctionArg>) -> Result<()> {
+        if !args.iter().any(|a| matches!(a, FunctionArg::Named { .. })) {
+            return Ok(());
+        }
+        let mut overloads = self
+            .overloads
+            .iter()
+            .filter(|o| o.function_name().eq_ignore_ascii_case(function))
+            .peekable();
+        if overloads.peek().is_none() {
+            return Ok(());
+        }
+        match overloads.find_map(|o| o.positional_arguments(args)) {
+            Some(positional) => {
+                *args = positional;
+                Ok(())
+            }
+            None => {
+                let args = args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                plan_err!(
+                    "Invalid arguments for table function {function}: {}",
+                    args.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl VisitorMut for TableFunctionArguments<'_> {
+    type Break = DataFusionError;
+
+    fn pre_visit_table_factor(
+        &mut self,
+        table_factor: &mut TableFactor,
+    ) -> ControlFlow<Self::Break> {
+        if let TableFactor::TableFunction {
+            expr: SqlExpr::Function(function),
+            alias,
+        } = table_factor
+        {
+            if let FunctionArguments::List(list) = &function.args {
+                *table_factor = TableFactor::Table {
+                    name: function.name.clone(),
+                    alias: alias.clone(),
+                    args: Some(TableFunctionArgs {
+                        args: list.args.clone(),
+                        settings: None,
+                    }),
+                    with_hints: vec![],
+                    version: None,
+                    with_ordinality: false,
+                    partitions: vec![],
+                    json_path: None,
+                };
+            }
+        }
+        if let TableFactor::Table {
+            name,
+            args: Some(args),
+            ..
+        } = table_factor
+        {
+            if let [function] = name.0.as_slice() {
+                if let Err(e) = self.rewrite(&function.value, &mut args.args) {
+                    return ControlFlow::Break(e);
+                }
+            }
+        }
+        if let TableFactor::Function {
+            lateral: true,
+            name,
+            ..
+        } = table_factor
+        {
+            let function = name.to_string();
+            if self
+                .overloads
+                .iter()
+                .any(|o| o.function_name().eq_ignore_ascii_case(&function))
+            {
+                return ControlFlow::Break(plan_datafusion_err!(
+                    "LATERAL {function} is not supported: the arguments of table function \
+                     {function} must be constants"
+                ));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,173 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Trino's time zones: the regions of the IANA time zone database, e.g. `America/New_York`, and
+//! fixed offsets from UTC, e.g. `+05:30`. The database is the one embedded by chrono-tz, so that
+//! the offsets of the regions do not depend on the host.
+//!
+//! As in Trino, the names of the regions are case-insensitive, the aliases of UTC (`Z`, `GMT`,
+//! `Etc/UTC`, `+00:00`, ...) are `UTC`, and the offsets are written `±HH:MM`.
+//!
+//! A `timestamp with time zone` is an Arrow timestamp whose type has the name of its zone, e.g.
+//! `Timestamp(Millisecond, Some("Europe/Paris"))`, and whose values are the instants since the
+//! epoch, in UTC.
+
+use arrow::array::timezone::Tz;
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::{OffsetName, TZ_VARIANTS};
+use datafusion::common::{exec_datafusion_err, Result};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::type_signature;
+
+pub(crate) const UTC: &str = "UTC";
+
+/// The names of UTC, in lower case, after an optional `etc/`.
+const UTC_ALIASES: &[&str] = &[
+    "utc",
+    "z",
+    "ut",
+    "uct",
+    "gmt",
+    "gmt0",
+    "greenwich",
+    "universal",
+    "zulu",
+];
+
+/// The prefixes of the offsets written from UTC, e.g. `UTC+2`.
+const OFFSET_PREFIXES: &[&str] = &["utc", "gmt", "ut"];
+
+/// The largest offset from UTC of a time zone, in minutes.
+const MAX_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// The name of the time zone `zone`, normalized as by Trino: `america/new_york` is
+/// `America/New_York`, `+0530` is `+05:30` and `GMT` is `UTC`.
+pub(crate) fn zone_key(zone: &str) -> Result<String> {
+    let not_supported = || exec_datafusion_err!("Time zone not supported: {zone}");
+    let lower = zone.trim().to_ascii_lowercase();
+    if UTC_ALIASES.contains(&lower.strip_prefix("etc/").unwrap_or(&lower)) {
+        return Ok(UTC.to_string());
+    }
+    let offset = OFFSET_PREFIXES
+        .iter()
+        .find_map(|prefix| lower.strip_prefix(prefix))
+        .filter(|rest| rest.starts_with(['+', '-']))
+        .unwrap_or(&lower);
+    if offset.starts_with(['+', '-']) {
+        return parse_offset_minutes(offset)
+            .and_then(|minutes| offset_key(minutes.into()))
+            .ok_or_else(not_supported);
+    }
+    TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(&lower))
+        .map(|tz| tz.name().to_string())
+        .ok_or_else(not_supported)
+}
+
+/// The name of the fixed offset of `minutes` from UTC, `±HH:MM` or `UTC`, if within the offsets of
+/// the time zones.
+pub(crate) fn offset_key(minutes: i64) -> Option<String> {
+    if minutes.abs() > MAX_OFFSET_MINUTES.into() {
+        return None;
+    }
+    if minutes == 0 {
+        return Some(UTC.to_string());
+    }
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let (hours, minutes) = (minutes.abs() / 60, minutes.abs() % 60);
+    Some(format!("{sign}{hours:02}:{minutes:02}"))
+}
+
+/// The time zone `zone`, see [`zone_key`].
+pub(crate) fn parse_zone(zone: &str) -> Result<Tz> {
+    let key = zone_key(zone)?;
+    Tz::from_str(&key).map_err(|_| exec_datafusion_err!("Time zone not supported: {zone}"))
+}
+
+/// The time zone `zone` as the time zone of an Arrow timestamp type: its name, see [`zone_key`],
+/// with UTC written as in the types of the `with time zone` parameters.
+pub(crate) fn arrow_zone(zone: &str) -> Result<Arc<str>> {
+    let key = zone_key(zone)?;
+    Ok(if key == UTC {
+        type_signature::UTC.into()
+    } else {
+        key.into()
+    })
+}
+
+/// The name of the time zone of an Arrow timestamp type, UTC if it has none.
+pub(crate) fn zone_name(zone: Option<&str>) -> &str {
+    match zone {
+        None | Some("+00:00" | "+0000" | "+00" | "Z" | "Etc/UTC") => UTC,
+        Some(zone) => zone,
+    }
+}
+
+/// The abbreviation of the name of the region `zone` at the instant `utc`, e.g. `EST` or `CEST`,
+/// if it has one.
+pub(crate) fn zone_abbreviation(zone: &str, utc: &NaiveDateTime) -> Option<String> {
+    let tz = chrono_tz::Tz::from_str(zone).ok()?;
+    let offset = tz.offset_from_utc_datetime(utc);
+    offset.abbreviation().map(str::to_string)
+}
+
+/// The date-time at the local date-time `local` in `zone`. As in Trino, a local time that is
+/// skipped by a change of offset is moved forward by the change, and a local time that occurs
+/// twice is the earlier of the two.
+pub(crate) fn from_local(zone: &Tz, local: &NaiveDateTime) -> DateTime<Tz> {
+    match zone.from_local_datetime(local) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earlier, _) => earlier,
+        LocalResult::None => {
+            // The offset before the change
+            let offset = zone
+                .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                .fix();
+            zone.from_utc_datetime(&(*local - offset))
+        }
+    }
+}
+
+/// The minutes of an offset `±H`, `±HH`, `±HHMM`, `±H:MM` or `±HH:MM`.
+fn parse_offset_minutes(offset: &str) -> Option<i32> {
+    let (sign, digits) = match offset.split_at(1) {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => (hours, minutes),
+        Some(_) => return None,
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, "00"),
+    };
+    if hours.is_empty()
+        || hours.len() > 2
+        || !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let (hours, minutes) = (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?);
+    let total = hours * 60 + minutes;
+    (minutes < 60 && total <= MAX_OFFSET_MINUTES).then_some(sign * total)
+}
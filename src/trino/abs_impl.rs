@@ -156,9 +156,9 @@ pub(super) struct abs_tinyintFunc {
 }
 
 impl abs_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8], volatility),
         }
     }
 }
@@ -186,6 +186,10 @@ impl ScalarUDFImpl for abs_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_tinyint")
+    }
 }
 
 #[derive(Debug)]
@@ -194,9 +198,9 @@ pub(super) struct abs_smallintFunc {
 }
 
 impl abs_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16], volatility),
         }
     }
 }
@@ -224,6 +228,10 @@ impl ScalarUDFImpl for abs_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -232,9 +240,9 @@ pub(super) struct abs_bigintFunc {
 }
 
 impl abs_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -262,6 +270,10 @@ impl ScalarUDFImpl for abs_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -270,9 +282,9 @@ pub(super) struct abs_doubleFunc {
 }
 
 impl abs_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -300,17 +312,24 @@ impl ScalarUDFImpl for abs_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_double")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct abs_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl abs_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -327,6 +346,10 @@ impl ScalarUDFImpl for abs_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("abs", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         abs_decimal_p_s_return_type(arg_types)
     }
@@ -338,6 +361,10 @@ impl ScalarUDFImpl for abs_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_decimal_p_s")
+    }
 }
 
 #[derive(Debug)]
@@ -346,9 +373,9 @@ pub(super) struct abs_realFunc {
 }
 
 impl abs_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -376,4 +403,8 @@ impl ScalarUDFImpl for abs_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         abs_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "abs_real")
+    }
 }
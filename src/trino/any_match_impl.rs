@@ -16,34 +16,42 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::{ListElements, Quantifier};
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn any_match_array_1_function_1_boolean_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("any_match")
 }
 
-fn any_match_array_1_function_1_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn any_match_array_1_function_1_boolean_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("any_match_array_1_function_1_boolean", arg_types)
 }
 
 fn any_match_array_1_function_1_boolean_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "any_match",
+        args,
+        info,
+        1,
+        any_match_array_1_function_1_boolean_return_type,
+        any_match,
+    )
+}
+
+fn any_match(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let elements = ListElements::new(&args[0])?;
+    let matches = lambdas[0].call(&[Arc::clone(&elements.values)], &elements.rows)?;
+    Quantifier::Any.combine(&elements, &matches)
 }
 
 // ========== Generated template below this line ==========
@@ -53,12 +61,18 @@ fn any_match_array_1_function_1_boolean_simplify(
 #[derive(Debug)]
 pub(super) struct any_match_array_1_function_1_booleanFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl any_match_array_1_function_1_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "function($1, boolean)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +89,10 @@ impl ScalarUDFImpl for any_match_array_1_function_1_booleanFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("any_match", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         any_match_array_1_function_1_boolean_return_type(arg_types)
     }
@@ -86,4 +104,11 @@ impl ScalarUDFImpl for any_match_array_1_function_1_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         any_match_array_1_function_1_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "any_match_array_1_function_1_boolean",
+        )
+    }
 }
@@ -0,0 +1,460 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use std::any::Any;
+
+fn approx_distinct_boolean_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_boolean_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_distinct_boolean_double_accumulator(
+    _args: AccumulatorArgs,
+) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_boolean_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_boolean_double_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_distinct_1_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_1_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_1_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_distinct_1_double_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_1_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_1_double_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_distinct_unknown_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_unknown_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_unknown_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_distinct_unknown_double_accumulator(
+    _args: AccumulatorArgs,
+) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_unknown_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_distinct_unknown_double_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_booleanFunc {
+    signature: Signature,
+}
+
+impl approx_distinct_booleanFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Boolean], volatility),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_booleanFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_boolean_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_boolean_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_boolean_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_boolean")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_boolean_doubleFunc {
+    signature: Signature,
+}
+
+impl approx_distinct_boolean_doubleFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Boolean, DataType::Float64], volatility),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_boolean_doubleFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_boolean_double_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_boolean_double_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_boolean_double_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_boolean_double")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_1Func {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl approx_distinct_1Func {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_1Func {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("approx_distinct", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_1_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_1_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_1_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_1")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_1_doubleFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl approx_distinct_1_doubleFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1", "double"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_1_doubleFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("approx_distinct", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_1_double_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_1_double_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_1_double_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_1_double")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_unknownFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl approx_distinct_unknownFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["unknown"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_unknownFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("approx_distinct", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_unknown_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_unknown_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_unknown_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_unknown")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_distinct_unknown_doubleFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl approx_distinct_unknown_doubleFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["unknown", "double"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_distinct_unknown_doubleFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("approx_distinct", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_distinct_unknown_double_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_distinct_unknown_double_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_distinct_unknown_double_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "approx_distinct_unknown_double")
+    }
+}
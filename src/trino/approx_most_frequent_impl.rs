@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::Result;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use std::any::Any;
+
+fn approx_most_frequent_bigint_bigint_bigint_accumulator(
+    _args: AccumulatorArgs,
+) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_most_frequent_bigint_bigint_bigint_return_type(
+    _arg_types: &[DataType],
+) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_most_frequent_bigint_bigint_bigint_state_fields(
+    args: StateFieldsArgs,
+) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+fn approx_most_frequent_bigint_varchar_bigint_accumulator(
+    _args: AccumulatorArgs,
+) -> Result<Box<dyn Accumulator>> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_most_frequent_bigint_varchar_bigint_return_type(
+    _arg_types: &[DataType],
+) -> Result<DataType> {
+    Err(DataFusionError::NotImplemented(format!(
+        "Not implemented {}:{}",
+        file!(),
+        line!()
+    )))
+}
+
+fn approx_most_frequent_bigint_varchar_bigint_state_fields(
+    args: StateFieldsArgs,
+) -> Result<Vec<Field>> {
+    Ok(vec![Field::new(
+        format_state_name(args.name, "value"),
+        args.return_type.clone(),
+        true,
+    )])
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct approx_most_frequent_bigint_bigint_bigintFunc {
+    signature: Signature,
+}
+
+impl approx_most_frequent_bigint_bigint_bigintFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_most_frequent_bigint_bigint_bigintFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_most_frequent"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_most_frequent_bigint_bigint_bigint_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_most_frequent_bigint_bigint_bigint_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_most_frequent_bigint_bigint_bigint_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "approx_most_frequent_bigint_bigint_bigint",
+        )
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct approx_most_frequent_bigint_varchar_bigintFunc {
+    signature: Signature,
+}
+
+impl approx_most_frequent_bigint_varchar_bigintFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Utf8, DataType::Int64],
+                volatility,
+            ),
+        }
+    }
+}
+
+impl AggregateUDFImpl for approx_most_frequent_bigint_varchar_bigintFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "approx_most_frequent"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        approx_most_frequent_bigint_varchar_bigint_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        approx_most_frequent_bigint_varchar_bigint_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        approx_most_frequent_bigint_varchar_bigint_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "approx_most_frequent_bigint_varchar_bigint",
+        )
+    }
+}
@@ -53,12 +53,18 @@ fn array_intersect_array_3_array_3_simplify(
 #[derive(Debug)]
 pub(super) struct array_intersect_array_3_array_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_intersect_array_3_array_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$3>", "array<$3>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +81,10 @@ impl ScalarUDFImpl for array_intersect_array_3_array_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_intersect", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_intersect_array_3_array_3_return_type(arg_types)
     }
@@ -86,4 +96,11 @@ impl ScalarUDFImpl for array_intersect_array_3_array_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_intersect_array_3_array_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "array_intersect_array_3_array_3",
+        )
+    }
 }
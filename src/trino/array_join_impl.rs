@@ -76,12 +76,18 @@ fn array_join_array_1_varchar_varchar_simplify(
 #[derive(Debug)]
 pub(super) struct array_join_array_1_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_join_array_1_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "varchar"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +104,10 @@ impl ScalarUDFImpl for array_join_array_1_varcharFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_join", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_join_array_1_varchar_return_type(arg_types)
     }
@@ -109,17 +119,27 @@ impl ScalarUDFImpl for array_join_array_1_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_join_array_1_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "array_join_array_1_varchar")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct array_join_array_1_varchar_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_join_array_1_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "varchar", "varchar"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +156,10 @@ impl ScalarUDFImpl for array_join_array_1_varchar_varcharFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_join", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_join_array_1_varchar_varchar_return_type(arg_types)
     }
@@ -147,4 +171,11 @@ impl ScalarUDFImpl for array_join_array_1_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_join_array_1_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "array_join_array_1_varchar_varchar",
+        )
+    }
 }
@@ -80,12 +80,15 @@ fn array_min_array_1_simplify(
 #[derive(Debug)]
 pub(super) struct array_min_array_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_min_array_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$1>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -102,6 +105,10 @@ impl ScalarUDFImpl for array_min_array_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_min", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_min_array_1_return_type(arg_types)
     }
@@ -113,4 +120,8 @@ impl ScalarUDFImpl for array_min_array_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_min_array_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "array_min_array_1")
+    }
 }
@@ -53,12 +53,15 @@ fn array_position_array_1_1_simplify(
 #[derive(Debug)]
 pub(super) struct array_position_array_1_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_position_array_1_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$1>", "$1"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +78,10 @@ impl ScalarUDFImpl for array_position_array_1_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_position", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_position_array_1_1_return_type(arg_types)
     }
@@ -86,4 +93,8 @@ impl ScalarUDFImpl for array_position_array_1_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_position_array_1_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "array_position_array_1_1")
+    }
 }
@@ -53,12 +53,15 @@ fn array_remove_array_3_3_simplify(
 #[derive(Debug)]
 pub(super) struct array_remove_array_3_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_remove_array_3_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$3>", "$3"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +78,10 @@ impl ScalarUDFImpl for array_remove_array_3_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_remove", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_remove_array_3_3_return_type(arg_types)
     }
@@ -86,4 +93,8 @@ impl ScalarUDFImpl for array_remove_array_3_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_remove_array_3_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "array_remove_array_3_3")
+    }
 }
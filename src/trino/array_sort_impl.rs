@@ -16,12 +16,18 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::ListElements;
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use arrow::compute::{cast, take};
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
+use datafusion::common::cast::as_int64_array;
+use datafusion::common::{exec_err, Result};
 use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::ops::Range;
 
 fn array_sort_array_3_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
@@ -47,26 +53,111 @@ fn array_sort_array_3_simplify(
 }
 
 fn array_sort_array_1_function_1_1_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("array_sort")
 }
 
-fn array_sort_array_1_function_1_1_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn array_sort_array_1_function_1_1_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("array_sort_array_1_function_1_1_bigint", arg_types)
 }
 
 fn array_sort_array_1_function_1_1_bigint_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "array_sort",
+        args,
+        info,
+        1,
+        array_sort_array_1_function_1_1_bigint_return_type,
+        array_sort,
+    )
+}
+
+/// A merge of two adjacent sorted runs of the elements of an array.
+struct Merge {
+    row: u32,
+    left: Range<usize>,
+    right: Range<usize>,
+    /// Where the next merged element goes
+    output: usize,
+}
+
+/// Sort the elements of the arrays with the comparator, a stable merge sort. The arrays are
+/// sorted in lockstep, bottom-up: each step of the merges of all the arrays calls the comparator
+/// once, on the pairs of elements at the heads of all the runs being merged.
+fn array_sort(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let elements = ListElements::new(&args[0])?;
+    let comparator = &lambdas[0];
+    let mut order = (0..elements.values.len() as u32).collect::<Vec<_>>();
+    let max_length = elements.lengths().max().unwrap_or(0);
+    let mut width = 1;
+    while width < max_length {
+        let mut sorted = order.clone();
+        let mut merges = vec![];
+        for (row, range) in elements.offsets.windows(2).enumerate() {
+            let (start, end) = (range[0] as usize, range[1] as usize);
+            for left in (start..end).step_by(2 * width) {
+                let middle = (left + width).min(end);
+                let right = (middle + width).min(end);
+                merges.push(Merge {
+                    row: row as u32,
+                    left: left..middle,
+                    right: middle..right,
+                    output: left,
+                });
+            }
+        }
+        loop {
+            merges.retain_mut(|merge| {
+                if merge.left.is_empty() || merge.right.is_empty() {
+                    let rest = if merge.left.is_empty() {
+                        &merge.right
+                    } else {
+                        &merge.left
+                    };
+                    sorted[merge.output..merge.output + rest.len()]
+                        .copy_from_slice(&order[rest.clone()]);
+                    return false;
+                }
+                true
+            });
+            if merges.is_empty() {
+                break;
+            }
+            let (lefts, rights): (Vec<_>, Vec<_>) = merges
+                .iter()
+                .map(|merge| (order[merge.left.start], order[merge.right.start]))
+                .unzip();
+            let rows = merges
+                .iter()
+                .map(|merge| merge.row)
+                .collect::<UInt32Array>();
+            let comparisons = comparator.call(
+                &[
+                    take(&elements.values, &UInt32Array::from(lefts), None)?,
+                    take(&elements.values, &UInt32Array::from(rights), None)?,
+                ],
+                &rows,
+            )?;
+            let comparisons = cast(&comparisons, &DataType::Int64)?;
+            let comparisons = as_int64_array(&comparisons)?;
+            for (merge, comparison) in merges.iter_mut().zip(comparisons) {
+                let next = match comparison {
+                    Some(-1) | Some(0) => &mut merge.left,
+                    Some(1) => &mut merge.right,
+                    _ => return exec_err!("Lambda comparator must return either -1, 0, or 1"),
+                };
+                sorted[merge.output] = order[next.start];
+                next.start += 1;
+                merge.output += 1;
+            }
+        }
+        order = sorted;
+        width *= 2;
+    }
+    let values = take(&elements.values, &UInt32Array::from(order), None)?;
+    elements.to_list(values)
 }
 
 // ========== Generated template below this line ==========
@@ -76,12 +167,15 @@ fn array_sort_array_1_function_1_1_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct array_sort_array_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_sort_array_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$3>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +192,10 @@ impl ScalarUDFImpl for array_sort_array_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_sort", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_sort_array_3_return_type(arg_types)
     }
@@ -109,17 +207,27 @@ impl ScalarUDFImpl for array_sort_array_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_sort_array_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "array_sort_array_3")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct array_sort_array_1_function_1_1_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl array_sort_array_1_function_1_1_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "function($1, $1, bigint)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +244,10 @@ impl ScalarUDFImpl for array_sort_array_1_function_1_1_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("array_sort", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         array_sort_array_1_function_1_1_bigint_return_type(arg_types)
     }
@@ -147,4 +259,11 @@ impl ScalarUDFImpl for array_sort_array_1_function_1_1_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         array_sort_array_1_function_1_1_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "array_sort_array_1_function_1_1_bigint",
+        )
+    }
 }
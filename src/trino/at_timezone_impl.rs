@@ -17,29 +17,44 @@
 
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{internal_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
-use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarUDFImpl, Signature, Volatility,
+};
 use std::any::Any;
 
-fn at_timezone_timestamp_p_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::{map_timestamp_zone, result_zone};
+
+fn at_timezone_timestamp_p_withtimezone_varchar_invoke(
+    args: &[ColumnarValue],
+) -> Result<ColumnarValue> {
+    map_timestamp_zone(&args[0], &args[1], true)
 }
 
-fn at_timezone_timestamp_p_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn at_timezone_timestamp_p_withtimezone_varchar_return_type(
+    arg_types: &[DataType],
+) -> Result<DataType> {
+    super::types::declared_return_type("at_timezone_timestamp_p_withtimezone_varchar", arg_types)
+}
+
+fn at_timezone_timestamp_p_withtimezone_varchar_return_type_from_args(
+    args: ReturnTypeArgs,
+) -> Result<ReturnInfo> {
+    // In the time zone of a constant zone
+    let DataType::Timestamp(unit, _) =
+        at_timezone_timestamp_p_withtimezone_varchar_return_type(args.arg_types)?
+    else {
+        return internal_err!("at_timezone must return a timestamp with time zone");
+    };
+    let zone = result_zone(args.scalar_arguments.get(1).copied().flatten());
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
-fn at_timezone_timestamp_p_varchar_simplify(
+fn at_timezone_timestamp_p_withtimezone_varchar_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
@@ -51,19 +66,25 @@ fn at_timezone_timestamp_p_varchar_simplify(
 // when template is regenerated!
 
 #[derive(Debug)]
-pub(super) struct at_timezone_timestamp_p_varcharFunc {
+pub(super) struct at_timezone_timestamp_p_withtimezone_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
-impl at_timezone_timestamp_p_varcharFunc {
-    pub fn new() -> Self {
+impl at_timezone_timestamp_p_withtimezone_varcharFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["timestamp(p) with time zone", "varchar"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
 
-impl ScalarUDFImpl for at_timezone_timestamp_p_varcharFunc {
+impl ScalarUDFImpl for at_timezone_timestamp_p_withtimezone_varcharFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -75,15 +96,33 @@ impl ScalarUDFImpl for at_timezone_timestamp_p_varcharFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("at_timezone", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        at_timezone_timestamp_p_varchar_return_type(arg_types)
+        at_timezone_timestamp_p_withtimezone_varchar_return_type(arg_types)
+    }
+
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        at_timezone_timestamp_p_withtimezone_varchar_return_type_from_args(args)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        at_timezone_timestamp_p_varchar_invoke(args)
+        at_timezone_timestamp_p_withtimezone_varchar_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        at_timezone_timestamp_p_varchar_simplify(args, info)
+        at_timezone_timestamp_p_withtimezone_varchar_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "at_timezone_timestamp_p_withtimezone_varchar",
+        )
     }
 }
@@ -56,9 +56,9 @@ pub(super) struct atan2_double_doubleFunc {
 }
 
 impl atan2_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64, DataType::Float64], volatility),
         }
     }
 }
@@ -86,4 +86,8 @@ impl ScalarUDFImpl for atan2_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         atan2_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "atan2_double_double")
+    }
 }
@@ -79,9 +79,9 @@ pub(super) struct bar_double_bigintFunc {
 }
 
 impl bar_double_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64, DataType::Int64], volatility),
         }
     }
 }
@@ -109,17 +109,27 @@ impl ScalarUDFImpl for bar_double_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bar_double_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "bar_double_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct bar_double_bigint_color_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl bar_double_bigint_color_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["double", "bigint", "color", "color"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +146,10 @@ impl ScalarUDFImpl for bar_double_bigint_color_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("bar", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         bar_double_bigint_color_color_return_type(arg_types)
     }
@@ -147,4 +161,8 @@ impl ScalarUDFImpl for bar_double_bigint_color_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bar_double_bigint_color_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "bar_double_bigint_color_color")
+    }
 }
@@ -56,9 +56,12 @@ pub(super) struct bing_tile_at_double_double_bigintFunc {
 }
 
 impl bing_tile_at_double_double_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Float64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,11 @@ impl ScalarUDFImpl for bing_tile_at_double_double_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tile_at_double_double_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bing_tile_at_double_double_bigint",
+        )
+    }
 }
@@ -56,9 +56,9 @@ pub(super) struct bing_tile_coordinates_bingtileFunc {
 }
 
 impl bing_tile_coordinates_bingtileFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -86,4 +86,8 @@ impl ScalarUDFImpl for bing_tile_coordinates_bingtileFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tile_coordinates_bingtile_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "bing_tile_coordinates_bingtile")
+    }
 }
@@ -79,9 +79,12 @@ pub(super) struct bing_tile_bigint_bigint_bigintFunc {
 }
 
 impl bing_tile_bigint_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -109,6 +112,10 @@ impl ScalarUDFImpl for bing_tile_bigint_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tile_bigint_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "bing_tile_bigint_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +124,9 @@ pub(super) struct bing_tile_varcharFunc {
 }
 
 impl bing_tile_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +154,8 @@ impl ScalarUDFImpl for bing_tile_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tile_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "bing_tile_varchar")
+    }
 }
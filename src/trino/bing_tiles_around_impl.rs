@@ -83,9 +83,12 @@ pub(super) struct bing_tiles_around_double_double_bigintFunc {
 }
 
 impl bing_tiles_around_double_double_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Float64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -113,6 +116,13 @@ impl ScalarUDFImpl for bing_tiles_around_double_double_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tiles_around_double_double_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bing_tiles_around_double_double_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -121,9 +131,17 @@ pub(super) struct bing_tiles_around_double_double_bigint_doubleFunc {
 }
 
 impl bing_tiles_around_double_double_bigint_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Int64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -151,4 +169,11 @@ impl ScalarUDFImpl for bing_tiles_around_double_double_bigint_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bing_tiles_around_double_double_bigint_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bing_tiles_around_double_double_bigint_double",
+        )
+    }
 }
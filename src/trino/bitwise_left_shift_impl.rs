@@ -125,9 +125,9 @@ pub(super) struct bitwise_left_shift_bigint_bigintFunc {
 }
 
 impl bitwise_left_shift_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64, DataType::Int64], volatility),
         }
     }
 }
@@ -155,6 +155,13 @@ impl ScalarUDFImpl for bitwise_left_shift_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_left_shift_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_left_shift_bigint_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -163,9 +170,9 @@ pub(super) struct bitwise_left_shift_integer_bigintFunc {
 }
 
 impl bitwise_left_shift_integer_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32, DataType::Int64], volatility),
         }
     }
 }
@@ -193,6 +200,13 @@ impl ScalarUDFImpl for bitwise_left_shift_integer_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_left_shift_integer_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_left_shift_integer_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -201,9 +215,9 @@ pub(super) struct bitwise_left_shift_smallint_bigintFunc {
 }
 
 impl bitwise_left_shift_smallint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16, DataType::Int64], volatility),
         }
     }
 }
@@ -231,6 +245,13 @@ impl ScalarUDFImpl for bitwise_left_shift_smallint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_left_shift_smallint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_left_shift_smallint_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -239,9 +260,9 @@ pub(super) struct bitwise_left_shift_tinyint_bigintFunc {
 }
 
 impl bitwise_left_shift_tinyint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8, DataType::Int64], volatility),
         }
     }
 }
@@ -269,4 +290,11 @@ impl ScalarUDFImpl for bitwise_left_shift_tinyint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_left_shift_tinyint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_left_shift_tinyint_bigint",
+        )
+    }
 }
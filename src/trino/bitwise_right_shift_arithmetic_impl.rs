@@ -141,9 +141,9 @@ pub(super) struct bitwise_right_shift_arithmetic_bigint_bigintFunc {
 }
 
 impl bitwise_right_shift_arithmetic_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64, DataType::Int64], volatility),
         }
     }
 }
@@ -171,6 +171,13 @@ impl ScalarUDFImpl for bitwise_right_shift_arithmetic_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_right_shift_arithmetic_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_right_shift_arithmetic_bigint_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -179,9 +186,9 @@ pub(super) struct bitwise_right_shift_arithmetic_integer_bigintFunc {
 }
 
 impl bitwise_right_shift_arithmetic_integer_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32, DataType::Int64], volatility),
         }
     }
 }
@@ -209,6 +216,13 @@ impl ScalarUDFImpl for bitwise_right_shift_arithmetic_integer_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_right_shift_arithmetic_integer_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_right_shift_arithmetic_integer_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -217,9 +231,9 @@ pub(super) struct bitwise_right_shift_arithmetic_smallint_bigintFunc {
 }
 
 impl bitwise_right_shift_arithmetic_smallint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16, DataType::Int64], volatility),
         }
     }
 }
@@ -247,6 +261,13 @@ impl ScalarUDFImpl for bitwise_right_shift_arithmetic_smallint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_right_shift_arithmetic_smallint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_right_shift_arithmetic_smallint_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -255,9 +276,9 @@ pub(super) struct bitwise_right_shift_arithmetic_tinyint_bigintFunc {
 }
 
 impl bitwise_right_shift_arithmetic_tinyint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8, DataType::Int64], volatility),
         }
     }
 }
@@ -285,4 +306,11 @@ impl ScalarUDFImpl for bitwise_right_shift_arithmetic_tinyint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         bitwise_right_shift_arithmetic_tinyint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "bitwise_right_shift_arithmetic_tinyint_bigint",
+        )
+    }
 }
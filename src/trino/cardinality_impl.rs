@@ -16,7 +16,7 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::array::{ArrayRef, UInt64Array};
+use arrow::array::{ArrayRef, Int64Array};
 use arrow::datatypes::DataType;
 use datafusion::common::cast::as_list_array;
 use datafusion::common::Result;
@@ -33,15 +33,15 @@ fn cardinality_array_3_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         let list_array = as_list_array(array)?;
         let result = list_array
             .iter()
-            .map(|item| item.map(|arr| arr.len() as u64))
-            .collect::<UInt64Array>();
+            .map(|item| item.map(|arr| arr.len() as i64))
+            .collect::<Int64Array>();
         Ok(Arc::new(result) as ArrayRef)
     })
 }
 
 fn cardinality_array_3_return_type(arg_types: &[DataType]) -> Result<DataType> {
     match arg_types[0] {
-        DataType::List(_) => Ok(DataType::UInt64),
+        DataType::List(_) => super::types::declared_return_type("cardinality_array_3", arg_types),
         _ => Err(DataFusionError::Plan(
             "The cardinality function only accepts List.".to_string(),
         )),
@@ -131,12 +131,15 @@ fn cardinality_setdigest_simplify(
 #[derive(Debug)]
 pub(super) struct cardinality_array_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl cardinality_array_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$3>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -153,6 +156,10 @@ impl ScalarUDFImpl for cardinality_array_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("cardinality", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         cardinality_array_3_return_type(arg_types)
     }
@@ -164,6 +171,10 @@ impl ScalarUDFImpl for cardinality_array_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cardinality_array_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cardinality_array_3")
+    }
 }
 
 #[derive(Debug)]
@@ -172,9 +183,9 @@ pub(super) struct cardinality_hyperloglogFunc {
 }
 
 impl cardinality_hyperloglogFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -202,17 +213,24 @@ impl ScalarUDFImpl for cardinality_hyperloglogFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cardinality_hyperloglog_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cardinality_hyperloglog")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct cardinality_map_4_5Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl cardinality_map_4_5Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["map<$4, $5>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -229,6 +247,10 @@ impl ScalarUDFImpl for cardinality_map_4_5Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("cardinality", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         cardinality_map_4_5_return_type(arg_types)
     }
@@ -240,6 +262,10 @@ impl ScalarUDFImpl for cardinality_map_4_5Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cardinality_map_4_5_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cardinality_map_4_5")
+    }
 }
 
 #[derive(Debug)]
@@ -248,9 +274,9 @@ pub(super) struct cardinality_setdigestFunc {
 }
 
 impl cardinality_setdigestFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -278,4 +304,8 @@ impl ScalarUDFImpl for cardinality_setdigestFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cardinality_setdigest_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cardinality_setdigest")
+    }
 }
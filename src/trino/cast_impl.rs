@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::datatypes::DataType;
+use datafusion::common::{plan_err, Result};
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use std::any::Any;
+
+fn cast_1_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    super::types::invoke_cast(args, false)
+}
+
+fn cast_1_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
+    // The type to cast to is the value of the second argument
+    plan_err!("cast requires the type to cast to as a constant")
+}
+
+fn cast_1_varchar_return_type_from_args(args: ReturnTypeArgs) -> Result<ReturnInfo> {
+    super::types::cast_return_type(args)
+}
+
+fn cast_1_varchar_simplify(
+    args: Vec<Expr>,
+    _info: &dyn SimplifyInfo,
+) -> Result<ExprSimplifyResult> {
+    Ok(ExprSimplifyResult::Original(args))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct cast_1_varcharFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl cast_1_varcharFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1", "varchar"], false)
+                .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl ScalarUDFImpl for cast_1_varcharFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "cast"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("cast", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        cast_1_varchar_return_type(arg_types)
+    }
+
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        cast_1_varchar_return_type_from_args(args)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        cast_1_varchar_invoke(args)
+    }
+
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        cast_1_varchar_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cast_1_varchar")
+    }
+}
@@ -53,9 +53,9 @@ pub(super) struct cbrt_doubleFunc {
 }
 
 impl cbrt_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -83,4 +83,8 @@ impl ScalarUDFImpl for cbrt_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cbrt_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "cbrt_double")
+    }
 }
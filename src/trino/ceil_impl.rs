@@ -168,9 +168,9 @@ pub(super) struct ceil_bigintFunc {
 }
 
 impl ceil_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -198,17 +198,24 @@ impl ScalarUDFImpl for ceil_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct ceil_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl ceil_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -225,6 +232,10 @@ impl ScalarUDFImpl for ceil_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("ceil", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         ceil_decimal_p_s_return_type(arg_types)
     }
@@ -236,6 +247,10 @@ impl ScalarUDFImpl for ceil_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_decimal_p_s")
+    }
 }
 
 #[derive(Debug)]
@@ -244,9 +259,9 @@ pub(super) struct ceil_doubleFunc {
 }
 
 impl ceil_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -274,6 +289,10 @@ impl ScalarUDFImpl for ceil_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_double")
+    }
 }
 
 #[derive(Debug)]
@@ -282,9 +301,9 @@ pub(super) struct ceil_integerFunc {
 }
 
 impl ceil_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32], volatility),
         }
     }
 }
@@ -312,6 +331,10 @@ impl ScalarUDFImpl for ceil_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -320,9 +343,9 @@ pub(super) struct ceil_realFunc {
 }
 
 impl ceil_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -350,6 +373,10 @@ impl ScalarUDFImpl for ceil_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_real")
+    }
 }
 
 #[derive(Debug)]
@@ -358,9 +385,9 @@ pub(super) struct ceil_smallintFunc {
 }
 
 impl ceil_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16], volatility),
         }
     }
 }
@@ -388,6 +415,10 @@ impl ScalarUDFImpl for ceil_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -396,9 +427,9 @@ pub(super) struct ceil_tinyintFunc {
 }
 
 impl ceil_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8], volatility),
         }
     }
 }
@@ -426,4 +457,8 @@ impl ScalarUDFImpl for ceil_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceil_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceil_tinyint")
+    }
 }
@@ -183,9 +183,9 @@ pub(super) struct ceiling_bigintFunc {
 }
 
 impl ceiling_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -213,17 +213,24 @@ impl ScalarUDFImpl for ceiling_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct ceiling_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl ceiling_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -240,6 +247,10 @@ impl ScalarUDFImpl for ceiling_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("ceiling", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         ceiling_decimal_p_s_return_type(arg_types)
     }
@@ -251,6 +262,10 @@ impl ScalarUDFImpl for ceiling_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_decimal_p_s")
+    }
 }
 
 #[derive(Debug)]
@@ -259,9 +274,9 @@ pub(super) struct ceiling_doubleFunc {
 }
 
 impl ceiling_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -289,6 +304,10 @@ impl ScalarUDFImpl for ceiling_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_double")
+    }
 }
 
 #[derive(Debug)]
@@ -297,9 +316,9 @@ pub(super) struct ceiling_integerFunc {
 }
 
 impl ceiling_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32], volatility),
         }
     }
 }
@@ -327,6 +346,10 @@ impl ScalarUDFImpl for ceiling_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -335,9 +358,9 @@ pub(super) struct ceiling_realFunc {
 }
 
 impl ceiling_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -365,6 +388,10 @@ impl ScalarUDFImpl for ceiling_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_real")
+    }
 }
 
 #[derive(Debug)]
@@ -373,9 +400,9 @@ pub(super) struct ceiling_smallintFunc {
 }
 
 impl ceiling_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16], volatility),
         }
     }
 }
@@ -403,6 +430,10 @@ impl ScalarUDFImpl for ceiling_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -411,9 +442,9 @@ pub(super) struct ceiling_tinyintFunc {
 }
 
 impl ceiling_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8], volatility),
         }
     }
 }
@@ -441,4 +472,8 @@ impl ScalarUDFImpl for ceiling_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ceiling_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ceiling_tinyint")
+    }
 }
@@ -53,12 +53,18 @@ fn classify_map_bigint_double_classifier_simplify(
 #[derive(Debug)]
 pub(super) struct classify_map_bigint_double_classifierFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl classify_map_bigint_double_classifierFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<bigint, double>", "classifier"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +81,10 @@ impl ScalarUDFImpl for classify_map_bigint_double_classifierFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("classify", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         classify_map_bigint_double_classifier_return_type(arg_types)
     }
@@ -86,4 +96,11 @@ impl ScalarUDFImpl for classify_map_bigint_double_classifierFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         classify_map_bigint_double_classifier_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "classify_map_bigint_double_classifier",
+        )
+    }
 }
@@ -96,12 +96,18 @@ fn color_varchar_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<E
 #[derive(Debug)]
 pub(super) struct color_double_color_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl color_double_color_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["double", "color", "color"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -118,6 +124,10 @@ impl ScalarUDFImpl for color_double_color_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("color", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         color_double_color_color_return_type(arg_types)
     }
@@ -129,17 +139,27 @@ impl ScalarUDFImpl for color_double_color_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         color_double_color_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "color_double_color_color")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct color_double_double_double_color_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl color_double_double_double_color_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(5, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["double", "double", "double", "color", "color"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -156,6 +176,10 @@ impl ScalarUDFImpl for color_double_double_double_color_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("color", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         color_double_double_double_color_color_return_type(arg_types)
     }
@@ -167,6 +191,13 @@ impl ScalarUDFImpl for color_double_double_double_color_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         color_double_double_double_color_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "color_double_double_double_color_color",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -175,9 +206,9 @@ pub(super) struct color_varcharFunc {
 }
 
 impl color_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -205,4 +236,8 @@ impl ScalarUDFImpl for color_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         color_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "color_varchar")
+    }
 }
@@ -168,12 +168,15 @@ fn concat_varbinary_simplify(
 #[derive(Debug)]
 pub(super) struct concat_3_array_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl concat_3_array_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$3", "array<$3>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -190,6 +193,10 @@ impl ScalarUDFImpl for concat_3_array_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("concat", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         concat_3_array_3_return_type(arg_types)
     }
@@ -201,17 +208,24 @@ impl ScalarUDFImpl for concat_3_array_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_3_array_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_3_array_3")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct concat_array_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl concat_array_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$3>"], true)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -228,6 +242,10 @@ impl ScalarUDFImpl for concat_array_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("concat", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         concat_array_3_return_type(arg_types)
     }
@@ -239,17 +257,24 @@ impl ScalarUDFImpl for concat_array_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_array_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_array_3")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct concat_array_3_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl concat_array_3_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$3>", "$3"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -266,6 +291,10 @@ impl ScalarUDFImpl for concat_array_3_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("concat", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         concat_array_3_3_return_type(arg_types)
     }
@@ -277,6 +306,10 @@ impl ScalarUDFImpl for concat_array_3_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_array_3_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_array_3_3")
+    }
 }
 
 #[derive(Debug)]
@@ -285,9 +318,9 @@ pub(super) struct concat_varchar_varcharFunc {
 }
 
 impl concat_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -315,6 +348,10 @@ impl ScalarUDFImpl for concat_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_varchar_varchar")
+    }
 }
 
 #[derive(Debug)]
@@ -323,9 +360,9 @@ pub(super) struct concat_varcharFunc {
 }
 
 impl concat_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::variadic(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -353,6 +390,10 @@ impl ScalarUDFImpl for concat_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_varchar")
+    }
 }
 
 #[derive(Debug)]
@@ -361,9 +402,9 @@ pub(super) struct concat_varbinaryFunc {
 }
 
 impl concat_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::variadic(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -391,4 +432,8 @@ impl ScalarUDFImpl for concat_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_varbinary")
+    }
 }
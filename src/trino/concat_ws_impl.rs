@@ -76,12 +76,18 @@ fn concat_ws_varchar_simplify(
 #[derive(Debug)]
 pub(super) struct concat_ws_varchar_array_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl concat_ws_varchar_array_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "array<varchar>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +104,10 @@ impl ScalarUDFImpl for concat_ws_varchar_array_varcharFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("concat_ws", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         concat_ws_varchar_array_varchar_return_type(arg_types)
     }
@@ -109,6 +119,13 @@ impl ScalarUDFImpl for concat_ws_varchar_array_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_ws_varchar_array_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "concat_ws_varchar_array_varchar",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +134,9 @@ pub(super) struct concat_ws_varcharFunc {
 }
 
 impl concat_ws_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::variadic(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +164,8 @@ impl ScalarUDFImpl for concat_ws_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         concat_ws_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "concat_ws_varchar")
+    }
 }
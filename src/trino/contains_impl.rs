@@ -76,12 +76,15 @@ fn contains_varchar_ipaddress_simplify(
 #[derive(Debug)]
 pub(super) struct contains_array_1_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl contains_array_1_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<$1>", "$1"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +101,10 @@ impl ScalarUDFImpl for contains_array_1_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("contains", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         contains_array_1_1_return_type(arg_types)
     }
@@ -109,6 +116,10 @@ impl ScalarUDFImpl for contains_array_1_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         contains_array_1_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "contains_array_1_1")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +128,12 @@ pub(super) struct contains_varchar_ipaddressFunc {
 }
 
 impl contains_varchar_ipaddressFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::FixedSizeBinary(16)],
+                volatility,
+            ),
         }
     }
 }
@@ -147,4 +161,8 @@ impl ScalarUDFImpl for contains_varchar_ipaddressFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         contains_varchar_ipaddress_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "contains_varchar_ipaddress")
+    }
 }
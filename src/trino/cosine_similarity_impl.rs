@@ -57,12 +57,18 @@ fn cosine_similarity_map_varchar_double_map_varchar_double_simplify(
 #[derive(Debug)]
 pub(super) struct cosine_similarity_map_varchar_double_map_varchar_doubleFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl cosine_similarity_map_varchar_double_map_varchar_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<varchar, double>", "map<varchar, double>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +85,10 @@ impl ScalarUDFImpl for cosine_similarity_map_varchar_double_map_varchar_doubleFu
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("cosine_similarity", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         cosine_similarity_map_varchar_double_map_varchar_double_return_type(arg_types)
     }
@@ -90,4 +100,11 @@ impl ScalarUDFImpl for cosine_similarity_map_varchar_double_map_varchar_doubleFu
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         cosine_similarity_map_varchar_double_map_varchar_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "cosine_similarity_map_varchar_double_map_varchar_double",
+        )
+    }
 }
@@ -18,32 +18,20 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn current_groups_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn current_groups_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
+    super::session::unknown_session("current_groups")
 }
 
-fn current_groups_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn current_groups_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("current_groups", arg_types)
 }
 
-fn current_groups_simplify(
-    args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
-) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+fn current_groups_simplify(args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+    super::session::simplify_session_function("current_groups", args, info)
 }
 
 // ========== Generated template below this line ==========
@@ -56,9 +44,9 @@ pub(super) struct current_groupsFunc {
 }
 
 impl current_groupsFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -79,11 +67,15 @@ impl ScalarUDFImpl for current_groupsFunc {
         current_groups_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        current_groups_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        current_groups_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_groups_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_groups")
+    }
 }
@@ -17,19 +17,28 @@
 
 #![allow(non_camel_case_types)]
 use arrow::datatypes::{DataType, TimeUnit};
-use datafusion::common::Result;
+use chrono::Utc;
+use datafusion::common::{internal_err, Result};
 use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use datafusion::scalar::ScalarValue;
 use std::any::Any;
 
-fn current_timestamp_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented("todo".to_string()))
+fn current_timestamp_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
+    // Only when not simplified, in UTC as the declared return type
+    let DataType::Timestamp(TimeUnit::Millisecond, tz) = current_timestamp_return_type(&[])? else {
+        return internal_err!("current_timestamp must return a timestamp(3) with time zone");
+    };
+    let millis = Utc::now().timestamp_millis();
+    Ok(ColumnarValue::Scalar(ScalarValue::TimestampMillisecond(
+        Some(millis),
+        tz,
+    )))
 }
 
-fn current_timestamp_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Ok(DataType::Timestamp(TimeUnit::Millisecond, None))
+fn current_timestamp_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("current_timestamp", arg_types)
 }
 
 fn current_timestamp_simplify(
@@ -38,9 +47,12 @@ fn current_timestamp_simplify(
 ) -> Result<ExprSimplifyResult> {
     let now_ts = info.execution_props().query_execution_start_time;
     let millis = now_ts.timestamp_millis();
-
+    let DataType::Timestamp(TimeUnit::Millisecond, tz) = current_timestamp_return_type(&[])? else {
+        return internal_err!("current_timestamp must return a timestamp(3) with time zone");
+    };
+    // In UTC: the function bound to the session converts it to the time zone of the session
     Ok(ExprSimplifyResult::Simplified(Expr::Literal(
-        ScalarValue::TimestampMillisecond(Some(millis), None),
+        ScalarValue::TimestampMillisecond(Some(millis), tz),
     )))
 }
 
@@ -114,9 +126,9 @@ pub(super) struct current_timestampFunc {
 }
 
 impl current_timestampFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -137,13 +149,17 @@ impl ScalarUDFImpl for current_timestampFunc {
         current_timestamp_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        current_timestamp_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        current_timestamp_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_timestamp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_timestamp")
+    }
 }
 
 #[derive(Debug)]
@@ -152,9 +168,9 @@ pub(super) struct current_timestamp_bigint_0Func {
 }
 
 impl current_timestamp_bigint_0Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -182,6 +198,10 @@ impl ScalarUDFImpl for current_timestamp_bigint_0Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_timestamp_bigint_0_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_timestamp_bigint_0")
+    }
 }
 
 #[derive(Debug)]
@@ -190,9 +210,9 @@ pub(super) struct current_timestamp_bigint_3Func {
 }
 
 impl current_timestamp_bigint_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -220,6 +240,10 @@ impl ScalarUDFImpl for current_timestamp_bigint_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_timestamp_bigint_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_timestamp_bigint_3")
+    }
 }
 
 #[derive(Debug)]
@@ -228,9 +252,9 @@ pub(super) struct current_timestamp_bigint_6Func {
 }
 
 impl current_timestamp_bigint_6Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -258,6 +282,10 @@ impl ScalarUDFImpl for current_timestamp_bigint_6Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_timestamp_bigint_6_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_timestamp_bigint_6")
+    }
 }
 
 #[derive(Debug)]
@@ -266,9 +294,9 @@ pub(super) struct current_timestamp_bigint_9Func {
 }
 
 impl current_timestamp_bigint_9Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -296,4 +324,8 @@ impl ScalarUDFImpl for current_timestamp_bigint_9Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         current_timestamp_bigint_9_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "current_timestamp_bigint_9")
+    }
 }
@@ -18,25 +18,19 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn date_format_timestamp_p_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::datetime_pattern::DateTimePattern;
+use crate::utils_datetime::map_format_timestamp;
+
+fn date_format_timestamp_p_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_format_timestamp(&args[0], &args[1], DateTimePattern::mysql)
 }
 
-fn date_format_timestamp_p_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_format_timestamp_p_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("date_format_timestamp_p_varchar", arg_types)
 }
 
 fn date_format_timestamp_p_varchar_simplify(
@@ -56,9 +50,29 @@ pub(super) struct date_format_timestamp_p_varcharFunc {
 }
 
 impl date_format_timestamp_p_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Second, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Millisecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None),
+                        DataType::Utf8,
+                    ]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +100,11 @@ impl ScalarUDFImpl for date_format_timestamp_p_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_format_timestamp_p_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "date_format_timestamp_p_varchar",
+        )
+    }
 }
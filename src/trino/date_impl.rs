@@ -76,9 +76,29 @@ pub(super) struct date_timestamp_pFunc {
 }
 
 impl date_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -106,6 +126,10 @@ impl ScalarUDFImpl for date_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_timestamp_p")
+    }
 }
 
 #[derive(Debug)]
@@ -114,9 +138,9 @@ pub(super) struct date_varcharFunc {
 }
 
 impl date_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -144,4 +168,8 @@ impl ScalarUDFImpl for date_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_varchar")
+    }
 }
@@ -18,25 +18,20 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn date_parse_varchar_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::datetime_pattern::DateTimePattern;
+use crate::utils_datetime::map_parse_timestamp;
+
+fn date_parse_varchar_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let return_type = date_parse_varchar_varchar_return_type(&[DataType::Utf8, DataType::Utf8])?;
+    map_parse_timestamp(&args[0], &args[1], DateTimePattern::mysql, &return_type)
 }
 
-fn date_parse_varchar_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_parse_varchar_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("date_parse_varchar_varchar", arg_types)
 }
 
 fn date_parse_varchar_varchar_simplify(
@@ -56,9 +51,9 @@ pub(super) struct date_parse_varchar_varcharFunc {
 }
 
 impl date_parse_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -86,4 +81,8 @@ impl ScalarUDFImpl for date_parse_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_parse_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_parse_varchar_varchar")
+    }
 }
@@ -18,25 +18,18 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn date_trunc_varchar_time_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_calendar::map_date_trunc;
+
+fn date_trunc_varchar_time_p_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_date_trunc(args)
 }
 
-fn date_trunc_varchar_time_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_trunc_varchar_time_p_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("date_trunc_varchar_time_p", arg_types)
 }
 
 fn date_trunc_varchar_time_p_simplify(
@@ -46,20 +39,12 @@ fn date_trunc_varchar_time_p_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn date_trunc_varchar_timestamp_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_trunc_varchar_timestamp_p_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_date_trunc(args)
 }
 
-fn date_trunc_varchar_timestamp_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_trunc_varchar_timestamp_p_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("date_trunc_varchar_timestamp_p", arg_types)
 }
 
 fn date_trunc_varchar_timestamp_p_simplify(
@@ -69,20 +54,12 @@ fn date_trunc_varchar_timestamp_p_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn date_trunc_varchar_date_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_trunc_varchar_date_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_date_trunc(args)
 }
 
-fn date_trunc_varchar_date_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn date_trunc_varchar_date_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("date_trunc_varchar_date", arg_types)
 }
 
 fn date_trunc_varchar_date_simplify(
@@ -92,6 +69,25 @@ fn date_trunc_varchar_date_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
+fn date_trunc_varchar_timestamp_p_withtimezone_invoke(
+    args: &[ColumnarValue],
+) -> Result<ColumnarValue> {
+    map_date_trunc(args)
+}
+
+fn date_trunc_varchar_timestamp_p_withtimezone_return_type(
+    arg_types: &[DataType],
+) -> Result<DataType> {
+    super::types::declared_return_type("date_trunc_varchar_timestamp_p_withtimezone", arg_types)
+}
+
+fn date_trunc_varchar_timestamp_p_withtimezone_simplify(
+    args: Vec<Expr>,
+    _info: &dyn SimplifyInfo,
+) -> Result<ExprSimplifyResult> {
+    Ok(ExprSimplifyResult::Original(args))
+}
+
 // ========== Generated template below this line ==========
 // Do *NOT* edit below this line: all changes will be overwritten
 // when template is regenerated!
@@ -102,9 +98,29 @@ pub(super) struct date_trunc_varchar_time_pFunc {
 }
 
 impl date_trunc_varchar_time_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Time32(arrow::datatypes::TimeUnit::Second),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Time32(arrow::datatypes::TimeUnit::Millisecond),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Time64(arrow::datatypes::TimeUnit::Microsecond),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Time64(arrow::datatypes::TimeUnit::Nanosecond),
+                    ]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -132,6 +148,10 @@ impl ScalarUDFImpl for date_trunc_varchar_time_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_trunc_varchar_time_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_trunc_varchar_time_p")
+    }
 }
 
 #[derive(Debug)]
@@ -140,9 +160,29 @@ pub(super) struct date_trunc_varchar_timestamp_pFunc {
 }
 
 impl date_trunc_varchar_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Second, None),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Millisecond, None),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None),
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Utf8,
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None),
+                    ]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -170,6 +210,65 @@ impl ScalarUDFImpl for date_trunc_varchar_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_trunc_varchar_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_trunc_varchar_timestamp_p")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct date_trunc_varchar_timestamp_p_withtimezoneFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl date_trunc_varchar_timestamp_p_withtimezoneFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "timestamp(p) with time zone"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl ScalarUDFImpl for date_trunc_varchar_timestamp_p_withtimezoneFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "date_trunc"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("date_trunc", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        date_trunc_varchar_timestamp_p_withtimezone_return_type(arg_types)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        date_trunc_varchar_timestamp_p_withtimezone_invoke(args)
+    }
+
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        date_trunc_varchar_timestamp_p_withtimezone_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "date_trunc_varchar_timestamp_p_withtimezone",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -178,9 +277,9 @@ pub(super) struct date_trunc_varchar_dateFunc {
 }
 
 impl date_trunc_varchar_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Date32], volatility),
         }
     }
 }
@@ -208,4 +307,8 @@ impl ScalarUDFImpl for date_trunc_varchar_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         date_trunc_varchar_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "date_trunc_varchar_date")
+    }
 }
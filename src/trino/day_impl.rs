@@ -78,9 +78,9 @@ pub(super) struct day_dateFunc {
 }
 
 impl day_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Date32], volatility),
         }
     }
 }
@@ -108,17 +108,27 @@ impl ScalarUDFImpl for day_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_date")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct day_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl day_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -135,6 +145,10 @@ impl ScalarUDFImpl for day_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("day", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         day_intervaldaytosecond_return_type(arg_types)
     }
@@ -146,6 +160,10 @@ impl ScalarUDFImpl for day_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_intervaldaytosecond")
+    }
 }
 
 #[derive(Debug)]
@@ -154,9 +172,29 @@ pub(super) struct day_timestamp_pFunc {
 }
 
 impl day_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -184,4 +222,8 @@ impl ScalarUDFImpl for day_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_timestamp_p")
+    }
 }
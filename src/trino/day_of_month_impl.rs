@@ -89,9 +89,9 @@ pub(super) struct day_of_month_dateFunc {
 }
 
 impl day_of_month_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Date32], volatility),
         }
     }
 }
@@ -119,17 +119,27 @@ impl ScalarUDFImpl for day_of_month_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_of_month_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_of_month_date")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct day_of_month_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl day_of_month_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -146,6 +156,10 @@ impl ScalarUDFImpl for day_of_month_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("day_of_month", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         day_of_month_intervaldaytosecond_return_type(arg_types)
     }
@@ -157,6 +171,13 @@ impl ScalarUDFImpl for day_of_month_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_of_month_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "day_of_month_intervaldaytosecond",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -165,9 +186,29 @@ pub(super) struct day_of_month_timestamp_pFunc {
 }
 
 impl day_of_month_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -195,4 +236,8 @@ impl ScalarUDFImpl for day_of_month_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_of_month_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_of_month_timestamp_p")
+    }
 }
@@ -65,9 +65,9 @@ pub(super) struct day_of_year_dateFunc {
 }
 
 impl day_of_year_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Date32], volatility),
         }
     }
 }
@@ -95,6 +95,10 @@ impl ScalarUDFImpl for day_of_year_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_of_year_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_of_year_date")
+    }
 }
 
 #[derive(Debug)]
@@ -103,9 +107,29 @@ pub(super) struct day_of_year_timestamp_pFunc {
 }
 
 impl day_of_year_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -133,4 +157,8 @@ impl ScalarUDFImpl for day_of_year_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         day_of_year_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "day_of_year_timestamp_p")
+    }
 }
@@ -76,12 +76,18 @@ fn element_at_array_3_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct element_at_map_4_5_4Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl element_at_map_4_5_4Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<$4, $5>", "$4"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +104,10 @@ impl ScalarUDFImpl for element_at_map_4_5_4Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("element_at", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         element_at_map_4_5_4_return_type(arg_types)
     }
@@ -109,17 +119,27 @@ impl ScalarUDFImpl for element_at_map_4_5_4Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         element_at_map_4_5_4_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "element_at_map_4_5_4")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct element_at_array_3_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl element_at_array_3_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$3>", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +156,10 @@ impl ScalarUDFImpl for element_at_array_3_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("element_at", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         element_at_array_3_bigint_return_type(arg_types)
     }
@@ -147,4 +171,8 @@ impl ScalarUDFImpl for element_at_array_3_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         element_at_array_3_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "element_at_array_3_bigint")
+    }
 }
@@ -23,7 +23,7 @@ use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn empty_approx_set_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
+fn empty_approx_set_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
         "Not implemented {}:{}",
         file!(),
@@ -56,9 +56,9 @@ pub(super) struct empty_approx_setFunc {
 }
 
 impl empty_approx_setFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -79,11 +79,15 @@ impl ScalarUDFImpl for empty_approx_setFunc {
         empty_approx_set_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        empty_approx_set_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        empty_approx_set_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         empty_approx_set_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "empty_approx_set")
+    }
 }
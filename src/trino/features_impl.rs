@@ -287,9 +287,9 @@ pub(super) struct features_doubleFunc {
 }
 
 impl features_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -317,6 +317,10 @@ impl ScalarUDFImpl for features_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "features_double")
+    }
 }
 
 #[derive(Debug)]
@@ -325,9 +329,9 @@ pub(super) struct features_double_doubleFunc {
 }
 
 impl features_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64, DataType::Float64], volatility),
         }
     }
 }
@@ -355,6 +359,10 @@ impl ScalarUDFImpl for features_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "features_double_double")
+    }
 }
 
 #[derive(Debug)]
@@ -363,9 +371,12 @@ pub(super) struct features_double_double_doubleFunc {
 }
 
 impl features_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Float64, DataType::Float64],
+                volatility,
+            ),
         }
     }
 }
@@ -393,6 +404,10 @@ impl ScalarUDFImpl for features_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "features_double_double_double")
+    }
 }
 
 #[derive(Debug)]
@@ -401,9 +416,17 @@ pub(super) struct features_double_double_double_doubleFunc {
 }
 
 impl features_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -431,6 +454,13 @@ impl ScalarUDFImpl for features_double_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -439,9 +469,18 @@ pub(super) struct features_double_double_double_double_doubleFunc {
 }
 
 impl features_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(5, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -469,6 +508,13 @@ impl ScalarUDFImpl for features_double_double_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -477,9 +523,19 @@ pub(super) struct features_double_double_double_double_double_doubleFunc {
 }
 
 impl features_double_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(6, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -507,6 +563,13 @@ impl ScalarUDFImpl for features_double_double_double_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -515,9 +578,20 @@ pub(super) struct features_double_double_double_double_double_double_doubleFunc
 }
 
 impl features_double_double_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(7, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -545,6 +619,13 @@ impl ScalarUDFImpl for features_double_double_double_double_double_double_double
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -553,9 +634,21 @@ pub(super) struct features_double_double_double_double_double_double_double_doub
 }
 
 impl features_double_double_double_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(8, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -583,6 +676,13 @@ impl ScalarUDFImpl for features_double_double_double_double_double_double_double
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -591,9 +691,22 @@ pub(super) struct features_double_double_double_double_double_double_double_doub
 }
 
 impl features_double_double_double_double_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(9, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -623,6 +736,13 @@ impl ScalarUDFImpl for features_double_double_double_double_double_double_double
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         features_double_double_double_double_double_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double_double_double_double_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -632,9 +752,23 @@ pub(super) struct features_double_double_double_double_double_double_double_doub
 }
 
 impl features_double_double_double_double_double_double_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(10, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -668,4 +802,11 @@ impl ScalarUDFImpl
             args, info,
         )
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "features_double_double_double_double_double_double_double_double_double_double",
+        )
+    }
 }
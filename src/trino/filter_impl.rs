@@ -16,34 +16,43 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::ListElements;
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn filter_array_1_function_1_boolean_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("filter")
 }
 
-fn filter_array_1_function_1_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn filter_array_1_function_1_boolean_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("filter_array_1_function_1_boolean", arg_types)
 }
 
 fn filter_array_1_function_1_boolean_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "filter",
+        args,
+        info,
+        1,
+        filter_array_1_function_1_boolean_return_type,
+        filter,
+    )
+}
+
+/// The arrays of the elements for which the lambda expression is true (not false or NULL).
+fn filter(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let elements = ListElements::new(&args[0])?;
+    let keep = lambdas[0].call(&[Arc::clone(&elements.values)], &elements.rows)?;
+    elements.filter(&keep)
 }
 
 // ========== Generated template below this line ==========
@@ -53,12 +62,18 @@ fn filter_array_1_function_1_boolean_simplify(
 #[derive(Debug)]
 pub(super) struct filter_array_1_function_1_booleanFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl filter_array_1_function_1_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "function($1, boolean)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +90,10 @@ impl ScalarUDFImpl for filter_array_1_function_1_booleanFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("filter", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         filter_array_1_function_1_boolean_return_type(arg_types)
     }
@@ -86,4 +105,11 @@ impl ScalarUDFImpl for filter_array_1_function_1_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         filter_array_1_function_1_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "filter_array_1_function_1_boolean",
+        )
+    }
 }
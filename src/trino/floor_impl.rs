@@ -171,9 +171,9 @@ pub(super) struct floor_bigintFunc {
 }
 
 impl floor_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -201,17 +201,24 @@ impl ScalarUDFImpl for floor_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct floor_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl floor_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -228,6 +235,10 @@ impl ScalarUDFImpl for floor_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("floor", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         floor_decimal_p_s_return_type(arg_types)
     }
@@ -239,6 +250,10 @@ impl ScalarUDFImpl for floor_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_decimal_p_s")
+    }
 }
 
 #[derive(Debug)]
@@ -247,9 +262,9 @@ pub(super) struct floor_doubleFunc {
 }
 
 impl floor_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -277,6 +292,10 @@ impl ScalarUDFImpl for floor_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_double")
+    }
 }
 
 #[derive(Debug)]
@@ -285,9 +304,9 @@ pub(super) struct floor_integerFunc {
 }
 
 impl floor_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32], volatility),
         }
     }
 }
@@ -315,6 +334,10 @@ impl ScalarUDFImpl for floor_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -323,9 +346,9 @@ pub(super) struct floor_realFunc {
 }
 
 impl floor_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -353,6 +376,10 @@ impl ScalarUDFImpl for floor_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_real")
+    }
 }
 
 #[derive(Debug)]
@@ -361,9 +388,9 @@ pub(super) struct floor_smallintFunc {
 }
 
 impl floor_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16], volatility),
         }
     }
 }
@@ -391,6 +418,10 @@ impl ScalarUDFImpl for floor_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -399,9 +430,9 @@ pub(super) struct floor_tinyintFunc {
 }
 
 impl floor_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8], volatility),
         }
     }
 }
@@ -429,4 +460,8 @@ impl ScalarUDFImpl for floor_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         floor_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "floor_tinyint")
+    }
 }
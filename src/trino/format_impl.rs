@@ -145,12 +145,15 @@ fn format_varchar_1_2_3_4_5_simplify(
 #[derive(Debug)]
 pub(super) struct format_varchar_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl format_varchar_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["varchar", "$1"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -167,6 +170,10 @@ impl ScalarUDFImpl for format_varchar_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("format", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         format_varchar_1_return_type(arg_types)
     }
@@ -178,17 +185,27 @@ impl ScalarUDFImpl for format_varchar_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         format_varchar_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "format_varchar_1")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct format_varchar_1_2Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl format_varchar_1_2Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "$1", "$2"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -205,6 +222,10 @@ impl ScalarUDFImpl for format_varchar_1_2Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("format", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         format_varchar_1_2_return_type(arg_types)
     }
@@ -216,17 +237,27 @@ impl ScalarUDFImpl for format_varchar_1_2Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         format_varchar_1_2_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "format_varchar_1_2")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct format_varchar_1_2_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl format_varchar_1_2_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "$1", "$2", "$3"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -243,6 +274,10 @@ impl ScalarUDFImpl for format_varchar_1_2_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("format", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         format_varchar_1_2_3_return_type(arg_types)
     }
@@ -254,17 +289,27 @@ impl ScalarUDFImpl for format_varchar_1_2_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         format_varchar_1_2_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "format_varchar_1_2_3")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct format_varchar_1_2_3_4Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl format_varchar_1_2_3_4Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(5, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "$1", "$2", "$3", "$4"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -281,6 +326,10 @@ impl ScalarUDFImpl for format_varchar_1_2_3_4Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("format", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         format_varchar_1_2_3_4_return_type(arg_types)
     }
@@ -292,17 +341,27 @@ impl ScalarUDFImpl for format_varchar_1_2_3_4Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         format_varchar_1_2_3_4_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "format_varchar_1_2_3_4")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct format_varchar_1_2_3_4_5Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl format_varchar_1_2_3_4_5Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(6, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "$1", "$2", "$3", "$4", "$5"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -319,6 +378,10 @@ impl ScalarUDFImpl for format_varchar_1_2_3_4_5Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("format", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         format_varchar_1_2_3_4_5_return_type(arg_types)
     }
@@ -330,4 +393,8 @@ impl ScalarUDFImpl for format_varchar_1_2_3_4_5Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         format_varchar_1_2_3_4_5_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "format_varchar_1_2_3_4_5")
+    }
 }
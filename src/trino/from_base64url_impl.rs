@@ -79,9 +79,9 @@ pub(super) struct from_base64url_varbinaryFunc {
 }
 
 impl from_base64url_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -109,6 +109,10 @@ impl ScalarUDFImpl for from_base64url_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_base64url_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_base64url_varbinary")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +121,9 @@ pub(super) struct from_base64url_varcharFunc {
 }
 
 impl from_base64url_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +151,8 @@ impl ScalarUDFImpl for from_base64url_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_base64url_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_base64url_varchar")
+    }
 }
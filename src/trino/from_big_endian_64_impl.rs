@@ -56,9 +56,9 @@ pub(super) struct from_big_endian_64_varbinaryFunc {
 }
 
 impl from_big_endian_64_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -86,4 +86,8 @@ impl ScalarUDFImpl for from_big_endian_64_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_big_endian_64_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_big_endian_64_varbinary")
+    }
 }
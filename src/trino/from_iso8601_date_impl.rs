@@ -18,25 +18,18 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn from_iso8601_date_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::map_parse_iso8601_date;
+
+fn from_iso8601_date_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_parse_iso8601_date(&args[0])
 }
 
-fn from_iso8601_date_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn from_iso8601_date_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_iso8601_date_varchar", arg_types)
 }
 
 fn from_iso8601_date_varchar_simplify(
@@ -56,9 +49,9 @@ pub(super) struct from_iso8601_date_varcharFunc {
 }
 
 impl from_iso8601_date_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -86,4 +79,8 @@ impl ScalarUDFImpl for from_iso8601_date_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_iso8601_date_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_iso8601_date_varchar")
+    }
 }
@@ -17,25 +17,41 @@
 
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{internal_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
-use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
 use std::any::Any;
 
-fn from_iso8601_timestamp_nanos_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::{iso8601_result_zone, map_parse_iso8601_timestamp};
+
+fn from_iso8601_timestamp_nanos_varchar_invoke_with_args(
+    args: ScalarFunctionArgs,
+) -> Result<ColumnarValue> {
+    map_parse_iso8601_timestamp(&args.args[0], args.return_type)
+}
+
+fn from_iso8601_timestamp_nanos_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_iso8601_timestamp_nanos_varchar", arg_types)
 }
 
-fn from_iso8601_timestamp_nanos_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn from_iso8601_timestamp_nanos_varchar_return_type_from_args(
+    args: ReturnTypeArgs,
+) -> Result<ReturnInfo> {
+    // In the offset of a constant text, and else in UTC or the time zone of the session
+    let DataType::Timestamp(unit, _) =
+        from_iso8601_timestamp_nanos_varchar_return_type(args.arg_types)?
+    else {
+        return internal_err!(
+            "from_iso8601_timestamp_nanos must return a timestamp(9) with time zone"
+        );
+    };
+    let zone = iso8601_result_zone(args.scalar_arguments.first().copied().flatten());
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
@@ -56,9 +72,9 @@ pub(super) struct from_iso8601_timestamp_nanos_varcharFunc {
 }
 
 impl from_iso8601_timestamp_nanos_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -79,11 +95,28 @@ impl ScalarUDFImpl for from_iso8601_timestamp_nanos_varcharFunc {
         from_iso8601_timestamp_nanos_varchar_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        from_iso8601_timestamp_nanos_varchar_invoke(args)
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        from_iso8601_timestamp_nanos_varchar_return_type_from_args(args)
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion::logical_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        from_iso8601_timestamp_nanos_varchar_invoke_with_args(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_iso8601_timestamp_nanos_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "from_iso8601_timestamp_nanos_varchar",
+        )
+    }
 }
@@ -16,84 +16,92 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::array::{AsArray, TimestampMillisecondArray};
-use arrow::datatypes::{DataType, Int64Type, TimeUnit};
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use arrow::datatypes::DataType;
+use datafusion::common::{internal_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
-use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarUDFImpl, Signature, Volatility,
+};
 use std::any::Any;
-use std::sync::Arc;
 
-use crate::utils::apply_unary_kernel;
+use crate::utils_datetime::{
+    map_timestamp_zone, map_unixtime, map_unixtime_offset, offset_result_zone, result_zone,
+};
 
-/// Convert `arg[0]`, which should be a BIGINT with the number of seconds since the Unix epoch, 1970-01-01,
-/// to a TIMESTAMP(3), which is the number of milliseconds.
-// TODO?: change the input from BIGINT to DOUBLE, which it is in Trino.
-// TODO?: make sure there is a timezone in the output, which it is in Trino.
-fn from_unixtime_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let unixtime = &args[0];
-    assert!(unixtime.data_type() == DataType::Int64);
-    apply_unary_kernel(unixtime, move |arr| {
-        let milli_factor = arrow::array::Int64Array::new_scalar(1000);
-        let milliseconds = arrow::compute::kernels::numeric::mul(&arr, &milli_factor)?;
-        let timestamp: TimestampMillisecondArray =
-            milliseconds.as_primitive::<Int64Type>().reinterpret_cast();
-        Ok(Arc::new(timestamp))
-    })
+/// Convert `arg[0]`, the number of seconds since the Unix epoch, 1970-01-01, to a
+/// TIMESTAMP(3) WITH TIME ZONE, rounded to the millisecond: in UTC, which the session converts to
+/// its time zone.
+fn from_unixtime_double_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_unixtime(&args[0])
 }
 
-fn from_unixtime_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Ok(DataType::Timestamp(TimeUnit::Millisecond, None))
+fn from_unixtime_double_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_unixtime_double", arg_types)
 }
 
-fn from_unixtime_bigint_simplify(
+fn from_unixtime_double_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn from_unixtime_bigint_bigint_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn from_unixtime_double_bigint_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_unixtime_offset(&args[0], &args[1], &args[2])
+}
+
+fn from_unixtime_double_bigint_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_unixtime_double_bigint_bigint", arg_types)
 }
 
-fn from_unixtime_bigint_bigint_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn from_unixtime_double_bigint_bigint_return_type_from_args(
+    args: ReturnTypeArgs,
+) -> Result<ReturnInfo> {
+    // At the offset of constant hours and minutes
+    let DataType::Timestamp(unit, _) =
+        from_unixtime_double_bigint_bigint_return_type(args.arg_types)?
+    else {
+        return internal_err!("from_unixtime must return a timestamp(3) with time zone");
+    };
+    let zone = offset_result_zone(
+        args.scalar_arguments.get(1).copied().flatten(),
+        args.scalar_arguments.get(2).copied().flatten(),
+    );
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
-fn from_unixtime_bigint_bigint_bigint_simplify(
+fn from_unixtime_double_bigint_bigint_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn from_unixtime_bigint_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn from_unixtime_double_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_timestamp_zone(&map_unixtime(&args[0])?, &args[1], true)
 }
 
-fn from_unixtime_bigint_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn from_unixtime_double_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_unixtime_double_varchar", arg_types)
+}
+
+fn from_unixtime_double_varchar_return_type_from_args(args: ReturnTypeArgs) -> Result<ReturnInfo> {
+    // In the time zone of a constant zone
+    let DataType::Timestamp(unit, _) = from_unixtime_double_varchar_return_type(args.arg_types)?
+    else {
+        return internal_err!("from_unixtime must return a timestamp(3) with time zone");
+    };
+    let zone = result_zone(args.scalar_arguments.get(1).copied().flatten());
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
-fn from_unixtime_bigint_varchar_simplify(
+fn from_unixtime_double_varchar_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
@@ -105,19 +113,19 @@ fn from_unixtime_bigint_varchar_simplify(
 // when template is regenerated!
 
 #[derive(Debug)]
-pub(super) struct from_unixtime_bigintFunc {
+pub(super) struct from_unixtime_doubleFunc {
     signature: Signature,
 }
 
-impl from_unixtime_bigintFunc {
-    pub fn new() -> Self {
+impl from_unixtime_doubleFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
 
-impl ScalarUDFImpl for from_unixtime_bigintFunc {
+impl ScalarUDFImpl for from_unixtime_doubleFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -130,32 +138,39 @@ impl ScalarUDFImpl for from_unixtime_bigintFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        from_unixtime_bigint_return_type(arg_types)
+        from_unixtime_double_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        from_unixtime_bigint_invoke(args)
+        from_unixtime_double_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        from_unixtime_bigint_simplify(args, info)
+        from_unixtime_double_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_unixtime_double")
     }
 }
 
 #[derive(Debug)]
-pub(super) struct from_unixtime_bigint_bigint_bigintFunc {
+pub(super) struct from_unixtime_double_bigint_bigintFunc {
     signature: Signature,
 }
 
-impl from_unixtime_bigint_bigint_bigintFunc {
-    pub fn new() -> Self {
+impl from_unixtime_double_bigint_bigintFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
 
-impl ScalarUDFImpl for from_unixtime_bigint_bigint_bigintFunc {
+impl ScalarUDFImpl for from_unixtime_double_bigint_bigintFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -168,32 +183,46 @@ impl ScalarUDFImpl for from_unixtime_bigint_bigint_bigintFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        from_unixtime_bigint_bigint_bigint_return_type(arg_types)
+        from_unixtime_double_bigint_bigint_return_type(arg_types)
+    }
+
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        from_unixtime_double_bigint_bigint_return_type_from_args(args)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        from_unixtime_bigint_bigint_bigint_invoke(args)
+        from_unixtime_double_bigint_bigint_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        from_unixtime_bigint_bigint_bigint_simplify(args, info)
+        from_unixtime_double_bigint_bigint_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "from_unixtime_double_bigint_bigint",
+        )
     }
 }
 
 #[derive(Debug)]
-pub(super) struct from_unixtime_bigint_varcharFunc {
+pub(super) struct from_unixtime_double_varcharFunc {
     signature: Signature,
 }
 
-impl from_unixtime_bigint_varcharFunc {
-    pub fn new() -> Self {
+impl from_unixtime_double_varcharFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64, DataType::Utf8], volatility),
         }
     }
 }
 
-impl ScalarUDFImpl for from_unixtime_bigint_varcharFunc {
+impl ScalarUDFImpl for from_unixtime_double_varcharFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -206,14 +235,25 @@ impl ScalarUDFImpl for from_unixtime_bigint_varcharFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        from_unixtime_bigint_varchar_return_type(arg_types)
+        from_unixtime_double_varchar_return_type(arg_types)
+    }
+
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        from_unixtime_double_varchar_return_type_from_args(args)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        from_unixtime_bigint_varchar_invoke(args)
+        from_unixtime_double_varchar_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        from_unixtime_bigint_varchar_simplify(args, info)
+        from_unixtime_double_varchar_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_unixtime_double_varchar")
     }
 }
@@ -16,27 +16,27 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use arrow::array::{Array, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Decimal128Type, Int64Type};
+use arrow::error::ArrowError;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
-fn from_unixtime_nanos_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils::apply_unary_kernel;
+
+/// Convert `arg[0]`, the number of nanoseconds since the Unix epoch, to a
+/// TIMESTAMP(9) WITH TIME ZONE: in UTC, which the session converts to its time zone.
+fn from_unixtime_nanos_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let return_type = from_unixtime_nanos_bigint_return_type(&[DataType::Int64])?;
+    args[0].cast_to(&return_type, None)
 }
 
-fn from_unixtime_nanos_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn from_unixtime_nanos_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_unixtime_nanos_bigint", arg_types)
 }
 
 fn from_unixtime_nanos_bigint_simplify(
@@ -46,20 +46,36 @@ fn from_unixtime_nanos_bigint_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn from_unixtime_nanos_decimal_p_s_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+/// As `from_unixtime_nanos_bigint_invoke`, with the decimal number of nanoseconds rounded half
+/// away from zero.
+fn from_unixtime_nanos_decimal_p_s_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let arg_type = args[0].data_type();
+    let DataType::Decimal128(_, scale) = arg_type else {
+        return exec_err!("Expected a decimal, got {arg_type}");
+    };
+    let return_type = from_unixtime_nanos_decimal_p_s_return_type(&[arg_type])?;
+    apply_unary_kernel(&args[0], move |arr| {
+        let unit = 10_i128.pow(u32::from(scale.unsigned_abs()));
+        let nanos = arr
+            .as_primitive::<Decimal128Type>()
+            .try_unary::<_, Int64Type, _>(|value| {
+                let nanos = match scale >= 0 {
+                    true => {
+                        let half = unit / 2 * value.signum();
+                        value.checked_add(half).map(|value| value / unit)
+                    }
+                    false => value.checked_mul(unit),
+                };
+                nanos
+                    .and_then(|nanos| i64::try_from(nanos).ok())
+                    .ok_or_else(|| ArrowError::ComputeError("Timestamp out of range".to_string()))
+            })?;
+        cast(&(Arc::new(nanos) as Arc<dyn Array>), &return_type)
+    })
 }
 
-fn from_unixtime_nanos_decimal_p_s_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn from_unixtime_nanos_decimal_p_s_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("from_unixtime_nanos_decimal_p_s", arg_types)
 }
 
 fn from_unixtime_nanos_decimal_p_s_simplify(
@@ -79,9 +95,9 @@ pub(super) struct from_unixtime_nanos_bigintFunc {
 }
 
 impl from_unixtime_nanos_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -109,17 +125,24 @@ impl ScalarUDFImpl for from_unixtime_nanos_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_unixtime_nanos_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_unixtime_nanos_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct from_unixtime_nanos_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl from_unixtime_nanos_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +159,10 @@ impl ScalarUDFImpl for from_unixtime_nanos_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("from_unixtime_nanos", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         from_unixtime_nanos_decimal_p_s_return_type(arg_types)
     }
@@ -147,4 +174,11 @@ impl ScalarUDFImpl for from_unixtime_nanos_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_unixtime_nanos_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "from_unixtime_nanos_decimal_p_s",
+        )
+    }
 }
@@ -102,9 +102,9 @@ pub(super) struct from_utf8_varbinaryFunc {
 }
 
 impl from_utf8_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -132,6 +132,10 @@ impl ScalarUDFImpl for from_utf8_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_utf8_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_utf8_varbinary")
+    }
 }
 
 #[derive(Debug)]
@@ -140,9 +144,9 @@ pub(super) struct from_utf8_varbinary_bigintFunc {
 }
 
 impl from_utf8_varbinary_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Int64], volatility),
         }
     }
 }
@@ -170,6 +174,10 @@ impl ScalarUDFImpl for from_utf8_varbinary_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_utf8_varbinary_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_utf8_varbinary_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -178,9 +186,9 @@ pub(super) struct from_utf8_varbinary_varcharFunc {
 }
 
 impl from_utf8_varbinary_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Utf8], volatility),
         }
     }
 }
@@ -208,4 +216,8 @@ impl ScalarUDFImpl for from_utf8_varbinary_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         from_utf8_varbinary_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "from_utf8_varbinary_varchar")
+    }
 }
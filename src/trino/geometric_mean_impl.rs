@@ -0,0 +1,345 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(non_camel_case_types)]
+use arrow::array::ArrayRef;
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::cast::{as_float64_array, as_int64_array};
+use datafusion::common::{Result, ScalarValue};
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use std::any::Any;
+
+/// The geometric mean, as the exponential of the mean of the logarithms, which can be
+/// retracted from sliding window frames. Its result is a `real` if `real`, and a `double`
+/// otherwise.
+///
+/// Only the logarithms of the positive finite values are summed: zeros, infinities and negative
+/// or NaN values are counted instead, as their logarithms (`-inf`, `inf` and NaN) could not be
+/// subtracted from the sum once added.
+#[derive(Debug)]
+struct GeometricMeanAccumulator {
+    real: bool,
+    logs: Logs,
+}
+
+/// The logarithms of the values of a batch or of a frame, NULLs being ignored.
+#[derive(Debug, Default, Clone, Copy)]
+struct Logs {
+    /// The sum of the logarithms of the positive finite values
+    sum: f64,
+    /// The number of values
+    count: i64,
+    zeros: i64,
+    infinities: i64,
+    /// The number of negative and NaN values
+    invalid: i64,
+}
+
+impl Logs {
+    fn of(values: &ArrayRef) -> Result<Self> {
+        let values = cast(values, &DataType::Float64)?;
+        let mut logs = Logs::default();
+        for value in as_float64_array(&values)?.iter().flatten() {
+            logs.count += 1;
+            match value {
+                _ if value.is_nan() || value < 0.0 => logs.invalid += 1,
+                0.0 => logs.zeros += 1,
+                f64::INFINITY => logs.infinities += 1,
+                _ => logs.sum += value.ln(),
+            }
+        }
+        Ok(logs)
+    }
+
+    fn add(&mut self, other: Logs, sign: i64) {
+        self.sum += sign as f64 * other.sum;
+        self.count += sign * other.count;
+        self.zeros += sign * other.zeros;
+        self.infinities += sign * other.infinities;
+        self.invalid += sign * other.invalid;
+    }
+
+    /// The geometric mean of the values, `None` if there are none.
+    fn geometric_mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(match (self.zeros > 0, self.infinities > 0) {
+            _ if self.invalid > 0 => f64::NAN,
+            (true, true) => f64::NAN,
+            (true, false) => 0.0,
+            (false, true) => f64::INFINITY,
+            (false, false) => (self.sum / self.count as f64).exp(),
+        })
+    }
+}
+
+impl GeometricMeanAccumulator {
+    fn new(real: bool) -> Self {
+        Self {
+            real,
+            logs: Logs::default(),
+        }
+    }
+}
+
+impl Accumulator for GeometricMeanAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.logs.add(Logs::of(&values[0])?, 1);
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mean = self.logs.geometric_mean();
+        Ok(match self.real {
+            true => ScalarValue::Float32(mean.map(|mean| mean as f32)),
+            false => ScalarValue::Float64(mean),
+        })
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let Logs {
+            sum,
+            count,
+            zeros,
+            infinities,
+            invalid,
+        } = self.logs;
+        Ok(vec![
+            ScalarValue::Float64(Some(sum)),
+            ScalarValue::Int64(Some(count)),
+            ScalarValue::Int64(Some(zeros)),
+            ScalarValue::Int64(Some(infinities)),
+            ScalarValue::Int64(Some(invalid)),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let count = |i: usize| -> Result<i64> {
+            Ok(as_int64_array(&states[i])?.iter().flatten().sum::<i64>())
+        };
+        self.logs.add(
+            Logs {
+                sum: as_float64_array(&states[0])?.iter().flatten().sum::<f64>(),
+                count: count(1)?,
+                zeros: count(2)?,
+                infinities: count(3)?,
+                invalid: count(4)?,
+            },
+            1,
+        );
+        Ok(())
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.logs.add(Logs::of(&values[0])?, -1);
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+}
+
+fn geometric_mean__state_fields(args: StateFieldsArgs) -> Vec<Field> {
+    let mut fields = vec![Field::new(
+        format_state_name(args.name, "sum_of_logs"),
+        DataType::Float64,
+        true,
+    )];
+    for name in ["count", "zeros", "infinities", "invalid"] {
+        fields.push(Field::new(
+            format_state_name(args.name, name),
+            DataType::Int64,
+            true,
+        ));
+    }
+    fields
+}
+
+fn geometric_mean_bigint_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Ok(Box::new(GeometricMeanAccumulator::new(false)))
+}
+
+fn geometric_mean_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("geometric_mean_bigint", arg_types)
+}
+
+fn geometric_mean_bigint_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(geometric_mean__state_fields(args))
+}
+
+fn geometric_mean_double_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Ok(Box::new(GeometricMeanAccumulator::new(false)))
+}
+
+fn geometric_mean_double_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("geometric_mean_double", arg_types)
+}
+
+fn geometric_mean_double_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(geometric_mean__state_fields(args))
+}
+
+fn geometric_mean_real_accumulator(_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+    Ok(Box::new(GeometricMeanAccumulator::new(true)))
+}
+
+fn geometric_mean_real_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("geometric_mean_real", arg_types)
+}
+
+fn geometric_mean_real_state_fields(args: StateFieldsArgs) -> Result<Vec<Field>> {
+    Ok(geometric_mean__state_fields(args))
+}
+
+// ========== Generated template below this line ==========
+// Do *NOT* edit below this line: all changes will be overwritten
+// when template is regenerated!
+
+#[derive(Debug)]
+pub(super) struct geometric_mean_bigintFunc {
+    signature: Signature,
+}
+
+impl geometric_mean_bigintFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Int64], volatility),
+        }
+    }
+}
+
+impl AggregateUDFImpl for geometric_mean_bigintFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "geometric_mean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        geometric_mean_bigint_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        geometric_mean_bigint_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        geometric_mean_bigint_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "geometric_mean_bigint")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct geometric_mean_doubleFunc {
+    signature: Signature,
+}
+
+impl geometric_mean_doubleFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Float64], volatility),
+        }
+    }
+}
+
+impl AggregateUDFImpl for geometric_mean_doubleFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "geometric_mean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        geometric_mean_double_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        geometric_mean_double_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        geometric_mean_double_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "geometric_mean_double")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct geometric_mean_realFunc {
+    signature: Signature,
+}
+
+impl geometric_mean_realFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Float32], volatility),
+        }
+    }
+}
+
+impl AggregateUDFImpl for geometric_mean_realFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "geometric_mean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        geometric_mean_real_return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        geometric_mean_real_accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        geometric_mean_real_state_fields(args)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "geometric_mean_real")
+    }
+}
@@ -56,9 +56,9 @@ pub(super) struct geometry_from_hadoop_shape_varbinaryFunc {
 }
 
 impl geometry_from_hadoop_shape_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for geometry_from_hadoop_shape_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         geometry_from_hadoop_shape_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "geometry_from_hadoop_shape_varbinary",
+        )
+    }
 }
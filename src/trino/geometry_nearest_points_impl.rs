@@ -60,9 +60,9 @@ pub(super) struct geometry_nearest_points_geometry_geometryFunc {
 }
 
 impl geometry_nearest_points_geometry_geometryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -90,4 +90,11 @@ impl ScalarUDFImpl for geometry_nearest_points_geometry_geometryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         geometry_nearest_points_geometry_geometry_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "geometry_nearest_points_geometry_geometry",
+        )
+    }
 }
@@ -56,9 +56,9 @@ pub(super) struct geometry_to_bing_tiles_geometry_bigintFunc {
 }
 
 impl geometry_to_bing_tiles_geometry_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Int64], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for geometry_to_bing_tiles_geometry_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         geometry_to_bing_tiles_geometry_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "geometry_to_bing_tiles_geometry_bigint",
+        )
+    }
 }
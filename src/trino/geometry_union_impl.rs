@@ -53,12 +53,15 @@ fn geometry_union_array_geometry_simplify(
 #[derive(Debug)]
 pub(super) struct geometry_union_array_geometryFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl geometry_union_array_geometryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["array<geometry>"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +78,10 @@ impl ScalarUDFImpl for geometry_union_array_geometryFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("geometry_union", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         geometry_union_array_geometry_return_type(arg_types)
     }
@@ -86,4 +93,8 @@ impl ScalarUDFImpl for geometry_union_array_geometryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         geometry_union_array_geometry_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "geometry_union_array_geometry")
+    }
 }
@@ -60,9 +60,17 @@ pub(super) struct great_circle_distance_double_double_double_doubleFunc {
 }
 
 impl great_circle_distance_double_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -90,4 +98,11 @@ impl ScalarUDFImpl for great_circle_distance_double_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         great_circle_distance_double_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "great_circle_distance_double_double_double_double",
+        )
+    }
 }
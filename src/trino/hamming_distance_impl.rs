@@ -17,7 +17,7 @@
 
 #![allow(non_camel_case_types)]
 use crate::utils::make_scalar_function;
-use arrow::array::{Array, ArrayRef, Int32Array, Int64Array, OffsetSizeTrait};
+use arrow::array::{ArrayRef, Int64Array, OffsetSizeTrait};
 use arrow::datatypes::DataType;
 use datafusion::common::cast::as_generic_string_array;
 use datafusion::common::{exec_err, Result};
@@ -37,39 +37,15 @@ fn hamming<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let str1_array = as_generic_string_array::<T>(&args[0])?;
     let str2_array = as_generic_string_array::<T>(&args[1])?;
 
-    match args[0].data_type() {
-        DataType::Utf8View | DataType::Utf8 => {
-            let result = str1_array
-                .iter()
-                .zip(str2_array.iter())
-                .map(|(string1, string2)| match (string1, string2) {
-                    (Some(string1), Some(string2)) => {
-                        Some(hamming_distance(string1, string2) as i32)
-                    }
-                    _ => None,
-                })
-                .collect::<Int32Array>();
-            Ok(Arc::new(result) as ArrayRef)
-        }
-        DataType::LargeUtf8 => {
-            let result = str1_array
-                .iter()
-                .zip(str2_array.iter())
-                .map(|(string1, string2)| match (string1, string2) {
-                    (Some(string1), Some(string2)) => {
-                        Some(hamming_distance(string1, string2) as i64)
-                    }
-                    _ => None,
-                })
-                .collect::<Int64Array>();
-            Ok(Arc::new(result) as ArrayRef)
-        }
-        other => {
-            exec_err!(
-                "hamming was called with {other} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
-            )
-        }
-    }
+    let result = str1_array
+        .iter()
+        .zip(str2_array.iter())
+        .map(|(string1, string2)| match (string1, string2) {
+            (Some(string1), Some(string2)) => Some(hamming_distance(string1, string2) as i64),
+            _ => None,
+        })
+        .collect::<Int64Array>();
+    Ok(Arc::new(result) as ArrayRef)
 }
 
 fn hamming_distance(s1: &str, s2: &str) -> usize {
@@ -89,8 +65,9 @@ fn hamming_distance_varchar_varchar_invoke(args: &[ColumnarValue]) -> Result<Col
 
 fn hamming_distance_varchar_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
     match &arg_types[0] {
-        DataType::Utf8View | DataType::Utf8 => Ok(DataType::Int32),
-        DataType::LargeUtf8 => Ok(DataType::Int64),
+        DataType::Utf8View | DataType::Utf8 | DataType::LargeUtf8 => {
+            super::types::declared_return_type("hamming_distance_varchar_varchar", arg_types)
+        }
         other => exec_err!("Unsupported data type {other:?} for function hamming"),
     }
 }
@@ -112,9 +89,9 @@ pub(super) struct hamming_distance_varchar_varcharFunc {
 }
 
 impl hamming_distance_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -142,4 +119,11 @@ impl ScalarUDFImpl for hamming_distance_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         hamming_distance_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "hamming_distance_varchar_varchar",
+        )
+    }
 }
@@ -56,9 +56,9 @@ pub(super) struct hmac_sha512_varbinary_varbinaryFunc {
 }
 
 impl hmac_sha512_varbinary_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for hmac_sha512_varbinary_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         hmac_sha512_varbinary_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "hmac_sha512_varbinary_varbinary",
+        )
+    }
 }
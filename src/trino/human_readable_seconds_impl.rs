@@ -16,27 +16,37 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::datatypes::DataType;
-use datafusion::common::{internal_err, Result, ScalarValue};
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::{DataType, Float64Type};
+use arrow::error::{ArrowError, Result as ArrowResult};
+use datafusion::common::Result;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use humantime::format_duration;
 use std::any::Any;
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::utils::apply_unary_kernel;
+
 fn human_readable_seconds_double_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    match &args[0] {
-        ColumnarValue::Scalar(ScalarValue::Int64(Some(v))) => {
-            let duration = Duration::from_secs(*v as u64);
-            let formatted_duration = format_duration(duration).to_string();
-            Ok(ColumnarValue::Scalar(ScalarValue::Utf8(Some(
-                formatted_duration,
-            ))))
-        }
-        _ => {
-            internal_err!("Invalid argument types to human_readable_seconds function")
-        }
-    }
+    apply_unary_kernel(&args[0], |array: &ArrayRef| {
+        let res = array
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|seconds| seconds.map(human_readable_seconds).transpose())
+            .collect::<ArrowResult<StringArray>>()?;
+        Ok(Arc::new(res) as ArrayRef)
+    })
+}
+
+fn human_readable_seconds(seconds: f64) -> ArrowResult<String> {
+    let duration = Duration::try_from_secs_f64(seconds.round()).map_err(|_| {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid argument found: {seconds} for human_readable_seconds"
+        ))
+    })?;
+    Ok(format_duration(duration).to_string())
 }
 
 fn human_readable_seconds_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
@@ -60,9 +70,9 @@ pub(super) struct human_readable_seconds_doubleFunc {
 }
 
 impl human_readable_seconds_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -90,4 +100,8 @@ impl ScalarUDFImpl for human_readable_seconds_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         human_readable_seconds_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "human_readable_seconds_double")
+    }
 }
@@ -52,12 +52,18 @@ fn if_boolean_1_1_simplify(
 #[derive(Debug)]
 pub(super) struct if_boolean_1_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl if_boolean_1_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["boolean", "$1", "$1"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -74,6 +80,10 @@ impl ScalarUDFImpl for if_boolean_1_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("if", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         if_boolean_1_1_return_type(arg_types)
     }
@@ -85,4 +95,8 @@ impl ScalarUDFImpl for if_boolean_1_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         if_boolean_1_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "if_boolean_1_1")
+    }
 }
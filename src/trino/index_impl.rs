@@ -56,9 +56,9 @@ pub(super) struct index_varchar_varcharFunc {
 }
 
 impl index_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -86,4 +86,8 @@ impl ScalarUDFImpl for index_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         index_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "index_varchar_varchar")
+    }
 }
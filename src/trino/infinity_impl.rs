@@ -23,7 +23,7 @@ use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn infinity_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
+fn infinity_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
         "Not implemented {}:{}",
         file!(),
@@ -53,9 +53,9 @@ pub(super) struct infinityFunc {
 }
 
 impl infinityFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -76,11 +76,15 @@ impl ScalarUDFImpl for infinityFunc {
         infinity_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        infinity_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        infinity_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         infinity_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "infinity")
+    }
 }
@@ -60,9 +60,9 @@ pub(super) struct intersection_cardinality_setdigest_setdigestFunc {
 }
 
 impl intersection_cardinality_setdigest_setdigestFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -90,4 +90,11 @@ impl ScalarUDFImpl for intersection_cardinality_setdigest_setdigestFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         intersection_cardinality_setdigest_setdigest_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "intersection_cardinality_setdigest_setdigest",
+        )
+    }
 }
@@ -56,9 +56,12 @@ pub(super) struct inverse_beta_cdf_double_double_doubleFunc {
 }
 
 impl inverse_beta_cdf_double_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Float64, DataType::Float64],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,11 @@ impl ScalarUDFImpl for inverse_beta_cdf_double_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         inverse_beta_cdf_double_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "inverse_beta_cdf_double_double_double",
+        )
+    }
 }
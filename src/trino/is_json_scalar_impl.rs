@@ -16,27 +16,29 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::BooleanArray;
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn is_json_scalar_json_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+fn is_json_scalar(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_rows::<BooleanArray, _, _>(&args[0], &[], |text, _| match Json::parse(text) {
+        Ok(json) => Ok(Some(json.is_scalar())),
+        Err(_) => exec_err!("Invalid JSON value: {text}"),
+    })
+}
+
+fn is_json_scalar_json_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    is_json_scalar(args)
 }
 
 fn is_json_scalar_json_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn is_json_scalar_json_simplify(
@@ -46,20 +48,12 @@ fn is_json_scalar_json_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn is_json_scalar_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn is_json_scalar_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    is_json_scalar(args)
 }
 
 fn is_json_scalar_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn is_json_scalar_varchar_simplify(
@@ -79,9 +73,9 @@ pub(super) struct is_json_scalar_jsonFunc {
 }
 
 impl is_json_scalar_jsonFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -109,6 +103,10 @@ impl ScalarUDFImpl for is_json_scalar_jsonFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         is_json_scalar_json_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "is_json_scalar_json")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +115,9 @@ pub(super) struct is_json_scalar_varcharFunc {
 }
 
 impl is_json_scalar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +145,8 @@ impl ScalarUDFImpl for is_json_scalar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         is_json_scalar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "is_json_scalar_varchar")
+    }
 }
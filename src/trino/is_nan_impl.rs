@@ -73,9 +73,9 @@ pub(super) struct is_nan_doubleFunc {
 }
 
 impl is_nan_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -103,6 +103,10 @@ impl ScalarUDFImpl for is_nan_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         is_nan_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "is_nan_double")
+    }
 }
 
 #[derive(Debug)]
@@ -111,9 +115,9 @@ pub(super) struct is_nan_realFunc {
 }
 
 impl is_nan_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -141,4 +145,8 @@ impl ScalarUDFImpl for is_nan_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         is_nan_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "is_nan_real")
+    }
 }
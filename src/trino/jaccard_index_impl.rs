@@ -56,9 +56,9 @@ pub(super) struct jaccard_index_setdigest_setdigestFunc {
 }
 
 impl jaccard_index_setdigest_setdigestFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for jaccard_index_setdigest_setdigestFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         jaccard_index_setdigest_setdigest_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "jaccard_index_setdigest_setdigest",
+        )
+    }
 }
@@ -16,27 +16,78 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::BooleanArray;
 use arrow::datatypes::DataType;
+use datafusion::common::cast::{
+    as_boolean_array, as_float64_array, as_int64_array, as_string_array,
+};
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_array_contains_json_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+/// Whether the JSON array contains an element for which `matches` holds, with the value of the
+/// row, NULL for other values and invalid JSON. Nested arrays are not searched.
+fn json_array_contains<F>(args: &[ColumnarValue], matches: F) -> Result<ColumnarValue>
+where
+    F: Fn(&Json, usize) -> bool,
+{
+    let scalar_value = matches!(args[1], ColumnarValue::Scalar(_));
+    map_json_rows::<BooleanArray, _, _>(&args[0], &[&args[1]], |json, row| {
+        let Ok(Json::Array(elements)) = Json::parse(json) else {
+            return Ok(None);
+        };
+        let row = if scalar_value { 0 } else { row };
+        Ok(Some(elements.iter().any(|element| matches(element, row))))
+    })
+}
+
+/// Integers only match integral JSON numbers.
+fn contains_bigint(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let values = args[1].to_owned().into_array(1)?;
+    let values = as_int64_array(&values)?;
+    json_array_contains(args, |element, row| match element {
+        Json::Number(n) => n.as_i64() == Some(values.value(row)),
+        _ => false,
+    })
+}
+
+fn contains_double(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let values = args[1].to_owned().into_array(1)?;
+    let values = as_float64_array(&values)?;
+    json_array_contains(args, |element, row| match element {
+        Json::Number(n) => n.as_f64() == values.value(row),
+        _ => false,
+    })
+}
+
+fn contains_boolean(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let values = args[1].to_owned().into_array(1)?;
+    let values = as_boolean_array(&values)?;
+    json_array_contains(
+        args,
+        |element, row| matches!(element, Json::Bool(b) if *b == values.value(row)),
+    )
+}
+
+fn contains_varchar(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let values = args[1].to_owned().into_array(1)?;
+    let values = as_string_array(&values)?;
+    json_array_contains(
+        args,
+        |element, row| matches!(element, Json::String(s) if s == values.value(row)),
+    )
+}
+
+fn json_array_contains_json_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_bigint(args)
 }
 
 fn json_array_contains_json_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_json_bigint_simplify(
@@ -46,20 +97,12 @@ fn json_array_contains_json_bigint_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_json_boolean_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_json_boolean_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_boolean(args)
 }
 
 fn json_array_contains_json_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_json_boolean_simplify(
@@ -69,20 +112,12 @@ fn json_array_contains_json_boolean_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_json_double_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_json_double_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_double(args)
 }
 
 fn json_array_contains_json_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_json_double_simplify(
@@ -92,20 +127,12 @@ fn json_array_contains_json_double_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_json_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_json_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_varchar(args)
 }
 
 fn json_array_contains_json_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_json_varchar_simplify(
@@ -115,20 +142,12 @@ fn json_array_contains_json_varchar_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_varchar_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_varchar_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_bigint(args)
 }
 
 fn json_array_contains_varchar_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_varchar_bigint_simplify(
@@ -138,20 +157,12 @@ fn json_array_contains_varchar_bigint_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_varchar_boolean_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_varchar_boolean_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_boolean(args)
 }
 
 fn json_array_contains_varchar_boolean_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_varchar_boolean_simplify(
@@ -161,20 +172,12 @@ fn json_array_contains_varchar_boolean_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_varchar_double_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_varchar_double_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_double(args)
 }
 
 fn json_array_contains_varchar_double_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_varchar_double_simplify(
@@ -184,20 +187,12 @@ fn json_array_contains_varchar_double_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_contains_varchar_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_contains_varchar_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    contains_varchar(args)
 }
 
 fn json_array_contains_varchar_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Boolean)
 }
 
 fn json_array_contains_varchar_varchar_simplify(
@@ -217,9 +212,9 @@ pub(super) struct json_array_contains_json_bigintFunc {
 }
 
 impl json_array_contains_json_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -247,6 +242,13 @@ impl ScalarUDFImpl for json_array_contains_json_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_json_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_json_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -255,9 +257,9 @@ pub(super) struct json_array_contains_json_booleanFunc {
 }
 
 impl json_array_contains_json_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Boolean], volatility),
         }
     }
 }
@@ -285,6 +287,13 @@ impl ScalarUDFImpl for json_array_contains_json_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_json_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_json_boolean",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -293,9 +302,9 @@ pub(super) struct json_array_contains_json_doubleFunc {
 }
 
 impl json_array_contains_json_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Float64], volatility),
         }
     }
 }
@@ -323,6 +332,13 @@ impl ScalarUDFImpl for json_array_contains_json_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_json_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_json_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -331,9 +347,9 @@ pub(super) struct json_array_contains_json_varcharFunc {
 }
 
 impl json_array_contains_json_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -361,6 +377,13 @@ impl ScalarUDFImpl for json_array_contains_json_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_json_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_json_varchar",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -369,9 +392,9 @@ pub(super) struct json_array_contains_varchar_bigintFunc {
 }
 
 impl json_array_contains_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -399,6 +422,13 @@ impl ScalarUDFImpl for json_array_contains_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_varchar_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -407,9 +437,9 @@ pub(super) struct json_array_contains_varchar_booleanFunc {
 }
 
 impl json_array_contains_varchar_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Boolean], volatility),
         }
     }
 }
@@ -437,6 +467,13 @@ impl ScalarUDFImpl for json_array_contains_varchar_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_varchar_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_varchar_boolean",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -445,9 +482,9 @@ pub(super) struct json_array_contains_varchar_doubleFunc {
 }
 
 impl json_array_contains_varchar_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Float64], volatility),
         }
     }
 }
@@ -475,6 +512,13 @@ impl ScalarUDFImpl for json_array_contains_varchar_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_varchar_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_varchar_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -483,9 +527,9 @@ pub(super) struct json_array_contains_varchar_varcharFunc {
 }
 
 impl json_array_contains_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -513,4 +557,11 @@ impl ScalarUDFImpl for json_array_contains_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_contains_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_array_contains_varchar_varchar",
+        )
+    }
 }
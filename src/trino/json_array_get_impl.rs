@@ -16,27 +16,49 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::StringArray;
 use arrow::datatypes::DataType;
+use datafusion::common::cast::as_int64_array;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_array_get_json_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+/// The element of a JSON array at an index, counted from the end if negative. As in Trino, the
+/// arrays and objects are returned as JSON, but the scalars as their text, e.g. `a` for `"a"`.
+fn json_array_get(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let indices = args[1].to_owned().into_array(1)?;
+    let scalar_index = matches!(args[1], ColumnarValue::Scalar(_));
+    let indices = as_int64_array(&indices)?;
+    map_json_rows::<StringArray, _, _>(&args[0], &[&args[1]], |json, row| {
+        let index = indices.value(if scalar_index { 0 } else { row });
+        let Ok(Json::Array(elements)) = Json::parse(json) else {
+            return Ok(None);
+        };
+        let index = if index < 0 {
+            (elements.len() as i64).checked_add(index)
+        } else {
+            Some(index)
+        };
+        let element = index
+            .and_then(|i| usize::try_from(i).ok())
+            .and_then(|i| elements.get(i));
+        Ok(element.and_then(|element| match element {
+            Json::Array(_) | Json::Object(_) => Some(element.to_text()),
+            scalar => scalar.scalar_text(),
+        }))
+    })
+}
+
+fn json_array_get_json_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    json_array_get(args)
 }
 
 fn json_array_get_json_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Utf8)
 }
 
 fn json_array_get_json_bigint_simplify(
@@ -46,20 +68,12 @@ fn json_array_get_json_bigint_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_get_varchar_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_get_varchar_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    json_array_get(args)
 }
 
 fn json_array_get_varchar_bigint_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Utf8)
 }
 
 fn json_array_get_varchar_bigint_simplify(
@@ -79,9 +93,9 @@ pub(super) struct json_array_get_json_bigintFunc {
 }
 
 impl json_array_get_json_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -109,6 +123,10 @@ impl ScalarUDFImpl for json_array_get_json_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_get_json_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_array_get_json_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +135,9 @@ pub(super) struct json_array_get_varchar_bigintFunc {
 }
 
 impl json_array_get_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -147,4 +165,8 @@ impl ScalarUDFImpl for json_array_get_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_get_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_array_get_varchar_bigint")
+    }
 }
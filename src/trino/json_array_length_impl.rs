@@ -16,27 +16,30 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::Int64Array;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_array_length_json_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+/// The length of a JSON array, NULL for other values and invalid JSON.
+fn json_array_length(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_rows::<Int64Array, _, _>(&args[0], &[], |json, _| match Json::parse(json) {
+        Ok(Json::Array(elements)) => Ok(Some(elements.len() as i64)),
+        _ => Ok(None),
+    })
+}
+
+fn json_array_length_json_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    json_array_length(args)
 }
 
 fn json_array_length_json_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Int64)
 }
 
 fn json_array_length_json_simplify(
@@ -46,20 +49,12 @@ fn json_array_length_json_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_array_length_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_array_length_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    json_array_length(args)
 }
 
 fn json_array_length_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Int64)
 }
 
 fn json_array_length_varchar_simplify(
@@ -79,9 +74,9 @@ pub(super) struct json_array_length_jsonFunc {
 }
 
 impl json_array_length_jsonFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -109,6 +104,10 @@ impl ScalarUDFImpl for json_array_length_jsonFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_length_json_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_array_length_json")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +116,9 @@ pub(super) struct json_array_length_varcharFunc {
 }
 
 impl json_array_length_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +146,8 @@ impl ScalarUDFImpl for json_array_length_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_array_length_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_array_length_varchar")
+    }
 }
@@ -16,27 +16,28 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::StringArray;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_extract_scalar_json_jsonpath_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::jsonpath::JsonPath;
+use crate::utils_json::map_json_path;
+
+/// The text of the scalar at `path`, NULL for JSON nulls, arrays and objects.
+fn json_extract_scalar(json: &Json, path: &JsonPath) -> Option<String> {
+    path.extract(json).and_then(|value| value.scalar_text())
+}
+
+fn json_extract_scalar_json_jsonpath_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_path::<StringArray, _, _>(&args[0], &args[1], json_extract_scalar)
 }
 
 fn json_extract_scalar_json_jsonpath_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Utf8)
 }
 
 fn json_extract_scalar_json_jsonpath_simplify(
@@ -46,20 +47,12 @@ fn json_extract_scalar_json_jsonpath_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_extract_scalar_varchar_jsonpath_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_extract_scalar_varchar_jsonpath_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_path::<StringArray, _, _>(&args[0], &args[1], json_extract_scalar)
 }
 
 fn json_extract_scalar_varchar_jsonpath_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Utf8)
 }
 
 fn json_extract_scalar_varchar_jsonpath_simplify(
@@ -76,12 +69,15 @@ fn json_extract_scalar_varchar_jsonpath_simplify(
 #[derive(Debug)]
 pub(super) struct json_extract_scalar_json_jsonpathFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl json_extract_scalar_json_jsonpathFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["json", "jsonpath"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +94,10 @@ impl ScalarUDFImpl for json_extract_scalar_json_jsonpathFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("json_extract_scalar", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         json_extract_scalar_json_jsonpath_return_type(arg_types)
     }
@@ -109,17 +109,30 @@ impl ScalarUDFImpl for json_extract_scalar_json_jsonpathFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_extract_scalar_json_jsonpath_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_extract_scalar_json_jsonpath",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct json_extract_scalar_varchar_jsonpathFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl json_extract_scalar_varchar_jsonpathFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "jsonpath"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +149,10 @@ impl ScalarUDFImpl for json_extract_scalar_varchar_jsonpathFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("json_extract_scalar", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         json_extract_scalar_varchar_jsonpath_return_type(arg_types)
     }
@@ -147,4 +164,11 @@ impl ScalarUDFImpl for json_extract_scalar_varchar_jsonpathFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_extract_scalar_varchar_jsonpath_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "json_extract_scalar_varchar_jsonpath",
+        )
+    }
 }
@@ -16,27 +16,25 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::StringArray;
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_parse_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::utils_json::map_json_rows;
+
+fn json_parse_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_rows::<StringArray, _, _>(&args[0], &[], |text, _| match Json::parse(text) {
+        Ok(json) => Ok(Some(json.canonical().to_text())),
+        Err(_) => exec_err!("Cannot convert value to JSON: '{text}'"),
+    })
 }
 
 fn json_parse_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Utf8)
 }
 
 fn json_parse_varchar_simplify(
@@ -56,9 +54,9 @@ pub(super) struct json_parse_varcharFunc {
 }
 
 impl json_parse_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -86,4 +84,8 @@ impl ScalarUDFImpl for json_parse_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_parse_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_parse_varchar")
+    }
 }
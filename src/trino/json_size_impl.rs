@@ -16,27 +16,28 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::Int64Array;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn json_size_json_jsonpath_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::json::Json;
+use crate::jsonpath::JsonPath;
+use crate::utils_json::map_json_path;
+
+/// The size of the array or object at `path`, 0 for scalars.
+fn json_size(json: &Json, path: &JsonPath) -> Option<i64> {
+    path.extract(json).map(|value| value.size() as i64)
+}
+
+fn json_size_json_jsonpath_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_path::<Int64Array, _, _>(&args[0], &args[1], json_size)
 }
 
 fn json_size_json_jsonpath_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Int64)
 }
 
 fn json_size_json_jsonpath_simplify(
@@ -46,20 +47,12 @@ fn json_size_json_jsonpath_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn json_size_varchar_jsonpath_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn json_size_varchar_jsonpath_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_json_path::<Int64Array, _, _>(&args[0], &args[1], json_size)
 }
 
 fn json_size_varchar_jsonpath_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Int64)
 }
 
 fn json_size_varchar_jsonpath_simplify(
@@ -76,12 +69,15 @@ fn json_size_varchar_jsonpath_simplify(
 #[derive(Debug)]
 pub(super) struct json_size_json_jsonpathFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl json_size_json_jsonpathFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["json", "jsonpath"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -98,6 +94,10 @@ impl ScalarUDFImpl for json_size_json_jsonpathFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("json_size", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         json_size_json_jsonpath_return_type(arg_types)
     }
@@ -109,17 +109,27 @@ impl ScalarUDFImpl for json_size_json_jsonpathFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_size_json_jsonpath_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_size_json_jsonpath")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct json_size_varchar_jsonpathFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl json_size_varchar_jsonpathFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "jsonpath"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +146,10 @@ impl ScalarUDFImpl for json_size_varchar_jsonpathFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("json_size", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         json_size_varchar_jsonpath_return_type(arg_types)
     }
@@ -147,4 +161,8 @@ impl ScalarUDFImpl for json_size_varchar_jsonpathFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         json_size_varchar_jsonpath_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "json_size_varchar_jsonpath")
+    }
 }
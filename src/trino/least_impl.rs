@@ -24,10 +24,11 @@ use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
+use crate::utils::with_nulls_of;
+
 fn least_3_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     let args = ColumnarValue::values_to_arrays(args)?;
-    let result = args.into_iter().fold(Ok(None), |acc, cur| {
-        let acc = acc?;
+    let result = args.iter().cloned().try_fold(None, |acc, cur| {
         if let Some(acc) = acc {
             let comparison = lt(&acc, &cur)?;
             zip(&comparison, &acc, &cur).map(Some)
@@ -36,7 +37,7 @@ fn least_3_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         }
     })?;
     if let Some(result) = result {
-        Ok(ColumnarValue::Array(result))
+        Ok(ColumnarValue::Array(with_nulls_of(result, &args)?))
     } else {
         exec_err!("least expects at least one argument")
     }
@@ -57,12 +58,15 @@ fn least_3_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSim
 #[derive(Debug)]
 pub(super) struct least_3Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl least_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$3"], true)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +83,10 @@ impl ScalarUDFImpl for least_3Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("least", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         least_3_return_type(arg_types)
     }
@@ -90,4 +98,8 @@ impl ScalarUDFImpl for least_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         least_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "least_3")
+    }
 }
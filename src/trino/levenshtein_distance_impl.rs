@@ -56,9 +56,9 @@ pub(super) struct levenshtein_distance_varchar_varcharFunc {
 }
 
 impl levenshtein_distance_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for levenshtein_distance_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         levenshtein_distance_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "levenshtein_distance_varchar_varchar",
+        )
+    }
 }
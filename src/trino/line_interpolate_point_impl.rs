@@ -56,9 +56,9 @@ pub(super) struct line_interpolate_point_geometry_doubleFunc {
 }
 
 impl line_interpolate_point_geometry_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float64], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for line_interpolate_point_geometry_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         line_interpolate_point_geometry_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "line_interpolate_point_geometry_double",
+        )
+    }
 }
@@ -60,9 +60,9 @@ pub(super) struct line_interpolate_points_geometry_doubleFunc {
 }
 
 impl line_interpolate_points_geometry_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float64], volatility),
         }
     }
 }
@@ -90,4 +90,11 @@ impl ScalarUDFImpl for line_interpolate_points_geometry_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         line_interpolate_points_geometry_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "line_interpolate_points_geometry_double",
+        )
+    }
 }
@@ -23,7 +23,7 @@ use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn localtimestamp_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
+fn localtimestamp_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
         "Not implemented {}:{}",
         file!(),
@@ -144,9 +144,9 @@ pub(super) struct localtimestampFunc {
 }
 
 impl localtimestampFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -167,13 +167,17 @@ impl ScalarUDFImpl for localtimestampFunc {
         localtimestamp_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        localtimestamp_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        localtimestamp_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         localtimestamp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "localtimestamp")
+    }
 }
 
 #[derive(Debug)]
@@ -182,9 +186,9 @@ pub(super) struct localtimestamp_bigint_0Func {
 }
 
 impl localtimestamp_bigint_0Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -212,6 +216,10 @@ impl ScalarUDFImpl for localtimestamp_bigint_0Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         localtimestamp_bigint_0_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "localtimestamp_bigint_0")
+    }
 }
 
 #[derive(Debug)]
@@ -220,9 +228,9 @@ pub(super) struct localtimestamp_bigint_3Func {
 }
 
 impl localtimestamp_bigint_3Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -250,6 +258,10 @@ impl ScalarUDFImpl for localtimestamp_bigint_3Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         localtimestamp_bigint_3_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "localtimestamp_bigint_3")
+    }
 }
 
 #[derive(Debug)]
@@ -258,9 +270,9 @@ pub(super) struct localtimestamp_bigint_6Func {
 }
 
 impl localtimestamp_bigint_6Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -288,6 +300,10 @@ impl ScalarUDFImpl for localtimestamp_bigint_6Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         localtimestamp_bigint_6_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "localtimestamp_bigint_6")
+    }
 }
 
 #[derive(Debug)]
@@ -296,9 +312,9 @@ pub(super) struct localtimestamp_bigint_9Func {
 }
 
 impl localtimestamp_bigint_9Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -326,4 +342,8 @@ impl ScalarUDFImpl for localtimestamp_bigint_9Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         localtimestamp_bigint_9_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "localtimestamp_bigint_9")
+    }
 }
@@ -76,9 +76,9 @@ pub(super) struct ltrim_varcharFunc {
 }
 
 impl ltrim_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -106,17 +106,27 @@ impl ScalarUDFImpl for ltrim_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ltrim_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ltrim_varchar")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct ltrim_varchar_codepointsFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl ltrim_varchar_codepointsFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "codepoints"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -133,6 +143,10 @@ impl ScalarUDFImpl for ltrim_varchar_codepointsFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("ltrim", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         ltrim_varchar_codepoints_return_type(arg_types)
     }
@@ -144,4 +158,8 @@ impl ScalarUDFImpl for ltrim_varchar_codepointsFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         ltrim_varchar_codepoints_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "ltrim_varchar_codepoints")
+    }
 }
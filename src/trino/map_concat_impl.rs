@@ -53,12 +53,15 @@ fn map_concat_map_4_5_simplify(
 #[derive(Debug)]
 pub(super) struct map_concat_map_4_5Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl map_concat_map_4_5Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["map<$4, $5>"], true)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +78,10 @@ impl ScalarUDFImpl for map_concat_map_4_5Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("map_concat", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         map_concat_map_4_5_return_type(arg_types)
     }
@@ -86,4 +93,8 @@ impl ScalarUDFImpl for map_concat_map_4_5Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         map_concat_map_4_5_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "map_concat_map_4_5")
+    }
 }
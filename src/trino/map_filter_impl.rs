@@ -16,38 +16,48 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::MapEntries;
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn map_filter_map_4_5_function_4_5_boolean_invoke(
     _args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("map_filter")
 }
 
-fn map_filter_map_4_5_function_4_5_boolean_return_type(
-    _arg_types: &[DataType],
-) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn map_filter_map_4_5_function_4_5_boolean_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("map_filter_map_4_5_function_4_5_boolean", arg_types)
 }
 
 fn map_filter_map_4_5_function_4_5_boolean_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "map_filter",
+        args,
+        info,
+        1,
+        map_filter_map_4_5_function_4_5_boolean_return_type,
+        map_filter,
+    )
+}
+
+/// The maps of the entries for which the lambda expression is true (not false or NULL).
+fn map_filter(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let entries = MapEntries::new(&args[0])?;
+    let keep = lambdas[0].call(
+        &[Arc::clone(&entries.keys), Arc::clone(&entries.values)],
+        &entries.rows,
+    )?;
+    entries.filter(&keep)
 }
 
 // ========== Generated template below this line ==========
@@ -57,12 +67,18 @@ fn map_filter_map_4_5_function_4_5_boolean_simplify(
 #[derive(Debug)]
 pub(super) struct map_filter_map_4_5_function_4_5_booleanFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl map_filter_map_4_5_function_4_5_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<$4, $5>", "function($4, $5, boolean)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +95,10 @@ impl ScalarUDFImpl for map_filter_map_4_5_function_4_5_booleanFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("map_filter", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         map_filter_map_4_5_function_4_5_boolean_return_type(arg_types)
     }
@@ -90,4 +110,11 @@ impl ScalarUDFImpl for map_filter_map_4_5_function_4_5_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         map_filter_map_4_5_function_4_5_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "map_filter_map_4_5_function_4_5_boolean",
+        )
+    }
 }
@@ -46,7 +46,7 @@ fn map_array_4_array_5_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn map_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
+fn map_invoke_no_args(_number_rows: usize) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
         "Not implemented {}:{}",
         file!(),
@@ -73,12 +73,18 @@ fn map_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSimplif
 #[derive(Debug)]
 pub(super) struct map_array_4_array_5Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl map_array_4_array_5Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$4>", "array<$5>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -95,6 +101,10 @@ impl ScalarUDFImpl for map_array_4_array_5Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("map", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         map_array_4_array_5_return_type(arg_types)
     }
@@ -106,6 +116,10 @@ impl ScalarUDFImpl for map_array_4_array_5Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         map_array_4_array_5_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "map_array_4_array_5")
+    }
 }
 
 #[derive(Debug)]
@@ -114,9 +128,9 @@ pub(super) struct mapFunc {
 }
 
 impl mapFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -137,11 +151,15 @@ impl ScalarUDFImpl for mapFunc {
         map_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        map_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        map_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         map_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "map")
+    }
 }
@@ -57,12 +57,18 @@ fn map_zip_with_map_4_8_map_4_7_function_4_8_7_6_simplify(
 #[derive(Debug)]
 pub(super) struct map_zip_with_map_4_8_map_4_7_function_4_8_7_6Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl map_zip_with_map_4_8_map_4_7_function_4_8_7_6Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<$4, $8>", "map<$4, $7>", "function($4, $8, $7, $6)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +85,10 @@ impl ScalarUDFImpl for map_zip_with_map_4_8_map_4_7_function_4_8_7_6Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("map_zip_with", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         map_zip_with_map_4_8_map_4_7_function_4_8_7_6_return_type(arg_types)
     }
@@ -90,4 +100,11 @@ impl ScalarUDFImpl for map_zip_with_map_4_8_map_4_7_function_4_8_7_6Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         map_zip_with_map_4_8_map_4_7_function_4_8_7_6_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "map_zip_with_map_4_8_map_4_7_function_4_8_7_6",
+        )
+    }
 }
@@ -86,12 +86,18 @@ fn millisecond_timestamp_p_simplify(
 #[derive(Debug)]
 pub(super) struct millisecond_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl millisecond_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -108,6 +114,10 @@ impl ScalarUDFImpl for millisecond_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("millisecond", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         millisecond_intervaldaytosecond_return_type(arg_types)
     }
@@ -119,6 +129,13 @@ impl ScalarUDFImpl for millisecond_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         millisecond_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "millisecond_intervaldaytosecond",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -127,9 +144,25 @@ pub(super) struct millisecond_time_pFunc {
 }
 
 impl millisecond_time_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time32(
+                        arrow::datatypes::TimeUnit::Second,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time32(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time64(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time64(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -157,6 +190,10 @@ impl ScalarUDFImpl for millisecond_time_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         millisecond_time_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "millisecond_time_p")
+    }
 }
 
 #[derive(Debug)]
@@ -165,9 +202,29 @@ pub(super) struct millisecond_timestamp_pFunc {
 }
 
 impl millisecond_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -195,4 +252,8 @@ impl ScalarUDFImpl for millisecond_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         millisecond_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "millisecond_timestamp_p")
+    }
 }
@@ -83,12 +83,18 @@ fn minute_timestamp_p_simplify(
 #[derive(Debug)]
 pub(super) struct minute_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl minute_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -105,6 +111,10 @@ impl ScalarUDFImpl for minute_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("minute", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         minute_intervaldaytosecond_return_type(arg_types)
     }
@@ -116,6 +126,10 @@ impl ScalarUDFImpl for minute_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         minute_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "minute_intervaldaytosecond")
+    }
 }
 
 #[derive(Debug)]
@@ -124,9 +138,25 @@ pub(super) struct minute_time_pFunc {
 }
 
 impl minute_time_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time32(
+                        arrow::datatypes::TimeUnit::Second,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time32(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time64(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Time64(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -154,6 +184,10 @@ impl ScalarUDFImpl for minute_time_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         minute_time_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "minute_time_p")
+    }
 }
 
 #[derive(Debug)]
@@ -162,9 +196,29 @@ pub(super) struct minute_timestamp_pFunc {
 }
 
 impl minute_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -192,4 +246,8 @@ impl ScalarUDFImpl for minute_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         minute_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "minute_timestamp_p")
+    }
 }
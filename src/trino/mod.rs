@@ -20,6 +20,12 @@ mod abs_impl;
 mod acos_impl;
 mod all_match_impl;
 mod any_match_impl;
+mod approx_distinct_impl;
+mod approx_most_frequent_impl;
+mod approx_percentile_impl;
+mod approx_set_impl;
+mod arbitrary_impl;
+mod array_agg_impl;
 mod array_distinct_impl;
 mod array_except_impl;
 mod array_intersect_impl;
@@ -35,6 +41,7 @@ mod asin_impl;
 mod at_timezone_impl;
 mod atan2_impl;
 mod atan_impl;
+mod avg_impl;
 mod bar_impl;
 mod beta_cdf_impl;
 mod bing_tile_at_impl;
@@ -45,18 +52,24 @@ mod bing_tile_quadkey_impl;
 mod bing_tile_zoom_level_impl;
 mod bing_tiles_around_impl;
 mod bit_count_impl;
+mod bitwise_and_agg_impl;
 mod bitwise_and_impl;
 mod bitwise_left_shift_impl;
 mod bitwise_not_impl;
+mod bitwise_or_agg_impl;
 mod bitwise_or_impl;
 mod bitwise_right_shift_arithmetic_impl;
 mod bitwise_right_shift_impl;
 mod bitwise_xor_impl;
+mod bool_and_impl;
+mod bool_or_impl;
 mod cardinality_impl;
+mod cast_impl;
 mod cbrt_impl;
 mod ceil_impl;
 mod ceiling_impl;
 mod char2hexint_impl;
+mod checksum_impl;
 mod chr_impl;
 mod classify_impl;
 mod coalesce_impl;
@@ -67,10 +80,17 @@ mod concat_impl;
 mod concat_ws_impl;
 mod contains_impl;
 mod contains_sequence_impl;
+mod convex_hull_agg_impl;
+mod corr_impl;
 mod cos_impl;
 mod cosh_impl;
 mod cosine_similarity_impl;
+mod count_if_impl;
+mod count_impl;
+mod covar_pop_impl;
+mod covar_samp_impl;
 mod crc32_impl;
+mod cume_dist_impl;
 mod current_catalog_impl;
 mod current_date_impl;
 mod current_groups_impl;
@@ -90,14 +110,18 @@ mod day_of_month_impl;
 mod day_of_week_impl;
 mod day_of_year_impl;
 mod degrees_impl;
+mod dense_rank_impl;
 mod dow_impl;
 mod doy_impl;
 mod e_impl;
 mod element_at_impl;
 mod empty_approx_set_impl;
+mod evaluate_classifier_predictions_impl;
+mod every_impl;
 mod exp_impl;
 mod features_impl;
 mod filter_impl;
+mod first_value_impl;
 mod flatten_impl;
 mod floor_impl;
 mod format_datetime_impl;
@@ -120,15 +144,19 @@ mod from_iso8601_timestamp_nanos_impl;
 mod from_unixtime_impl;
 mod from_unixtime_nanos_impl;
 mod from_utf8_impl;
+mod geometric_mean_impl;
 mod geometry_from_hadoop_shape_impl;
 mod geometry_invalid_reason_impl;
 mod geometry_nearest_points_impl;
 mod geometry_to_bing_tiles_impl;
+mod geometry_union_agg_impl;
 mod geometry_union_impl;
 mod great_circle_distance_impl;
 mod greatest_impl;
+mod grouping_impl;
 mod hamming_distance_impl;
 mod hash_counts_impl;
+mod histogram_impl;
 mod hmac_md5_impl;
 mod hmac_sha1_impl;
 mod hmac_sha256_impl;
@@ -154,13 +182,22 @@ mod json_extract_scalar_impl;
 mod json_format_impl;
 mod json_parse_impl;
 mod json_size_impl;
+mod kurtosis_impl;
+mod lag_impl;
 mod last_day_of_month_impl;
+mod last_value_impl;
+mod lead_impl;
+mod learn_classifier_impl;
+mod learn_libsvm_classifier_impl;
+mod learn_libsvm_regressor_impl;
+mod learn_regressor_impl;
 mod least_impl;
 mod length_impl;
 mod levenshtein_distance_impl;
 mod line_interpolate_point_impl;
 mod line_interpolate_points_impl;
 mod line_locate_point_impl;
+mod listagg_impl;
 mod ln_impl;
 mod localtime_impl;
 mod localtimestamp_impl;
@@ -171,19 +208,29 @@ mod lower_impl;
 mod lpad_impl;
 mod ltrim_impl;
 mod luhn_check_impl;
+mod make_set_digest_impl;
+mod map_agg_impl;
 mod map_concat_impl;
 mod map_entries_impl;
 mod map_filter_impl;
 mod map_from_entries_impl;
 mod map_impl;
 mod map_keys_impl;
+mod map_union_impl;
 mod map_values_impl;
 mod map_zip_with_impl;
+mod max_by_impl;
+mod max_impl;
 mod md5_impl;
+mod merge_impl;
+mod merge_set_digest_impl;
 mod millisecond_impl;
+mod min_by_impl;
+mod min_impl;
 mod minute_impl;
 mod mod_impl;
 mod month_impl;
+mod multimap_agg_impl;
 mod multimap_from_entries_impl;
 mod murmur3_impl;
 mod nan_impl;
@@ -192,21 +239,28 @@ mod none_match_impl;
 mod normal_cdf_impl;
 mod normalize_impl;
 mod now_impl;
+mod nth_value_impl;
+mod ntile_impl;
 mod nullif_impl;
+mod numeric_histogram_impl;
 mod objectid_impl;
 mod objectid_timestamp_impl;
 mod parse_data_size_impl;
 mod parse_datetime_impl;
 mod parse_duration_impl;
 mod parse_presto_data_size_impl;
+mod percent_rank_impl;
 mod pi_impl;
 mod pow_impl;
 mod power_impl;
+mod qdigest_agg_impl;
 mod quantile_at_value_impl;
 mod quarter_impl;
 mod radians_impl;
 mod rand_impl;
 mod random_impl;
+mod rank_impl;
+mod reduce_agg_impl;
 mod reduce_impl;
 mod regexp_count_impl;
 mod regexp_extract_all_impl;
@@ -215,6 +269,8 @@ mod regexp_like_impl;
 mod regexp_position_impl;
 mod regexp_replace_impl;
 mod regexp_split_impl;
+mod regr_intercept_impl;
+mod regr_slope_impl;
 mod regress_impl;
 mod render_impl;
 mod repeat_impl;
@@ -222,6 +278,7 @@ mod replace_impl;
 mod reverse_impl;
 mod rgb_impl;
 mod round_impl;
+mod row_number_impl;
 mod rpad_impl;
 mod rtrim_impl;
 mod second_impl;
@@ -234,8 +291,10 @@ mod sign_impl;
 mod simplify_geometry_impl;
 mod sin_impl;
 mod sinh_impl;
+mod skewness_impl;
 mod slice_impl;
 mod soundex_impl;
+mod spatial_partitioning_impl;
 mod spatial_partitions_impl;
 mod split_impl;
 mod split_part_impl;
@@ -302,11 +361,16 @@ mod st_y_impl;
 mod st_ymax_impl;
 mod st_ymin_impl;
 mod starts_with_impl;
+mod stddev_impl;
+mod stddev_pop_impl;
+mod stddev_samp_impl;
 mod strpos_impl;
 mod substr_impl;
 mod substring_impl;
+mod sum_impl;
 mod tan_impl;
 mod tanh_impl;
+mod tdigest_agg_impl;
 mod timestamp_objectid_impl;
 mod timezone_hour_impl;
 mod timezone_minute_impl;
@@ -337,6 +401,7 @@ mod translate_impl;
 mod trim_array_impl;
 mod trim_impl;
 mod truncate_impl;
+mod try_cast_impl;
 mod try_impl;
 mod typeof_impl;
 mod upper_impl;
@@ -352,6 +417,9 @@ mod url_extract_query_impl;
 mod uuid_impl;
 mod value_at_quantile_impl;
 mod values_at_quantiles_impl;
+mod var_pop_impl;
+mod var_samp_impl;
+mod variance_impl;
 mod week_impl;
 mod week_of_year_impl;
 mod width_bucket_impl;
@@ -366,6 +434,9 @@ mod yow_impl;
 mod zip_impl;
 mod zip_with_impl;
 
+pub mod session;
+pub mod types;
+
 // create  UDFs
 make_udf_function!(abs_impl::abs_tinyintFunc, ABS_TINYINT, abs_tinyint);
 make_udf_function!(abs_impl::abs_smallintFunc, ABS_SMALLINT, abs_smallint);
@@ -471,9 +542,9 @@ make_udf_function!(
 make_udf_function!(asin_impl::asin_doubleFunc, ASIN_DOUBLE, asin_double);
 
 make_udf_function!(
-    at_timezone_impl::at_timezone_timestamp_p_varcharFunc,
-    AT_TIMEZONE_TIMESTAMP_P_VARCHAR,
-    at_timezone_timestamp_p_varchar
+    at_timezone_impl::at_timezone_timestamp_p_withtimezone_varcharFunc,
+    AT_TIMEZONE_TIMESTAMP_P_WITHTIMEZONE_VARCHAR,
+    at_timezone_timestamp_p_withtimezone_varchar
 );
 
 make_udf_function!(atan_impl::atan_doubleFunc, ATAN_DOUBLE, atan_double);
@@ -667,6 +738,12 @@ make_udf_function!(
     cardinality_setdigest
 );
 
+make_udf_function!(
+    cast_impl::cast_1_varcharFunc,
+    CAST_1_VARCHAR,
+    cast_1_varchar
+);
+
 make_udf_function!(cbrt_impl::cbrt_doubleFunc, CBRT_DOUBLE, cbrt_double);
 
 make_udf_function!(ceil_impl::ceil_bigintFunc, CEIL_BIGINT, ceil_bigint);
@@ -831,69 +908,81 @@ make_udf_function!(
 make_udf_function!(
     current_catalog_impl::current_catalogFunc,
     CURRENT_CATALOG,
-    current_catalog
+    current_catalog,
+    Stable
 );
 
 make_udf_function!(
     current_date_impl::current_dateFunc,
     CURRENT_DATE,
-    current_date
+    current_date,
+    Stable
 );
 
 make_udf_function!(
     current_groups_impl::current_groupsFunc,
     CURRENT_GROUPS,
-    current_groups
+    current_groups,
+    Stable
 );
 
 make_udf_function!(
     current_schema_impl::current_schemaFunc,
     CURRENT_SCHEMA,
-    current_schema
+    current_schema,
+    Stable
 );
 
 make_udf_function!(
     current_time_impl::current_timeFunc,
     CURRENT_TIME,
-    current_time
+    current_time,
+    Stable
 );
 
 make_udf_function!(
     current_timestamp_impl::current_timestampFunc,
     CURRENT_TIMESTAMP,
-    current_timestamp
+    current_timestamp,
+    Stable
 );
 make_udf_function!(
     current_timestamp_impl::current_timestamp_bigint_0Func,
     CURRENT_TIMESTAMP_BIGINT_0,
-    current_timestamp_bigint_0
+    current_timestamp_bigint_0,
+    Stable
 );
 make_udf_function!(
     current_timestamp_impl::current_timestamp_bigint_3Func,
     CURRENT_TIMESTAMP_BIGINT_3,
-    current_timestamp_bigint_3
+    current_timestamp_bigint_3,
+    Stable
 );
 make_udf_function!(
     current_timestamp_impl::current_timestamp_bigint_6Func,
     CURRENT_TIMESTAMP_BIGINT_6,
-    current_timestamp_bigint_6
+    current_timestamp_bigint_6,
+    Stable
 );
 make_udf_function!(
     current_timestamp_impl::current_timestamp_bigint_9Func,
     CURRENT_TIMESTAMP_BIGINT_9,
-    current_timestamp_bigint_9
+    current_timestamp_bigint_9,
+    Stable
 );
 
 make_udf_function!(
     current_timezone_impl::current_timezoneFunc,
     CURRENT_TIMEZONE,
-    current_timezone
+    current_timezone,
+    Stable
 );
 
 make_udf_function!(
     current_user_impl::current_userFunc,
     CURRENT_USER,
-    current_user
+    current_user,
+    Stable
 );
 
 make_udf_function!(
@@ -918,6 +1007,11 @@ make_udf_function!(
     DATE_ADD_VARCHAR_BIGINT_TIMESTAMP_P,
     date_add_varchar_bigint_timestamp_p
 );
+make_udf_function!(
+    date_add_impl::date_add_varchar_bigint_timestamp_p_withtimezoneFunc,
+    DATE_ADD_VARCHAR_BIGINT_TIMESTAMP_P_WITHTIMEZONE,
+    date_add_varchar_bigint_timestamp_p_withtimezone
+);
 
 make_udf_function!(
     date_diff_impl::date_diff_varchar_date_dateFunc,
@@ -934,6 +1028,11 @@ make_udf_function!(
     DATE_DIFF_VARCHAR_TIMESTAMP_P_TIMESTAMP_P,
     date_diff_varchar_timestamp_p_timestamp_p
 );
+make_udf_function!(
+    date_diff_impl::date_diff_varchar_timestamp_p_withtimezone_timestamp_p_withtimezoneFunc,
+    DATE_DIFF_VARCHAR_TIMESTAMP_P_WITHTIMEZONE_TIMESTAMP_P_WITHTIMEZONE,
+    date_diff_varchar_timestamp_p_withtimezone_timestamp_p_withtimezone
+);
 
 make_udf_function!(
     date_format_impl::date_format_timestamp_p_varcharFunc,
@@ -957,6 +1056,11 @@ make_udf_function!(
     DATE_TRUNC_VARCHAR_TIMESTAMP_P,
     date_trunc_varchar_timestamp_p
 );
+make_udf_function!(
+    date_trunc_impl::date_trunc_varchar_timestamp_p_withtimezoneFunc,
+    DATE_TRUNC_VARCHAR_TIMESTAMP_P_WITHTIMEZONE,
+    date_trunc_varchar_timestamp_p_withtimezone
+);
 make_udf_function!(
     date_trunc_impl::date_trunc_varchar_dateFunc,
     DATE_TRUNC_VARCHAR_DATE,
@@ -1277,19 +1381,19 @@ make_udf_function!(
 );
 
 make_udf_function!(
-    from_unixtime_impl::from_unixtime_bigintFunc,
-    FROM_UNIXTIME_BIGINT,
-    from_unixtime_bigint
+    from_unixtime_impl::from_unixtime_doubleFunc,
+    FROM_UNIXTIME_DOUBLE,
+    from_unixtime_double
 );
 make_udf_function!(
-    from_unixtime_impl::from_unixtime_bigint_bigint_bigintFunc,
-    FROM_UNIXTIME_BIGINT_BIGINT_BIGINT,
-    from_unixtime_bigint_bigint_bigint
+    from_unixtime_impl::from_unixtime_double_bigint_bigintFunc,
+    FROM_UNIXTIME_DOUBLE_BIGINT_BIGINT,
+    from_unixtime_double_bigint_bigint
 );
 make_udf_function!(
-    from_unixtime_impl::from_unixtime_bigint_varcharFunc,
-    FROM_UNIXTIME_BIGINT_VARCHAR,
-    from_unixtime_bigint_varchar
+    from_unixtime_impl::from_unixtime_double_varcharFunc,
+    FROM_UNIXTIME_DOUBLE_VARCHAR,
+    from_unixtime_double_varchar
 );
 
 make_udf_function!(
@@ -1629,32 +1733,37 @@ make_udf_function!(
 
 make_udf_function!(ln_impl::ln_doubleFunc, LN_DOUBLE, ln_double);
 
-make_udf_function!(localtime_impl::localtimeFunc, LOCALTIME, localtime);
+make_udf_function!(localtime_impl::localtimeFunc, LOCALTIME, localtime, Stable);
 
 make_udf_function!(
     localtimestamp_impl::localtimestampFunc,
     LOCALTIMESTAMP,
-    localtimestamp
+    localtimestamp,
+    Stable
 );
 make_udf_function!(
     localtimestamp_impl::localtimestamp_bigint_0Func,
     LOCALTIMESTAMP_BIGINT_0,
-    localtimestamp_bigint_0
+    localtimestamp_bigint_0,
+    Stable
 );
 make_udf_function!(
     localtimestamp_impl::localtimestamp_bigint_3Func,
     LOCALTIMESTAMP_BIGINT_3,
-    localtimestamp_bigint_3
+    localtimestamp_bigint_3,
+    Stable
 );
 make_udf_function!(
     localtimestamp_impl::localtimestamp_bigint_6Func,
     LOCALTIMESTAMP_BIGINT_6,
-    localtimestamp_bigint_6
+    localtimestamp_bigint_6,
+    Stable
 );
 make_udf_function!(
     localtimestamp_impl::localtimestamp_bigint_9Func,
     LOCALTIMESTAMP_BIGINT_9,
-    localtimestamp_bigint_9
+    localtimestamp_bigint_9,
+    Stable
 );
 
 make_udf_function!(
@@ -1854,7 +1963,7 @@ make_udf_function!(
     normalize_varchar_varchar
 );
 
-make_udf_function!(now_impl::nowFunc, NOW, now);
+make_udf_function!(now_impl::nowFunc, NOW, now, Stable);
 
 make_udf_function!(nullif_impl::nullif_1_1Func, NULLIF_1_1, nullif_1_1);
 
@@ -1938,68 +2047,104 @@ make_udf_function!(
     radians_double
 );
 
-make_udf_function!(rand_impl::rand_bigintFunc, RAND_BIGINT, rand_bigint);
+make_udf_function!(
+    rand_impl::rand_bigintFunc,
+    RAND_BIGINT,
+    rand_bigint,
+    Volatile
+);
 make_udf_function!(
     rand_impl::rand_bigint_bigintFunc,
     RAND_BIGINT_BIGINT,
-    rand_bigint_bigint
+    rand_bigint_bigint,
+    Volatile
+);
+make_udf_function!(rand_impl::randFunc, RAND, rand, Volatile);
+make_udf_function!(
+    rand_impl::rand_integerFunc,
+    RAND_INTEGER,
+    rand_integer,
+    Volatile
 );
-make_udf_function!(rand_impl::randFunc, RAND, rand);
-make_udf_function!(rand_impl::rand_integerFunc, RAND_INTEGER, rand_integer);
 make_udf_function!(
     rand_impl::rand_integer_integerFunc,
     RAND_INTEGER_INTEGER,
-    rand_integer_integer
+    rand_integer_integer,
+    Volatile
+);
+make_udf_function!(
+    rand_impl::rand_smallintFunc,
+    RAND_SMALLINT,
+    rand_smallint,
+    Volatile
 );
-make_udf_function!(rand_impl::rand_smallintFunc, RAND_SMALLINT, rand_smallint);
 make_udf_function!(
     rand_impl::rand_smallint_smallintFunc,
     RAND_SMALLINT_SMALLINT,
-    rand_smallint_smallint
+    rand_smallint_smallint,
+    Volatile
+);
+make_udf_function!(
+    rand_impl::rand_tinyintFunc,
+    RAND_TINYINT,
+    rand_tinyint,
+    Volatile
 );
-make_udf_function!(rand_impl::rand_tinyintFunc, RAND_TINYINT, rand_tinyint);
 make_udf_function!(
     rand_impl::rand_tinyint_tinyintFunc,
     RAND_TINYINT_TINYINT,
-    rand_tinyint_tinyint
+    rand_tinyint_tinyint,
+    Volatile
 );
 
-make_udf_function!(random_impl::random_bigintFunc, RANDOM_BIGINT, random_bigint);
+make_udf_function!(
+    random_impl::random_bigintFunc,
+    RANDOM_BIGINT,
+    random_bigint,
+    Volatile
+);
 make_udf_function!(
     random_impl::random_bigint_bigintFunc,
     RANDOM_BIGINT_BIGINT,
-    random_bigint_bigint
+    random_bigint_bigint,
+    Volatile
 );
-make_udf_function!(random_impl::randomFunc, RANDOM, random);
+make_udf_function!(random_impl::randomFunc, RANDOM, random, Volatile);
 make_udf_function!(
     random_impl::random_integerFunc,
     RANDOM_INTEGER,
-    random_integer
+    random_integer,
+    Volatile
 );
 make_udf_function!(
     random_impl::random_integer_integerFunc,
     RANDOM_INTEGER_INTEGER,
-    random_integer_integer
+    random_integer_integer,
+    Volatile
 );
 make_udf_function!(
     random_impl::random_smallintFunc,
     RANDOM_SMALLINT,
-    random_smallint
+    random_smallint,
+    Volatile
 );
 make_udf_function!(
     random_impl::random_smallint_smallintFunc,
     RANDOM_SMALLINT_SMALLINT,
-    random_smallint_smallint
+    random_smallint_smallint,
+    Volatile
 );
 make_udf_function!(
     random_impl::random_tinyintFunc,
     RANDOM_TINYINT,
-    random_tinyint
+    random_tinyint,
+    Volatile
 );
 make_udf_function!(
     random_impl::random_tinyint_tinyintFunc,
     RANDOM_TINYINT_TINYINT,
-    random_tinyint_tinyint
+    random_tinyint_tinyint,
+    Volatile
 );
 
 make_udf_function!(
@@ -2279,7 +2424,8 @@ make_udf_function!(
 make_udf_function!(
     shuffle_impl::shuffle_array_3Func,
     SHUFFLE_ARRAY_3,
-    shuffle_array_3
+    shuffle_array_3,
+    Volatile
 );
 
 make_udf_function!(sign_impl::sign_bigintFunc, SIGN_BIGINT, sign_bigint);
@@ -2779,25 +2925,25 @@ make_udf_function!(
 );
 
 make_udf_function!(
-    timezone_hour_impl::timezone_hour_time_pFunc,
-    TIMEZONE_HOUR_TIME_P,
-    timezone_hour_time_p
+    timezone_hour_impl::timezone_hour_time_p_withtimezoneFunc,
+    TIMEZONE_HOUR_TIME_P_WITHTIMEZONE,
+    timezone_hour_time_p_withtimezone
 );
 make_udf_function!(
-    timezone_hour_impl::timezone_hour_timestamp_pFunc,
-    TIMEZONE_HOUR_TIMESTAMP_P,
-    timezone_hour_timestamp_p
+    timezone_hour_impl::timezone_hour_timestamp_p_withtimezoneFunc,
+    TIMEZONE_HOUR_TIMESTAMP_P_WITHTIMEZONE,
+    timezone_hour_timestamp_p_withtimezone
 );
 
 make_udf_function!(
-    timezone_minute_impl::timezone_minute_time_pFunc,
-    TIMEZONE_MINUTE_TIME_P,
-    timezone_minute_time_p
+    timezone_minute_impl::timezone_minute_time_p_withtimezoneFunc,
+    TIMEZONE_MINUTE_TIME_P_WITHTIMEZONE,
+    timezone_minute_time_p_withtimezone
 );
 make_udf_function!(
-    timezone_minute_impl::timezone_minute_timestamp_pFunc,
-    TIMEZONE_MINUTE_TIMESTAMP_P,
-    timezone_minute_timestamp_p
+    timezone_minute_impl::timezone_minute_timestamp_p_withtimezoneFunc,
+    TIMEZONE_MINUTE_TIMESTAMP_P_WITHTIMEZONE,
+    timezone_minute_timestamp_p_withtimezone
 );
 
 make_udf_function!(
@@ -2894,6 +3040,11 @@ make_udf_function!(
     TO_ISO8601_TIMESTAMP_P,
     to_iso8601_timestamp_p
 );
+make_udf_function!(
+    to_iso8601_impl::to_iso8601_timestamp_p_withtimezoneFunc,
+    TO_ISO8601_TIMESTAMP_P_WITHTIMEZONE,
+    to_iso8601_timestamp_p_withtimezone
+);
 
 make_udf_function!(
     to_milliseconds_impl::to_milliseconds_intervaldaytosecondFunc,
@@ -2914,9 +3065,9 @@ make_udf_function!(
 );
 
 make_udf_function!(
-    to_unixtime_impl::to_unixtime_timestamp_pFunc,
-    TO_UNIXTIME_TIMESTAMP_P,
-    to_unixtime_timestamp_p
+    to_unixtime_impl::to_unixtime_timestamp_p_withtimezoneFunc,
+    TO_UNIXTIME_TIMESTAMP_P_WITHTIMEZONE,
+    to_unixtime_timestamp_p_withtimezone
 );
 
 make_udf_function!(
@@ -2985,6 +3136,12 @@ make_udf_function!(
 
 make_udf_function!(try_impl::try_1Func, TRY_1, try_1);
 
+make_udf_function!(
+    try_cast_impl::try_cast_1_varcharFunc,
+    TRY_CAST_1_VARCHAR,
+    try_cast_1_varchar
+);
+
 make_udf_function!(typeof_impl::typeof_1Func, TYPEOF_1, typeof_1);
 
 make_udf_function!(upper_impl::upper_varcharFunc, UPPER_VARCHAR, upper_varchar);
@@ -3043,7 +3200,7 @@ make_udf_function!(
     url_extract_query_varchar
 );
 
-make_udf_function!(uuid_impl::uuidFunc, UUID, uuid);
+make_udf_function!(uuid_impl::uuidFunc, UUID, uuid, Volatile);
 
 make_udf_function!(
     value_at_quantile_impl::value_at_quantile_qdigest_doubleFunc,
@@ -3188,923 +3345,1781 @@ make_udf_function!(
     zip_with_array_1_array_11_function_1_11_9
 );
 
+// create UDAFs
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_booleanFunc,
+    APPROX_DISTINCT_BOOLEAN,
+    approx_distinct_boolean
+);
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_boolean_doubleFunc,
+    APPROX_DISTINCT_BOOLEAN_DOUBLE,
+    approx_distinct_boolean_double
+);
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_1Func,
+    APPROX_DISTINCT_1,
+    approx_distinct_1
+);
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_1_doubleFunc,
+    APPROX_DISTINCT_1_DOUBLE,
+    approx_distinct_1_double
+);
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_unknownFunc,
+    APPROX_DISTINCT_UNKNOWN,
+    approx_distinct_unknown
+);
+make_udaf_function!(
+    approx_distinct_impl::approx_distinct_unknown_doubleFunc,
+    APPROX_DISTINCT_UNKNOWN_DOUBLE,
+    approx_distinct_unknown_double
+);
+
+make_udaf_function!(
+    approx_most_frequent_impl::approx_most_frequent_bigint_bigint_bigintFunc,
+    APPROX_MOST_FREQUENT_BIGINT_BIGINT_BIGINT,
+    approx_most_frequent_bigint_bigint_bigint
+);
+make_udaf_function!(
+    approx_most_frequent_impl::approx_most_frequent_bigint_varchar_bigintFunc,
+    APPROX_MOST_FREQUENT_BIGINT_VARCHAR_BIGINT,
+    approx_most_frequent_bigint_varchar_bigint
+);
+
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_bigint_array_doubleFunc,
+    APPROX_PERCENTILE_BIGINT_ARRAY_DOUBLE,
+    approx_percentile_bigint_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_bigint_double_array_doubleFunc,
+    APPROX_PERCENTILE_BIGINT_DOUBLE_ARRAY_DOUBLE,
+    approx_percentile_bigint_double_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_double_array_doubleFunc,
+    APPROX_PERCENTILE_DOUBLE_ARRAY_DOUBLE,
+    approx_percentile_double_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_double_double_array_doubleFunc,
+    APPROX_PERCENTILE_DOUBLE_DOUBLE_ARRAY_DOUBLE,
+    approx_percentile_double_double_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_real_array_doubleFunc,
+    APPROX_PERCENTILE_REAL_ARRAY_DOUBLE,
+    approx_percentile_real_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_real_double_array_doubleFunc,
+    APPROX_PERCENTILE_REAL_DOUBLE_ARRAY_DOUBLE,
+    approx_percentile_real_double_array_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_bigint_doubleFunc,
+    APPROX_PERCENTILE_BIGINT_DOUBLE,
+    approx_percentile_bigint_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_bigint_double_doubleFunc,
+    APPROX_PERCENTILE_BIGINT_DOUBLE_DOUBLE,
+    approx_percentile_bigint_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_bigint_double_double_doubleFunc,
+    APPROX_PERCENTILE_BIGINT_DOUBLE_DOUBLE_DOUBLE,
+    approx_percentile_bigint_double_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_double_doubleFunc,
+    APPROX_PERCENTILE_DOUBLE_DOUBLE,
+    approx_percentile_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_double_double_doubleFunc,
+    APPROX_PERCENTILE_DOUBLE_DOUBLE_DOUBLE,
+    approx_percentile_double_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_double_double_double_doubleFunc,
+    APPROX_PERCENTILE_DOUBLE_DOUBLE_DOUBLE_DOUBLE,
+    approx_percentile_double_double_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_real_doubleFunc,
+    APPROX_PERCENTILE_REAL_DOUBLE,
+    approx_percentile_real_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_real_double_doubleFunc,
+    APPROX_PERCENTILE_REAL_DOUBLE_DOUBLE,
+    approx_percentile_real_double_double
+);
+make_udaf_function!(
+    approx_percentile_impl::approx_percentile_real_double_double_doubleFunc,
+    APPROX_PERCENTILE_REAL_DOUBLE_DOUBLE_DOUBLE,
+    approx_percentile_real_double_double_double
+);
+
+make_udaf_function!(
+    approx_set_impl::approx_set_bigintFunc,
+    APPROX_SET_BIGINT,
+    approx_set_bigint
+);
+make_udaf_function!(
+    approx_set_impl::approx_set_doubleFunc,
+    APPROX_SET_DOUBLE,
+    approx_set_double
+);
+make_udaf_function!(
+    approx_set_impl::approx_set_varcharFunc,
+    APPROX_SET_VARCHAR,
+    approx_set_varchar
+);
+
+make_udaf_function!(arbitrary_impl::arbitrary_1Func, ARBITRARY_1, arbitrary_1);
+
+make_udaf_function!(array_agg_impl::array_agg_1Func, ARRAY_AGG_1, array_agg_1);
+
+make_udaf_function!(avg_impl::avg_doubleFunc, AVG_DOUBLE, avg_double);
+make_udaf_function!(
+    avg_impl::avg_decimal_p_sFunc,
+    AVG_DECIMAL_P_S,
+    avg_decimal_p_s
+);
+make_udaf_function!(
+    avg_impl::avg_intervaldaytosecondFunc,
+    AVG_INTERVALDAYTOSECOND,
+    avg_intervaldaytosecond
+);
+make_udaf_function!(
+    avg_impl::avg_intervalyeartomonthFunc,
+    AVG_INTERVALYEARTOMONTH,
+    avg_intervalyeartomonth
+);
+make_udaf_function!(avg_impl::avg_realFunc, AVG_REAL, avg_real);
+
+make_udaf_function!(
+    bitwise_and_agg_impl::bitwise_and_agg_bigintFunc,
+    BITWISE_AND_AGG_BIGINT,
+    bitwise_and_agg_bigint
+);
+
+make_udaf_function!(
+    bitwise_or_agg_impl::bitwise_or_agg_bigintFunc,
+    BITWISE_OR_AGG_BIGINT,
+    bitwise_or_agg_bigint
+);
+
+make_udaf_function!(
+    bool_and_impl::bool_and_booleanFunc,
+    BOOL_AND_BOOLEAN,
+    bool_and_boolean
+);
+
+make_udaf_function!(
+    bool_or_impl::bool_or_booleanFunc,
+    BOOL_OR_BOOLEAN,
+    bool_or_boolean
+);
+
+make_udaf_function!(checksum_impl::checksum_1Func, CHECKSUM_1, checksum_1);
+
+make_udaf_function!(
+    convex_hull_agg_impl::convex_hull_agg_geometryFunc,
+    CONVEX_HULL_AGG_GEOMETRY,
+    convex_hull_agg_geometry
+);
+
+make_udaf_function!(
+    corr_impl::corr_double_doubleFunc,
+    CORR_DOUBLE_DOUBLE,
+    corr_double_double
+);
+make_udaf_function!(
+    corr_impl::corr_real_realFunc,
+    CORR_REAL_REAL,
+    corr_real_real
+);
+
+make_udaf_function!(count_impl::countFunc, COUNT, count);
+make_udaf_function!(count_impl::count_1Func, COUNT_1, count_1);
+
+make_udaf_function!(
+    count_if_impl::count_if_booleanFunc,
+    COUNT_IF_BOOLEAN,
+    count_if_boolean
+);
+
+make_udaf_function!(
+    covar_pop_impl::covar_pop_double_doubleFunc,
+    COVAR_POP_DOUBLE_DOUBLE,
+    covar_pop_double_double
+);
+make_udaf_function!(
+    covar_pop_impl::covar_pop_real_realFunc,
+    COVAR_POP_REAL_REAL,
+    covar_pop_real_real
+);
+
+make_udaf_function!(
+    covar_samp_impl::covar_samp_double_doubleFunc,
+    COVAR_SAMP_DOUBLE_DOUBLE,
+    covar_samp_double_double
+);
+make_udaf_function!(
+    covar_samp_impl::covar_samp_real_realFunc,
+    COVAR_SAMP_REAL_REAL,
+    covar_samp_real_real
+);
+
+make_udaf_function!(
+    evaluate_classifier_predictions_impl::evaluate_classifier_predictions_bigint_bigintFunc,
+    EVALUATE_CLASSIFIER_PREDICTIONS_BIGINT_BIGINT,
+    evaluate_classifier_predictions_bigint_bigint
+);
+make_udaf_function!(
+    evaluate_classifier_predictions_impl::evaluate_classifier_predictions_varchar_varcharFunc,
+    EVALUATE_CLASSIFIER_PREDICTIONS_VARCHAR_VARCHAR,
+    evaluate_classifier_predictions_varchar_varchar
+);
+
+make_udaf_function!(every_impl::every_booleanFunc, EVERY_BOOLEAN, every_boolean);
+
+make_udaf_function!(
+    geometric_mean_impl::geometric_mean_bigintFunc,
+    GEOMETRIC_MEAN_BIGINT,
+    geometric_mean_bigint
+);
+make_udaf_function!(
+    geometric_mean_impl::geometric_mean_doubleFunc,
+    GEOMETRIC_MEAN_DOUBLE,
+    geometric_mean_double
+);
+make_udaf_function!(
+    geometric_mean_impl::geometric_mean_realFunc,
+    GEOMETRIC_MEAN_REAL,
+    geometric_mean_real
+);
+
+make_udaf_function!(
+    geometry_union_agg_impl::geometry_union_agg_geometryFunc,
+    GEOMETRY_UNION_AGG_GEOMETRY,
+    geometry_union_agg_geometry
+);
+
+make_udaf_function!(grouping_impl::groupingFunc, GROUPING, grouping);
+
+make_udaf_function!(histogram_impl::histogram_1Func, HISTOGRAM_1, histogram_1);
+
+make_udaf_function!(
+    kurtosis_impl::kurtosis_bigintFunc,
+    KURTOSIS_BIGINT,
+    kurtosis_bigint
+);
+make_udaf_function!(
+    kurtosis_impl::kurtosis_doubleFunc,
+    KURTOSIS_DOUBLE,
+    kurtosis_double
+);
+
+make_udaf_function!(
+    learn_classifier_impl::learn_classifier_bigint_map_bigint_doubleFunc,
+    LEARN_CLASSIFIER_BIGINT_MAP_BIGINT_DOUBLE,
+    learn_classifier_bigint_map_bigint_double
+);
+make_udaf_function!(
+    learn_classifier_impl::learn_classifier_double_map_bigint_doubleFunc,
+    LEARN_CLASSIFIER_DOUBLE_MAP_BIGINT_DOUBLE,
+    learn_classifier_double_map_bigint_double
+);
+make_udaf_function!(
+    learn_classifier_impl::learn_classifier_varchar_map_bigint_doubleFunc,
+    LEARN_CLASSIFIER_VARCHAR_MAP_BIGINT_DOUBLE,
+    learn_classifier_varchar_map_bigint_double
+);
+
+make_udaf_function!(
+    learn_libsvm_classifier_impl::learn_libsvm_classifier_bigint_map_bigint_double_varcharFunc,
+    LEARN_LIBSVM_CLASSIFIER_BIGINT_MAP_BIGINT_DOUBLE_VARCHAR,
+    learn_libsvm_classifier_bigint_map_bigint_double_varchar
+);
+make_udaf_function!(
+    learn_libsvm_classifier_impl::learn_libsvm_classifier_double_map_bigint_double_varcharFunc,
+    LEARN_LIBSVM_CLASSIFIER_DOUBLE_MAP_BIGINT_DOUBLE_VARCHAR,
+    learn_libsvm_classifier_double_map_bigint_double_varchar
+);
+make_udaf_function!(
+    learn_libsvm_classifier_impl::learn_libsvm_classifier_varchar_map_bigint_double_varcharFunc,
+    LEARN_LIBSVM_CLASSIFIER_VARCHAR_MAP_BIGINT_DOUBLE_VARCHAR,
+    learn_libsvm_classifier_varchar_map_bigint_double_varchar
+);
+
+make_udaf_function!(
+    learn_libsvm_regressor_impl::learn_libsvm_regressor_bigint_map_bigint_double_varcharFunc,
+    LEARN_LIBSVM_REGRESSOR_BIGINT_MAP_BIGINT_DOUBLE_VARCHAR,
+    learn_libsvm_regressor_bigint_map_bigint_double_varchar
+);
+make_udaf_function!(
+    learn_libsvm_regressor_impl::learn_libsvm_regressor_double_map_bigint_double_varcharFunc,
+    LEARN_LIBSVM_REGRESSOR_DOUBLE_MAP_BIGINT_DOUBLE_VARCHAR,
+    learn_libsvm_regressor_double_map_bigint_double_varchar
+);
+
+make_udaf_function!(
+    learn_regressor_impl::learn_regressor_bigint_map_bigint_doubleFunc,
+    LEARN_REGRESSOR_BIGINT_MAP_BIGINT_DOUBLE,
+    learn_regressor_bigint_map_bigint_double
+);
+make_udaf_function!(
+    learn_regressor_impl::learn_regressor_double_map_bigint_doubleFunc,
+    LEARN_REGRESSOR_DOUBLE_MAP_BIGINT_DOUBLE,
+    learn_regressor_double_map_bigint_double
+);
+
+make_udaf_function!(
+    listagg_impl::listagg_varchar_varchar_boolean_varchar_booleanFunc,
+    LISTAGG_VARCHAR_VARCHAR_BOOLEAN_VARCHAR_BOOLEAN,
+    listagg_varchar_varchar_boolean_varchar_boolean
+);
+
+make_udaf_function!(
+    make_set_digest_impl::make_set_digest_1Func,
+    MAKE_SET_DIGEST_1,
+    make_set_digest_1
+);
+
+make_udaf_function!(map_agg_impl::map_agg_4_5Func, MAP_AGG_4_5, map_agg_4_5);
+
+make_udaf_function!(
+    map_union_impl::map_union_map_4_5Func,
+    MAP_UNION_MAP_4_5,
+    map_union_map_4_5
+);
+
+make_udaf_function!(max_impl::max_3_bigintFunc, MAX_3_BIGINT, max_3_bigint);
+make_udaf_function!(max_impl::max_1Func, MAX_1, max_1);
+
+make_udaf_function!(
+    max_by_impl::max_by_5_4_bigintFunc,
+    MAX_BY_5_4_BIGINT,
+    max_by_5_4_bigint
+);
+make_udaf_function!(max_by_impl::max_by_5_4Func, MAX_BY_5_4, max_by_5_4);
+
+make_udaf_function!(
+    merge_impl::merge_hyperloglogFunc,
+    MERGE_HYPERLOGLOG,
+    merge_hyperloglog
+);
+make_udaf_function!(merge_impl::merge_qdigestFunc, MERGE_QDIGEST, merge_qdigest);
+make_udaf_function!(merge_impl::merge_tdigestFunc, MERGE_TDIGEST, merge_tdigest);
+
+make_udaf_function!(
+    merge_set_digest_impl::merge_set_digest_setdigestFunc,
+    MERGE_SET_DIGEST_SETDIGEST,
+    merge_set_digest_setdigest
+);
+
+make_udaf_function!(min_impl::min_3_bigintFunc, MIN_3_BIGINT, min_3_bigint);
+make_udaf_function!(min_impl::min_1Func, MIN_1, min_1);
+
+make_udaf_function!(
+    min_by_impl::min_by_5_4_bigintFunc,
+    MIN_BY_5_4_BIGINT,
+    min_by_5_4_bigint
+);
+make_udaf_function!(min_by_impl::min_by_5_4Func, MIN_BY_5_4, min_by_5_4);
+
+make_udaf_function!(
+    multimap_agg_impl::multimap_agg_4_5Func,
+    MULTIMAP_AGG_4_5,
+    multimap_agg_4_5
+);
+
+make_udaf_function!(
+    numeric_histogram_impl::numeric_histogram_bigint_doubleFunc,
+    NUMERIC_HISTOGRAM_BIGINT_DOUBLE,
+    numeric_histogram_bigint_double
+);
+make_udaf_function!(
+    numeric_histogram_impl::numeric_histogram_bigint_double_doubleFunc,
+    NUMERIC_HISTOGRAM_BIGINT_DOUBLE_DOUBLE,
+    numeric_histogram_bigint_double_double
+);
+make_udaf_function!(
+    numeric_histogram_impl::numeric_histogram_bigint_realFunc,
+    NUMERIC_HISTOGRAM_BIGINT_REAL,
+    numeric_histogram_bigint_real
+);
+make_udaf_function!(
+    numeric_histogram_impl::numeric_histogram_bigint_real_doubleFunc,
+    NUMERIC_HISTOGRAM_BIGINT_REAL_DOUBLE,
+    numeric_histogram_bigint_real_double
+);
+
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_bigintFunc,
+    QDIGEST_AGG_BIGINT,
+    qdigest_agg_bigint
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_bigint_bigintFunc,
+    QDIGEST_AGG_BIGINT_BIGINT,
+    qdigest_agg_bigint_bigint
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_bigint_bigint_doubleFunc,
+    QDIGEST_AGG_BIGINT_BIGINT_DOUBLE,
+    qdigest_agg_bigint_bigint_double
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_doubleFunc,
+    QDIGEST_AGG_DOUBLE,
+    qdigest_agg_double
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_double_bigintFunc,
+    QDIGEST_AGG_DOUBLE_BIGINT,
+    qdigest_agg_double_bigint
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_double_bigint_doubleFunc,
+    QDIGEST_AGG_DOUBLE_BIGINT_DOUBLE,
+    qdigest_agg_double_bigint_double
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_realFunc,
+    QDIGEST_AGG_REAL,
+    qdigest_agg_real
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_real_bigintFunc,
+    QDIGEST_AGG_REAL_BIGINT,
+    qdigest_agg_real_bigint
+);
+make_udaf_function!(
+    qdigest_agg_impl::qdigest_agg_real_bigint_doubleFunc,
+    QDIGEST_AGG_REAL_BIGINT_DOUBLE,
+    qdigest_agg_real_bigint_double
+);
+
+make_udaf_function!(
+    reduce_agg_impl::reduce_agg_1_10_function_10_1_10_function_10_10_10Func,
+    REDUCE_AGG_1_10_FUNCTION_10_1_10_FUNCTION_10_10_10,
+    reduce_agg_1_10_function_10_1_10_function_10_10_10
+);
+
+make_udaf_function!(
+    regr_intercept_impl::regr_intercept_double_doubleFunc,
+    REGR_INTERCEPT_DOUBLE_DOUBLE,
+    regr_intercept_double_double
+);
+make_udaf_function!(
+    regr_intercept_impl::regr_intercept_real_realFunc,
+    REGR_INTERCEPT_REAL_REAL,
+    regr_intercept_real_real
+);
+
+make_udaf_function!(
+    regr_slope_impl::regr_slope_double_doubleFunc,
+    REGR_SLOPE_DOUBLE_DOUBLE,
+    regr_slope_double_double
+);
+make_udaf_function!(
+    regr_slope_impl::regr_slope_real_realFunc,
+    REGR_SLOPE_REAL_REAL,
+    regr_slope_real_real
+);
+
+make_udaf_function!(
+    skewness_impl::skewness_bigintFunc,
+    SKEWNESS_BIGINT,
+    skewness_bigint
+);
+make_udaf_function!(
+    skewness_impl::skewness_doubleFunc,
+    SKEWNESS_DOUBLE,
+    skewness_double
+);
+
+make_udaf_function!(
+    spatial_partitioning_impl::spatial_partitioning_geometryFunc,
+    SPATIAL_PARTITIONING_GEOMETRY,
+    spatial_partitioning_geometry
+);
+
+make_udaf_function!(stddev_impl::stddev_bigintFunc, STDDEV_BIGINT, stddev_bigint);
+make_udaf_function!(stddev_impl::stddev_doubleFunc, STDDEV_DOUBLE, stddev_double);
+
+make_udaf_function!(
+    stddev_pop_impl::stddev_pop_bigintFunc,
+    STDDEV_POP_BIGINT,
+    stddev_pop_bigint
+);
+make_udaf_function!(
+    stddev_pop_impl::stddev_pop_doubleFunc,
+    STDDEV_POP_DOUBLE,
+    stddev_pop_double
+);
+
+make_udaf_function!(
+    stddev_samp_impl::stddev_samp_bigintFunc,
+    STDDEV_SAMP_BIGINT,
+    stddev_samp_bigint
+);
+make_udaf_function!(
+    stddev_samp_impl::stddev_samp_doubleFunc,
+    STDDEV_SAMP_DOUBLE,
+    stddev_samp_double
+);
+
+make_udaf_function!(sum_impl::sum_bigintFunc, SUM_BIGINT, sum_bigint);
+make_udaf_function!(sum_impl::sum_doubleFunc, SUM_DOUBLE, sum_double);
+make_udaf_function!(
+    sum_impl::sum_decimal_p_sFunc,
+    SUM_DECIMAL_P_S,
+    sum_decimal_p_s
+);
+make_udaf_function!(sum_impl::sum_realFunc, SUM_REAL, sum_real);
+make_udaf_function!(
+    sum_impl::sum_intervaldaytosecondFunc,
+    SUM_INTERVALDAYTOSECOND,
+    sum_intervaldaytosecond
+);
+make_udaf_function!(
+    sum_impl::sum_intervalyeartomonthFunc,
+    SUM_INTERVALYEARTOMONTH,
+    sum_intervalyeartomonth
+);
+
+make_udaf_function!(
+    tdigest_agg_impl::tdigest_agg_doubleFunc,
+    TDIGEST_AGG_DOUBLE,
+    tdigest_agg_double
+);
+make_udaf_function!(
+    tdigest_agg_impl::tdigest_agg_double_doubleFunc,
+    TDIGEST_AGG_DOUBLE_DOUBLE,
+    tdigest_agg_double_double
+);
+
+make_udaf_function!(
+    var_pop_impl::var_pop_bigintFunc,
+    VAR_POP_BIGINT,
+    var_pop_bigint
+);
+make_udaf_function!(
+    var_pop_impl::var_pop_doubleFunc,
+    VAR_POP_DOUBLE,
+    var_pop_double
+);
+
+make_udaf_function!(
+    var_samp_impl::var_samp_bigintFunc,
+    VAR_SAMP_BIGINT,
+    var_samp_bigint
+);
+make_udaf_function!(
+    var_samp_impl::var_samp_doubleFunc,
+    VAR_SAMP_DOUBLE,
+    var_samp_double
+);
+
+make_udaf_function!(
+    variance_impl::variance_bigintFunc,
+    VARIANCE_BIGINT,
+    variance_bigint
+);
+make_udaf_function!(
+    variance_impl::variance_doubleFunc,
+    VARIANCE_DOUBLE,
+    variance_double
+);
+
+// create UDWFs
+make_udwf_function!(cume_dist_impl::cume_distFunc, CUME_DIST, cume_dist);
+
+make_udwf_function!(dense_rank_impl::dense_rankFunc, DENSE_RANK, dense_rank);
+
+make_udwf_function!(
+    first_value_impl::first_value_1Func,
+    FIRST_VALUE_1,
+    first_value_1
+);
+
+make_udwf_function!(lag_impl::lag_1Func, LAG_1, lag_1);
+make_udwf_function!(lag_impl::lag_1_bigintFunc, LAG_1_BIGINT, lag_1_bigint);
+make_udwf_function!(lag_impl::lag_1_bigint_1Func, LAG_1_BIGINT_1, lag_1_bigint_1);
+
+make_udwf_function!(
+    last_value_impl::last_value_1Func,
+    LAST_VALUE_1,
+    last_value_1
+);
+
+make_udwf_function!(lead_impl::lead_1Func, LEAD_1, lead_1);
+make_udwf_function!(lead_impl::lead_1_bigintFunc, LEAD_1_BIGINT, lead_1_bigint);
+make_udwf_function!(
+    lead_impl::lead_1_bigint_1Func,
+    LEAD_1_BIGINT_1,
+    lead_1_bigint_1
+);
+
+make_udwf_function!(
+    nth_value_impl::nth_value_1_bigintFunc,
+    NTH_VALUE_1_BIGINT,
+    nth_value_1_bigint
+);
+
+make_udwf_function!(ntile_impl::ntile_bigintFunc, NTILE_BIGINT, ntile_bigint);
+
+make_udwf_function!(
+    percent_rank_impl::percent_rankFunc,
+    PERCENT_RANK,
+    percent_rank
+);
+
+make_udwf_function!(rank_impl::rankFunc, RANK, rank);
+
+make_udwf_function!(row_number_impl::row_numberFunc, ROW_NUMBER, row_number);
+
 // Export the functions out of this package, both as expr_fn as well as a list of functions
 export_functions!(
-    (trino, abs_tinyint, arg1, "function doc"),
-    (trino, abs_smallint, arg1, "function doc"),
-    (trino, abs_bigint, arg1, "function doc"),
-    (trino, abs_double, arg1, "function doc"),
-    (trino, abs_decimal_p_s, arg1, "function doc"),
-    (trino, abs_real, arg1, "function doc"),
+    (trino, abs_tinyint, arg1, ["tinyint"], "Returns the absolute value of x.\n\n`abs(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+    (trino, abs_smallint, arg1, ["smallint"], "Returns the absolute value of x.\n\n`abs(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+    (trino, abs_bigint, arg1, ["bigint"], "Returns the absolute value of x.\n\n`abs(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+    (trino, abs_double, arg1, ["double"], "Returns the absolute value of x.\n\n`abs(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+    (trino, abs_decimal_p_s, arg1, ["decimal(p, s)"], "Returns the absolute value of x.\n\n`abs(decimal(p, s)) -> decimal(p, s)`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+    (trino, abs_real, arg1, ["real"], "Returns the absolute value of x.\n\n`abs(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#abs>"),
+
+    (trino, acos_double, arg1, ["double"], "Returns the arc cosine of x.\n\n`acos(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#acos>"),
 
-    (trino, acos_double, arg1, "function doc"),
+    (trino, all_match_array_1_function_1_boolean, arg1 arg2, ["array<$1>", "function($1, boolean)"], "Returns whether all elements of an array match the given predicate. Returns true if all the elements match the predicate (a special case is when the array is empty); false if one or more elements don’t match; NULL if the predicate function returns NULL for one or more elements and true for all other elements.\n\n`all_match(array<$1>, function($1, boolean)) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#all_match>"),
 
-    (trino, all_match_array_1_function_1_boolean, arg1 arg2, "function doc"),
+    (trino, any_match_array_1_function_1_boolean, arg1 arg2, ["array<$1>", "function($1, boolean)"], "Returns whether any elements of an array match the given predicate. Returns true if one or more elements match the predicate; false if none of the elements matches (a special case is when the array is empty); NULL if the predicate function returns NULL for one or more elements and false for all other elements.\n\n`any_match(array<$1>, function($1, boolean)) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#any_match>"),
 
-    (trino, any_match_array_1_function_1_boolean, arg1 arg2, "function doc"),
+    (trino, array_distinct_array_3, arg1, ["array<$3>"], "Remove duplicate values from the array x.\n\n`array_distinct(array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_distinct>"),
 
-    (trino, array_distinct_array_3, arg1, "function doc"),
+    (trino, array_except_array_3_array_3, arg1 arg2, ["array<$3>", "array<$3>"], "Returns an array of elements in x but not in y, without duplicates.\n\n`array_except(array<$3>, array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_except>"),
 
-    (trino, array_except_array_3_array_3, arg1 arg2, "function doc"),
+    (trino, array_intersect_array_3_array_3, arg1 arg2, ["array<$3>", "array<$3>"], "Returns an array of the elements in the intersection of x and y, without duplicates.\n\n`array_intersect(array<$3>, array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_intersect>"),
 
-    (trino, array_intersect_array_3_array_3, arg1 arg2, "function doc"),
+    (trino, array_join_array_1_varchar, arg1 arg2, ["array<$1>", "varchar"], "Concatenates the elements of the given array using the delimiter. Null elements are omitted in the result.\n\n`array_join(array<$1>, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/array.html#array_join>"),
+    (trino, array_join_array_1_varchar_varchar, arg1 arg2 arg3, ["array<$1>", "varchar", "varchar"], "Concatenates the elements of the given array using the delimiter. Null elements are omitted in the result.\n\n`array_join(array<$1>, varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/array.html#array_join>"),
 
-    (trino, array_join_array_1_varchar, arg1 arg2, "function doc"),
-    (trino, array_join_array_1_varchar_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, array_max_array_1, arg1, ["array<$1>"], "Returns the maximum value of input array.\n\n`array_max(array<$1>) -> $1`\n\nSee <https://trino.io/docs/current/functions/array.html#array_max>"),
 
-    (trino, array_max_array_1, arg1, "function doc"),
+    (trino, array_min_array_1, arg1, ["array<$1>"], "Returns the minimum value of input array.\n\n`array_min(array<$1>) -> $1`\n\nSee <https://trino.io/docs/current/functions/array.html#array_min>"),
 
-    (trino, array_min_array_1, arg1, "function doc"),
+    (trino, array_position_array_1_1, arg1 arg2, ["array<$1>", "$1"], "Returns the position of the first occurrence of the element in array x (or 0 if not found).\n\n`array_position(array<$1>, $1) -> bigint`\n\nSee <https://trino.io/docs/current/functions/array.html#array_position>"),
 
-    (trino, array_position_array_1_1, arg1 arg2, "function doc"),
+    (trino, array_remove_array_3_3, arg1 arg2, ["array<$3>", "$3"], "Remove all elements that equal element from array x.\n\n`array_remove(array<$3>, $3) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_remove>"),
 
-    (trino, array_remove_array_3_3, arg1 arg2, "function doc"),
+    (trino, array_sort_array_3, arg1, ["array<$3>"], "Sorts and returns the array x. The elements of x must be orderable. Null elements will be placed at the end of the returned array.\n\n`array_sort(array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_sort>"),
+    (trino, array_sort_array_1_function_1_1_bigint, arg1 arg2, ["array<$1>", "function($1, $1, bigint)"], "Sorts and returns the array x. The elements of x must be orderable. Null elements will be placed at the end of the returned array.\n\n`array_sort(array<$1>, function($1, $1, bigint)) -> array<$1>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_sort>"),
 
-    (trino, array_sort_array_3, arg1, "function doc"),
-    (trino, array_sort_array_1_function_1_1_bigint, arg1 arg2, "function doc"),
+    (trino, array_union_array_3_array_3, arg1 arg2, ["array<$3>", "array<$3>"], "Returns an array of the elements in the union of x and y, without duplicates.\n\n`array_union(array<$3>, array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#array_union>"),
 
-    (trino, array_union_array_3_array_3, arg1 arg2, "function doc"),
+    (trino, arrays_overlap_array_3_array_3, arg1 arg2, ["array<$3>", "array<$3>"], "Tests if arrays x and y have any non-null elements in common. Returns null if there are no non-null elements in common but either array contains null.\n\n`arrays_overlap(array<$3>, array<$3>) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#arrays_overlap>"),
 
-    (trino, arrays_overlap_array_3_array_3, arg1 arg2, "function doc"),
+    (trino, asin_double, arg1, ["double"], "Returns the arc sine of x.\n\n`asin(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#asin>"),
 
-    (trino, asin_double, arg1, "function doc"),
+    (trino, at_timezone_timestamp_p_withtimezone_varchar, arg1 arg2, ["timestamp(p) with time zone", "varchar"], "Returns the timestamp specified in timestamp with the time zone converted from the session time zone to the time zone specified in zone with precision p. In the following example, the session time zone is set to America/New_York, which is three hours ahead of America/Los_Angeles.\n\n`at_timezone(timestamp(p) with time zone, varchar) -> timestamp(p) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#at_timezone>"),
 
-    (trino, at_timezone_timestamp_p_varchar, arg1 arg2, "function doc"),
+    (trino, atan_double, arg1, ["double"], "Returns the arc tangent of x.\n\n`atan(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#atan>"),
 
-    (trino, atan_double, arg1, "function doc"),
+    (trino, atan2_double_double, arg1 arg2, ["double", "double"], "Returns the arc tangent of y / x.\n\n`atan2(double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#atan2>"),
 
-    (trino, atan2_double_double, arg1 arg2, "function doc"),
+    (trino, bar_double_bigint, arg1 arg2, ["double", "bigint"], "Renders a single bar in an ANSI bar chart using a default low_color of red and a high_color of green.  For example, if x of 25% and width of 40 are passed to this function. A 10-character red bar will be drawn followed by 30 spaces to create a bar of 40 characters.\n\n`bar(double, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#bar>"),
+    (trino, bar_double_bigint_color_color, arg1 arg2 arg3 arg4, ["double", "bigint", "color", "color"], "Renders a single bar in an ANSI bar chart using a default low_color of red and a high_color of green.  For example, if x of 25% and width of 40 are passed to this function. A 10-character red bar will be drawn followed by 30 spaces to create a bar of 40 characters.\n\n`bar(double, bigint, color, color) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#bar>"),
 
-    (trino, bar_double_bigint, arg1 arg2, "function doc"),
-    (trino, bar_double_bigint_color_color, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, beta_cdf_double_double_double, arg1 arg2 arg3, ["double", "double", "double"], "Compute the Beta cdf with given a, b parameters.  P(N < v; a, b). The a, b parameters must be positive real numbers and value v must be a real value. The value v must lie on the interval [0, 1].\n\n`beta_cdf(double, double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#beta_cdf>"),
 
-    (trino, beta_cdf_double_double_double, arg1 arg2 arg3, "function doc"),
+    (trino, bing_tile_bigint_bigint_bigint, arg1 arg2 arg3, ["bigint", "bigint", "bigint"], "Creates a Bing tile object from XY coordinates and a zoom level. Zoom levels from 1 to 23 are supported.\n\n`bing_tile(bigint, bigint, bigint) -> bingtile`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile>"),
+    (trino, bing_tile_varchar, arg1, ["varchar"], "Creates a Bing tile object from XY coordinates and a zoom level. Zoom levels from 1 to 23 are supported.\n\n`bing_tile(varchar) -> bingtile`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile>"),
 
-    (trino, bing_tile_bigint_bigint_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, bing_tile_varchar, arg1, "function doc"),
+    (trino, bing_tile_at_double_double_bigint, arg1 arg2 arg3, ["double", "double", "bigint"], "Returns a Bing tile at a given zoom level containing a point at a given latitude and longitude. Latitude must be within [-85.05112878, 85.05112878] range. Longitude must be within [-180, 180] range. Zoom levels from 1 to 23 are supported.\n\n`bing_tile_at(double, double, bigint) -> bingtile`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile_at>"),
 
-    (trino, bing_tile_at_double_double_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, bing_tile_coordinates_bingtile, arg1, ["bingtile"], "Returns the XY coordinates of a given Bing tile.\n\n`bing_tile_coordinates(bingtile) -> row(x bigint, y bigint)`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile_coordinates>"),
 
-    (trino, bing_tile_coordinates_bingtile, arg1, "function doc"),
+    (trino, bing_tile_polygon_bingtile, arg1, ["bingtile"], "Returns the polygon representation of a given Bing tile.\n\n`bing_tile_polygon(bingtile) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile_polygon>"),
 
-    (trino, bing_tile_polygon_bingtile, arg1, "function doc"),
+    (trino, bing_tile_quadkey_bingtile, arg1, ["bingtile"], "Returns the quadkey of a given Bing tile.\n\n`bing_tile_quadkey(bingtile) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile_quadkey>"),
 
-    (trino, bing_tile_quadkey_bingtile, arg1, "function doc"),
+    (trino, bing_tile_zoom_level_bingtile, arg1, ["bingtile"], "Returns the zoom level of a given Bing tile.\n\n`bing_tile_zoom_level(bingtile) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tile_zoom_level>"),
 
-    (trino, bing_tile_zoom_level_bingtile, arg1, "function doc"),
+    (trino, bing_tiles_around_double_double_bigint, arg1 arg2 arg3, ["double", "double", "bigint"], "Returns a collection of Bing tiles that surround the point specified by the latitude and longitude arguments at a given zoom level.\n\n`bing_tiles_around(double, double, bigint) -> array<bingtile>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tiles_around>"),
+    (trino, bing_tiles_around_double_double_bigint_double, arg1 arg2 arg3 arg4, ["double", "double", "bigint", "double"], "Returns a collection of Bing tiles that surround the point specified by the latitude and longitude arguments at a given zoom level.\n\n`bing_tiles_around(double, double, bigint, double) -> array<bingtile>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#bing_tiles_around>"),
 
-    (trino, bing_tiles_around_double_double_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, bing_tiles_around_double_double_bigint_double, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, bit_count_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Count the number of bits set in x (treated as bits-bit signed integer) in 2’s complement representation.\n\n`bit_count(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bit_count>"),
 
-    (trino, bit_count_bigint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_and_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the bitwise AND of x and y in 2’s complement representation.\n\n`bitwise_and(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_and>"),
 
-    (trino, bitwise_and_bigint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_left_shift_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the left shifted value of value.\n\n`bitwise_left_shift(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_left_shift>"),
+    (trino, bitwise_left_shift_integer_bigint, arg1 arg2, ["integer", "bigint"], "Returns the left shifted value of value.\n\n`bitwise_left_shift(integer, bigint) -> integer`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_left_shift>"),
+    (trino, bitwise_left_shift_smallint_bigint, arg1 arg2, ["smallint", "bigint"], "Returns the left shifted value of value.\n\n`bitwise_left_shift(smallint, bigint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_left_shift>"),
+    (trino, bitwise_left_shift_tinyint_bigint, arg1 arg2, ["tinyint", "bigint"], "Returns the left shifted value of value.\n\n`bitwise_left_shift(tinyint, bigint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_left_shift>"),
 
-    (trino, bitwise_left_shift_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_left_shift_integer_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_left_shift_smallint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_left_shift_tinyint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_not_bigint, arg1, ["bigint"], "Returns the bitwise NOT of x in 2’s complement representation (NOT x = -x - 1).\n\n`bitwise_not(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_not>"),
 
-    (trino, bitwise_not_bigint, arg1, "function doc"),
+    (trino, bitwise_or_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the bitwise OR of x and y in 2’s complement representation.\n\n`bitwise_or(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_or>"),
 
-    (trino, bitwise_or_bigint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_right_shift_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the logical right shifted value of value.\n\n`bitwise_right_shift(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift>"),
+    (trino, bitwise_right_shift_integer_bigint, arg1 arg2, ["integer", "bigint"], "Returns the logical right shifted value of value.\n\n`bitwise_right_shift(integer, bigint) -> integer`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift>"),
+    (trino, bitwise_right_shift_smallint_bigint, arg1 arg2, ["smallint", "bigint"], "Returns the logical right shifted value of value.\n\n`bitwise_right_shift(smallint, bigint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift>"),
+    (trino, bitwise_right_shift_tinyint_bigint, arg1 arg2, ["tinyint", "bigint"], "Returns the logical right shifted value of value.\n\n`bitwise_right_shift(tinyint, bigint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift>"),
 
-    (trino, bitwise_right_shift_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_integer_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_smallint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_tinyint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_right_shift_arithmetic_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the arithmetic right shifted value of value.\n\n`bitwise_right_shift_arithmetic(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift_arithmetic>"),
+    (trino, bitwise_right_shift_arithmetic_integer_bigint, arg1 arg2, ["integer", "bigint"], "Returns the arithmetic right shifted value of value.\n\n`bitwise_right_shift_arithmetic(integer, bigint) -> integer`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift_arithmetic>"),
+    (trino, bitwise_right_shift_arithmetic_smallint_bigint, arg1 arg2, ["smallint", "bigint"], "Returns the arithmetic right shifted value of value.\n\n`bitwise_right_shift_arithmetic(smallint, bigint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift_arithmetic>"),
+    (trino, bitwise_right_shift_arithmetic_tinyint_bigint, arg1 arg2, ["tinyint", "bigint"], "Returns the arithmetic right shifted value of value.\n\n`bitwise_right_shift_arithmetic(tinyint, bigint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_right_shift_arithmetic>"),
 
-    (trino, bitwise_right_shift_arithmetic_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_arithmetic_integer_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_arithmetic_smallint_bigint, arg1 arg2, "function doc"),
-    (trino, bitwise_right_shift_arithmetic_tinyint_bigint, arg1 arg2, "function doc"),
+    (trino, bitwise_xor_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the bitwise XOR of x and y in 2’s complement representation.\n\n`bitwise_xor(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/bitwise.html#bitwise_xor>"),
 
-    (trino, bitwise_xor_bigint_bigint, arg1 arg2, "function doc"),
+    (trino, cardinality_array_3, arg1, ["array<$3>"], "Returns the cardinality (size) of the array x.\n\n`cardinality(array<$3>) -> bigint`\n\nSee <https://trino.io/docs/current/functions/array.html#cardinality>"),
+    (trino, cardinality_hyperloglog, arg1, ["hyperloglog"], "Returns the cardinality (size) of the array x.\n\n`cardinality(hyperloglog) -> bigint`\n\nSee <https://trino.io/docs/current/functions/array.html#cardinality>"),
+    (trino, cardinality_map_4_5, arg1, ["map<$4, $5>"], "Returns the cardinality (size) of the array x.\n\n`cardinality(map<$4, $5>) -> bigint`\n\nSee <https://trino.io/docs/current/functions/array.html#cardinality>"),
+    (trino, cardinality_setdigest, arg1, ["setdigest"], "Returns the cardinality (size) of the array x.\n\n`cardinality(setdigest) -> bigint`\n\nSee <https://trino.io/docs/current/functions/array.html#cardinality>"),
 
-    (trino, cardinality_array_3, arg1, "function doc"),
-    (trino, cardinality_hyperloglog, arg1, "function doc"),
-    (trino, cardinality_map_4_5, arg1, "function doc"),
-    (trino, cardinality_setdigest, arg1, "function doc"),
+    (trino, cast_1_varchar, arg1 arg2, ["$1", "varchar"], "Explicitly cast a value as a type, given by its name, e.g. `decimal(10,2)`. Fails if the value cannot be cast.\n\n`cast($1, varchar) -> unknown`\n\nSee <https://trino.io/docs/current/functions/conversion.html#cast>"),
 
-    (trino, cbrt_double, arg1, "function doc"),
+    (trino, cbrt_double, arg1, ["double"], "Returns the cube root of x.\n\n`cbrt(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#cbrt>"),
 
-    (trino, ceil_bigint, arg1, "function doc"),
-    (trino, ceil_decimal_p_s, arg1, "function doc"),
-    (trino, ceil_double, arg1, "function doc"),
-    (trino, ceil_integer, arg1, "function doc"),
-    (trino, ceil_real, arg1, "function doc"),
-    (trino, ceil_smallint, arg1, "function doc"),
-    (trino, ceil_tinyint, arg1, "function doc"),
+    (trino, ceil_bigint, arg1, ["bigint"], "This is an alias for ceiling().\n\n`ceil(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_decimal_p_s, arg1, ["decimal(p, s)"], "This is an alias for ceiling().\n\n`ceil(decimal(p, s)) -> decimal(rp, 0)`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_double, arg1, ["double"], "This is an alias for ceiling().\n\n`ceil(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_integer, arg1, ["integer"], "This is an alias for ceiling().\n\n`ceil(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_real, arg1, ["real"], "This is an alias for ceiling().\n\n`ceil(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_smallint, arg1, ["smallint"], "This is an alias for ceiling().\n\n`ceil(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
+    (trino, ceil_tinyint, arg1, ["tinyint"], "This is an alias for ceiling().\n\n`ceil(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceil>"),
 
-    (trino, ceiling_bigint, arg1, "function doc"),
-    (trino, ceiling_decimal_p_s, arg1, "function doc"),
-    (trino, ceiling_double, arg1, "function doc"),
-    (trino, ceiling_integer, arg1, "function doc"),
-    (trino, ceiling_real, arg1, "function doc"),
-    (trino, ceiling_smallint, arg1, "function doc"),
-    (trino, ceiling_tinyint, arg1, "function doc"),
+    (trino, ceiling_bigint, arg1, ["bigint"], "Returns x rounded up to the nearest integer.\n\n`ceiling(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_decimal_p_s, arg1, ["decimal(p, s)"], "Returns x rounded up to the nearest integer.\n\n`ceiling(decimal(p, s)) -> decimal(rp, 0)`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_double, arg1, ["double"], "Returns x rounded up to the nearest integer.\n\n`ceiling(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_integer, arg1, ["integer"], "Returns x rounded up to the nearest integer.\n\n`ceiling(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_real, arg1, ["real"], "Returns x rounded up to the nearest integer.\n\n`ceiling(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_smallint, arg1, ["smallint"], "Returns x rounded up to the nearest integer.\n\n`ceiling(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
+    (trino, ceiling_tinyint, arg1, ["tinyint"], "Returns x rounded up to the nearest integer.\n\n`ceiling(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#ceiling>"),
 
-    (trino, char2hexint_varchar, arg1, "function doc"),
+    (trino, char2hexint_varchar, arg1, ["varchar"], "Returns the hexadecimal representation of the UTF-16BE encoding of the string.\n\n`char2hexint(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/teradata.html#char2hexint>"),
 
-    (trino, chr_bigint, arg1, "function doc"),
+    (trino, chr_bigint, arg1, ["bigint"], "Returns the Unicode code point n as a single character string.\n\n`chr(bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#chr>"),
 
-    (trino, classify_map_bigint_double_classifier, arg1 arg2, "function doc"),
+    (trino, classify_map_bigint_double_classifier, arg1 arg2, ["map<bigint, double>", "classifier"], "Returns a label predicted by the given classifier SVM model.\n\n`classify(map<bigint, double>, classifier) -> bigint`\n\nSee <https://trino.io/docs/current/functions/ml.html#classify>"),
 
-    (trino, coalesce_1, arg1, "function doc"),
+    (trino, coalesce_1, arg1, variadic ["$1"], "Returns the first non-null value in the argument list. Like a CASE expression, arguments are only evaluated if necessary.\n\n`coalesce($1, ...) -> $1`\n\nSee <https://trino.io/docs/current/functions/conditional.html#coalesce-function>"),
 
-    (trino, codepoint_varchar, arg1, "function doc"),
+    (trino, codepoint_varchar, arg1, ["varchar"], "Returns the Unicode code point of the only character of `string`.\n\n`codepoint(varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#codepoint>"),
 
-    (trino, color_double_color_color, arg1 arg2 arg3, "function doc"),
-    (trino, color_double_double_double_color_color, arg1 arg2 arg3 arg4 arg5, "function doc"),
-    (trino, color_varchar, arg1, "function doc"),
+    (trino, color_double_color_color, arg1 arg2 arg3, ["double", "color", "color"], "Returns a color capturing a decoded RGB value from a 4-character string of the format “#000”.  The input string should be varchar containing a CSS-style short rgb string or one of black, red, green, yellow, blue, magenta, cyan, white.\n\n`color(double, color, color) -> color`\n\nSee <https://trino.io/docs/current/functions/color.html#color>"),
+    (trino, color_double_double_double_color_color, arg1 arg2 arg3 arg4 arg5, ["double", "double", "double", "color", "color"], "Returns a color capturing a decoded RGB value from a 4-character string of the format “#000”.  The input string should be varchar containing a CSS-style short rgb string or one of black, red, green, yellow, blue, magenta, cyan, white.\n\n`color(double, double, double, color, color) -> color`\n\nSee <https://trino.io/docs/current/functions/color.html#color>"),
+    (trino, color_varchar, arg1, ["varchar"], "Returns a color capturing a decoded RGB value from a 4-character string of the format “#000”.  The input string should be varchar containing a CSS-style short rgb string or one of black, red, green, yellow, blue, magenta, cyan, white.\n\n`color(varchar) -> color`\n\nSee <https://trino.io/docs/current/functions/color.html#color>"),
 
-    (trino, combinations_array_1_bigint, arg1 arg2, "function doc"),
+    (trino, combinations_array_1_bigint, arg1 arg2, ["array<$1>", "bigint"], "Returns n-element sub-groups of input array. If the input array has no duplicates, combinations returns n-element subsets.\n\n`combinations(array<$1>, bigint) -> array<array<$1>>`\n\nSee <https://trino.io/docs/current/functions/array.html#combinations>"),
 
-    (trino, concat_3_array_3, arg1 arg2, "function doc"),
-    (trino, concat_array_3, arg1, "function doc"),
-    (trino, concat_array_3_3, arg1 arg2, "function doc"),
-    (trino, concat_varchar_varchar, arg1 arg2, "function doc"),
-    (trino, concat_varchar, arg1, "function doc"),
-    (trino, concat_varbinary, arg1, "function doc"),
+    (trino, concat_3_array_3, arg1 arg2, ["$3", "array<$3>"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat($3, array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
+    (trino, concat_array_3, arg1, variadic ["array<$3>"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat(array<$3>, ...) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
+    (trino, concat_array_3_3, arg1 arg2, ["array<$3>", "$3"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat(array<$3>, $3) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
+    (trino, concat_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat(varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
+    (trino, concat_varchar, arg1, variadic ["varchar"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat(varchar, ...) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
+    (trino, concat_varbinary, arg1, variadic ["varbinary"], "Returns the concatenation of string1, string2, ..., stringN. This function provides the same functionality as the SQL-standard concatenation operator (||).\n\n`concat(varbinary, ...) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#concat>"),
 
-    (trino, concat_ws_varchar_array_varchar, arg1 arg2, "function doc"),
-    (trino, concat_ws_varchar, arg1, "function doc"),
+    (trino, concat_ws_varchar_array_varchar, arg1 arg2, ["varchar", "array<varchar>"], "Using the first array `string0` element as separator, returns the concatenation of all subsequent strings string1, string2, ... If `string0`` is null, then the return value is null. Any null values provided in the arguments  after the separator are skipped.\n\n`concat_ws(varchar, array<varchar>) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#concat_ws>"),
+    (trino, concat_ws_varchar, arg1, variadic ["varchar"], "Returns the concatenation of string1, string2, ..., stringN using string0 as a separator. If string0 is null, then the return value is null. Any null values provided in the arguments after the separator are skipped.\n\n`concat_ws(varchar, ...) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#concat_ws>"),
 
-    (trino, contains_array_1_1, arg1 arg2, "function doc"),
-    (trino, contains_varchar_ipaddress, arg1 arg2, "function doc"),
+    (trino, contains_array_1_1, arg1 arg2, ["array<$1>", "$1"], "Takes an array and an element. Returns true if the array contains the element, false if not.\n\n`contains(array<$1>, $1) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#contains>"),
+    (trino, contains_varchar_ipaddress, arg1 arg2, ["varchar", "ipaddress"], "Returns true if the array x contains the element.\n\n`contains(varchar, ipaddress) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#contains>"),
 
-    (trino, contains_sequence_array_1_array_1, arg1 arg2, "function doc"),
+    (trino, contains_sequence_array_1_array_1, arg1 arg2, ["array<$1>", "array<$1>"], "Return true if array x contains all of array seq as a subsequence (all values in the same consecutive order).\n\n`contains_sequence(array<$1>, array<$1>) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#contains_sequence>"),
 
-    (trino, cos_double, arg1, "function doc"),
+    (trino, cos_double, arg1, ["double"], "Returns the cosine of x.\n\n`cos(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#cos>"),
 
-    (trino, cosh_double, arg1, "function doc"),
+    (trino, cosh_double, arg1, ["double"], "Returns the hyperbolic cosine of the given value.\n\n`cosh(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#cosh>"),
 
-    (trino, cosine_similarity_map_varchar_double_map_varchar_double, arg1 arg2, "function doc"),
+    (trino, cosine_similarity_map_varchar_double_map_varchar_double, arg1 arg2, ["map<varchar, double>", "map<varchar, double>"], "Returns the cosine similarity between the sparse vectors x and y.\n\n`cosine_similarity(map<varchar, double>, map<varchar, double>) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#cosine_similarity>"),
 
-    (trino, crc32_varbinary, arg1, "function doc"),
+    (trino, crc32_varbinary, arg1, ["varbinary"], "Computes the CRC-32 of binary. For general purpose hashing, use xxhash64(), as it is much faster and produces a better quality hash.\n\n`crc32(varbinary) -> bigint`\n\nSee <https://trino.io/docs/current/functions/binary.html#crc32>"),
 
-    (trino, current_catalog, , "function doc"),
+    (trino, current_catalog, , [], "Returns a character string that represents the current catalog name.\n\n`current_catalog() -> varchar`\n\nSee <https://trino.io/docs/current/functions/session.html#current_catalog>"),
 
-    (trino, current_date, , "function doc"),
+    (trino, current_date, , [], "Returns the current date as of the start of the query.\n\n`current_date() -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_date>"),
 
-    (trino, current_groups, , "function doc"),
+    (trino, current_groups, , [], "Returns the list of groups for the current user running the query.\n\n`current_groups() -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/session.html#current_groups>"),
 
-    (trino, current_schema, , "function doc"),
+    (trino, current_schema, , [], "Returns a character string that represents the current unqualified schema name.\n\n`current_schema() -> varchar`\n\nSee <https://trino.io/docs/current/functions/session.html#current_schema>"),
 
-    (trino, current_time, , "function doc"),
+    (trino, current_time, , [], "Returns the current time with time zone as of the start of the query.\n\n`current_time() -> time`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_time>"),
 
-    (trino, current_timestamp, , "function doc"),
-    (trino, current_timestamp_bigint_0, arg1, "function doc"),
-    (trino, current_timestamp_bigint_3, arg1, "function doc"),
-    (trino, current_timestamp_bigint_6, arg1, "function doc"),
-    (trino, current_timestamp_bigint_9, arg1, "function doc"),
+    (trino, current_timestamp, , [], "Returns the current timestamp with time zone as of the start of the query, with 3 digits of subsecond precision,\n\n`current_timestamp() -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timestamp>"),
+    (trino, current_timestamp_bigint_0, arg1, ["bigint"], "Returns the current timestamp with time zone as of the start of the query, with 3 digits of subsecond precision,\n\n`current_timestamp(bigint) -> timestamp(0)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timestamp>"),
+    (trino, current_timestamp_bigint_3, arg1, ["bigint"], "Returns the current timestamp with time zone as of the start of the query, with 3 digits of subsecond precision,\n\n`current_timestamp(bigint) -> timestamp(3)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timestamp>"),
+    (trino, current_timestamp_bigint_6, arg1, ["bigint"], "Returns the current timestamp with time zone as of the start of the query, with 3 digits of subsecond precision,\n\n`current_timestamp(bigint) -> timestamp(6)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timestamp>"),
+    (trino, current_timestamp_bigint_9, arg1, ["bigint"], "Returns the current timestamp with time zone as of the start of the query, with 3 digits of subsecond precision,\n\n`current_timestamp(bigint) -> timestamp(9)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timestamp>"),
 
-    (trino, current_timezone, , "function doc"),
+    (trino, current_timezone, , [], "Returns the current time zone in the format defined by IANA (e.g., America/Los_Angeles) or as fixed offset from UTC (e.g., +08.35)\n\n`current_timezone() -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#current_timezone>"),
 
-    (trino, current_user, , "function doc"),
+    (trino, current_user, , [], "Returns the current user running the query.\n\n`current_user() -> varchar`\n\nSee <https://trino.io/docs/current/functions/session.html#current_user>"),
 
-    (trino, date_timestamp_p, arg1, "function doc"),
-    (trino, date_varchar, arg1, "function doc"),
+    (trino, date_timestamp_p, arg1, ["timestamp(p)"], "This `date(x)` is an alias for `CAST(x AS date)`.\n\n`date(timestamp(p)) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date>"),
+    (trino, date_varchar, arg1, ["varchar"], "This is an alias for CAST(x AS date).\n\n`date(varchar) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date>"),
 
-    (trino, date_add_varchar_bigint_date, arg1 arg2 arg3, "function doc"),
-    (trino, date_add_varchar_bigint_time_p, arg1 arg2 arg3, "function doc"),
-    (trino, date_add_varchar_bigint_timestamp_p, arg1 arg2 arg3, "function doc"),
+    (trino, date_add_varchar_bigint_date, arg1 arg2 arg3, ["varchar", "bigint", "date"], "Adds an interval value of type unit to timestamp. Subtraction can be performed by using a negative value.\n\n`date_add(varchar, bigint, date) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_add>"),
+    (trino, date_add_varchar_bigint_time_p, arg1 arg2 arg3, ["varchar", "bigint", "time(p)"], "Adds an interval value of type unit to timestamp. Subtraction can be performed by using a negative value.\n\n`date_add(varchar, bigint, time(p)) -> time(p)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_add>"),
+    (trino, date_add_varchar_bigint_timestamp_p, arg1 arg2 arg3, ["varchar", "bigint", "timestamp(p)"], "Adds an interval value of type unit to timestamp. Subtraction can be performed by using a negative value.\n\n`date_add(varchar, bigint, timestamp(p)) -> timestamp(p)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_add>"),
+    (trino, date_add_varchar_bigint_timestamp_p_withtimezone, arg1 arg2 arg3, ["varchar", "bigint", "timestamp(p) with time zone"], "Adds an interval value of type unit to timestamp. Subtraction can be performed by using a negative value.\n\n`date_add(varchar, bigint, timestamp(p) with time zone) -> timestamp(p) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_add>"),
 
-    (trino, date_diff_varchar_date_date, arg1 arg2 arg3, "function doc"),
-    (trino, date_diff_varchar_time_p_time_p, arg1 arg2 arg3, "function doc"),
-    (trino, date_diff_varchar_timestamp_p_timestamp_p, arg1 arg2 arg3, "function doc"),
+    (trino, date_diff_varchar_date_date, arg1 arg2 arg3, ["varchar", "date", "date"], "Takes a unit, timestamp1, and timestamp2. Returns timestamp2 - timestamp1 expressed in terms of unit.\n\n`date_diff(varchar, date, date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_diff>"),
+    (trino, date_diff_varchar_time_p_time_p, arg1 arg2 arg3, ["varchar", "time(p)", "time(p)"], "Returns timestamp2 - timestamp1 expressed in terms of unit.\n\n`date_diff(varchar, time(p), time(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_diff>"),
+    (trino, date_diff_varchar_timestamp_p_timestamp_p, arg1 arg2 arg3, ["varchar", "timestamp(p)", "timestamp(p)"], "Returns timestamp2 - timestamp1 expressed in terms of unit.\n\n`date_diff(varchar, timestamp(p), timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_diff>"),
+    (trino, date_diff_varchar_timestamp_p_withtimezone_timestamp_p_withtimezone, arg1 arg2 arg3, ["varchar", "timestamp(p) with time zone", "timestamp(p) with time zone"], "Returns timestamp2 - timestamp1 expressed in terms of unit.\n\n`date_diff(varchar, timestamp(p) with time zone, timestamp(p) with time zone) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_diff>"),
 
-    (trino, date_format_timestamp_p_varchar, arg1 arg2, "function doc"),
+    (trino, date_format_timestamp_p_varchar, arg1 arg2, ["timestamp(p)", "varchar"], "Formats timestamp as a string using format.\n\n`date_format(timestamp(p), varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_format>"),
 
-    (trino, date_parse_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, date_parse_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Parses string into a timestamp using format.\n\n`date_parse(varchar, varchar) -> timestamp`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_parse>"),
 
-    (trino, date_trunc_varchar_time_p, arg1 arg2, "function doc"),
-    (trino, date_trunc_varchar_timestamp_p, arg1 arg2, "function doc"),
-    (trino, date_trunc_varchar_date, arg1 arg2, "function doc"),
+    (trino, date_trunc_varchar_time_p, arg1 arg2, ["varchar", "time(p)"], "Returns the date x truncated to unit.\n\n`date_trunc(varchar, time(p)) -> time(p)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_trunc>"),
+    (trino, date_trunc_varchar_timestamp_p, arg1 arg2, ["varchar", "timestamp(p)"], "Returns x truncated to unit.\n\n`date_trunc(varchar, timestamp(p)) -> timestamp(p)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_trunc>"),
+    (trino, date_trunc_varchar_timestamp_p_withtimezone, arg1 arg2, ["varchar", "timestamp(p) with time zone"], "Returns x truncated to unit.\n\n`date_trunc(varchar, timestamp(p) with time zone) -> timestamp(p) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_trunc>"),
+    (trino, date_trunc_varchar_date, arg1 arg2, ["varchar", "date"], "Returns x truncated to unit.\n\n`date_trunc(varchar, date) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#date_trunc>"),
 
-    (trino, day_date, arg1, "function doc"),
-    (trino, day_intervaldaytosecond, arg1, "function doc"),
-    (trino, day_timestamp_p, arg1, "function doc"),
+    (trino, day_date, arg1, ["date"], "Returns the day of the month from x.\n\n`day(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day>"),
+    (trino, day_intervaldaytosecond, arg1, ["interval day to second"], "Returns the day of the month from x.\n\n`day(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day>"),
+    (trino, day_timestamp_p, arg1, ["timestamp(p)"], "Returns the day of the month from x.\n\n`day(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day>"),
 
-    (trino, day_of_month_date, arg1, "function doc"),
-    (trino, day_of_month_intervaldaytosecond, arg1, "function doc"),
-    (trino, day_of_month_timestamp_p, arg1, "function doc"),
+    (trino, day_of_month_date, arg1, ["date"], "This is an alias for day().\n\n`day_of_month(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_month>"),
+    (trino, day_of_month_intervaldaytosecond, arg1, ["interval day to second"], "This is an alias for day().\n\n`day_of_month(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_month>"),
+    (trino, day_of_month_timestamp_p, arg1, ["timestamp(p)"], "This is an alias for day().\n\n`day_of_month(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_month>"),
 
-    (trino, day_of_week_date, arg1, "function doc"),
-    (trino, day_of_week_timestamp_p, arg1, "function doc"),
+    (trino, day_of_week_date, arg1, ["date"], "Returns the ISO day of the week from x. The value ranges from 1 (Monday) to 7 (Sunday).\n\n`day_of_week(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_week>"),
+    (trino, day_of_week_timestamp_p, arg1, ["timestamp(p)"], "Returns the ISO day of the week from x. The value ranges from 1 (Monday) to 7 (Sunday).\n\n`day_of_week(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_week>"),
 
-    (trino, day_of_year_date, arg1, "function doc"),
-    (trino, day_of_year_timestamp_p, arg1, "function doc"),
+    (trino, day_of_year_date, arg1, ["date"], "Returns the day of the year from x. The value ranges from 1 to 366.\n\n`day_of_year(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_year>"),
+    (trino, day_of_year_timestamp_p, arg1, ["timestamp(p)"], "Returns the day of the year from x. The value ranges from 1 to 366.\n\n`day_of_year(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#day_of_year>"),
 
-    (trino, degrees_double, arg1, "function doc"),
+    (trino, degrees_double, arg1, ["double"], "Converts angle x in radians to degrees.\n\n`degrees(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#degrees>"),
 
-    (trino, dow_date, arg1, "function doc"),
-    (trino, dow_timestamp_p, arg1, "function doc"),
+    (trino, dow_date, arg1, ["date"], "This is an alias for day_of_week().\n\n`dow(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#dow>"),
+    (trino, dow_timestamp_p, arg1, ["timestamp(p)"], "This is an alias for day_of_week().\n\n`dow(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#dow>"),
 
-    (trino, doy_date, arg1, "function doc"),
-    (trino, doy_timestamp_p, arg1, "function doc"),
+    (trino, doy_date, arg1, ["date"], "This is an alias for day_of_year().\n\n`doy(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#doy>"),
+    (trino, doy_timestamp_p, arg1, ["timestamp(p)"], "This is an alias for day_of_year().\n\n`doy(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#doy>"),
 
-    (trino, e, , "function doc"),
+    (trino, e, , [], "Returns the constant Euler’s number.\n\n`e() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#e>"),
 
-    (trino, element_at_map_4_5_4, arg1 arg2, "function doc"),
-    (trino, element_at_array_3_bigint, arg1 arg2, "function doc"),
+    (trino, element_at_map_4_5_4, arg1 arg2, ["map<$4, $5>", "$4"], "Returns element of array at given index. If index > 0, this function provides the same functionality as the SQL-standard subscript operator ([]), except that the function returns NULL when accessing an index larger than array length, whereas the subscript operator would fail in such a case. If index < 0, element_at accesses elements from the last to the first.\n\n`element_at(map<$4, $5>, $4) -> $5`\n\nSee <https://trino.io/docs/current/functions/array.html#element_at>"),
+    (trino, element_at_array_3_bigint, arg1 arg2, ["array<$3>", "bigint"], "Returns element of array at given index. If index > 0, this function provides the same functionality as the SQL-standard subscript operator ([]), except that the function returns NULL when accessing an index larger than array length, whereas the subscript operator would fail in such a case. If index < 0, element_at accesses elements from the last to the first.\n\n`element_at(array<$3>, bigint) -> $3`\n\nSee <https://trino.io/docs/current/functions/array.html#element_at>"),
 
-    (trino, empty_approx_set, , "function doc"),
+    (trino, empty_approx_set, , [], "Returns an empty HyperLogLog.\n\n`empty_approx_set() -> hyperloglog`\n\nSee <https://trino.io/docs/current/functions/hyperloglog.html#empty_approx_set>"),
 
-    (trino, exp_double, arg1, "function doc"),
+    (trino, exp_double, arg1, ["double"], "Returns Euler’s number raised to the power of x.\n\n`exp(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#exp>"),
 
-    (trino, features_double, arg1, "function doc"),
-    (trino, features_double_double, arg1 arg2, "function doc"),
-    (trino, features_double_double_double, arg1 arg2 arg3, "function doc"),
-    (trino, features_double_double_double_double, arg1 arg2 arg3 arg4, "function doc"),
-    (trino, features_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5, "function doc"),
-    (trino, features_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6, "function doc"),
-    (trino, features_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7, "function doc"),
-    (trino, features_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8, "function doc"),
-    (trino, features_double_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9, "function doc"),
-    (trino, features_double_double_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9 arg10, "function doc"),
+    (trino, features_double, arg1, ["double"], "Returns the map representing the feature vector.\n\n`features(double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double, arg1 arg2, ["double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double, arg1 arg2 arg3, ["double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double, arg1 arg2 arg3 arg4, ["double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5, ["double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6, ["double", "double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7, ["double", "double", "double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8, ["double", "double", "double", "double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9, ["double", "double", "double", "double", "double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
+    (trino, features_double_double_double_double_double_double_double_double_double_double, arg1 arg2 arg3 arg4 arg5 arg6 arg7 arg8 arg9 arg10, ["double", "double", "double", "double", "double", "double", "double", "double", "double", "double"], "Returns the map representing the feature vector.\n\n`features(double, double, double, double, double, double, double, double, double, double) -> map<bigint, double>`\n\nSee <https://trino.io/docs/current/functions/ml.html#features>"),
 
-    (trino, filter_array_1_function_1_boolean, arg1 arg2, "function doc"),
+    (trino, filter_array_1_function_1_boolean, arg1 arg2, ["array<$1>", "function($1, boolean)"], "Constructs an array from those elements of array for which function returns true.\n\n`filter(array<$1>, function($1, boolean)) -> array<$1>`\n\nSee <https://trino.io/docs/current/functions/array.html#filter>"),
 
-    (trino, flatten_array_array_3, arg1, "function doc"),
+    (trino, flatten_array_array_3, arg1, ["array<array<$3>>"], "Flattens an array(array(T)) to an array(T) by concatenating the contained arrays.\n\n`flatten(array<array<$3>>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#flatten>"),
 
-    (trino, floor_bigint, arg1, "function doc"),
-    (trino, floor_decimal_p_s, arg1, "function doc"),
-    (trino, floor_double, arg1, "function doc"),
-    (trino, floor_integer, arg1, "function doc"),
-    (trino, floor_real, arg1, "function doc"),
-    (trino, floor_smallint, arg1, "function doc"),
-    (trino, floor_tinyint, arg1, "function doc"),
+    (trino, floor_bigint, arg1, ["bigint"], "Returns x rounded down to the nearest integer.\n\n`floor(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_decimal_p_s, arg1, ["decimal(p, s)"], "Returns x rounded down to the nearest integer.\n\n`floor(decimal(p, s)) -> decimal(rp, 0)`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_double, arg1, ["double"], "Returns x rounded down to the nearest integer.\n\n`floor(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_integer, arg1, ["integer"], "Returns x rounded down to the nearest integer.\n\n`floor(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_real, arg1, ["real"], "Returns x rounded down to the nearest integer.\n\n`floor(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_smallint, arg1, ["smallint"], "Returns x rounded down to the nearest integer.\n\n`floor(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
+    (trino, floor_tinyint, arg1, ["tinyint"], "Returns x rounded down to the nearest integer.\n\n`floor(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#floor>"),
 
-    (trino, format_varchar_1, arg1 arg2, "function doc"),
-    (trino, format_varchar_1_2, arg1 arg2 arg3, "function doc"),
-    (trino, format_varchar_1_2_3, arg1 arg2 arg3 arg4, "function doc"),
-    (trino, format_varchar_1_2_3_4, arg1 arg2 arg3 arg4 arg5, "function doc"),
-    (trino, format_varchar_1_2_3_4_5, arg1 arg2 arg3 arg4 arg5 arg6, "function doc"),
+    (trino, format_varchar_1, arg1 arg2, ["varchar", "$1"], "Returns a formatted string using the specified format string and arguments.\n\n`format(varchar, $1) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format>"),
+    (trino, format_varchar_1_2, arg1 arg2 arg3, ["varchar", "$1", "$2"], "Returns a formatted string using the specified format string and arguments.\n\n`format(varchar, $1, $2) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format>"),
+    (trino, format_varchar_1_2_3, arg1 arg2 arg3 arg4, ["varchar", "$1", "$2", "$3"], "Returns a formatted string using the specified format string and arguments.\n\n`format(varchar, $1, $2, $3) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format>"),
+    (trino, format_varchar_1_2_3_4, arg1 arg2 arg3 arg4 arg5, ["varchar", "$1", "$2", "$3", "$4"], "Returns a formatted string using the specified format string and arguments.\n\n`format(varchar, $1, $2, $3, $4) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format>"),
+    (trino, format_varchar_1_2_3_4_5, arg1 arg2 arg3 arg4 arg5 arg6, ["varchar", "$1", "$2", "$3", "$4", "$5"], "Returns a formatted string using the specified format string and arguments.\n\n`format(varchar, $1, $2, $3, $4, $5) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format>"),
 
-    (trino, format_datetime_timestamp_p_varchar, arg1 arg2, "function doc"),
+    (trino, format_datetime_timestamp_p_varchar, arg1 arg2, ["timestamp(p)", "varchar"], "Formats timestamp as a string using format.\n\n`format_datetime(timestamp(p), varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#format_datetime>"),
 
-    (trino, format_number_bigint, arg1, "function doc"),
-    (trino, format_number_double, arg1, "function doc"),
+    (trino, format_number_bigint, arg1, ["bigint"], "Returns a formatted string using a unit symbol.\n\n`format_number(bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format_number>"),
+    (trino, format_number_double, arg1, ["double"], "Returns a formatted string using a unit symbol.\n\n`format_number(double) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#format_number>"),
 
-    (trino, from_base_varchar_bigint, arg1 arg2, "function doc"),
+    (trino, from_base_varchar_bigint, arg1 arg2, ["varchar", "bigint"], "Returns the value of string interpreted as a base-radix number.\n\n`from_base(varchar, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#from_base>"),
 
-    (trino, from_base32_varbinary, arg1, "function doc"),
-    (trino, from_base32_varchar, arg1, "function doc"),
+    (trino, from_base32_varbinary, arg1, ["varbinary"], "Decodes binary data from the base32 encoded string.\n\n`from_base32(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base32>"),
+    (trino, from_base32_varchar, arg1, ["varchar"], "Decodes binary data from the base32 encoded string.\n\n`from_base32(varchar) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base32>"),
 
-    (trino, from_base64_varbinary, arg1, "function doc"),
-    (trino, from_base64_varchar, arg1, "function doc"),
+    (trino, from_base64_varbinary, arg1, ["varbinary"], "Decodes binary data from the base64 encoded string.\n\n`from_base64(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base64>"),
+    (trino, from_base64_varchar, arg1, ["varchar"], "Decodes binary data from the base64 encoded string.\n\n`from_base64(varchar) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base64>"),
 
-    (trino, from_base64url_varbinary, arg1, "function doc"),
-    (trino, from_base64url_varchar, arg1, "function doc"),
+    (trino, from_base64url_varbinary, arg1, ["varbinary"], "Decodes binary data from the base64 encoded string using the URL safe alphabet.\n\n`from_base64url(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base64url>"),
+    (trino, from_base64url_varchar, arg1, ["varchar"], "Decodes binary data from the base64 encoded string using the URL safe alphabet.\n\n`from_base64url(varchar) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_base64url>"),
 
-    (trino, from_big_endian_32_varbinary, arg1, "function doc"),
+    (trino, from_big_endian_32_varbinary, arg1, ["varbinary"], "Decodes the 32-bit two’s complement big-endian binary. The input must be exactly 4 bytes.\n\n`from_big_endian_32(varbinary) -> bigint`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_big_endian_32>"),
 
-    (trino, from_big_endian_64_varbinary, arg1, "function doc"),
+    (trino, from_big_endian_64_varbinary, arg1, ["varbinary"], "Decodes the 64-bit two’s complement big-endian binary. The input must be exactly 8 bytes.\n\n`from_big_endian_64(varbinary) -> bigint`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_big_endian_64>"),
 
-    (trino, from_encoded_polyline_varchar, arg1, "function doc"),
+    (trino, from_encoded_polyline_varchar, arg1, ["varchar"], "Decodes a polyline to a linestring.\n\n`from_encoded_polyline(varchar) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#from_encoded_polyline>"),
 
-    (trino, from_geojson_geometry_varchar, arg1, "function doc"),
+    (trino, from_geojson_geometry_varchar, arg1, ["varchar"], "Returns the spherical geography type object from the GeoJSON representation stripping non geometry key/values. Feature and FeatureCollection are not supported.\n\n`from_geojson_geometry(varchar) -> sphericalgeography`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#from_geojson_geometry>"),
 
-    (trino, from_hex_varbinary, arg1, "function doc"),
-    (trino, from_hex_varchar, arg1, "function doc"),
+    (trino, from_hex_varbinary, arg1, ["varbinary"], "Decodes binary data from the hex encoded string.\n\n`from_hex(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_hex>"),
+    (trino, from_hex_varchar, arg1, ["varchar"], "Decodes binary data from the hex encoded string.\n\n`from_hex(varchar) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_hex>"),
 
-    (trino, from_ieee754_32_varbinary, arg1, "function doc"),
+    (trino, from_ieee754_32_varbinary, arg1, ["varbinary"], "Decodes the 32-bit big-endian binary in IEEE 754 single-precision floating-point format. The input must be exactly 4 bytes.\n\n`from_ieee754_32(varbinary) -> real`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_ieee754_32>"),
 
-    (trino, from_ieee754_64_varbinary, arg1, "function doc"),
+    (trino, from_ieee754_64_varbinary, arg1, ["varbinary"], "Decodes the 64-bit big-endian binary in IEEE 754 double-precision floating-point format. The input must be exactly 8 bytes.\n\n`from_ieee754_64(varbinary) -> double`\n\nSee <https://trino.io/docs/current/functions/binary.html#from_ieee754_64>"),
 
-    (trino, from_iso8601_date_varchar, arg1, "function doc"),
+    (trino, from_iso8601_date_varchar, arg1, ["varchar"], "Parses the ISO 8601 formatted date string into a date. The date can be a calendar date, a week date using ISO week numbering, or year and day of year combined.\n\n`from_iso8601_date(varchar) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_iso8601_date>"),
 
-    (trino, from_iso8601_timestamp_varchar, arg1, "function doc"),
+    (trino, from_iso8601_timestamp_varchar, arg1, ["varchar"], "Parses the ISO 8601 formatted date string, optionally with time and time zone, into a timestamp(3) with time zone. The time defaults to 00.00.00.000, and the time zone defaults to the session time zone.\n\n`from_iso8601_timestamp(varchar) -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_iso8601_timestamp>"),
 
-    (trino, from_iso8601_timestamp_nanos_varchar, arg1, "function doc"),
+    (trino, from_iso8601_timestamp_nanos_varchar, arg1, ["varchar"], "Parses the ISO 8601 formatted date and time string. The time zone defaults to the session time zone.\n\n`from_iso8601_timestamp_nanos(varchar) -> timestamp(9) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_iso8601_timestamp_nanos>"),
 
-    (trino, from_unixtime_bigint, arg1, "function doc"),
-    (trino, from_unixtime_bigint_bigint_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, from_unixtime_bigint_varchar, arg1 arg2, "function doc"),
+    (trino, from_unixtime_double, arg1, ["double"], "Returns the UNIX timestamp unixtime as a timestamp with time zone. unixtime is the number of seconds since 1970-01-01 00.00.00 UTC.\n\n`from_unixtime(double) -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_unixtime>"),
+    (trino, from_unixtime_double_bigint_bigint, arg1 arg2 arg3, ["double", "bigint", "bigint"], "Returns the UNIX timestamp unixtime as a timestamp with time zone. unixtime is the number of seconds since 1970-01-01 00.00.00 UTC.\n\n`from_unixtime(double, bigint, bigint) -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_unixtime>"),
+    (trino, from_unixtime_double_varchar, arg1 arg2, ["double", "varchar"], "Returns the UNIX timestamp unixtime as a timestamp with time zone. unixtime is the number of seconds since 1970-01-01 00.00.00 UTC.\n\n`from_unixtime(double, varchar) -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_unixtime>"),
 
-    (trino, from_unixtime_nanos_bigint, arg1, "function doc"),
-    (trino, from_unixtime_nanos_decimal_p_s, arg1, "function doc"),
+    (trino, from_unixtime_nanos_bigint, arg1, ["bigint"], "Returns the UNIX timestamp unixtime as a timestamp with time zone. unixtime is the number of nanoseconds since 1970-01-01 00.00.00.000000000 UTC.\n\n`from_unixtime_nanos(bigint) -> timestamp(9) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_unixtime_nanos>"),
+    (trino, from_unixtime_nanos_decimal_p_s, arg1, ["decimal(p, s)"], "Returns the UNIX timestamp unixtime as a timestamp with time zone. unixtime is the number of nanoseconds since 1970-01-01 00.00.00.000000000 UTC.\n\n`from_unixtime_nanos(decimal(p, s)) -> timestamp(9) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#from_unixtime_nanos>"),
 
-    (trino, from_utf8_varbinary, arg1, "function doc"),
-    (trino, from_utf8_varbinary_bigint, arg1 arg2, "function doc"),
-    (trino, from_utf8_varbinary_varchar, arg1 arg2, "function doc"),
+    (trino, from_utf8_varbinary, arg1, ["varbinary"], "Decodes a UTF-8 encoded string from binary. Invalid UTF-8 sequences are replaced with the Unicode replacement character U+FFFD.\n\n`from_utf8(varbinary) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#from_utf8>"),
+    (trino, from_utf8_varbinary_bigint, arg1 arg2, ["varbinary", "bigint"], "Decodes a UTF-8 encoded string from binary. Invalid UTF-8 sequences are replaced with the Unicode replacement character U+FFFD.\n\n`from_utf8(varbinary, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#from_utf8>"),
+    (trino, from_utf8_varbinary_varchar, arg1 arg2, ["varbinary", "varchar"], "Decodes a UTF-8 encoded string from binary. Invalid UTF-8 sequences are replaced with the Unicode replacement character U+FFFD.\n\n`from_utf8(varbinary, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#from_utf8>"),
 
-    (trino, geometry_from_hadoop_shape_varbinary, arg1, "function doc"),
+    (trino, geometry_from_hadoop_shape_varbinary, arg1, ["varbinary"], "Returns a geometry type object from Spatial Framework for Hadoop representation.\n\n`geometry_from_hadoop_shape(varbinary) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#geometry_from_hadoop_shape>"),
 
-    (trino, geometry_invalid_reason_geometry, arg1, "function doc"),
+    (trino, geometry_invalid_reason_geometry, arg1, ["geometry"], "Returns the reason for why the input geometry is not valid. Returns NULL if the input is valid.\n\n`geometry_invalid_reason(geometry) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#geometry_invalid_reason>"),
 
-    (trino, geometry_nearest_points_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, geometry_nearest_points_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns the points on each geometry nearest the other.  If either geometry is empty, return NULL.  Otherwise, return a row of two Points that have the minimum distance of any two points on the geometries.  The first Point will be from the first Geometry argument, the second from the second Geometry argument.  If there are multiple pairs with the minimum distance, one pair is chosen arbitrarily.\n\n`geometry_nearest_points(geometry, geometry) -> row(c0 geometry, c1 geometry)`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#geometry_nearest_points>"),
 
-    (trino, geometry_to_bing_tiles_geometry_bigint, arg1 arg2, "function doc"),
+    (trino, geometry_to_bing_tiles_geometry_bigint, arg1 arg2, ["geometry", "bigint"], "Returns the minimum set of Bing tiles that fully covers a given geometry at a given zoom level. Zoom levels from 1 to 23 are supported.\n\n`geometry_to_bing_tiles(geometry, bigint) -> array<bingtile>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#geometry_to_bing_tiles>"),
 
-    (trino, geometry_union_array_geometry, arg1, "function doc"),
+    (trino, geometry_union_array_geometry, arg1, ["array<geometry>"], "Returns a geometry that represents the point set union of the input geometries. Performance of this function, in conjunction with array_agg() to first aggregate the input geometries, may be better than geometry_union_agg(), at the expense of higher memory utilization.\n\n`geometry_union(array<geometry>) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#geometry_union>"),
 
-    (trino, great_circle_distance_double_double_double_double, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, great_circle_distance_double_double_double_double, arg1 arg2 arg3 arg4, ["double", "double", "double", "double"], "Returns the great-circle distance between two points on Earth’s surface in kilometers.\n\n`great_circle_distance(double, double, double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#great_circle_distance>"),
 
-    (trino, greatest_3, arg1, "function doc"),
+    (trino, greatest_3, arg1, variadic ["$3"], "Returns the largest of the provided values.\n\n`greatest($3, ...) -> $3`\n\nSee <https://trino.io/docs/current/functions/comparison.html#greatest>"),
 
-    (trino, hamming_distance_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, hamming_distance_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the Hamming distance of string1 and string2, i.e. the number of positions at which the corresponding characters are different. Note that the two strings must have the same length.\n\n`hamming_distance(varchar, varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#hamming_distance>"),
 
-    (trino, hash_counts_setdigest, arg1, "function doc"),
+    (trino, hash_counts_setdigest, arg1, ["setdigest"], "Returns a map containing the Murmur3Hash128 hashed values and the count of their occurences within the internal MinHash structure belonging to x.\n\n`hash_counts(setdigest) -> map<bigint, smallint>`\n\nSee <https://trino.io/docs/current/functions/setdigest.html#hash_counts>"),
 
-    (trino, hmac_md5_varbinary_varbinary, arg1 arg2, "function doc"),
+    (trino, hmac_md5_varbinary_varbinary, arg1 arg2, ["varbinary", "varbinary"], "Computes HMAC with MD5 of binary with the given key.\n\n`hmac_md5(varbinary, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#hmac_md5>"),
 
-    (trino, hmac_sha1_varbinary_varbinary, arg1 arg2, "function doc"),
+    (trino, hmac_sha1_varbinary_varbinary, arg1 arg2, ["varbinary", "varbinary"], "Computes HMAC with SHA1 of binary with the given key.\n\n`hmac_sha1(varbinary, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#hmac_sha1>"),
 
-    (trino, hmac_sha256_varbinary_varbinary, arg1 arg2, "function doc"),
+    (trino, hmac_sha256_varbinary_varbinary, arg1 arg2, ["varbinary", "varbinary"], "Computes HMAC with SHA256 of binary with the given key.\n\n`hmac_sha256(varbinary, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#hmac_sha256>"),
 
-    (trino, hmac_sha512_varbinary_varbinary, arg1 arg2, "function doc"),
+    (trino, hmac_sha512_varbinary_varbinary, arg1 arg2, ["varbinary", "varbinary"], "Computes HMAC with SHA512 of binary with the given key.\n\n`hmac_sha512(varbinary, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#hmac_sha512>"),
 
-    (trino, hour_intervaldaytosecond, arg1, "function doc"),
-    (trino, hour_time_p, arg1, "function doc"),
-    (trino, hour_timestamp_p, arg1, "function doc"),
+    (trino, hour_intervaldaytosecond, arg1, ["interval day to second"], "Returns the hour of the day from x. The value ranges from 0 to 23.\n\n`hour(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#hour>"),
+    (trino, hour_time_p, arg1, ["time(p)"], "Returns the hour of the day from x. The value ranges from 0 to 23.\n\n`hour(time(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#hour>"),
+    (trino, hour_timestamp_p, arg1, ["timestamp(p)"], "Returns the hour of the day from x. The value ranges from 0 to 23.\n\n`hour(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#hour>"),
 
-    (trino, human_readable_seconds_double, arg1, "function doc"),
+    (trino, human_readable_seconds_double, arg1, ["double"], "Formats the double value of seconds into a human readable string containing weeks, days, hours, minutes, and seconds.\n\n`human_readable_seconds(double) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#human_readable_seconds>"),
 
-    (trino, if_boolean_1_1, arg1 arg2 arg3, "function doc"),
+    (trino, if_boolean_1_1, arg1 arg2 arg3, ["boolean", "$1", "$1"], "Evaluates expression parameter and returns second if condition is true, otherwise null is returned and true_value is not evaluated.\n\n`if(boolean, $1, $1) -> $1`\n\nSee <https://trino.io/docs/current/functions/conditional.html#if-expression>"),
 
-    (trino, index_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, index_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Alias for strpos() function.\n\n`index(varchar, varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/teradata.html#index>"),
 
-    (trino, infinity, , "function doc"),
+    (trino, infinity, , [], "Returns the constant representing positive infinity.\n\n`infinity() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#infinity>"),
 
-    (trino, intersection_cardinality_setdigest_setdigest, arg1 arg2, "function doc"),
+    (trino, intersection_cardinality_setdigest_setdigest, arg1 arg2, ["setdigest", "setdigest"], "Returns the estimation for the cardinality of the intersection of the two set digests.\n\n`intersection_cardinality(setdigest, setdigest) -> bigint`\n\nSee <https://trino.io/docs/current/functions/setdigest.html#intersection_cardinality>"),
 
-    (trino, inverse_beta_cdf_double_double_double, arg1 arg2 arg3, "function doc"),
+    (trino, inverse_beta_cdf_double_double_double, arg1 arg2 arg3, ["double", "double", "double"], "Compute the inverse of the Beta cdf with given a, b parameters for the cumulative probability (p). P(N < n). The a, b parameters must be positive real values. The probability p must lie on the interval [0, 1].\n\n`inverse_beta_cdf(double, double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#inverse_beta_cdf>"),
 
-    (trino, inverse_normal_cdf_double_double_double, arg1 arg2 arg3, "function doc"),
+    (trino, inverse_normal_cdf_double_double_double, arg1 arg2 arg3, ["double", "double", "double"], "Compute the inverse of the Normal cdf with given mean and standard deviation (sd) for the cumulative probability (p). P(N < n). The mean must be a real value and the standard deviation must be a real and positive value. The probability p must lie on the interval (0, 1).\n\n`inverse_normal_cdf(double, double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#inverse_normal_cdf>"),
 
-    (trino, is_finite_double, arg1, "function doc"),
+    (trino, is_finite_double, arg1, ["double"], "Determine if x is finite.\n\n`is_finite(double) -> boolean`\n\nSee <https://trino.io/docs/current/functions/math.html#is_finite>"),
 
-    (trino, is_infinite_double, arg1, "function doc"),
+    (trino, is_infinite_double, arg1, ["double"], "Determine if x is infinite.\n\n`is_infinite(double) -> boolean`\n\nSee <https://trino.io/docs/current/functions/math.html#is_infinite>"),
 
-    (trino, is_json_scalar_json, arg1, "function doc"),
-    (trino, is_json_scalar_varchar, arg1, "function doc"),
+    (trino, is_json_scalar_json, arg1, ["json"], "Determine if json is a scalar (i.e. a JSON number, a JSON string, true, false or null).\n\n`is_json_scalar(json) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#is_json_scalar>"),
+    (trino, is_json_scalar_varchar, arg1, ["varchar"], "Determine if json is a scalar (i.e. a JSON number, a JSON string, true, false or null).\n\n`is_json_scalar(varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#is_json_scalar>"),
 
-    (trino, is_nan_double, arg1, "function doc"),
-    (trino, is_nan_real, arg1, "function doc"),
+    (trino, is_nan_double, arg1, ["double"], "Determine if `x` is not-a-number.\n\n`is_nan(double) -> boolean`\n\nSee <https://trino.io/docs/current/functions/math.html#is_nan>"),
+    (trino, is_nan_real, arg1, ["real"], "Determine if x is not-a-number.\n\n`is_nan(real) -> boolean`\n\nSee <https://trino.io/docs/current/functions/math.html#is_nan>"),
 
-    (trino, jaccard_index_setdigest_setdigest, arg1 arg2, "function doc"),
+    (trino, jaccard_index_setdigest_setdigest, arg1 arg2, ["setdigest", "setdigest"], "Returns the estimation of Jaccard index for the two set digests.\n\n`jaccard_index(setdigest, setdigest) -> double`\n\nSee <https://trino.io/docs/current/functions/setdigest.html#jaccard_index>"),
 
-    (trino, json_array_contains_json_bigint, arg1 arg2, "function doc"),
-    (trino, json_array_contains_json_boolean, arg1 arg2, "function doc"),
-    (trino, json_array_contains_json_double, arg1 arg2, "function doc"),
-    (trino, json_array_contains_json_varchar, arg1 arg2, "function doc"),
-    (trino, json_array_contains_varchar_bigint, arg1 arg2, "function doc"),
-    (trino, json_array_contains_varchar_boolean, arg1 arg2, "function doc"),
-    (trino, json_array_contains_varchar_double, arg1 arg2, "function doc"),
-    (trino, json_array_contains_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, json_array_contains_json_bigint, arg1 arg2, ["json", "bigint"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(json, bigint) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_json_boolean, arg1 arg2, ["json", "boolean"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(json, boolean) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_json_double, arg1 arg2, ["json", "double"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(json, double) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_json_varchar, arg1 arg2, ["json", "varchar"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(json, varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_varchar_bigint, arg1 arg2, ["varchar", "bigint"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(varchar, bigint) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_varchar_boolean, arg1 arg2, ["varchar", "boolean"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(varchar, boolean) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_varchar_double, arg1 arg2, ["varchar", "double"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(varchar, double) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
+    (trino, json_array_contains_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Determine if value exists in json (a string containing a JSON array).\n\n`json_array_contains(varchar, varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_contains>"),
 
-    (trino, json_array_get_json_bigint, arg1 arg2, "function doc"),
-    (trino, json_array_get_varchar_bigint, arg1 arg2, "function doc"),
+    (trino, json_array_get_json_bigint, arg1 arg2, ["json", "bigint"], "Warning\n\n`json_array_get(json, bigint) -> json`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_get>"),
+    (trino, json_array_get_varchar_bigint, arg1 arg2, ["varchar", "bigint"], "Warning\n\n`json_array_get(varchar, bigint) -> json`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_get>"),
 
-    (trino, json_array_length_json, arg1, "function doc"),
-    (trino, json_array_length_varchar, arg1, "function doc"),
+    (trino, json_array_length_json, arg1, ["json"], "Returns the array length of json (a string containing a JSON array).\n\n`json_array_length(json) -> bigint`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_length>"),
+    (trino, json_array_length_varchar, arg1, ["varchar"], "Returns the array length of json (a string containing a JSON array).\n\n`json_array_length(varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/json.html#json_array_length>"),
 
-    (trino, json_extract_json_jsonpath, arg1 arg2, "function doc"),
-    (trino, json_extract_varchar_jsonpath, arg1 arg2, "function doc"),
+    (trino, json_extract_json_jsonpath, arg1 arg2, ["json", "jsonpath"], "Evaluates the JSONPath-like expression json_path on json (a string containing JSON) and returns the result as a JSON string.\n\n`json_extract(json, jsonpath) -> json`\n\nSee <https://trino.io/docs/current/functions/json.html#json_extract>"),
+    (trino, json_extract_varchar_jsonpath, arg1 arg2, ["varchar", "jsonpath"], "Evaluates the JSONPath-like expression json_path on json (a string containing JSON) and returns the result as a JSON string.\n\n`json_extract(varchar, jsonpath) -> json`\n\nSee <https://trino.io/docs/current/functions/json.html#json_extract>"),
 
-    (trino, json_extract_scalar_json_jsonpath, arg1 arg2, "function doc"),
-    (trino, json_extract_scalar_varchar_jsonpath, arg1 arg2, "function doc"),
+    (trino, json_extract_scalar_json_jsonpath, arg1 arg2, ["json", "jsonpath"], "Like json_extract(), but returns the result value as a string (as opposed to being encoded as JSON). The value referenced by json_path must be a scalar (boolean, number or string).\n\n`json_extract_scalar(json, jsonpath) -> varchar`\n\nSee <https://trino.io/docs/current/functions/json.html#json_extract_scalar>"),
+    (trino, json_extract_scalar_varchar_jsonpath, arg1 arg2, ["varchar", "jsonpath"], "Like json_extract(), but returns the result value as a string (as opposed to being encoded as JSON). The value referenced by json_path must be a scalar (boolean, number or string).\n\n`json_extract_scalar(varchar, jsonpath) -> varchar`\n\nSee <https://trino.io/docs/current/functions/json.html#json_extract_scalar>"),
 
-    (trino, json_format_json, arg1, "function doc"),
+    (trino, json_format_json, arg1, ["json"], "Returns the JSON text serialized from the input JSON value. This is inverse function to json_parse().\n\n`json_format(json) -> varchar`\n\nSee <https://trino.io/docs/current/functions/json.html#json_format>"),
 
-    (trino, json_parse_varchar, arg1, "function doc"),
+    (trino, json_parse_varchar, arg1, ["varchar"], "Returns the JSON value deserialized from the input JSON text. This is inverse function to json_format().\n\n`json_parse(varchar) -> json`\n\nSee <https://trino.io/docs/current/functions/json.html#json_parse>"),
 
-    (trino, json_size_json_jsonpath, arg1 arg2, "function doc"),
-    (trino, json_size_varchar_jsonpath, arg1 arg2, "function doc"),
+    (trino, json_size_json_jsonpath, arg1 arg2, ["json", "jsonpath"], "Like json_extract(), but returns the size of the value. For objects or arrays, the size is the number of members, and the size of a scalar value is zero.\n\n`json_size(json, jsonpath) -> bigint`\n\nSee <https://trino.io/docs/current/functions/json.html#json_size>"),
+    (trino, json_size_varchar_jsonpath, arg1 arg2, ["varchar", "jsonpath"], "Like json_extract(), but returns the size of the value. For objects or arrays, the size is the number of members, and the size of a scalar value is zero.\n\n`json_size(varchar, jsonpath) -> bigint`\n\nSee <https://trino.io/docs/current/functions/json.html#json_size>"),
 
-    (trino, last_day_of_month_date, arg1, "function doc"),
-    (trino, last_day_of_month_timestamp_p, arg1, "function doc"),
+    (trino, last_day_of_month_date, arg1, ["date"], "Returns the last day of the month.\n\n`last_day_of_month(date) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#last_day_of_month>"),
+    (trino, last_day_of_month_timestamp_p, arg1, ["timestamp(p)"], "Returns the last day of the month.\n\n`last_day_of_month(timestamp(p)) -> date`\n\nSee <https://trino.io/docs/current/functions/datetime.html#last_day_of_month>"),
 
-    (trino, least_3, arg1, "function doc"),
+    (trino, least_3, arg1, variadic ["$3"], "Returns the smallest of the provided values.\n\n`least($3, ...) -> $3`\n\nSee <https://trino.io/docs/current/functions/comparison.html#least>"),
 
-    (trino, length_varchar, arg1, "function doc"),
-    (trino, length_varbinary, arg1, "function doc"),
+    (trino, length_varchar, arg1, ["varchar"], "Returns the length of string in characters.\n\n`length(varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#length>"),
+    (trino, length_varbinary, arg1, ["varbinary"], "Returns the length of string in characters.\n\n`length(varbinary) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#length>"),
 
-    (trino, levenshtein_distance_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, levenshtein_distance_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the Levenshtein edit distance of string1 and string2, i.e. the minimum number of single-character edits (insertions, deletions or substitutions) needed to change string1 into string2.\n\n`levenshtein_distance(varchar, varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#levenshtein_distance>"),
 
-    (trino, line_interpolate_point_geometry_double, arg1 arg2, "function doc"),
+    (trino, line_interpolate_point_geometry_double, arg1 arg2, ["geometry", "double"], "Returns a Point interpolated along a LineString at the fraction given. The fraction must be between 0 and 1, inclusive.\n\n`line_interpolate_point(geometry, double) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#line_interpolate_point>"),
 
-    (trino, line_interpolate_points_geometry_double, arg1 arg2, "function doc"),
+    (trino, line_interpolate_points_geometry_double, arg1 arg2, ["geometry", "double"], "Returns an array of Points interpolated along a LineString. The fraction must be between 0 and 1, inclusive.\n\n`line_interpolate_points(geometry, double) -> array<geometry>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#line_interpolate_points>"),
 
-    (trino, line_locate_point_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, line_locate_point_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns a float between 0 and 1 representing the location of the closest point on the LineString to the given Point, as a fraction of total 2d line length.\n\n`line_locate_point(geometry, geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#line_locate_point>"),
 
-    (trino, ln_double, arg1, "function doc"),
+    (trino, ln_double, arg1, ["double"], "Returns the natural logarithm of x.\n\n`ln(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#ln>"),
 
-    (trino, localtime, , "function doc"),
+    (trino, localtime, , [], "Returns the current time as of the start of the query.\n\n`localtime() -> time`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtime>"),
 
-    (trino, localtimestamp, , "function doc"),
-    (trino, localtimestamp_bigint_0, arg1, "function doc"),
-    (trino, localtimestamp_bigint_3, arg1, "function doc"),
-    (trino, localtimestamp_bigint_6, arg1, "function doc"),
-    (trino, localtimestamp_bigint_9, arg1, "function doc"),
+    (trino, localtimestamp, , [], "Returns the current timestamp as of the start of the query, with 3 digits of subsecond precision.\n\n`localtimestamp() -> timestamp`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtimestamp>"),
+    (trino, localtimestamp_bigint_0, arg1, ["bigint"], "Returns the current timestamp as of the start of the query, with 3 digits of subsecond precision.\n\n`localtimestamp(bigint) -> timestamp(0)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtimestamp>"),
+    (trino, localtimestamp_bigint_3, arg1, ["bigint"], "Returns the current timestamp as of the start of the query, with 3 digits of subsecond precision.\n\n`localtimestamp(bigint) -> timestamp(3)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtimestamp>"),
+    (trino, localtimestamp_bigint_6, arg1, ["bigint"], "Returns the current timestamp as of the start of the query, with 3 digits of subsecond precision.\n\n`localtimestamp(bigint) -> timestamp(6)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtimestamp>"),
+    (trino, localtimestamp_bigint_9, arg1, ["bigint"], "Returns the current timestamp as of the start of the query, with 3 digits of subsecond precision.\n\n`localtimestamp(bigint) -> timestamp(9)`\n\nSee <https://trino.io/docs/current/functions/datetime.html#localtimestamp>"),
 
-    (trino, log_double_double, arg1 arg2, "function doc"),
+    (trino, log_double_double, arg1 arg2, ["double", "double"], "Returns the base b logarithm of x.\n\n`log(double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#log>"),
 
-    (trino, log10_double, arg1, "function doc"),
+    (trino, log10_double, arg1, ["double"], "Returns the base 10 logarithm of x.\n\n`log10(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#log10>"),
 
-    (trino, log2_double, arg1, "function doc"),
+    (trino, log2_double, arg1, ["double"], "Returns the base 2 logarithm of x.\n\n`log2(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#log2>"),
 
-    (trino, lower_varchar, arg1, "function doc"),
+    (trino, lower_varchar, arg1, ["varchar"], "Converts string to lowercase.\n\n`lower(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#lower>"),
 
-    (trino, lpad_varbinary_bigint_varbinary, arg1 arg2 arg3, "function doc"),
-    (trino, lpad_varchar_bigint_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, lpad_varbinary_bigint_varbinary, arg1 arg2 arg3, ["varbinary", "bigint", "varbinary"], "Left pads string to size characters with padstring. If size is less than the length of string, the result is truncated to size characters. size must not be negative and padstring must be non-empty.\n\n`lpad(varbinary, bigint, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#lpad>"),
+    (trino, lpad_varchar_bigint_varchar, arg1 arg2 arg3, ["varchar", "bigint", "varchar"], "Left pads string to size characters with padstring. If size is less than the length of string, the result is truncated to size characters. size must not be negative and padstring must be non-empty.\n\n`lpad(varchar, bigint, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#lpad>"),
 
-    (trino, ltrim_varchar, arg1, "function doc"),
-    (trino, ltrim_varchar_codepoints, arg1 arg2, "function doc"),
+    (trino, ltrim_varchar, arg1, ["varchar"], "Removes leading whitespace from string.\n\n`ltrim(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#ltrim>"),
+    (trino, ltrim_varchar_codepoints, arg1 arg2, ["varchar", "codepoints"], "Removes leading whitespace from string.\n\n`ltrim(varchar, codepoints) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#ltrim>"),
 
-    (trino, luhn_check_varchar, arg1, "function doc"),
+    (trino, luhn_check_varchar, arg1, ["varchar"], "Tests whether a string of digits is valid according to the Luhn algorithm.\n\n`luhn_check(varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/string.html#luhn_check>"),
 
-    (trino, map_array_4_array_5, arg1 arg2, "function doc"),
-    (trino, map, , "function doc"),
+    (trino, map_array_4_array_5, arg1 arg2, ["array<$4>", "array<$5>"], "Returns an empty map.\n\n`map(array<$4>, array<$5>) -> map<$4, $5>`\n\nSee <https://trino.io/docs/current/functions/map.html#map>"),
+    (trino, map, , [], "Returns an empty map.\n\n`map() -> map<unknown, unknown>`\n\nSee <https://trino.io/docs/current/functions/map.html#map>"),
 
-    (trino, map_concat_map_4_5, arg1, "function doc"),
+    (trino, map_concat_map_4_5, arg1, variadic ["map<$4, $5>"], "Returns the union of all the given maps. If a key is found in multiple given maps, that key’s value in the resulting map comes from the last one of those maps.\n\n`map_concat(map<$4, $5>, ...) -> map<$4, $5>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_concat>"),
 
-    (trino, map_entries_map_4_5, arg1, "function doc"),
+    (trino, map_entries_map_4_5, arg1, ["map<$4, $5>"], "Returns an array of all entries in the given map.\n\n`map_entries(map<$4, $5>) -> array<row(c0 $4, c1 $5)>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_entries>"),
 
-    (trino, map_filter_map_4_5_function_4_5_boolean, arg1 arg2, "function doc"),
+    (trino, map_filter_map_4_5_function_4_5_boolean, arg1 arg2, ["map<$4, $5>", "function($4, $5, boolean)"], "Constructs a map from those entries of map for which function returns true.\n\n`map_filter(map<$4, $5>, function($4, $5, boolean)) -> map<$4, $5>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_filter>"),
 
-    (trino, map_from_entries_array_row_c04_c15, arg1, "function doc"),
+    (trino, map_from_entries_array_row_c04_c15, arg1, ["array<row(c0 $4, c1 $5)>"], "Returns a map created from the given array of entries.\n\n`map_from_entries(array<row(c0 $4, c1 $5)>) -> map<$4, $5>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_from_entries>"),
 
-    (trino, map_keys_map_4_5, arg1, "function doc"),
+    (trino, map_keys_map_4_5, arg1, ["map<$4, $5>"], "Returns all the keys in the map x.\n\n`map_keys(map<$4, $5>) -> array<$4>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_keys>"),
 
-    (trino, map_values_map_4_5, arg1, "function doc"),
+    (trino, map_values_map_4_5, arg1, ["map<$4, $5>"], "Returns all the values in the map x.\n\n`map_values(map<$4, $5>) -> array<$5>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_values>"),
 
-    (trino, map_zip_with_map_4_8_map_4_7_function_4_8_7_6, arg1 arg2 arg3, "function doc"),
+    (trino, map_zip_with_map_4_8_map_4_7_function_4_8_7_6, arg1 arg2 arg3, ["map<$4, $8>", "map<$4, $7>", "function($4, $8, $7, $6)"], "Merges the two given maps into a single map by applying function to the pair of values with the same key. For keys only presented in one map, NULL will be passed as the value for the missing key.\n\n`map_zip_with(map<$4, $8>, map<$4, $7>, function($4, $8, $7, $6)) -> map<$4, $6>`\n\nSee <https://trino.io/docs/current/functions/map.html#map_zip_with>"),
 
-    (trino, md5_varbinary, arg1, "function doc"),
+    (trino, md5_varbinary, arg1, ["varbinary"], "Computes the MD5 hash of binary.\n\n`md5(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#md5>"),
 
-    (trino, millisecond_intervaldaytosecond, arg1, "function doc"),
-    (trino, millisecond_time_p, arg1, "function doc"),
-    (trino, millisecond_timestamp_p, arg1, "function doc"),
+    (trino, millisecond_intervaldaytosecond, arg1, ["interval day to second"], "Returns the millisecond of the second from x.\n\n`millisecond(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#millisecond>"),
+    (trino, millisecond_time_p, arg1, ["time(p)"], "Returns the millisecond of the second from x.\n\n`millisecond(time(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#millisecond>"),
+    (trino, millisecond_timestamp_p, arg1, ["timestamp(p)"], "Returns the millisecond of the second from x.\n\n`millisecond(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#millisecond>"),
 
-    (trino, minute_intervaldaytosecond, arg1, "function doc"),
-    (trino, minute_time_p, arg1, "function doc"),
-    (trino, minute_timestamp_p, arg1, "function doc"),
+    (trino, minute_intervaldaytosecond, arg1, ["interval day to second"], "Returns the minute of the hour from x.\n\n`minute(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#minute>"),
+    (trino, minute_time_p, arg1, ["time(p)"], "Returns the minute of the hour from x.\n\n`minute(time(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#minute>"),
+    (trino, minute_timestamp_p, arg1, ["timestamp(p)"], "Returns the minute of the hour from x.\n\n`minute(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#minute>"),
 
-    (trino, mod_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, mod_decimal_a_precision_a_scale_decimal_b_precision_b_scale, arg1 arg2, "function doc"),
-    (trino, mod_double_double, arg1 arg2, "function doc"),
-    (trino, mod_integer_integer, arg1 arg2, "function doc"),
-    (trino, mod_real_real, arg1 arg2, "function doc"),
-    (trino, mod_smallint_smallint, arg1 arg2, "function doc"),
-    (trino, mod_tinyint_tinyint, arg1 arg2, "function doc"),
+    (trino, mod_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_decimal_a_precision_a_scale_decimal_b_precision_b_scale, arg1 arg2, ["decimal(a_precision, a_scale)", "decimal(b_precision, b_scale)"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(decimal(a_precision, a_scale), decimal(b_precision, b_scale)) -> decimal(r_precision, r_scale)`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_double_double, arg1 arg2, ["double", "double"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_integer_integer, arg1 arg2, ["integer", "integer"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(integer, integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_real_real, arg1 arg2, ["real", "real"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(real, real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_smallint_smallint, arg1 arg2, ["smallint", "smallint"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(smallint, smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
+    (trino, mod_tinyint_tinyint, arg1 arg2, ["tinyint", "tinyint"], "Returns the modulus (remainder) of n divided by m.\n\n`mod(tinyint, tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#mod>"),
 
-    (trino, month_date, arg1, "function doc"),
-    (trino, month_intervalyeartomonth, arg1, "function doc"),
-    (trino, month_timestamp_p, arg1, "function doc"),
+    (trino, month_date, arg1, ["date"], "Returns the month of the year from x.\n\n`month(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#month>"),
+    (trino, month_intervalyeartomonth, arg1, ["interval year to month"], "Returns the month of the year from x.\n\n`month(interval year to month) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#month>"),
+    (trino, month_timestamp_p, arg1, ["timestamp(p)"], "Returns the month of the year from x.\n\n`month(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#month>"),
 
-    (trino, multimap_from_entries_array_row_c04_c15, arg1, "function doc"),
+    (trino, multimap_from_entries_array_row_c04_c15, arg1, ["array<row(c0 $4, c1 $5)>"], "Returns a multimap created from the given array of entries. Each key can be associated with multiple values.\n\n`multimap_from_entries(array<row(c0 $4, c1 $5)>) -> map<$4, array<$5>>`\n\nSee <https://trino.io/docs/current/functions/map.html#multimap_from_entries>"),
 
-    (trino, murmur3_varbinary, arg1, "function doc"),
+    (trino, murmur3_varbinary, arg1, ["varbinary"], "Computes the 128-bit MurmurHash3 hash of binary.\n\n`murmur3(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#murmur3>"),
 
-    (trino, nan, , "function doc"),
+    (trino, nan, , [], "Returns the constant representing not-a-number.\n\n`nan() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#nan>"),
 
-    (trino, ngrams_array_1_bigint, arg1 arg2, "function doc"),
+    (trino, ngrams_array_1_bigint, arg1 arg2, ["array<$1>", "bigint"], "Returns n-grams (sub-sequences of adjacent n elements) for the array. The order of the n-grams in the result is unspecified.\n\n`ngrams(array<$1>, bigint) -> array<array<$1>>`\n\nSee <https://trino.io/docs/current/functions/array.html#ngrams>"),
 
-    (trino, none_match_array_1_function_1_boolean, arg1 arg2, "function doc"),
+    (trino, none_match_array_1_function_1_boolean, arg1 arg2, ["array<$1>", "function($1, boolean)"], "Returns whether no elements of an array match the given predicate. Returns true if none of the elements matches the predicate (a special case is when the array is empty); false if one or more elements match; NULL if the predicate function returns NULL for one or more elements and false for all other elements.\n\n`none_match(array<$1>, function($1, boolean)) -> boolean`\n\nSee <https://trino.io/docs/current/functions/array.html#none_match>"),
 
-    (trino, normal_cdf_double_double_double, arg1 arg2 arg3, "function doc"),
+    (trino, normal_cdf_double_double_double, arg1 arg2 arg3, ["double", "double", "double"], "Compute the Normal cdf with given mean and standard deviation (sd).  P(N < v; mean, sd). The mean and value v must be real values and the standard deviation must be a real and positive value.\n\n`normal_cdf(double, double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#normal_cdf>"),
 
-    (trino, normalize_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, normalize_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Transforms string with NFC normalization form.\n\n`normalize(varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#normalize>"),
 
-    (trino, now, , "function doc"),
+    (trino, now, , [], "This is an alias for current_timestamp.\n\n`now() -> timestamp`\n\nSee <https://trino.io/docs/current/functions/datetime.html#now>"),
 
-    (trino, nullif_1_1, arg1 arg2, "function doc"),
+    (trino, nullif_1_1, arg1 arg2, ["$1", "$1"], "Returns null if value1 equals value2, otherwise returns value1.\n\n`nullif($1, $1) -> $1`\n\nSee <https://trino.io/docs/current/functions/conditional.html#nullif-function>"),
 
-    (trino, objectid, , "function doc"),
-    (trino, objectid_varchar, arg1, "function doc"),
+    (trino, objectid, , [], "Extracts the TIMESTAMP WITH TIME ZONE from a given ObjectId.\n\n`objectid() -> objectid`\n\nSee <https://trino.io/docs/current/connector/mongodb.html#objectid>"),
+    (trino, objectid_varchar, arg1, ["varchar"], "Extracts the TIMESTAMP WITH TIME ZONE from a given ObjectId.\n\n`objectid(varchar) -> objectid`\n\nSee <https://trino.io/docs/current/connector/mongodb.html#objectid>"),
 
-    (trino, objectid_timestamp_objectid, arg1, "function doc"),
+    (trino, objectid_timestamp_objectid, arg1, ["objectid"], "Extracts the TIMESTAMP WITH TIME ZONE from a given ObjectId.\n\n`objectid_timestamp(objectid) -> timestamp`\n\nSee <https://trino.io/docs/current/functions/../connector/mongodb.html#objectid_timestamp>"),
 
-    (trino, parse_data_size_varchar, arg1, "function doc"),
+    (trino, parse_data_size_varchar, arg1, ["varchar"], "Parses string of format value unit into a number, where value is the fractional number of unit values.\n\n`parse_data_size(varchar) -> decimal(38, 0)`\n\nSee <https://trino.io/docs/current/functions/conversion.html#parse_data_size>"),
 
-    (trino, parse_datetime_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, parse_datetime_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Parses string into a timestamp with time zone using format.\n\n`parse_datetime(varchar, varchar) -> timestamp(3) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#parse_datetime>"),
 
-    (trino, parse_duration_varchar, arg1, "function doc"),
+    (trino, parse_duration_varchar, arg1, ["varchar"], "Parses string of format value unit into an interval, where value is fractional number of unit values.\n\n`parse_duration(varchar) -> interval day to second`\n\nSee <https://trino.io/docs/current/functions/datetime.html#parse_duration>"),
 
-    (trino, parse_presto_data_size_varchar, arg1, "function doc"),
+    (trino, parse_presto_data_size_varchar, arg1, ["varchar"], "Parses string of format value unit into a number, where value is the fractional number of unit values.\n\n`parse_presto_data_size(varchar) -> decimal(38, 0)`\n\nSee <https://trino.io/docs/current/functions/conversion.html#parse_data_size>"),
 
-    (trino, pi, , "function doc"),
+    (trino, pi, , [], "Returns the constant Pi.\n\n`pi() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#pi>"),
 
-    (trino, pow_double_double, arg1 arg2, "function doc"),
+    (trino, pow_double_double, arg1 arg2, ["double", "double"], "This is an alias for power().\n\n`pow(double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#pow>"),
 
-    (trino, power_double_double, arg1 arg2, "function doc"),
+    (trino, power_double_double, arg1 arg2, ["double", "double"], "Returns x raised to the power of p.\n\n`power(double, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#power>"),
 
-    (trino, quantile_at_value_qdigest_bigint, arg1 arg2, "function doc"),
-    (trino, quantile_at_value_qdigest_double, arg1 arg2, "function doc"),
-    (trino, quantile_at_value_qdigest_real, arg1 arg2, "function doc"),
+    (trino, quantile_at_value_qdigest_bigint, arg1 arg2, ["qdigest", "bigint"], "Returns the approximate quantile number between 0 and 1 from the quantile digest given an input value. Null is returned if the quantile digest is empty or the input value is outside of the range of the quantile digest.\n\n`quantile_at_value(qdigest, bigint) -> double`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#quantile_at_value>"),
+    (trino, quantile_at_value_qdigest_double, arg1 arg2, ["qdigest", "double"], "Returns the approximate quantile number between 0 and 1 from the quantile digest given an input value. Null is returned if the quantile digest is empty or the input value is outside of the range of the quantile digest.\n\n`quantile_at_value(qdigest, double) -> double`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#quantile_at_value>"),
+    (trino, quantile_at_value_qdigest_real, arg1 arg2, ["qdigest", "real"], "Returns the approximate quantile number between 0 and 1 from the quantile digest given an input value. Null is returned if the quantile digest is empty or the input value is outside of the range of the quantile digest.\n\n`quantile_at_value(qdigest, real) -> double`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#quantile_at_value>"),
 
-    (trino, quarter_date, arg1, "function doc"),
-    (trino, quarter_timestamp_p, arg1, "function doc"),
+    (trino, quarter_date, arg1, ["date"], "Returns the quarter of the year from x. The value ranges from 1 to 4.\n\n`quarter(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#quarter>"),
+    (trino, quarter_timestamp_p, arg1, ["timestamp(p)"], "Returns the quarter of the year from x. The value ranges from 1 to 4.\n\n`quarter(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#quarter>"),
 
-    (trino, radians_double, arg1, "function doc"),
+    (trino, radians_double, arg1, ["double"], "Converts angle x in degrees to radians.\n\n`radians(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#radians>"),
 
-    (trino, rand_bigint, arg1, "function doc"),
-    (trino, rand_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, rand, , "function doc"),
-    (trino, rand_integer, arg1, "function doc"),
-    (trino, rand_integer_integer, arg1 arg2, "function doc"),
-    (trino, rand_smallint, arg1, "function doc"),
-    (trino, rand_smallint_smallint, arg1 arg2, "function doc"),
-    (trino, rand_tinyint, arg1, "function doc"),
-    (trino, rand_tinyint_tinyint, arg1 arg2, "function doc"),
+    (trino, rand_bigint, arg1, ["bigint"], "This is an alias for random().\n\n`rand(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "This is an alias for random().\n\n`rand(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand, , [], "This is an alias for random().\n\n`rand() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_integer, arg1, ["integer"], "This is an alias for random().\n\n`rand(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_integer_integer, arg1 arg2, ["integer", "integer"], "This is an alias for random().\n\n`rand(integer, integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_smallint, arg1, ["smallint"], "This is an alias for random().\n\n`rand(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_smallint_smallint, arg1 arg2, ["smallint", "smallint"], "This is an alias for random().\n\n`rand(smallint, smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_tinyint, arg1, ["tinyint"], "This is an alias for random().\n\n`rand(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
+    (trino, rand_tinyint_tinyint, arg1 arg2, ["tinyint", "tinyint"], "This is an alias for random().\n\n`rand(tinyint, tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#rand>"),
 
-    (trino, random_bigint, arg1, "function doc"),
-    (trino, random_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, random, , "function doc"),
-    (trino, random_integer, arg1, "function doc"),
-    (trino, random_integer_integer, arg1 arg2, "function doc"),
-    (trino, random_smallint, arg1, "function doc"),
-    (trino, random_smallint_smallint, arg1 arg2, "function doc"),
-    (trino, random_tinyint, arg1, "function doc"),
-    (trino, random_tinyint_tinyint, arg1 arg2, "function doc"),
+    (trino, random_bigint, arg1, ["bigint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random, , [], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random() -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_integer, arg1, ["integer"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_integer_integer, arg1 arg2, ["integer", "integer"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(integer, integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_smallint, arg1, ["smallint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_smallint_smallint, arg1 arg2, ["smallint", "smallint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(smallint, smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_tinyint, arg1, ["tinyint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
+    (trino, random_tinyint_tinyint, arg1 arg2, ["tinyint", "tinyint"], "Returns a pseudo-random value in the range 0.0 <= x < 1.0.\n\n`random(tinyint, tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#random>"),
 
-    (trino, reduce_array_1_10_function_10_1_10_function_10_9, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, reduce_array_1_10_function_10_1_10_function_10_9, arg1 arg2 arg3 arg4, ["array<$1>", "$10", "function($10, $1, $10)", "function($10, $9)"], "Returns a single value reduced from array. inputFunction will be invoked for each element in array in order. In addition to taking the element, inputFunction takes the current state, initially initialState, and returns the new state. outputFunction will be invoked to turn the final state into the result value. It may be the identity function (i -> i).\n\n`reduce(array<$1>, $10, function($10, $1, $10), function($10, $9)) -> $9`\n\nSee <https://trino.io/docs/current/functions/array.html#reduce>"),
 
-    (trino, regexp_count_varchar_joniregexp, arg1 arg2, "function doc"),
+    (trino, regexp_count_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Returns the number of occurrence of pattern in string.\n\n`regexp_count(varchar, joniregexp) -> bigint`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_count>"),
 
-    (trino, regexp_extract_varchar_joniregexp, arg1 arg2, "function doc"),
-    (trino, regexp_extract_varchar_joniregexp_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, regexp_extract_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Returns the first substring matched in a string by the regular expression pattern.\n\n`regexp_extract(varchar, joniregexp) -> varchar`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_extract>"),
+    (trino, regexp_extract_varchar_joniregexp_bigint, arg1 arg2 arg3, ["varchar", "joniregexp", "bigint"], "Returns the first substring matched in a string by the regular expression pattern's capture group.\n\n`regexp_extract(varchar, joniregexp, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_extract>"),
 
-    (trino, regexp_extract_all_varchar_joniregexp, arg1 arg2, "function doc"),
-    (trino, regexp_extract_all_varchar_joniregexp_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, regexp_extract_all_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Returns the substrings of a string matched by the regular expression pattern.\n\n`regexp_extract_all(varchar, joniregexp) -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_extract_all>"),
+    (trino, regexp_extract_all_varchar_joniregexp_bigint, arg1 arg2 arg3, ["varchar", "joniregexp", "bigint"], "Returns the substrings of a string matched by the regular expression pattern's capturing group.\n\n`regexp_extract_all(varchar, joniregexp, bigint) -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_extract_all>"),
 
-    (trino, regexp_like_varchar_joniregexp, arg1 arg2, "function doc"),
+    (trino, regexp_like_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Evaluates the regular expression pattern and determines if it is contained within string.\n\n`regexp_like(varchar, joniregexp) -> boolean`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_like>"),
 
-    (trino, regexp_position_varchar_joniregexp, arg1 arg2, "function doc"),
-    (trino, regexp_position_varchar_joniregexp_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, regexp_position_varchar_joniregexp_bigint_bigint, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, regexp_position_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Returns the index of the first occurrence (counting from 1) of pattern in string. Returns -1 if not found.\n\n`regexp_position(varchar, joniregexp) -> bigint`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_position>"),
+    (trino, regexp_position_varchar_joniregexp_bigint, arg1 arg2 arg3, ["varchar", "joniregexp", "bigint"], "Returns the index of the first occurrence (counting from 1) of pattern in string. Returns -1 if not found.\n\n`regexp_position(varchar, joniregexp, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_position>"),
+    (trino, regexp_position_varchar_joniregexp_bigint_bigint, arg1 arg2 arg3 arg4, ["varchar", "joniregexp", "bigint", "bigint"], "Returns the index of the first occurrence (counting from 1) of pattern in string. Returns -1 if not found.\n\n`regexp_position(varchar, joniregexp, bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_position>"),
 
-    (trino, regexp_replace_varchar_joniregexp_function_array_varchar_varchar, arg1 arg2 arg3, "function doc"),
-    (trino, regexp_replace_varchar_joniregexp, arg1 arg2, "function doc"),
-    (trino, regexp_replace_varchar_joniregexp_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, regexp_replace_varchar_joniregexp_function_array_varchar_varchar, arg1 arg2 arg3, ["varchar", "joniregexp", "function(array<varchar>, varchar)"], "Removes every instance of the substring matched by the regular expression pattern from string.\n\n`regexp_replace(varchar, joniregexp, function(array<varchar>, varchar)) -> varchar`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_replace>"),
+    (trino, regexp_replace_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Removes every instance of the substring matched by the regular expression pattern from string.\n\n`regexp_replace(varchar, joniregexp) -> varchar`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_replace>"),
+    (trino, regexp_replace_varchar_joniregexp_varchar, arg1 arg2 arg3, ["varchar", "joniregexp", "varchar"], "Removes every instance of the substring matched by the regular expression pattern from string.\n\n`regexp_replace(varchar, joniregexp, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_replace>"),
 
-    (trino, regexp_split_varchar_joniregexp, arg1 arg2, "function doc"),
+    (trino, regexp_split_varchar_joniregexp, arg1 arg2, ["varchar", "joniregexp"], "Splits string using the regular expression pattern and returns an array. Trailing empty strings are preserved.\n\n`regexp_split(varchar, joniregexp) -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/regexp.html#regexp_split>"),
 
-    (trino, regress_map_bigint_double_regressor, arg1 arg2, "function doc"),
+    (trino, regress_map_bigint_double_regressor, arg1 arg2, ["map<bigint, double>", "regressor"], "Returns a predicted target value by the given regressor SVM model.\n\n`regress(map<bigint, double>, regressor) -> double`\n\nSee <https://trino.io/docs/current/functions/ml.html#regress>"),
 
-    (trino, render_boolean, arg1, "function doc"),
-    (trino, render_bigint_color, arg1 arg2, "function doc"),
-    (trino, render_double_color, arg1 arg2, "function doc"),
-    (trino, render_varchar_color, arg1 arg2, "function doc"),
+    (trino, render_boolean, arg1, ["boolean"], "Renders value x using the specific color using ANSI color codes. x can be either a double, bigint, or varchar.\n\n`render(boolean) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#render>"),
+    (trino, render_bigint_color, arg1 arg2, ["bigint", "color"], "Renders value x using the specific color using ANSI color codes. x can be either a double, bigint, or varchar.\n\n`render(bigint, color) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#render>"),
+    (trino, render_double_color, arg1 arg2, ["double", "color"], "Renders value x using the specific color using ANSI color codes. x can be either a double, bigint, or varchar.\n\n`render(double, color) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#render>"),
+    (trino, render_varchar_color, arg1 arg2, ["varchar", "color"], "Renders value x using the specific color using ANSI color codes. x can be either a double, bigint, or varchar.\n\n`render(varchar, color) -> varchar`\n\nSee <https://trino.io/docs/current/functions/color.html#render>"),
 
-    (trino, repeat_1_bigint, arg1 arg2, "function doc"),
+    (trino, repeat_1_bigint, arg1 arg2, ["$1", "bigint"], "Repeat element for count times.\n\n`repeat($1, bigint) -> array<$1>`\n\nSee <https://trino.io/docs/current/functions/array.html#repeat>"),
 
-    (trino, replace_varchar_varchar_varchar, arg1 arg2 arg3, "function doc"),
-    (trino, replace_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, replace_varchar_varchar_varchar, arg1 arg2 arg3, ["varchar", "varchar", "varchar"], "Removes all instances of search from string.\n\n`replace(varchar, varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#replace>"),
+    (trino, replace_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Removes all instances of search from string.\n\n`replace(varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#replace>"),
 
-    (trino, reverse_array_3, arg1, "function doc"),
-    (trino, reverse_varbinary, arg1, "function doc"),
-    (trino, reverse_varchar, arg1, "function doc"),
+    (trino, reverse_array_3, arg1, ["array<$3>"], "Returns string with the characters in reverse order.\n\n`reverse(array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/string.html#reverse>"),
+    (trino, reverse_varbinary, arg1, ["varbinary"], "Returns string with the characters in reverse order.\n\n`reverse(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#reverse>"),
+    (trino, reverse_varchar, arg1, ["varchar"], "Returns string with the characters in reverse order.\n\n`reverse(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#reverse>"),
 
-    (trino, rgb_bigint_bigint_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, rgb_bigint_bigint_bigint, arg1 arg2 arg3, ["bigint", "bigint", "bigint"], "Returns a color value capturing the RGB value of three component color values supplied as int parameters ranging from 0 to 255. red, green, blue.\n\n`rgb(bigint, bigint, bigint) -> color`\n\nSee <https://trino.io/docs/current/functions/color.html#rgb>"),
 
-    (trino, round_double, arg1, "function doc"),
-    (trino, round_double_bigint, arg1 arg2, "function doc"),
-    (trino, round_real, arg1, "function doc"),
-    (trino, round_real_bigint, arg1 arg2, "function doc"),
-    (trino, round_integer, arg1, "function doc"),
-    (trino, round_integer_integer, arg1 arg2, "function doc"),
-    (trino, round_decimal_p_s, arg1, "function doc"),
-    (trino, round_decimal_p_s_bigint, arg1 arg2, "function doc"),
-    (trino, round_bigint, arg1, "function doc"),
-    (trino, round_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, round_smallint, arg1, "function doc"),
-    (trino, round_smallint_bigint, arg1 arg2, "function doc"),
-    (trino, round_tinyint, arg1, "function doc"),
-    (trino, round_tinyint_bigint, arg1 arg2, "function doc"),
+    (trino, round_double, arg1, ["double"], "Returns x rounded to the nearest integer.\n\n`round(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_double_bigint, arg1 arg2, ["double", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(double, bigint) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_real, arg1, ["real"], "Returns x rounded to the nearest integer.\n\n`round(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_real_bigint, arg1 arg2, ["real", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(real, bigint) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_integer, arg1, ["integer"], "Returns x rounded to the nearest integer.\n\n`round(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_integer_integer, arg1 arg2, ["integer", "integer"], "Returns x rounded to the nearest integer.\n\n`round(integer, integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_decimal_p_s, arg1, ["decimal(p, s)"], "Returns x rounded to the nearest integer.\n\n`round(decimal(p, s)) -> decimal(rp, rs)`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_decimal_p_s_bigint, arg1 arg2, ["decimal(p, s)", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(decimal(p, s), bigint) -> decimal(rp, s)`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_bigint, arg1, ["bigint"], "Returns x rounded to the nearest integer.\n\n`round(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(bigint, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_smallint, arg1, ["smallint"], "Returns x rounded to the nearest integer.\n\n`round(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_smallint_bigint, arg1 arg2, ["smallint", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(smallint, bigint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_tinyint, arg1, ["tinyint"], "Returns x rounded to the nearest integer.\n\n`round(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
+    (trino, round_tinyint_bigint, arg1 arg2, ["tinyint", "bigint"], "Returns x rounded to the nearest integer.\n\n`round(tinyint, bigint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#round>"),
 
-    (trino, rpad_varbinary_bigint_varbinary, arg1 arg2 arg3, "function doc"),
-    (trino, rpad_varchar_bigint_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, rpad_varbinary_bigint_varbinary, arg1 arg2 arg3, ["varbinary", "bigint", "varbinary"], "Right pads string to size characters with padstring. If size is less than the length of string, the result is truncated to size characters. size must not be negative and padstring must be non-empty.\n\n`rpad(varbinary, bigint, varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#rpad>"),
+    (trino, rpad_varchar_bigint_varchar, arg1 arg2 arg3, ["varchar", "bigint", "varchar"], "Right pads string to size characters with padstring. If size is less than the length of string, the result is truncated to size characters. size must not be negative and padstring must be non-empty.\n\n`rpad(varchar, bigint, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#rpad>"),
 
-    (trino, rtrim_varchar, arg1, "function doc"),
-    (trino, rtrim_varchar_codepoints, arg1 arg2, "function doc"),
+    (trino, rtrim_varchar, arg1, ["varchar"], "Removes trailing whitespace from string.\n\n`rtrim(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#rtrim>"),
+    (trino, rtrim_varchar_codepoints, arg1 arg2, ["varchar", "codepoints"], "Removes trailing whitespace from string.\n\n`rtrim(varchar, codepoints) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#rtrim>"),
 
-    (trino, second_intervaldaytosecond, arg1, "function doc"),
-    (trino, second_time_p, arg1, "function doc"),
-    (trino, second_timestamp_p, arg1, "function doc"),
+    (trino, second_intervaldaytosecond, arg1, ["interval day to second"], "Returns the second of the minute from x.\n\n`second(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#second>"),
+    (trino, second_time_p, arg1, ["time(p)"], "Returns the second of the minute from x.\n\n`second(time(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#second>"),
+    (trino, second_timestamp_p, arg1, ["timestamp(p)"], "Returns the second of the minute from x.\n\n`second(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#second>"),
 
-    (trino, sequence_bigint_bigint, arg1 arg2, "function doc"),
-    (trino, sequence_bigint_bigint_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, sequence_date_date, arg1 arg2, "function doc"),
-    (trino, sequence_date_date_intervaldaytosecond, arg1 arg2 arg3, "function doc"),
-    (trino, sequence_date_date_intervalyeartomonth, arg1 arg2 arg3, "function doc"),
-    (trino, sequence_timestamp_p_timestamp_p_intervaldaytosecond, arg1 arg2 arg3, "function doc"),
+    (trino, sequence_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(bigint, bigint) -> array<bigint>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
+    (trino, sequence_bigint_bigint_bigint, arg1 arg2 arg3, ["bigint", "bigint", "bigint"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(bigint, bigint, bigint) -> array<bigint>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
+    (trino, sequence_date_date, arg1 arg2, ["date", "date"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(date, date) -> array<date>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
+    (trino, sequence_date_date_intervaldaytosecond, arg1 arg2 arg3, ["date", "date", "interval day to second"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(date, date, interval day to second) -> array<date>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
+    (trino, sequence_date_date_intervalyeartomonth, arg1 arg2 arg3, ["date", "date", "interval year to month"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(date, date, interval year to month) -> array<date>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
+    (trino, sequence_timestamp_p_timestamp_p_intervaldaytosecond, arg1 arg2 arg3, ["timestamp(p)", "timestamp(p)", "interval day to second"], "Generate a sequence of integers from start to stop, incrementing by 1 if start is less than or equal to stop, otherwise -1.\n\n`sequence(timestamp(p), timestamp(p), interval day to second) -> array<timestamp(p)>`\n\nSee <https://trino.io/docs/current/functions/array.html#sequence>"),
 
-    (trino, sha1_varbinary, arg1, "function doc"),
+    (trino, sha1_varbinary, arg1, ["varbinary"], "Computes the SHA1 hash of binary.\n\n`sha1(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#sha1>"),
 
-    (trino, sha256_varbinary, arg1, "function doc"),
+    (trino, sha256_varbinary, arg1, ["varbinary"], "Computes the SHA256 hash of binary.\n\n`sha256(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#sha256>"),
 
-    (trino, sha512_varbinary, arg1, "function doc"),
+    (trino, sha512_varbinary, arg1, ["varbinary"], "Computes the SHA512 hash of binary.\n\n`sha512(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#sha512>"),
 
-    (trino, shuffle_array_3, arg1, "function doc"),
+    (trino, shuffle_array_3, arg1, ["array<$3>"], "Generate a random permutation of the given array x.\n\n`shuffle(array<$3>) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#shuffle>"),
 
-    (trino, sign_bigint, arg1, "function doc"),
-    (trino, sign_decimal_p_s, arg1, "function doc"),
-    (trino, sign_double, arg1, "function doc"),
-    (trino, sign_integer, arg1, "function doc"),
-    (trino, sign_real, arg1, "function doc"),
-    (trino, sign_smallint, arg1, "function doc"),
-    (trino, sign_tinyint, arg1, "function doc"),
+    (trino, sign_bigint, arg1, ["bigint"], "Returns the signum function of x, that is.\n\n`sign(bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_decimal_p_s, arg1, ["decimal(p, s)"], "Returns the signum function of x, that is.\n\n`sign(decimal(p, s)) -> decimal(1, 0)`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_double, arg1, ["double"], "Returns the signum function of x, that is.\n\n`sign(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_integer, arg1, ["integer"], "Returns the signum function of x, that is.\n\n`sign(integer) -> integer`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_real, arg1, ["real"], "Returns the signum function of x, that is.\n\n`sign(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_smallint, arg1, ["smallint"], "Returns the signum function of x, that is.\n\n`sign(smallint) -> smallint`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
+    (trino, sign_tinyint, arg1, ["tinyint"], "Returns the signum function of x, that is.\n\n`sign(tinyint) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/math.html#sign>"),
 
-    (trino, simplify_geometry_geometry_double, arg1 arg2, "function doc"),
+    (trino, simplify_geometry_geometry_double, arg1 arg2, ["geometry", "double"], "Returns a “simplified” version of the input geometry using the Douglas-Peucker algorithm. Will avoid creating derived geometries (polygons in particular) that are invalid.\n\n`simplify_geometry(geometry, double) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#simplify_geometry>"),
 
-    (trino, sin_double, arg1, "function doc"),
+    (trino, sin_double, arg1, ["double"], "Returns the sine of x.\n\n`sin(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#sin>"),
 
-    (trino, sinh_double, arg1, "function doc"),
+    (trino, sinh_double, arg1, ["double"], "Returns the hyperbolic sine of x.\n\n`sinh(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#sinh>"),
 
-    (trino, slice_array_3_bigint_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, slice_array_3_bigint_bigint, arg1 arg2 arg3, ["array<$3>", "bigint", "bigint"], "Subsets array x starting from index start (or starting from the end if start is negative) with a length of length.\n\n`slice(array<$3>, bigint, bigint) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#slice>"),
 
-    (trino, soundex_varchar, arg1, "function doc"),
+    (trino, soundex_varchar, arg1, ["varchar"], "It is typically used to evaluate the similarity of two expressions phonetically, that is how the string sounds when spoken.\n\n`soundex(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#soundex>"),
 
-    (trino, spatial_partitions_kdbtree_geometry, arg1 arg2, "function doc"),
-    (trino, spatial_partitions_kdbtree_geometry_double, arg1 arg2 arg3, "function doc"),
+    (trino, spatial_partitions_kdbtree_geometry, arg1 arg2, ["kdbtree", "geometry"], "The spatial_partitions function.\n\n`spatial_partitions(kdbtree, geometry) -> array<bigint>`"),
+    (trino, spatial_partitions_kdbtree_geometry_double, arg1 arg2 arg3, ["kdbtree", "geometry", "double"], "The spatial_partitions function.\n\n`spatial_partitions(kdbtree, geometry, double) -> array<bigint>`"),
 
-    (trino, split_varchar_varchar, arg1 arg2, "function doc"),
-    (trino, split_varchar_varchar_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, split_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Splits string on delimiter and returns an array.\n\n`split(varchar, varchar) -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/string.html#split>"),
+    (trino, split_varchar_varchar_bigint, arg1 arg2 arg3, ["varchar", "varchar", "bigint"], "Splits string on delimiter and returns an array.\n\n`split(varchar, varchar, bigint) -> array<varchar>`\n\nSee <https://trino.io/docs/current/functions/string.html#split>"),
 
-    (trino, split_part_varchar_varchar_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, split_part_varchar_varchar_bigint, arg1 arg2 arg3, ["varchar", "varchar", "bigint"], "Splits string on delimiter and returns the field index. Field indexes start with 1. If the index is larger than the number of fields, then null is returned.\n\n`split_part(varchar, varchar, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#split_part>"),
 
-    (trino, split_to_map_varchar_varchar_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, split_to_map_varchar_varchar_varchar, arg1 arg2 arg3, ["varchar", "varchar", "varchar"], "Splits string by entryDelimiter and keyValueDelimiter and returns a map. entryDelimiter splits string into key-value pairs. keyValueDelimiter splits each pair into key and value.\n\n`split_to_map(varchar, varchar, varchar) -> map<varchar, varchar>`\n\nSee <https://trino.io/docs/current/functions/string.html#split_to_map>"),
 
-    (trino, split_to_multimap_varchar_varchar_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, split_to_multimap_varchar_varchar_varchar, arg1 arg2 arg3, ["varchar", "varchar", "varchar"], "Splits string by entryDelimiter and keyValueDelimiter and returns a map containing an array of values for each unique key. entryDelimiter splits string into key-value pairs. keyValueDelimiter splits each pair into key and value. The values for each key will be in the same order as they appeared in string.\n\n`split_to_multimap(varchar, varchar, varchar) -> map<varchar, array<varchar>>`\n\nSee <https://trino.io/docs/current/functions/string.html#split_to_multimap>"),
 
-    (trino, spooky_hash_v2_32_varbinary, arg1, "function doc"),
+    (trino, spooky_hash_v2_32_varbinary, arg1, ["varbinary"], "Computes the 32-bit SpookyHashV2 hash of binary.\n\n`spooky_hash_v2_32(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#spooky_hash_v2_32>"),
 
-    (trino, spooky_hash_v2_64_varbinary, arg1, "function doc"),
+    (trino, spooky_hash_v2_64_varbinary, arg1, ["varbinary"], "Computes the 64-bit SpookyHashV2 hash of binary.\n\n`spooky_hash_v2_64(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#spooky_hash_v2_64>"),
 
-    (trino, sqrt_double, arg1, "function doc"),
+    (trino, sqrt_double, arg1, ["double"], "Returns the square root of x.\n\n`sqrt(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#sqrt>"),
 
-    (trino, st_area_geometry, arg1, "function doc"),
-    (trino, st_area_sphericalgeography, arg1, "function doc"),
+    (trino, st_area_geometry, arg1, ["geometry"], "Returns the 2D Euclidean area of a geometry.\n\n`ST_Area(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Area>"),
+    (trino, st_area_sphericalgeography, arg1, ["sphericalgeography"], "Returns the 2D Euclidean area of a geometry.\n\n`ST_Area(sphericalgeography) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Area>"),
 
-    (trino, st_asbinary_geometry, arg1, "function doc"),
+    (trino, st_asbinary_geometry, arg1, ["geometry"], "Returns the WKB representation of the geometry.\n\n`ST_AsBinary(geometry) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_AsBinary>"),
 
-    (trino, st_astext_geometry, arg1, "function doc"),
+    (trino, st_astext_geometry, arg1, ["geometry"], "Returns the WKT representation of the geometry. For empty geometries, ST_AsText(ST_LineFromText('LINESTRING EMPTY')) will produce 'MULTILINESTRING EMPTY' and ST_AsText(ST_Polygon('POLYGON EMPTY')) will produce 'MULTIPOLYGON EMPTY'.\n\n`ST_AsText(geometry) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_AsText>"),
 
-    (trino, st_boundary_geometry, arg1, "function doc"),
+    (trino, st_boundary_geometry, arg1, ["geometry"], "Returns the closure of the combinatorial boundary of this geometry.\n\n`ST_Boundary(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Boundary>"),
 
-    (trino, st_buffer_geometry_double, arg1 arg2, "function doc"),
+    (trino, st_buffer_geometry_double, arg1 arg2, ["geometry", "double"], "Returns the geometry that represents all points whose distance from the specified geometry is less than or equal to the specified distance. If the points of the geometry are extremely close together (delta < 1e-8), this might return an empty geometry.\n\n`ST_Buffer(geometry, double) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Buffer>"),
 
-    (trino, st_centroid_geometry, arg1, "function doc"),
+    (trino, st_centroid_geometry, arg1, ["geometry"], "Returns the point value that is the mathematical centroid of a geometry.\n\n`ST_Centroid(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Centroid>"),
 
-    (trino, st_contains_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_contains_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if and only if no points of the second geometry lie in the exterior of the first geometry, and at least one point of the interior of the first geometry lies in the interior of the second geometry.\n\n`ST_Contains(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Contains>"),
 
-    (trino, st_convexhull_geometry, arg1, "function doc"),
+    (trino, st_convexhull_geometry, arg1, ["geometry"], "Returns the minimum convex geometry that encloses all input geometries.\n\n`ST_ConvexHull(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_ConvexHull>"),
 
-    (trino, st_coorddim_geometry, arg1, "function doc"),
+    (trino, st_coorddim_geometry, arg1, ["geometry"], "Returns the coordinate dimension of the geometry.\n\n`ST_CoordDim(geometry) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_CoordDim>"),
 
-    (trino, st_crosses_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_crosses_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the supplied geometries have some, but not all, interior points in common.\n\n`ST_Crosses(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Crosses>"),
 
-    (trino, st_difference_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_difference_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns the geometry value that represents the point set difference of the given geometries.\n\n`ST_Difference(geometry, geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Difference>"),
 
-    (trino, st_dimension_geometry, arg1, "function doc"),
+    (trino, st_dimension_geometry, arg1, ["geometry"], "Returns the inherent dimension of this geometry object, which must be less than or equal to the coordinate dimension.\n\n`ST_Dimension(geometry) -> tinyint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Dimension>"),
 
-    (trino, st_disjoint_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_disjoint_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the give geometries do not spatially intersect – if they do not share any space together.\n\n`ST_Disjoint(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Disjoint>"),
 
-    (trino, st_distance_geometry_geometry, arg1 arg2, "function doc"),
-    (trino, st_distance_sphericalgeography_sphericalgeography, arg1 arg2, "function doc"),
+    (trino, st_distance_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns the great-circle distance in meters between two SphericalGeography points.\n\n`ST_Distance(geometry, geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Distance>"),
+    (trino, st_distance_sphericalgeography_sphericalgeography, arg1 arg2, ["sphericalgeography", "sphericalgeography"], "Returns the great-circle distance in meters between two SphericalGeography points.\n\n`ST_Distance(sphericalgeography, sphericalgeography) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Distance>"),
 
-    (trino, st_endpoint_geometry, arg1, "function doc"),
+    (trino, st_endpoint_geometry, arg1, ["geometry"], "Returns the last point of a LineString geometry as a Point. This is a shortcut for ST_PointN(geometry, ST_NumPoints(geometry)).\n\n`ST_EndPoint(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_EndPoint>"),
 
-    (trino, st_envelope_geometry, arg1, "function doc"),
+    (trino, st_envelope_geometry, arg1, ["geometry"], "Returns the bounding rectangular polygon of a geometry.\n\n`ST_Envelope(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Envelope>"),
 
-    (trino, st_envelopeaspts_geometry, arg1, "function doc"),
+    (trino, st_envelopeaspts_geometry, arg1, ["geometry"], "Returns an array of two points. the lower left and upper right corners of the bounding rectangular polygon of a geometry. Returns NULL if input geometry is empty.\n\n`ST_EnvelopeAsPts(geometry) -> array<geometry>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_EnvelopeAsPts>"),
 
-    (trino, st_equals_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_equals_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the given geometries represent the same geometry.\n\n`ST_Equals(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Equals>"),
 
-    (trino, st_exteriorring_geometry, arg1, "function doc"),
+    (trino, st_exteriorring_geometry, arg1, ["geometry"], "Returns a line string representing the exterior ring of the input polygon.\n\n`ST_ExteriorRing(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_ExteriorRing>"),
 
-    (trino, st_geometries_geometry, arg1, "function doc"),
+    (trino, st_geometries_geometry, arg1, ["geometry"], "Returns an array of geometries in the specified collection. Returns a one-element array if the input geometry is not a multi-geometry. Returns NULL if input geometry is empty.\n\n`ST_Geometries(geometry) -> array<geometry>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Geometries>"),
 
-    (trino, st_geometryfromtext_varchar, arg1, "function doc"),
+    (trino, st_geometryfromtext_varchar, arg1, ["varchar"], "Returns a geometry type object from WKT representation.\n\n`ST_GeometryFromText(varchar) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_GeometryFromText>"),
 
-    (trino, st_geometryn_geometry_bigint, arg1 arg2, "function doc"),
+    (trino, st_geometryn_geometry_bigint, arg1 arg2, ["geometry", "bigint"], "Returns the geometry element at a given index (indices start at 1). If the geometry is a collection of geometries (e.g., GEOMETRYCOLLECTION or MULTI*), returns the geometry at a given index. If the given index is less than 1 or greater than the total number of elements in the collection, returns NULL. Use ST_NumGeometries() to find out the total number of elements. Singular geometries (e.g., POINT, LINESTRING, POLYGON), are treated as collections of one element. Empty geometries are treated as empty collections.\n\n`ST_GeometryN(geometry, bigint) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_GeometryN>"),
 
-    (trino, st_geometrytype_geometry, arg1, "function doc"),
+    (trino, st_geometrytype_geometry, arg1, ["geometry"], "Returns the type of the geometry.\n\n`ST_GeometryType(geometry) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_GeometryType>"),
 
-    (trino, st_geomfrombinary_varbinary, arg1, "function doc"),
+    (trino, st_geomfrombinary_varbinary, arg1, ["varbinary"], "Returns a geometry type object from WKB representation.\n\n`ST_GeomFromBinary(varbinary) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_GeomFromBinary>"),
 
-    (trino, st_interiorringn_geometry_bigint, arg1 arg2, "function doc"),
+    (trino, st_interiorringn_geometry_bigint, arg1 arg2, ["geometry", "bigint"], "Returns the interior ring element at the specified index (indices start at 1). If the given index is less than 1 or greater than the total number of interior rings in the input geometry, returns NULL. The input geometry must be a polygon. Use ST_NumInteriorRing() to find out the total number of elements.\n\n`ST_InteriorRingN(geometry, bigint) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_InteriorRingN>"),
 
-    (trino, st_interiorrings_geometry, arg1, "function doc"),
+    (trino, st_interiorrings_geometry, arg1, ["geometry"], "Returns an array of all interior rings found in the input geometry, or an empty array if the polygon has no interior rings. Returns NULL if the input geometry is empty. The input geometry must be a polygon.\n\n`ST_InteriorRings(geometry) -> array<geometry>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_InteriorRings>"),
 
-    (trino, st_intersection_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_intersection_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns the geometry value that represents the point set intersection of two geometries.\n\n`ST_Intersection(geometry, geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Intersection>"),
 
-    (trino, st_intersects_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_intersects_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the given geometries spatially intersect in two dimensions (share any portion of space) and false if they do not (they are disjoint).\n\n`ST_Intersects(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Intersects>"),
 
-    (trino, st_isclosed_geometry, arg1, "function doc"),
+    (trino, st_isclosed_geometry, arg1, ["geometry"], "Returns true if the linestring’s start and end points are coincident.\n\n`ST_IsClosed(geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_IsClosed>"),
 
-    (trino, st_isempty_geometry, arg1, "function doc"),
+    (trino, st_isempty_geometry, arg1, ["geometry"], "Returns true if this Geometry is an empty geometrycollection, polygon, point etc.\n\n`ST_IsEmpty(geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_IsEmpty>"),
 
-    (trino, st_isring_geometry, arg1, "function doc"),
+    (trino, st_isring_geometry, arg1, ["geometry"], "Returns true if and only if the line is closed and simple.\n\n`ST_IsRing(geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_IsRing>"),
 
-    (trino, st_issimple_geometry, arg1, "function doc"),
+    (trino, st_issimple_geometry, arg1, ["geometry"], "Returns true if this Geometry has no anomalous geometric points, such as self intersection or self tangency.\n\n`ST_IsSimple(geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_IsSimple>"),
 
-    (trino, st_isvalid_geometry, arg1, "function doc"),
+    (trino, st_isvalid_geometry, arg1, ["geometry"], "Returns true if and only if the input geometry is well formed. Use geometry_invalid_reason() to determine why the geometry is not well formed.\n\n`ST_IsValid(geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_IsValid>"),
 
-    (trino, st_length_geometry, arg1, "function doc"),
-    (trino, st_length_sphericalgeography, arg1, "function doc"),
+    (trino, st_length_geometry, arg1, ["geometry"], "Returns the length of a linestring or multi-linestring using Euclidean measurement on a two dimensional plane (based on spatial ref) in projected units.\n\n`ST_Length(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Length>"),
+    (trino, st_length_sphericalgeography, arg1, ["sphericalgeography"], "Returns the length of a linestring or multi-linestring using Euclidean measurement on a two dimensional plane (based on spatial ref) in projected units.\n\n`ST_Length(sphericalgeography) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Length>"),
 
-    (trino, st_linefromtext_varchar, arg1, "function doc"),
+    (trino, st_linefromtext_varchar, arg1, ["varchar"], "Returns a geometry type linestring object from WKT representation.\n\n`ST_LineFromText(varchar) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_LineFromText>"),
 
-    (trino, st_linestring_array_geometry, arg1, "function doc"),
+    (trino, st_linestring_array_geometry, arg1, ["array<geometry>"], "Returns a LineString formed from an array of points. If there are fewer than two non-empty points in the input array, an empty LineString will be returned. Array elements must not be NULL or the same as the previous element. The returned geometry may not be simple, e.g. may self-intersect or may contain duplicate vertexes depending on the input.\n\n`ST_LineString(array<geometry>) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_LineString>"),
 
-    (trino, st_multipoint_array_geometry, arg1, "function doc"),
+    (trino, st_multipoint_array_geometry, arg1, ["array<geometry>"], "Returns a MultiPoint geometry object formed from the specified points. Returns NULL if input array is empty. Array elements must not be NULL or empty. The returned geometry may not be simple and may contain duplicate points if input array has duplicates.\n\n`ST_MultiPoint(array<geometry>) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_MultiPoint>"),
 
-    (trino, st_numgeometries_geometry, arg1, "function doc"),
+    (trino, st_numgeometries_geometry, arg1, ["geometry"], "Returns the number of geometries in the collection. If the geometry is a collection of geometries (e.g., GEOMETRYCOLLECTION or MULTI*), returns the number of geometries, for single geometries returns 1, for empty geometries returns 0.\n\n`ST_NumGeometries(geometry) -> bigint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_NumGeometries>"),
 
-    (trino, st_numinteriorring_geometry, arg1, "function doc"),
+    (trino, st_numinteriorring_geometry, arg1, ["geometry"], "Returns the cardinality of the collection of interior rings of a polygon.\n\n`ST_NumInteriorRing(geometry) -> bigint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_NumInteriorRing>"),
 
-    (trino, st_numpoints_geometry, arg1, "function doc"),
+    (trino, st_numpoints_geometry, arg1, ["geometry"], "Returns the number of points in a geometry. This is an extension to the SQL/MM ST_NumPoints function which only applies to point and linestring.\n\n`ST_NumPoints(geometry) -> bigint`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_NumPoints>"),
 
-    (trino, st_overlaps_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_overlaps_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the given geometries share space, are of the same dimension, but are not completely contained by each other.\n\n`ST_Overlaps(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Overlaps>"),
 
-    (trino, st_point_double_double, arg1 arg2, "function doc"),
+    (trino, st_point_double_double, arg1 arg2, ["double", "double"], "Returns a geometry type point object with the given coordinate values.\n\n`ST_Point(double, double) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Point>"),
 
-    (trino, st_pointn_geometry_bigint, arg1 arg2, "function doc"),
+    (trino, st_pointn_geometry_bigint, arg1 arg2, ["geometry", "bigint"], "Returns the vertex of a linestring at a given index (indices start at 1). If the given index is less than 1 or greater than the total number of elements in the collection, returns NULL. Use ST_NumPoints() to find out the total number of elements.\n\n`ST_PointN(geometry, bigint) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_PointN>"),
 
-    (trino, st_points_geometry, arg1, "function doc"),
+    (trino, st_points_geometry, arg1, ["geometry"], "Returns an array of points in a linestring.\n\n`ST_Points(geometry) -> array<geometry>`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Points>"),
 
-    (trino, st_polygon_varchar, arg1, "function doc"),
+    (trino, st_polygon_varchar, arg1, ["varchar"], "Returns a geometry type polygon object from WKT representation.\n\n`ST_Polygon(varchar) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Polygon>"),
 
-    (trino, st_relate_geometry_geometry_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, st_relate_geometry_geometry_varchar, arg1 arg2 arg3, ["geometry", "geometry", "varchar"], "Returns true if first geometry is spatially related to second geometry.\n\n`ST_Relate(geometry, geometry, varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Relate>"),
 
-    (trino, st_startpoint_geometry, arg1, "function doc"),
+    (trino, st_startpoint_geometry, arg1, ["geometry"], "Returns the first point of a LineString geometry as a Point. This is a shortcut for ST_PointN(geometry, 1).\n\n`ST_StartPoint(geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_StartPoint>"),
 
-    (trino, st_symdifference_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_symdifference_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns the geometry value that represents the point set symmetric difference of two geometries.\n\n`ST_SymDifference(geometry, geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_SymDifference>"),
 
-    (trino, st_touches_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_touches_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if the given geometries have at least one point in common, but their interiors do not intersect.\n\n`ST_Touches(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Touches>"),
 
-    (trino, st_union_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_union_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns a geometry that represents the point set union of the input geometries.\n\n`ST_Union(geometry, geometry) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Union>"),
 
-    (trino, st_within_geometry_geometry, arg1 arg2, "function doc"),
+    (trino, st_within_geometry_geometry, arg1 arg2, ["geometry", "geometry"], "Returns true if first geometry is completely inside second geometry.\n\n`ST_Within(geometry, geometry) -> boolean`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Within>"),
 
-    (trino, st_x_geometry, arg1, "function doc"),
+    (trino, st_x_geometry, arg1, ["geometry"], "Returns the X coordinate of the point.\n\n`ST_X(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_X>"),
 
-    (trino, st_xmax_geometry, arg1, "function doc"),
+    (trino, st_xmax_geometry, arg1, ["geometry"], "Returns X maxima of a bounding box of a geometry.\n\n`ST_XMax(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_XMax>"),
 
-    (trino, st_xmin_geometry, arg1, "function doc"),
+    (trino, st_xmin_geometry, arg1, ["geometry"], "Returns X minima of a bounding box of a geometry.\n\n`ST_XMin(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_XMin>"),
 
-    (trino, st_y_geometry, arg1, "function doc"),
+    (trino, st_y_geometry, arg1, ["geometry"], "Returns the Y coordinate of the point.\n\n`ST_Y(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_Y>"),
 
-    (trino, st_ymax_geometry, arg1, "function doc"),
+    (trino, st_ymax_geometry, arg1, ["geometry"], "Returns Y maxima of a bounding box of a geometry.\n\n`ST_YMax(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_YMax>"),
 
-    (trino, st_ymin_geometry, arg1, "function doc"),
+    (trino, st_ymin_geometry, arg1, ["geometry"], "Returns Y minima of a bounding box of a geometry.\n\n`ST_YMin(geometry) -> double`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#ST_YMin>"),
 
-    (trino, starts_with_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, starts_with_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Tests whether substring is a prefix of string.\n\n`starts_with(varchar, varchar) -> boolean`\n\nSee <https://trino.io/docs/current/functions/string.html#starts_with>"),
 
-    (trino, strpos_varchar_varchar, arg1 arg2, "function doc"),
-    (trino, strpos_varchar_varchar_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, strpos_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the starting position of the first instance of substring in string. Positions start with 1. If not found, 0 is returned.\n\n`strpos(varchar, varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#strpos>"),
+    (trino, strpos_varchar_varchar_bigint, arg1 arg2 arg3, ["varchar", "varchar", "bigint"], "Returns the starting position of the first instance of substring in string. Positions start with 1. If not found, 0 is returned.\n\n`strpos(varchar, varchar, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/string.html#strpos>"),
 
-    (trino, substr_varchar_bigint, arg1 arg2, "function doc"),
-    (trino, substr_varchar_bigint_bigint, arg1 arg2 arg3, "function doc"),
-    (trino, substr_varbinary_bigint, arg1 arg2, "function doc"),
-    (trino, substr_varbinary_bigint_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, substr_varchar_bigint, arg1 arg2, ["varchar", "bigint"], "This is an alias for substring().\n\n`substr(varchar, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#substr>"),
+    (trino, substr_varchar_bigint_bigint, arg1 arg2 arg3, ["varchar", "bigint", "bigint"], "This is an alias for substring().\n\n`substr(varchar, bigint, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#substr>"),
+    (trino, substr_varbinary_bigint, arg1 arg2, ["varbinary", "bigint"], "This is an alias for substring().\n\n`substr(varbinary, bigint) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#substr>"),
+    (trino, substr_varbinary_bigint_bigint, arg1 arg2 arg3, ["varbinary", "bigint", "bigint"], "This is an alias for substring().\n\n`substr(varbinary, bigint, bigint) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#substr>"),
 
-    (trino, substring_varchar_bigint, arg1 arg2, "function doc"),
-    (trino, substring_varchar_bigint_bigint, arg1 arg2 arg3, "function doc"),
+    (trino, substring_varchar_bigint, arg1 arg2, ["varchar", "bigint"], "Returns the rest of string from the starting position start. Positions start with 1. A negative starting position is interpreted as being relative to the end of the string.\n\n`substring(varchar, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#substring>"),
+    (trino, substring_varchar_bigint_bigint, arg1 arg2 arg3, ["varchar", "bigint", "bigint"], "Returns the rest of string from the starting position start. Positions start with 1. A negative starting position is interpreted as being relative to the end of the string.\n\n`substring(varchar, bigint, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#substring>"),
 
-    (trino, tan_double, arg1, "function doc"),
+    (trino, tan_double, arg1, ["double"], "Returns the tangent of x.\n\n`tan(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#tan>"),
 
-    (trino, tanh_double, arg1, "function doc"),
+    (trino, tanh_double, arg1, ["double"], "Returns the hyperbolic tangent of x.\n\n`tanh(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#tanh>"),
 
-    (trino, timestamp_objectid_timestamp_0, arg1, "function doc"),
+    (trino, timestamp_objectid_timestamp_0, arg1, ["timestamp(0)"], "Creates an ObjectId from a TIMESTAMP WITH TIME ZONE.\n\n`timestamp_objectid(timestamp(0)) -> objectid`\n\nSee <https://trino.io/docs/current/functions/../connector/mongodb.html#timestamp_objectid>"),
 
-    (trino, timezone_hour_time_p, arg1, "function doc"),
-    (trino, timezone_hour_timestamp_p, arg1, "function doc"),
+    (trino, timezone_hour_time_p_withtimezone, arg1, ["time(p) with time zone"], "Returns the hour of the time zone offset from timestamp.\n\n`timezone_hour(time(p) with time zone) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#timezone_hour>"),
+    (trino, timezone_hour_timestamp_p_withtimezone, arg1, ["timestamp(p) with time zone"], "Returns the hour of the time zone offset from timestamp.\n\n`timezone_hour(timestamp(p) with time zone) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#timezone_hour>"),
 
-    (trino, timezone_minute_time_p, arg1, "function doc"),
-    (trino, timezone_minute_timestamp_p, arg1, "function doc"),
+    (trino, timezone_minute_time_p_withtimezone, arg1, ["time(p) with time zone"], "Returns the minute of the time zone offset from timestamp.\n\n`timezone_minute(time(p) with time zone) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#timezone_minute>"),
+    (trino, timezone_minute_timestamp_p_withtimezone, arg1, ["timestamp(p) with time zone"], "Returns the minute of the time zone offset from timestamp.\n\n`timezone_minute(timestamp(p) with time zone) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#timezone_minute>"),
 
-    (trino, to_base_bigint_bigint, arg1 arg2, "function doc"),
+    (trino, to_base_bigint_bigint, arg1 arg2, ["bigint", "bigint"], "Returns the base-radix representation of x.\n\n`to_base(bigint, bigint) -> varchar`\n\nSee <https://trino.io/docs/current/functions/math.html#to_base>"),
 
-    (trino, to_base32_varbinary, arg1, "function doc"),
+    (trino, to_base32_varbinary, arg1, ["varbinary"], "Encodes binary into a base32 string representation.\n\n`to_base32(varbinary) -> varchar`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_base32>"),
 
-    (trino, to_base64_varbinary, arg1, "function doc"),
+    (trino, to_base64_varbinary, arg1, ["varbinary"], "Encodes binary into a base64 string representation.\n\n`to_base64(varbinary) -> varchar`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_base64>"),
 
-    (trino, to_base64url_varbinary, arg1, "function doc"),
+    (trino, to_base64url_varbinary, arg1, ["varbinary"], "Encodes binary into a base64 string representation using the URL safe alphabet.\n\n`to_base64url(varbinary) -> varchar`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_base64url>"),
 
-    (trino, to_big_endian_32_bigint, arg1, "function doc"),
+    (trino, to_big_endian_32_bigint, arg1, ["bigint"], "Encodes integer into a 32-bit two’s complement big-endian format.\n\n`to_big_endian_32(bigint) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_big_endian_32>"),
 
-    (trino, to_big_endian_64_bigint, arg1, "function doc"),
+    (trino, to_big_endian_64_bigint, arg1, ["bigint"], "Encodes bigint into a 64-bit two’s complement big-endian format.\n\n`to_big_endian_64(bigint) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_big_endian_64>"),
 
-    (trino, to_char_timestamp_p_varchar, arg1 arg2, "function doc"),
+    (trino, to_char_timestamp_p_varchar, arg1 arg2, ["timestamp(p)", "varchar"], "Formats timestamp as a string using format.\n\n`to_char(timestamp(p), varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/teradata.html#to_char>"),
 
-    (trino, to_date_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, to_date_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Parses string into a DATE using format.\n\n`to_date(varchar, varchar) -> date`\n\nSee <https://trino.io/docs/current/functions/teradata.html#to_date>"),
 
-    (trino, to_encoded_polyline_geometry, arg1, "function doc"),
+    (trino, to_encoded_polyline_geometry, arg1, ["geometry"], "Encodes a linestring or multipoint to a polyline.\n\n`to_encoded_polyline(geometry) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#to_encoded_polyline>"),
 
-    (trino, to_geojson_geometry_sphericalgeography, arg1, "function doc"),
+    (trino, to_geojson_geometry_sphericalgeography, arg1, ["sphericalgeography"], "Returns the GeoJSON encoded defined by the input spherical geography.\n\n`to_geojson_geometry(sphericalgeography) -> varchar`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#to_geojson_geometry>"),
 
-    (trino, to_geometry_sphericalgeography, arg1, "function doc"),
+    (trino, to_geometry_sphericalgeography, arg1, ["sphericalgeography"], "Converts a SphericalGeography object to a Geometry object.\n\n`to_geometry(sphericalgeography) -> geometry`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#to_geometry>"),
 
-    (trino, to_hex_varbinary, arg1, "function doc"),
+    (trino, to_hex_varbinary, arg1, ["varbinary"], "Encodes binary into a hex string representation.\n\n`to_hex(varbinary) -> varchar`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_hex>"),
 
-    (trino, to_ieee754_32_real, arg1, "function doc"),
+    (trino, to_ieee754_32_real, arg1, ["real"], "Encodes real into a 32-bit big-endian binary according to IEEE 754 single-precision floating-point format.\n\n`to_ieee754_32(real) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_ieee754_32>"),
 
-    (trino, to_ieee754_64_double, arg1, "function doc"),
+    (trino, to_ieee754_64_double, arg1, ["double"], "Encodes double into a 64-bit big-endian binary according to IEEE 754 double-precision floating-point format.\n\n`to_ieee754_64(double) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#to_ieee754_64>"),
 
-    (trino, to_iso8601_date, arg1, "function doc"),
-    (trino, to_iso8601_timestamp_p, arg1, "function doc"),
+    (trino, to_iso8601_date, arg1, ["date"], "Formats x as an ISO 8601 string. x can be date, timestamp, or timestamp with time zone.\n\n`to_iso8601(date) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#to_iso8601>"),
+    (trino, to_iso8601_timestamp_p, arg1, ["timestamp(p)"], "Formats x as an ISO 8601 string. x can be date, timestamp, or timestamp with time zone.\n\n`to_iso8601(timestamp(p)) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#to_iso8601>"),
+    (trino, to_iso8601_timestamp_p_withtimezone, arg1, ["timestamp(p) with time zone"], "Formats x as an ISO 8601 string. x can be date, timestamp, or timestamp with time zone.\n\n`to_iso8601(timestamp(p) with time zone) -> varchar`\n\nSee <https://trino.io/docs/current/functions/datetime.html#to_iso8601>"),
 
-    (trino, to_milliseconds_intervaldaytosecond, arg1, "function doc"),
+    (trino, to_milliseconds_intervaldaytosecond, arg1, ["interval day to second"], "Returns the day-to-second interval as milliseconds.\n\n`to_milliseconds(interval day to second) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#to_milliseconds>"),
 
-    (trino, to_spherical_geography_geometry, arg1, "function doc"),
+    (trino, to_spherical_geography_geometry, arg1, ["geometry"], "Converts a Geometry object to a SphericalGeography object on the sphere of the Earth’s radius. This function is only applicable to POINT, MULTIPOINT, LINESTRING, MULTILINESTRING, POLYGON, MULTIPOLYGON geometries defined in 2D space, or GEOMETRYCOLLECTION of such geometries. For each point of the input geometry, it verifies that point.x is within [-180.0, 180.0] and point.y is within [-90.0, 90.0], and uses them as (longitude, latitude) degrees to construct the shape of the SphericalGeography result.\n\n`to_spherical_geography(geometry) -> sphericalgeography`\n\nSee <https://trino.io/docs/current/functions/geospatial.html#to_spherical_geography>"),
 
-    (trino, to_timestamp_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, to_timestamp_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Parses string into a TIMESTAMP using format.\n\n`to_timestamp(varchar, varchar) -> timestamp`\n\nSee <https://trino.io/docs/current/functions/teradata.html#to_timestamp>"),
 
-    (trino, to_unixtime_timestamp_p, arg1, "function doc"),
+    (trino, to_unixtime_timestamp_p_withtimezone, arg1, ["timestamp(p) with time zone"], "Returns timestamp as a UNIX timestamp.\n\n`to_unixtime(timestamp(p) with time zone) -> double`\n\nSee <https://trino.io/docs/current/functions/datetime.html#to_unixtime>"),
 
-    (trino, to_utf8_varchar, arg1, "function doc"),
+    (trino, to_utf8_varchar, arg1, ["varchar"], "Encodes string into a UTF-8 varbinary representation.\n\n`to_utf8(varchar) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/string.html#to_utf8>"),
 
-    (trino, transform_array_1_function_1_11, arg1 arg2, "function doc"),
+    (trino, transform_array_1_function_1_11, arg1 arg2, ["array<$1>", "function($1, $11)"], "Returns an array that is the result of applying function to each element of array.\n\n`transform(array<$1>, function($1, $11)) -> array<$11>`\n\nSee <https://trino.io/docs/current/functions/array.html#transform>"),
 
-    (trino, transform_keys_map_13_5_function_13_5_12, arg1 arg2, "function doc"),
+    (trino, transform_keys_map_13_5_function_13_5_12, arg1 arg2, ["map<$13, $5>", "function($13, $5, $12)"], "Returns a map that applies function to each entry of map and transforms the keys.\n\n`transform_keys(map<$13, $5>, function($13, $5, $12)) -> map<$12, $5>`\n\nSee <https://trino.io/docs/current/functions/map.html#transform_keys>"),
 
-    (trino, transform_values_map_4_8_function_4_8_7, arg1 arg2, "function doc"),
+    (trino, transform_values_map_4_8_function_4_8_7, arg1 arg2, ["map<$4, $8>", "function($4, $8, $7)"], "Returns a map that applies function to each entry of map and transforms the values.\n\n`transform_values(map<$4, $8>, function($4, $8, $7)) -> map<$4, $7>`\n\nSee <https://trino.io/docs/current/functions/map.html#transform_values>"),
 
-    (trino, translate_varchar_varchar_varchar, arg1 arg2 arg3, "function doc"),
+    (trino, translate_varchar_varchar_varchar, arg1 arg2 arg3, ["varchar", "varchar", "varchar"], "Returns the source string translated by replacing characters found in the from string with the corresponding characters in the to string.  If the from string contains duplicates, only the first is used.  If the source character does not exist in the from string, the source character will be copied without translation.  If the index of the matching character in the from string is beyond the length of the to string, the source character will be omitted from the resulting string.\n\n`translate(varchar, varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#translate>"),
 
-    (trino, trim_varchar, arg1, "function doc"),
-    (trino, trim_varchar_codepoints, arg1 arg2, "function doc"),
+    (trino, trim_varchar, arg1, ["varchar"], "Removes any leading and/or trailing characters as specified up to and including string from source.\n\n`trim(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#trim>"),
+    (trino, trim_varchar_codepoints, arg1 arg2, ["varchar", "codepoints"], "Removes any leading and/or trailing characters as specified up to and including string from source.\n\n`trim(varchar, codepoints) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#trim>"),
 
-    (trino, trim_array_array_3_bigint, arg1 arg2, "function doc"),
+    (trino, trim_array_array_3_bigint, arg1 arg2, ["array<$3>", "bigint"], "Remove n elements from the end of array.\n\n`trim_array(array<$3>, bigint) -> array<$3>`\n\nSee <https://trino.io/docs/current/functions/array.html#trim_array>"),
 
-    (trino, truncate_decimal_p_s_bigint, arg1 arg2, "function doc"),
-    (trino, truncate_decimal_p_s, arg1, "function doc"),
-    (trino, truncate_double, arg1, "function doc"),
-    (trino, truncate_real, arg1, "function doc"),
+    (trino, truncate_decimal_p_s_bigint, arg1 arg2, ["decimal(p, s)", "bigint"], "Returns x rounded to integer by dropping digits after decimal point.\n\n`truncate(decimal(p, s), bigint) -> decimal(p, s)`\n\nSee <https://trino.io/docs/current/functions/math.html#truncate>"),
+    (trino, truncate_decimal_p_s, arg1, ["decimal(p, s)"], "Returns x rounded to integer by dropping digits after decimal point.\n\n`truncate(decimal(p, s)) -> decimal(rp, 0)`\n\nSee <https://trino.io/docs/current/functions/math.html#truncate>"),
+    (trino, truncate_double, arg1, ["double"], "Returns x rounded to integer by dropping digits after decimal point.\n\n`truncate(double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#truncate>"),
+    (trino, truncate_real, arg1, ["real"], "Returns x rounded to integer by dropping digits after decimal point.\n\n`truncate(real) -> real`\n\nSee <https://trino.io/docs/current/functions/math.html#truncate>"),
 
-    (trino, try_1, arg1, "function doc"),
+    (trino, try_1, arg1, ["$1"], "Evaluate an expression and handle certain types of errors by returning NULL.\n\n`try($1) -> $1`\n\nSee <https://trino.io/docs/current/functions/conditional.html#try-function>"),
 
-    (trino, typeof_1, arg1, "function doc"),
+    (trino, try_cast_1_varchar, arg1 arg2, ["$1", "varchar"], "Like cast, but returns NULL if the value cannot be cast.\n\n`try_cast($1, varchar) -> unknown`\n\nSee <https://trino.io/docs/current/functions/conversion.html#try-cast>"),
 
-    (trino, upper_varchar, arg1, "function doc"),
+    (trino, typeof_1, arg1, ["$1"], "Returns the name of the type of the provided expression.\n\n`typeof($1) -> varchar`\n\nSee <https://trino.io/docs/current/functions/conversion.html#typeof>"),
 
-    (trino, url_decode_varchar, arg1, "function doc"),
+    (trino, upper_varchar, arg1, ["varchar"], "Converts string to uppercase.\n\n`upper(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#upper>"),
 
-    (trino, url_encode_varchar, arg1, "function doc"),
+    (trino, url_decode_varchar, arg1, ["varchar"], "Unescapes the URL encoded value. This function is the inverse of url_encode().\n\n`url_decode(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_decode>"),
 
-    (trino, url_extract_fragment_varchar, arg1, "function doc"),
+    (trino, url_encode_varchar, arg1, ["varchar"], "Escapes value by encoding it so that it can be safely included in URL query parameter names and values.\n\n`url_encode(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_encode>"),
 
-    (trino, url_extract_host_varchar, arg1, "function doc"),
+    (trino, url_extract_fragment_varchar, arg1, ["varchar"], "Returns the fragment identifier from url.\n\n`url_extract_fragment(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_fragment>"),
 
-    (trino, url_extract_parameter_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, url_extract_host_varchar, arg1, ["varchar"], "Returns the host from url.\n\n`url_extract_host(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_host>"),
 
-    (trino, url_extract_path_varchar, arg1, "function doc"),
+    (trino, url_extract_parameter_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the value of the first query string parameter named name from url. Parameter extraction is handled in the typical manner as specified by RFC 1866#section-8.2.1.\n\n`url_extract_parameter(varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_parameter>"),
 
-    (trino, url_extract_port_varchar, arg1, "function doc"),
+    (trino, url_extract_path_varchar, arg1, ["varchar"], "Returns the path from url.\n\n`url_extract_path(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_path>"),
 
-    (trino, url_extract_protocol_varchar, arg1, "function doc"),
+    (trino, url_extract_port_varchar, arg1, ["varchar"], "Returns the port number from url.\n\n`url_extract_port(varchar) -> bigint`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_port>"),
 
-    (trino, url_extract_query_varchar, arg1, "function doc"),
+    (trino, url_extract_protocol_varchar, arg1, ["varchar"], "Returns the protocol from url.\n\n`url_extract_protocol(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_protocol>"),
 
-    (trino, uuid, , "function doc"),
+    (trino, url_extract_query_varchar, arg1, ["varchar"], "Returns the query string from url.\n\n`url_extract_query(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/url.html#url_extract_query>"),
 
-    (trino, value_at_quantile_qdigest_double, arg1 arg2, "function doc"),
-    (trino, value_at_quantile_tdigest_double, arg1 arg2, "function doc"),
+    (trino, uuid, , [], "Returns a pseudo randomly generated UUID (type 4).\n\n`uuid() -> uuid`\n\nSee <https://trino.io/docs/current/functions/uuid.html#uuid>"),
 
-    (trino, values_at_quantiles_qdigest_array_double, arg1 arg2, "function doc"),
-    (trino, values_at_quantiles_tdigest_array_double, arg1 arg2, "function doc"),
+    (trino, value_at_quantile_qdigest_double, arg1 arg2, ["qdigest", "double"], "Returns the approximate percentile value from the quantile digest given the number quantile between 0 and 1.\n\n`value_at_quantile(qdigest, double) -> bigint`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#value_at_quantile>"),
+    (trino, value_at_quantile_tdigest_double, arg1 arg2, ["tdigest", "double"], "Returns the approximate percentile value from the quantile digest given the number quantile between 0 and 1.\n\n`value_at_quantile(tdigest, double) -> double`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#value_at_quantile>"),
 
-    (trino, week_date, arg1, "function doc"),
-    (trino, week_timestamp_p, arg1, "function doc"),
+    (trino, values_at_quantiles_qdigest_array_double, arg1 arg2, ["qdigest", "array<double>"], "Returns the approximate percentile values as an array given the input quantile digest and array of values between 0 and 1 which represent the quantiles to return.\n\n`values_at_quantiles(qdigest, array<double>) -> array<bigint>`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#values_at_quantiles>"),
+    (trino, values_at_quantiles_tdigest_array_double, arg1 arg2, ["tdigest", "array<double>"], "Returns the approximate percentile values as an array given the input quantile digest and array of values between 0 and 1 which represent the quantiles to return.\n\n`values_at_quantiles(tdigest, array<double>) -> array<double>`\n\nSee <https://trino.io/docs/current/functions/qdigest.html#values_at_quantiles>"),
 
-    (trino, week_of_year_date, arg1, "function doc"),
-    (trino, week_of_year_timestamp_p, arg1, "function doc"),
+    (trino, week_date, arg1, ["date"], "Returns the ISO week of the year from x. The value ranges from 1 to 53.\n\n`week(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#week>"),
+    (trino, week_timestamp_p, arg1, ["timestamp(p)"], "Returns the ISO week of the year from x. The value ranges from 1 to 53.\n\n`week(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#week>"),
 
-    (trino, width_bucket_double_array_double, arg1 arg2, "function doc"),
-    (trino, width_bucket_double_double_double_bigint, arg1 arg2 arg3 arg4, "function doc"),
+    (trino, week_of_year_date, arg1, ["date"], "This is an alias for week().\n\n`week_of_year(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#week_of_year>"),
+    (trino, week_of_year_timestamp_p, arg1, ["timestamp(p)"], "This is an alias for week().\n\n`week_of_year(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#week_of_year>"),
 
-    (trino, wilson_interval_lower_bigint_bigint_double, arg1 arg2 arg3, "function doc"),
+    (trino, width_bucket_double_array_double, arg1 arg2, ["double", "array<double>"], "Returns the bin number of x in an equi-width histogram with the specified bound1 and bound2 bounds and n number of buckets.\n\n`width_bucket(double, array<double>) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#width_bucket>"),
+    (trino, width_bucket_double_double_double_bigint, arg1 arg2 arg3 arg4, ["double", "double", "double", "bigint"], "Returns the bin number of x in an equi-width histogram with the specified bound1 and bound2 bounds and n number of buckets.\n\n`width_bucket(double, double, double, bigint) -> bigint`\n\nSee <https://trino.io/docs/current/functions/math.html#width_bucket>"),
 
-    (trino, wilson_interval_upper_bigint_bigint_double, arg1 arg2 arg3, "function doc"),
+    (trino, wilson_interval_lower_bigint_bigint_double, arg1 arg2 arg3, ["bigint", "bigint", "double"], "Returns the lower bound of the Wilson score interval of a Bernoulli trial process at a confidence specified by the z-score z.\n\n`wilson_interval_lower(bigint, bigint, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#wilson_interval_lower>"),
 
-    (trino, with_timezone_timestamp_p_varchar, arg1 arg2, "function doc"),
+    (trino, wilson_interval_upper_bigint_bigint_double, arg1 arg2 arg3, ["bigint", "bigint", "double"], "Returns the upper bound of the Wilson score interval of a Bernoulli trial process at a confidence specified by the z-score z.\n\n`wilson_interval_upper(bigint, bigint, double) -> double`\n\nSee <https://trino.io/docs/current/functions/math.html#wilson_interval_upper>"),
 
-    (trino, word_stem_varchar, arg1, "function doc"),
-    (trino, word_stem_varchar_varchar, arg1 arg2, "function doc"),
+    (trino, with_timezone_timestamp_p_varchar, arg1 arg2, ["timestamp(p)", "varchar"], "Returns the timestamp specified in timestamp with the time zone specified in zone with precision p.\n\n`with_timezone(timestamp(p), varchar) -> timestamp(p) with time zone`\n\nSee <https://trino.io/docs/current/functions/datetime.html#with_timezone>"),
 
-    (trino, xxhash64_varbinary, arg1, "function doc"),
+    (trino, word_stem_varchar, arg1, ["varchar"], "Returns the stem of word in the English language.\n\n`word_stem(varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#word_stem>"),
+    (trino, word_stem_varchar_varchar, arg1 arg2, ["varchar", "varchar"], "Returns the stem of word in the English language.\n\n`word_stem(varchar, varchar) -> varchar`\n\nSee <https://trino.io/docs/current/functions/string.html#word_stem>"),
 
-    (trino, year_date, arg1, "function doc"),
-    (trino, year_intervalyeartomonth, arg1, "function doc"),
-    (trino, year_timestamp_p, arg1, "function doc"),
+    (trino, xxhash64_varbinary, arg1, ["varbinary"], "Computes the xxHash64 hash of binary.\n\n`xxhash64(varbinary) -> varbinary`\n\nSee <https://trino.io/docs/current/functions/binary.html#xxhash64>"),
 
-    (trino, year_of_week_date, arg1, "function doc"),
-    (trino, year_of_week_timestamp_p, arg1, "function doc"),
+    (trino, year_date, arg1, ["date"], "Returns the year from x.\n\n`year(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#year>"),
+    (trino, year_intervalyeartomonth, arg1, ["interval year to month"], "Returns the year from x.\n\n`year(interval year to month) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#year>"),
+    (trino, year_timestamp_p, arg1, ["timestamp(p)"], "Returns the year from x.\n\n`year(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#year>"),
 
-    (trino, yow_date, arg1, "function doc"),
-    (trino, yow_timestamp_p, arg1, "function doc"),
+    (trino, year_of_week_date, arg1, ["date"], "Returns the year of the ISO week from x.\n\n`year_of_week(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#year_of_week>"),
+    (trino, year_of_week_timestamp_p, arg1, ["timestamp(p)"], "Returns the year of the ISO week from x.\n\n`year_of_week(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#year_of_week>"),
 
-    (trino, zip_array_14_array_15, arg1 arg2, "function doc"),
-    (trino, zip_array_14_array_15_array_16, arg1 arg2 arg3, "function doc"),
-    (trino, zip_array_14_array_15_array_16_array_17, arg1 arg2 arg3 arg4, "function doc"),
-    (trino, zip_array_14_array_15_array_16_array_17_array_18, arg1 arg2 arg3 arg4 arg5, "function doc"),
+    (trino, yow_date, arg1, ["date"], "This is an alias for year_of_week().\n\n`yow(date) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#yow>"),
+    (trino, yow_timestamp_p, arg1, ["timestamp(p)"], "This is an alias for year_of_week().\n\n`yow(timestamp(p)) -> bigint`\n\nSee <https://trino.io/docs/current/functions/datetime.html#yow>"),
 
-    (trino, zip_with_array_1_array_11_function_1_11_9, arg1 arg2 arg3, "function doc"),
+    (trino, zip_array_14_array_15, arg1 arg2, ["array<$14>", "array<$15>"], "Merges the given arrays, element-wise, into a single array of rows. The M-th element of the N-th argument will be the N-th field of the M-th output element. If the arguments have an uneven length, missing values are filled with NULL.\n\n`zip(array<$14>, array<$15>) -> array<row(c0 $14, c1 $15)>`\n\nSee <https://trino.io/docs/current/functions/array.html#zip>"),
+    (trino, zip_array_14_array_15_array_16, arg1 arg2 arg3, ["array<$14>", "array<$15>", "array<$16>"], "Merges the given arrays, element-wise, into a single array of rows. The M-th element of the N-th argument will be the N-th field of the M-th output element. If the arguments have an uneven length, missing values are filled with NULL.\n\n`zip(array<$14>, array<$15>, array<$16>) -> array<row(c0 $14, c1 $15, c2 $16)>`\n\nSee <https://trino.io/docs/current/functions/array.html#zip>"),
+    (trino, zip_array_14_array_15_array_16_array_17, arg1 arg2 arg3 arg4, ["array<$14>", "array<$15>", "array<$16>", "array<$17>"], "Merges the given arrays, element-wise, into a single array of rows. The M-th element of the N-th argument will be the N-th field of the M-th output element. If the arguments have an uneven length, missing values are filled with NULL.\n\n`zip(array<$14>, array<$15>, array<$16>, array<$17>) -> array<row(c0 $14, c1 $15, c2 $16, c3 $17)>`\n\nSee <https://trino.io/docs/current/functions/array.html#zip>"),
+    (trino, zip_array_14_array_15_array_16_array_17_array_18, arg1 arg2 arg3 arg4 arg5, ["array<$14>", "array<$15>", "array<$16>", "array<$17>", "array<$18>"], "Merges the given arrays, element-wise, into a single array of rows. The M-th element of the N-th argument will be the N-th field of the M-th output element. If the arguments have an uneven length, missing values are filled with NULL.\n\n`zip(array<$14>, array<$15>, array<$16>, array<$17>, array<$18>) -> array<row(c0 $14, c1 $15, c2 $16, c3 $17, c4 $18)>`\n\nSee <https://trino.io/docs/current/functions/array.html#zip>"),
+
+    (trino, zip_with_array_1_array_11_function_1_11_9, arg1 arg2 arg3, ["array<$1>", "array<$11>", "function($1, $11, $9)"], "Merges the two given arrays, element-wise, into a single array using function. If one array is shorter, nulls are appended at the end to match the length of the longer array, before applying function.\n\n`zip_with(array<$1>, array<$11>, function($1, $11, $9)) -> array<$9>`\n\nSee <https://trino.io/docs/current/functions/array.html#zip_with>"),
 
 );
+
+// Export the aggregate functions out of this package
+export_aggregate_functions!(
+    (trino, approx_distinct_boolean, ["boolean"]),
+    (trino, approx_distinct_boolean_double, ["boolean", "double"]),
+    (trino, approx_distinct_1, ["$1"]),
+    (trino, approx_distinct_1_double, ["$1", "double"]),
+    (trino, approx_distinct_unknown, ["unknown"]),
+    (trino, approx_distinct_unknown_double, ["unknown", "double"]),
+
+    (trino, approx_most_frequent_bigint_bigint_bigint, ["bigint", "bigint", "bigint"]),
+    (trino, approx_most_frequent_bigint_varchar_bigint, ["bigint", "varchar", "bigint"]),
+
+    (trino, approx_percentile_bigint_array_double, ["bigint", "array<double>"]),
+    (trino, approx_percentile_bigint_double_array_double, ["bigint", "double", "array<double>"]),
+    (trino, approx_percentile_double_array_double, ["double", "array<double>"]),
+    (trino, approx_percentile_double_double_array_double, ["double", "double", "array<double>"]),
+    (trino, approx_percentile_real_array_double, ["real", "array<double>"]),
+    (trino, approx_percentile_real_double_array_double, ["real", "double", "array<double>"]),
+    (trino, approx_percentile_bigint_double, ["bigint", "double"]),
+    (trino, approx_percentile_bigint_double_double, ["bigint", "double", "double"]),
+    (trino, approx_percentile_bigint_double_double_double, ["bigint", "double", "double", "double"]),
+    (trino, approx_percentile_double_double, ["double", "double"]),
+    (trino, approx_percentile_double_double_double, ["double", "double", "double"]),
+    (trino, approx_percentile_double_double_double_double, ["double", "double", "double", "double"]),
+    (trino, approx_percentile_real_double, ["real", "double"]),
+    (trino, approx_percentile_real_double_double, ["real", "double", "double"]),
+    (trino, approx_percentile_real_double_double_double, ["real", "double", "double", "double"]),
+
+    (trino, approx_set_bigint, ["bigint"]),
+    (trino, approx_set_double, ["double"]),
+    (trino, approx_set_varchar, ["varchar"]),
+
+    (trino, arbitrary_1, ["$1"]),
+
+    (trino, array_agg_1, ["$1"]),
+
+    (trino, avg_double, ["double"]),
+    (trino, avg_decimal_p_s, ["decimal(p, s)"]),
+    (trino, avg_intervaldaytosecond, ["interval day to second"]),
+    (trino, avg_intervalyeartomonth, ["interval year to month"]),
+    (trino, avg_real, ["real"]),
+
+    (trino, bitwise_and_agg_bigint, ["bigint"]),
+
+    (trino, bitwise_or_agg_bigint, ["bigint"]),
+
+    (trino, bool_and_boolean, ["boolean"]),
+
+    (trino, bool_or_boolean, ["boolean"]),
+
+    (trino, checksum_1, ["$1"]),
+
+    (trino, convex_hull_agg_geometry, ["geometry"]),
+
+    (trino, corr_double_double, ["double", "double"]),
+    (trino, corr_real_real, ["real", "real"]),
+
+    (trino, count, []),
+    (trino, count_1, ["$1"]),
+
+    (trino, count_if_boolean, ["boolean"]),
+
+    (trino, covar_pop_double_double, ["double", "double"]),
+    (trino, covar_pop_real_real, ["real", "real"]),
+
+    (trino, covar_samp_double_double, ["double", "double"]),
+    (trino, covar_samp_real_real, ["real", "real"]),
+
+    (trino, evaluate_classifier_predictions_bigint_bigint, ["bigint", "bigint"]),
+    (trino, evaluate_classifier_predictions_varchar_varchar, ["varchar", "varchar"]),
+
+    (trino, every_boolean, ["boolean"]),
+
+    (trino, geometric_mean_bigint, ["bigint"]),
+    (trino, geometric_mean_double, ["double"]),
+    (trino, geometric_mean_real, ["real"]),
+
+    (trino, geometry_union_agg_geometry, ["geometry"]),
+
+    (trino, grouping, variadic []),
+
+    (trino, histogram_1, ["$1"]),
+
+    (trino, kurtosis_bigint, ["bigint"]),
+    (trino, kurtosis_double, ["double"]),
+
+    (trino, learn_classifier_bigint_map_bigint_double, ["bigint", "map<bigint, double>"]),
+    (trino, learn_classifier_double_map_bigint_double, ["double", "map<bigint, double>"]),
+    (trino, learn_classifier_varchar_map_bigint_double, ["varchar", "map<bigint, double>"]),
+
+    (trino, learn_libsvm_classifier_bigint_map_bigint_double_varchar, ["bigint", "map<bigint, double>", "varchar"]),
+    (trino, learn_libsvm_classifier_double_map_bigint_double_varchar, ["double", "map<bigint, double>", "varchar"]),
+    (trino, learn_libsvm_classifier_varchar_map_bigint_double_varchar, ["varchar", "map<bigint, double>", "varchar"]),
+
+    (trino, learn_libsvm_regressor_bigint_map_bigint_double_varchar, ["bigint", "map<bigint, double>", "varchar"]),
+    (trino, learn_libsvm_regressor_double_map_bigint_double_varchar, ["double", "map<bigint, double>", "varchar"]),
+
+    (trino, learn_regressor_bigint_map_bigint_double, ["bigint", "map<bigint, double>"]),
+    (trino, learn_regressor_double_map_bigint_double, ["double", "map<bigint, double>"]),
+
+    (trino, listagg_varchar_varchar_boolean_varchar_boolean, ["varchar", "varchar", "boolean", "varchar", "boolean"]),
+
+    (trino, make_set_digest_1, ["$1"]),
+
+    (trino, map_agg_4_5, ["$4", "$5"]),
+
+    (trino, map_union_map_4_5, ["map<$4, $5>"]),
+
+    (trino, max_3_bigint, ["$3", "bigint"]),
+    (trino, max_1, variadic ["$1"]),
+
+    (trino, max_by_5_4_bigint, ["$5", "$4", "bigint"]),
+    (trino, max_by_5_4, ["$5", "$4"]),
+
+    (trino, merge_hyperloglog, ["hyperloglog"]),
+    (trino, merge_qdigest, ["qdigest"]),
+    (trino, merge_tdigest, ["tdigest"]),
+
+    (trino, merge_set_digest_setdigest, ["setdigest"]),
+
+    (trino, min_3_bigint, ["$3", "bigint"]),
+    (trino, min_1, variadic ["$1"]),
+
+    (trino, min_by_5_4_bigint, ["$5", "$4", "bigint"]),
+    (trino, min_by_5_4, ["$5", "$4"]),
+
+    (trino, multimap_agg_4_5, ["$4", "$5"]),
+
+    (trino, numeric_histogram_bigint_double, ["bigint", "double"]),
+    (trino, numeric_histogram_bigint_double_double, ["bigint", "double", "double"]),
+    (trino, numeric_histogram_bigint_real, ["bigint", "real"]),
+    (trino, numeric_histogram_bigint_real_double, ["bigint", "real", "double"]),
+
+    (trino, qdigest_agg_bigint, ["bigint"]),
+    (trino, qdigest_agg_bigint_bigint, ["bigint", "bigint"]),
+    (trino, qdigest_agg_bigint_bigint_double, ["bigint", "bigint", "double"]),
+    (trino, qdigest_agg_double, ["double"]),
+    (trino, qdigest_agg_double_bigint, ["double", "bigint"]),
+    (trino, qdigest_agg_double_bigint_double, ["double", "bigint", "double"]),
+    (trino, qdigest_agg_real, ["real"]),
+    (trino, qdigest_agg_real_bigint, ["real", "bigint"]),
+    (trino, qdigest_agg_real_bigint_double, ["real", "bigint", "double"]),
+
+    (trino, reduce_agg_1_10_function_10_1_10_function_10_10_10, ["$1", "$10", "function($10, $1, $10)", "function($10, $10, $10)"]),
+
+    (trino, regr_intercept_double_double, ["double", "double"]),
+    (trino, regr_intercept_real_real, ["real", "real"]),
+
+    (trino, regr_slope_double_double, ["double", "double"]),
+    (trino, regr_slope_real_real, ["real", "real"]),
+
+    (trino, skewness_bigint, ["bigint"]),
+    (trino, skewness_double, ["double"]),
+
+    (trino, spatial_partitioning_geometry, ["geometry"]),
+
+    (trino, stddev_bigint, ["bigint"]),
+    (trino, stddev_double, ["double"]),
+
+    (trino, stddev_pop_bigint, ["bigint"]),
+    (trino, stddev_pop_double, ["double"]),
+
+    (trino, stddev_samp_bigint, ["bigint"]),
+    (trino, stddev_samp_double, ["double"]),
+
+    (trino, sum_bigint, ["bigint"]),
+    (trino, sum_double, ["double"]),
+    (trino, sum_decimal_p_s, ["decimal(p, s)"]),
+    (trino, sum_real, ["real"]),
+    (trino, sum_intervaldaytosecond, ["interval day to second"]),
+    (trino, sum_intervalyeartomonth, ["interval year to month"]),
+
+    (trino, tdigest_agg_double, ["double"]),
+    (trino, tdigest_agg_double_double, ["double", "double"]),
+
+    (trino, var_pop_bigint, ["bigint"]),
+    (trino, var_pop_double, ["double"]),
+
+    (trino, var_samp_bigint, ["bigint"]),
+    (trino, var_samp_double, ["double"]),
+
+    (trino, variance_bigint, ["bigint"]),
+    (trino, variance_double, ["double"]),
+
+);
+
+// Export the window functions out of this package
+export_window_functions!(
+    (trino, cume_dist, []),
+    (trino, dense_rank, []),
+    (trino, first_value_1, ["$1"]),
+    (trino, lag_1, ["$1"]),
+    (trino, lag_1_bigint, ["$1", "bigint"]),
+    (trino, lag_1_bigint_1, ["$1", "bigint", "$1"]),
+    (trino, last_value_1, ["$1"]),
+    (trino, lead_1, ["$1"]),
+    (trino, lead_1_bigint, ["$1", "bigint"]),
+    (trino, lead_1_bigint_1, ["$1", "bigint", "$1"]),
+    (trino, nth_value_1_bigint, ["$1", "bigint"]),
+    (trino, ntile_bigint, ["bigint"]),
+    (trino, percent_rank, []),
+    (trino, rank, []),
+    (trino, row_number, []),
+);
+
+// Export the table functions out of this package
+export_table_functions!();
@@ -31,7 +31,7 @@ fn mod_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     let arg0 = columnar_to_datum(&args[0]);
     let arg1 = columnar_to_datum(&args[1]);
     let result = arrow::compute::kernels::numeric::rem(&*arg0, &*arg1)?;
-    Ok(array_to_columnar(result))
+    array_to_columnar(result, &[&args[0], &args[1]])
 }
 
 fn mod_bigint_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -194,9 +194,9 @@ pub(super) struct mod_bigint_bigintFunc {
 }
 
 impl mod_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64, DataType::Int64], volatility),
         }
     }
 }
@@ -224,17 +224,30 @@ impl ScalarUDFImpl for mod_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct mod_decimal_a_precision_a_scale_decimal_b_precision_b_scaleFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl mod_decimal_a_precision_a_scale_decimal_b_precision_b_scaleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &[
+                    "decimal(a_precision, a_scale)",
+                    "decimal(b_precision, b_scale)",
+                ],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -251,6 +264,10 @@ impl ScalarUDFImpl for mod_decimal_a_precision_a_scale_decimal_b_precision_b_sca
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("mod", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         mod_decimal_a_precision_a_scale_decimal_b_precision_b_scale_return_type(arg_types)
     }
@@ -262,6 +279,13 @@ impl ScalarUDFImpl for mod_decimal_a_precision_a_scale_decimal_b_precision_b_sca
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_decimal_a_precision_a_scale_decimal_b_precision_b_scale_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "mod_decimal_a_precision_a_scale_decimal_b_precision_b_scale",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -270,9 +294,9 @@ pub(super) struct mod_double_doubleFunc {
 }
 
 impl mod_double_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64, DataType::Float64], volatility),
         }
     }
 }
@@ -300,6 +324,10 @@ impl ScalarUDFImpl for mod_double_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_double_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_double_double")
+    }
 }
 
 #[derive(Debug)]
@@ -308,9 +336,9 @@ pub(super) struct mod_integer_integerFunc {
 }
 
 impl mod_integer_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32, DataType::Int32], volatility),
         }
     }
 }
@@ -338,6 +366,10 @@ impl ScalarUDFImpl for mod_integer_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_integer_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_integer_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -346,9 +378,9 @@ pub(super) struct mod_real_realFunc {
 }
 
 impl mod_real_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32, DataType::Float32], volatility),
         }
     }
 }
@@ -376,6 +408,10 @@ impl ScalarUDFImpl for mod_real_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_real_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_real_real")
+    }
 }
 
 #[derive(Debug)]
@@ -384,9 +420,9 @@ pub(super) struct mod_smallint_smallintFunc {
 }
 
 impl mod_smallint_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16, DataType::Int16], volatility),
         }
     }
 }
@@ -414,6 +450,10 @@ impl ScalarUDFImpl for mod_smallint_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_smallint_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_smallint_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -422,9 +462,9 @@ pub(super) struct mod_tinyint_tinyintFunc {
 }
 
 impl mod_tinyint_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8, DataType::Int8], volatility),
         }
     }
 }
@@ -452,4 +492,8 @@ impl ScalarUDFImpl for mod_tinyint_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         mod_tinyint_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "mod_tinyint_tinyint")
+    }
 }
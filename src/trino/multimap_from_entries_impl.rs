@@ -57,12 +57,18 @@ fn multimap_from_entries_array_row_c04_c15_simplify(
 #[derive(Debug)]
 pub(super) struct multimap_from_entries_array_row_c04_c15Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl multimap_from_entries_array_row_c04_c15Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<row(c0 $4, c1 $5)>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +85,14 @@ impl ScalarUDFImpl for multimap_from_entries_array_row_c04_c15Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments(
+            "multimap_from_entries",
+            &self.parameters,
+            arg_types,
+        )
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         multimap_from_entries_array_row_c04_c15_return_type(arg_types)
     }
@@ -90,4 +104,11 @@ impl ScalarUDFImpl for multimap_from_entries_array_row_c04_c15Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         multimap_from_entries_array_row_c04_c15_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "multimap_from_entries_array_row_c04_c15",
+        )
+    }
 }
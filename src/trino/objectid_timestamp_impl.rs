@@ -56,9 +56,9 @@ pub(super) struct objectid_timestamp_objectidFunc {
 }
 
 impl objectid_timestamp_objectidFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::FixedSizeBinary(12)], volatility),
         }
     }
 }
@@ -86,4 +86,8 @@ impl ScalarUDFImpl for objectid_timestamp_objectidFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         objectid_timestamp_objectid_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "objectid_timestamp_objectid")
+    }
 }
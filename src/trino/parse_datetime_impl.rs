@@ -17,25 +17,48 @@
 
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{internal_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
-use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
 use std::any::Any;
 
-fn parse_datetime_varchar_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::datetime_pattern::DateTimePattern;
+use crate::utils_datetime::{map_parse_timestamp, parse_result_zone};
+
+fn parse_datetime_varchar_varchar_invoke_with_args(
+    args: ScalarFunctionArgs,
+) -> Result<ColumnarValue> {
+    map_parse_timestamp(
+        &args.args[0],
+        &args.args[1],
+        DateTimePattern::joda,
+        args.return_type,
+    )
+}
+
+fn parse_datetime_varchar_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("parse_datetime_varchar_varchar", arg_types)
 }
 
-fn parse_datetime_varchar_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn parse_datetime_varchar_varchar_return_type_from_args(
+    args: ReturnTypeArgs,
+) -> Result<ReturnInfo> {
+    // In the time zone of a constant text, and else in UTC or the time zone of the session
+    let DataType::Timestamp(unit, _) = parse_datetime_varchar_varchar_return_type(args.arg_types)?
+    else {
+        return internal_err!("parse_datetime must return a timestamp(3) with time zone");
+    };
+    let zone = parse_result_zone(
+        args.scalar_arguments.first().copied().flatten(),
+        args.scalar_arguments.get(1).copied().flatten(),
+        DateTimePattern::joda,
+    );
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
@@ -56,9 +79,9 @@ pub(super) struct parse_datetime_varchar_varcharFunc {
 }
 
 impl parse_datetime_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -79,11 +102,25 @@ impl ScalarUDFImpl for parse_datetime_varchar_varcharFunc {
         parse_datetime_varchar_varchar_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        parse_datetime_varchar_varchar_invoke(args)
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        parse_datetime_varchar_varchar_return_type_from_args(args)
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion::logical_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        parse_datetime_varchar_varchar_invoke_with_args(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         parse_datetime_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "parse_datetime_varchar_varchar")
+    }
 }
@@ -16,29 +16,31 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use arrow::array::{ArrayRef, AsArray, IntervalMonthDayNanoArray};
 use arrow::compute::kernels::cast_utils::parse_interval_month_day_nano;
 use arrow::datatypes::{DataType, IntervalUnit};
-use datafusion::common::{internal_err, Result, ScalarValue};
+use arrow::error::Result as ArrowResult;
+use datafusion::common::Result;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
+
+use crate::utils::apply_unary_kernel;
 
 fn parse_duration_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    match &args[0] {
-        ColumnarValue::Scalar(ScalarValue::Utf8(Some(v))) => {
-            let interval = parse_interval_month_day_nano(v.as_str())?;
-            Ok(ColumnarValue::Scalar(ScalarValue::IntervalMonthDayNano(
-                Some(interval),
-            )))
-        }
-        _ => {
-            internal_err!("Invalid argument types to parse_duration function")
-        }
-    }
+    apply_unary_kernel(&args[0], |array: &ArrayRef| {
+        let res = array
+            .as_string::<i32>()
+            .iter()
+            .map(|duration| duration.map(parse_interval_month_day_nano).transpose())
+            .collect::<ArrowResult<IntervalMonthDayNanoArray>>()?;
+        Ok(Arc::new(res) as ArrayRef)
+    })
 }
 
 fn parse_duration_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Ok(DataType::Interval(IntervalUnit::DayTime))
+    Ok(DataType::Interval(IntervalUnit::MonthDayNano))
 }
 
 fn parse_duration_varchar_simplify(
@@ -58,9 +60,9 @@ pub(super) struct parse_duration_varcharFunc {
 }
 
 impl parse_duration_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8], volatility),
         }
     }
 }
@@ -88,4 +90,8 @@ impl ScalarUDFImpl for parse_duration_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         parse_duration_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "parse_duration_varchar")
+    }
 }
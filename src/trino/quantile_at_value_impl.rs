@@ -102,9 +102,9 @@ pub(super) struct quantile_at_value_qdigest_bigintFunc {
 }
 
 impl quantile_at_value_qdigest_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Int64], volatility),
         }
     }
 }
@@ -132,6 +132,13 @@ impl ScalarUDFImpl for quantile_at_value_qdigest_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         quantile_at_value_qdigest_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "quantile_at_value_qdigest_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -140,9 +147,9 @@ pub(super) struct quantile_at_value_qdigest_doubleFunc {
 }
 
 impl quantile_at_value_qdigest_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float64], volatility),
         }
     }
 }
@@ -170,6 +177,13 @@ impl ScalarUDFImpl for quantile_at_value_qdigest_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         quantile_at_value_qdigest_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "quantile_at_value_qdigest_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -178,9 +192,9 @@ pub(super) struct quantile_at_value_qdigest_realFunc {
 }
 
 impl quantile_at_value_qdigest_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float32], volatility),
         }
     }
 }
@@ -208,4 +222,8 @@ impl ScalarUDFImpl for quantile_at_value_qdigest_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         quantile_at_value_qdigest_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "quantile_at_value_qdigest_real")
+    }
 }
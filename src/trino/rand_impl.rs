@@ -19,6 +19,7 @@
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
 use datafusion::error::DataFusionError;
+use datafusion::functions::math::random;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
@@ -66,20 +67,12 @@ fn rand_bigint_bigint_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn rand_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn rand_invoke_no_args(number_rows: usize) -> Result<ColumnarValue> {
+    random().invoke_batch(&[], number_rows)
 }
 
 fn rand_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    Ok(DataType::Float64)
 }
 
 fn rand_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
@@ -225,9 +218,9 @@ pub(super) struct rand_bigintFunc {
 }
 
 impl rand_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64], volatility),
         }
     }
 }
@@ -255,6 +248,10 @@ impl ScalarUDFImpl for rand_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -263,9 +260,9 @@ pub(super) struct rand_bigint_bigintFunc {
 }
 
 impl rand_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64, DataType::Int64], volatility),
         }
     }
 }
@@ -293,6 +290,10 @@ impl ScalarUDFImpl for rand_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -301,9 +302,9 @@ pub(super) struct randFunc {
 }
 
 impl randFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(0, Volatility::Immutable),
+            signature: Signature::nullary(volatility),
         }
     }
 }
@@ -324,13 +325,17 @@ impl ScalarUDFImpl for randFunc {
         rand_return_type(arg_types)
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        rand_invoke(args)
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        rand_invoke_no_args(number_rows)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand")
+    }
 }
 
 #[derive(Debug)]
@@ -339,9 +344,9 @@ pub(super) struct rand_integerFunc {
 }
 
 impl rand_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32], volatility),
         }
     }
 }
@@ -369,6 +374,10 @@ impl ScalarUDFImpl for rand_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -377,9 +386,9 @@ pub(super) struct rand_integer_integerFunc {
 }
 
 impl rand_integer_integerFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int32, DataType::Int32], volatility),
         }
     }
 }
@@ -407,6 +416,10 @@ impl ScalarUDFImpl for rand_integer_integerFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_integer_integer_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_integer_integer")
+    }
 }
 
 #[derive(Debug)]
@@ -415,9 +428,9 @@ pub(super) struct rand_smallintFunc {
 }
 
 impl rand_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16], volatility),
         }
     }
 }
@@ -445,6 +458,10 @@ impl ScalarUDFImpl for rand_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -453,9 +470,9 @@ pub(super) struct rand_smallint_smallintFunc {
 }
 
 impl rand_smallint_smallintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int16, DataType::Int16], volatility),
         }
     }
 }
@@ -483,6 +500,10 @@ impl ScalarUDFImpl for rand_smallint_smallintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_smallint_smallint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_smallint_smallint")
+    }
 }
 
 #[derive(Debug)]
@@ -491,9 +512,9 @@ pub(super) struct rand_tinyintFunc {
 }
 
 impl rand_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8], volatility),
         }
     }
 }
@@ -521,6 +542,10 @@ impl ScalarUDFImpl for rand_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_tinyint")
+    }
 }
 
 #[derive(Debug)]
@@ -529,9 +554,9 @@ pub(super) struct rand_tinyint_tinyintFunc {
 }
 
 impl rand_tinyint_tinyintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int8, DataType::Int8], volatility),
         }
     }
 }
@@ -559,4 +584,8 @@ impl ScalarUDFImpl for rand_tinyint_tinyintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rand_tinyint_tinyint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rand_tinyint_tinyint")
+    }
 }
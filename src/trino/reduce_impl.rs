@@ -16,38 +16,102 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::ListElements;
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use arrow::compute::{cast, interleave, take};
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn reduce_array_1_10_function_10_1_10_function_10_9_invoke(
     _args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("reduce")
 }
 
 fn reduce_array_1_10_function_10_1_10_function_10_9_return_type(
-    _arg_types: &[DataType],
+    arg_types: &[DataType],
 ) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    super::types::declared_return_type(
+        "reduce_array_1_10_function_10_1_10_function_10_9",
+        arg_types,
+    )
 }
 
 fn reduce_array_1_10_function_10_1_10_function_10_9_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "reduce",
+        args,
+        info,
+        2,
+        reduce_array_1_10_function_10_1_10_function_10_9_return_type,
+        reduce,
+    )
+}
+
+/// Fold the elements of the arrays into their initial states with the input function, and
+/// apply the output function to the final states. The arrays are folded in lockstep: the input
+/// function is called once per position, on the elements at that position of all the arrays
+/// that are long enough. NULL arrays give NULL.
+fn reduce(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let elements = ListElements::new(&args[0])?;
+    let (input, output) = (&lambdas[0], &lambdas[1]);
+    let lengths = elements.lengths().collect::<Vec<_>>();
+    let starts = &elements.offsets[..lengths.len()];
+    let mut states = Arc::clone(&args[1]);
+    for position in 0..lengths.iter().copied().max().unwrap_or(0) {
+        let rows = (0..lengths.len())
+            .filter(|&row| lengths[row] > position)
+            .collect::<Vec<_>>();
+        let indices = rows
+            .iter()
+            .map(|&row| (starts[row] as usize + position) as u32)
+            .collect::<UInt32Array>();
+        let rows = rows
+            .into_iter()
+            .map(|row| row as u32)
+            .collect::<UInt32Array>();
+        let state = take(&states, &rows, None)?;
+        let element = take(&elements.values, &indices, None)?;
+        let mut next = input.call(&[state, element], &rows)?;
+        if next.data_type() != states.data_type() {
+            next = cast(&next, states.data_type())?;
+        }
+        // The states of the other arrays are left as they are
+        let mut updated = rows.values().iter().enumerate().peekable();
+        let scatter = (0..states.len())
+            .map(|row| match updated.next_if(|(_, &r)| r as usize == row) {
+                Some((i, _)) => (1, i),
+                None => (0, row),
+            })
+            .collect::<Vec<_>>();
+        states = interleave(&[states.as_ref(), next.as_ref()], &scatter)?;
+    }
+    let rows = (0..states.len())
+        .filter(|&row| !elements.is_null(row))
+        .map(|row| row as u32)
+        .collect::<UInt32Array>();
+    if rows.len() == states.len() {
+        return output.call(&[states], &rows);
+    }
+    let result = output.call(&[take(&states, &rows, None)?], &rows)?;
+    let mut valid = 0;
+    let indices = (0..states.len())
+        .map(|row| {
+            (!elements.is_null(row)).then(|| {
+                valid += 1;
+                valid - 1
+            })
+        })
+        .collect::<UInt32Array>();
+    Ok(take(&result, &indices, None)?)
 }
 
 // ========== Generated template below this line ==========
@@ -57,12 +121,23 @@ fn reduce_array_1_10_function_10_1_10_function_10_9_simplify(
 #[derive(Debug)]
 pub(super) struct reduce_array_1_10_function_10_1_10_function_10_9Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl reduce_array_1_10_function_10_1_10_function_10_9Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &[
+                    "array<$1>",
+                    "$10",
+                    "function($10, $1, $10)",
+                    "function($10, $9)",
+                ],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +154,10 @@ impl ScalarUDFImpl for reduce_array_1_10_function_10_1_10_function_10_9Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("reduce", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         reduce_array_1_10_function_10_1_10_function_10_9_return_type(arg_types)
     }
@@ -90,4 +169,11 @@ impl ScalarUDFImpl for reduce_array_1_10_function_10_1_10_function_10_9Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         reduce_array_1_10_function_10_1_10_function_10_9_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "reduce_array_1_10_function_10_1_10_function_10_9",
+        )
+    }
 }
@@ -16,28 +16,27 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::joni::JoniRegex;
 use crate::utils_regexp::map_rowfun__pat_hay_to_i64;
 
 fn regexp_count_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     map_rowfun__pat_hay_to_i64(&args[1], &args[0], Arc::new(regexp_count__rowfun))
 }
 
-fn regexp_count__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> i64>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun = move |str: &str| re.find_iter(str).count() as i64;
+fn regexp_count__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> Result<i64>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str| {
+        re.find_iter(str)
+            .try_fold(0, |count, m| m.map(|_| count + 1))
+    };
     Ok(Arc::new(rowfun))
 }
 
@@ -59,12 +58,18 @@ fn regexp_count_varchar_joniregexp_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_count_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_count_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -81,6 +86,10 @@ impl ScalarUDFImpl for regexp_count_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_count", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_count_varchar_joniregexp_return_type(arg_types)
     }
@@ -92,4 +101,11 @@ impl ScalarUDFImpl for regexp_count_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_count_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_count_varchar_joniregexp",
+        )
+    }
 }
@@ -16,15 +16,15 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::joni::JoniRegex;
 use crate::utils_regexp::{map_rowfun__pat_hay_int_to_nstrlst, map_rowfun__pat_hay_to_strlst};
 
 fn regexp_extract_all_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -33,14 +33,10 @@ fn regexp_extract_all_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Resul
 
 fn regexp_extract_all__rowfun(
     pat: &str,
-) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Vec<&'a str>>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Vec<&'a str>> =
-        Arc::new(move |hay: &str| re.find_iter(hay).map(|m| m.as_str()).collect());
+) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Result<Vec<&'a str>>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Result<Vec<&'a str>>> =
+        Arc::new(move |hay: &str| re.find_iter(hay).map(|m| Ok(m?.as_str())).collect());
     Ok(rowfun)
 }
 
@@ -68,18 +64,16 @@ fn regexp_extract_all_varchar_joniregexp_bigint_invoke(
 
 fn regexp_extract_all__rowfun2(
     pat: &str,
-) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Vec<Option<&'a str>>>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Vec<Option<&'a str>>> =
-        Arc::new(move |hay: &str, grp: i64| {
-            re.captures_iter(hay)
-                .map(|cs| cs.get(grp as usize).map(|m| m.as_str()))
-                .collect()
-        });
+) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Result<Vec<Option<&'a str>>>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun: Arc<
+        dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Result<Vec<Option<&'a str>>>,
+    > = Arc::new(move |hay: &str, grp: i64| {
+        let grp = re.group_index_of(grp)?;
+        re.captures_iter(hay)
+            .map(|cs| Ok(cs?.get(grp).map(|m| m.as_str())))
+            .collect()
+    });
     Ok(rowfun)
 }
 
@@ -103,12 +97,18 @@ fn regexp_extract_all_varchar_joniregexp_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_extract_all_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_extract_all_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -125,6 +125,10 @@ impl ScalarUDFImpl for regexp_extract_all_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_extract_all", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_extract_all_varchar_joniregexp_return_type(arg_types)
     }
@@ -136,17 +140,30 @@ impl ScalarUDFImpl for regexp_extract_all_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_extract_all_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_extract_all_varchar_joniregexp",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_extract_all_varchar_joniregexp_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_extract_all_varchar_joniregexp_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -163,6 +180,10 @@ impl ScalarUDFImpl for regexp_extract_all_varchar_joniregexp_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_extract_all", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_extract_all_varchar_joniregexp_bigint_return_type(arg_types)
     }
@@ -174,4 +195,11 @@ impl ScalarUDFImpl for regexp_extract_all_varchar_joniregexp_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_extract_all_varchar_joniregexp_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_extract_all_varchar_joniregexp_bigint",
+        )
+    }
 }
@@ -16,41 +16,25 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::array::StringArray;
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
-use datafusion::common::cast::{as_list_array, as_string_array};
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
-use datafusion::functions::regex::regexpmatch::regexp_match;
+use datafusion::common::{Result, ScalarValue};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
-use crate::utils::distinct_to_string_array;
+use crate::joni::JoniRegex;
 use crate::utils_regexp::map_rowfun__pat_hay_int_to_nstr;
 
 fn regexp_extract_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let args = ColumnarValue::values_to_arrays(args)?;
-    let value = args[0].to_owned();
-    let pattern = distinct_to_string_array(&args[1])?;
-
-    let arrays = vec![value, Arc::new(pattern)];
-    let result = regexp_match(&arrays)?;
-    let result = as_list_array(result.as_ref())?;
-    let result = result
-        .iter()
-        .map(|x| {
-            x.map(|x| {
-                let inner = as_string_array(&x)?;
-                Ok(inner.value(0).to_owned())
-            })
-            .transpose()
-        })
-        .collect::<Result<StringArray>>()?;
-
-    Ok(ColumnarValue::Array(Arc::new(result)))
+    let group = ColumnarValue::Scalar(ScalarValue::Int64(Some(0)));
+    map_rowfun__pat_hay_int_to_nstr(
+        &args[1],
+        &args[0],
+        &group,
+        Arc::new(regexp_extract__rowfun2),
+    )
 }
 
 fn regexp_extract_varchar_joniregexp_return_type(_arg_types: &[DataType]) -> Result<DataType> {
@@ -77,16 +61,14 @@ fn regexp_extract_varchar_joniregexp_bigint_invoke(
 
 fn regexp_extract__rowfun2(
     pat: &str,
-) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Option<&'a str>>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Option<&'a str>> =
-        Arc::new(move |hay: &str, grp: i64| match re.captures(hay) {
-            None => None,
-            Some(cs) => cs.get(grp as usize).map(|m| m.as_str()),
+) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Result<Option<&'a str>>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str, /*grp:*/ i64) -> Result<Option<&'a str>>> =
+        Arc::new(move |hay: &str, grp: i64| {
+            let grp = re.group_index_of(grp)?;
+            Ok(re
+                .captures(hay)?
+                .and_then(|cs| cs.get(grp).map(|m| m.as_str())))
         });
     Ok(rowfun)
 }
@@ -111,12 +93,18 @@ fn regexp_extract_varchar_joniregexp_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_extract_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_extract_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -133,6 +121,10 @@ impl ScalarUDFImpl for regexp_extract_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_extract", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_extract_varchar_joniregexp_return_type(arg_types)
     }
@@ -144,17 +136,30 @@ impl ScalarUDFImpl for regexp_extract_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_extract_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_extract_varchar_joniregexp",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_extract_varchar_joniregexp_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_extract_varchar_joniregexp_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -171,6 +176,10 @@ impl ScalarUDFImpl for regexp_extract_varchar_joniregexp_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_extract", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_extract_varchar_joniregexp_bigint_return_type(arg_types)
     }
@@ -182,4 +191,11 @@ impl ScalarUDFImpl for regexp_extract_varchar_joniregexp_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_extract_varchar_joniregexp_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_extract_varchar_joniregexp_bigint",
+        )
+    }
 }
@@ -16,24 +16,25 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::functions::regex::regexplike::regexp_like;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 use std::sync::Arc;
 
-use crate::utils::distinct_to_string_array;
+use crate::joni::JoniRegex;
+use crate::utils_regexp::map_rowfun__pat_hay_to_bool;
 
 fn regexp_like_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let args = ColumnarValue::values_to_arrays(args)?;
-    let value = args[0].to_owned();
-    let pattern = distinct_to_string_array(&args[1])?;
+    map_rowfun__pat_hay_to_bool(&args[1], &args[0], Arc::new(regexp_like__rowfun))
+}
 
-    let args = vec![value, Arc::new(pattern)];
-    let array = regexp_like(&args)?;
-    Ok(ColumnarValue::Array(array))
+fn regexp_like__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> Result<bool>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str| re.is_match(str);
+    Ok(Arc::new(rowfun))
 }
 
 fn regexp_like_varchar_joniregexp_return_type(_arg_types: &[DataType]) -> Result<DataType> {
@@ -54,12 +55,18 @@ fn regexp_like_varchar_joniregexp_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_like_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_like_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -76,6 +83,10 @@ impl ScalarUDFImpl for regexp_like_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_like", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_like_varchar_joniregexp_return_type(arg_types)
     }
@@ -87,4 +98,8 @@ impl ScalarUDFImpl for regexp_like_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_like_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "regexp_like_varchar_joniregexp")
+    }
 }
@@ -16,15 +16,15 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::joni::JoniRegex;
 use crate::utils_regexp::{
     map_rowfun__pat_hay_int_int_to_i64, map_rowfun__pat_hay_int_to_i64, map_rowfun__pat_hay_to_i64,
 };
@@ -33,18 +33,14 @@ fn regexp_position_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<C
     map_rowfun__pat_hay_to_i64(&args[1], &args[0], Arc::new(regexp_position__rowfun))
 }
 
-fn regexp_position__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> i64>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_position did not compile: {e:?}"
-        ))
-    })?;
+fn regexp_position__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> Result<i64>>> {
+    let re = JoniRegex::new(pat)?;
     let rowfun = move |str: &str| {
-        let res = re.find(str);
-        match res {
+        let res = re.find_at(str, 0)?;
+        Ok(match res {
             None => -1,
             Some(m) => m.start() as i64 + 1,
-        }
+        })
     };
     Ok(Arc::new(rowfun))
 }
@@ -73,28 +69,9 @@ fn regexp_position_varchar_joniregexp_bigint_invoke(
 
 fn regexp_position__rowfun2(
     pat: &str,
-) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*start:*/ i64) -> i64>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_position did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun = move |str: &str, start: i64| {
-        let start = start as usize;
-        if start < 1 {
-            return -1; // TODO Trino gives out an error here; need to understand if Result from rowfun is worth it
-        } else if start > str.len() {
-            return -1;
-            // Working around what might be a bug in regex crate: it panics here,
-            // but only when start is "well past" the end of str
-            // (e.g., for str="", must be start >= 3, for "abc" and pattern "b", start >= 6).
-        }
-        let res = re.find_at(str, start - 1);
-        match res {
-            None => -1,
-            Some(m) => m.start() as i64 + 1,
-        }
-    };
+) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*start:*/ i64) -> Result<i64>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str, start: i64| regexp_position__find(&re, str, start, 1);
     Ok(Arc::new(rowfun))
 }
 
@@ -125,39 +102,32 @@ fn regexp_position_varchar_joniregexp_bigint_bigint_invoke(
 
 fn regexp_position__rowfun3(
     pat: &str,
-) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*start:*/ i64, /*occ:*/ i64) -> i64>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_position did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun = move |str: &str, start: i64, occ: i64| {
-        let start = start as usize;
-        if start < 1 {
-            return -1; // TODO Trino gives out an error here; need to understand if Result from rowfun is worth it
-        } else if start > str.len() {
-            return -1;
-            // Working around what might be a bug in regex crate: it panics here,
-            // but only when start is "well past" the end of str
-            // (e.g., for str="", must be start >= 3, for "abc" and pattern "b", start >= 6).
-        }
-        if !str.is_char_boundary(start - 1) {
-            return -1;
-        };
-        if occ < 1 {
-            return -1; // TODO Trino gives out an error here as well
-        }
-
-        let suffix = &str[(start - 1)..];
-        let res = re.find_iter(suffix).nth((occ - 1) as usize);
-        match res {
-            None => -1,
-            Some(m) => (m.start() + 1) as i64 + (start - 1) as i64,
-        }
-    };
+) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*start:*/ i64, /*occ:*/ i64) -> Result<i64>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str, start: i64, occ: i64| regexp_position__find(&re, str, start, occ);
     Ok(Arc::new(rowfun))
 }
 
+/// The 1-based position of the `occ`th match of `re` in `str` from the 1-based `start`, or -1.
+fn regexp_position__find(re: &JoniRegex, str: &str, start: i64, occ: i64) -> Result<i64> {
+    if start < 1 {
+        return exec_err!("start position cannot be smaller than 1");
+    }
+    if occ < 1 {
+        return exec_err!("occurrence cannot be smaller than 1");
+    }
+    let start = start as usize;
+    if start > str.len() || !str.is_char_boundary(start - 1) {
+        return Ok(-1);
+    }
+    let suffix = &str[(start - 1)..];
+    let res = re.find_iter(suffix).nth((occ - 1) as usize).transpose()?;
+    Ok(match res {
+        None => -1,
+        Some(m) => (m.start() + 1) as i64 + (start - 1) as i64,
+    })
+}
+
 fn regexp_position_varchar_joniregexp_bigint_bigint_return_type(
     _arg_types: &[DataType],
 ) -> Result<DataType> {
@@ -178,12 +148,18 @@ fn regexp_position_varchar_joniregexp_bigint_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_position_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_position_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -200,6 +176,10 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_position", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_position_varchar_joniregexp_return_type(arg_types)
     }
@@ -211,17 +191,30 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_position_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_position_varchar_joniregexp",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_position_varchar_joniregexp_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_position_varchar_joniregexp_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -238,6 +231,10 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexp_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_position", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_position_varchar_joniregexp_bigint_return_type(arg_types)
     }
@@ -249,17 +246,30 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexp_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_position_varchar_joniregexp_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_position_varchar_joniregexp_bigint",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_position_varchar_joniregexp_bigint_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_position_varchar_joniregexp_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "bigint", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -276,6 +286,10 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexp_bigint_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_position", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_position_varchar_joniregexp_bigint_bigint_return_type(arg_types)
     }
@@ -287,4 +301,11 @@ impl ScalarUDFImpl for regexp_position_varchar_joniregexp_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_position_varchar_joniregexp_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_position_varchar_joniregexp_bigint_bigint",
+        )
+    }
 }
@@ -16,15 +16,16 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
 use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::joni::{JoniRegex, Replacement};
 use crate::utils_regexp::{map_rowfun__pat_hay_str_to_string, map_rowfun__pat_hay_to_string};
 
 fn regexp_replace_varchar_joniregexp_function_array_varchar_varchar_invoke(
@@ -50,13 +51,9 @@ fn regexp_replace_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<Co
     map_rowfun__pat_hay_to_string(&args[1], &args[0], Arc::new(regexp_replace__rowfun))
 }
 
-fn regexp_replace__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> String>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun = move |str: &str| re.replace_all(str, "").into_owned();
+fn regexp_replace__rowfun(pat: &str) -> Result<Arc<dyn Fn(/*hay:*/ &str) -> Result<String>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str| re.replace_all(str, &Replacement::empty());
     Ok(Arc::new(rowfun))
 }
 
@@ -84,13 +81,12 @@ fn regexp_replace_varchar_joniregexp_varchar_invoke(
 
 fn regexp_replace__rowfun2(
     pat: &str,
-) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*str1:*/ &str) -> String>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun = move |str: &str, str1: &str| re.replace_all(str, str1).into_owned();
+) -> Result<Arc<dyn Fn(/*hay:*/ &str, /*str1:*/ &str) -> Result<String>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun = move |str: &str, str1: &str| {
+        let replacement = Replacement::new(str1, &re)?;
+        re.replace_all(str, &replacement)
+    };
     Ok(Arc::new(rowfun))
 }
 
@@ -114,12 +110,18 @@ fn regexp_replace_varchar_joniregexp_varchar_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_replace_varchar_joniregexp_function_array_varchar_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_replace_varchar_joniregexp_function_array_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "function(array<varchar>, varchar)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -136,6 +138,10 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexp_function_array_varchar_
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_replace", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_replace_varchar_joniregexp_function_array_varchar_varchar_return_type(arg_types)
     }
@@ -147,17 +153,30 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexp_function_array_varchar_
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_replace_varchar_joniregexp_function_array_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_replace_varchar_joniregexp_function_array_varchar_varchar",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_replace_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_replace_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -174,6 +193,10 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_replace", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_replace_varchar_joniregexp_return_type(arg_types)
     }
@@ -185,17 +208,30 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_replace_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_replace_varchar_joniregexp",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct regexp_replace_varchar_joniregexp_varcharFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_replace_varchar_joniregexp_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp", "varchar"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -212,6 +248,10 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexp_varcharFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_replace", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_replace_varchar_joniregexp_varchar_return_type(arg_types)
     }
@@ -223,4 +263,11 @@ impl ScalarUDFImpl for regexp_replace_varchar_joniregexp_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_replace_varchar_joniregexp_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_replace_varchar_joniregexp_varchar",
+        )
+    }
 }
@@ -16,15 +16,15 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+#![allow(clippy::type_complexity)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::joni::JoniRegex;
 use crate::utils_regexp::map_rowfun__pat_hay_to_strlst;
 
 fn regexp_split_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -33,14 +33,10 @@ fn regexp_split_varchar_joniregexp_invoke(args: &[ColumnarValue]) -> Result<Colu
 
 fn regexp_split__rowfun(
     pat: &str,
-) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Vec<&'a str>>> {
-    let re = Regex::new(pat).map_err(|e| {
-        DataFusionError::Execution(format!(
-            "Regular expression for regexp_count did not compile: {e:?}"
-        ))
-    })?;
-    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Vec<&'a str>> =
-        Arc::new(move |hay: &str| re.split(hay).collect());
+) -> Result<Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Result<Vec<&'a str>>>> {
+    let re = JoniRegex::new(pat)?;
+    let rowfun: Arc<dyn for<'a> Fn(/*hay:*/ &'a str) -> Result<Vec<&'a str>>> =
+        Arc::new(move |hay: &str| re.split(hay));
     Ok(rowfun)
 }
 
@@ -62,12 +58,18 @@ fn regexp_split_varchar_joniregexp_simplify(
 #[derive(Debug)]
 pub(super) struct regexp_split_varchar_joniregexpFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regexp_split_varchar_joniregexpFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["varchar", "joniregexp"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -84,6 +86,10 @@ impl ScalarUDFImpl for regexp_split_varchar_joniregexpFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regexp_split", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regexp_split_varchar_joniregexp_return_type(arg_types)
     }
@@ -95,4 +101,11 @@ impl ScalarUDFImpl for regexp_split_varchar_joniregexpFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regexp_split_varchar_joniregexp_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regexp_split_varchar_joniregexp",
+        )
+    }
 }
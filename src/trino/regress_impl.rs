@@ -53,12 +53,18 @@ fn regress_map_bigint_double_regressor_simplify(
 #[derive(Debug)]
 pub(super) struct regress_map_bigint_double_regressorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl regress_map_bigint_double_regressorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<bigint, double>", "regressor"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +81,10 @@ impl ScalarUDFImpl for regress_map_bigint_double_regressorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("regress", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         regress_map_bigint_double_regressor_return_type(arg_types)
     }
@@ -86,4 +96,11 @@ impl ScalarUDFImpl for regress_map_bigint_double_regressorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         regress_map_bigint_double_regressor_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "regress_map_bigint_double_regressor",
+        )
+    }
 }
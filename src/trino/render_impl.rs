@@ -125,9 +125,9 @@ pub(super) struct render_booleanFunc {
 }
 
 impl render_booleanFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Boolean], volatility),
         }
     }
 }
@@ -155,17 +155,24 @@ impl ScalarUDFImpl for render_booleanFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         render_boolean_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "render_boolean")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct render_bigint_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl render_bigint_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["bigint", "color"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -182,6 +189,10 @@ impl ScalarUDFImpl for render_bigint_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("render", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         render_bigint_color_return_type(arg_types)
     }
@@ -193,17 +204,24 @@ impl ScalarUDFImpl for render_bigint_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         render_bigint_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "render_bigint_color")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct render_double_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl render_double_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["double", "color"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -220,6 +238,10 @@ impl ScalarUDFImpl for render_double_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("render", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         render_double_color_return_type(arg_types)
     }
@@ -231,17 +253,24 @@ impl ScalarUDFImpl for render_double_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         render_double_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "render_double_color")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct render_varchar_colorFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl render_varchar_colorFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["varchar", "color"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -258,6 +287,10 @@ impl ScalarUDFImpl for render_varchar_colorFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("render", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         render_varchar_color_return_type(arg_types)
     }
@@ -269,4 +302,8 @@ impl ScalarUDFImpl for render_varchar_colorFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         render_varchar_color_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "render_varchar_color")
+    }
 }
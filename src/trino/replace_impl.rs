@@ -79,9 +79,12 @@ pub(super) struct replace_varchar_varchar_varcharFunc {
 }
 
 impl replace_varchar_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8, DataType::Utf8],
+                volatility,
+            ),
         }
     }
 }
@@ -109,6 +112,13 @@ impl ScalarUDFImpl for replace_varchar_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         replace_varchar_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "replace_varchar_varchar_varchar",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +127,9 @@ pub(super) struct replace_varchar_varcharFunc {
 }
 
 impl replace_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -147,4 +157,8 @@ impl ScalarUDFImpl for replace_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         replace_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "replace_varchar_varchar")
+    }
 }
@@ -56,9 +56,12 @@ pub(super) struct rgb_bigint_bigint_bigintFunc {
 }
 
 impl rgb_bigint_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,8 @@ impl ScalarUDFImpl for rgb_bigint_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rgb_bigint_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rgb_bigint_bigint_bigint")
+    }
 }
@@ -79,9 +79,12 @@ pub(super) struct rpad_varbinary_bigint_varbinaryFunc {
 }
 
 impl rpad_varbinary_bigint_varbinaryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Binary, DataType::Int64, DataType::Binary],
+                volatility,
+            ),
         }
     }
 }
@@ -109,6 +112,13 @@ impl ScalarUDFImpl for rpad_varbinary_bigint_varbinaryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rpad_varbinary_bigint_varbinary_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "rpad_varbinary_bigint_varbinary",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +127,12 @@ pub(super) struct rpad_varchar_bigint_varcharFunc {
 }
 
 impl rpad_varchar_bigint_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Int64, DataType::Utf8],
+                volatility,
+            ),
         }
     }
 }
@@ -147,4 +160,8 @@ impl ScalarUDFImpl for rpad_varchar_bigint_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         rpad_varchar_bigint_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "rpad_varchar_bigint_varchar")
+    }
 }
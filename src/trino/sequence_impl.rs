@@ -23,6 +23,8 @@ use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
+use crate::utils_calendar::map_sequence;
+
 fn sequence_bigint_bigint_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
     Err(DataFusionError::NotImplemented(format!(
         "Not implemented {}:{}",
@@ -92,20 +94,12 @@ fn sequence_date_date_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn sequence_date_date_intervaldaytosecond_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn sequence_date_date_intervaldaytosecond_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_sequence(args)
 }
 
-fn sequence_date_date_intervaldaytosecond_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn sequence_date_date_intervaldaytosecond_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("sequence_date_date_intervaldaytosecond", arg_types)
 }
 
 fn sequence_date_date_intervaldaytosecond_simplify(
@@ -115,20 +109,12 @@ fn sequence_date_date_intervaldaytosecond_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn sequence_date_date_intervalyeartomonth_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn sequence_date_date_intervalyeartomonth_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_sequence(args)
 }
 
-fn sequence_date_date_intervalyeartomonth_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn sequence_date_date_intervalyeartomonth_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("sequence_date_date_intervalyeartomonth", arg_types)
 }
 
 fn sequence_date_date_intervalyeartomonth_simplify(
@@ -139,23 +125,18 @@ fn sequence_date_date_intervalyeartomonth_simplify(
 }
 
 fn sequence_timestamp_p_timestamp_p_intervaldaytosecond_invoke(
-    _args: &[ColumnarValue],
+    args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    map_sequence(args)
 }
 
 fn sequence_timestamp_p_timestamp_p_intervaldaytosecond_return_type(
-    _arg_types: &[DataType],
+    arg_types: &[DataType],
 ) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    super::types::declared_return_type(
+        "sequence_timestamp_p_timestamp_p_intervaldaytosecond",
+        arg_types,
+    )
 }
 
 fn sequence_timestamp_p_timestamp_p_intervaldaytosecond_simplify(
@@ -175,9 +156,9 @@ pub(super) struct sequence_bigint_bigintFunc {
 }
 
 impl sequence_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Int64, DataType::Int64], volatility),
         }
     }
 }
@@ -205,6 +186,10 @@ impl ScalarUDFImpl for sequence_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "sequence_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -213,9 +198,12 @@ pub(super) struct sequence_bigint_bigint_bigintFunc {
 }
 
 impl sequence_bigint_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -243,6 +231,10 @@ impl ScalarUDFImpl for sequence_bigint_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_bigint_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "sequence_bigint_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -251,9 +243,9 @@ pub(super) struct sequence_date_dateFunc {
 }
 
 impl sequence_date_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Date32, DataType::Date32], volatility),
         }
     }
 }
@@ -281,17 +273,27 @@ impl ScalarUDFImpl for sequence_date_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_date_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "sequence_date_date")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct sequence_date_date_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl sequence_date_date_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["date", "date", "interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -308,6 +310,10 @@ impl ScalarUDFImpl for sequence_date_date_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("sequence", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         sequence_date_date_intervaldaytosecond_return_type(arg_types)
     }
@@ -319,6 +325,13 @@ impl ScalarUDFImpl for sequence_date_date_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_date_date_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "sequence_date_date_intervaldaytosecond",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -327,9 +340,16 @@ pub(super) struct sequence_date_date_intervalyeartomonthFunc {
 }
 
 impl sequence_date_date_intervalyeartomonthFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Date32,
+                    DataType::Date32,
+                    DataType::Interval(arrow::datatypes::IntervalUnit::YearMonth),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -357,17 +377,30 @@ impl ScalarUDFImpl for sequence_date_date_intervalyeartomonthFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_date_date_intervalyeartomonth_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "sequence_date_date_intervalyeartomonth",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct sequence_timestamp_p_timestamp_p_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl sequence_timestamp_p_timestamp_p_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["timestamp(p)", "timestamp(p)", "interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -384,6 +417,10 @@ impl ScalarUDFImpl for sequence_timestamp_p_timestamp_p_intervaldaytosecondFunc
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("sequence", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         sequence_timestamp_p_timestamp_p_intervaldaytosecond_return_type(arg_types)
     }
@@ -395,4 +432,11 @@ impl ScalarUDFImpl for sequence_timestamp_p_timestamp_p_intervaldaytosecondFunc
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         sequence_timestamp_p_timestamp_p_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "sequence_timestamp_p_timestamp_p_intervaldaytosecond",
+        )
+    }
 }
@@ -0,0 +1,547 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The Trino session: who runs the queries, where, and in which time zone.
+//!
+//! [`TrinoSession`] is a config extension of DataFusion's `SessionConfig`, whose options can
+//! also be set in SQL, e.g. `SET trino.user = 'alice'`. The session functions `current_user`,
+//! `current_groups`, `current_catalog`, `current_schema` and `current_timezone` are folded into
+//! constants from it by [`apply_session`], which [`crate::sql`] runs on the plans of Trino
+//! queries. The functions whose result is in the time zone of the session, e.g.
+//! `current_timestamp`, are typed in it by [`bind_session`], which [`crate::sql`] runs before
+//! planning them, as are the timestamps without a time zone passed to `timezone_hour` and
+//! `timezone_minute`. As in Trino, `current_user`, `current_catalog` and `current_schema` are
+//! called without parentheses, and `TIMESTAMP` literals may have a time zone, e.g.
+//! `TIMESTAMP '2024-01-01 00:00:00 +05:30'` (see [`rewrite_zoned_literals`]).
+//!
+//! ```
+//! use datafusion::prelude::{SessionConfig, SessionContext};
+//! use sql_functions::trino::session::TrinoSession;
+//! use sql_functions::{register_dialect, Dialect};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> datafusion::common::Result<()> {
+//! let mut session = TrinoSession::default();
+//! session.user = "alice".to_string();
+//! let config = SessionConfig::new().with_option_extension(session);
+//! let mut ctx = SessionContext::new_with_config(config);
+//! register_dialect(&mut ctx, Dialect::Trino)?;
+//! sql_functions::sql(&ctx, Dialect::Trino, "SET trino.time_zone = 'Europe/Paris'").await?;
+//! let df = sql_functions::sql(&ctx, Dialect::Trino, "SELECT current_user, current_timezone()").await?;
+//! # df.collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use arrow::datatypes::DataType;
+use chrono::{DateTime, Utc};
+use datafusion::common::config::{ConfigExtension, ConfigOptions};
+use datafusion::common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
+use datafusion::common::{exec_err, extensions_options, plan_datafusion_err, Result, ScalarValue};
+use datafusion::execution::context::ExecutionProps;
+use datafusion::execution::{FunctionRegistry, SessionState};
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::expr_rewriter::NamePreserver;
+use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion::logical_expr::var_provider::{VarProvider, VarType};
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, Expr, LogicalPlan, ReturnInfo, ReturnTypeArgs,
+    ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature,
+};
+use datafusion::optimizer::simplify_expressions::SimplifyContext;
+use datafusion::sql::sqlparser::ast::{
+    visit_expressions_mut, DataType as SqlDataType, Expr as SqlExpr, Function, FunctionArg,
+    FunctionArgExpr, FunctionArgumentList, FunctionArguments, Ident, ObjectName, Statement, Value,
+};
+use std::any::Any;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use crate::datetime_pattern::DateTimePattern;
+use crate::timezone::{arrow_zone, zone_key};
+use crate::utils_datetime::{iso8601_has_offset, parsed_has_zone};
+
+/// The functions whose value depends on the session, folded by [`apply_session`].
+const SESSION_FUNCTIONS: &[&str] = &[
+    "current_user",
+    "current_groups",
+    "current_catalog",
+    "current_schema",
+    "current_timezone",
+    "current_timestamp",
+];
+
+/// The functions whose result is in the time zone of the session, bound to it by
+/// [`bind_session`], with whether it is for the constant arguments of a call.
+const ZONED_FUNCTIONS: &[(&str, InSessionZone)] = &[
+    ("current_timestamp", |_| true),
+    // Unless at a given time zone or offset
+    ("from_unixtime", |args| args.len() == 1),
+    ("from_unixtime_nanos", |_| true),
+    // Unless the text has an offset
+    ("from_iso8601_timestamp", |args| {
+        !iso8601_has_offset(args[0])
+    }),
+    ("from_iso8601_timestamp_nanos", |args| {
+        !iso8601_has_offset(args[0])
+    }),
+    // Unless the text has a time zone
+    ("parse_datetime", |args| {
+        !parsed_has_zone(args[0], args[1], DateTimePattern::joda)
+    }),
+];
+
+/// The functions of a `timestamp(p) with time zone` whose timestamps without a time zone are in
+/// the time zone of the session, as Trino casts them, bound to it by [`bind_session`].
+const ZONED_ARGUMENT_FUNCTIONS: &[&str] = &["timezone_hour", "timezone_minute"];
+
+/// Whether the result of a call with the constant arguments `args` is in the time zone of the
+/// session.
+type InSessionZone = fn(args: &[Option<&ScalarValue>]) -> bool;
+
+extensions_options! {
+    /// The options of the Trino session, under the `trino` prefix, e.g. `trino.user`. Empty
+    /// options are unset.
+    pub struct TrinoSession {
+        /// The user running the queries, `current_user`. NULL when unset.
+        pub user: String, default = String::new()
+        /// The groups of the user, `current_groups`, separated by commas
+        pub groups: String, default = String::new()
+        /// The catalog of the session, `current_catalog`. NULL when unset.
+        pub catalog: String, default = String::new()
+        /// The schema of the session, `current_schema`. NULL when unset.
+        pub schema: String, default = String::new()
+        /// The time zone of the session, `current_timezone`: a region such as `America/New_York`,
+        /// or an offset such as `+02:00`
+        pub time_zone: String, default = "UTC".to_string()
+        /// The start time of the queries as an RFC 3339 timestamp, e.g.
+        /// `2024-01-01T12:00:00Z`. When unset, the time at which each query is planned.
+        pub start_time: String, default = String::new()
+    }
+}
+
+impl ConfigExtension for TrinoSession {
+    const PREFIX: &'static str = "trino";
+}
+
+impl TrinoSession {
+    /// The session of `options`, or the default session if it has no `trino` options.
+    pub fn from_config(options: &ConfigOptions) -> Self {
+        options
+            .extensions
+            .get::<Self>()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The start time of the queries, if set.
+    pub fn query_start_time(&self) -> Result<Option<DateTime<Utc>>> {
+        if self.start_time.is_empty() {
+            return Ok(None);
+        }
+        let start_time = DateTime::parse_from_rfc3339(&self.start_time).map_err(|e| {
+            plan_datafusion_err!("Invalid session start time '{}': {e}", self.start_time)
+        })?;
+        Ok(Some(start_time.with_timezone(&Utc)))
+    }
+
+    fn value(&self, function: &str) -> Result<ScalarValue> {
+        let optional = |value: &str| ScalarValue::Utf8((!value.is_empty()).then(|| value.into()));
+        match function {
+            "current_user" => Ok(optional(&self.user)),
+            "current_catalog" => Ok(optional(&self.catalog)),
+            "current_schema" => Ok(optional(&self.schema)),
+            "current_groups" => {
+                let groups = self
+                    .groups
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|group| !group.is_empty())
+                    .map(|group| ScalarValue::Utf8(Some(group.into())))
+                    .collect::<Vec<_>>();
+                Ok(ScalarValue::List(ScalarValue::new_list_nullable(
+                    &groups,
+                    &DataType::Utf8,
+                )))
+            }
+            "current_timezone" => Ok(ScalarValue::Utf8(Some(zone_key(&self.time_zone)?))),
+            _ => exec_err!("{function} is not a session function"),
+        }
+    }
+}
+
+/// The session is handed to the `simplify` of the session functions as the system variables of
+/// the [`ExecutionProps`], named after the functions.
+impl VarProvider for TrinoSession {
+    fn get_value(&self, var_names: Vec<String>) -> Result<ScalarValue> {
+        match var_names.as_slice() {
+            [function] => self.value(function),
+            _ => exec_err!("Unexpected session variable {}", var_names.join(".")),
+        }
+    }
+
+    fn get_type(&self, var_names: &[String]) -> Option<DataType> {
+        self.get_value(var_names.to_vec())
+            .ok()
+            .map(|value| value.data_type())
+    }
+}
+
+/// Rewrite `current_schema` into a call of the function: Trino calls it without parentheses, like
+/// `current_user`, but DataFusion parses it as a column.
+pub fn rewrite_session_functions(statement: &mut Statement) {
+    let _ = visit_expressions_mut(statement, |expr| {
+        if let SqlExpr::Identifier(ident) = expr {
+            if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("current_schema") {
+                *expr = SqlExpr::Function(Function {
+                    name: ObjectName(vec![ident.clone()]),
+                    uses_odbc_syntax: false,
+                    parameters: FunctionArguments::None,
+                    args: FunctionArguments::None,
+                    null_treatment: None,
+                    filter: None,
+                    over: None,
+                    within_group: vec![],
+                });
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+/// Rewrite the `TIMESTAMP` literals with a time zone, e.g. `TIMESTAMP '2024-01-01 00:00:00
+/// +05:30'`, into calls of `with_timezone` on the local date-time: DataFusion converts them to
+/// UTC, dropping their time zone.
+pub fn rewrite_zoned_literals(statement: &mut Statement) {
+    let _ = visit_expressions_mut(statement, |expr| {
+        if let SqlExpr::TypedString {
+            data_type: data_type @ SqlDataType::Timestamp(..),
+            value,
+        } = expr
+        {
+            if let Some((local, zone)) = value
+                .trim()
+                .rsplit_once(' ')
+                .filter(|(_, zone)| zone_key(zone).is_ok())
+            {
+                let local = SqlExpr::TypedString {
+                    data_type: data_type.clone(),
+                    value: local.trim().to_string(),
+                };
+                let zone = SqlExpr::Value(Value::SingleQuotedString(zone.to_string()));
+                *expr = SqlExpr::Function(Function {
+                    name: ObjectName(vec![Ident::new("with_timezone")]),
+                    uses_odbc_syntax: false,
+                    parameters: FunctionArguments::None,
+                    args: FunctionArguments::List(FunctionArgumentList {
+                        duplicate_treatment: None,
+                        args: [local, zone]
+                            .into_iter()
+                            .map(|arg| FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)))
+                            .collect(),
+                        clauses: vec![],
+                    }),
+                    null_treatment: None,
+                    filter: None,
+                    over: None,
+                    within_group: vec![],
+                });
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+/// Bind the functions of `state` whose result is in the time zone of the session, e.g.
+/// `current_timestamp`, to the time zone of its `trino` options: the plans of `state` are then
+/// typed in the time zone of the session, and so are the values of the functions. The timestamps
+/// without a time zone passed to `timezone_hour` and `timezone_minute` are in the time
+/// zone of the session too.
+pub fn bind_session(state: &mut SessionState) -> Result<()> {
+    let time_zone = TrinoSession::from_config(state.config_options())
+        .time_zone
+        .clone();
+    for name in ZONED_ARGUMENT_FUNCTIONS {
+        let Some(inner) = state.scalar_functions().get(*name).cloned() else {
+            continue;
+        };
+        state.register_udf(Arc::new(ScalarUDF::new_from_impl(SessionZonedArguments {
+            inner,
+            time_zone: arrow_zone(&time_zone)?,
+        })))?;
+    }
+    for (name, in_session_zone) in ZONED_FUNCTIONS {
+        let Some(inner) = state.scalar_functions().get(*name).cloned() else {
+            continue;
+        };
+        state.register_udf(Arc::new(ScalarUDF::new_from_impl(SessionZoned {
+            inner,
+            time_zone: time_zone.clone(),
+            in_session_zone: *in_session_zone,
+        })))?;
+    }
+    Ok(())
+}
+
+/// A function whose result is in the time zone of the session: its result, typed in UTC, is
+/// converted to the time zone of the session, which is only checked when the function is planned.
+/// The function is called with its return type in the time zone of the session, e.g. for the
+/// local date-times it reads to be in that time zone.
+#[derive(Debug)]
+struct SessionZoned {
+    inner: Arc<ScalarUDF>,
+    time_zone: String,
+    in_session_zone: InSessionZone,
+}
+
+impl SessionZoned {
+    /// `data_type` of a call with the constant arguments `args`, in the time zone of the session
+    /// if it is a timestamp with time zone
+    fn zoned(&self, data_type: DataType, args: &[Option<&ScalarValue>]) -> Result<DataType> {
+        Ok(match data_type {
+            DataType::Timestamp(unit, Some(_)) if (self.in_session_zone)(args) => {
+                DataType::Timestamp(unit, Some(arrow_zone(&self.time_zone)?))
+            }
+            data_type => data_type,
+        })
+    }
+}
+
+impl ScalarUDFImpl for SessionZoned {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn aliases(&self) -> &[String] {
+        self.inner.aliases()
+    }
+
+    fn signature(&self) -> &Signature {
+        self.inner.signature()
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        self.inner.coerce_types(arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let args = vec![None; arg_types.len()];
+        self.zoned(self.inner.return_type(arg_types)?, &args)
+    }
+
+    fn return_type_from_args(&self, args: ReturnTypeArgs) -> Result<ReturnInfo> {
+        let scalar_arguments = args.scalar_arguments;
+        let info = self.inner.return_type_from_args(args)?;
+        let return_type = self.zoned(info.return_type().clone(), scalar_arguments)?;
+        Ok(if info.nullable() {
+            ReturnInfo::new_nullable(return_type)
+        } else {
+            ReturnInfo::new_non_nullable(return_type)
+        })
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let return_type = args.return_type;
+        let result = self.inner.invoke_with_args(args)?;
+        if result.data_type() == *return_type {
+            return Ok(result);
+        }
+        result.cast_to(return_type, None)
+    }
+
+    /// The function is folded into a constant in the time zone of the session.
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        let constants = args
+            .iter()
+            .map(|arg| match arg {
+                Expr::Literal(value) => Some(value.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        Ok(match self.inner.simplify(args, info)? {
+            ExprSimplifyResult::Simplified(Expr::Literal(value)) => {
+                let constants = constants.iter().map(Option::as_ref).collect::<Vec<_>>();
+                let return_type = self.zoned(value.data_type(), &constants)?;
+                ExprSimplifyResult::Simplified(Expr::Literal(value.cast_to(&return_type)?))
+            }
+            result => result,
+        })
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.inner.documentation()
+    }
+}
+
+/// A function whose timestamps without a time zone are coerced to the time zone of the session,
+/// rather than to UTC.
+#[derive(Debug)]
+struct SessionZonedArguments {
+    inner: Arc<ScalarUDF>,
+    time_zone: Arc<str>,
+}
+
+impl ScalarUDFImpl for SessionZonedArguments {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn aliases(&self) -> &[String] {
+        self.inner.aliases()
+    }
+
+    fn signature(&self) -> &Signature {
+        self.inner.signature()
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let coerced = self.inner.coerce_types(arg_types)?;
+        Ok(arg_types
+            .iter()
+            .zip(coerced)
+            .map(|(arg_type, coerced)| match (arg_type, coerced) {
+                (DataType::Timestamp(_, None), DataType::Timestamp(unit, Some(_))) => {
+                    DataType::Timestamp(unit, Some(Arc::clone(&self.time_zone)))
+                }
+                (_, coerced) => coerced,
+            })
+            .collect())
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        self.inner.return_type(arg_types)
+    }
+
+    fn return_type_from_args(&self, args: ReturnTypeArgs) -> Result<ReturnInfo> {
+        self.inner.return_type_from_args(args)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        self.inner.invoke_with_args(args)
+    }
+
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        self.inner.simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.inner.documentation()
+    }
+}
+
+/// Fold the session functions of `plan` into constants, from the `trino` options of `options`.
+/// Their calls are simplified with the session in the [`ExecutionProps`], and the query started
+/// at `query_start_time` unless the session sets its start time.
+///
+/// The values of the functions whose result is in the time zone of the session, e.g.
+/// `current_timestamp`, are in that time zone if `plan` was planned with [`bind_session`], and
+/// in UTC otherwise, as their types.
+pub fn apply_session(
+    plan: LogicalPlan,
+    options: &ConfigOptions,
+    query_start_time: DateTime<Utc>,
+) -> Result<LogicalPlan> {
+    if !calls_session_function(&plan)? {
+        return Ok(plan);
+    }
+    let session = TrinoSession::from_config(options);
+    let mut props = ExecutionProps::new();
+    props.query_execution_start_time = session.query_start_time()?.unwrap_or(query_start_time);
+    props.add_var_provider(VarType::System, Arc::new(session));
+    let info = SimplifyContext::new(&props);
+    let plan = plan.transform_up_with_subqueries(|plan| {
+        let names = NamePreserver::new(&plan);
+        plan.map_expressions(|expr| {
+            let name = names.save(&expr);
+            let folded = expr.transform_up(|expr| fold(expr, &info))?;
+            Ok(folded.update_data(|expr| name.restore(expr)))
+        })
+    })?;
+    Ok(plan.data)
+}
+
+fn is_session_function(expr: &Expr) -> bool {
+    matches!(expr, Expr::ScalarFunction(f) if SESSION_FUNCTIONS.contains(&f.func.name()))
+}
+
+fn calls_session_function(plan: &LogicalPlan) -> Result<bool> {
+    let mut found = false;
+    plan.apply_with_subqueries(|plan| {
+        plan.apply_expressions(|expr| {
+            expr.apply(|expr| {
+                found = is_session_function(expr);
+                Ok(if found {
+                    TreeNodeRecursion::Stop
+                } else {
+                    TreeNodeRecursion::Continue
+                })
+            })
+        })
+    })?;
+    Ok(found)
+}
+
+fn fold(expr: Expr, info: &SimplifyContext) -> Result<Transformed<Expr>> {
+    if !is_session_function(&expr) {
+        return Ok(Transformed::no(expr));
+    }
+    let Expr::ScalarFunction(ScalarFunction { func, args }) = expr else {
+        unreachable!()
+    };
+    Ok(match func.simplify(args, info)? {
+        ExprSimplifyResult::Simplified(expr) => Transformed::yes(expr),
+        ExprSimplifyResult::Original(args) => {
+            Transformed::no(Expr::ScalarFunction(ScalarFunction { func, args }))
+        }
+    })
+}
+
+/// The value of the session function `name`, if the session is known to the simplification,
+/// i.e. in [`apply_session`].
+fn session_value(name: &str, info: &dyn SimplifyInfo) -> Result<Option<ScalarValue>> {
+    let Some(session) = info.execution_props().get_var_provider(VarType::System) else {
+        return Ok(None);
+    };
+    session.get_value(vec![name.to_string()]).map(Some)
+}
+
+/// Fold the call of the session function `name` into its value, cast to its declared return type.
+pub(crate) fn simplify_session_function(
+    name: &str,
+    args: Vec<Expr>,
+    info: &dyn SimplifyInfo,
+) -> Result<ExprSimplifyResult> {
+    let Some(value) = session_value(name, info)? else {
+        return Ok(ExprSimplifyResult::Original(args));
+    };
+    let value = value.cast_to(&super::types::declared_return_type(name, &[])?)?;
+    Ok(ExprSimplifyResult::Simplified(Expr::Literal(value)))
+}
+
+/// The session functions are only evaluated when they could not be folded, the session being
+/// unknown to the execution.
+pub(crate) fn unknown_session(name: &str) -> Result<ColumnarValue> {
+    exec_err!("{name} depends on the Trino session, which is only applied by sql_functions::sql")
+}
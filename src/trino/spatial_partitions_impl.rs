@@ -83,9 +83,9 @@ pub(super) struct spatial_partitions_kdbtree_geometryFunc {
 }
 
 impl spatial_partitions_kdbtree_geometryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -113,6 +113,13 @@ impl ScalarUDFImpl for spatial_partitions_kdbtree_geometryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         spatial_partitions_kdbtree_geometry_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "spatial_partitions_kdbtree_geometry",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -121,9 +128,12 @@ pub(super) struct spatial_partitions_kdbtree_geometry_doubleFunc {
 }
 
 impl spatial_partitions_kdbtree_geometry_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Binary, DataType::Binary, DataType::Float64],
+                volatility,
+            ),
         }
     }
 }
@@ -151,4 +161,11 @@ impl ScalarUDFImpl for spatial_partitions_kdbtree_geometry_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         spatial_partitions_kdbtree_geometry_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "spatial_partitions_kdbtree_geometry_double",
+        )
+    }
 }
@@ -109,9 +109,9 @@ pub(super) struct split_varchar_varcharFunc {
 }
 
 impl split_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -139,6 +139,10 @@ impl ScalarUDFImpl for split_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         split_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "split_varchar_varchar")
+    }
 }
 
 #[derive(Debug)]
@@ -147,9 +151,12 @@ pub(super) struct split_varchar_varchar_bigintFunc {
 }
 
 impl split_varchar_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -177,4 +184,8 @@ impl ScalarUDFImpl for split_varchar_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         split_varchar_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "split_varchar_varchar_bigint")
+    }
 }
@@ -56,9 +56,12 @@ pub(super) struct split_part_varchar_varchar_bigintFunc {
 }
 
 impl split_part_varchar_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,11 @@ impl ScalarUDFImpl for split_part_varchar_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         split_part_varchar_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "split_part_varchar_varchar_bigint",
+        )
+    }
 }
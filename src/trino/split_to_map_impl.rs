@@ -56,9 +56,12 @@ pub(super) struct split_to_map_varchar_varchar_varcharFunc {
 }
 
 impl split_to_map_varchar_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8, DataType::Utf8],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,11 @@ impl ScalarUDFImpl for split_to_map_varchar_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         split_to_map_varchar_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "split_to_map_varchar_varchar_varchar",
+        )
+    }
 }
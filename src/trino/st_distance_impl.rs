@@ -83,9 +83,9 @@ pub(super) struct st_distance_geometry_geometryFunc {
 }
 
 impl st_distance_geometry_geometryFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -113,6 +113,10 @@ impl ScalarUDFImpl for st_distance_geometry_geometryFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         st_distance_geometry_geometry_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "st_distance_geometry_geometry")
+    }
 }
 
 #[derive(Debug)]
@@ -121,9 +125,9 @@ pub(super) struct st_distance_sphericalgeography_sphericalgeographyFunc {
 }
 
 impl st_distance_sphericalgeography_sphericalgeographyFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Binary], volatility),
         }
     }
 }
@@ -151,4 +155,11 @@ impl ScalarUDFImpl for st_distance_sphericalgeography_sphericalgeographyFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         st_distance_sphericalgeography_sphericalgeography_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "st_distance_sphericalgeography_sphericalgeography",
+        )
+    }
 }
@@ -56,9 +56,9 @@ pub(super) struct st_interiorringn_geometry_bigintFunc {
 }
 
 impl st_interiorringn_geometry_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Int64], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for st_interiorringn_geometry_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         st_interiorringn_geometry_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "st_interiorringn_geometry_bigint",
+        )
+    }
 }
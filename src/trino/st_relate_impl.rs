@@ -56,9 +56,12 @@ pub(super) struct st_relate_geometry_geometry_varcharFunc {
 }
 
 impl st_relate_geometry_geometry_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Binary, DataType::Binary, DataType::Utf8],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +89,11 @@ impl ScalarUDFImpl for st_relate_geometry_geometry_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         st_relate_geometry_geometry_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "st_relate_geometry_geometry_varchar",
+        )
+    }
 }
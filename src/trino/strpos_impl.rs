@@ -26,11 +26,12 @@ use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Vo
 use std::any::Any;
 
 fn strpos_varchar_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    strpos().invoke_batch(args, 1)
+    // DataFusion's strpos returns the positions in an Int32 array for Utf8 strings
+    strpos().invoke_batch(args, 1)?.cast_to(&Int64, None)
 }
 
-fn strpos_varchar_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Ok(Int64)
+fn strpos_varchar_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("strpos_varchar_varchar", arg_types)
 }
 
 fn strpos_varchar_varchar_simplify(
@@ -69,9 +70,9 @@ pub(super) struct strpos_varchar_varcharFunc {
 }
 
 impl strpos_varchar_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Utf8], volatility),
         }
     }
 }
@@ -99,6 +100,10 @@ impl ScalarUDFImpl for strpos_varchar_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         strpos_varchar_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "strpos_varchar_varchar")
+    }
 }
 
 #[derive(Debug)]
@@ -107,9 +112,12 @@ pub(super) struct strpos_varchar_varchar_bigintFunc {
 }
 
 impl strpos_varchar_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -137,4 +145,8 @@ impl ScalarUDFImpl for strpos_varchar_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         strpos_varchar_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "strpos_varchar_varchar_bigint")
+    }
 }
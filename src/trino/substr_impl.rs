@@ -125,9 +125,9 @@ pub(super) struct substr_varchar_bigintFunc {
 }
 
 impl substr_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -155,6 +155,10 @@ impl ScalarUDFImpl for substr_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substr_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "substr_varchar_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -163,9 +167,12 @@ pub(super) struct substr_varchar_bigint_bigintFunc {
 }
 
 impl substr_varchar_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -193,6 +200,10 @@ impl ScalarUDFImpl for substr_varchar_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substr_varchar_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "substr_varchar_bigint_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -201,9 +212,9 @@ pub(super) struct substr_varbinary_bigintFunc {
 }
 
 impl substr_varbinary_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Int64], volatility),
         }
     }
 }
@@ -231,6 +242,10 @@ impl ScalarUDFImpl for substr_varbinary_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substr_varbinary_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "substr_varbinary_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -239,9 +254,12 @@ pub(super) struct substr_varbinary_bigint_bigintFunc {
 }
 
 impl substr_varbinary_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Binary, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -269,4 +287,8 @@ impl ScalarUDFImpl for substr_varbinary_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substr_varbinary_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "substr_varbinary_bigint_bigint")
+    }
 }
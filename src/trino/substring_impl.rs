@@ -79,9 +79,9 @@ pub(super) struct substring_varchar_bigintFunc {
 }
 
 impl substring_varchar_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Utf8, DataType::Int64], volatility),
         }
     }
 }
@@ -109,6 +109,10 @@ impl ScalarUDFImpl for substring_varchar_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substring_varchar_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "substring_varchar_bigint")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +121,12 @@ pub(super) struct substring_varchar_bigint_bigintFunc {
 }
 
 impl substring_varchar_bigint_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Int64, DataType::Int64],
+                volatility,
+            ),
         }
     }
 }
@@ -147,4 +154,11 @@ impl ScalarUDFImpl for substring_varchar_bigint_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         substring_varchar_bigint_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "substring_varchar_bigint_bigint",
+        )
+    }
 }
@@ -56,9 +56,15 @@ pub(super) struct timestamp_objectid_timestamp_0Func {
 }
 
 impl timestamp_objectid_timestamp_0Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Timestamp(
+                    arrow::datatypes::TimeUnit::Second,
+                    None,
+                )],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +92,8 @@ impl ScalarUDFImpl for timestamp_objectid_timestamp_0Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         timestamp_objectid_timestamp_0_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "timestamp_objectid_timestamp_0")
+    }
 }
@@ -18,51 +18,45 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn timezone_minute_time_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::map_zone_offset;
+
+/// The minutes past the hours of an offset from UTC in minutes, negative west of UTC.
+fn offset_minutes(minutes: i64) -> i64 {
+    minutes % 60
+}
+
+fn timezone_minute_time_p_withtimezone_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_zone_offset(&args[0], offset_minutes)
 }
 
-fn timezone_minute_time_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn timezone_minute_time_p_withtimezone_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("timezone_minute_time_p_withtimezone", arg_types)
 }
 
-fn timezone_minute_time_p_simplify(
+fn timezone_minute_time_p_withtimezone_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn timezone_minute_timestamp_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn timezone_minute_timestamp_p_withtimezone_invoke(
+    args: &[ColumnarValue],
+) -> Result<ColumnarValue> {
+    map_zone_offset(&args[0], offset_minutes)
 }
 
-fn timezone_minute_timestamp_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn timezone_minute_timestamp_p_withtimezone_return_type(
+    arg_types: &[DataType],
+) -> Result<DataType> {
+    super::types::declared_return_type("timezone_minute_timestamp_p_withtimezone", arg_types)
 }
 
-fn timezone_minute_timestamp_p_simplify(
+fn timezone_minute_timestamp_p_withtimezone_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
@@ -74,19 +68,25 @@ fn timezone_minute_timestamp_p_simplify(
 // when template is regenerated!
 
 #[derive(Debug)]
-pub(super) struct timezone_minute_time_pFunc {
+pub(super) struct timezone_minute_time_p_withtimezoneFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
-impl timezone_minute_time_pFunc {
-    pub fn new() -> Self {
+impl timezone_minute_time_p_withtimezoneFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["time(p) with time zone"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
 
-impl ScalarUDFImpl for timezone_minute_time_pFunc {
+impl ScalarUDFImpl for timezone_minute_time_p_withtimezoneFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -98,33 +98,50 @@ impl ScalarUDFImpl for timezone_minute_time_pFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("timezone_minute", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        timezone_minute_time_p_return_type(arg_types)
+        timezone_minute_time_p_withtimezone_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        timezone_minute_time_p_invoke(args)
+        timezone_minute_time_p_withtimezone_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        timezone_minute_time_p_simplify(args, info)
+        timezone_minute_time_p_withtimezone_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "timezone_minute_time_p_withtimezone",
+        )
     }
 }
 
 #[derive(Debug)]
-pub(super) struct timezone_minute_timestamp_pFunc {
+pub(super) struct timezone_minute_timestamp_p_withtimezoneFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
-impl timezone_minute_timestamp_pFunc {
-    pub fn new() -> Self {
+impl timezone_minute_timestamp_p_withtimezoneFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["timestamp(p) with time zone"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
 
-impl ScalarUDFImpl for timezone_minute_timestamp_pFunc {
+impl ScalarUDFImpl for timezone_minute_timestamp_p_withtimezoneFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -136,15 +153,26 @@ impl ScalarUDFImpl for timezone_minute_timestamp_pFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("timezone_minute", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        timezone_minute_timestamp_p_return_type(arg_types)
+        timezone_minute_timestamp_p_withtimezone_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        timezone_minute_timestamp_p_invoke(args)
+        timezone_minute_timestamp_p_withtimezone_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        timezone_minute_timestamp_p_simplify(args, info)
+        timezone_minute_timestamp_p_withtimezone_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "timezone_minute_timestamp_p_withtimezone",
+        )
     }
 }
@@ -56,9 +56,29 @@ pub(super) struct to_char_timestamp_p_varcharFunc {
 }
 
 impl to_char_timestamp_p_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Second, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Millisecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None),
+                        DataType::Utf8,
+                    ]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -86,4 +106,8 @@ impl ScalarUDFImpl for to_char_timestamp_p_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         to_char_timestamp_p_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "to_char_timestamp_p_varchar")
+    }
 }
@@ -56,9 +56,9 @@ pub(super) struct to_geojson_geometry_sphericalgeographyFunc {
 }
 
 impl to_geojson_geometry_sphericalgeographyFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary], volatility),
         }
     }
 }
@@ -86,4 +86,11 @@ impl ScalarUDFImpl for to_geojson_geometry_sphericalgeographyFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         to_geojson_geometry_sphericalgeography_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "to_geojson_geometry_sphericalgeography",
+        )
+    }
 }
@@ -18,25 +18,18 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 
-fn to_iso8601_date_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::map_format_iso8601;
+
+fn to_iso8601_date_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_format_iso8601(&args[0])
 }
 
-fn to_iso8601_date_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn to_iso8601_date_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("to_iso8601_date", arg_types)
 }
 
 fn to_iso8601_date_simplify(
@@ -46,20 +39,12 @@ fn to_iso8601_date_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
-fn to_iso8601_timestamp_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn to_iso8601_timestamp_p_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_format_iso8601(&args[0])
 }
 
-fn to_iso8601_timestamp_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn to_iso8601_timestamp_p_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("to_iso8601_timestamp_p", arg_types)
 }
 
 fn to_iso8601_timestamp_p_simplify(
@@ -69,6 +54,21 @@ fn to_iso8601_timestamp_p_simplify(
     Ok(ExprSimplifyResult::Original(args))
 }
 
+fn to_iso8601_timestamp_p_withtimezone_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_format_iso8601(&args[0])
+}
+
+fn to_iso8601_timestamp_p_withtimezone_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("to_iso8601_timestamp_p_withtimezone", arg_types)
+}
+
+fn to_iso8601_timestamp_p_withtimezone_simplify(
+    args: Vec<Expr>,
+    _info: &dyn SimplifyInfo,
+) -> Result<ExprSimplifyResult> {
+    Ok(ExprSimplifyResult::Original(args))
+}
+
 // ========== Generated template below this line ==========
 // Do *NOT* edit below this line: all changes will be overwritten
 // when template is regenerated!
@@ -79,9 +79,9 @@ pub(super) struct to_iso8601_dateFunc {
 }
 
 impl to_iso8601_dateFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Date32], volatility),
         }
     }
 }
@@ -109,6 +109,10 @@ impl ScalarUDFImpl for to_iso8601_dateFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         to_iso8601_date_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "to_iso8601_date")
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +121,29 @@ pub(super) struct to_iso8601_timestamp_pFunc {
 }
 
 impl to_iso8601_timestamp_pFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Second,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Millisecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Microsecond,
+                        None,
+                    )]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![DataType::Timestamp(
+                        arrow::datatypes::TimeUnit::Nanosecond,
+                        None,
+                    )]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -147,4 +171,63 @@ impl ScalarUDFImpl for to_iso8601_timestamp_pFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         to_iso8601_timestamp_p_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "to_iso8601_timestamp_p")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct to_iso8601_timestamp_p_withtimezoneFunc {
+    signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
+}
+
+impl to_iso8601_timestamp_p_withtimezoneFunc {
+    pub fn new(volatility: Volatility) -> Self {
+        Self {
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["timestamp(p) with time zone"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
+        }
+    }
+}
+
+impl ScalarUDFImpl for to_iso8601_timestamp_p_withtimezoneFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "to_iso8601"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("to_iso8601", &self.parameters, arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        to_iso8601_timestamp_p_withtimezone_return_type(arg_types)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        to_iso8601_timestamp_p_withtimezone_invoke(args)
+    }
+
+    fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
+        to_iso8601_timestamp_p_withtimezone_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "to_iso8601_timestamp_p_withtimezone",
+        )
+    }
 }
@@ -16,27 +16,36 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
-use arrow::datatypes::DataType;
+use arrow::array::{Array, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Int64Type, IntervalMonthDayNanoType, IntervalUnit};
+use arrow::error::ArrowError;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
-fn to_milliseconds_intervaldaytosecond_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils::apply_unary_kernel;
+
+/// The number of milliseconds of the day to second interval `arg[0]`, its nanoseconds truncated.
+fn to_milliseconds_intervaldaytosecond_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    apply_unary_kernel(&args[0], |arr| {
+        let intervals = cast(arr, &DataType::Interval(IntervalUnit::MonthDayNano))?;
+        let millis = intervals
+            .as_primitive::<IntervalMonthDayNanoType>()
+            .try_unary::<_, Int64Type, _>(|interval| {
+                i64::from(interval.days)
+                    .checked_mul(86_400_000)
+                    .and_then(|millis| millis.checked_add(interval.nanoseconds / 1_000_000))
+                    .ok_or_else(|| ArrowError::ComputeError("bigint overflow".to_string()))
+            })?;
+        Ok(Arc::new(millis) as Arc<dyn Array>)
+    })
 }
 
-fn to_milliseconds_intervaldaytosecond_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn to_milliseconds_intervaldaytosecond_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("to_milliseconds_intervaldaytosecond", arg_types)
 }
 
 fn to_milliseconds_intervaldaytosecond_simplify(
@@ -53,12 +62,18 @@ fn to_milliseconds_intervaldaytosecond_simplify(
 #[derive(Debug)]
 pub(super) struct to_milliseconds_intervaldaytosecondFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl to_milliseconds_intervaldaytosecondFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["interval day to second"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +90,10 @@ impl ScalarUDFImpl for to_milliseconds_intervaldaytosecondFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("to_milliseconds", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         to_milliseconds_intervaldaytosecond_return_type(arg_types)
     }
@@ -86,4 +105,11 @@ impl ScalarUDFImpl for to_milliseconds_intervaldaytosecondFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         to_milliseconds_intervaldaytosecond_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "to_milliseconds_intervaldaytosecond",
+        )
+    }
 }
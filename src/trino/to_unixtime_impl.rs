@@ -18,28 +18,26 @@
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
-fn to_unixtime_timestamp_p_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils::{array_to_columnar, columnar_len};
+use crate::utils_datetime::epoch_seconds;
+
+fn to_unixtime_timestamp_p_withtimezone_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let len = columnar_len(&[&args[0]]);
+    let ts_arr = args[0].to_array(len)?;
+    let res = Arc::new(epoch_seconds(&ts_arr)?);
+    array_to_columnar(res, &[&args[0]])
 }
 
-fn to_unixtime_timestamp_p_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn to_unixtime_timestamp_p_withtimezone_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("to_unixtime_timestamp_p_withtimezone", arg_types)
 }
 
-fn to_unixtime_timestamp_p_simplify(
+fn to_unixtime_timestamp_p_withtimezone_simplify(
     args: Vec<Expr>,
     _info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
@@ -51,19 +49,25 @@ fn to_unixtime_timestamp_p_simplify(
 // when template is regenerated!
 
 #[derive(Debug)]
-pub(super) struct to_unixtime_timestamp_pFunc {
+pub(super) struct to_unixtime_timestamp_p_withtimezoneFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
-impl to_unixtime_timestamp_pFunc {
-    pub fn new() -> Self {
+impl to_unixtime_timestamp_p_withtimezoneFunc {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["timestamp(p) with time zone"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
 
-impl ScalarUDFImpl for to_unixtime_timestamp_pFunc {
+impl ScalarUDFImpl for to_unixtime_timestamp_p_withtimezoneFunc {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -75,15 +79,26 @@ impl ScalarUDFImpl for to_unixtime_timestamp_pFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("to_unixtime", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        to_unixtime_timestamp_p_return_type(arg_types)
+        to_unixtime_timestamp_p_withtimezone_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_unixtime_timestamp_p_invoke(args)
+        to_unixtime_timestamp_p_withtimezone_invoke(args)
     }
 
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
-        to_unixtime_timestamp_p_simplify(args, info)
+        to_unixtime_timestamp_p_withtimezone_simplify(args, info)
+    }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "to_unixtime_timestamp_p_withtimezone",
+        )
     }
 }
@@ -16,34 +16,43 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::ListElements;
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn transform_array_1_function_1_11_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("transform")
 }
 
-fn transform_array_1_function_1_11_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn transform_array_1_function_1_11_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("transform_array_1_function_1_11", arg_types)
 }
 
 fn transform_array_1_function_1_11_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "transform",
+        args,
+        info,
+        1,
+        transform_array_1_function_1_11_return_type,
+        transform,
+    )
+}
+
+/// The arrays of the results of the lambda expression over their elements.
+fn transform(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let elements = ListElements::new(&args[0])?;
+    let values = lambdas[0].call(&[Arc::clone(&elements.values)], &elements.rows)?;
+    elements.to_list(values)
 }
 
 // ========== Generated template below this line ==========
@@ -53,12 +62,18 @@ fn transform_array_1_function_1_11_simplify(
 #[derive(Debug)]
 pub(super) struct transform_array_1_function_1_11Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl transform_array_1_function_1_11Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "function($1, $11)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -75,6 +90,10 @@ impl ScalarUDFImpl for transform_array_1_function_1_11Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("transform", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         transform_array_1_function_1_11_return_type(arg_types)
     }
@@ -86,4 +105,11 @@ impl ScalarUDFImpl for transform_array_1_function_1_11Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         transform_array_1_function_1_11_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "transform_array_1_function_1_11",
+        )
+    }
 }
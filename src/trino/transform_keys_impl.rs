@@ -16,38 +16,68 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::MapEntries;
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use arrow::row::{RowConverter, SortField};
+use arrow::util::display::array_value_to_string;
+use datafusion::common::{exec_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 fn transform_keys_map_13_5_function_13_5_12_invoke(
     _args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("transform_keys")
 }
 
 fn transform_keys_map_13_5_function_13_5_12_return_type(
-    _arg_types: &[DataType],
+    arg_types: &[DataType],
 ) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    super::types::declared_return_type("transform_keys_map_13_5_function_13_5_12", arg_types)
 }
 
 fn transform_keys_map_13_5_function_13_5_12_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "transform_keys",
+        args,
+        info,
+        1,
+        transform_keys_map_13_5_function_13_5_12_return_type,
+        transform_keys,
+    )
+}
+
+/// The maps whose keys are replaced by the results of the lambda expression over their
+/// entries, which must be distinct within each map and not NULL.
+fn transform_keys(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let entries = MapEntries::new(&args[0])?;
+    let keys = lambdas[0].call(
+        &[Arc::clone(&entries.keys), Arc::clone(&entries.values)],
+        &entries.rows,
+    )?;
+    if keys.null_count() > 0 {
+        return exec_err!("map key cannot be null");
+    }
+    let converter = RowConverter::new(vec![SortField::new(keys.data_type().clone())])?;
+    let rows = converter.convert_columns(&[Arc::clone(&keys)])?;
+    for range in entries.offsets.windows(2) {
+        let mut seen = HashSet::new();
+        for i in range[0] as usize..range[1] as usize {
+            if !seen.insert(rows.row(i)) {
+                let key = array_value_to_string(&keys, i)?;
+                return exec_err!("Duplicate keys ({key}) are not allowed");
+            }
+        }
+    }
+    entries.to_map(keys, Arc::clone(&entries.values))
 }
 
 // ========== Generated template below this line ==========
@@ -57,12 +87,18 @@ fn transform_keys_map_13_5_function_13_5_12_simplify(
 #[derive(Debug)]
 pub(super) struct transform_keys_map_13_5_function_13_5_12Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl transform_keys_map_13_5_function_13_5_12Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<$13, $5>", "function($13, $5, $12)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +115,10 @@ impl ScalarUDFImpl for transform_keys_map_13_5_function_13_5_12Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("transform_keys", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         transform_keys_map_13_5_function_13_5_12_return_type(arg_types)
     }
@@ -90,4 +130,11 @@ impl ScalarUDFImpl for transform_keys_map_13_5_function_13_5_12Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         transform_keys_map_13_5_function_13_5_12_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "transform_keys_map_13_5_function_13_5_12",
+        )
+    }
 }
@@ -16,38 +16,49 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::MapEntries;
+use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
+use std::sync::Arc;
 
 fn transform_values_map_4_8_function_4_8_7_invoke(
     _args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("transform_values")
 }
 
-fn transform_values_map_4_8_function_4_8_7_return_type(
-    _arg_types: &[DataType],
-) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+fn transform_values_map_4_8_function_4_8_7_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("transform_values_map_4_8_function_4_8_7", arg_types)
 }
 
 fn transform_values_map_4_8_function_4_8_7_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "transform_values",
+        args,
+        info,
+        1,
+        transform_values_map_4_8_function_4_8_7_return_type,
+        transform_values,
+    )
+}
+
+/// The maps whose values are replaced by the results of the lambda expression over their
+/// entries.
+fn transform_values(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let entries = MapEntries::new(&args[0])?;
+    let values = lambdas[0].call(
+        &[Arc::clone(&entries.keys), Arc::clone(&entries.values)],
+        &entries.rows,
+    )?;
+    entries.to_map(Arc::clone(&entries.keys), values)
 }
 
 // ========== Generated template below this line ==========
@@ -57,12 +68,18 @@ fn transform_values_map_4_8_function_4_8_7_simplify(
 #[derive(Debug)]
 pub(super) struct transform_values_map_4_8_function_4_8_7Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl transform_values_map_4_8_function_4_8_7Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["map<$4, $8>", "function($4, $8, $7)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +96,10 @@ impl ScalarUDFImpl for transform_values_map_4_8_function_4_8_7Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("transform_values", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         transform_values_map_4_8_function_4_8_7_return_type(arg_types)
     }
@@ -90,4 +111,11 @@ impl ScalarUDFImpl for transform_values_map_4_8_function_4_8_7Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         transform_values_map_4_8_function_4_8_7_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "transform_values_map_4_8_function_4_8_7",
+        )
+    }
 }
@@ -18,8 +18,8 @@
 #![allow(non_camel_case_types)]
 use arrow::array::Decimal128Array;
 use arrow::datatypes::DataType;
-use datafusion::common::cast::as_decimal128_array;
-use datafusion::common::{exec_err, Result};
+use datafusion::common::cast::{as_decimal128_array, as_int64_array};
+use datafusion::common::Result;
 use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
@@ -27,35 +27,39 @@ use datafusion::scalar::ScalarValue;
 use std::any::Any;
 use std::sync::Arc;
 
-fn truncate_decimal_p_s_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let precision = if let ColumnarValue::Scalar(ScalarValue::Int64(Some(v))) = args[1].to_owned() {
-        v
-    } else {
-        return exec_err!("Second argument of `truncate` must be non-null scalar bigint");
-    };
-
-    let num = truncate(args, precision)?;
+use crate::utils::{array_to_columnar, columnar_len};
 
-    Ok(ColumnarValue::Array(Arc::new(num)))
+fn truncate_decimal_p_s_bigint_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    truncate(&args[0], &args[1])
 }
 
-fn truncate(args: &[ColumnarValue], precision: i64) -> Result<Decimal128Array> {
-    let args = ColumnarValue::values_to_arrays(args)?;
-    let num = as_decimal128_array(&args[0])?;
-    let p = num.precision();
+/// Truncate the decimals `num_col` to `digits_col` digits after the decimal point
+/// (before it, if negative).
+fn truncate(num_col: &ColumnarValue, digits_col: &ColumnarValue) -> Result<ColumnarValue> {
+    let len = columnar_len(&[num_col, digits_col]);
+    let num_arr = num_col.to_owned().into_array(len)?;
+    let digits_arr = digits_col.to_owned().into_array(len)?;
+    let num = as_decimal128_array(&num_arr)?;
+    let digits = as_int64_array(&digits_arr)?;
     let s = num.scale();
-    let num = num
-        .into_iter()
-        .map(|num| {
-            num.map(|num| {
-                let factor = 10i128.pow((s - precision as i8).max(0) as u32);
-
-                num / factor * factor
-            })
+    let res = num
+        .iter()
+        .zip(digits.iter())
+        .map(|pair| match pair {
+            (Some(num), Some(digits)) => {
+                let exponent = (i64::from(s) - digits).clamp(0, u32::MAX.into()) as u32;
+                // Truncating to more digits than the number has leaves 0
+                Some(
+                    10i128
+                        .checked_pow(exponent)
+                        .map_or(0, |factor| num / factor * factor),
+                )
+            }
+            _ => None,
         })
         .collect::<Decimal128Array>()
-        .with_precision_and_scale(p, s)?;
-    Ok(num)
+        .with_precision_and_scale(num.precision(), s)?;
+    array_to_columnar(Arc::new(res), &[num_col, digits_col])
 }
 
 fn truncate_decimal_p_s_bigint_return_type(arg_types: &[DataType]) -> Result<DataType> {
@@ -70,9 +74,10 @@ fn truncate_decimal_p_s_bigint_simplify(
 }
 
 fn truncate_decimal_p_s_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let num = truncate(args, 0)?;
-
-    Ok(ColumnarValue::Array(Arc::new(num)))
+    truncate(
+        &args[0],
+        &ColumnarValue::Scalar(ScalarValue::Int64(Some(0))),
+    )
 }
 
 fn truncate_decimal_p_s_return_type(arg_types: &[DataType]) -> Result<DataType> {
@@ -136,12 +141,18 @@ fn truncate_real_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<E
 #[derive(Debug)]
 pub(super) struct truncate_decimal_p_s_bigintFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl truncate_decimal_p_s_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["decimal(p, s)", "bigint"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -158,6 +169,10 @@ impl ScalarUDFImpl for truncate_decimal_p_s_bigintFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("truncate", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         truncate_decimal_p_s_bigint_return_type(arg_types)
     }
@@ -169,17 +184,24 @@ impl ScalarUDFImpl for truncate_decimal_p_s_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         truncate_decimal_p_s_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "truncate_decimal_p_s_bigint")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct truncate_decimal_p_sFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl truncate_decimal_p_sFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["decimal(p, s)"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -196,6 +218,10 @@ impl ScalarUDFImpl for truncate_decimal_p_sFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("truncate", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         truncate_decimal_p_s_return_type(arg_types)
     }
@@ -207,6 +233,10 @@ impl ScalarUDFImpl for truncate_decimal_p_sFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         truncate_decimal_p_s_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "truncate_decimal_p_s")
+    }
 }
 
 #[derive(Debug)]
@@ -215,9 +245,9 @@ pub(super) struct truncate_doubleFunc {
 }
 
 impl truncate_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float64], volatility),
         }
     }
 }
@@ -245,6 +275,10 @@ impl ScalarUDFImpl for truncate_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         truncate_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "truncate_double")
+    }
 }
 
 #[derive(Debug)]
@@ -253,9 +287,9 @@ pub(super) struct truncate_realFunc {
 }
 
 impl truncate_realFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Float32], volatility),
         }
     }
 }
@@ -283,4 +317,8 @@ impl ScalarUDFImpl for truncate_realFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         truncate_real_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "truncate_real")
+    }
 }
@@ -50,12 +50,15 @@ fn typeof_1_simplify(args: Vec<Expr>, _info: &dyn SimplifyInfo) -> Result<ExprSi
 #[derive(Debug)]
 pub(super) struct typeof_1Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl typeof_1Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(&["$1"], false)
+                .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -72,6 +75,10 @@ impl ScalarUDFImpl for typeof_1Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("typeof", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         typeof_1_return_type(arg_types)
     }
@@ -83,4 +90,8 @@ impl ScalarUDFImpl for typeof_1Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         typeof_1_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "typeof_1")
+    }
 }
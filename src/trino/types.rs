@@ -0,0 +1,470 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Trino types and their Arrow representation.
+//!
+//! [`parse_type`] maps Trino type syntax, e.g. `decimal(10,2)`, `timestamp(6) with time zone`,
+//! `array(map(varchar, bigint))` or `row(a bigint, b varchar)`, to the Arrow type the functions
+//! of this crate use for it, and [`type_name`] maps an Arrow type back to the name of the Trino
+//! type. The return types of the functions are those declared in `functions.sdf.yml`, mapped
+//! the same way.
+//!
+//! [`cast`] converts an array to a Trino type with the semantics of Trino's `CAST` and `TRY_CAST`,
+//! which differ from Arrow's: e.g. doubles are rounded rather than truncated to integers, and
+//! values out of range fail rather than turn into NULL.
+//!
+//! ```
+//! use arrow::datatypes::DataType;
+//! use sql_functions::trino::types;
+//!
+//! let dt = types::parse_type("array(decimal(10,2))")?;
+//! assert_eq!(types::type_name(&dt), "array(decimal(10,2))");
+//! # Ok::<(), datafusion::error::DataFusionError>(())
+//! ```
+
+use arrow::array::{Array, ArrayRef, AsArray, BooleanArray, Float64Array, StringArray};
+use arrow::compute::{can_cast_types, cast_with_options, CastOptions};
+use arrow::datatypes::{DataType, Float32Type, Float64Type, TimeUnit};
+use arrow::util::display::{array_value_to_string, FormatOptions};
+use datafusion::common::{
+    exec_datafusion_err, exec_err, internal_err, plan_datafusion_err, plan_err, DataFusionError,
+    Result, ScalarValue,
+};
+use datafusion::logical_expr::{ColumnarValue, ReturnInfo, ReturnTypeArgs};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use crate::catalog::{FunctionCatalog, VariadicMode};
+use crate::type_signature::{display_data_type, FunctionSignature, SdfType};
+use crate::utils::{array_to_columnar, columnar_len};
+
+/// The names of the Trino types without parameters, or whose parameters may be omitted.
+const TYPE_NAMES: &[&str] = &[
+    "boolean",
+    "tinyint",
+    "smallint",
+    "integer",
+    "int",
+    "bigint",
+    "real",
+    "double",
+    "decimal",
+    "varchar",
+    "char",
+    "varbinary",
+    "json",
+    "date",
+    "time",
+    "time with time zone",
+    "timestamp",
+    "timestamp with time zone",
+    "interval day to second",
+    "interval year to month",
+    "uuid",
+    "ipaddress",
+    "geometry",
+    "sphericalgeography",
+    "hyperloglog",
+    "qdigest",
+    "tdigest",
+    "setdigest",
+    "bingtile",
+    "color",
+    "objectid",
+    "joniregexp",
+    "re2jregexp",
+    "jsonpath",
+    "likepattern",
+    "codepoints",
+    "unknown",
+];
+
+/// The Arrow type of the Trino type `text`, e.g. `timestamp(3) with time zone`.
+pub fn parse_type(text: &str) -> Result<DataType> {
+    let sdf_type =
+        SdfType::parse(text).map_err(|_| plan_datafusion_err!("Unknown type: {text}"))?;
+    check_type(&sdf_type, text)?;
+    match sdf_type.default_data_type(&HashMap::new()) {
+        Some(dt) => Ok(dt),
+        None => plan_err!("Unknown type: {text}"),
+    }
+}
+
+/// The name of the Trino type represented by the Arrow type `dt`, e.g. `timestamp(3)`.
+pub fn type_name(dt: &DataType) -> String {
+    display_data_type(dt)
+}
+
+/// Checks that `t` is a Trino type with valid parameters.
+fn check_type(t: &SdfType, text: &str) -> Result<()> {
+    match t {
+        SdfType::Named(name) if TYPE_NAMES.contains(&name.as_str()) => Ok(()),
+        SdfType::Parametric(name, params) => {
+            let numbers = params
+                .iter()
+                .map(|p| p.parse::<u32>())
+                .collect::<std::result::Result<Vec<_>, _>>();
+            match (name.as_str(), numbers.as_deref(), params.as_slice()) {
+                ("decimal", Ok([precision]), _) if (1..=38).contains(precision) => Ok(()),
+                ("decimal", Ok([precision, scale]), _)
+                    if (1..=38).contains(precision) && scale <= precision =>
+                {
+                    Ok(())
+                }
+                ("decimal", Ok(_), _) => {
+                    plan_err!("Invalid decimal precision or scale in type {text}")
+                }
+                ("varchar" | "char", Ok([length]), _) if *length > 0 => Ok(()),
+                (
+                    "time" | "time with time zone" | "timestamp" | "timestamp with time zone",
+                    Ok([precision]),
+                    _,
+                ) => match precision {
+                    0..=9 => Ok(()),
+                    10..=12 => plan_err!("Precision {precision} of type {text} is not supported"),
+                    _ => plan_err!("Invalid precision {precision} in type {text}"),
+                },
+                ("qdigest", _, [value_type])
+                    if ["bigint", "double", "real"].contains(&value_type.as_str()) =>
+                {
+                    Ok(())
+                }
+                _ => plan_err!("Unknown type: {text}"),
+            }
+        }
+        SdfType::Array(elem) => check_type(elem, text),
+        SdfType::Map(key, value) => {
+            check_type(key, text)?;
+            check_type(value, text)
+        }
+        SdfType::Row(fields) => fields.iter().try_for_each(|(_, t)| check_type(t, text)),
+        SdfType::Named(_) | SdfType::Variable(_) | SdfType::Function(_) => {
+            plan_err!("Unknown type: {text}")
+        }
+    }
+}
+
+/// The declared parameter and return types of an overload.
+struct Declaration {
+    signature: FunctionSignature,
+    returns: SdfType,
+}
+
+fn declarations() -> &'static HashMap<String, Declaration> {
+    static DECLARATIONS: OnceLock<HashMap<String, Declaration>> = OnceLock::new();
+    DECLARATIONS.get_or_init(|| {
+        // The definitions are embedded, so this only fails if they are invalid: the return
+        // types are then reported as undeclared
+        let Ok(catalog) = FunctionCatalog::load_dialect("trino") else {
+            return HashMap::new();
+        };
+        catalog
+            .functions()
+            .iter()
+            .filter_map(|function| {
+                let params = function
+                    .parameters
+                    .iter()
+                    .map(|p| p.datatype.as_str())
+                    .collect::<Vec<_>>();
+                let variadic = matches!(function.variadic, Some(VariadicMode::Uniform));
+                let signature = FunctionSignature::new(&params, variadic).ok()?;
+                let returns = SdfType::parse(function.return_type.as_deref()?).ok()?;
+                Some((
+                    function.overload.clone(),
+                    Declaration { signature, returns },
+                ))
+            })
+            .collect()
+    })
+}
+
+/// The Arrow type of the return type declared in `functions.sdf.yml` for the overload `overload`
+/// (e.g. `date_diff_varchar_date_date`) called with arguments of types `arg_types`: type
+/// variables (`$1`) and precisions (`timestamp(p)`) are bound by the arguments, and a returned
+/// `timestamp(p) with time zone` is in the zone of the first `timestamp(p) with time zone`
+/// argument.
+pub(crate) fn declared_return_type(overload: &str, arg_types: &[DataType]) -> Result<DataType> {
+    let Some(declaration) = declarations().get(overload) else {
+        return internal_err!("No declared return type for {overload}");
+    };
+    let Some(resolution) = declaration.signature.resolve(arg_types) else {
+        return internal_err!("Invalid arguments for {overload}: {arg_types:?}");
+    };
+    let mut precisions = HashMap::new();
+    for (param, arg) in declaration
+        .signature
+        .parameters
+        .iter()
+        .cycle()
+        .zip(&resolution.coerced_types)
+    {
+        bind_precisions(param, arg, &mut precisions);
+    }
+    let returns = substitute_precisions(&declaration.returns, &precisions);
+    match returns.default_data_type(&resolution.bindings) {
+        Some(DataType::Timestamp(unit, Some(default))) if is_zoned(&declaration.returns) => {
+            let zone = declaration
+                .signature
+                .parameters
+                .iter()
+                .cycle()
+                .zip(&resolution.coerced_types)
+                .find_map(|(param, arg)| match arg {
+                    DataType::Timestamp(_, Some(zone)) if is_zoned(param) => Some(zone.clone()),
+                    _ => None,
+                });
+            Ok(DataType::Timestamp(unit, Some(zone.unwrap_or(default))))
+        }
+        Some(dt) => Ok(dt),
+        None => internal_err!("Unbound return type {} of {overload}", declaration.returns),
+    }
+}
+
+/// Whether `t` is a `timestamp(p) with time zone`.
+fn is_zoned(t: &SdfType) -> bool {
+    matches!(t, SdfType::Parametric(name, _) if name == "timestamp with time zone")
+}
+
+/// Binds the precision and scale parameters (e.g. `p` of `timestamp(p)`) of `param` to those
+/// of the argument type `arg`.
+fn bind_precisions(param: &SdfType, arg: &DataType, precisions: &mut HashMap<String, String>) {
+    let SdfType::Parametric(_, params) = param else {
+        return;
+    };
+    let Ok(SdfType::Parametric(_, values)) = SdfType::parse(&type_name(arg)) else {
+        return;
+    };
+    for (name, value) in params.iter().zip(values) {
+        if name.parse::<u32>().is_err() {
+            precisions.entry(name.clone()).or_insert(value);
+        }
+    }
+}
+
+fn substitute_precisions(t: &SdfType, precisions: &HashMap<String, String>) -> SdfType {
+    match t {
+        SdfType::Parametric(name, params) => SdfType::Parametric(
+            name.clone(),
+            params
+                .iter()
+                .map(|p| precisions.get(p).cloned().unwrap_or_else(|| p.clone()))
+                .collect(),
+        ),
+        SdfType::Array(elem) => SdfType::Array(Box::new(substitute_precisions(elem, precisions))),
+        SdfType::Map(key, value) => SdfType::Map(
+            Box::new(substitute_precisions(key, precisions)),
+            Box::new(substitute_precisions(value, precisions)),
+        ),
+        SdfType::Row(fields) => SdfType::Row(
+            fields
+                .iter()
+                .map(|(name, t)| (name.clone(), substitute_precisions(t, precisions)))
+                .collect(),
+        ),
+        _ => t.clone(),
+    }
+}
+
+/// Casts `array` to the Trino type `to`, e.g. `varchar(3)`, as Trino's `CAST` does: values that
+/// cannot be cast fail, or turn into NULL if `try_cast`, as Trino's `TRY_CAST` does.
+pub fn cast(array: &ArrayRef, to: &str, try_cast: bool) -> Result<ArrayRef> {
+    let to_type = parse_type(to)?;
+    let from_type = array.data_type();
+    if !can_cast_types(from_type, &to_type) {
+        return plan_err!("Cannot cast {} to {to}", type_name(from_type));
+    }
+    let result = match (from_type, &to_type) {
+        (DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View, DataType::Boolean) => {
+            let strings = cast_with_options(array, &DataType::Utf8, &CastOptions::default())?;
+            let strings = strings.as_string::<i32>();
+            let mut booleans = Vec::with_capacity(strings.len());
+            for value in strings {
+                booleans.push(match value.map(|v| (v, parse_boolean(v))) {
+                    Some((v, None)) if !try_cast => {
+                        return Err(exec_datafusion_err!("Cannot cast '{v}' to BOOLEAN"))
+                    }
+                    value => value.and_then(|(_, b)| b),
+                });
+            }
+            Arc::new(BooleanArray::from(booleans))
+        }
+        // Rounded half away from zero, which Arrow's cast truncates
+        (DataType::Float32 | DataType::Float64, to_type) if to_type.is_integer() => {
+            let doubles = cast_with_options(array, &DataType::Float64, &CastOptions::default())?;
+            let rounded: Float64Array = doubles.as_primitive::<Float64Type>().unary(f64::round);
+            checked_cast(array, &(Arc::new(rounded) as ArrayRef), to, try_cast)?
+        }
+        (DataType::Decimal128(_, scale), to_type) if to_type.is_integer() && *scale > 0 => {
+            let rounded =
+                cast_with_options(array, &DataType::Decimal128(38, 0), &CastOptions::default())?;
+            checked_cast(array, &rounded, to, try_cast)?
+        }
+        (DataType::Float32 | DataType::Float64, DataType::Utf8) => {
+            let strings = (0..array.len())
+                .map(|i| {
+                    array
+                        .is_valid(i)
+                        .then(|| display_value(array, i))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(StringArray::from(strings))
+        }
+        (DataType::Timestamp(unit, tz), DataType::Utf8) => {
+            let format = match unit {
+                TimeUnit::Second => "%Y-%m-%d %H:%M:%S",
+                TimeUnit::Millisecond => "%Y-%m-%d %H:%M:%S%.3f",
+                TimeUnit::Microsecond => "%Y-%m-%d %H:%M:%S%.6f",
+                TimeUnit::Nanosecond => "%Y-%m-%d %H:%M:%S%.9f",
+            };
+            let options = CastOptions {
+                safe: false,
+                format_options: FormatOptions::new()
+                    .with_timestamp_format(Some(format))
+                    .with_timestamp_tz_format(Some(format)),
+            };
+            let strings = cast_with_options(array, &DataType::Utf8, &options)?;
+            match tz.as_deref() {
+                None => strings,
+                Some(tz) => {
+                    let zone = match tz {
+                        "+00:00" | "Z" => "UTC",
+                        tz => tz,
+                    };
+                    let strings = strings
+                        .as_string::<i32>()
+                        .iter()
+                        .map(|v| v.map(|v| format!("{v} {zone}")))
+                        .collect::<StringArray>();
+                    Arc::new(strings)
+                }
+            }
+        }
+        _ => checked_cast(array, array, to, try_cast)?,
+    };
+    // Only the length of a varchar(n) or char(n) is not represented by the Arrow type
+    match SdfType::parse(to)? {
+        SdfType::Parametric(name, params) if name == "varchar" || name == "char" => {
+            let length = params[0]
+                .parse::<usize>()
+                .map_err(|_| plan_datafusion_err!("Unknown type: {to}"))?;
+            let strings = result
+                .as_string::<i32>()
+                .iter()
+                .map(|v| {
+                    v.map(|v| {
+                        let mut v = v.chars().take(length).collect::<String>();
+                        if name == "char" {
+                            let padding = length - v.chars().count();
+                            v.extend(std::iter::repeat_n(' ', padding));
+                        }
+                        v
+                    })
+                })
+                .collect::<StringArray>();
+            Ok(Arc::new(strings))
+        }
+        _ => Ok(result),
+    }
+}
+
+/// The return type of the `cast` and `try_cast` functions, whose second argument is the name
+/// of the type to cast to, as a constant.
+pub(crate) fn cast_return_type(args: ReturnTypeArgs) -> Result<ReturnInfo> {
+    match args.scalar_arguments.get(1) {
+        Some(Some(ScalarValue::Utf8(Some(to)))) => Ok(ReturnInfo::new_nullable(parse_type(to)?)),
+        _ => plan_err!("The type to cast to must be a constant varchar"),
+    }
+}
+
+/// Invokes the `cast` or `try_cast` function.
+pub(crate) fn invoke_cast(args: &[ColumnarValue], try_cast: bool) -> Result<ColumnarValue> {
+    let ColumnarValue::Scalar(ScalarValue::Utf8(Some(to))) = &args[1] else {
+        return exec_err!("The type to cast to must be a constant varchar");
+    };
+    let array = args[0].to_array(columnar_len(&[&args[0]]))?;
+    array_to_columnar(cast(&array, to, try_cast)?, &[&args[0]])
+}
+
+/// Casts `values`, converted from `source`, with Arrow's cast, failing on the values it turns into
+/// NULL unless `try_cast`.
+fn checked_cast(
+    source: &ArrayRef,
+    values: &ArrayRef,
+    to: &str,
+    try_cast: bool,
+) -> Result<ArrayRef> {
+    let to_type = parse_type(to)?;
+    let options = CastOptions {
+        safe: true,
+        ..Default::default()
+    };
+    let result = cast_with_options(values, &to_type, &options)?;
+    if try_cast {
+        return Ok(result);
+    }
+    let nulls = values.logical_nulls();
+    let is_valid = |i| nulls.as_ref().is_none_or(|nulls| nulls.is_valid(i));
+    match (0..result.len()).find(|&i| result.is_null(i) && is_valid(i)) {
+        Some(i) => Err(cast_error(source, i, to)),
+        None => Ok(result),
+    }
+}
+
+fn cast_error(source: &ArrayRef, i: usize, to: &str) -> DataFusionError {
+    let value = match display_value(source, i) {
+        Ok(value) => value,
+        Err(e) => return e,
+    };
+    match source.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+            exec_datafusion_err!("Cannot cast '{value}' to {}", to.to_uppercase())
+        }
+        dt if dt.is_numeric() => exec_datafusion_err!("Out of range for {to}: {value}"),
+        dt => exec_datafusion_err!("Cannot cast {} '{value}' to {to}", type_name(dt)),
+    }
+}
+
+/// The value of row `i` of `array` as Trino displays it: doubles and reals are in Java's
+/// scientific notation, e.g. `1.5E0`.
+fn display_value(array: &ArrayRef, i: usize) -> Result<String> {
+    let text = match array.data_type() {
+        DataType::Float64 => format!("{:E}", array.as_primitive::<Float64Type>().value(i)),
+        DataType::Float32 => format!("{:E}", array.as_primitive::<Float32Type>().value(i)),
+        _ => return Ok(array_value_to_string(array, i)?),
+    };
+    Ok(match text.as_str() {
+        "inf" => "Infinity".to_string(),
+        "-inf" => "-Infinity".to_string(),
+        _ => match text.split_once('E') {
+            Some((mantissa, exponent)) if !mantissa.contains('.') => {
+                format!("{mantissa}.0E{exponent}")
+            }
+            _ => text,
+        },
+    })
+}
+
+/// Trino's boolean literals, as cast from varchar.
+fn parse_boolean(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "t" | "1" => Some(true),
+        "false" | "f" | "0" => Some(false),
+        _ => None,
+    }
+}
@@ -79,9 +79,9 @@ pub(super) struct value_at_quantile_qdigest_doubleFunc {
 }
 
 impl value_at_quantile_qdigest_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float64], volatility),
         }
     }
 }
@@ -109,6 +109,13 @@ impl ScalarUDFImpl for value_at_quantile_qdigest_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         value_at_quantile_qdigest_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "value_at_quantile_qdigest_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -117,9 +124,9 @@ pub(super) struct value_at_quantile_tdigest_doubleFunc {
 }
 
 impl value_at_quantile_tdigest_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::exact(vec![DataType::Binary, DataType::Float64], volatility),
         }
     }
 }
@@ -147,4 +154,11 @@ impl ScalarUDFImpl for value_at_quantile_tdigest_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         value_at_quantile_tdigest_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "value_at_quantile_tdigest_double",
+        )
+    }
 }
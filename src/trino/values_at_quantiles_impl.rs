@@ -84,12 +84,18 @@ fn values_at_quantiles_tdigest_array_double_simplify(
 #[derive(Debug)]
 pub(super) struct values_at_quantiles_qdigest_array_doubleFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl values_at_quantiles_qdigest_array_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["qdigest", "array<double>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -106,6 +112,10 @@ impl ScalarUDFImpl for values_at_quantiles_qdigest_array_doubleFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("values_at_quantiles", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         values_at_quantiles_qdigest_array_double_return_type(arg_types)
     }
@@ -117,17 +127,30 @@ impl ScalarUDFImpl for values_at_quantiles_qdigest_array_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         values_at_quantiles_qdigest_array_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "values_at_quantiles_qdigest_array_double",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct values_at_quantiles_tdigest_array_doubleFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl values_at_quantiles_tdigest_array_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["tdigest", "array<double>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -144,6 +167,10 @@ impl ScalarUDFImpl for values_at_quantiles_tdigest_array_doubleFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("values_at_quantiles", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         values_at_quantiles_tdigest_array_double_return_type(arg_types)
     }
@@ -155,4 +182,11 @@ impl ScalarUDFImpl for values_at_quantiles_tdigest_array_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         values_at_quantiles_tdigest_array_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "values_at_quantiles_tdigest_array_double",
+        )
+    }
 }
@@ -80,12 +80,18 @@ fn width_bucket_double_double_double_bigint_simplify(
 #[derive(Debug)]
 pub(super) struct width_bucket_double_array_doubleFunc {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl width_bucket_double_array_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["double", "array<double>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -102,6 +108,10 @@ impl ScalarUDFImpl for width_bucket_double_array_doubleFunc {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("width_bucket", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         width_bucket_double_array_double_return_type(arg_types)
     }
@@ -113,6 +123,13 @@ impl ScalarUDFImpl for width_bucket_double_array_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         width_bucket_double_array_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "width_bucket_double_array_double",
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -121,9 +138,17 @@ pub(super) struct width_bucket_double_double_double_bigintFunc {
 }
 
 impl width_bucket_double_double_double_bigintFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Float64,
+                    DataType::Int64,
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -151,4 +176,11 @@ impl ScalarUDFImpl for width_bucket_double_double_double_bigintFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         width_bucket_double_double_double_bigint_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "width_bucket_double_double_double_bigint",
+        )
+    }
 }
@@ -60,9 +60,12 @@ pub(super) struct wilson_interval_lower_bigint_bigint_doubleFunc {
 }
 
 impl wilson_interval_lower_bigint_bigint_doubleFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Float64],
+                volatility,
+            ),
         }
     }
 }
@@ -90,4 +93,11 @@ impl ScalarUDFImpl for wilson_interval_lower_bigint_bigint_doubleFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         wilson_interval_lower_bigint_bigint_double_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "wilson_interval_lower_bigint_bigint_double",
+        )
+    }
 }
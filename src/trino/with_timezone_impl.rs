@@ -17,25 +17,36 @@
 
 #![allow(non_camel_case_types)]
 use arrow::datatypes::DataType;
-use datafusion::common::Result;
-use datafusion::error::DataFusionError;
+use datafusion::common::{internal_err, Result};
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
-use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnInfo, ReturnTypeArgs, ScalarUDFImpl, Signature, Volatility,
+};
 use std::any::Any;
 
-fn with_timezone_timestamp_p_varchar_invoke(_args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+use crate::utils_datetime::{map_timestamp_zone, result_zone};
+
+fn with_timezone_timestamp_p_varchar_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    map_timestamp_zone(&args[0], &args[1], false)
+}
+
+fn with_timezone_timestamp_p_varchar_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    super::types::declared_return_type("with_timezone_timestamp_p_varchar", arg_types)
 }
 
-fn with_timezone_timestamp_p_varchar_return_type(_arg_types: &[DataType]) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
+fn with_timezone_timestamp_p_varchar_return_type_from_args(
+    args: ReturnTypeArgs,
+) -> Result<ReturnInfo> {
+    // In the time zone of a constant zone
+    let DataType::Timestamp(unit, _) =
+        with_timezone_timestamp_p_varchar_return_type(args.arg_types)?
+    else {
+        return internal_err!("with_timezone must return a timestamp with time zone");
+    };
+    let zone = result_zone(args.scalar_arguments.get(1).copied().flatten());
+    Ok(ReturnInfo::new_nullable(DataType::Timestamp(
+        unit,
+        Some(zone),
     )))
 }
 
@@ -56,9 +67,29 @@ pub(super) struct with_timezone_timestamp_p_varcharFunc {
 }
 
 impl with_timezone_timestamp_p_varcharFunc {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Second, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Millisecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None),
+                        DataType::Utf8,
+                    ]),
+                    datafusion::logical_expr::TypeSignature::Exact(vec![
+                        DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None),
+                        DataType::Utf8,
+                    ]),
+                ],
+                volatility,
+            ),
         }
     }
 }
@@ -79,6 +110,13 @@ impl ScalarUDFImpl for with_timezone_timestamp_p_varcharFunc {
         with_timezone_timestamp_p_varchar_return_type(arg_types)
     }
 
+    fn return_type_from_args(
+        &self,
+        args: datafusion::logical_expr::ReturnTypeArgs,
+    ) -> Result<datafusion::logical_expr::ReturnInfo> {
+        with_timezone_timestamp_p_varchar_return_type_from_args(args)
+    }
+
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
         with_timezone_timestamp_p_varchar_invoke(args)
     }
@@ -86,4 +124,11 @@ impl ScalarUDFImpl for with_timezone_timestamp_p_varcharFunc {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         with_timezone_timestamp_p_varchar_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "with_timezone_timestamp_p_varchar",
+        )
+    }
 }
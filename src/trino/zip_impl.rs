@@ -130,12 +130,18 @@ fn zip_array_14_array_15_array_16_array_17_array_18_simplify(
 #[derive(Debug)]
 pub(super) struct zip_array_14_array_15Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl zip_array_14_array_15Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$14>", "array<$15>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -152,6 +158,10 @@ impl ScalarUDFImpl for zip_array_14_array_15Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("zip", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         zip_array_14_array_15_return_type(arg_types)
     }
@@ -163,17 +173,27 @@ impl ScalarUDFImpl for zip_array_14_array_15Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         zip_array_14_array_15_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "zip_array_14_array_15")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct zip_array_14_array_15_array_16Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl zip_array_14_array_15_array_16Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$14>", "array<$15>", "array<$16>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -190,6 +210,10 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("zip", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         zip_array_14_array_15_array_16_return_type(arg_types)
     }
@@ -201,17 +225,27 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         zip_array_14_array_15_array_16_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(crate::Dialect::Trino, "zip_array_14_array_15_array_16")
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct zip_array_14_array_15_array_16_array_17Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl zip_array_14_array_15_array_16_array_17Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(4, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$14>", "array<$15>", "array<$16>", "array<$17>"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -228,6 +262,10 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16_array_17Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("zip", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         zip_array_14_array_15_array_16_array_17_return_type(arg_types)
     }
@@ -239,17 +277,36 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16_array_17Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         zip_array_14_array_15_array_16_array_17_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "zip_array_14_array_15_array_16_array_17",
+        )
+    }
 }
 
 #[derive(Debug)]
 pub(super) struct zip_array_14_array_15_array_16_array_17_array_18Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl zip_array_14_array_15_array_16_array_17_array_18Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(5, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &[
+                    "array<$14>",
+                    "array<$15>",
+                    "array<$16>",
+                    "array<$17>",
+                    "array<$18>",
+                ],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -266,6 +323,10 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16_array_17_array_18Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("zip", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         zip_array_14_array_15_array_16_array_17_array_18_return_type(arg_types)
     }
@@ -277,4 +338,11 @@ impl ScalarUDFImpl for zip_array_14_array_15_array_16_array_17_array_18Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         zip_array_14_array_15_array_16_array_17_array_18_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "zip_array_14_array_15_array_16_array_17_array_18",
+        )
+    }
 }
@@ -16,9 +16,13 @@
 // under the License.
 
 #![allow(non_camel_case_types)]
+use crate::lambda::{not_simplified, simplify_higher_order, BoundLambda};
+use crate::utils_lambda::ListElements;
+use arrow::array::{new_empty_array, ArrayRef, UInt32Array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::take;
 use arrow::datatypes::DataType;
 use datafusion::common::Result;
-use datafusion::error::DataFusionError;
 use datafusion::logical_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion::logical_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
@@ -26,28 +30,66 @@ use std::any::Any;
 fn zip_with_array_1_array_11_function_1_11_9_invoke(
     _args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    not_simplified("zip_with")
 }
 
 fn zip_with_array_1_array_11_function_1_11_9_return_type(
-    _arg_types: &[DataType],
+    arg_types: &[DataType],
 ) -> Result<DataType> {
-    Err(DataFusionError::NotImplemented(format!(
-        "Not implemented {}:{}",
-        file!(),
-        line!()
-    )))
+    super::types::declared_return_type("zip_with_array_1_array_11_function_1_11_9", arg_types)
 }
 
 fn zip_with_array_1_array_11_function_1_11_9_simplify(
     args: Vec<Expr>,
-    _info: &dyn SimplifyInfo,
+    info: &dyn SimplifyInfo,
 ) -> Result<ExprSimplifyResult> {
-    Ok(ExprSimplifyResult::Original(args))
+    simplify_higher_order(
+        "zip_with",
+        args,
+        info,
+        1,
+        zip_with_array_1_array_11_function_1_11_9_return_type,
+        zip_with,
+    )
+}
+
+/// The arrays of the results of the lambda expression over the pairs of elements at the same
+/// positions, the shorter array being padded with NULLs. NULL if either array is NULL.
+fn zip_with(args: &[ArrayRef], lambdas: &[BoundLambda]) -> Result<ArrayRef> {
+    let left = ListElements::new(&args[0])?;
+    let right = ListElements::new(&args[1])?;
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    let mut rows = vec![];
+    let mut lengths = vec![];
+    let mut valid = vec![];
+    let pairs = left.lengths().zip(right.lengths()).enumerate();
+    for (row, (left_length, right_length)) in pairs {
+        if left.is_null(row) || right.is_null(row) {
+            lengths.push(0);
+            valid.push(false);
+            continue;
+        }
+        let length = left_length.max(right_length);
+        let (left_start, right_start) = (left.offsets[row] as u32, right.offsets[row] as u32);
+        for position in 0..length {
+            left_indices.push((position < left_length).then_some(left_start + position as u32));
+            right_indices.push((position < right_length).then_some(right_start + position as u32));
+            rows.push(row as u32);
+        }
+        lengths.push(length);
+        valid.push(true);
+    }
+    let pairs = ListElements {
+        values: new_empty_array(&DataType::Null),
+        rows: UInt32Array::from(rows),
+        offsets: OffsetBuffer::from_lengths(lengths),
+        nulls: Some(NullBuffer::from(valid)),
+    };
+    let left = take(&left.values, &UInt32Array::from(left_indices), None)?;
+    let right = take(&right.values, &UInt32Array::from(right_indices), None)?;
+    let values = lambdas[0].call(&[left, right], &pairs.rows)?;
+    pairs.to_list(values)
 }
 
 // ========== Generated template below this line ==========
@@ -57,12 +99,18 @@ fn zip_with_array_1_array_11_function_1_11_9_simplify(
 #[derive(Debug)]
 pub(super) struct zip_with_array_1_array_11_function_1_11_9Func {
     signature: Signature,
+    parameters: crate::type_signature::FunctionSignature,
 }
 
 impl zip_with_array_1_array_11_function_1_11_9Func {
-    pub fn new() -> Self {
+    pub fn new(volatility: Volatility) -> Self {
         Self {
-            signature: Signature::any(3, Volatility::Immutable),
+            signature: Signature::user_defined(volatility),
+            parameters: crate::type_signature::FunctionSignature::new(
+                &["array<$1>", "array<$11>", "function($1, $11, $9)"],
+                false,
+            )
+            .expect("the parameters are checked when generated"),
         }
     }
 }
@@ -79,6 +127,10 @@ impl ScalarUDFImpl for zip_with_array_1_array_11_function_1_11_9Func {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        crate::type_signature::coerce_arguments("zip_with", &self.parameters, arg_types)
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         zip_with_array_1_array_11_function_1_11_9_return_type(arg_types)
     }
@@ -90,4 +142,11 @@ impl ScalarUDFImpl for zip_with_array_1_array_11_function_1_11_9Func {
     fn simplify(&self, args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<ExprSimplifyResult> {
         zip_with_array_1_array_11_function_1_11_9_simplify(args, info)
     }
+
+    fn documentation(&self) -> Option<&datafusion::logical_expr::Documentation> {
+        crate::documentation::documentation(
+            crate::Dialect::Trino,
+            "zip_with_array_1_array_11_function_1_11_9",
+        )
+    }
 }
@@ -0,0 +1,1120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Type signatures of dialect functions, as written in the `functions.sdf.yml` assets
+//! (e.g. `bigint`, `decimal(p, s)`, `array<$1>`, `map<$4, $5>`, `function($1, boolean)`),
+//! and their correspondence to Arrow data types.
+//! The matching of Arrow argument types against a signature follows Trino's overload resolution:
+//! - Type variables (`$1`, `$3`, ...) are bound to the common super type of all arguments they match.
+//! - An argument either belongs to the family of Arrow types representing a SQL type
+//!   (e.g. Utf8, LargeUtf8 and Utf8View all represent `varchar`), or is implicitly coerced to it
+//!   following Trino's coercion lattice (e.g. `tinyint` -> `smallint` -> `integer` -> `bigint` -> `real` -> `double`).
+//! - Each implicit coercion has a cost; among the applicable overloads the cheapest one wins,
+//!   with ties going to the most specific one (see [`crate::overload::resolve_overload`]).
+
+use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+use datafusion::common::{plan_err, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::extension;
+
+/// A parsed SDF/Trino type expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SdfType {
+    /// A type variable such as `$1`, bound to a concrete type during overload resolution.
+    Variable(String),
+    /// A non-parametric type, e.g. `bigint`, `varchar`, `geometry`, `interval day to second`.
+    Named(String),
+    /// A type with precision/scale parameters, e.g. `decimal(p, s)`, `timestamp(3)`, `varchar(10)`.
+    Parametric(String, Vec<String>),
+    /// `array<T>`
+    Array(Box<SdfType>),
+    /// `map<K, V>`
+    Map(Box<SdfType>, Box<SdfType>),
+    /// `row(name1 T1, name2 T2, ...)`; fields may be anonymous.
+    Row(Vec<(Option<String>, SdfType)>),
+    /// `function(A1, ..., An, R)`: a lambda taking A1..An and returning R. A lambda argument is
+    /// typed by its body (see [`crate::lambda`]), which is matched against R.
+    Function(Vec<SdfType>),
+}
+
+/// Bindings of type variables to Arrow types, established while matching arguments.
+pub type Bindings = HashMap<String, DataType>;
+
+/// Names of the SDF "distinct" types: represented as a FixedSizeList of size 1 wrapping the
+/// underlying value, so that they can be told apart from their underlying type.
+const DISTINCT_TYPES: &[&str] = &[
+    "joniregexp",
+    "re2jregexp",
+    "jsonpath",
+    "likepattern",
+    "codepoints",
+    "color",
+];
+
+/// Names of the types without an Arrow type nor an extension type, as opaque binaries.
+const OPAQUE_TYPES: &[&str] = &["kdbtree", "classifier", "regressor"];
+
+impl SdfType {
+    /// Parse a type expression such as `array<map<varchar, $2>>` or `timestamp(p) with time zone`.
+    /// Both the SDF (`array<T>`) and the Trino (`array(T)`) spellings of containers are accepted.
+    pub fn parse(text: &str) -> Result<SdfType> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+        };
+        let res = parser.parse_type()?;
+        if parser.pos != tokens.len() {
+            return plan_err!("Unexpected trailing input in type '{text}'");
+        }
+        Ok(res)
+    }
+
+    /// Whether the type mentions a type variable anywhere.
+    pub fn has_variables(&self) -> bool {
+        match self {
+            SdfType::Variable(_) => true,
+            SdfType::Named(_) | SdfType::Parametric(_, _) => false,
+            SdfType::Array(elem) => elem.has_variables(),
+            SdfType::Map(key, value) => key.has_variables() || value.has_variables(),
+            SdfType::Row(fields) => fields.iter().any(|(_, t)| t.has_variables()),
+            SdfType::Function(ts) => ts.iter().any(|t| t.has_variables()),
+        }
+    }
+
+    /// The names of the types without an Arrow type this type is made of, e.g. `hllsketch`.
+    pub fn unknown_names(&self) -> Vec<&str> {
+        match self {
+            SdfType::Variable(_) => vec![],
+            SdfType::Named(name) => match known_data_type(name) {
+                Some(_) => vec![],
+                None => vec![name],
+            },
+            SdfType::Parametric(name, params) => match parametric_data_type(name, params) {
+                Some(_) => vec![],
+                None => vec![name],
+            },
+            SdfType::Array(elem) => elem.unknown_names(),
+            SdfType::Map(key, value) => [key.unknown_names(), value.unknown_names()].concat(),
+            SdfType::Row(fields) => fields.iter().flat_map(|(_, t)| t.unknown_names()).collect(),
+            SdfType::Function(ts) => ts.iter().flat_map(SdfType::unknown_names).collect(),
+        }
+    }
+
+    /// The Arrow type of the arguments of a parameter of this type if they have a single one, so
+    /// that DataFusion can check them with an exact signature rather than [`coerce_arguments`].
+    /// The decimals, the times and timestamps of a variable precision or with a time zone, the
+    /// intervals of days to seconds, the distinct types and `unknown` accept several types.
+    pub fn exact_data_type(&self) -> Option<DataType> {
+        match self {
+            SdfType::Named(name) => match name.as_str() {
+                "decimal"
+                | "time"
+                | "timestamp"
+                | "timestamp with time zone"
+                | "interval day to second"
+                | "unknown" => None,
+                name if DISTINCT_TYPES.contains(&name) => None,
+                name => known_data_type(name),
+            },
+            SdfType::Parametric(name, params)
+                if matches!(name.as_str(), "time" | "timestamp")
+                    && params.iter().all(|p| p.parse::<u32>().is_ok()) =>
+            {
+                parametric_data_type(name, params)
+            }
+            _ => None,
+        }
+    }
+
+    /// The Arrow type used for a value of this SQL type when nothing else is known about it,
+    /// e.g. for a NULL argument or as the target of an implicit coercion.
+    /// Returns `None` for unbound type variables.
+    pub fn default_data_type(&self, bindings: &Bindings) -> Option<DataType> {
+        match self {
+            SdfType::Variable(v) => bindings.get(v).cloned(),
+            SdfType::Named(name) => known_data_type(name),
+            SdfType::Parametric(name, params) => parametric_data_type(name, params),
+            SdfType::Array(elem) => Some(list_of(elem.default_data_type(bindings)?)),
+            SdfType::Map(key, value) => Some(map_of(
+                key.default_data_type(bindings)?,
+                value.default_data_type(bindings)?,
+            )),
+            SdfType::Row(fields) => {
+                let fields = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, t))| {
+                        let name = name.clone().unwrap_or_else(|| format!("c{i}"));
+                        t.default_data_type(bindings)
+                            .map(|dt| Field::new(name, dt, true))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(DataType::Struct(fields.into()))
+            }
+            SdfType::Function(ts) => Some(lambda_of(ts.last()?.default_data_type(bindings)?)),
+        }
+    }
+
+    /// Binds the type variables of this type against the argument type `arg`,
+    /// widening existing bindings to a common super type where needed.
+    /// Returns false if the argument's shape is incompatible with this type.
+    fn bind(&self, arg: &DataType, bindings: &mut Bindings) -> bool {
+        match (self, arg) {
+            (_, DataType::Dictionary(_, value)) => self.bind(value, bindings),
+            (SdfType::Variable(v), _) => {
+                let bound = match bindings.get(v) {
+                    None => Some(arg.clone()),
+                    Some(prev) => common_super_type(prev, arg),
+                };
+                match bound {
+                    Some(bound) => {
+                        bindings.insert(v.clone(), bound);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            (_, DataType::Null) => true,
+            // A lambda only binds the variables of its result that no other argument bound:
+            // `reduce(array<$1>, $10, function($10, $1, $10), ...)` takes the type of its state
+            // from the initial state, to which the result of the lambda must then be coerced.
+            (SdfType::Function(ts), _) => match (ts.last(), lambda_result(arg)) {
+                (Some(SdfType::Variable(v)), Some(_)) if bindings.contains_key(v) => true,
+                (Some(result), Some(arg)) => result.bind(arg, bindings),
+                _ => false,
+            },
+            (SdfType::Array(elem), DataType::List(f))
+            | (SdfType::Array(elem), DataType::LargeList(f))
+            | (SdfType::Array(elem), DataType::FixedSizeList(f, _)) => {
+                elem.bind(f.data_type(), bindings)
+            }
+            (SdfType::Map(key, value), DataType::Map(entries, _)) => match entries.data_type() {
+                DataType::Struct(fields) if fields.len() == 2 => {
+                    key.bind(fields[0].data_type(), bindings)
+                        && value.bind(fields[1].data_type(), bindings)
+                }
+                _ => false,
+            },
+            (SdfType::Row(fields), DataType::Struct(arg_fields)) => {
+                fields.len() == arg_fields.len()
+                    && fields
+                        .iter()
+                        .zip(arg_fields.iter())
+                        .all(|((_, t), f)| t.bind(f.data_type(), bindings))
+            }
+            (SdfType::Array(_), _) | (SdfType::Map(_, _), _) | (SdfType::Row(_), _) => false,
+            (SdfType::Named(_), _) | (SdfType::Parametric(_, _), _) => true,
+        }
+    }
+
+    /// Determines the Arrow type an argument of type `arg` has to be coerced to in order to match
+    /// this type, together with the cost of the coercion (0 when no coercion is needed).
+    /// Returns `None` if `arg` cannot be implicitly coerced to this type.
+    pub fn coerce(&self, arg: &DataType, bindings: &Bindings) -> Option<(DataType, u32)> {
+        if let DataType::Null = arg {
+            // NULL (Trino `unknown`) coerces to everything
+            let target = self.default_data_type(bindings)?;
+            let cost = if target == DataType::Null { 0 } else { 1 };
+            return Some((target, cost));
+        }
+        if let DataType::Dictionary(_, value) = arg {
+            let (target, cost) = self.coerce(value, bindings)?;
+            return Some((target, cost + 1));
+        }
+        match self {
+            SdfType::Variable(v) => {
+                let bound = bindings.get(v)?;
+                coerce_data_type(arg, bound).map(|cost| (bound.clone(), cost))
+            }
+            SdfType::Named(name) => coerce_to_named(arg, name),
+            SdfType::Parametric(name, params) => coerce_to_parametric(arg, name, params),
+            SdfType::Array(elem) => {
+                let (field, large) = match arg {
+                    DataType::List(f) | DataType::FixedSizeList(f, _) => (f, false),
+                    DataType::LargeList(f) => (f, true),
+                    _ => return None,
+                };
+                let (elem_type, cost) = elem.coerce(field.data_type(), bindings)?;
+                let cost = cost + u32::from(matches!(arg, DataType::FixedSizeList(_, _)));
+                let field = Arc::new(Field::new(field.name(), elem_type, true));
+                if large {
+                    Some((DataType::LargeList(field), cost))
+                } else {
+                    Some((DataType::List(field), cost))
+                }
+            }
+            SdfType::Map(key, value) => {
+                let (entries, sorted) = match arg {
+                    DataType::Map(entries, sorted) => (entries, *sorted),
+                    _ => return None,
+                };
+                let fields = match entries.data_type() {
+                    DataType::Struct(fields) if fields.len() == 2 => fields,
+                    _ => return None,
+                };
+                let (key_type, key_cost) = key.coerce(fields[0].data_type(), bindings)?;
+                let (value_type, value_cost) = value.coerce(fields[1].data_type(), bindings)?;
+                let entries = Field::new(
+                    entries.name(),
+                    DataType::Struct(
+                        vec![
+                            Field::new(fields[0].name(), key_type, false),
+                            Field::new(fields[1].name(), value_type, true),
+                        ]
+                        .into(),
+                    ),
+                    false,
+                );
+                Some((
+                    DataType::Map(Arc::new(entries), sorted),
+                    key_cost + value_cost,
+                ))
+            }
+            SdfType::Row(fields) => {
+                let arg_fields = match arg {
+                    DataType::Struct(arg_fields) if arg_fields.len() == fields.len() => arg_fields,
+                    _ => return None,
+                };
+                let mut cost = 0;
+                let mut coerced = Vec::with_capacity(fields.len());
+                for ((_, t), f) in fields.iter().zip(arg_fields.iter()) {
+                    let (dt, c) = t.coerce(f.data_type(), bindings)?;
+                    cost += c;
+                    coerced.push(Field::new(f.name(), dt, true));
+                }
+                Some((DataType::Struct(coerced.into()), cost))
+            }
+            SdfType::Function(ts) => {
+                let (dt, cost) = ts.last()?.coerce(lambda_result(arg)?, bindings)?;
+                Some((lambda_of(dt), cost))
+            }
+        }
+    }
+}
+
+impl fmt::Display for SdfType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SdfType::Variable(v) | SdfType::Named(v) => write!(f, "{v}"),
+            SdfType::Parametric(name, params) => match name.strip_suffix(" with time zone") {
+                Some(base) => write!(f, "{base}({}) with time zone", params.join(", ")),
+                None => write!(f, "{name}({})", params.join(", ")),
+            },
+            SdfType::Array(elem) => write!(f, "array<{elem}>"),
+            SdfType::Map(key, value) => write!(f, "map<{key}, {value}>"),
+            SdfType::Row(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, t)| match name {
+                        Some(name) => format!("{name} {t}"),
+                        None => t.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "row({})", fields.join(", "))
+            }
+            SdfType::Function(ts) => {
+                let ts = ts.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+                write!(f, "function({})", ts.join(", "))
+            }
+        }
+    }
+}
+
+/// The declared parameters of one overload of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    pub parameters: Vec<SdfType>,
+    /// Whether the (single) parameter may be repeated any number of times, all occurrences
+    /// sharing the same type bindings (SDF `variadic: uniform`).
+    pub variadic: bool,
+}
+
+/// The outcome of successfully matching argument types against a [`FunctionSignature`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// The types the arguments must be coerced to.
+    pub coerced_types: Vec<DataType>,
+    /// Total cost of the implicit coercions; 0 for an exact match.
+    pub cost: u32,
+    /// The types bound to the type variables of the signature.
+    pub bindings: Bindings,
+}
+
+impl FunctionSignature {
+    pub fn new(parameters: &[&str], variadic: bool) -> Result<Self> {
+        let parameters = parameters
+            .iter()
+            .map(|p| SdfType::parse(p))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            parameters,
+            variadic,
+        })
+    }
+
+    /// Match the argument types against this signature.
+    pub fn resolve(&self, arg_types: &[DataType]) -> Option<Resolution> {
+        let params: Vec<&SdfType> = if self.variadic {
+            if arg_types.is_empty() {
+                return None;
+            }
+            self.parameters
+                .iter()
+                .cycle()
+                .take(arg_types.len())
+                .collect()
+        } else {
+            if arg_types.len() != self.parameters.len() {
+                return None;
+            }
+            self.parameters.iter().collect()
+        };
+
+        let mut bindings = Bindings::new();
+        for (param, arg) in params.iter().zip(arg_types) {
+            if !param.bind(arg, &mut bindings) {
+                return None;
+            }
+        }
+        // Variables that only matched NULLs stay NULL.
+        for param in &params {
+            bind_unbound_to_null(param, &mut bindings);
+        }
+
+        let mut cost = 0;
+        let mut coerced_types = Vec::with_capacity(arg_types.len());
+        for (param, arg) in params.iter().zip(arg_types) {
+            let (dt, c) = param.coerce(arg, &bindings)?;
+            cost += c;
+            coerced_types.push(dt);
+        }
+        Some(Resolution {
+            coerced_types,
+            cost,
+            bindings,
+        })
+    }
+
+    /// Render as a call, e.g. `abs(tinyint)`.
+    pub fn display_call(&self, name: &str) -> String {
+        let params = self
+            .parameters
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>();
+        let ellipsis = if self.variadic { ", ..." } else { "" };
+        format!("{name}({}{ellipsis})", params.join(", "))
+    }
+
+    /// Whether the function takes a lambda expression (see [`crate::lambda`]).
+    pub fn takes_lambda(&self) -> bool {
+        self.parameters
+            .iter()
+            .any(|p| matches!(p, SdfType::Function(_)))
+    }
+}
+
+/// Coerce the argument types of a call to `function` to its declared `signature`,
+/// failing at plan time with the dialect's error message if they do not match.
+pub fn coerce_arguments(
+    function: &str,
+    signature: &FunctionSignature,
+    arg_types: &[DataType],
+) -> Result<Vec<DataType>> {
+    match signature.resolve(arg_types) {
+        Some(res) => Ok(res.coerced_types),
+        None => plan_err!(
+            "{}",
+            unexpected_parameters(function, arg_types, [signature])
+        ),
+    }
+}
+
+/// Trino's error message for a call matching none of the `expected` signatures.
+pub(crate) fn unexpected_parameters<'a>(
+    function: &str,
+    arg_types: &[DataType],
+    expected: impl IntoIterator<Item = &'a FunctionSignature>,
+) -> String {
+    let args = arg_types.iter().map(display_data_type).collect::<Vec<_>>();
+    let expected = expected
+        .into_iter()
+        .map(|sig| sig.display_call(function))
+        .collect::<Vec<_>>();
+    format!(
+        "Unexpected parameters ({}) for function {}. Expected: {}",
+        args.join(", "),
+        function,
+        expected.join(", ")
+    )
+}
+
+/// Trino's error message for a call matching several `candidates` equally well.
+pub(crate) fn ambiguous_call<'a>(
+    function: &str,
+    arg_types: &[DataType],
+    candidates: impl IntoIterator<Item = &'a FunctionSignature>,
+) -> String {
+    let args = arg_types.iter().map(display_data_type).collect::<Vec<_>>();
+    let candidates = candidates
+        .into_iter()
+        .map(|sig| sig.display_call(function))
+        .collect::<Vec<_>>();
+    format!(
+        "Could not choose a best candidate for {}({}) among: {}. Explicit type casts must be added.",
+        function,
+        args.join(", "),
+        candidates.join(", ")
+    )
+}
+
+fn bind_unbound_to_null(t: &SdfType, bindings: &mut Bindings) {
+    match t {
+        SdfType::Variable(v) => {
+            bindings.entry(v.clone()).or_insert(DataType::Null);
+        }
+        SdfType::Named(_) | SdfType::Parametric(_, _) => {}
+        SdfType::Array(elem) => bind_unbound_to_null(elem, bindings),
+        SdfType::Map(key, value) => {
+            bind_unbound_to_null(key, bindings);
+            bind_unbound_to_null(value, bindings);
+        }
+        SdfType::Row(fields) => fields
+            .iter()
+            .for_each(|(_, t)| bind_unbound_to_null(t, bindings)),
+        SdfType::Function(ts) => ts.iter().for_each(|t| bind_unbound_to_null(t, bindings)),
+    }
+}
+
+pub(crate) fn list_of(dt: DataType) -> DataType {
+    DataType::List(Arc::new(Field::new_list_field(dt, true)))
+}
+
+pub(crate) fn map_of(key: DataType, value: DataType) -> DataType {
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(
+            vec![
+                Field::new("key", key, false),
+                Field::new("value", value, true),
+            ]
+            .into(),
+        ),
+        false,
+    );
+    DataType::Map(Arc::new(entries), false)
+}
+
+/// The Arrow representation of a lambda expression whose body is of type `result`, which only
+/// matches `function(...)` parameters (see [`crate::lambda`]).
+pub(crate) fn lambda_of(result: DataType) -> DataType {
+    DataType::Struct(vec![Field::new(LAMBDA_FIELD, result, true)].into())
+}
+
+/// The type of the body of a lambda expression of type `dt`, if it is one.
+pub(crate) fn lambda_result(dt: &DataType) -> Option<&DataType> {
+    match dt {
+        DataType::Struct(fields) if fields.len() == 1 && fields[0].name() == LAMBDA_FIELD => {
+            Some(fields[0].data_type())
+        }
+        _ => None,
+    }
+}
+
+const LAMBDA_FIELD: &str = "__lambda";
+
+/// The Arrow representation of a distinct type wrapping values of `inner`.
+pub(crate) fn distinct_of(inner: DataType) -> DataType {
+    DataType::FixedSizeList(Arc::new(Field::new_list_field(inner, true)), 1)
+}
+
+fn distinct_inner_type(name: &str) -> DataType {
+    match name {
+        "color" => DataType::Int64,
+        _ => DataType::Utf8,
+    }
+}
+
+/// Arrow time unit to precision (fractional second digits).
+fn time_unit_precision(unit: &TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
+/// Precision (fractional second digits) to Arrow time unit.
+fn precision_time_unit(precision: &str) -> Option<TimeUnit> {
+    match precision.parse::<u32>() {
+        Ok(0) => Some(TimeUnit::Second),
+        Ok(1..=3) => Some(TimeUnit::Millisecond),
+        Ok(4..=6) => Some(TimeUnit::Microsecond),
+        Ok(7..=9) => Some(TimeUnit::Nanosecond),
+        Ok(_) => None,
+        // A precision variable such as `p`: Trino's default precision is 3
+        Err(_) => Some(TimeUnit::Millisecond),
+    }
+}
+
+fn time_data_type(unit: TimeUnit) -> DataType {
+    match unit {
+        TimeUnit::Second | TimeUnit::Millisecond => DataType::Time32(unit),
+        TimeUnit::Microsecond | TimeUnit::Nanosecond => DataType::Time64(unit),
+    }
+}
+
+/// The time zone of the `with time zone` types, UTC
+pub(crate) const UTC: &str = "+00:00";
+
+/// The Arrow type of the non-parametric type `name`, if it is known. The generator rejects the
+/// functions of unknown types, see [`SdfType::unknown_names`].
+fn known_data_type(name: &str) -> Option<DataType> {
+    let dt = match name {
+        "boolean" => DataType::Boolean,
+        "tinyint" => DataType::Int8,
+        "smallint" => DataType::Int16,
+        "integer" | "int" => DataType::Int32,
+        "bigint" => DataType::Int64,
+        "real" => DataType::Float32,
+        // Redshift's and Snowflake's `float` is a double precision
+        "double" | "float" | "float8" => DataType::Float64,
+        "varchar" | "char" | "json" | "string" => DataType::Utf8,
+        // Snowflake's semi-structured values, as JSON text
+        "variant" | "object" => DataType::Utf8,
+        "varbinary" => DataType::Binary,
+        "date" => DataType::Date32,
+        "time" => DataType::Time32(TimeUnit::Millisecond),
+        "timestamp" => DataType::Timestamp(TimeUnit::Millisecond, None),
+        "timestamp with time zone" => DataType::Timestamp(TimeUnit::Millisecond, Some(UTC.into())),
+        "decimal" => DataType::Decimal128(38, 0),
+        "interval day to second" => DataType::Interval(IntervalUnit::MonthDayNano),
+        "interval year to month" => DataType::Interval(IntervalUnit::YearMonth),
+        "unknown" => DataType::Null,
+        name if DISTINCT_TYPES.contains(&name) => distinct_of(distinct_inner_type(name)),
+        name if OPAQUE_TYPES.contains(&name) => DataType::Binary,
+        // The storage of their extension type (geometry, uuid, ...)
+        _ => return extension::storage_type(name),
+    };
+    Some(dt)
+}
+
+fn parametric_data_type(name: &str, params: &[String]) -> Option<DataType> {
+    match (name, params) {
+        ("decimal", [precision, scale]) => Some(DataType::Decimal128(
+            precision.parse().unwrap_or(38),
+            scale.parse().unwrap_or(0),
+        )),
+        ("decimal", [precision]) => Some(DataType::Decimal128(precision.parse().unwrap_or(38), 0)),
+        ("timestamp", [precision]) => {
+            Some(DataType::Timestamp(precision_time_unit(precision)?, None))
+        }
+        ("timestamp with time zone", [precision]) => Some(DataType::Timestamp(
+            precision_time_unit(precision)?,
+            Some(UTC.into()),
+        )),
+        ("time", [precision]) | ("time with time zone", [precision]) => {
+            Some(time_data_type(precision_time_unit(precision)?))
+        }
+        (name, _) => known_data_type(name),
+    }
+}
+
+/// Position of an integral type in Trino's widening chain.
+fn integer_rank(dt: &DataType) -> Option<u32> {
+    match dt {
+        DataType::Int8 => Some(0),
+        DataType::Int16 | DataType::UInt8 => Some(1),
+        DataType::Int32 | DataType::UInt16 => Some(2),
+        DataType::Int64 | DataType::UInt32 => Some(3),
+        _ => None,
+    }
+}
+
+/// Decimal precision required to hold any value of an integral type.
+fn integer_decimal_precision(dt: &DataType) -> Option<u8> {
+    match dt {
+        DataType::Int8 | DataType::UInt8 => Some(3),
+        DataType::Int16 | DataType::UInt16 => Some(5),
+        DataType::Int32 | DataType::UInt32 => Some(10),
+        DataType::Int64 => Some(19),
+        DataType::UInt64 => Some(20),
+        _ => None,
+    }
+}
+
+fn is_string(dt: &DataType) -> bool {
+    matches!(
+        dt,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    )
+}
+
+fn is_binary(dt: &DataType) -> bool {
+    matches!(
+        dt,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+    )
+}
+
+fn integer_target(name: &str) -> Option<(DataType, u32)> {
+    match name {
+        "tinyint" => Some((DataType::Int8, 0)),
+        "smallint" => Some((DataType::Int16, 1)),
+        "integer" | "int" => Some((DataType::Int32, 2)),
+        "bigint" => Some((DataType::Int64, 3)),
+        _ => None,
+    }
+}
+
+fn coerce_to_named(arg: &DataType, name: &str) -> Option<(DataType, u32)> {
+    if let Some((target, target_rank)) = integer_target(name) {
+        let rank = integer_rank(arg)?;
+        return match (arg == &target, rank <= target_rank) {
+            (true, _) => Some((target, 0)),
+            (false, true) => Some((target, target_rank - rank + u32::from(rank == target_rank))),
+            (false, false) => None,
+        };
+    }
+    match name {
+        "boolean" => matches!(arg, DataType::Boolean).then(|| (arg.clone(), 0)),
+        "real" => match arg {
+            DataType::Float32 => Some((arg.clone(), 0)),
+            DataType::Float16 => Some((DataType::Float32, 1)),
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => Some((DataType::Float32, 1)),
+            _ if integer_decimal_precision(arg).is_some() => Some((DataType::Float32, 5)),
+            _ => None,
+        },
+        "double" | "float" | "float8" => match arg {
+            DataType::Float64 => Some((arg.clone(), 0)),
+            DataType::Float32 | DataType::Float16 => Some((DataType::Float64, 1)),
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => Some((DataType::Float64, 2)),
+            _ if integer_decimal_precision(arg).is_some() => Some((DataType::Float64, 6)),
+            _ => None,
+        },
+        "varchar" | "char" | "json" | "string" | "variant" | "object" => {
+            is_string(arg).then(|| (arg.clone(), 0))
+        }
+        "varbinary" => match arg {
+            _ if is_binary(arg) => Some((arg.clone(), 0)),
+            DataType::FixedSizeBinary(_) => Some((DataType::Binary, 1)),
+            _ => None,
+        },
+        "date" => matches!(arg, DataType::Date32 | DataType::Date64).then(|| (arg.clone(), 0)),
+        "decimal" => coerce_to_parametric(arg, "decimal", &["p".into(), "s".into()]),
+        "time" | "timestamp" | "timestamp with time zone" => {
+            coerce_to_parametric(arg, name, &["p".into()])
+        }
+        "interval day to second" => match arg {
+            DataType::Interval(IntervalUnit::DayTime)
+            | DataType::Interval(IntervalUnit::MonthDayNano)
+            | DataType::Duration(_) => Some((arg.clone(), 0)),
+            _ => None,
+        },
+        "interval year to month" => {
+            matches!(arg, DataType::Interval(IntervalUnit::YearMonth)).then(|| (arg.clone(), 0))
+        }
+        "unknown" => None,
+        name if DISTINCT_TYPES.contains(&name) => {
+            let inner = distinct_inner_type(name);
+            match arg {
+                DataType::FixedSizeList(f, 1) if f.data_type() == &inner => Some((arg.clone(), 0)),
+                // Trino implicitly casts varchar to regexp/json path/code point types
+                _ if is_string(arg) && inner == DataType::Utf8 => {
+                    Some((distinct_of(DataType::Utf8), 1))
+                }
+                _ => None,
+            }
+        }
+        _ => match extension::storage_type(name) {
+            // Fixed layouts only match themselves
+            Some(storage @ (DataType::FixedSizeBinary(_) | DataType::Int64)) => {
+                (arg == &storage).then_some((storage, 0))
+            }
+            // Unknown types match nothing
+            None if !OPAQUE_TYPES.contains(&name) => None,
+            _ => match arg {
+                _ if is_binary(arg) => Some((arg.clone(), 0)),
+                DataType::FixedSizeBinary(_) => Some((DataType::Binary, 1)),
+                _ => None,
+            },
+        },
+    }
+}
+
+fn coerce_to_parametric(arg: &DataType, name: &str, params: &[String]) -> Option<(DataType, u32)> {
+    match name {
+        "decimal" => match arg {
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => Some((arg.clone(), 0)),
+            _ => integer_decimal_precision(arg).map(|p| (DataType::Decimal128(p, 0), 4)),
+        },
+        "timestamp" | "timestamp with time zone" => {
+            let with_tz = name == "timestamp with time zone";
+            // A fixed precision requires a specific unit, a precision variable accepts any
+            let unit = params
+                .first()
+                .filter(|p| p.parse::<u32>().is_ok())
+                .and_then(|p| precision_time_unit(p));
+            match arg {
+                DataType::Timestamp(arg_unit, tz) => {
+                    // A zoned timestamp is passed as is to a `timestamp(p)`, whose functions
+                    // handle zones where they have no `timestamp(p) with time zone` overload
+                    // (e.g. `format_datetime`), but the overloads with a zone are preferred
+                    let tz_cost = u32::from(with_tz != tz.is_some());
+                    let tz = if with_tz && tz.is_none() {
+                        Some(UTC.into())
+                    } else {
+                        tz.clone()
+                    };
+                    match unit {
+                        Some(unit) if &unit != arg_unit => {
+                            Some((DataType::Timestamp(unit, tz), tz_cost + 1))
+                        }
+                        _ => Some((DataType::Timestamp(*arg_unit, tz), tz_cost)),
+                    }
+                }
+                DataType::Date32 | DataType::Date64 => {
+                    let unit = unit.unwrap_or(TimeUnit::Millisecond);
+                    let tz = with_tz.then(|| UTC.into());
+                    Some((DataType::Timestamp(unit, tz), 1))
+                }
+                _ => None,
+            }
+        }
+        "time" | "time with time zone" => match arg {
+            DataType::Time32(_) | DataType::Time64(_) => Some((arg.clone(), 0)),
+            _ => None,
+        },
+        "varchar" | "char" => is_string(arg).then(|| (arg.clone(), 0)),
+        _ => coerce_to_named(arg, name),
+    }
+}
+
+/// Whether a value of type `from` is implicitly coerced to the type `to`.
+pub(crate) fn can_coerce(from: &DataType, to: &DataType) -> bool {
+    coerce_data_type(from, to).is_some()
+}
+
+/// Cost of implicitly coercing an Arrow type to another one, when the target is bound to a type variable.
+fn coerce_data_type(from: &DataType, to: &DataType) -> Option<u32> {
+    if from == to {
+        return Some(0);
+    }
+    match (from, to) {
+        (DataType::Null, _) => Some(1),
+        (_, DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64) => {
+            let (from_rank, to_rank) = (integer_rank(from)?, integer_rank(to)?);
+            (from_rank < to_rank).then_some(to_rank - from_rank)
+        }
+        (_, DataType::Float32 | DataType::Float64 | DataType::Decimal128(_, _)) => {
+            let name = match to {
+                DataType::Float32 => "real",
+                DataType::Float64 => "double",
+                _ => "decimal",
+            };
+            coerce_to_named(from, name).map(|(_, cost)| cost)
+        }
+        (DataType::List(f), DataType::List(t))
+        | (DataType::LargeList(f), DataType::LargeList(t))
+        | (DataType::FixedSizeList(f, _), DataType::List(t)) => {
+            coerce_data_type(f.data_type(), t.data_type())
+        }
+        _ if is_string(from) && is_string(to) => Some(1),
+        _ if is_binary(from) && is_binary(to) => Some(1),
+        (DataType::Date32, DataType::Timestamp(_, _)) => Some(1),
+        (DataType::Timestamp(_, _), DataType::Timestamp(_, _)) => Some(1),
+        _ => None,
+    }
+}
+
+/// The smallest type both `a` and `b` can be implicitly coerced to, if any.
+fn common_super_type(a: &DataType, b: &DataType) -> Option<DataType> {
+    if a == b {
+        return Some(a.clone());
+    }
+    match (a, b) {
+        (DataType::Null, _) => Some(b.clone()),
+        (_, DataType::Null) => Some(a.clone()),
+        (DataType::List(fa), DataType::List(fb)) => {
+            Some(list_of(common_super_type(fa.data_type(), fb.data_type())?))
+        }
+        _ if coerce_data_type(a, b).is_some() => Some(b.clone()),
+        _ if coerce_data_type(b, a).is_some() => Some(a.clone()),
+        (DataType::Decimal128(_, _), DataType::Float32 | DataType::Float64) => Some(b.clone()),
+        (DataType::Float32 | DataType::Float64, DataType::Decimal128(_, _)) => Some(a.clone()),
+        (DataType::Decimal128(pa, sa), DataType::Decimal128(pb, sb)) => {
+            let scale = (*sa).max(*sb);
+            let integral = (*pa as i8 - *sa).max(*pb as i8 - *sb);
+            let precision = (integral + scale).clamp(1, 38) as u8;
+            Some(DataType::Decimal128(precision, scale))
+        }
+        _ => {
+            // Mixed integral and decimal: widen the integral part of the decimal as needed
+            let (dec, int) = match (a, b) {
+                (DataType::Decimal128(p, s), other) | (other, DataType::Decimal128(p, s)) => {
+                    ((*p, *s), other)
+                }
+                _ => return None,
+            };
+            let int_precision = integer_decimal_precision(int)? as i8;
+            let integral = (dec.0 as i8 - dec.1).max(int_precision);
+            let precision = (integral + dec.1).clamp(1, 38) as u8;
+            Some(DataType::Decimal128(precision, dec.1))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Variable(String),
+    Punct(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '$' {
+            chars.next();
+            let mut var = String::from("$");
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_alphanumeric()) {
+                var.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Variable(var));
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut word = String::new();
+            while let Some(&d) = chars
+                .peek()
+                .filter(|d| d.is_ascii_alphanumeric() || **d == '_')
+            {
+                word.push(d.to_ascii_lowercase());
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        } else if "<>(),".contains(c) {
+            tokens.push(Token::Punct(c));
+            chars.next();
+        } else {
+            return plan_err!("Unexpected character '{c}' in type '{text}'");
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punct(c))
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        if self.peek_punct(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            plan_err!("Expected '{c}' in type, found {:?}", self.peek())
+        }
+    }
+
+    fn word(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Token::Word(w)) => {
+                let w = w.clone();
+                self.pos += 1;
+                Some(w)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<SdfType> {
+        if let Some(Token::Variable(v)) = self.peek() {
+            let v = v.clone();
+            self.pos += 1;
+            return Ok(SdfType::Variable(v));
+        }
+        let Some(first) = self.word() else {
+            return plan_err!("Expected a type, found {:?}", self.peek());
+        };
+        match first.as_str() {
+            "array" => {
+                let close = self.open_container()?;
+                let elem = self.parse_type()?;
+                self.expect_punct(close)?;
+                Ok(SdfType::Array(Box::new(elem)))
+            }
+            "map" => {
+                let close = self.open_container()?;
+                let key = self.parse_type()?;
+                self.expect_punct(',')?;
+                let value = self.parse_type()?;
+                self.expect_punct(close)?;
+                Ok(SdfType::Map(Box::new(key), Box::new(value)))
+            }
+            "row" => {
+                self.expect_punct('(')?;
+                let mut fields = vec![];
+                loop {
+                    fields.push(self.parse_row_field()?);
+                    if self.peek_punct(',') {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                self.expect_punct(')')?;
+                Ok(SdfType::Row(fields))
+            }
+            "function" => {
+                self.expect_punct('(')?;
+                let mut ts = vec![self.parse_type()?];
+                while self.peek_punct(',') {
+                    self.pos += 1;
+                    ts.push(self.parse_type()?);
+                }
+                self.expect_punct(')')?;
+                Ok(SdfType::Function(ts))
+            }
+            _ => {
+                // multi-word names such as `interval day to second` or `double precision`
+                let mut name = first;
+                while let Some(Token::Word(_)) = self.peek() {
+                    name.push(' ');
+                    name.push_str(&self.word().unwrap_or_default());
+                }
+                if !self.peek_punct('(') {
+                    return Ok(SdfType::Named(name));
+                }
+                self.pos += 1;
+                let mut params = vec![];
+                loop {
+                    match self.word() {
+                        Some(p) => params.push(p),
+                        None => return plan_err!("Expected a type parameter in '{name}'"),
+                    }
+                    if self.peek_punct(',') {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                self.expect_punct(')')?;
+                // `timestamp(p) with time zone`
+                while let Some(Token::Word(_)) = self.peek() {
+                    name.push(' ');
+                    name.push_str(&self.word().unwrap_or_default());
+                }
+                Ok(SdfType::Parametric(name, params))
+            }
+        }
+    }
+
+    fn open_container(&mut self) -> Result<char> {
+        if self.peek_punct('<') {
+            self.pos += 1;
+            Ok('>')
+        } else {
+            self.expect_punct('(')?;
+            Ok(')')
+        }
+    }
+
+    fn parse_row_field(&mut self) -> Result<(Option<String>, SdfType)> {
+        // A field is either `name type` or an anonymous `type`. It is named if a word is followed
+        // by the start of another type (a word or a type variable) and is not a multi-word type name.
+        let start = self.pos;
+        if let Some(Token::Word(w)) = self.peek() {
+            let is_multi_word_type =
+                matches!(w.as_str(), "interval" | "double" | "time" | "timestamp");
+            let next = self.tokens.get(self.pos + 1);
+            if !is_multi_word_type
+                && matches!(next, Some(Token::Word(_)) | Some(Token::Variable(_)))
+            {
+                let name = self.word();
+                return Ok((name, self.parse_type()?));
+            }
+        }
+        self.pos = start;
+        Ok((None, self.parse_type()?))
+    }
+}
+
+/// Render an Arrow type with the name of the SQL type it represents, for error messages.
+pub fn display_data_type(dt: &DataType) -> String {
+    match dt {
+        DataType::Null => "unknown".to_string(),
+        DataType::Boolean => "boolean".to_string(),
+        DataType::Int8 => "tinyint".to_string(),
+        DataType::Int16 => "smallint".to_string(),
+        DataType::Int32 => "integer".to_string(),
+        DataType::Int64 => "bigint".to_string(),
+        DataType::Float32 => "real".to_string(),
+        DataType::Float64 => "double".to_string(),
+        DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => format!("decimal({p},{s})"),
+        _ if is_string(dt) => "varchar".to_string(),
+        _ if is_binary(dt) => "varbinary".to_string(),
+        DataType::Date32 | DataType::Date64 => "date".to_string(),
+        DataType::Timestamp(unit, tz) => {
+            let precision = time_unit_precision(unit);
+            match tz {
+                Some(_) => format!("timestamp({precision}) with time zone"),
+                None => format!("timestamp({precision})"),
+            }
+        }
+        DataType::Time32(unit) | DataType::Time64(unit) => {
+            format!("time({})", time_unit_precision(unit))
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => "interval year to month".to_string(),
+        DataType::Interval(_) | DataType::Duration(_) => "interval day to second".to_string(),
+        DataType::List(f) | DataType::LargeList(f) => {
+            format!("array({})", display_data_type(f.data_type()))
+        }
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => format!(
+                "map({}, {})",
+                display_data_type(fields[0].data_type()),
+                display_data_type(fields[1].data_type())
+            ),
+            _ => dt.to_string(),
+        },
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|f| format!("{} {}", f.name(), display_data_type(f.data_type())))
+                .collect::<Vec<_>>();
+            format!("row({})", fields.join(", "))
+        }
+        _ => dt.to_string(),
+    }
+}
@@ -15,20 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::StringArray;
 use arrow::array::{
-    Array, ArrayRef, AsArray, BooleanArray, Datum, GenericByteArray, ListArray, NullArray,
-    OffsetSizeTrait, PrimitiveArray,
+    make_array, Array, ArrayRef, AsArray, BooleanArray, Datum, GenericByteArray, ListArray,
+    NullArray, OffsetSizeTrait, PrimitiveArray,
 };
+use arrow::buffer::NullBuffer;
 use arrow::compute::{cast, date_part, DatePart};
 use arrow::datatypes::{ArrowPrimitiveType, DataType, GenericBinaryType, GenericStringType};
 use arrow::error::Result as ArrowResult;
-use datafusion::common::cast::{as_fixed_size_list_array, as_string_array};
 use datafusion::common::{Result, ScalarValue};
 use datafusion::error::DataFusionError;
+use datafusion::logical_expr::function::Hint;
 use datafusion::logical_expr::{ColumnarValue, ScalarFunctionImplementation};
+use std::collections::HashMap;
 use std::sync::Arc;
-use datafusion::logical_expr::function::Hint;
 
 /// Creates a scalar function implementation for the given function.
 /// * `inner` - the function to be executed
@@ -131,36 +131,77 @@ pub(super) fn columnar_to_datum(col: &ColumnarValue) -> Box<dyn Datum + '_> {
     }
 }
 
-/// Wrap as a DataFusion ColumnarValue, recognizing a singleton vector.
-pub(super) fn array_to_columnar(array: ArrayRef) -> ColumnarValue {
-    if array.len() == 1 {
-        match ScalarValue::try_from_array(&array, 0) {
-            Ok(scalar) => ColumnarValue::Scalar(scalar),
-            Err(_) => ColumnarValue::Array(array), // Should not happen; can just panic instead
-        }
+/// Wrap the result computed from `args` as a DataFusion ColumnarValue:
+/// a scalar if all the arguments are scalars (the result then has a single row), else an array.
+pub(super) fn array_to_columnar(array: ArrayRef, args: &[&ColumnarValue]) -> Result<ColumnarValue> {
+    if args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)))
+    {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &array, 0,
+        )?))
     } else {
-        ColumnarValue::Array(array)
+        Ok(ColumnarValue::Array(array))
     }
 }
 
-/// Extract the underlying string array from an array of SDF "distinct" type.
-/// (The distinct type is FixedSizeList with size 1.)
-/// TODO: This implementation iterates and reconstructs into a new array;
-/// it could be possible to exploit the size=1 circumstance to achieve no-copy implementation.
-pub(super) fn distinct_to_string_array(distinct_arr: &Arc<dyn Array>) -> Result<StringArray> {
-    let fxsize_arr = as_fixed_size_list_array(distinct_arr)?;
-    let res = fxsize_arr
-        .iter()
-        .map(|lst_opt| {
-            lst_opt
-                .map(|lst| {
-                    let inner = as_string_array(&lst)?;
-                    Ok(inner.value(0).to_owned())
-                })
-                .transpose()
+/// Set the rows of `array` where any of `args` is NULL to NULL,
+/// as for the functions that return NULL if any argument is NULL.
+pub(super) fn with_nulls_of(array: ArrayRef, args: &[ArrayRef]) -> Result<ArrayRef> {
+    let nulls = args.iter().fold(array.logical_nulls(), |nulls, arg| {
+        NullBuffer::union(nulls.as_ref(), arg.logical_nulls().as_ref())
+    });
+    let data = array.into_data().into_builder().nulls(nulls).build()?;
+    Ok(make_array(data))
+}
+
+/// The number of rows of columnar values: the length of the arrays among them, or 1 if all are scalars.
+pub(super) fn columnar_len(args: &[&ColumnarValue]) -> usize {
+    args.iter()
+        .find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            ColumnarValue::Scalar(_) => None,
         })
-        .collect::<Result<StringArray>>()?;
-    Ok(res)
+        .unwrap_or(1)
+}
+
+/// The maximum number of patterns whose row function a [`RowfunCache`] keeps.
+const ROWFUN_CACHE_CAPACITY: usize = 64;
+
+/// The row functions of the patterns of a column (e.g. regular expressions or JSON paths), each
+/// computed once by the curried row function.
+/// When full, the cache is emptied, so a column of mostly distinct patterns costs no more than
+/// computing the row function on every row.
+pub(super) struct RowfunCache<'a, F, R> {
+    rowfun: &'a F,
+    compiled: HashMap<String, R>,
+}
+
+impl<'a, F, R> RowfunCache<'a, F, R>
+where
+    F: Fn(/*pat:*/ &str) -> Result<R>,
+    R: Clone,
+{
+    pub(super) fn new(rowfun: &'a F) -> Self {
+        Self {
+            rowfun,
+            compiled: HashMap::new(),
+        }
+    }
+
+    /// The row function of `pat`.
+    pub(super) fn get(&mut self, pat: &str) -> Result<R> {
+        if let Some(regfun) = self.compiled.get(pat) {
+            return Ok(regfun.clone());
+        }
+        let regfun = (self.rowfun)(pat)?;
+        if self.compiled.len() == ROWFUN_CACHE_CAPACITY {
+            self.compiled.clear();
+        }
+        self.compiled.insert(pat.to_owned(), regfun.clone());
+        Ok(regfun)
+    }
 }
 
 /// Means to lift a suite of kernels on typed arrays to operate on a list array (an array of lists).
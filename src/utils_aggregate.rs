@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::cast::{as_boolean_array, as_int64_array};
+use datafusion::common::{Result, ScalarValue};
+use datafusion::logical_expr::function::StateFieldsArgs;
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::Accumulator;
+
+/// The accumulator of `bool_and` (if `all`) and `bool_or`. It counts the true and false values
+/// rather than combining them, so that they can be retracted from sliding window frames.
+#[derive(Debug)]
+pub(super) struct BoolAccumulator {
+    all: bool,
+    trues: i64,
+    falses: i64,
+}
+
+impl BoolAccumulator {
+    pub(super) fn new(all: bool) -> Self {
+        Self {
+            all,
+            trues: 0,
+            falses: 0,
+        }
+    }
+
+    /// The fields of the state of the accumulator: the counts of true and false values.
+    pub(super) fn state_fields(args: StateFieldsArgs) -> Vec<Field> {
+        vec![
+            Field::new(format_state_name(args.name, "trues"), DataType::Int64, true),
+            Field::new(
+                format_state_name(args.name, "falses"),
+                DataType::Int64,
+                true,
+            ),
+        ]
+    }
+
+    /// The counts of true and false values of `values`, NULLs being ignored.
+    fn counts(values: &ArrayRef) -> Result<(i64, i64)> {
+        let values = as_boolean_array(values)?;
+        let trues = values.true_count() as i64;
+        let falses = (values.len() - values.null_count()) as i64 - trues;
+        Ok((trues, falses))
+    }
+}
+
+impl Accumulator for BoolAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let (trues, falses) = Self::counts(&values[0])?;
+        self.trues += trues;
+        self.falses += falses;
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let value = match (self.trues, self.falses) {
+            (0, 0) => None,
+            (_, falses) if self.all => Some(falses == 0),
+            (trues, _) => Some(trues > 0),
+        };
+        Ok(ScalarValue::Boolean(value))
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            ScalarValue::Int64(Some(self.trues)),
+            ScalarValue::Int64(Some(self.falses)),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.trues += as_int64_array(&states[0])?.iter().flatten().sum::<i64>();
+        self.falses += as_int64_array(&states[1])?.iter().flatten().sum::<i64>();
+        Ok(())
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let (trues, falses) = Self::counts(&values[0])?;
+        self.trues -= trues;
+        self.falses -= falses;
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+}
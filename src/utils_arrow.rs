@@ -35,7 +35,7 @@ impl<'a> FromIterator<Option<&'a str>> for StringArrayExt {
 
 /// Enables collect::<ListArrayExt> from an Iterator of Option<String>,
 /// where None represents a null list.
-impl<'a> FromIterator<Option<String>> for StringArrayExt {
+impl FromIterator<Option<String>> for StringArrayExt {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = Option<String>>,
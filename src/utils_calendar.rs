@@ -0,0 +1,334 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helper functors for `date_add`, `date_diff`, `date_trunc` and the `sequence` of dates or
+//! timestamps, mapping the [`calendar`] arithmetic over a column of units or intervals and columns of
+//! dates, times or timestamps.
+//! - The units are parsed once per distinct unit, and only for the rows with values, so that
+//!   invalid units give NULL for NULL values.
+//! - The units of a day and longer apply to the local date-times of timestamps with a time zone,
+//!   which are then converted back to instants in their time zone, and the shorter units to their
+//!   instants.
+//! - Rows with a NULL argument are NULL.
+//! - The result is a scalar if all the columns are scalars, and else an array with the length of the
+//!   array columns.
+//!
+//! [`calendar`]: crate::calendar
+
+use arrow::array::timezone::Tz;
+use arrow::array::{Array, ArrayRef, AsArray, Int64Array, ListArray};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    DataType, Field as ArrowField, Int64Type, IntervalMonthDayNanoType, IntervalUnit, TimeUnit,
+};
+use chrono::{DateTime, NaiveDateTime, TimeZone};
+use datafusion::common::cast::as_string_array;
+use datafusion::common::{exec_datafusion_err, exec_err, DataFusionError, Result};
+use datafusion::logical_expr::ColumnarValue;
+use std::sync::Arc;
+
+use crate::calendar::{self, Field, Unit};
+use crate::timezone::{from_local, parse_zone};
+use crate::utils::{array_to_columnar, columnar_len, RowfunCache};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const NANOS_PER_DAY: i64 = 86_400 * NANOS_PER_SECOND;
+
+/// The maximum number of elements of a `sequence`, as in Trino.
+const MAX_SEQUENCE_LENGTH: i64 = 10_000;
+
+/// The kind of the values of a column of dates, times or timestamps, which converts them to local
+/// date-times and back.
+#[derive(Clone, Copy)]
+enum Temporal {
+    /// Days since the epoch
+    Date,
+    /// Units since midnight
+    Time(TimeUnit),
+    /// Units since the epoch, in UTC, with the time zone of their type
+    Timestamp(TimeUnit, Option<Tz>),
+}
+
+impl Temporal {
+    fn of(data_type: &DataType) -> Result<Self> {
+        Ok(match data_type {
+            DataType::Date32 => Temporal::Date,
+            DataType::Time32(unit) | DataType::Time64(unit) => Temporal::Time(*unit),
+            DataType::Timestamp(unit, zone) => {
+                let tz = zone.as_deref().map(parse_zone).transpose()?;
+                Temporal::Timestamp(*unit, tz)
+            }
+            other => return exec_err!("Expected a date, time or timestamp, got {other}"),
+        })
+    }
+
+    fn field(self) -> Field {
+        match self {
+            Temporal::Date => Field::Date,
+            Temporal::Time(_) => Field::Time,
+            Temporal::Timestamp(_, _) => Field::Timestamp,
+        }
+    }
+
+    /// The length of a value in nanoseconds.
+    fn nanos(self) -> i64 {
+        match self {
+            Temporal::Date => NANOS_PER_DAY,
+            Temporal::Time(unit) | Temporal::Timestamp(unit, _) => match unit {
+                TimeUnit::Second => NANOS_PER_SECOND,
+                TimeUnit::Millisecond => 1_000_000,
+                TimeUnit::Microsecond => 1_000,
+                TimeUnit::Nanosecond => 1,
+            },
+        }
+    }
+
+    /// The local date-time of the value `value`, on the epoch day for times.
+    fn local(self, value: i64) -> Result<NaiveDateTime> {
+        let nanos = i128::from(value) * i128::from(self.nanos());
+        let (seconds, subsec_nanos) = (
+            nanos.div_euclid(NANOS_PER_SECOND.into()),
+            nanos.rem_euclid(NANOS_PER_SECOND.into()),
+        );
+        let utc = i64::try_from(seconds)
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, subsec_nanos as u32))
+            .ok_or_else(out_of_range)?
+            .naive_utc();
+        Ok(match self {
+            Temporal::Timestamp(_, Some(tz)) => tz.from_utc_datetime(&utc).naive_local(),
+            _ => utc,
+        })
+    }
+
+    /// The value of the local date-time `local`, truncated to the precision of the values. Times
+    /// wrap around midnight.
+    fn value(self, local: &NaiveDateTime) -> Result<i64> {
+        let utc = match self {
+            Temporal::Timestamp(_, Some(tz)) => from_local(&tz, local).naive_utc(),
+            _ => *local,
+        };
+        let since_epoch = utc - DateTime::UNIX_EPOCH.naive_utc();
+        let nanos = i128::from(since_epoch.num_seconds()) * i128::from(NANOS_PER_SECOND)
+            + i128::from(since_epoch.subsec_nanos());
+        let nanos = match self {
+            Temporal::Time(_) => nanos.rem_euclid(NANOS_PER_DAY.into()),
+            _ => nanos,
+        };
+        i64::try_from(nanos.div_euclid(self.nanos().into())).map_err(|_| out_of_range())
+    }
+
+    /// The value `value` plus `count` `unit`s, for a unit shorter than a day.
+    fn add_time(self, value: i64, unit: Unit, count: i64) -> Result<i64> {
+        let unit_nanos = unit.nanos().expect("A unit of time has a length");
+        let delta = i128::from(count) * i128::from(unit_nanos) / i128::from(self.nanos());
+        let value = i128::from(value) + delta;
+        let value = match self {
+            Temporal::Time(_) => value.rem_euclid((NANOS_PER_DAY / self.nanos()).into()),
+            _ => value,
+        };
+        i64::try_from(value).map_err(|_| out_of_range())
+    }
+}
+
+/// `date_add(unit, value, x)`: `x` plus `value` `unit`s, of the type of `x`.
+pub(super) fn map_date_add(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let temporal = Temporal::of(&args[2].data_type())?;
+    let arrays = to_arrays(args)?;
+    let counts = arrays[1].as_primitive::<Int64Type>();
+    let values = int64_values(&arrays[2])?;
+    let res = map_unit_rows(&arrays, temporal.field(), |row, unit| {
+        let (value, count) = (values.value(row), counts.value(row));
+        if unit.is_time() {
+            return temporal.add_time(value, unit, count);
+        }
+        let local = temporal.local(value)?;
+        let added = calendar::add(&local, unit, count).ok_or_else(out_of_range)?;
+        temporal.value(&added)
+    })?;
+    let res = from_int64_values(&res, &args[2].data_type())?;
+    array_to_columnar(res, &args.iter().collect::<Vec<_>>())
+}
+
+/// `date_diff(unit, x1, x2)`: the number of `unit`s from `x1` to `x2`, as a BIGINT. The local
+/// date-times are in the time zone of `x1`.
+pub(super) fn map_date_diff(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let temporal = Temporal::of(&args[1].data_type())?;
+    let arrays = to_arrays(args)?;
+    let (starts, ends) = (int64_values(&arrays[1])?, int64_values(&arrays[2])?);
+    let end_nanos = Temporal::of(&args[2].data_type())?.nanos();
+    let res = map_unit_rows(&arrays, temporal.field(), |row, unit| {
+        let (start, end) = (starts.value(row), ends.value(row));
+        match unit.nanos() {
+            Some(unit_nanos) if unit.is_time() => {
+                let delta = i128::from(end) * i128::from(end_nanos)
+                    - i128::from(start) * i128::from(temporal.nanos());
+                i64::try_from(delta / i128::from(unit_nanos)).map_err(|_| out_of_range())
+            }
+            _ => {
+                let end = match temporal {
+                    // `x2` may be of another unit
+                    Temporal::Timestamp(_, _) | Temporal::Time(_) => {
+                        let nanos = i128::from(end) * i128::from(end_nanos);
+                        let end = nanos.div_euclid(temporal.nanos().into());
+                        i64::try_from(end).map_err(|_| out_of_range())?
+                    }
+                    Temporal::Date => end,
+                };
+                let (start, end) = (temporal.local(start)?, temporal.local(end)?);
+                Ok(calendar::diff(&start, &end, unit))
+            }
+        }
+    })?;
+    array_to_columnar(Arc::new(res), &args.iter().collect::<Vec<_>>())
+}
+
+/// `date_trunc(unit, x)`: `x` truncated to `unit`, of the type of `x`.
+pub(super) fn map_date_trunc(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let temporal = Temporal::of(&args[1].data_type())?;
+    let arrays = to_arrays(args)?;
+    let values = int64_values(&arrays[1])?;
+    let res = map_unit_rows(&arrays, temporal.field(), |row, unit| {
+        let local = temporal.local(values.value(row))?;
+        temporal.value(&calendar::truncate(&local, unit))
+    })?;
+    let res = from_int64_values(&res, &args[1].data_type())?;
+    array_to_columnar(res, &args.iter().collect::<Vec<_>>())
+}
+
+/// `sequence(start, stop, step)` for dates or timestamps and an interval `step`: the list of
+/// `start`, `start` plus `step`, plus twice `step`, ... up to `stop`. Each element is `start` plus
+/// a multiple of `step`, so that months stay on the day of `start` after a shorter month.
+pub(super) fn map_sequence(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let data_type = args[0].data_type();
+    let temporal = Temporal::of(&data_type)?;
+    let arrays = to_arrays(args)?;
+    let (starts, stops) = (int64_values(&arrays[0])?, int64_values(&arrays[1])?);
+    let step_arr = cast(&arrays[2], &DataType::Interval(IntervalUnit::MonthDayNano))?;
+    let steps = step_arr.as_primitive::<IntervalMonthDayNanoType>();
+    let mut values = vec![];
+    let mut lengths = vec![];
+    let mut nulls = vec![];
+    for row in 0..starts.len() {
+        if arrays.iter().any(|array| array.is_null(row)) {
+            lengths.push(0);
+            nulls.push(false);
+            continue;
+        }
+        let step = steps.value(row);
+        let (months, days, nanos) = (
+            i64::from(step.months),
+            i64::from(step.days),
+            step.nanoseconds,
+        );
+        let step_nanos = i128::from(days) * i128::from(NANOS_PER_DAY) + i128::from(nanos);
+        let ascending = match months {
+            0 if step_nanos == 0 => return exec_err!("step must not be zero"),
+            0 => step_nanos > 0,
+            _ => months > 0,
+        };
+        if matches!(temporal, Temporal::Date) && step_nanos % i128::from(NANOS_PER_DAY) != 0 {
+            return exec_err!(
+                "sequence step must be a day interval if start and end values are dates"
+            );
+        }
+        let (start, stop) = (starts.value(row), stops.value(row));
+        if (ascending && start > stop) || (!ascending && start < stop) {
+            return exec_err!(
+                "sequence stop value should be greater than or equal to start value if step is \
+                 greater than zero otherwise stop should be less than or equal to start"
+            );
+        }
+        let (start, stop) = (temporal.local(start)?, temporal.local(stop)?);
+        let mut length = 0;
+        loop {
+            let element = calendar::add_interval(&start, (months, days, nanos), length)
+                .ok_or_else(out_of_range)?;
+            if (ascending && element > stop) || (!ascending && element < stop) {
+                break;
+            }
+            if length == MAX_SEQUENCE_LENGTH {
+                return exec_err!(
+                    "result of sequence function must not have more than {MAX_SEQUENCE_LENGTH} entries"
+                );
+            }
+            values.push(temporal.value(&element)?);
+            length += 1;
+        }
+        lengths.push(length as usize);
+        nulls.push(true);
+    }
+    let values = from_int64_values(&Int64Array::from(values), &data_type)?;
+    let res = ListArray::try_new(
+        Arc::new(ArrowField::new_list_field(data_type, true)),
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        Some(NullBuffer::from(nulls)),
+    )?;
+    array_to_columnar(Arc::new(res), &args.iter().collect::<Vec<_>>())
+}
+
+/// Map `rowfun` over the rows of `arrays` without a NULL, passing it the index of the row and the
+/// unit of the first array, parsed for `field`.
+fn map_unit_rows<F>(arrays: &[ArrayRef], field: Field, mut rowfun: F) -> Result<Int64Array>
+where
+    F: FnMut(/*row:*/ usize, /*unit:*/ Unit) -> Result<i64>,
+{
+    let units = as_string_array(&arrays[0])?;
+    let parse = |unit: &str| Unit::parse(unit, field);
+    let mut parsed = RowfunCache::new(&parse);
+    (0..units.len())
+        .map(|row| {
+            if arrays.iter().any(|array| array.is_null(row)) {
+                return Ok(None);
+            }
+            let unit = parsed.get(units.value(row))?;
+            rowfun(row, unit).map(Some)
+        })
+        .collect()
+}
+
+/// The arrays of `args`, with the length of the array arguments.
+fn to_arrays(args: &[ColumnarValue]) -> Result<Vec<ArrayRef>> {
+    let len = columnar_len(&args.iter().collect::<Vec<_>>());
+    args.iter()
+        .map(|arg| arg.to_owned().into_array(len))
+        .collect()
+}
+
+/// The values of a column of dates, times or timestamps, see [`Temporal`].
+fn int64_values(array: &ArrayRef) -> Result<Int64Array> {
+    let array = match array.data_type() {
+        DataType::Date32 | DataType::Time32(_) => cast(array, &DataType::Int32)?,
+        _ => Arc::clone(array),
+    };
+    Ok(cast(&array, &DataType::Int64)?.as_primitive().clone())
+}
+
+/// The column of dates, times or timestamps of `data_type` with the values `values`.
+fn from_int64_values(values: &Int64Array, data_type: &DataType) -> Result<ArrayRef> {
+    let values = match data_type {
+        DataType::Date32 | DataType::Time32(_) => cast(values, &DataType::Int32)?,
+        _ => Arc::new(values.clone()),
+    };
+    Ok(cast(&values, data_type)?)
+}
+
+fn out_of_range() -> DataFusionError {
+    exec_datafusion_err!("Value out of range for a date, time or timestamp")
+}
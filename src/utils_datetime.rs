@@ -0,0 +1,574 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helper functors for the functions formatting and parsing timestamps with a
+//! [`DateTimePattern`], mapping them over a column of timestamps or texts and a column of
+//! patterns, and for the functions on the time zones of timestamps.
+//! - As for the regexp functors, a scalar pattern is compiled once, and the patterns of a column
+//!   are kept in a bounded cache, so the rows sharing a pattern compile it only once. Patterns are
+//!   only compiled for rows with a value, so that invalid patterns give NULL for NULL values.
+//! - The ISO 8601 texts and the Unix times are read as by Trino, see [`iso8601`], and the results
+//!   are in the time zone of a constant offset or zone, and else in UTC.
+//! - Rows with a NULL argument are NULL.
+//! - The result is a scalar if all the columns are scalars, and else an array with the length of the
+//!   array columns.
+//!
+//! [`iso8601`]: crate::iso8601
+
+use arrow::array::{Array, ArrayRef, AsArray, Date32Array, Float64Array, Int64Array, StringArray};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    DataType, Float64Type, Int64Type, TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType,
+};
+use chrono::{DateTime, NaiveDateTime, Offset, TimeDelta, TimeZone};
+use datafusion::common::cast::{as_date32_array, as_string_array};
+use datafusion::common::{exec_datafusion_err, exec_err, Result};
+use datafusion::logical_expr::ColumnarValue;
+use datafusion::scalar::ScalarValue;
+use std::sync::Arc;
+
+use crate::datetime_pattern::DateTimePattern;
+use crate::iso8601;
+use crate::timezone::{arrow_zone, from_local, offset_key, parse_zone, zone_name};
+use crate::type_signature::UTC as UTC_TYPE;
+use crate::utils::{array_to_columnar, columnar_len, RowfunCache};
+
+/// The timestamps of `array` in milliseconds since the epoch. Timestamps of a higher precision are
+/// truncated, towards the past.
+pub(super) fn epoch_millis(array: &dyn Array) -> Result<Int64Array> {
+    Ok(match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            array
+                .as_primitive::<TimestampSecondType>()
+                .unary::<_, Int64Type>(|seconds| seconds.saturating_mul(1000))
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => array
+            .as_primitive::<TimestampMillisecondType>()
+            .reinterpret_cast::<Int64Type>(),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => array
+            .as_primitive::<TimestampMicrosecondType>()
+            .unary::<_, Int64Type>(|micros| micros.div_euclid(1000)),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => array
+            .as_primitive::<TimestampNanosecondType>()
+            .unary::<_, Int64Type>(|nanos| nanos.div_euclid(1_000_000)),
+        other => return exec_err!("Expected a timestamp, got {other}"),
+    })
+}
+
+/// The timestamps of `array` in seconds since the epoch, with their fraction of a second.
+pub(super) fn epoch_seconds(array: &dyn Array) -> Result<Float64Array> {
+    let DataType::Timestamp(unit, _) = array.data_type() else {
+        return exec_err!("Expected a timestamp, got {}", array.data_type());
+    };
+    let per_second = units_per_second(*unit);
+    let values = cast(array, &DataType::Int64)?;
+    Ok(values
+        .as_primitive::<Int64Type>()
+        .unary::<_, Float64Type>(|value| {
+            value.div_euclid(per_second) as f64
+                + value.rem_euclid(per_second) as f64 / per_second as f64
+        }))
+}
+
+/// Format the timestamps of `ts_col`, in their time zone, with the patterns of `pattern_col`
+/// compiled by `compile`.
+pub(super) fn map_format_timestamp(
+    ts_col: &ColumnarValue,
+    pattern_col: &ColumnarValue,
+    compile: fn(&str) -> Result<DateTimePattern>,
+) -> Result<ColumnarValue> {
+    let DataType::Timestamp(_, zone) = ts_col.data_type() else {
+        return exec_err!("Expected a timestamp, got {}", ts_col.data_type());
+    };
+    let zone = zone_name(zone.as_deref());
+    let tz = parse_zone(zone)?;
+    let len = columnar_len(&[ts_col, pattern_col]);
+    let millis = epoch_millis(&ts_col.to_owned().into_array(len)?)?;
+    map_pattern_rows::<StringArray, _, _>(&millis, pattern_col, compile, |row, pattern| {
+        let datetime = tz
+            .timestamp_millis_opt(millis.value(row))
+            .single()
+            .ok_or_else(|| exec_datafusion_err!("Timestamp out of range"))?;
+        Ok(Some(pattern.format(&datetime, zone)))
+    })
+    .and_then(|res| array_to_columnar(res, &[ts_col, pattern_col]))
+}
+
+/// The name of the time zone of the constant text `text_col` parsed with the constant pattern
+/// `pattern_col` compiled by `compile`, if it has one. Invalid texts and patterns are reported
+/// when the rows are mapped.
+fn parsed_zone(
+    text_col: Option<&ScalarValue>,
+    pattern_col: Option<&ScalarValue>,
+    compile: fn(&str) -> Result<DateTimePattern>,
+) -> Option<String> {
+    match (text_col, pattern_col) {
+        (Some(ScalarValue::Utf8(Some(text))), Some(ScalarValue::Utf8(Some(pattern)))) => {
+            compile(pattern).ok()?.zone_of(text).ok().flatten()
+        }
+        _ => None,
+    }
+}
+
+/// The time zone of the result of [`map_parse_timestamp`] for the texts `text_col` and the
+/// patterns `pattern_col`: the time zone of a constant text, and else UTC, which the session
+/// replaces by its time zone (see [`parsed_has_zone`]).
+pub(super) fn parse_result_zone(
+    text_col: Option<&ScalarValue>,
+    pattern_col: Option<&ScalarValue>,
+    compile: fn(&str) -> Result<DateTimePattern>,
+) -> Arc<str> {
+    parsed_zone(text_col, pattern_col, compile)
+        .and_then(|zone| arrow_zone(&zone).ok())
+        .unwrap_or_else(|| UTC_TYPE.into())
+}
+
+/// Whether the text `text_col` is a constant with a time zone, when parsed with the constant
+/// pattern `pattern_col`, the result of [`map_parse_timestamp`] being in the time zone of the
+/// session otherwise.
+pub(crate) fn parsed_has_zone(
+    text_col: Option<&ScalarValue>,
+    pattern_col: Option<&ScalarValue>,
+    compile: fn(&str) -> Result<DateTimePattern>,
+) -> bool {
+    parsed_zone(text_col, pattern_col, compile).is_some()
+}
+
+/// Parse the texts of `text_col` with the patterns of `pattern_col` compiled by `compile`, into
+/// timestamps of `return_type`. The texts without a time zone are in the time zone of
+/// `return_type`, and else in UTC.
+pub(super) fn map_parse_timestamp(
+    text_col: &ColumnarValue,
+    pattern_col: &ColumnarValue,
+    compile: fn(&str) -> Result<DateTimePattern>,
+    return_type: &DataType,
+) -> Result<ColumnarValue> {
+    let DataType::Timestamp(TimeUnit::Millisecond, zone) = return_type else {
+        return exec_err!("Expected a timestamp(3), got {return_type}");
+    };
+    let tz = parse_zone(zone_name(zone.as_deref()))?;
+    let len = columnar_len(&[text_col, pattern_col]);
+    let text_arr = text_col.to_owned().into_array(len)?;
+    let texts = as_string_array(&text_arr)?;
+    map_pattern_rows::<Int64Array, _, _>(texts, pattern_col, compile, |row, pattern| {
+        let datetime = pattern.parse(texts.value(row), &tz)?;
+        Ok(Some(datetime.timestamp_millis()))
+    })
+    .and_then(|res| Ok(cast(&res, return_type)?))
+    .and_then(|res| array_to_columnar(res, &[text_col, pattern_col]))
+}
+
+/// The time zone of the result of [`map_timestamp_zone`] for the zones `zone_col`: the zone of a
+/// constant, and else UTC. Invalid zones are reported when the rows are mapped, so that NULL
+/// timestamps give NULL for any zone.
+pub(super) fn result_zone(zone_col: Option<&ScalarValue>) -> Arc<str> {
+    match zone_col {
+        Some(ScalarValue::Utf8(Some(zone))) => arrow_zone(zone).unwrap_or_else(|_| UTC_TYPE.into()),
+        _ => UTC_TYPE.into(),
+    }
+}
+
+/// Set the time zone of the timestamps of `ts_col` to the zones of `zone_col`, the result being in
+/// the time zone [`result_zone`]. If `keep_instant`, the timestamps are the same instants, as by
+/// `at_timezone`, and else the same local date-times, as by `with_timezone`.
+pub(super) fn map_timestamp_zone(
+    ts_col: &ColumnarValue,
+    zone_col: &ColumnarValue,
+    keep_instant: bool,
+) -> Result<ColumnarValue> {
+    let DataType::Timestamp(unit, _) = ts_col.data_type() else {
+        return exec_err!("Expected a timestamp, got {}", ts_col.data_type());
+    };
+    let to_zone = match zone_col {
+        ColumnarValue::Scalar(zone) => result_zone(Some(zone)),
+        ColumnarValue::Array(_) => result_zone(None),
+    };
+    let len = columnar_len(&[ts_col, zone_col]);
+    // The local date-times of timestamps with a time zone, or their instants
+    let ts_arr = ts_col.to_owned().into_array(len)?;
+    let ts_arr = match keep_instant {
+        true => ts_arr,
+        false => cast(&ts_arr, &DataType::Timestamp(unit, None))?,
+    };
+    let values = cast(&ts_arr, &DataType::Int64)?;
+    let values = values.as_primitive::<Int64Type>();
+    let zone_arr = zone_col.to_owned().into_array(len)?;
+    let zones = as_string_array(&zone_arr)?;
+    let not_valid = |zone: &str| exec_datafusion_err!("'{zone}' is not a valid time zone");
+    let parse = |zone: &str| parse_zone(zone).map_err(|_| not_valid(zone));
+    let mut parsed = RowfunCache::new(&parse);
+    let res = (0..len)
+        .map(|row| {
+            if values.is_null(row) || zones.is_null(row) {
+                return Ok(None);
+            }
+            let zone = parsed.get(zones.value(row))?;
+            let value = values.value(row);
+            if keep_instant {
+                return Ok(Some(value));
+            }
+            let local = naive_datetime(value, unit)?;
+            // Shifted by whole seconds, keeping the fraction of the second
+            let shift = local - from_local(&zone, &local).naive_utc();
+            Ok(Some(value - shift.num_seconds() * units_per_second(unit)))
+        })
+        .collect::<Result<Int64Array>>()?;
+    let res = cast(&res, &DataType::Timestamp(unit, Some(to_zone)))?;
+    array_to_columnar(res, &[ts_col, zone_col])
+}
+
+/// Parse the ISO 8601 dates of `text_col`, see [`iso8601::parse_date`].
+pub(super) fn map_parse_iso8601_date(text_col: &ColumnarValue) -> Result<ColumnarValue> {
+    let len = columnar_len(&[text_col]);
+    let text_arr = text_col.to_owned().into_array(len)?;
+    let res = as_string_array(&text_arr)?
+        .iter()
+        .map(|text| {
+            text.map(|text| {
+                let date = iso8601::parse_date(text)?;
+                let days = (date - DateTime::UNIX_EPOCH.date_naive()).num_days();
+                i32::try_from(days).map_err(|_| exec_datafusion_err!("Date out of range"))
+            })
+            .transpose()
+        })
+        .collect::<Result<Date32Array>>()?;
+    array_to_columnar(Arc::new(res), &[text_col])
+}
+
+/// The time zone of the result of [`map_parse_iso8601_timestamp`] for the texts `text_col`: the
+/// offset of a constant text, and else UTC, which the session replaces by its time zone (see
+/// [`iso8601_has_offset`]). Invalid texts are reported when the rows are mapped.
+pub(super) fn iso8601_result_zone(text_col: Option<&ScalarValue>) -> Arc<str> {
+    let offset = match text_col {
+        Some(ScalarValue::Utf8(Some(text))) => iso8601::parse_datetime(text)
+            .ok()
+            .and_then(|datetime| datetime.offset),
+        _ => None,
+    };
+    offset
+        // The offsets in seconds are not those of a time zone
+        .filter(|offset| offset.num_seconds() % 60 == 0 && offset.subsec_nanos() == 0)
+        .and_then(|offset| offset_key(offset.num_minutes()))
+        .and_then(|key| arrow_zone(&key).ok())
+        .unwrap_or_else(|| UTC_TYPE.into())
+}
+
+/// Whether the text `text_col` is a constant with an offset, the result of
+/// [`map_parse_iso8601_timestamp`] being in the time zone of the session otherwise.
+pub(crate) fn iso8601_has_offset(text_col: Option<&ScalarValue>) -> bool {
+    match text_col {
+        Some(ScalarValue::Utf8(Some(text))) => {
+            iso8601::parse_datetime(text).is_ok_and(|datetime| datetime.offset.is_some())
+        }
+        _ => false,
+    }
+}
+
+/// Parse the ISO 8601 date-times of `text_col`, see [`iso8601::parse_datetime`], into timestamps
+/// of `return_type`, truncated towards the past. The texts without an offset are local date-times
+/// in the time zone of `return_type`, e.g. that of the session.
+pub(super) fn map_parse_iso8601_timestamp(
+    text_col: &ColumnarValue,
+    return_type: &DataType,
+) -> Result<ColumnarValue> {
+    let DataType::Timestamp(unit, zone) = return_type else {
+        return exec_err!("Expected a timestamp, got {return_type}");
+    };
+    let tz = parse_zone(zone_name(zone.as_deref()))?;
+    let len = columnar_len(&[text_col]);
+    let text_arr = text_col.to_owned().into_array(len)?;
+    let res = as_string_array(&text_arr)?
+        .iter()
+        .map(|text| {
+            text.map(|text| {
+                let datetime = iso8601::parse_datetime(text)?;
+                let utc = match datetime.offset {
+                    Some(_) => datetime.utc(),
+                    None => from_local(&tz, &datetime.local).naive_utc(),
+                };
+                epoch_value(&utc, *unit)
+            })
+            .transpose()
+        })
+        .collect::<Result<Int64Array>>()?;
+    let res = cast(&res, return_type)?;
+    array_to_columnar(res, &[text_col])
+}
+
+/// Format the dates and timestamps of `col` as ISO 8601 texts, see [`iso8601::format_datetime`]:
+/// the timestamps with the fraction of a second of their precision, and those with a time zone
+/// with their offset from UTC.
+pub(super) fn map_format_iso8601(col: &ColumnarValue) -> Result<ColumnarValue> {
+    let len = columnar_len(&[col]);
+    let arr = col.to_owned().into_array(len)?;
+    let res = match arr.data_type() {
+        DataType::Date32 => as_date32_array(&arr)?
+            .iter()
+            .map(|days| {
+                days.map(|days| {
+                    DateTime::UNIX_EPOCH
+                        .date_naive()
+                        .checked_add_signed(TimeDelta::days(days.into()))
+                        .map(|date| iso8601::format_date(&date))
+                        .ok_or_else(|| exec_datafusion_err!("Date out of range"))
+                })
+                .transpose()
+            })
+            .collect::<Result<StringArray>>()?,
+        DataType::Timestamp(unit, zone) => {
+            let tz = zone.as_deref().map(parse_zone).transpose()?;
+            let digits = match unit {
+                TimeUnit::Second => 0,
+                TimeUnit::Millisecond => 3,
+                TimeUnit::Microsecond => 6,
+                TimeUnit::Nanosecond => 9,
+            };
+            let values = cast(&arr, &DataType::Int64)?;
+            values
+                .as_primitive::<Int64Type>()
+                .iter()
+                .map(|value| {
+                    value
+                        .map(|value| {
+                            let utc = naive_datetime(value, *unit)?;
+                            Ok(match tz {
+                                Some(tz) => {
+                                    let datetime = tz.from_utc_datetime(&utc);
+                                    let offset = datetime.offset().fix().local_minus_utc();
+                                    let local = datetime.naive_local();
+                                    iso8601::format_datetime(&local, digits, Some(offset))
+                                }
+                                None => iso8601::format_datetime(&utc, digits, None),
+                            })
+                        })
+                        .transpose()
+                })
+                .collect::<Result<StringArray>>()?
+        }
+        other => return exec_err!("Expected a date or a timestamp, got {other}"),
+    };
+    array_to_columnar(Arc::new(res), &[col])
+}
+
+/// The timestamps in milliseconds, in UTC, at the numbers of seconds since the epoch of
+/// `seconds_col`, rounded half up as in Trino. The session converts them to its time zone.
+pub(super) fn map_unixtime(seconds_col: &ColumnarValue) -> Result<ColumnarValue> {
+    let len = columnar_len(&[seconds_col]);
+    let seconds_arr = seconds_col.to_owned().into_array(len)?;
+    let res = seconds_arr
+        .as_primitive::<Float64Type>()
+        .iter()
+        .map(|seconds| seconds.map(unixtime_millis).transpose())
+        .collect::<Result<Int64Array>>()?;
+    let res = cast(
+        &res,
+        &DataType::Timestamp(TimeUnit::Millisecond, Some(UTC_TYPE.into())),
+    )?;
+    array_to_columnar(res, &[seconds_col])
+}
+
+/// The time zone of the result of [`map_unixtime_offset`] for the offsets `hours_col` and
+/// `minutes_col`: the offset of constants, and else UTC. Invalid offsets are reported when the rows
+/// are mapped.
+pub(super) fn offset_result_zone(
+    hours_col: Option<&ScalarValue>,
+    minutes_col: Option<&ScalarValue>,
+) -> Arc<str> {
+    match (hours_col, minutes_col) {
+        (Some(ScalarValue::Int64(Some(hours))), Some(ScalarValue::Int64(Some(minutes)))) => {
+            offset_key(offset_minutes(*hours, *minutes))
+                .and_then(|key| arrow_zone(&key).ok())
+                .unwrap_or_else(|| UTC_TYPE.into())
+        }
+        _ => UTC_TYPE.into(),
+    }
+}
+
+/// The timestamps in milliseconds at the numbers of seconds since the epoch of `seconds_col`, as
+/// by [`map_unixtime`], at the offsets of `hours_col` hours and `minutes_col` minutes from UTC, the
+/// result being in the time zone [`offset_result_zone`].
+pub(super) fn map_unixtime_offset(
+    seconds_col: &ColumnarValue,
+    hours_col: &ColumnarValue,
+    minutes_col: &ColumnarValue,
+) -> Result<ColumnarValue> {
+    let zone = match (hours_col, minutes_col) {
+        (ColumnarValue::Scalar(hours), ColumnarValue::Scalar(minutes)) => {
+            offset_result_zone(Some(hours), Some(minutes))
+        }
+        _ => offset_result_zone(None, None),
+    };
+    let args = [seconds_col, hours_col, minutes_col];
+    let len = columnar_len(&args);
+    let arrays = args
+        .iter()
+        .map(|arg| arg.to_array(len))
+        .collect::<Result<Vec<_>>>()?;
+    let seconds = arrays[0].as_primitive::<Float64Type>();
+    let hours = arrays[1].as_primitive::<Int64Type>();
+    let minutes = arrays[2].as_primitive::<Int64Type>();
+    let res = (0..len)
+        .map(|row| {
+            if arrays.iter().any(|array| array.is_null(row)) {
+                return Ok(None);
+            }
+            let offset = offset_minutes(hours.value(row), minutes.value(row));
+            if offset_key(offset).is_none() {
+                return exec_err!("Invalid offset minutes {offset}");
+            }
+            unixtime_millis(seconds.value(row)).map(Some)
+        })
+        .collect::<Result<Int64Array>>()?;
+    let res = cast(
+        &res,
+        &DataType::Timestamp(TimeUnit::Millisecond, Some(zone)),
+    )?;
+    array_to_columnar(res, &args)
+}
+
+/// Map `offset_part` over the offsets from UTC, in minutes, of the timestamps of `ts_col` in their
+/// time zone. The times with a time zone are in UTC.
+pub(super) fn map_zone_offset(
+    ts_col: &ColumnarValue,
+    offset_part: fn(/*minutes:*/ i64) -> i64,
+) -> Result<ColumnarValue> {
+    let len = columnar_len(&[ts_col]);
+    let ts_arr = ts_col.to_owned().into_array(len)?;
+    let res = match ts_arr.data_type() {
+        DataType::Timestamp(unit, zone) => {
+            let tz = parse_zone(zone_name(zone.as_deref()))?;
+            let values = cast(&ts_arr, &DataType::Int64)?;
+            values
+                .as_primitive::<Int64Type>()
+                .iter()
+                .map(|value| {
+                    value
+                        .map(|value| {
+                            let utc = naive_datetime(value, *unit)?;
+                            let offset = tz.offset_from_utc_datetime(&utc).fix();
+                            Ok(offset_part(i64::from(offset.local_minus_utc() / 60)))
+                        })
+                        .transpose()
+                })
+                .collect::<Result<Int64Array>>()?
+        }
+        DataType::Time32(_) | DataType::Time64(_) => (0..len)
+            .map(|row| ts_arr.is_valid(row).then(|| offset_part(0)))
+            .collect::<Int64Array>(),
+        other => return exec_err!("Expected a timestamp or a time, got {other}"),
+    };
+    array_to_columnar(Arc::new(res), &[ts_col])
+}
+
+/// The number of `unit`s in a second.
+fn units_per_second(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// The number of `unit`s since the epoch of the date-time `utc`, truncated towards the past.
+fn epoch_value(utc: &NaiveDateTime, unit: TimeUnit) -> Result<i64> {
+    let since_epoch = *utc - DateTime::UNIX_EPOCH.naive_utc();
+    let nanos = i128::from(since_epoch.num_seconds()) * 1_000_000_000
+        + i128::from(since_epoch.subsec_nanos());
+    let unit_nanos = 1_000_000_000 / i128::from(units_per_second(unit));
+    i64::try_from(nanos.div_euclid(unit_nanos))
+        .map_err(|_| exec_datafusion_err!("Timestamp out of range"))
+}
+
+/// The milliseconds since the epoch at `seconds` seconds since the epoch, rounded half up.
+fn unixtime_millis(seconds: f64) -> Result<i64> {
+    let millis = (seconds * 1000.0 + 0.5).floor();
+    // As a timestamp, which excludes the infinities and NaN
+    match millis.is_finite() && naive_datetime(millis as i64, TimeUnit::Millisecond).is_ok() {
+        true => Ok(millis as i64),
+        false => exec_err!("Timestamp out of range: {seconds}"),
+    }
+}
+
+/// The offset of `hours` hours and `minutes` minutes from UTC, in minutes.
+fn offset_minutes(hours: i64, minutes: i64) -> i64 {
+    hours.saturating_mul(60).saturating_add(minutes)
+}
+
+/// The date-time at `value` `unit`s since the epoch.
+fn naive_datetime(value: i64, unit: TimeUnit) -> Result<NaiveDateTime> {
+    let per_second = units_per_second(unit);
+    let nanos = value.rem_euclid(per_second) * (1_000_000_000 / per_second);
+    DateTime::from_timestamp(value.div_euclid(per_second), nanos as u32)
+        .map(|datetime| datetime.naive_utc())
+        .ok_or_else(|| exec_datafusion_err!("Timestamp out of range"))
+}
+
+/// Map `rowfun` over the rows of `values` and their patterns, passing it the index of the row and
+/// its compiled pattern.
+fn map_pattern_rows<A, T, F>(
+    values: &dyn Array,
+    pattern_col: &ColumnarValue,
+    compile: fn(&str) -> Result<DateTimePattern>,
+    mut rowfun: F,
+) -> Result<ArrayRef>
+where
+    A: Array + FromIterator<Option<T>> + 'static,
+    F: FnMut(/*row:*/ usize, /*pattern:*/ &DateTimePattern) -> Result<Option<T>>,
+{
+    let len = values.len();
+    let res = match pattern_col {
+        ColumnarValue::Scalar(pattern_scalar) => {
+            let pattern_arr = pattern_scalar.to_array()?;
+            let pattern_str = as_string_array(&pattern_arr)?;
+            if pattern_str.is_null(0) {
+                (0..len).map(|_| None).collect::<A>()
+            } else {
+                // Compiled at the first value, so that NULL values give NULL for any pattern
+                let mut pattern = None;
+                (0..len)
+                    .map(|row| {
+                        if values.is_null(row) {
+                            return Ok(None);
+                        }
+                        let pattern = match pattern {
+                            Some(ref pattern) => pattern,
+                            None => pattern.insert(compile(pattern_str.value(0))?),
+                        };
+                        rowfun(row, pattern)
+                    })
+                    .collect::<Result<A>>()?
+            }
+        }
+        ColumnarValue::Array(pattern_arr) => {
+            let compile = |pattern: &str| compile(pattern).map(Arc::new);
+            let mut compiled = RowfunCache::new(&compile);
+            let patterns = as_string_array(pattern_arr)?;
+            (0..len)
+                .map(|row| {
+                    if values.is_null(row) || patterns.is_null(row) {
+                        return Ok(None);
+                    }
+                    let pattern = compiled.get(patterns.value(row))?;
+                    rowfun(row, &pattern)
+                })
+                .collect::<Result<A>>()?
+        }
+    };
+    Ok(Arc::new(res))
+}
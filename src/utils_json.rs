@@ -0,0 +1,120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helper functors for the JSON functions, mapping a row function over a column of JSON texts
+//! (`json` or `varchar`) and the other columns of the arguments.
+//! - As for the regexp functors, a scalar JSON path is parsed once, and the paths of a column of
+//!   JSON paths are kept in a bounded cache, so the rows sharing a path parse it only once. Paths
+//!   are only parsed for rows with a JSON text, so that invalid paths give NULL for NULL texts.
+//! - Rows with a NULL argument are NULL.
+//! - The result is a scalar if all the columns are scalars, and else an array with the length of the
+//!   array columns.
+
+use arrow::array::{Array, ArrayRef};
+use datafusion::common::cast::as_string_array;
+use datafusion::common::Result;
+use datafusion::logical_expr::ColumnarValue;
+use std::sync::Arc;
+
+use crate::distinct::{self, JsonPath as JsonPathType};
+use crate::json::Json;
+use crate::jsonpath::JsonPath;
+use crate::utils::{array_to_columnar, columnar_len, RowfunCache};
+
+/// Map `rowfun` over the rows of a column of JSON texts and the columns `others`, passing it the
+/// text and the index of the row, e.g. to read the values of the other columns.
+pub(super) fn map_json_rows<A, T, F>(
+    json_col: &ColumnarValue,
+    others: &[&ColumnarValue],
+    mut rowfun: F,
+) -> Result<ColumnarValue>
+where
+    A: Array + FromIterator<Option<T>> + 'static,
+    F: FnMut(/*json:*/ &str, /*row:*/ usize) -> Result<Option<T>>,
+{
+    let columns = [&[json_col], others].concat();
+    let len = columnar_len(&columns);
+    let arrays = columns
+        .iter()
+        .map(|c| (*c).clone().into_array(len))
+        .collect::<Result<Vec<_>>>()?;
+    let json = as_string_array(&arrays[0])?;
+    let res = (0..len)
+        .map(|row| {
+            if arrays.iter().any(|a| a.is_null(row)) {
+                return Ok(None);
+            }
+            rowfun(json.value(row), row)
+        })
+        .collect::<Result<A>>()?;
+    array_to_columnar(Arc::new(res) as ArrayRef, &columns)
+}
+
+/// Map `rowfun` over a column of JSON texts and a column of JSON paths. Texts that are not valid
+/// JSON give NULL.
+pub(super) fn map_json_path<A, T, F>(
+    json_col: &ColumnarValue,
+    path_col: &ColumnarValue,
+    rowfun: F,
+) -> Result<ColumnarValue>
+where
+    A: Array + FromIterator<Option<T>> + 'static,
+    F: Fn(/*json:*/ &Json, /*path:*/ &JsonPath) -> Option<T>,
+{
+    let len = columnar_len(&[json_col, path_col]);
+    let json_arr = json_col.to_owned().into_array(len)?;
+    let json = as_string_array(&json_arr)?;
+    let parse = |row: usize| Json::parse(json.value(row)).ok();
+    let res = match path_col {
+        ColumnarValue::Scalar(path_scalar) => {
+            let path_arr = distinct::unwrap_scalar::<JsonPathType>(path_scalar)?;
+            if path_arr.is_null(0) {
+                (0..len).map(|_| None).collect::<A>()
+            } else {
+                // Parsed at the first JSON text, so that NULL texts give NULL for any path
+                let mut path = None;
+                (0..len)
+                    .map(|row| {
+                        if json.is_null(row) {
+                            return Ok(None);
+                        }
+                        let path = match path {
+                            Some(ref path) => path,
+                            None => path.insert(JsonPath::parse(path_arr.value(0))?),
+                        };
+                        Ok(parse(row).and_then(|value| rowfun(&value, path)))
+                    })
+                    .collect::<Result<A>>()?
+            }
+        }
+        ColumnarValue::Array(path_arr) => {
+            let compile = |path: &str| JsonPath::parse(path).map(Arc::new);
+            let mut compiled = RowfunCache::new(&compile);
+            let paths = distinct::unwrap::<JsonPathType>(path_arr.as_ref())?;
+            (0..len)
+                .map(|row| {
+                    if json.is_null(row) || paths.is_null(row) {
+                        return Ok(None);
+                    }
+                    let path = compiled.get(paths.value(row))?;
+                    Ok(parse(row).and_then(|value| rowfun(&value, &path)))
+                })
+                .collect::<Result<A>>()?
+        }
+    };
+    array_to_columnar(Arc::new(res) as ArrayRef, &[json_col, path_col])
+}
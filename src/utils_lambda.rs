@@ -0,0 +1,271 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::type_signature::map_of;
+use arrow::array::{
+    new_empty_array, Array, ArrayRef, BooleanArray, ListArray, MapArray, StructArray, UInt32Array,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::{cast, filter, take};
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::cast::{as_boolean_array, as_list_array, as_map_array};
+use datafusion::common::{exec_err, internal_err, Result};
+use std::sync::Arc;
+
+/// The elements to pass to a lambda expression, in order, out of rows whose ranges are given by
+/// `offsets`: the indices of the elements in the original values (`None` if they are all of them,
+/// in order), the row of each element, and the offsets of the rows in the elements. The elements
+/// of NULL rows are skipped.
+fn flatten(
+    offsets: &OffsetBuffer<i32>,
+    nulls: Option<&NullBuffer>,
+    len: usize,
+) -> (Option<UInt32Array>, UInt32Array, OffsetBuffer<i32>) {
+    let all = nulls.is_none_or(|n| n.null_count() == 0)
+        && offsets.first() == Some(&0)
+        && offsets.last().is_some_and(|&last| last as usize == len);
+    let mut indices = vec![];
+    let mut rows = vec![];
+    let mut lengths = vec![];
+    for (row, range) in offsets.windows(2).enumerate() {
+        let (start, end) = (range[0] as u32, range[1] as u32);
+        if nulls.is_some_and(|n| n.is_null(row)) {
+            lengths.push(0);
+            continue;
+        }
+        if !all {
+            indices.extend(start..end);
+        }
+        rows.extend(std::iter::repeat_n(row as u32, (end - start) as usize));
+        lengths.push((end - start) as usize);
+    }
+    let indices = (!all).then(|| UInt32Array::from(indices));
+    (indices, rows.into(), OffsetBuffer::from_lengths(lengths))
+}
+
+fn take_all(values: &ArrayRef, indices: Option<&UInt32Array>) -> Result<ArrayRef> {
+    match indices {
+        Some(indices) => Ok(take(values, indices, None)?),
+        None => Ok(Arc::clone(values)),
+    }
+}
+
+/// The offsets of the rows once the elements of the rows `offsets` are filtered by `keep`.
+fn filtered_offsets(offsets: &OffsetBuffer<i32>, keep: &BooleanArray) -> OffsetBuffer<i32> {
+    let lengths = offsets.windows(2).map(|range| {
+        (range[0] as usize..range[1] as usize)
+            .filter(|&i| keep.is_valid(i) && keep.value(i))
+            .count()
+    });
+    OffsetBuffer::from_lengths(lengths)
+}
+
+/// The elements of the arrays of a batch of rows, to pass to lambda expressions. Lambda
+/// expressions are not called on the elements of NULL arrays.
+pub(super) struct ListElements {
+    /// The elements of the arrays, in order
+    pub(super) values: ArrayRef,
+    /// The row of each element
+    pub(super) rows: UInt32Array,
+    /// The offsets of the arrays in `values`
+    pub(super) offsets: OffsetBuffer<i32>,
+    pub(super) nulls: Option<NullBuffer>,
+}
+
+impl ListElements {
+    pub(super) fn new(array: &ArrayRef) -> Result<Self> {
+        let array = match array.data_type() {
+            DataType::List(_) => Arc::clone(array),
+            DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+                cast(array, &DataType::List(Arc::clone(field)))?
+            }
+            DataType::Null => {
+                let offsets = OffsetBuffer::new_zeroed(array.len());
+                return Ok(Self {
+                    values: new_empty_array(&DataType::Null),
+                    rows: UInt32Array::from(Vec::<u32>::new()),
+                    offsets,
+                    nulls: Some(NullBuffer::new_null(array.len())),
+                });
+            }
+            other => return internal_err!("Expected an array, got {other}"),
+        };
+        let list = as_list_array(&array)?;
+        let (indices, rows, offsets) = flatten(list.offsets(), list.nulls(), list.values().len());
+        Ok(Self {
+            values: take_all(list.values(), indices.as_ref())?,
+            rows,
+            offsets,
+            nulls: list.nulls().cloned(),
+        })
+    }
+
+    /// The arrays of the rows whose elements are now `values`.
+    pub(super) fn to_list(&self, values: ArrayRef) -> Result<ArrayRef> {
+        let field = Field::new_list_field(values.data_type().clone(), true);
+        let list = ListArray::try_new(
+            Arc::new(field),
+            self.offsets.clone(),
+            values,
+            self.nulls.clone(),
+        )?;
+        Ok(Arc::new(list))
+    }
+
+    /// The arrays of the elements for which `keep` is true.
+    pub(super) fn filter(&self, keep: &ArrayRef) -> Result<ArrayRef> {
+        let keep = as_boolean_array(keep)?;
+        let values = filter(&self.values, keep)?;
+        let field = Field::new_list_field(values.data_type().clone(), true);
+        let list = ListArray::try_new(
+            Arc::new(field),
+            filtered_offsets(&self.offsets, keep),
+            values,
+            self.nulls.clone(),
+        )?;
+        Ok(Arc::new(list))
+    }
+
+    /// The length of the array of each row, 0 for NULL arrays.
+    pub(super) fn lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.offsets
+            .windows(2)
+            .map(|range| (range[1] - range[0]) as usize)
+    }
+
+    pub(super) fn is_null(&self, row: usize) -> bool {
+        self.nulls.as_ref().is_some_and(|n| n.is_null(row))
+    }
+}
+
+/// The entries of the maps of a batch of rows, to pass to lambda expressions. Lambda
+/// expressions are not called on the entries of NULL maps.
+pub(super) struct MapEntries {
+    pub(super) keys: ArrayRef,
+    pub(super) values: ArrayRef,
+    /// The row of each entry
+    pub(super) rows: UInt32Array,
+    /// The offsets of the maps in `keys` and `values`
+    pub(super) offsets: OffsetBuffer<i32>,
+    pub(super) nulls: Option<NullBuffer>,
+}
+
+impl MapEntries {
+    pub(super) fn new(array: &ArrayRef) -> Result<Self> {
+        if array.data_type() == &DataType::Null {
+            return Ok(Self {
+                keys: new_empty_array(&DataType::Null),
+                values: new_empty_array(&DataType::Null),
+                rows: UInt32Array::from(Vec::<u32>::new()),
+                offsets: OffsetBuffer::new_zeroed(array.len()),
+                nulls: Some(NullBuffer::new_null(array.len())),
+            });
+        }
+        let map = as_map_array(array)?;
+        let (indices, rows, offsets) = flatten(map.offsets(), map.nulls(), map.keys().len());
+        Ok(Self {
+            keys: take_all(map.keys(), indices.as_ref())?,
+            values: take_all(map.values(), indices.as_ref())?,
+            rows,
+            offsets,
+            nulls: map.nulls().cloned(),
+        })
+    }
+
+    /// The maps of the rows whose entries are now `keys` and `values`.
+    pub(super) fn to_map(&self, keys: ArrayRef, values: ArrayRef) -> Result<ArrayRef> {
+        map_array(keys, values, self.offsets.clone(), self.nulls.clone())
+    }
+
+    /// The maps of the entries for which `keep` is true.
+    pub(super) fn filter(&self, keep: &ArrayRef) -> Result<ArrayRef> {
+        let keep = as_boolean_array(keep)?;
+        map_array(
+            filter(&self.keys, keep)?,
+            filter(&self.values, keep)?,
+            filtered_offsets(&self.offsets, keep),
+            self.nulls.clone(),
+        )
+    }
+}
+
+fn map_array(
+    keys: ArrayRef,
+    values: ArrayRef,
+    offsets: OffsetBuffer<i32>,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef> {
+    let DataType::Map(field, _) = map_of(keys.data_type().clone(), values.data_type().clone())
+    else {
+        return internal_err!("Expected a map type");
+    };
+    let DataType::Struct(fields) = field.data_type() else {
+        return internal_err!("Expected the entries of a map to be a struct");
+    };
+    if keys.null_count() > 0 {
+        return exec_err!("map key cannot be null");
+    }
+    let entries = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+    let map = MapArray::try_new(Arc::clone(&field), offsets, entries, nulls, false)?;
+    Ok(Arc::new(map))
+}
+
+/// How the results of a predicate over the elements of an array are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Quantifier {
+    /// `any_match`
+    Any,
+    /// `all_match`
+    All,
+    /// `none_match`
+    None,
+}
+
+impl Quantifier {
+    /// Combine the results `matches` of the predicate over the elements of the arrays, NULL
+    /// results making the outcome NULL unless it is decided by the other ones: `any_match` is
+    /// true if the predicate is true for some element, else NULL if it is NULL for some element,
+    /// else false. The outcome is NULL for NULL arrays.
+    pub(super) fn combine(&self, elements: &ListElements, matches: &ArrayRef) -> Result<ArrayRef> {
+        let matches = as_boolean_array(matches)?;
+        let (decisive, decided) = match self {
+            Quantifier::Any => (true, true),
+            Quantifier::All => (false, false),
+            Quantifier::None => (true, false),
+        };
+        let result = elements
+            .offsets
+            .windows(2)
+            .enumerate()
+            .map(|(row, range)| {
+                if elements.is_null(row) {
+                    return None;
+                }
+                let mut unknown = false;
+                for i in range[0] as usize..range[1] as usize {
+                    if matches.is_null(i) {
+                        unknown = true;
+                    } else if matches.value(i) == decisive {
+                        return Some(decided);
+                    }
+                }
+                (!unknown).then_some(!decided)
+            })
+            .collect::<BooleanArray>();
+        Ok(Arc::new(result))
+    }
+}
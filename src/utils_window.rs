@@ -0,0 +1,208 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, ArrayRef, BooleanBuilder, Float64Array, UInt64Builder};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{cast, take};
+use arrow::datatypes::DataType;
+use datafusion::common::cast::{as_float64_array, as_int64_array};
+use datafusion::common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion::logical_expr::function::PartitionEvaluatorArgs;
+use datafusion::logical_expr::PartitionEvaluator;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The indices of the non-NULL values of `values`.
+fn valid_indices(values: &ArrayRef) -> Vec<usize> {
+    match values.logical_nulls() {
+        Some(nulls) => nulls.valid_indices().collect(),
+        None => (0..values.len()).collect(),
+    }
+}
+
+/// The evaluator of `lag` and `lead` (if `lead`): the value `offset` rows before (or after) the
+/// current row of the partition, or the default value if there is no such row. The offset and
+/// the default value are evaluated per row, and the offset defaults to 1. With
+/// `IGNORE NULLS`, only the rows whose value is not NULL are counted.
+#[derive(Debug)]
+pub(super) struct OffsetEvaluator {
+    lead: bool,
+    ignore_nulls: bool,
+}
+
+impl OffsetEvaluator {
+    pub(super) fn new(lead: bool, args: &PartitionEvaluatorArgs) -> Self {
+        Self {
+            lead,
+            ignore_nulls: args.ignore_nulls(),
+        }
+    }
+
+    /// The row `offset` rows away from `row`, given the non-NULL rows `valid` if NULLs are
+    /// ignored.
+    fn target(&self, row: usize, offset: usize, num_rows: usize, valid: &[usize]) -> Option<usize> {
+        if self.ignore_nulls && offset > 0 {
+            if self.lead {
+                let after = valid.partition_point(|&i| i <= row);
+                valid.get(after.checked_add(offset - 1)?).copied()
+            } else {
+                let before = valid.partition_point(|&i| i < row);
+                before.checked_sub(offset).map(|i| valid[i])
+            }
+        } else if self.lead {
+            row.checked_add(offset).filter(|&target| target < num_rows)
+        } else {
+            row.checked_sub(offset)
+        }
+    }
+}
+
+impl PartitionEvaluator for OffsetEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        let value = &values[0];
+        let offsets = values.get(1).map(|o| as_int64_array(o)).transpose()?;
+        let valid = if self.ignore_nulls {
+            valid_indices(value)
+        } else {
+            vec![]
+        };
+        let mut indices = UInt64Builder::with_capacity(num_rows);
+        let mut use_default = BooleanBuilder::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let offset = match offsets {
+                Some(offsets) if offsets.is_null(row) => None,
+                Some(offsets) => Some(offsets.value(row)),
+                None => Some(1),
+            };
+            // A NULL offset gives NULL, not the default value
+            let Some(offset) = offset else {
+                indices.append_null();
+                use_default.append_value(false);
+                continue;
+            };
+            let Ok(offset) = usize::try_from(offset) else {
+                return exec_err!("Offset must be at least 0");
+            };
+            let target = self.target(row, offset, num_rows, &valid);
+            indices.append_option(target.map(|t| t as u64));
+            use_default.append_value(target.is_none());
+        }
+        let result = take(value, &indices.finish(), None)?;
+        match values.get(2) {
+            Some(default) => Ok(zip(&use_default.finish(), default, &result)?),
+            None => Ok(result),
+        }
+    }
+}
+
+/// The evaluator of `nth_value`: the value of the `offset`-th row (from 1) of the window frame,
+/// counted from its last row if `from_last`, and NULL if the frame has fewer rows. The offset is
+/// evaluated per row. With `IGNORE NULLS`, only the rows whose value is not NULL are counted.
+///
+/// The evaluator is reversible: `nth_value(x, n) FROM LAST` is `nth_value(x, n)` over the
+/// reversed window, so `from_last` is the `is_reversed` flag of its arguments.
+#[derive(Debug)]
+pub(super) struct NthValueEvaluator {
+    ignore_nulls: bool,
+    from_last: bool,
+    /// The index in the partition of the row being evaluated
+    row: usize,
+    /// The non-NULL rows of the partition, if NULLs are ignored
+    valid: Option<Vec<usize>>,
+}
+
+impl NthValueEvaluator {
+    pub(super) fn new(args: &PartitionEvaluatorArgs) -> Self {
+        Self {
+            ignore_nulls: args.ignore_nulls(),
+            from_last: args.is_reversed(),
+            row: 0,
+            valid: None,
+        }
+    }
+}
+
+impl PartitionEvaluator for NthValueEvaluator {
+    /// Without bounded execution, the rows of the whole partition are evaluated in order with
+    /// the same `values`, so the row is counted and the non-NULL rows are computed once.
+    fn evaluate(&mut self, values: &[ArrayRef], range: &Range<usize>) -> Result<ScalarValue> {
+        let value = &values[0];
+        let offsets = as_int64_array(&values[1])?;
+        let row = self.row;
+        self.row += 1;
+        if offsets.is_null(row) {
+            return ScalarValue::try_from(value.data_type());
+        }
+        let n = match usize::try_from(offsets.value(row)) {
+            Ok(n) if n >= 1 => n - 1,
+            _ => return exec_err!("Offset must be at least 1"),
+        };
+        let index = if self.ignore_nulls {
+            let valid = self.valid.get_or_insert_with(|| valid_indices(value));
+            let start = valid.partition_point(|&i| i < range.start);
+            let end = valid.partition_point(|&i| i < range.end);
+            let frame = &valid[start..end];
+            if self.from_last {
+                frame.len().checked_sub(n + 1).map(|i| frame[i])
+            } else {
+                frame.get(n).copied()
+            }
+        } else if n >= range.len() {
+            None
+        } else if self.from_last {
+            Some(range.end - 1 - n)
+        } else {
+            Some(range.start + n)
+        };
+        match index {
+            Some(index) => ScalarValue::try_from_array(value, index),
+            None => ScalarValue::try_from(value.data_type()),
+        }
+    }
+
+    fn uses_window_frame(&self) -> bool {
+        true
+    }
+}
+
+/// The return type of `ratio_to_report`, a DOUBLE for any numeric argument.
+pub(super) fn ratio_to_report_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    match arg_types.first() {
+        Some(arg) if arg.is_numeric() || arg == &DataType::Null => Ok(DataType::Float64),
+        Some(arg) => plan_err!("ratio_to_report does not support {arg}"),
+        None => plan_err!("ratio_to_report expects an argument"),
+    }
+}
+
+/// The evaluator of `ratio_to_report`: the ratio of the value of each row to the sum of the
+/// values of the partition, whatever the window frame. It is NULL if the value is NULL or the
+/// sum is 0.
+#[derive(Debug)]
+pub(super) struct RatioToReportEvaluator;
+
+impl PartitionEvaluator for RatioToReportEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], _num_rows: usize) -> Result<ArrayRef> {
+        let values = cast(&values[0], &DataType::Float64)?;
+        let values = as_float64_array(&values)?;
+        let sum = values.iter().flatten().sum::<f64>();
+        let ratios = values
+            .iter()
+            .map(|v| v.filter(|_| sum != 0.0).map(|v| v / sum))
+            .collect::<Float64Array>();
+        Ok(Arc::new(ratios))
+    }
+}
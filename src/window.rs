@@ -0,0 +1,454 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Window dialect functions.
+//!
+//! As for scalar functions (see [`crate::overload`]), every overload of a window function is
+//! generated as its own `WindowUDFImpl` (e.g. `lag_1`, `lag_1_bigint`, ...), whose hand-written
+//! `<overload>_partition_evaluator` and `<overload>_return_type` functions define the
+//! [`PartitionEvaluator`] and the result type. A function that can be evaluated over the
+//! reversed window (e.g. `nth_value`, whose evaluator then counts from the end of the frame) also
+//! defines `<overload>_reverse_expr`. A [`OverloadedUDWF`] bundles the overloads of one name and
+//! delegates to the one matching the argument types.
+//!
+//! The `IGNORE NULLS` clause reaches the evaluators as
+//! [`PartitionEvaluatorArgs::ignore_nulls`].
+//!
+//! Unlike the arguments of scalar and aggregate functions, those of window functions are not
+//! coerced by DataFusion: the [`OverloadedUDWF`] casts them to the types of the resolved overload
+//! before they reach its evaluator.
+//!
+//! DataFusion does not parse the `FROM FIRST` and `FROM LAST` clauses of window functions, e.g.
+//! `nth_value(x, 2) FROM LAST OVER (ORDER BY k)`: [`crate::sql`] marks the calls with
+//! [`mark_from_last`] before parsing them, and [`rewrite_from_last`] then evaluates them over
+//! the reversed window.
+
+use crate::overload::{overload_documentation, resolve_overload, Overload};
+use crate::type_signature::{FunctionSignature, Resolution};
+use arrow::array::ArrayRef;
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field};
+use datafusion::common::{plan_datafusion_err, DataFusionError, Result, ScalarValue};
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::window_state::WindowAggState;
+use datafusion::logical_expr::{
+    Documentation, PartitionEvaluator, ReversedUDWF, Signature, Volatility, WindowUDF,
+    WindowUDFImpl,
+};
+use datafusion::sql::sqlparser::ast::{
+    visit_expressions_mut, Expr as SqlExpr, Ident, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowSpec, WindowType,
+};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::tokenizer::{Location, Token, TokenWithSpan, Tokenizer};
+use std::any::Any;
+use std::borrow::Cow;
+use std::ops::{ControlFlow, Range};
+use std::sync::Arc;
+
+/// The suffix of the names of the window functions called `FROM LAST`, see [`mark_from_last`].
+const FROM_LAST: &str = " FROM LAST";
+
+/// One overload of a dialect window function.
+#[derive(Debug, Clone)]
+pub struct WindowOverload {
+    pub dialect: &'static str,
+    /// The generated name of the overload, e.g. `lag_1_bigint`
+    pub name: &'static str,
+    pub udwf: Arc<WindowUDF>,
+    pub signature: FunctionSignature,
+}
+
+impl Overload for WindowOverload {
+    type Function = WindowUDF;
+
+    fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn function_name(&self) -> &str {
+        self.udwf.name()
+    }
+
+    fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Option<DataType> {
+        let field = self
+            .udwf
+            .field(WindowUDFFieldArgs::new(arg_types, self.name));
+        field.ok().map(|f| f.data_type().clone())
+    }
+
+    fn dispatcher(name: &str, overloads: Vec<Self>) -> Arc<WindowUDF> {
+        Arc::new(WindowUDF::new_from_impl(OverloadedUDWF::new(
+            name, overloads,
+        )))
+    }
+}
+
+/// A window UDF dispatching to the overload of a function best matching its argument types.
+#[derive(Debug, Clone)]
+pub struct OverloadedUDWF {
+    name: String,
+    signature: Signature,
+    /// The overloads, in declaration order, see [`resolve_overload`].
+    overloads: Vec<WindowOverload>,
+    documentation: Option<Documentation>,
+}
+
+impl OverloadedUDWF {
+    /// Create the dispatcher for `overloads`, which must all share the name `name`.
+    pub fn new(name: &str, overloads: Vec<WindowOverload>) -> Self {
+        let volatility = overloads
+            .iter()
+            .map(|o| o.udwf.signature().volatility)
+            .max_by_key(|v| match v {
+                Volatility::Immutable => 0,
+                Volatility::Stable => 1,
+                Volatility::Volatile => 2,
+            })
+            .unwrap_or(Volatility::Immutable);
+        let documentation =
+            overload_documentation(overloads.iter().filter_map(|o| o.udwf.documentation()));
+        Self {
+            name: name.to_string(),
+            signature: Signature::user_defined(volatility),
+            overloads,
+            documentation,
+        }
+    }
+
+    /// The overloads of this function, in declaration order.
+    pub fn overloads(&self) -> &[WindowOverload] {
+        &self.overloads
+    }
+
+    /// Pick the overload best matching `arg_types`, see [`resolve_overload`].
+    pub fn resolve(&self, arg_types: &[DataType]) -> Result<(&Arc<WindowUDF>, Resolution)> {
+        resolve_overload(&self.name, &self.overloads, arg_types).map(|(o, res)| (&o.udwf, res))
+    }
+}
+
+impl WindowUDFImpl for OverloadedUDWF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let (_, res) = self.resolve(arg_types)?;
+        Ok(res.coerced_types)
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<Field> {
+        let (udwf, res) = self.resolve(field_args.input_types())?;
+        udwf.field(WindowUDFFieldArgs::new(
+            &res.coerced_types,
+            field_args.name(),
+        ))
+    }
+
+    fn partition_evaluator(
+        &self,
+        partition_evaluator_args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        let input_types = partition_evaluator_args.input_types();
+        let (udwf, res) = self.resolve(input_types)?;
+        if res.coerced_types == input_types {
+            return udwf.partition_evaluator_factory(partition_evaluator_args);
+        }
+        let inner = udwf.partition_evaluator_factory(PartitionEvaluatorArgs::new(
+            partition_evaluator_args.input_exprs(),
+            &res.coerced_types,
+            partition_evaluator_args.is_reversed(),
+            partition_evaluator_args.ignore_nulls(),
+        ))?;
+        Ok(Box::new(CoercedEvaluator {
+            inner,
+            coerced_types: res.coerced_types,
+            coerced: None,
+        }))
+    }
+
+    /// The dispatcher can be reversed if all of its overloads can: the reversed evaluators are
+    /// then created from the `is_reversed` flag of their arguments.
+    fn reverse_expr(&self) -> ReversedUDWF {
+        let reversed = self
+            .overloads
+            .iter()
+            .map(|o| o.udwf.reverse_expr())
+            .collect::<Vec<_>>();
+        if reversed
+            .iter()
+            .all(|r| matches!(r, ReversedUDWF::Identical))
+        {
+            ReversedUDWF::Identical
+        } else if reversed
+            .iter()
+            .all(|r| !matches!(r, ReversedUDWF::NotSupported))
+        {
+            ReversedUDWF::Reversed(Arc::new(WindowUDF::new_from_impl(self.clone())))
+        } else {
+            ReversedUDWF::NotSupported
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.documentation.as_ref()
+    }
+}
+
+/// An evaluator whose arguments are cast to `coerced_types` before they reach `inner`. The
+/// values that follow the arguments (the ORDER BY columns) are left as they are.
+#[derive(Debug)]
+struct CoercedEvaluator {
+    inner: Box<dyn PartitionEvaluator>,
+    coerced_types: Vec<DataType>,
+    /// The last values and their coercion: frame-aware evaluators are called once per row
+    /// with the same values.
+    coerced: Option<(Vec<ArrayRef>, Vec<ArrayRef>)>,
+}
+
+impl CoercedEvaluator {
+    fn coerce(&mut self, values: &[ArrayRef]) -> Result<Vec<ArrayRef>> {
+        if let Some((original, coerced)) = &self.coerced {
+            if original.len() == values.len()
+                && original.iter().zip(values).all(|(a, b)| Arc::ptr_eq(a, b))
+            {
+                return Ok(coerced.clone());
+            }
+        }
+        let coerced = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| match self.coerced_types.get(i) {
+                Some(to) if to != value.data_type() => Ok(cast(value, to)?),
+                _ => Ok(Arc::clone(value)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.coerced = Some((values.to_vec(), coerced.clone()));
+        Ok(coerced)
+    }
+}
+
+impl PartitionEvaluator for CoercedEvaluator {
+    fn memoize(&mut self, state: &mut WindowAggState) -> Result<()> {
+        self.inner.memoize(state)
+    }
+
+    fn get_range(&self, idx: usize, n_rows: usize) -> Result<Range<usize>> {
+        self.inner.get_range(idx, n_rows)
+    }
+
+    fn is_causal(&self) -> bool {
+        self.inner.is_causal()
+    }
+
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        let values = self.coerce(values)?;
+        self.inner.evaluate_all(&values, num_rows)
+    }
+
+    fn evaluate(&mut self, values: &[ArrayRef], range: &Range<usize>) -> Result<ScalarValue> {
+        let values = self.coerce(values)?;
+        self.inner.evaluate(&values, range)
+    }
+
+    fn evaluate_all_with_rank(
+        &self,
+        num_rows: usize,
+        ranks_in_partition: &[Range<usize>],
+    ) -> Result<ArrayRef> {
+        self.inner
+            .evaluate_all_with_rank(num_rows, ranks_in_partition)
+    }
+
+    fn supports_bounded_execution(&self) -> bool {
+        self.inner.supports_bounded_execution()
+    }
+
+    fn uses_window_frame(&self) -> bool {
+        self.inner.uses_window_frame()
+    }
+
+    fn include_rank(&self) -> bool {
+        self.inner.include_rank()
+    }
+}
+
+/// Mark the window functions of `sql` called `FROM FIRST` or `FROM LAST`, e.g.
+/// `nth_value(x, 2) FROM LAST OVER (ORDER BY k)`, which DataFusion does not parse: `FROM FIRST`,
+/// the default, is removed, and the functions called `FROM LAST` are renamed, e.g. to
+/// `"nth_value FROM LAST"`, for [`rewrite_from_last`] to restore them. A text that does not
+/// tokenize is returned as is, for the parser to report its errors.
+pub fn mark_from_last(sql: &str) -> Cow<'_, str> {
+    let Ok(tokens) = Tokenizer::new(&GenericDialect, sql).tokenize_with_location() else {
+        return Cow::Borrowed(sql);
+    };
+    let tokens = tokens
+        .into_iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+        .collect::<Vec<_>>();
+    let word = |i: usize, words: &[&str]| match tokens.get(i).map(|t| &t.token) {
+        Some(Token::Word(w)) => {
+            w.quote_style.is_none() && words.iter().any(|k| w.value.eq_ignore_ascii_case(k))
+        }
+        _ => false,
+    };
+    // The replacements of the spans of the text, in order
+    let mut edits = vec![];
+    for i in 0..tokens.len() {
+        if tokens[i].token != Token::RParen
+            || !word(i + 1, &["FROM"])
+            || !word(i + 2, &["FIRST", "LAST"])
+            || !word(i + 3, &["OVER", "IGNORE", "RESPECT"])
+        {
+            continue;
+        }
+        let from_last = word(i + 2, &["LAST"]);
+        if from_last {
+            let Some(name) = function_name(&tokens[..i]) else {
+                continue;
+            };
+            let Token::Word(w) = &name.token else {
+                continue;
+            };
+            let marked = format!("\"{}{FROM_LAST}\"", w.value);
+            edits.push((name.span.start, name.span.end, marked));
+        }
+        let (from, last) = (&tokens[i + 1], &tokens[i + 2]);
+        edits.push((from.span.start, last.span.end, String::new()));
+    }
+    if edits.is_empty() {
+        return Cow::Borrowed(sql);
+    }
+    edits.sort_by_key(|(start, _, _)| (start.line, start.column));
+    let line_starts = std::iter::once(0)
+        .chain(sql.match_indices('\n').map(|(i, _)| i + 1))
+        .collect::<Vec<_>>();
+    let offset = |location: &Location| {
+        let line_start = line_starts[location.line as usize - 1];
+        sql[line_start..]
+            .char_indices()
+            .nth(location.column as usize - 1)
+            .map_or(sql.len(), |(i, _)| line_start + i)
+    };
+    let mut marked = String::with_capacity(sql.len());
+    let mut pos = 0;
+    for (start, end, replacement) in edits {
+        marked.push_str(&sql[pos..offset(&start)]);
+        marked.push_str(&replacement);
+        pos = offset(&end);
+    }
+    marked.push_str(&sql[pos..]);
+    Cow::Owned(marked)
+}
+
+/// The name of the function whose arguments end `tokens` with their closing parenthesis.
+fn function_name(tokens: &[TokenWithSpan]) -> Option<&TokenWithSpan> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().rev() {
+        match token.token {
+            Token::RParen => depth += 1,
+            Token::LParen if depth == 0 => {
+                return tokens
+                    .get(i.checked_sub(1)?)
+                    .filter(|t| matches!(t.token, Token::Word(_)));
+            }
+            Token::LParen => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Restore the window functions of `statement` marked by [`mark_from_last`] as called
+/// `FROM LAST`, over the reversed window: its order is reversed, and so is its frame, so that the
+/// function counts from the last row of the frame.
+pub fn rewrite_from_last(statement: &mut datafusion::sql::sqlparser::ast::Statement) -> Result<()> {
+    let res = visit_expressions_mut(statement, |expr| {
+        let SqlExpr::Function(function) = expr else {
+            return ControlFlow::Continue(());
+        };
+        let Some(name) = function.name.0.last_mut() else {
+            return ControlFlow::Continue(());
+        };
+        let Some(unmarked) = name.value.strip_suffix(FROM_LAST) else {
+            return ControlFlow::Continue(());
+        };
+        if name.quote_style != Some('"') {
+            return ControlFlow::Continue(());
+        }
+        *name = Ident::new(unmarked);
+        match &mut function.over {
+            Some(WindowType::WindowSpec(spec)) if spec.window_name.is_none() => {
+                reverse_window(spec);
+                ControlFlow::Continue(())
+            }
+            _ => ControlFlow::Break(plan_datafusion_err!(
+                "{} FROM LAST needs an inline window specification",
+                function.name
+            )),
+        }
+    });
+    match res {
+        ControlFlow::Break(e) => Err::<(), DataFusionError>(e),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+/// Reverse the order and the frame of `spec`, as DataFusion's default null ordering.
+fn reverse_window(spec: &mut WindowSpec) {
+    for order in &mut spec.order_by {
+        let asc = order.asc.unwrap_or(true);
+        let nulls_first = order.nulls_first.unwrap_or(!asc);
+        order.asc = Some(!asc);
+        order.nulls_first = Some(!nulls_first);
+    }
+    let reverse = |bound: WindowFrameBound| match bound {
+        WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+        WindowFrameBound::Preceding(n) => WindowFrameBound::Following(n),
+        WindowFrameBound::Following(n) => WindowFrameBound::Preceding(n),
+    };
+    spec.window_frame = match spec.window_frame.take() {
+        Some(frame) => Some(WindowFrame {
+            units: frame.units,
+            start_bound: reverse(frame.end_bound.unwrap_or(WindowFrameBound::CurrentRow)),
+            end_bound: Some(reverse(frame.start_bound)),
+        }),
+        // The default frame ends at the current row, or is the whole partition without an order
+        None if spec.order_by.is_empty() => None,
+        None => Some(WindowFrame {
+            units: WindowFrameUnits::Range,
+            start_bound: WindowFrameBound::CurrentRow,
+            end_bound: Some(WindowFrameBound::Following(None)),
+        }),
+    };
+}
@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+
+use arrow::array::Int64Array;
+use arrow::datatypes::DataType;
+use datafusion::common::cast::as_int64_array;
+use datafusion::common::ScalarValue;
+use datafusion::error::Result;
+use datafusion::logical_expr::ReversedUDAF;
+use datafusion::prelude::SessionContext;
+use sql_functions::coverage::ImplementationStatus;
+use sql_functions::overload::{overloaded_functions, Overload};
+use sql_functions::sdf::load_functions;
+use sql_functions::{register_dialect, Dialect};
+
+#[test]
+fn every_aggregate_overload_is_generated() -> Result<()> {
+    let defined = load_functions("trino")?
+        .iter()
+        .filter(|f| f.kind() == "aggregate")
+        .map(|f| format!("trino::{}", f.overload_name()))
+        .collect::<HashSet<_>>();
+    let generated = sql_functions::trino::aggregate_functions()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect::<HashSet<_>>();
+    assert_eq!(defined, generated);
+    Ok(())
+}
+
+#[tokio::test]
+async fn stubs_do_not_shadow_builtin_aggregates() -> Result<()> {
+    let count = Dialect::Trino
+        .aggregate_overloads()
+        .into_iter()
+        .filter(|o| o.function_name() == "count")
+        .collect::<Vec<_>>();
+    assert!(!count.is_empty());
+    assert!(count
+        .iter()
+        .all(|o| o.status() == ImplementationStatus::Stub));
+
+    let mut ctx = SessionContext::new();
+    let registered = register_dialect(&mut ctx, Dialect::Trino)?;
+    assert!(registered.iter().any(|name| name == "count_if"));
+    assert!(!registered.iter().any(|name| name == "count"));
+    let batches = ctx
+        .sql("SELECT count(*), count_if(x > 1) FROM (VALUES (1), (2), (3)) AS t(x)")
+        .await?
+        .collect()
+        .await?;
+    assert_eq!(
+        as_int64_array(batches[0].column(0))?,
+        &Int64Array::from(vec![3])
+    );
+    assert_eq!(
+        as_int64_array(batches[0].column(1))?,
+        &Int64Array::from(vec![2])
+    );
+    Ok(())
+}
+
+#[test]
+fn dispatchers_forward_the_properties_of_the_overloads() -> Result<()> {
+    let (_, count_if) = overloaded_functions(Dialect::Trino.aggregate_overloads())
+        .into_iter()
+        .find(|(key, _)| key == "trino::count_if")
+        .expect("count_if");
+    assert_eq!(
+        count_if.default_value(&DataType::Int64)?,
+        ScalarValue::Int64(Some(0))
+    );
+    assert!(count_if.order_sensitivity().hard_requires());
+    assert!(matches!(count_if.reverse_udf(), ReversedUDAF::NotSupported));
+
+    // Without a value of their own over no rows, it is NULL
+    let (_, checksum) = overloaded_functions(Dialect::Trino.aggregate_overloads())
+        .into_iter()
+        .find(|(key, _)| key == "trino::checksum")
+        .expect("checksum");
+    assert!(checksum.default_value(&DataType::Binary)?.is_null());
+    Ok(())
+}
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use sql_functions::catalog::FunctionKind;
+use sql_functions::FunctionCatalog;
+
+#[test]
+fn lookup_returns_the_overloads_in_definition_order() {
+    let catalog = FunctionCatalog::load().unwrap();
+    let overloads = catalog.lookup("trino", "ABS");
+    let parameters = overloads
+        .iter()
+        .map(|f| f.parameters[0].datatype.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parameters,
+        [
+            "tinyint",
+            "smallint",
+            "bigint",
+            "double",
+            "decimal(p, s)",
+            "real"
+        ]
+    );
+    let abs = overloads[0];
+    assert_eq!(abs.overload, "abs_tinyint");
+    assert_eq!(abs.kind, FunctionKind::Scalar);
+    assert_eq!(abs.section, "math");
+    assert_eq!(abs.display_signature(), "abs(tinyint) -> tinyint");
+}
+
+#[test]
+fn lookup_ignores_the_case_of_the_name() {
+    let catalog = FunctionCatalog::load().unwrap();
+    let overloads = catalog.lookup("trino", "regexp_like");
+    assert!(!overloads.is_empty());
+    assert_eq!(catalog.lookup("trino", "REGEXP_LIKE"), overloads);
+    assert_eq!(catalog.lookup("trino", "Regexp_Like"), overloads);
+    assert_eq!(
+        catalog.names_with_prefix("trino", "REGEXP_L"),
+        ["regexp_like"]
+    );
+}
+
+#[test]
+fn unknown_names_are_not_found() {
+    let catalog = FunctionCatalog::load().unwrap();
+    assert!(catalog.lookup("trino", "no_such_function").is_empty());
+    assert!(catalog.lookup("no_such_dialect", "abs").is_empty());
+    assert!(catalog.names_with_prefix("trino", "no_such_").is_empty());
+    assert!(catalog.sections("no_such_dialect").is_empty());
+    assert!(FunctionCatalog::load_dialect("no_such_dialect").is_err());
+}
+
+#[test]
+fn every_dialect_has_its_overloads() {
+    let catalog = FunctionCatalog::load().unwrap();
+    assert_eq!(
+        catalog.dialects(),
+        ["bigquery", "redshift", "snowflake", "trino"]
+    );
+    // (dialect, overloads, names)
+    for (dialect, overloads, names) in [
+        ("bigquery", 521, 326),
+        ("redshift", 395, 323),
+        ("snowflake", 1331, 734),
+        ("trino", 716, 417),
+    ] {
+        assert_eq!(catalog.dialect(dialect).count(), overloads, "{dialect}");
+        assert_eq!(
+            catalog.names_with_prefix(dialect, "").len(),
+            names,
+            "{dialect}"
+        );
+        let only = FunctionCatalog::load_dialect(dialect).unwrap();
+        assert_eq!(only.dialects(), [dialect]);
+        assert_eq!(only.functions().len(), overloads, "{dialect}");
+    }
+    assert_eq!(catalog.functions().len(), 521 + 395 + 1331 + 716);
+}
+
+#[test]
+fn the_engine_lists_fill_in_missing_descriptions_and_kinds() {
+    let catalog = FunctionCatalog::load().unwrap();
+    // No description in the definitions of Trino's spatial_partitions nor Snowflake's
+    // current_client, but one in trino.csv and snowflake.csv
+    for (dialect, name, description) in [
+        (
+            "trino",
+            "spatial_partitions",
+            "Returns an array of spatial partition IDs for a given geometry",
+        ),
+        (
+            "snowflake",
+            "current_client",
+            "Returns the current client version.",
+        ),
+    ] {
+        let overloads = catalog.lookup(dialect, name);
+        assert!(!overloads.is_empty(), "{name}");
+        for overload in overloads {
+            assert_eq!(overload.description.as_deref(), Some(description));
+        }
+    }
+
+    // The kind of the second overload of st_collect is not defined: the first ST_COLLECT of
+    // snowflake.csv is an aggregate
+    let st_collect = catalog.lookup("snowflake", "st_collect");
+    assert_eq!(st_collect.len(), 2);
+    assert!(st_collect
+        .iter()
+        .all(|overload| overload.kind == FunctionKind::Aggregate));
+
+    // Descriptions of the definitions take precedence over the lists
+    let abs = catalog.lookup("snowflake", "abs");
+    assert_eq!(
+        abs[0].description.as_deref(),
+        Some("returns absolute value of x")
+    );
+}
@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conformance of the dialect functions with the results of the engines themselves.
+//!
+//! Each `tests/<dialect>/<function>.slt` file holds records separated by blank lines, run in
+//! order through a `SessionContext` with the functions (including the table functions) of the
+//! dialect registered, and the options of the Trino session (e.g. `SET trino.user = 'alice'`):
+//!
+//! ```text
+//! # A comment
+//! statement ok
+//! CREATE TABLE t AS VALUES (1), (NULL)
+//!
+//! query bigint
+//! SELECT array_min(ARRAY[1, NULL, 3])
+//! ----
+//! NULL
+//!
+//! query error Unexpected parameters (varchar) for function abs
+//! SELECT abs('x')
+//! ```
+//!
+//! A `query` lists the expected types of the columns (spelled as in Trino), separated by ` | `,
+//! followed by the expected rows, one per line, with the values of the columns separated by
+//! ` | ` too, and `(empty)` for empty strings. `query error` and `statement error` expect an error containing
+//! the given message.
+//!
+//! A record can be preceded by `xfail <reason>` when the function does not behave like the
+//! engine yet: the record must then fail, so that the marker is removed once the function is fixed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use arrow::array::RecordBatch;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use sql_functions::trino::session::TrinoSession;
+use sql_functions::type_signature::display_data_type;
+use sql_functions::{register_dialect, register_table_functions, Dialect, RegisterOptions};
+
+const SEPARATOR: &str = " | ";
+
+#[derive(Debug)]
+enum Expected {
+    Ok,
+    Rows { types: String, rows: Vec<String> },
+    Error(String),
+}
+
+#[derive(Debug)]
+struct Record {
+    line: usize,
+    sql: String,
+    expected: Expected,
+    /// Why the function does not behave like the engine yet
+    xfail: Option<String>,
+}
+
+fn parse(path: &Path) -> Vec<Record> {
+    let source = fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    let mut records = vec![];
+    let mut lines = source.lines().enumerate().peekable();
+    let mut xfail = None;
+    while let Some((i, line)) = lines.next() {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(reason) = line.strip_prefix("xfail ") {
+            xfail = Some(reason.to_string());
+            continue;
+        }
+        let mut sql = vec![];
+        let mut rows = vec![];
+        let mut in_rows = false;
+        while let Some((_, next)) = lines.next_if(|(_, l)| !l.trim().is_empty()) {
+            match next.trim_end() {
+                "----" => in_rows = true,
+                next if in_rows => rows.push(next.to_string()),
+                next => sql.push(next),
+            }
+        }
+        let expected = match line.split_once(' ').unwrap_or((line, "")) {
+            ("statement", "ok") => Expected::Ok,
+            ("statement" | "query", rest) if rest.starts_with("error") => {
+                Expected::Error(rest["error".len()..].trim().to_string())
+            }
+            ("query", types) if !types.is_empty() => Expected::Rows {
+                types: types.to_string(),
+                rows,
+            },
+            _ => panic!("{}:{}: invalid record {line}", path.display(), i + 1),
+        };
+        records.push(Record {
+            line: i + 1,
+            sql: sql.join("\n"),
+            expected,
+            xfail: xfail.take(),
+        });
+    }
+    records
+}
+
+fn format_rows(batches: &[RecordBatch]) -> Result<Vec<String>> {
+    let options = FormatOptions::default().with_null("NULL");
+    let mut rows = vec![];
+    for batch in batches {
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|c| ArrayFormatter::try_new(c.as_ref(), &options))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        for row in 0..batch.num_rows() {
+            let values = formatters
+                .iter()
+                .map(|f| match f.value(row).to_string() {
+                    value if value.is_empty() => "(empty)".to_string(),
+                    value => value,
+                })
+                .collect::<Vec<_>>();
+            rows.push(values.join(SEPARATOR));
+        }
+    }
+    Ok(rows)
+}
+
+/// The types and rows of the result of `sql`.
+async fn execute(
+    ctx: &SessionContext,
+    dialect: Dialect,
+    sql: &str,
+) -> Result<(String, Vec<String>)> {
+    let df = sql_functions::sql(ctx, dialect, sql).await?;
+    let types = df
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| display_data_type(f.data_type()))
+        .collect::<Vec<_>>()
+        .join(SEPARATOR);
+    let rows = format_rows(&df.collect().await?)?;
+    Ok((types, rows))
+}
+
+/// Run `record`, returning a description of the mismatch if it fails.
+async fn run(ctx: &SessionContext, dialect: Dialect, record: &Record) -> Option<String> {
+    match (&record.expected, execute(ctx, dialect, &record.sql).await) {
+        (Expected::Ok, Ok(_)) => None,
+        (Expected::Rows { types, rows }, Ok((actual_types, actual_rows))) => {
+            if *types != actual_types {
+                Some(format!("expected types {types}, got {actual_types}"))
+            } else if *rows != actual_rows {
+                Some(format!(
+                    "expected\n{}\ngot\n{}",
+                    rows.join("\n"),
+                    actual_rows.join("\n")
+                ))
+            } else {
+                None
+            }
+        }
+        (Expected::Error(message), Err(e)) if e.to_string().contains(message.as_str()) => None,
+        (Expected::Error(message), Err(e)) => {
+            Some(format!("expected error containing {message:?}, got {e}"))
+        }
+        (Expected::Error(message), Ok((_, rows))) => Some(format!(
+            "expected error containing {message:?}, got\n{}",
+            rows.join("\n")
+        )),
+        (_, Err(e)) => Some(format!("unexpected error {e}")),
+    }
+}
+
+fn slt_files(dialect: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(dialect);
+    let mut files = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| entry.expect("directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "slt"))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Run the conformance tests of `dialect`, failing with every mismatch.
+async fn conformance(dialect: Dialect) -> Result<()> {
+    let mut failures = vec![];
+    let files = slt_files(dialect.name());
+    assert!(!files.is_empty(), "no conformance tests");
+    for path in files {
+        let mut config = SessionConfig::new();
+        if dialect == Dialect::Trino {
+            config = config.with_option_extension(TrinoSession::default());
+        }
+        let mut ctx = SessionContext::new_with_config(config);
+        register_dialect(&mut ctx, dialect)?;
+        register_table_functions(&ctx, dialect, &RegisterOptions::default())?;
+        for record in parse(&path) {
+            let failure = match (run(&ctx, dialect, &record).await, &record.xfail) {
+                (Some(failure), None) => failure,
+                (None, Some(reason)) => format!("passes, remove `xfail {reason}`"),
+                _ => continue,
+            };
+            failures.push(format!(
+                "{}:{}: {}\n{failure}",
+                path.display(),
+                record.line,
+                record.sql
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn trino_conformance() -> Result<()> {
+    conformance(Dialect::Trino).await
+}
+
+#[tokio::test]
+async fn redshift_conformance() -> Result<()> {
+    conformance(Dialect::Redshift).await
+}
+
+#[tokio::test]
+async fn snowflake_conformance() -> Result<()> {
+    conformance(Dialect::Snowflake).await
+}
@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use sql_functions::coverage::{implementation_status, CoverageReport, ImplementationStatus};
+use sql_functions::Dialect;
+
+#[test]
+fn counts_add_up_to_the_definitions() {
+    let report = CoverageReport::new(Dialect::Trino).unwrap();
+    let total = &report.total;
+    assert_eq!(total.total, report.functions.len());
+    assert_eq!(
+        total.total,
+        total.implemented + total.partial + total.stub + total.missing
+    );
+    assert_eq!(
+        report.sections.iter().map(|s| s.total).sum::<usize>(),
+        total.total
+    );
+
+    let regexp = report
+        .sections
+        .iter()
+        .find(|s| s.section == "regexp")
+        .unwrap();
+    let overloads = report
+        .functions
+        .iter()
+        .filter(|f| f.section == "regexp")
+        .collect::<Vec<_>>();
+    assert_eq!(regexp.total, overloads.len());
+    assert_eq!(
+        regexp.implemented,
+        overloads
+            .iter()
+            .filter(|f| f.status == Some(ImplementationStatus::Implemented))
+            .count()
+    );
+    assert!(overloads
+        .iter()
+        .any(|f| f.overload == "regexp_like_varchar_joniregexp"
+            && f.status == Some(ImplementationStatus::Implemented)));
+}
+
+#[test]
+fn unknown_overloads_are_stubs() {
+    assert_eq!(
+        implementation_status("trino", "regexp_like_varchar_joniregexp"),
+        ImplementationStatus::Implemented
+    );
+    assert_eq!(
+        implementation_status("trino", "no_such_function"),
+        ImplementationStatus::Stub
+    );
+}
+
+#[test]
+fn overloads_reaching_unimplemented_helpers_are_partial() {
+    // The element types the KernelLifter of array_max does not support are not implemented
+    assert_eq!(
+        implementation_status("trino", "array_max_array_1"),
+        ImplementationStatus::Partial
+    );
+    let report = CoverageReport::new(Dialect::Trino).unwrap();
+    let array = report
+        .sections
+        .iter()
+        .find(|s| s.section == "array")
+        .unwrap();
+    assert!(array.partial >= 2);
+    assert_eq!(
+        report.total.partial,
+        report.sections.iter().map(|s| s.partial).sum::<usize>()
+    );
+}
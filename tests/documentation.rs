@@ -0,0 +1,78 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_REGEX;
+use sql_functions::documentation::documentation;
+use sql_functions::Dialect;
+
+#[test]
+fn udfs_are_documented_from_the_definitions() {
+    let (_, udf) = sql_functions::trino::functions()
+        .into_iter()
+        .find(|(name, _)| name == "trino::regexp_like_varchar_joniregexp")
+        .unwrap();
+    let doc = udf.documentation().unwrap();
+    assert_eq!(
+        doc.description,
+        "Evaluates the regular expression pattern and determines if it is contained within \
+         string.\n\nSee https://trino.io/docs/current/functions/regexp.html#regexp_like"
+    );
+    assert_eq!(
+        doc.syntax_example,
+        "regexp_like(varchar, joniregexp) -> boolean"
+    );
+    assert_eq!(
+        doc.sql_example.as_deref(),
+        Some(
+            "```sql\n> SELECT REGEXP_like('email_address@gmail.com', '.*@.*\\..*') AS \
+             is_valid_email\nTRUE\n```"
+        )
+    );
+    // The parameters of regexp_like have no names in the definitions: the arguments are named as
+    // those of its expr_fn function
+    assert_eq!(
+        doc.arguments,
+        Some(vec![
+            ("arg1".to_string(), "varchar".to_string()),
+            ("arg2".to_string(), "joniregexp".to_string()),
+        ])
+    );
+    assert_eq!(doc.doc_section.label, DOC_SECTION_REGEX.label);
+}
+
+#[test]
+fn arguments_are_documented_with_the_names_of_the_parameters() {
+    let doc = documentation(Dialect::Snowflake, "flatten_1").unwrap();
+    assert_eq!(
+        doc.description,
+        "The flatten function.\n\nSee https://docs.snowflake.com/en/sql-reference/functions/flatten"
+    );
+    assert_eq!(
+        doc.syntax_example,
+        "flatten(input $1[, path string[, outer boolean[, recursive boolean[, mode string]]]]) \
+         -> struct<seq bigint, key string, path string, index bigint, value variant, this $1>"
+    );
+    let arguments = [
+        ("input", "$1"),
+        ("path", "optional string"),
+        ("outer", "optional boolean"),
+        ("recursive", "optional boolean"),
+        ("mode", "optional string"),
+    ]
+    .map(|(name, description)| (name.to_string(), description.to_string()));
+    assert_eq!(doc.arguments.as_deref(), Some(arguments.as_slice()));
+}
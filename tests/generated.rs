@@ -15,18 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
-#![allow(non_snake_case)]
-{% for name, fs in function_infos | items %}mod {{name}}_impl;
-{% endfor %}
+use std::process::Command;
 
-// create  UDFs
-{% for name, fs in function_infos | items %}{% for function_info in fs %}make_udf_function!({{name}}_impl::{{function_info.name}}Func, {{function_info.gname}}, {{function_info.name}});
-{% endfor %}
-{% endfor %}
-
-
-// Export the functions out of this package, both as expr_fn as well as a list of functions
-export_functions!(
-{% for name, fs in function_infos | items %}{% for function_info in fs %}    (trino, {{function_info.name}}, {{function_info.arg_names}}, "function doc"),
-{% endfor %}
-{% endfor %});
+/// The generated UDFs must match the function definitions of `assets/<dialect>/functions.sdf.yml`.
+#[test]
+fn generated_code_is_up_to_date() {
+    for dialect in ["trino", "redshift", "snowflake"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_sql-functions-generate"))
+            .args(["--dialect", dialect, "--check"])
+            .output()
+            .expect("run sql-functions-generate");
+        assert!(
+            output.status.success(),
+            "{dialect}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
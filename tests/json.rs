@@ -0,0 +1,160 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, AsArray};
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+use sql_functions::{register_dialect, Dialect};
+
+/// The single varchar value of `sql`.
+async fn value(ctx: &SessionContext, sql: &str) -> Result<Option<String>> {
+    let batches = ctx.sql(sql).await?.collect().await?;
+    let column = batches[0].column(0).as_string::<i32>();
+    Ok((!column.is_null(0)).then(|| column.value(0).to_string()))
+}
+
+#[tokio::test]
+async fn wildcards_select_the_array_of_the_matches() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let doc = r#"'{"book": [{"title": "A", "tags": ["x"]}, {"title": "B", "tags": ["y", "z"]}, {"price": 1}]}'"#;
+    let cases = [
+        ("$.book[*].title", Some(r#"["A","B"]"#)),
+        ("$.book.*.tags[0]", Some(r#"["x","y"]"#)),
+        ("$.book[*].tags[*]", Some(r#"["x","y","z"]"#)),
+        ("$.book[2].*", Some("[1]")),
+        ("$.missing[*]", Some("[]")),
+        // A quoted star is a key
+        (r#"$.book[0]["*"]"#, None),
+    ];
+    for (path, expected) in cases {
+        let actual = value(&ctx, &format!("SELECT json_extract({doc}, '{path}')")).await?;
+        assert_eq!(actual.as_deref(), expected, "{path}");
+    }
+    // The matches form an array, which is not a scalar
+    let scalar = value(
+        &ctx,
+        &format!("SELECT json_extract_scalar({doc}, '$.book[*].title')"),
+    )
+    .await?;
+    assert_eq!(scalar, None);
+    Ok(())
+}
+
+/// The error of `sql`, which must fail.
+async fn error(ctx: &SessionContext, sql: &str) -> String {
+    let result = match ctx.sql(sql).await {
+        Ok(df) => df.collect().await.map(|_| ()),
+        Err(e) => Err(e),
+    };
+    result.expect_err(sql).to_string()
+}
+
+#[tokio::test]
+async fn subscripts_select_an_element_or_nothing() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let doc = r#"'{"a": [10, 20, 30]}'"#;
+    let cases = [
+        ("$.a[0]", Some("10")),
+        ("$.a[2]", Some("30")),
+        ("$.a.1", Some("20")),
+        ("$.a[3]", None),
+        ("$.a[99999999999999999999]", None),
+        // A subscript of an object is a key
+        ("$[0]", None),
+    ];
+    for (path, expected) in cases {
+        let actual = value(&ctx, &format!("SELECT json_extract({doc}, '{path}')")).await?;
+        assert_eq!(actual.as_deref(), expected, "{path}");
+    }
+    // json_array_get counts negative indexes from the end
+    let cases = [
+        ("0", Some("10")),
+        ("-1", Some("30")),
+        ("-3", Some("10")),
+        ("-4", None),
+        ("3", None),
+    ];
+    for (index, expected) in cases {
+        let sql = format!("SELECT json_array_get('[10, 20, 30]', {index})");
+        assert_eq!(value(&ctx, &sql).await?.as_deref(), expected, "{index}");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn null_and_missing_members() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let doc = r#"'{"a": null, "b": {"c": null}}'"#;
+    let cases = [
+        // A JSON null is a value, which is not a scalar
+        ("json_extract", "$.a", Some("null")),
+        ("json_extract_scalar", "$.a", None),
+        ("json_extract", "$.b.c", Some("null")),
+        ("json_extract", "$.b", Some(r#"{"c":null}"#)),
+        ("json_extract", "$.missing", None),
+        ("json_extract_scalar", "$.missing", None),
+        // Nothing below a null or a missing member
+        ("json_extract", "$.a.c", None),
+        ("json_extract", "$.missing.c", None),
+    ];
+    for (function, path, expected) in cases {
+        let sql = format!("SELECT {function}({doc}, '{path}')");
+        assert_eq!(value(&ctx, &sql).await?.as_deref(), expected, "{sql}");
+    }
+    // A NULL document or path is a NULL result
+    let sql = "SELECT json_extract(CAST(NULL AS VARCHAR), '$.a')";
+    assert_eq!(value(&ctx, sql).await?, None);
+    let sql = format!("SELECT json_extract({doc}, CAST(NULL AS VARCHAR))");
+    assert_eq!(value(&ctx, &sql).await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn malformed_paths_are_errors() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let paths = [
+        "",
+        "a",
+        "$.",
+        "$..a",
+        "$[",
+        "$[0",
+        r#"$["a"#,
+        "$.a[*",
+        // Trino's legacy paths have neither array slices nor negative subscripts...
+        "$.a[0:2]",
+        "$.a[-1]",
+        // ...nor the modes of the SQL/JSON paths of json_query and json_value
+        "lax $.a",
+        "strict $.a",
+    ];
+    for path in paths {
+        for function in ["json_extract", "json_extract_scalar", "json_size"] {
+            let sql = format!("SELECT {function}('{{\"a\": [1, 2]}}', '{path}')");
+            let err = error(&ctx, &sql).await;
+            assert!(
+                err.contains(&format!("Invalid JSON path: '{path}'")),
+                "{sql}: {err}"
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::util::pretty::pretty_format_batches;
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::Statement;
+use sql_functions::lambda::{parse_statement, rewrite_lambdas};
+use sql_functions::{register_dialect, Dialect};
+
+/// `sql` with the lambda expressions passed to the Trino functions rewritten.
+fn rewrite(sql: &str) -> Result<String> {
+    let mut statement = parse_statement(sql)?;
+    let Statement::Statement(statement) = &mut statement else {
+        panic!("not a SQL statement");
+    };
+    rewrite_lambdas(statement, &Dialect::Trino.overloads())?;
+    Ok(statement.to_string())
+}
+
+#[test]
+fn lambdas_are_rewritten() -> Result<()> {
+    assert_eq!(
+        rewrite("SELECT transform(a, x -> x + k) FROM t")?,
+        "SELECT transform(a, __lambda(1, __lambda_parameter(1, 0, 'x', '$1', 'array<$1>', a) + k, 'x')) FROM t"
+    );
+    // Inner lambda expressions are rewritten first, and their parameters shadow the outer ones
+    assert_eq!(
+        rewrite("SELECT filter(a, y -> any_match(b, x -> x = Y))")?,
+        "SELECT filter(a, __lambda(2, any_match(b, __lambda(1, __lambda_parameter(1, 0, 'x', '$1', 'array<$1>', b) = __lambda_parameter(2, 0, 'y', '$1', 'array<$1>', a), 'x')), 'y'))"
+    );
+    assert_eq!(
+        rewrite("SELECT filter(a, x -> any_match(b, X -> x > 0))")?,
+        "SELECT filter(a, __lambda(2, any_match(b, __lambda(1, __lambda_parameter(1, 0, 'x', '$1', 'array<$1>', b) > 0, 'x')), 'x'))"
+    );
+    // Quoted parameters are case-sensitive
+    assert_eq!(
+        rewrite(r#"SELECT transform(a, "X" -> x)"#)?,
+        "SELECT transform(a, __lambda(1, x, 'X'))"
+    );
+    assert!(rewrite("SELECT transform(a, (x, y) -> x)").is_err());
+    let err = rewrite("SELECT abs(x -> x)").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Function abs does not take a lambda expression as argument 1"));
+    let err = rewrite("SELECT transform(a, (x -> x))").unwrap_err();
+    assert!(err.to_string().contains("Unexpected lambda expression"));
+    assert!(rewrite("SELECT 1; SELECT 2").is_err());
+    Ok(())
+}
+
+async fn run(ctx: &SessionContext, sql: &str) -> Result<String> {
+    let batches = sql_functions::sql(ctx, Dialect::Trino, sql)
+        .await?
+        .collect()
+        .await?;
+    Ok(pretty_format_batches(&batches)?.to_string())
+}
+
+#[tokio::test]
+async fn lambdas_are_planned_and_evaluated() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    ctx.sql("CREATE TABLE t (k BIGINT, a BIGINT[]) AS VALUES (1, [1, 2]), (2, [3]), (3, NULL)")
+        .await?
+        .collect()
+        .await?;
+
+    // The lambda expressions are part of the names of the columns
+    assert_eq!(
+        run(&ctx, "SELECT transform(a, x -> x + k) FROM t ORDER BY k").await?,
+        [
+            "+-----------------------------+",
+            "| transform(t.a,x -> x + t.k) |",
+            "+-----------------------------+",
+            "| [2, 3]                      |",
+            "| [5]                         |",
+            "|                             |",
+            "+-----------------------------+",
+        ]
+        .join("\n")
+    );
+    // In filters, and grouped by
+    assert_eq!(
+        run(
+            &ctx,
+            "SELECT any_match(a, x -> x > 1) AS m, count(*) AS n FROM t \
+             WHERE k < 3 AND all_match(a, x -> x < k * 2) GROUP BY any_match(a, x -> x > 1)"
+        )
+        .await?,
+        [
+            "+------+---+",
+            "| m    | n |",
+            "+------+---+",
+            "| true | 1 |",
+            "+------+---+",
+        ]
+        .join("\n")
+    );
+    // The placeholders fail if they are ever evaluated
+    assert!(ctx
+        .sql("SELECT __lambda(1, 2, 'x')")
+        .await?
+        .collect()
+        .await
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow::datatypes::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::{create_udf, ColumnarValue, Volatility};
+use sql_functions::overload::{FunctionOverload, Overload, OverloadedUDF};
+use sql_functions::type_signature::{FunctionSignature, SdfType};
+use sql_functions::Dialect;
+
+/// An overload `f(parameters)` returning `return_type`.
+fn overload(name: &'static str, parameters: &[&str], return_type: DataType) -> FunctionOverload {
+    let udf = create_udf(
+        "f",
+        vec![],
+        return_type,
+        Volatility::Immutable,
+        Arc::new(|_: &[ColumnarValue]| unimplemented!()),
+    );
+    FunctionOverload {
+        dialect: "test",
+        name,
+        udf: Arc::new(udf),
+        signature: FunctionSignature::new(parameters, false).unwrap(),
+    }
+}
+
+#[test]
+fn the_most_specific_overload_wins() -> Result<()> {
+    let abs = Dialect::Trino
+        .overloads()
+        .into_iter()
+        .filter(|o| o.function_name() == "abs")
+        .collect::<Vec<_>>();
+    let abs = OverloadedUDF::new("abs", abs);
+    let (_, res) = abs.resolve(&[DataType::Null])?;
+    assert_eq!(res.coerced_types, vec![DataType::Int8]);
+    let (_, res) = abs.resolve(&[DataType::Int32])?;
+    assert_eq!(res.coerced_types, vec![DataType::Int64]);
+
+    // `f(bigint)` coerces to `f(double)`, whatever the declaration order
+    let f = OverloadedUDF::new(
+        "f",
+        vec![
+            overload("f_double", &["double"], DataType::Float64),
+            overload("f_bigint", &["bigint"], DataType::Int64),
+        ],
+    );
+    let (udf, _) = f.resolve(&[DataType::Null])?;
+    assert_eq!(udf.return_type(&[DataType::Int64])?, DataType::Int64);
+    Ok(())
+}
+
+#[test]
+fn calls_without_a_most_specific_overload_are_ambiguous() {
+    let f = OverloadedUDF::new(
+        "f",
+        vec![
+            overload("f_bigint_double", &["bigint", "double"], DataType::Int64),
+            overload("f_double_bigint", &["double", "bigint"], DataType::Int64),
+        ],
+    );
+    let err = f
+        .resolve(&[DataType::Int64, DataType::Int64])
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains(
+            "Could not choose a best candidate for f(bigint, bigint) among: \
+             f(bigint, double), f(double, bigint)"
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn null_arguments_fall_back_to_overloads_of_the_same_type() -> Result<()> {
+    let same = OverloadedUDF::new(
+        "f",
+        vec![
+            overload("f_varchar", &["varchar"], DataType::Int64),
+            overload("f_boolean", &["boolean"], DataType::Int64),
+        ],
+    );
+    let (_, res) = same.resolve(&[DataType::Null])?;
+    assert_eq!(res.coerced_types, vec![DataType::Utf8]);
+
+    let different = OverloadedUDF::new(
+        "f",
+        vec![
+            overload("f_varchar", &["varchar"], DataType::Utf8),
+            overload("f_boolean", &["boolean"], DataType::Boolean),
+        ],
+    );
+    assert!(different.resolve(&[DataType::Null]).is_err());
+    Ok(())
+}
+
+#[test]
+fn unknown_types_match_no_argument() -> Result<()> {
+    let hll = SdfType::parse("array<hllsketch>")?;
+    assert_eq!(hll.unknown_names(), ["hllsketch"]);
+    assert!(SdfType::parse("map<varchar, float>")?
+        .unknown_names()
+        .is_empty());
+    // Rather than the binaries they used to be mapped to
+    let signature = FunctionSignature::new(&["hllsketch"], false)?;
+    assert!(signature.resolve(&[DataType::Binary]).is_none());
+    Ok(())
+}
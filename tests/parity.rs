@@ -0,0 +1,393 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Properties every implemented Trino function must have, checked on random inputs:
+//! - it returns the same values whether each argument is a scalar or an array,
+//! - it returns one value per row,
+//! - a NULL argument makes the result NULL, unless the function handles NULLs in Trino.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{Array, FixedSizeListArray, StringArray};
+use arrow::datatypes::{DataType, IntervalMonthDayNano, IntervalUnit, TimeUnit};
+use datafusion::common::ScalarValue;
+use datafusion::error::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDF, Volatility};
+use sql_functions::coverage::ImplementationStatus;
+use sql_functions::overload::{FunctionOverload, Overload};
+
+/// Functions that are not null-on-null in Trino, i.e. that return a value for NULL arguments.
+const NULL_HANDLING: &[&str] = &["coalesce", "if", "nullif", "try"];
+
+/// Functions whose return type is named by a constant argument, which random strings are not:
+/// they are covered by their conformance tests instead.
+const TYPE_ARGUMENT: &[&str] = &["cast", "try_cast"];
+
+/// The overloads with argument types [`random_value`] does not generate, which are only covered
+/// by their conformance tests: an overload taking a new type is either listed here or generated.
+const SKIPPED: &[&str] = &[];
+
+const ITERATIONS: usize = 20;
+const ROWS: usize = 8;
+
+const STRINGS: &[&str] = &[
+    "",
+    "a",
+    "abc",
+    "1a 2b 14m",
+    "héllo wörld",
+    "a.b.c",
+    ".",
+    "day",
+    "hour",
+    "month",
+    "year",
+    "week",
+    "quarter",
+    "millisecond",
+    "1.5s",
+    "42.8ms",
+    "2024-03-05",
+    "2024-03-05T14:07",
+    "2024-03-05T14:07:09.123Z",
+    "2024-03-05T14:07:09.123456789+05:30",
+];
+
+const PATTERNS: &[&str] = &[
+    "\\d+",
+    "[a-z]+",
+    "(\\d+)([a-z]+)",
+    "a",
+    "^a",
+    "l+",
+    "\\s*",
+    "x",
+    "(a)|(b)",
+];
+
+/// A deterministic SplitMix64 generator, so that failures are reproducible.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low) as u64 + 1) as i64
+    }
+
+    fn pick<'a>(&mut self, values: &[&'a str]) -> &'a str {
+        values[self.below(values.len() as u64) as usize]
+    }
+}
+
+/// Whether [`random_value`] generates values of `data_type`.
+fn is_generated(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal128(_, _)
+        | DataType::Utf8
+        | DataType::Binary
+        | DataType::Date32
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+        | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        | DataType::Interval(IntervalUnit::YearMonth | IntervalUnit::MonthDayNano) => true,
+        DataType::List(field) => is_generated(field.data_type()),
+        DataType::FixedSizeList(field, 1) => field.data_type() == &DataType::Utf8,
+        _ => false,
+    }
+}
+
+/// A random value of `data_type`, NULL one time out of eight.
+fn random_value(random: &mut Random, data_type: &DataType) -> ScalarValue {
+    if random.below(8) == 0 {
+        return ScalarValue::try_from(data_type).unwrap();
+    }
+    match data_type {
+        DataType::Boolean => ScalarValue::Boolean(Some(random.below(2) == 0)),
+        DataType::Int8 => ScalarValue::Int8(Some(random.range(-100, 100) as i8)),
+        DataType::Int16 => ScalarValue::Int16(Some(random.range(-1000, 1000) as i16)),
+        DataType::Int32 => ScalarValue::Int32(Some(random.range(-1000, 1000) as i32)),
+        DataType::Int64 => ScalarValue::Int64(Some(random.range(-100_000, 100_000))),
+        DataType::Float32 => ScalarValue::Float32(Some(random.range(-10_000, 10_000) as f32 / 8.0)),
+        DataType::Float64 => {
+            ScalarValue::Float64(Some(random.range(-100_000, 100_000) as f64 / 8.0))
+        }
+        DataType::Decimal128(precision, scale) => ScalarValue::Decimal128(
+            Some(random.range(-1_000_000, 1_000_000) as i128),
+            *precision,
+            *scale,
+        ),
+        DataType::Utf8 => ScalarValue::Utf8(Some(random.pick(STRINGS).to_string())),
+        DataType::Binary => ScalarValue::Binary(Some(
+            (0..random.below(4))
+                .map(|_| random.below(256) as u8)
+                .collect(),
+        )),
+        DataType::Date32 => ScalarValue::Date32(Some(random.range(-20_000, 40_000) as i32)),
+        DataType::Timestamp(unit, tz) => {
+            let seconds = random.range(-1_000_000_000, 3_000_000_000);
+            let (value, fraction) = match unit {
+                TimeUnit::Second => (seconds, 1),
+                TimeUnit::Millisecond => (seconds * 1_000, 1_000),
+                TimeUnit::Microsecond => (seconds * 1_000_000, 1_000_000),
+                TimeUnit::Nanosecond => (seconds * 1_000_000_000, 1_000_000_000),
+            };
+            let value = Some(value + random.range(0, fraction - 1));
+            match unit {
+                TimeUnit::Second => ScalarValue::TimestampSecond(value, tz.clone()),
+                TimeUnit::Millisecond => ScalarValue::TimestampMillisecond(value, tz.clone()),
+                TimeUnit::Microsecond => ScalarValue::TimestampMicrosecond(value, tz.clone()),
+                TimeUnit::Nanosecond => ScalarValue::TimestampNanosecond(value, tz.clone()),
+            }
+        }
+        DataType::Time32(TimeUnit::Second) => {
+            ScalarValue::Time32Second(Some(random.range(0, 86_399) as i32))
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            ScalarValue::Time32Millisecond(Some(random.range(0, 86_399_999) as i32))
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            ScalarValue::Time64Microsecond(Some(random.range(0, 86_399_999_999)))
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            ScalarValue::Time64Nanosecond(Some(random.range(0, 86_399_999_999_999)))
+        }
+        // Intervals of days to seconds have no months
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            ScalarValue::IntervalYearMonth(Some(random.range(-24, 24) as i32))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            ScalarValue::IntervalMonthDayNano(Some(IntervalMonthDayNano::new(
+                0,
+                random.range(-10, 10) as i32,
+                random.range(-86_400, 86_400) * 1_000_000_000,
+            )))
+        }
+        DataType::List(field) => {
+            let elements = (0..random.below(5))
+                .map(|_| random_value(random, field.data_type()))
+                .collect::<Vec<_>>();
+            ScalarValue::List(ScalarValue::new_list_nullable(&elements, field.data_type()))
+        }
+        // The distinct types of regular expressions
+        DataType::FixedSizeList(field, 1) if field.data_type() == &DataType::Utf8 => {
+            // A NULL string cast to a distinct type is NULL inside the list
+            let pattern = (random.below(8) != 0).then(|| random.pick(PATTERNS));
+            let pattern = StringArray::from(vec![pattern]);
+            let list = FixedSizeListArray::new(field.clone(), 1, Arc::new(pattern), None);
+            ScalarValue::FixedSizeList(Arc::new(list))
+        }
+        _ => panic!("no random values of {data_type}"),
+    }
+}
+
+/// The argument types of `overload`, with type variables bound to bigint.
+fn argument_types(overload: &FunctionOverload) -> Option<Vec<DataType>> {
+    let bindings = (0..10)
+        .map(|i| (format!("${i}"), DataType::Int64))
+        .collect::<HashMap<_, _>>();
+    let mut types = overload
+        .signature
+        .parameters
+        .iter()
+        .map(|p| p.default_data_type(&bindings))
+        .collect::<Option<Vec<_>>>()?;
+    if overload.signature.variadic {
+        types = types.iter().cycle().take(3).cloned().collect();
+    }
+    Some(types)
+}
+
+fn invoke(
+    udf: &ScalarUDF,
+    args: Vec<ColumnarValue>,
+    arg_types: &[DataType],
+    rows: usize,
+) -> Result<ColumnarValue> {
+    let return_type = udf.return_type(arg_types)?;
+    udf.invoke_with_args(ScalarFunctionArgs {
+        args,
+        number_rows: rows,
+        return_type: &return_type,
+    })
+}
+
+/// The value of the single row of `result`.
+fn single_value(result: ColumnarValue) -> std::result::Result<ScalarValue, String> {
+    match result {
+        ColumnarValue::Scalar(value) => Ok(value),
+        ColumnarValue::Array(array) if array.len() == 1 => {
+            ScalarValue::try_from_array(&array, 0).map_err(|e| e.to_string())
+        }
+        ColumnarValue::Array(array) => {
+            Err(format!("returns {} rows for scalar arguments", array.len()))
+        }
+    }
+}
+
+/// Check the properties of `overload` on random `arg_types`, returning the first violation.
+fn check(
+    overload: &FunctionOverload,
+    arg_types: &[DataType],
+    random: &mut Random,
+) -> Option<String> {
+    let udf = &overload.udf;
+    let null_on_null = !NULL_HANDLING.contains(&overload.function_name());
+    for _ in 0..ITERATIONS {
+        // Each bit of `mask` makes an argument an array
+        for mask in 1..(1u32 << arg_types.len()) {
+            let is_array = |i: usize| mask & (1 << i) != 0;
+            let constants = arg_types
+                .iter()
+                .map(|t| random_value(random, t))
+                .collect::<Vec<_>>();
+            let mut rows = vec![];
+            let mut expected = vec![];
+            for _ in 0..ROWS {
+                let row = arg_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| match is_array(i) {
+                        true => random_value(random, t),
+                        false => constants[i].clone(),
+                    })
+                    .collect::<Vec<_>>();
+                let args = row.iter().cloned().map(ColumnarValue::Scalar).collect();
+                let has_null = row.iter().any(ScalarValue::is_null);
+                let call = format!("{}({row:?})", udf.name());
+                match invoke(udf, args, arg_types, 1) {
+                    Ok(result) => {
+                        let value = match single_value(result) {
+                            Ok(value) => value,
+                            Err(e) => return Some(format!("{call} {e}")),
+                        };
+                        if null_on_null && has_null && !value.is_null() {
+                            return Some(format!("{call} returns {value:?} instead of NULL"));
+                        }
+                        rows.push(row);
+                        expected.push(value);
+                    }
+                    Err(e) if null_on_null && has_null => {
+                        return Some(format!("{call} fails instead of returning NULL: {e}"))
+                    }
+                    // Invalid arguments
+                    Err(_) => {}
+                }
+            }
+            if rows.is_empty() {
+                continue;
+            }
+            let args = (0..arg_types.len())
+                .map(|i| match is_array(i) {
+                    true => ScalarValue::iter_to_array(rows.iter().map(|r| r[i].clone()))
+                        .map(ColumnarValue::Array),
+                    false => Ok(ColumnarValue::Scalar(constants[i].clone())),
+                })
+                .collect::<Result<Vec<_>>>();
+            let call = format!(
+                "{} with array arguments {:b} of {:?}",
+                udf.name(),
+                mask,
+                rows
+            );
+            let result = match args.and_then(|args| invoke(udf, args, arg_types, rows.len())) {
+                Ok(result) => result,
+                Err(e) => return Some(format!("{call} fails, but not with scalar arguments: {e}")),
+            };
+            let result = match result.into_array(rows.len()) {
+                Ok(result) if result.len() == rows.len() => result,
+                Ok(result) => {
+                    return Some(format!(
+                        "{call} returns {} rows instead of {}",
+                        result.len(),
+                        rows.len()
+                    ))
+                }
+                Err(e) => return Some(format!("{call}: {e}")),
+            };
+            for (i, expected) in expected.iter().enumerate() {
+                match ScalarValue::try_from_array(&result, i) {
+                    Ok(value) if value == *expected => {}
+                    Ok(value) => {
+                        return Some(format!(
+                            "{call} returns {value:?} for row {i}, but {expected:?} with scalar arguments"
+                        ))
+                    }
+                    Err(e) => return Some(format!("{call}: {e}")),
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The implemented overloads with arguments whose results only depend on their arguments. The
+/// higher-order functions, which are only evaluated once their lambda expressions are compiled
+/// by the simplifier, are covered by their conformance tests instead.
+fn deterministic_overloads() -> Vec<FunctionOverload> {
+    sql_functions::trino::overloads()
+        .into_iter()
+        .filter(|o| o.status() == ImplementationStatus::Implemented)
+        .filter(|o| o.udf.signature().volatility == Volatility::Immutable)
+        .filter(|o| !o.signature.parameters.is_empty())
+        .filter(|o| !TYPE_ARGUMENT.contains(&o.function_name()))
+        .filter(|o| !o.signature.takes_lambda())
+        .collect()
+}
+
+#[test]
+fn scalar_and_array_arguments_give_the_same_results() {
+    let mut random = Random(42);
+    let mut checked = 0;
+    let mut skipped = vec![];
+    let mut failures = vec![];
+    for overload in deterministic_overloads() {
+        let arg_types = match argument_types(&overload) {
+            Some(arg_types) if arg_types.iter().all(is_generated) => arg_types,
+            _ => {
+                skipped.push(overload.name.to_string());
+                continue;
+            }
+        };
+        checked += 1;
+        if let Some(failure) = check(&overload, &arg_types, &mut random) {
+            failures.push(format!("{}: {failure}", overload.name));
+        }
+    }
+    assert!(checked > 0);
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    skipped.sort();
+    assert_eq!(skipped, SKIPPED);
+}
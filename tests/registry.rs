@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow::datatypes::DataType;
+use arrow::util::pretty::pretty_format_batches;
+use datafusion::error::Result;
+use datafusion::logical_expr::{create_udf, ColumnarValue, Volatility};
+use datafusion::prelude::SessionContext;
+use sql_functions::registry::Builtins;
+use sql_functions::{register_dialect, register_dialect_with_options, Dialect, RegisterOptions};
+
+async fn run(ctx: &SessionContext, sql: &str) -> Result<String> {
+    let batches = ctx.sql(sql).await?.collect().await?;
+    Ok(pretty_format_batches(&batches)?.to_string())
+}
+
+#[tokio::test]
+async fn stubs_do_not_shadow_builtins() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    let registered = register_dialect(&mut ctx, Dialect::Trino)?;
+    assert!(registered.iter().any(|name| name == "regexp_like"));
+    assert!(!registered.iter().any(|name| name == "coalesce"));
+    let result = run(&ctx, "SELECT coalesce(NULL, 2) AS c").await?;
+    assert!(result.contains("| 2 |"), "{result}");
+
+    let mut ctx = SessionContext::new();
+    let options = RegisterOptions::default().with_implemented_only(false);
+    let registered = register_dialect_with_options(&mut ctx, Dialect::Trino, &options)?;
+    assert!(registered.iter().any(|name| name == "coalesce"));
+    let err = run(&ctx, "SELECT coalesce(NULL, 2)").await.unwrap_err();
+    assert!(err.to_string().contains("Not implemented"), "{err}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn functions_are_registered_under_the_schema() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    let options = RegisterOptions::default().with_schema("trino");
+    let registered = register_dialect_with_options(&mut ctx, Dialect::Trino, &options)?;
+    assert!(registered.iter().any(|name| name == "trino.regexp_extract"));
+    assert!(!registered.iter().any(|name| name == "regexp_extract"));
+    let result = run(&ctx, "SELECT trino.regexp_extract('abc', 'b') AS r").await?;
+    assert!(result.contains("| b |"), "{result}");
+    assert!(run(&ctx, "SELECT regexp_extract('abc', 'b')")
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn existing_functions_are_kept() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    let existing = create_udf(
+        "regexp_like",
+        vec![DataType::Utf8, DataType::Utf8],
+        DataType::Utf8,
+        Volatility::Immutable,
+        Arc::new(|_: &[ColumnarValue]| {
+            Ok(ColumnarValue::from(datafusion::scalar::ScalarValue::from(
+                "existing",
+            )))
+        }),
+    );
+    ctx.register_udf(existing);
+    let options = RegisterOptions::default().with_builtins(Builtins::Keep);
+    let registered = register_dialect_with_options(&mut ctx, Dialect::Trino, &options)?;
+    assert!(!registered.iter().any(|name| name == "regexp_like"));
+    assert!(registered.iter().any(|name| name == "regexp_extract"));
+    let result = run(&ctx, "SELECT regexp_like('abc', 'b') AS r").await?;
+    assert!(result.contains("| existing |"), "{result}");
+
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let result = run(&ctx, "SELECT regexp_like('abc', 'b') AS r").await?;
+    assert!(result.contains("| true |"), "{result}");
+    Ok(())
+}
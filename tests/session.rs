@@ -0,0 +1,127 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::datatypes::{DataType, TimeUnit};
+use arrow::util::pretty::pretty_format_batches;
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use sql_functions::trino::session::TrinoSession;
+use sql_functions::{register_dialect, Dialect};
+
+async fn context(session: TrinoSession) -> Result<SessionContext> {
+    let config = SessionConfig::new().with_option_extension(session);
+    let mut ctx = SessionContext::new_with_config(config);
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    Ok(ctx)
+}
+
+async fn query(ctx: &SessionContext, sql: &str) -> Result<String> {
+    let batches = sql_functions::sql(ctx, Dialect::Trino, sql)
+        .await?
+        .collect()
+        .await?;
+    Ok(pretty_format_batches(&batches)?.to_string())
+}
+
+#[tokio::test]
+async fn session_functions_are_folded_from_the_config() -> Result<()> {
+    let mut session = TrinoSession::default();
+    session.user = "alice".to_string();
+    session.groups = "analysts,admins".to_string();
+    session.catalog = "hive".to_string();
+    session.time_zone = "Europe/Paris".to_string();
+    session.start_time = "2024-07-01T10:00:00Z".to_string();
+    let ctx = context(session).await?;
+    let result = query(
+        &ctx,
+        "SELECT current_user AS u, current_groups() AS g, current_catalog AS c, \
+         current_schema AS s, current_timezone() AS tz, current_timestamp AS ts",
+    )
+    .await?;
+    assert_eq!(
+        result,
+        "+-------+--------------------+------+---+--------------+---------------------------+\n\
+         | u     | g                  | c    | s | tz           | ts                        |\n\
+         +-------+--------------------+------+---+--------------+---------------------------+\n\
+         | alice | [analysts, admins] | hive |   | Europe/Paris | 2024-07-01T12:00:00+02:00 |\n\
+         +-------+--------------------+------+---+--------------+---------------------------+"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_functions_need_the_session() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Trino)?;
+    let err = ctx
+        .sql("SELECT current_user")
+        .await?
+        .collect()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("current_user depends on the Trino session"),
+        "{err}"
+    );
+    // Without `trino` options, `sql_functions::sql` uses the default session
+    let result = query(&ctx, "SELECT current_timezone() AS tz").await?;
+    assert!(result.contains("| UTC |"), "{result}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_start_time() -> Result<()> {
+    let mut session = TrinoSession::default();
+    session.start_time = "yesterday".to_string();
+    let ctx = context(session).await?;
+    let err = query(&ctx, "SELECT current_timestamp").await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid session start time 'yesterday'"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_timestamps_are_typed_in_the_session_time_zone() -> Result<()> {
+    let mut session = TrinoSession::default();
+    session.time_zone = "America/New_York".to_string();
+    session.start_time = "2024-03-10T12:34:56.789Z".to_string();
+    let ctx = context(session).await?;
+    for sql in [
+        "SELECT current_timestamp AS ts",
+        "SELECT current_timestamp AS ts UNION ALL SELECT current_timestamp",
+        "SELECT CASE WHEN true THEN current_timestamp END AS ts",
+        "SELECT ts FROM (VALUES (1)) AS t(x) CROSS JOIN (SELECT current_timestamp AS ts)",
+        "SELECT current_timestamp AS ts UNION ALL SELECT from_iso8601_timestamp('2024-03-10T00:00:00')",
+        "SELECT CASE WHEN true THEN current_timestamp ELSE from_unixtime(0) END AS ts",
+    ] {
+        let df = sql_functions::sql(&ctx, Dialect::Trino, sql).await?;
+        let planned = df.schema().field(0).data_type().clone();
+        assert_eq!(
+            planned,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("America/New_York".into())),
+            "{sql}"
+        );
+        for batch in df.collect().await? {
+            assert_eq!(batch.column(0).data_type(), &planned, "{sql}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::{DFParser, Statement};
+use sql_functions::coverage::ImplementationStatus;
+use sql_functions::overload::Overload;
+use sql_functions::sdf::load_functions;
+use sql_functions::table::rewrite_table_function_arguments;
+use sql_functions::{register_table_functions, Dialect, RegisterOptions};
+
+#[test]
+fn every_table_overload_is_generated() -> Result<()> {
+    let defined = load_functions("snowflake")?
+        .iter()
+        .filter(|f| f.kind() == "table")
+        .map(|f| f.overload_name())
+        .collect::<HashSet<_>>();
+    let generated = Dialect::Snowflake
+        .table_overloads()
+        .iter()
+        .map(|o| o.name.to_string())
+        .collect::<HashSet<_>>();
+    assert_eq!(defined, generated);
+    assert!(Dialect::Trino.table_overloads().is_empty());
+    Ok(())
+}
+
+#[test]
+fn implemented_table_functions_are_registered() -> Result<()> {
+    let overloads = Dialect::Snowflake.table_overloads();
+    let status = |name: &str| {
+        overloads
+            .iter()
+            .find(|o| o.name == name)
+            .map(|o| o.status())
+    };
+    assert_eq!(status("flatten_1"), Some(ImplementationStatus::Implemented));
+    assert_eq!(status("infer_schema"), Some(ImplementationStatus::Stub));
+
+    let ctx = SessionContext::new();
+    let options = RegisterOptions::default().with_implemented_only(true);
+    let registered = register_table_functions(&ctx, Dialect::Snowflake, &options)?;
+    assert!(registered.iter().any(|name| name == "flatten"));
+    assert!(registered.iter().any(|name| name == "generator"));
+    assert!(!registered.iter().any(|name| name == "infer_schema"));
+    assert!(ctx.table_function("flatten").is_ok());
+
+    let keys = sql_functions::get_all_table_functions()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect::<HashSet<_>>();
+    assert!(keys.contains("snowflake::flatten_1"));
+    assert!(keys.contains("snowflake::generator"));
+    Ok(())
+}
+
+/// `sql` with the named arguments of the Snowflake table functions rewritten.
+fn rewrite(sql: &str) -> Result<String> {
+    let mut statement = DFParser::parse_sql(sql)?.pop_front().expect("a statement");
+    let Statement::Statement(statement) = &mut statement else {
+        panic!("not a SQL statement");
+    };
+    rewrite_table_function_arguments(statement, &Dialect::Snowflake.table_overloads())?;
+    Ok(statement.to_string())
+}
+
+#[test]
+fn named_arguments_are_rewritten() -> Result<()> {
+    assert_eq!(
+        rewrite("SELECT * FROM TABLE(generator(rowcount => 3)) AS g")?,
+        "SELECT * FROM generator(3) AS g"
+    );
+    assert_eq!(
+        rewrite("SELECT * FROM generator(TIMELIMIT => 10)")?,
+        "SELECT * FROM generator(NULL, 10)"
+    );
+    assert_eq!(
+        rewrite("SELECT * FROM flatten('[1]', mode => 'array', outer => true)")?,
+        "SELECT * FROM flatten('[1]', NULL, true, NULL, 'array')"
+    );
+    // Positional arguments and the functions of other dialects are left as they are
+    assert_eq!(
+        rewrite("SELECT * FROM flatten('[1]', 'a')")?,
+        "SELECT * FROM flatten('[1]', 'a')"
+    );
+    assert_eq!(
+        rewrite("SELECT * FROM range(stop => 3)")?,
+        "SELECT * FROM range(stop => 3)"
+    );
+    // A required parameter cannot be omitted
+    assert!(rewrite("SELECT * FROM flatten(path => 'a')").is_err());
+    Ok(())
+}
+
+#[test]
+fn lateral_table_functions_are_reported() {
+    let err = rewrite("SELECT f.value FROM t, LATERAL FLATTEN(input => t.v) AS f").unwrap_err();
+    assert!(
+        err.to_string().contains(
+            "LATERAL FLATTEN is not supported: the arguments of table function FLATTEN must be constants"
+        ),
+        "{err}"
+    );
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::datatypes::{DataType, IntervalUnit, TimeUnit};
+use datafusion::error::Result;
+use sql_functions::trino::types;
+
+#[test]
+fn type_names_round_trip() -> Result<()> {
+    for name in [
+        "boolean",
+        "bigint",
+        "double",
+        "decimal(10,2)",
+        "varchar",
+        "date",
+        "time(6)",
+        "timestamp(3)",
+        "timestamp(6) with time zone",
+        "interval day to second",
+        "interval year to month",
+        "array(map(varchar, bigint))",
+        "row(a bigint, b varchar)",
+    ] {
+        assert_eq!(types::type_name(&types::parse_type(name)?), name);
+    }
+    Ok(())
+}
+
+#[test]
+fn types_map_to_arrow() -> Result<()> {
+    assert_eq!(types::parse_type("integer")?, DataType::Int32);
+    assert_eq!(types::parse_type("varchar(10)")?, DataType::Utf8);
+    assert_eq!(
+        types::parse_type("timestamp(9)")?,
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    );
+    assert_eq!(
+        types::parse_type("interval day to second")?,
+        DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_types_are_rejected() {
+    for name in [
+        "bigness",
+        "decimal(40,2)",
+        "decimal(5,6)",
+        "timestamp(12)",
+        "timestamp(13)",
+        "varchar(0)",
+        "array(bigness)",
+    ] {
+        assert!(types::parse_type(name).is_err(), "{name}");
+    }
+}
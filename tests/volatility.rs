@@ -0,0 +1,161 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::array::{Float64Array, Int64Array, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion::common::cast::as_float64_array;
+use datafusion::datasource::MemTable;
+use datafusion::error::Result;
+use datafusion::logical_expr::Volatility;
+use datafusion::prelude::SessionContext;
+
+fn volatility_of(name: &str) -> Vec<Volatility> {
+    sql_functions::trino::functions()
+        .into_iter()
+        .filter(|(_, udf)| udf.name() == name)
+        .map(|(_, udf)| udf.signature().volatility)
+        .collect()
+}
+
+#[test]
+fn non_deterministic_functions_are_volatile() {
+    for name in ["rand", "random", "shuffle", "uuid"] {
+        let volatilities = volatility_of(name);
+        assert!(!volatilities.is_empty(), "{name}");
+        assert!(
+            volatilities.iter().all(|v| *v == Volatility::Volatile),
+            "{name}: {volatilities:?}"
+        );
+    }
+}
+
+#[test]
+fn session_dependent_functions_are_stable() {
+    for name in [
+        "now",
+        "current_timestamp",
+        "current_date",
+        "current_time",
+        "localtime",
+        "localtimestamp",
+        "current_timezone",
+        "current_user",
+        "current_catalog",
+        "current_schema",
+        "current_groups",
+    ] {
+        let volatilities = volatility_of(name);
+        assert!(!volatilities.is_empty(), "{name}");
+        assert!(
+            volatilities.iter().all(|v| *v == Volatility::Stable),
+            "{name}: {volatilities:?}"
+        );
+    }
+}
+
+#[test]
+fn deterministic_functions_are_immutable() {
+    for name in ["abs", "exp", "regexp_like", "date_diff"] {
+        let volatilities = volatility_of(name);
+        assert!(!volatilities.is_empty(), "{name}");
+        assert!(
+            volatilities.iter().all(|v| *v == Volatility::Immutable),
+            "{name}: {volatilities:?}"
+        );
+    }
+}
+
+#[test]
+fn overloaded_function_takes_most_volatile_overload() {
+    let rand = sql_functions::trino::overloaded_functions()
+        .into_iter()
+        .find(|(name, _)| name == "trino::rand")
+        .map(|(_, udf)| udf)
+        .unwrap();
+    assert_eq!(rand.signature().volatility, Volatility::Volatile);
+}
+
+async fn context_with_rows(rows: i64) -> Result<SessionContext> {
+    let ctx = SessionContext::new();
+    for (_, udf) in sql_functions::get_all_overloaded_functions() {
+        ctx.register_udf(udf.as_ref().clone());
+    }
+    let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int64, false)]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from_iter_values(0..rows))],
+    )?;
+    let table = MemTable::try_new(schema, vec![vec![batch]])?;
+    ctx.register_table("t", Arc::new(table))?;
+    Ok(ctx)
+}
+
+#[tokio::test]
+async fn rand_produces_distinct_values_per_row() -> Result<()> {
+    let ctx = context_with_rows(100).await?;
+    let batches = ctx
+        .sql("SELECT rand() AS r FROM t")
+        .await?
+        .collect()
+        .await?;
+    let mut values = HashSet::new();
+    let mut rows = 0;
+    for batch in &batches {
+        let column = as_float64_array(batch.column(0))?;
+        for value in column.values() {
+            assert!((0.0..1.0).contains(value));
+            values.insert(value.to_bits());
+            rows += 1;
+        }
+    }
+    assert_eq!(rows, 100);
+    assert_eq!(values.len(), 100);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rand_is_not_constant_folded() -> Result<()> {
+    let ctx = context_with_rows(2).await?;
+    let plan = ctx
+        .sql("SELECT rand() AS r FROM t")
+        .await?
+        .into_optimized_plan()?;
+    assert!(format!("{plan}").contains("rand()"), "{plan}");
+
+    let batches = ctx
+        .sql("SELECT random() AS r FROM t")
+        .await?
+        .collect()
+        .await?;
+    let values: Vec<f64> = batches
+        .iter()
+        .flat_map(|b| {
+            b.column(0)
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        })
+        .collect();
+    assert_eq!(values.len(), 2);
+    assert_ne!(values[0], values[1]);
+    Ok(())
+}
@@ -0,0 +1,131 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+
+use arrow::array::Int64Array;
+use datafusion::common::cast::as_uint64_array;
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+use sql_functions::coverage::ImplementationStatus;
+use sql_functions::overload::Overload;
+use sql_functions::sdf::load_functions;
+use sql_functions::window::mark_from_last;
+use sql_functions::{register_dialect, Dialect};
+
+#[test]
+fn every_window_overload_is_generated() -> Result<()> {
+    for dialect in [Dialect::Trino, Dialect::Redshift, Dialect::Snowflake] {
+        let defined = load_functions(dialect.name())?
+            .iter()
+            .filter(|f| f.kind() == "window")
+            .map(|f| f.overload_name())
+            .collect::<HashSet<_>>();
+        let generated = dialect
+            .window_overloads()
+            .iter()
+            .map(|o| o.name.to_string())
+            .collect::<HashSet<_>>();
+        assert_eq!(defined, generated, "{}", dialect.name());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn stubs_do_not_shadow_builtin_window_functions() -> Result<()> {
+    let rank = Dialect::Trino
+        .window_overloads()
+        .into_iter()
+        .filter(|o| o.function_name() == "rank")
+        .collect::<Vec<_>>();
+    assert!(!rank.is_empty());
+    assert!(rank
+        .iter()
+        .all(|o| o.status() == ImplementationStatus::Stub));
+
+    let mut ctx = SessionContext::new();
+    let registered = register_dialect(&mut ctx, Dialect::Trino)?;
+    assert!(registered.iter().any(|name| name == "lag"));
+    assert!(!registered.iter().any(|name| name == "rank"));
+    let batches = ctx
+        .sql("SELECT rank() OVER (ORDER BY x), lag(x) OVER (ORDER BY x) FROM (VALUES (1), (2)) AS t(x)")
+        .await?
+        .collect()
+        .await?;
+    assert_eq!(as_uint64_array(batches[0].column(0))?.values(), &[1, 2]);
+    assert_eq!(
+        batches[0].column(1).as_ref(),
+        &Int64Array::from(vec![None, Some(1)])
+    );
+    Ok(())
+}
+
+#[test]
+fn from_first_and_from_last_are_marked() {
+    assert_eq!(
+        mark_from_last("SELECT nth_value(x, f(2)) from  last\nOVER w, 'x) FROM LAST OVER' FROM t"),
+        "SELECT \"nth_value FROM LAST\"(x, f(2)) \nOVER w, 'x) FROM LAST OVER' FROM t"
+    );
+    assert_eq!(
+        mark_from_last("SELECT nth_value(x, 2) FROM FIRST IGNORE NULLS OVER ()"),
+        "SELECT nth_value(x, 2)  IGNORE NULLS OVER ()"
+    );
+    assert_eq!(
+        mark_from_last("SELECT x FROM (SELECT 1) FROM LAST"),
+        "SELECT x FROM (SELECT 1) FROM LAST"
+    );
+}
+
+#[tokio::test]
+async fn from_last_needs_an_inline_window() -> Result<()> {
+    let mut ctx = SessionContext::new();
+    register_dialect(&mut ctx, Dialect::Redshift)?;
+    let err = sql_functions::sql(
+        &ctx,
+        Dialect::Redshift,
+        "SELECT nth_value(x, 2) FROM LAST OVER w FROM (VALUES (1)) AS t(x) WINDOW w AS (ORDER BY x)",
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("nth_value FROM LAST needs an inline window specification"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn only_window_and_implemented_scalar_functions_are_generated_for_redshift_and_snowflake() {
+    for dialect in [Dialect::Redshift, Dialect::Snowflake] {
+        assert!(dialect.aggregate_overloads().is_empty());
+    }
+    assert!(Dialect::Redshift.overloads().is_empty());
+    let scalars = Dialect::Snowflake
+        .overloads()
+        .iter()
+        .map(|o| o.name)
+        .collect::<Vec<_>>();
+    assert_eq!(scalars, ["parse_json_varchar"]);
+    let keys = sql_functions::get_all_window_functions()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect::<HashSet<_>>();
+    assert!(keys.contains("trino::lag_1_bigint"));
+    assert!(keys.contains("redshift::ratio_to_report_1"));
+    assert!(keys.contains("snowflake::conditional_true_event_boolean"));
+}